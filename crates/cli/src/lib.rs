@@ -9,7 +9,8 @@
 //!
 //! # Features
 //!
-//! - **modern-tui**: Enable modern ratatui-based terminal interface
+//! - **modern-tui**: Enable the full-screen ratatui interface (also available at
+//!   runtime with `NTK_USE_MODERN_TUI=1`)
 //!
 //! # Architecture
 //!
@@ -19,7 +20,7 @@
 
 use crossterm::event::{DisableFocusChange, EnableFocusChange};
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
-use nettoolskit_core::{AppConfig, CommandEntry, MenuEntry};
+use nettoolskit_core::{AppConfig, MenuEntry};
 use owo_colors::OwoColorize;
 use std::collections::VecDeque;
 use std::future::Future;
@@ -33,8 +34,12 @@ use std::time::{Duration, Instant};
 pub mod display;
 /// User input handling and command parsing.
 pub mod input;
+/// Command palette entries ranked by history frecency.
+mod palette;
 /// Shared rich session state primitives.
 pub mod state;
+/// Full-screen ratatui interface.
+mod tui;
 
 use display::print_logo;
 use input::{read_line, InputResult, RustylineInput};
@@ -229,11 +234,15 @@ pub struct InteractiveOptions {
 
 /// Launch the interactive CLI mode
 pub async fn interactive_mode(options: InteractiveOptions) -> ExitStatus {
-    interactive_mode_with_runner(options, run_interactive_loop).await
+    if nettoolskit_core::Features::detect().use_modern_tui {
+        return interactive_mode_with_runner(options, false, tui::run_modern_tui).await;
+    }
+    interactive_mode_with_runner(options, true, run_interactive_loop).await
 }
 
 async fn interactive_mode_with_runner<F, Fut>(
     options: InteractiveOptions,
+    use_terminal_layout: bool,
     run_loop: F,
 ) -> ExitStatus
 where
@@ -244,21 +253,27 @@ where
     set_footer_output_enabled(options.footer_output);
     let mut log_guard = begin_interactive_logging();
 
-    let (layout_failure_notice, _terminal_layout) =
-        match TerminalLayout::initialize(Some(print_logo)) {
-            Ok(layout) => (None, Some(layout)),
-            Err(e) => {
-                let failure_message = format!("Failed to initialize terminal layout: {e}");
-                let _ = append_footer_log(&format!("Warning: {failure_message}"));
-                log_guard.deactivate();
-                if let Err(clear_error) = clear_terminal() {
-                    let clear_failure = format!("Failed to clear terminal: {clear_error}");
-                    let _ = append_footer_log(&format!("Warning: {clear_failure}"));
-                }
-                print_logo();
-                (Some(failure_message), None)
+    // The full-screen TUI owns the whole screen, so it skips the scroll-region layout.
+    let layout = if use_terminal_layout {
+        Some(TerminalLayout::initialize(Some(print_logo)))
+    } else {
+        None
+    };
+    let (layout_failure_notice, _terminal_layout) = match layout {
+        None => (None, None),
+        Some(Ok(layout)) => (None, Some(layout)),
+        Some(Err(e)) => {
+            let failure_message = format!("Failed to initialize terminal layout: {e}");
+            let _ = append_footer_log(&format!("Warning: {failure_message}"));
+            log_guard.deactivate();
+            if let Err(clear_error) = clear_terminal() {
+                let clear_failure = format!("Failed to clear terminal: {clear_error}");
+                let _ = append_footer_log(&format!("Warning: {clear_failure}"));
             }
-        };
+            print_logo();
+            (Some(failure_message), None)
+        }
+    };
 
     // Initialize telemetry with development configuration
     let tracing_config = TracingConfig {
@@ -395,6 +410,7 @@ async fn run_input_loop(
             let mut raw_mode = RawModeGuard::new()?;
             set_focus_detection_enabled(attention.unfocused_only);
             set_terminal_focused(true);
+            let menu_state = state.clone();
             let runtime_context = InputLoopRuntimeContext {
                 interrupted,
                 state,
@@ -405,7 +421,7 @@ async fn run_input_loop(
                 &mut status_bar,
                 runtime_context,
                 |buffer, interrupted| Box::pin(read_line(buffer, interrupted)),
-                show_main_menu,
+                || show_main_menu(&menu_state),
                 render_prompt,
             )
             .await;
@@ -457,15 +473,14 @@ async fn run_input_loop_with_rustyline(
                 status_bar.set_mode(StatusBarMode::Menu);
                 status_bar
                     .push_notification(StatusNotificationLevel::Info, "Command palette opened");
-                if let Some(selected_cmd) = show_main_menu() {
-                    if selected_cmd == MainAction::Quit {
+                if let Some(selected_label) = show_main_menu(&state) {
+                    if get_main_action(&selected_label) == Some(MainAction::Quit) {
                         status_bar.set_mode(StatusBarMode::Shutdown);
                         render_status_bar(status_bar);
                         print_goodbye();
                         return Ok(ExitStatus::Success);
                     }
 
-                    let selected_label = selected_cmd.slash_static();
                     record_session_history(&mut session_history, &selected_label);
                     record_state_history_entry(&state, HistoryEntryKind::Command, &selected_label);
                     if is_history_command(&selected_label) {
@@ -477,6 +492,11 @@ async fn run_input_loop_with_rustyline(
                         ensure_layout_guard();
                         continue;
                     }
+                    if is_ai_resume_command(&selected_label) {
+                        handle_ai_resume_with_picker(status_bar);
+                        ensure_layout_guard();
+                        continue;
+                    }
 
                     let started = Instant::now();
                     status_bar.set_mode(StatusBarMode::Command);
@@ -590,7 +610,7 @@ async fn run_input_loop_with<R, F, M, P>(
 where
     R: RawModeControl,
     F: for<'a> FnMut(&'a mut String, &'a Arc<AtomicBool>) -> ReadLineFuture<'a>,
-    M: FnMut() -> Option<MainAction>,
+    M: FnMut() -> Option<String>,
     P: FnMut() -> io::Result<()>,
 {
     let interrupted = &runtime_context.interrupted;
@@ -638,11 +658,11 @@ where
                 status_bar.set_mode(StatusBarMode::Menu);
                 status_bar
                     .push_notification(StatusNotificationLevel::Info, "Command palette opened");
-                if let Some(selected_cmd) = show_menu_fn() {
+                if let Some(selected_label) = show_menu_fn() {
                     raw_mode.disable()?;
 
                     // Check if user selected quit command
-                    if selected_cmd == MainAction::Quit {
+                    if get_main_action(&selected_label) == Some(MainAction::Quit) {
                         status_bar.set_mode(StatusBarMode::Shutdown);
                         render_status_bar(status_bar);
                         print_goodbye();
@@ -652,7 +672,6 @@ where
                         return Ok(ExitStatus::Success);
                     }
 
-                    let selected_label = selected_cmd.slash_static();
                     record_session_history(&mut session_history, &selected_label);
                     record_state_history_entry(state, HistoryEntryKind::Command, &selected_label);
                     if is_history_command(&selected_label) {
@@ -665,6 +684,12 @@ where
                         ensure_layout_guard();
                         continue;
                     }
+                    if is_ai_resume_command(&selected_label) {
+                        handle_ai_resume_with_picker(status_bar);
+                        raw_mode.enable()?;
+                        ensure_layout_guard();
+                        continue;
+                    }
 
                     let started = Instant::now();
                     status_bar.set_mode(StatusBarMode::Command);
@@ -1168,26 +1193,28 @@ fn record_state_history_entry(state: &SharedCliState, kind: HistoryEntryKind, en
     }
 }
 
-/// Show main menu when user types "/" - returns MainAction enum directly
-fn show_main_menu() -> Option<MainAction> {
-    use nettoolskit_core::MenuProvider;
-    let menu_entries = MainAction::all_variants();
+/// Show the command palette when user types "/" - returns the selected command line
+fn show_main_menu(state: &SharedCliState) -> Option<String> {
     let current_dir = std::env::current_dir()
         .ok()
         .and_then(|p| p.to_str().map(String::from))
         .unwrap_or_else(|| String::from("."));
 
-    let palette = CommandPalette::new(menu_entries.clone())
-        .with_prompt("   /")
-        .with_title("NetToolsKit Commands")
-        .with_subtitle("Select a command to execute")
-        .with_directory(current_dir);
+    let palette = {
+        let guard = state
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        palette::build_command_palette(&guard)
+    }
+    .with_prompt("   /")
+    .with_title("NetToolsKit Commands")
+    .with_subtitle("Select a command to execute")
+    .with_directory(current_dir);
 
-    palette.show().and_then(|label| {
-        menu_entries
-            .into_iter()
-            .find(|cmd| cmd.slash_static() == label.trim())
-    })
+    palette
+        .show()
+        .map(|label| label.trim().to_string())
+        .filter(|label| !label.is_empty())
 }
 
 fn is_history_command(command: &str) -> bool {
@@ -1593,19 +1620,21 @@ mod tests {
 
     #[tokio::test]
     async fn interactive_mode_with_runner_success_maps_to_success() {
-        let status = interactive_mode_with_runner(default_interactive_options(), |_opts| async {
-            Ok(ExitStatus::Success)
-        })
-        .await;
+        let status =
+            interactive_mode_with_runner(default_interactive_options(), true, |_opts| async {
+                Ok(ExitStatus::Success)
+            })
+            .await;
         assert_eq!(status, ExitStatus::Success);
     }
 
     #[tokio::test]
     async fn interactive_mode_with_runner_error_maps_to_error() {
-        let status = interactive_mode_with_runner(default_interactive_options(), |_opts| async {
-            Err(io::Error::other("synthetic interactive loop failure"))
-        })
-        .await;
+        let status =
+            interactive_mode_with_runner(default_interactive_options(), true, |_opts| async {
+                Err(io::Error::other("synthetic interactive loop failure"))
+            })
+            .await;
         assert_eq!(status, ExitStatus::Error);
     }
 
@@ -1663,7 +1692,7 @@ mod tests {
             &mut status_bar,
             test_runtime_context(interrupted),
             scripted_reader(vec![InputResult::ShowMenu]),
-            || Some(MainAction::Quit),
+            || Some("/quit".to_string()),
             || Ok(()),
         )
         .await
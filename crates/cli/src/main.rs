@@ -3,23 +3,31 @@
 use axum::{
     body::Bytes,
     error_handling::HandleErrorLayer,
-    extract::{DefaultBodyLimit, Extension, Json, Request, State},
+    extract::{DefaultBodyLimit, Extension, Json, Path, Request, State},
     http::{HeaderMap as AxumHeaderMap, HeaderValue, StatusCode},
     middleware::{self, Next},
     response::{IntoResponse, Response},
     routing::{get, post},
     BoxError, Router,
 };
-use clap::{CommandFactory, Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
 use ed25519_dalek::{Signature, Verifier, VerifyingKey};
 use nettoolskit_cli::{interactive_mode, InteractiveOptions};
+use nettoolskit_core::secrets::{NTK_SECRETS_BACKEND_ENV, NTK_SECRETS_PASSPHRASE_ENV};
 use nettoolskit_core::{
-    AppConfig, ApprovalState, ColorMode, CommandEntry, ControlEnvelope, ControlPolicyContext,
-    IngressTransport, OperatorContext, OperatorKind, RuntimeMode, SessionContext, SessionKind,
-    TaskIntent, TaskIntentKind, UnicodeMode,
+    AppConfig, ApprovalState, ColorMode, CommandEntry, ConfigLayers, ControlEnvelope,
+    ControlPolicyContext, IngressTransport, OperatorContext, OperatorKind, PolicySource,
+    RuntimeMode, SecretBackend, SecretStore, SessionContext, SessionKind, TaskExecutionStatus,
+    TaskIntent, TaskIntentKind, TraceContext, TrustStore, UnicodeMode,
+};
+use nettoolskit_orchestrator::execution::headless;
+use nettoolskit_orchestrator::{
+    capture_output, disable_remote_service_dispatch, discord_interaction_decides_approval,
+    require_remote_task_records, set_remote_service_endpoint, telegram_webhook_decides_approval,
+    AiIntent, AiRunOptions, CommandFailure, CommandOutput, ConfigValueReport, ConsoleMode,
+    ExitStatus, OutputBlock, RemoteTaskDecisionRequest, TaskApprovalPolicy, TaskSnapshot,
 };
-use nettoolskit_orchestrator::ExitStatus;
 use nettoolskit_otel::{
     init_tracing_with_config, next_correlation_id, shutdown_tracing, TracingConfig,
 };
@@ -31,7 +39,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use tokio::net::TcpListener;
 use tower::{timeout::TimeoutLayer, ServiceBuilder};
-use tracing::{info, info_span};
+use tracing::{info, info_span, Instrument};
 
 #[cfg(test)]
 use axum::body::Body;
@@ -43,12 +51,14 @@ const NTK_CHATOPS_TELEGRAM_WEBHOOK_SECRET_TOKEN_ENV: &str =
 const NTK_CHATOPS_DISCORD_INTERACTIONS_PUBLIC_KEY_ENV: &str =
     "NTK_CHATOPS_DISCORD_INTERACTIONS_PUBLIC_KEY";
 const NTK_SERVICE_AUTH_TOKEN_ENV: &str = "NTK_SERVICE_AUTH_TOKEN";
+const NTK_SERVICE_OPERATOR_TOKENS_ENV: &str = "NTK_SERVICE_OPERATOR_TOKENS";
 const NTK_CHATOPS_INGRESS_REPLAY_WINDOW_SECONDS_ENV: &str =
     "NTK_CHATOPS_INGRESS_REPLAY_WINDOW_SECONDS";
 const NTK_CHATOPS_INGRESS_REPLAY_MAX_ENTRIES_ENV: &str = "NTK_CHATOPS_INGRESS_REPLAY_MAX_ENTRIES";
 const NTK_CHATOPS_INGRESS_REPLAY_BACKEND_ENV: &str = "NTK_CHATOPS_INGRESS_REPLAY_BACKEND";
 const NTK_CHATOPS_INGRESS_REPLAY_FILE_PATH_ENV: &str = "NTK_CHATOPS_INGRESS_REPLAY_FILE_PATH";
 const NTK_SERVICE_HTTP_TIMEOUT_MS_ENV: &str = "NTK_SERVICE_HTTP_TIMEOUT_MS";
+const NTK_SERVICE_METRICS_AUTH_ENV: &str = "NTK_SERVICE_METRICS_AUTH";
 const DEFAULT_CHATOPS_INGRESS_REPLAY_WINDOW_SECONDS: u64 = 300;
 const DEFAULT_CHATOPS_INGRESS_REPLAY_MAX_ENTRIES: usize = 4_096;
 const DEFAULT_CHATOPS_INGRESS_REPLAY_FILE_LOCK_WAIT_MS: u64 = 1_000;
@@ -72,6 +82,34 @@ pub struct GlobalArgs {
     /// Enable verbose output
     #[clap(short, long, global = true)]
     pub verbose: bool,
+
+    /// Output format for `ai`, `task`, `config` and `manifest list|check|render` (text, json)
+    #[clap(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+
+    /// Send task commands to a running `ntk service` (overrides `general.service_endpoint`)
+    #[clap(long, global = true, value_name = "URL")]
+    pub remote: Option<String>,
+}
+
+/// Output format for non-interactive commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable terminal output
+    #[default]
+    Text,
+    /// One stable JSON document on stdout (see docs/operations/cli-json-output.md)
+    Json,
+}
+
+impl OutputFormat {
+    /// Console mode for entrypoints that may print progress or warnings.
+    const fn console_mode(self) -> ConsoleMode {
+        match self {
+            OutputFormat::Text => ConsoleMode::Text,
+            OutputFormat::Json => ConsoleMode::Silent,
+        }
+    }
 }
 
 /// Available CLI commands
@@ -84,6 +122,34 @@ pub enum Commands {
         command: Option<ManifestCommand>,
     },
 
+    /// Run AI assistant requests without the interactive shell
+    Ai {
+        /// AI subcommand
+        #[clap(subcommand)]
+        command: AiCommand,
+    },
+
+    /// Submit and inspect runtime tasks
+    Task {
+        /// Task subcommand
+        #[clap(subcommand)]
+        command: TaskCommand,
+    },
+
+    /// Read and update user configuration
+    Config {
+        /// Config subcommand
+        #[clap(subcommand)]
+        command: ConfigCommand,
+    },
+
+    /// Store secrets in the OS keyring (encrypted file on headless hosts)
+    Secrets {
+        /// Secrets subcommand
+        #[clap(subcommand)]
+        command: SecretsCommand,
+    },
+
     /// Generate shell completions for the specified shell
     Completions {
         /// Target shell (bash, zsh, fish, powershell)
@@ -115,6 +181,10 @@ struct ServiceTaskSubmitResponse {
     exit_status: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     task_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task_status: Option<TaskExecutionStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rejection_reason: Option<String>,
     request_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     correlation_id: Option<String>,
@@ -175,9 +245,36 @@ struct ServiceRuntimeState {
     ingress_security: Arc<ServiceIngressSecurityConfig>,
     replay_guard: Arc<IngressReplayGuard>,
     service_auth_token: Option<String>,
+    operator_tokens: Vec<ServiceOperatorToken>,
+    metrics_require_auth: bool,
     data_dir: Option<std::path::PathBuf>,
 }
 
+impl ServiceRuntimeState {
+    /// Whether HTTP callers must present a bearer token.
+    fn requires_bearer_auth(&self) -> bool {
+        self.service_auth_token.is_some() || !self.operator_tokens.is_empty()
+    }
+
+    fn accepts_bearer_token(&self, token: &str) -> bool {
+        self.service_auth_token.as_deref() == Some(token) || self.bound_operator(token).is_some()
+    }
+
+    fn bound_operator(&self, token: &str) -> Option<&ServiceOperatorToken> {
+        self.operator_tokens
+            .iter()
+            .find(|operator| operator.token == token)
+    }
+}
+
+/// Bearer token bound to one operator identity (`NTK_SERVICE_OPERATOR_TOKENS`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ServiceOperatorToken {
+    operator_id: String,
+    operator_kind: OperatorKind,
+    token: String,
+}
+
 #[derive(Debug, Clone)]
 struct ServiceRequestContext {
     request_id: String,
@@ -241,6 +338,13 @@ struct TelegramUpdateReplayEnvelope {
     update_id: Option<i64>,
 }
 
+#[derive(Debug, Deserialize)]
+struct DiscordInteractionReplayEnvelope {
+    id: Option<String>,
+    #[serde(rename = "type")]
+    kind: Option<u8>,
+}
+
 /// Non-interactive manifest subcommands.
 #[derive(Debug, Subcommand)]
 pub enum ManifestCommand {
@@ -249,7 +353,7 @@ pub enum ManifestCommand {
     /// Validate manifest structure and dependencies.
     Check {
         /// Path to manifest file (required for deterministic validation).
-        path: String,
+        path: std::path::PathBuf,
         /// Validate as template file instead of manifest YAML.
         #[clap(long)]
         template: bool,
@@ -257,76 +361,214 @@ pub enum ManifestCommand {
     /// Preview generated files without applying changes.
     Render {
         /// Path to manifest file.
-        path: String,
-        /// Keep operation in dry-run mode (preview only).
+        path: std::path::PathBuf,
+        /// Keep operation in dry-run mode (preview only; render never writes).
         #[clap(long)]
         dry_run: bool,
         /// Optional output root directory for rendering preview.
         #[clap(long)]
-        output: Option<String>,
+        output_dir: Option<std::path::PathBuf>,
     },
     /// Apply a manifest file to generate/update project files.
     Apply {
         /// Path to manifest file.
-        path: String,
+        path: std::path::PathBuf,
         /// Optional output root directory.
         #[clap(long)]
-        output: Option<String>,
+        output_dir: Option<std::path::PathBuf>,
         /// Run without writing changes.
         #[clap(long)]
         dry_run: bool,
     },
+    /// Draft a manifest from an existing C# codebase.
+    Import {
+        /// Directory containing the C# sources to scan.
+        #[clap(default_value = ".")]
+        dir: std::path::PathBuf,
+        /// Write the draft manifest to this file instead of stdout.
+        #[clap(long)]
+        output_file: Option<std::path::PathBuf>,
+    },
+}
+
+/// Non-interactive AI assistant subcommands.
+#[derive(Debug, Subcommand)]
+pub enum AiCommand {
+    /// Ask a free-form engineering question.
+    Ask {
+        /// Prompt text.
+        #[clap(required = true)]
+        prompt: Vec<String>,
+    },
+    /// Produce a step-by-step implementation plan.
+    Plan {
+        /// Goal to plan for.
+        #[clap(required = true)]
+        goal: Vec<String>,
+    },
+    /// Explain technical behavior.
+    Explain {
+        /// Topic to explain.
+        #[clap(required = true)]
+        topic: Vec<String>,
+    },
+    /// Propose a patch (advisory; gated by the approval policy).
+    Apply {
+        /// Preview only (recommended).
+        #[clap(long)]
+        dry_run: bool,
+        /// Explicitly approve a mutating request.
+        #[clap(long)]
+        approve_write: bool,
+        /// Instruction describing the change.
+        #[clap(required = true)]
+        instruction: Vec<String>,
+    },
+}
+
+/// Non-interactive task subcommands.
+///
+/// With `--remote` or `general.service_endpoint` these call the running
+/// service; otherwise `list`, `watch` and `cancel` read the task registry of the
+/// current process, so they only see tasks submitted by the same invocation.
+#[derive(Debug, Subcommand)]
+pub enum TaskCommand {
+    /// Submit a task (intents: command, ai-ask, ai-plan, ai-explain, ai-apply-dry-run, repo-workflow).
+    Submit {
+        /// Task intent.
+        intent: String,
+        /// Task payload.
+        #[clap(required = true)]
+        payload: Vec<String>,
+    },
+    /// List known tasks, most recently updated first.
+    List,
+    /// Show one task with its audit trail.
+    Watch {
+        /// Task identifier.
+        id: String,
+    },
+    /// Cancel a queued, parked or running task.
+    Cancel {
+        /// Task identifier.
+        id: String,
+    },
+    /// List tasks waiting for operator approval.
+    Approvals,
+    /// Approve a parked task and release it for execution.
+    Approve {
+        /// Task identifier.
+        id: String,
+        /// Note recorded in the approval audit log.
+        #[clap(long)]
+        note: Option<String>,
+    },
+    /// Reject a parked task; it is cancelled without running.
+    Reject {
+        /// Task identifier.
+        id: String,
+        /// Reason recorded in the approval audit log.
+        #[clap(long)]
+        reason: Option<String>,
+    },
+}
+
+/// Secret store subcommands.
+///
+/// Names are `service/account`; a bare name uses the `nettoolskit` service.
+/// Stored secrets are referenced from configuration as `keyring:service/account`.
+#[derive(Debug, Subcommand)]
+pub enum SecretsCommand {
+    /// Store a secret; the value is read from stdin when omitted.
+    Set {
+        /// Secret name.
+        name: String,
+        /// Secret value (prefer stdin to keep it out of shell history).
+        value: Option<String>,
+    },
+    /// Print a stored secret.
+    Get {
+        /// Secret name.
+        name: String,
+    },
+    /// List stored secret names and their backends.
+    List,
+    /// Remove a stored secret.
+    #[clap(alias = "remove")]
+    Rm {
+        /// Secret name.
+        name: String,
+    },
+}
+
+/// Non-interactive configuration subcommands.
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Print the effective value of a key.
+    Get {
+        /// Configuration key (for example `log_level` or `display.color`).
+        key: String,
+    },
+    /// Persist a value for a key.
+    Set {
+        /// Configuration key.
+        key: String,
+        /// Value to store.
+        #[clap(required = true)]
+        value: Vec<String>,
+    },
+    /// Reset a key to its default.
+    Unset {
+        /// Configuration key.
+        key: String,
+    },
 }
 
 impl Commands {
-    /// Execute this command
-    pub async fn execute(self) -> ExitStatus {
+    /// Execute this command, rendering results in the requested output format.
+    pub async fn execute(self, output: OutputFormat) -> ExitStatus {
         use nettoolskit_orchestrator::{process_command, MainAction};
 
+        if output == OutputFormat::Json && !self.supports_json_output() {
+            return emit_json_document::<()>(
+                self.json_command_name(),
+                Err(CommandFailure {
+                    message: "JSON output is not supported for this command".to_string(),
+                    hints: vec![
+                        "Supported: ai, task, config, secrets and manifest list|check|render subcommands"
+                            .to_string(),
+                    ],
+                }),
+                |_| ExitStatus::Success,
+            );
+        }
+
         match self {
+            Commands::Manifest {
+                command: Some(command),
+            } if output == OutputFormat::Json => execute_manifest_json_command(command).await,
             Commands::Manifest { command } => match command {
                 None => process_command(&MainAction::Manifest.slash_static()).await,
-                Some(ManifestCommand::List) => process_command("/manifest list").await,
+                Some(ManifestCommand::List) => headless::manifest_list().await,
                 Some(ManifestCommand::Check { path, template }) => {
-                    let mut command_line = format!("/manifest check {path}");
-                    if template {
-                        command_line.push_str(" --template");
-                    }
-                    process_command(&command_line).await
+                    headless::manifest_check(path, template).await
                 }
                 Some(ManifestCommand::Render {
-                    path,
-                    dry_run,
-                    output,
-                }) => {
-                    let cmd = if dry_run {
-                        format!("/manifest render {path} --dry-run")
-                    } else {
-                        format!("/manifest render {path}")
-                    };
-                    let mut command_line = cmd;
-                    if let Some(output_dir) = output {
-                        command_line.push_str(" --output ");
-                        command_line.push_str(&output_dir);
-                    }
-                    process_command(&command_line).await
-                }
+                    path, output_dir, ..
+                }) => headless::manifest_render(path, output_dir).await,
                 Some(ManifestCommand::Apply {
                     path,
-                    output,
+                    output_dir,
                     dry_run,
-                }) => {
-                    let mut command_line = format!("/manifest apply {path}");
-                    if dry_run {
-                        command_line.push_str(" --dry-run");
-                    }
-                    if let Some(output_dir) = output {
-                        command_line.push_str(" --output ");
-                        command_line.push_str(&output_dir);
-                    }
-                    process_command(&command_line).await
+                }) => headless::manifest_apply(path, output_dir, dry_run).await,
+                Some(ManifestCommand::Import { dir, output_file }) => {
+                    headless::manifest_import(dir, output_file).await
                 }
             },
+            Commands::Ai { command } => execute_ai_command(command, output).await,
+            Commands::Task { command } => execute_task_command(command, output).await,
+            Commands::Config { command } => execute_config_command(command, output).await,
+            Commands::Secrets { command } => execute_secrets_command(command, output),
             Commands::Completions { shell } => {
                 clap_complete::generate(shell, &mut Cli::command(), "ntk", &mut std::io::stdout());
                 ExitStatus::Success
@@ -334,185 +576,755 @@ impl Commands {
             Commands::Service { host, port } => run_service_mode(host, port).await,
         }
     }
-}
-
-impl ServiceIngressSecurityConfig {
-    fn from_env() -> Result<Self, String> {
-        let telegram_secret_token = std::env::var(NTK_CHATOPS_TELEGRAM_WEBHOOK_SECRET_TOKEN_ENV)
-            .ok()
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty());
 
-        let discord_verifying_key =
-            match std::env::var(NTK_CHATOPS_DISCORD_INTERACTIONS_PUBLIC_KEY_ENV) {
-                Ok(value) if !value.trim().is_empty() => {
-                    Some(parse_discord_public_key(value.trim())?)
+    const fn supports_json_output(&self) -> bool {
+        matches!(
+            self,
+            Commands::Ai { .. }
+                | Commands::Task { .. }
+                | Commands::Config { .. }
+                | Commands::Secrets { .. }
+                | Commands::Manifest {
+                    command: Some(
+                        ManifestCommand::List
+                            | ManifestCommand::Check { .. }
+                            | ManifestCommand::Render { .. }
+                    ),
                 }
-                _ => None,
-            };
+        )
+    }
 
-        let replay_window = std::env::var(NTK_CHATOPS_INGRESS_REPLAY_WINDOW_SECONDS_ENV)
-            .ok()
-            .and_then(|value| value.trim().parse::<u64>().ok())
-            .map(|seconds| std::time::Duration::from_secs(seconds.max(1)))
-            .unwrap_or_else(|| {
-                std::time::Duration::from_secs(DEFAULT_CHATOPS_INGRESS_REPLAY_WINDOW_SECONDS)
-            });
-        let replay_max_entries = std::env::var(NTK_CHATOPS_INGRESS_REPLAY_MAX_ENTRIES_ENV)
-            .ok()
-            .and_then(|value| value.trim().parse::<usize>().ok())
-            .map(|entries| entries.max(128))
-            .unwrap_or(DEFAULT_CHATOPS_INGRESS_REPLAY_MAX_ENTRIES);
-        let replay_backend = resolve_replay_backend_from_env()?;
+    fn json_command_name(&self) -> &'static str {
+        match self {
+            Commands::Manifest {
+                command: Some(command),
+            } => command.json_name(),
+            Commands::Manifest { command: None } => "manifest",
+            Commands::Ai { command } => command.json_name(),
+            Commands::Task { command } => command.json_name(),
+            Commands::Config { command } => command.json_name(),
+            Commands::Secrets { command } => command.json_name(),
+            Commands::Completions { .. } => "completions",
+            Commands::Service { .. } => "service",
+        }
+    }
+}
 
-        Ok(Self {
-            telegram_secret_token,
-            discord_verifying_key,
-            replay_window,
-            replay_max_entries,
-            replay_backend,
-        })
+impl ManifestCommand {
+    const fn json_name(&self) -> &'static str {
+        match self {
+            ManifestCommand::List => "manifest.list",
+            ManifestCommand::Check { .. } => "manifest.check",
+            ManifestCommand::Render { .. } => "manifest.render",
+            ManifestCommand::Apply { .. } => "manifest.apply",
+            ManifestCommand::Import { .. } => "manifest.import",
+        }
     }
+}
 
-    const fn has_telegram_signature_validation(&self) -> bool {
-        self.telegram_secret_token.is_some()
+impl AiCommand {
+    const fn json_name(&self) -> &'static str {
+        match self {
+            AiCommand::Ask { .. } => "ai.ask",
+            AiCommand::Plan { .. } => "ai.plan",
+            AiCommand::Explain { .. } => "ai.explain",
+            AiCommand::Apply { .. } => "ai.apply",
+        }
     }
+}
 
-    const fn has_discord_signature_validation(&self) -> bool {
-        self.discord_verifying_key.is_some()
+impl TaskCommand {
+    const fn json_name(&self) -> &'static str {
+        match self {
+            TaskCommand::Submit { .. } => "task.submit",
+            TaskCommand::List => "task.list",
+            TaskCommand::Watch { .. } => "task.watch",
+            TaskCommand::Cancel { .. } => "task.cancel",
+            TaskCommand::Approvals => "task.approvals",
+            TaskCommand::Approve { .. } => "task.approve",
+            TaskCommand::Reject { .. } => "task.reject",
+        }
     }
+}
 
-    fn replay_backend_description(&self) -> String {
-        match &self.replay_backend {
-            IngressReplayBackendConfig::Memory => "memory".to_string(),
-            IngressReplayBackendConfig::File { path } => format!("file ({})", path.display()),
+impl ConfigCommand {
+    const fn json_name(&self) -> &'static str {
+        match self {
+            ConfigCommand::Get { .. } => "config.get",
+            ConfigCommand::Set { .. } => "config.set",
+            ConfigCommand::Unset { .. } => "config.unset",
         }
     }
 }
 
-fn resolve_replay_backend_from_env() -> Result<IngressReplayBackendConfig, String> {
-    let backend_value = std::env::var(NTK_CHATOPS_INGRESS_REPLAY_BACKEND_ENV)
-        .unwrap_or_else(|_| "memory".to_string());
-    match backend_value.trim().to_ascii_lowercase().as_str() {
-        "" | "memory" | "in_memory" | "in-memory" => Ok(IngressReplayBackendConfig::Memory),
-        "file" => {
-            let configured_path = std::env::var(NTK_CHATOPS_INGRESS_REPLAY_FILE_PATH_ENV)
-                .ok()
-                .map(|value| value.trim().to_string())
-                .filter(|value| !value.is_empty());
-            let path = configured_path
-                .map(std::path::PathBuf::from)
-                .unwrap_or_else(default_replay_file_path);
-            Ok(IngressReplayBackendConfig::File { path })
+impl SecretsCommand {
+    const fn json_name(&self) -> &'static str {
+        match self {
+            SecretsCommand::Set { .. } => "secrets.set",
+            SecretsCommand::Get { .. } => "secrets.get",
+            SecretsCommand::List => "secrets.list",
+            SecretsCommand::Rm { .. } => "secrets.rm",
         }
-        other => Err(format!(
-            "unsupported replay backend `{other}` (expected `memory` or `file`)"
-        )),
     }
 }
 
-fn default_replay_file_path() -> std::path::PathBuf {
-    AppConfig::default_data_dir()
-        .map(|base| base.join("chatops").join("ingress-replay-cache.json"))
-        .unwrap_or_else(|| {
-            std::path::PathBuf::from(".temp")
-                .join("chatops")
-                .join("ingress-replay-cache.json")
-        })
+/// Version of the `--output json` document layout.
+const CLI_JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Stable JSON document written by `--output json`.
+#[derive(Debug, Serialize)]
+struct CliJsonDocument<T: Serialize> {
+    schema_version: u32,
+    command: &'static str,
+    status: String,
+    exit_code: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<CommandFailure>,
 }
 
-impl IngressReplayGuard {
-    fn with_backend(
-        window: std::time::Duration,
-        max_entries: usize,
-        backend: IngressReplayBackendConfig,
+impl<T: Serialize> CliJsonDocument<T> {
+    fn new(
+        command: &'static str,
+        result: Result<T, CommandFailure>,
+        status_of: impl FnOnce(&T) -> ExitStatus,
     ) -> Self {
+        let status = match &result {
+            Ok(data) => status_of(data),
+            Err(_) => ExitStatus::Error,
+        };
+        let (data, error) = match result {
+            Ok(data) => (Some(data), None),
+            Err(failure) => (None, Some(failure)),
+        };
         Self {
-            replay_window_ms: u64::try_from(window.as_millis()).unwrap_or(u64::MAX).max(1),
-            max_entries: max_entries.max(128),
-            backend: match backend {
-                IngressReplayBackendConfig::Memory => IngressReplayBackend::Memory {
-                    entries: Mutex::new(HashMap::new()),
-                },
-                IngressReplayBackendConfig::File { path } => IngressReplayBackend::File { path },
-            },
+            schema_version: CLI_JSON_SCHEMA_VERSION,
+            command,
+            status: status.to_string(),
+            exit_code: status.into(),
+            data,
+            error,
         }
     }
 
-    fn check_and_record(
-        &self,
-        replay_key: &str,
-        now_unix_ms: u64,
-    ) -> Result<(), IngressSecurityError> {
-        match &self.backend {
-            IngressReplayBackend::Memory { entries } => {
-                let mut entries = entries
-                    .lock()
-                    .unwrap_or_else(|poisoned| poisoned.into_inner());
-                entries.retain(|_, seen_at| {
-                    now_unix_ms.saturating_sub(*seen_at) <= self.replay_window_ms
-                });
-                if entries.contains_key(replay_key) {
-                    return Err(IngressSecurityError::Replay);
-                }
-                entries.insert(replay_key.to_string(), now_unix_ms);
-                trim_replay_entries(&mut entries, self.max_entries);
-                Ok(())
-            }
-            IngressReplayBackend::File { path } => {
-                self.check_and_record_file(path, replay_key, now_unix_ms)
-            }
+    fn exit_status(&self) -> ExitStatus {
+        match self.exit_code {
+            0 => ExitStatus::Success,
+            130 => ExitStatus::Interrupted,
+            _ => ExitStatus::Error,
         }
     }
+}
 
-    fn check_and_record_file(
-        &self,
-        path: &std::path::Path,
-        replay_key: &str,
-        now_unix_ms: u64,
-    ) -> Result<(), IngressSecurityError> {
-        let lock_path = replay_lock_path(path);
-        let _lock = acquire_replay_file_lock(
-            &lock_path,
-            std::time::Duration::from_millis(DEFAULT_CHATOPS_INGRESS_REPLAY_FILE_LOCK_WAIT_MS),
-            std::time::Duration::from_secs(DEFAULT_CHATOPS_INGRESS_REPLAY_FILE_LOCK_STALE_SECONDS),
-        )?;
-
-        let mut entries = load_replay_entries_from_file(path)?;
-        entries.retain(|_, seen_at| now_unix_ms.saturating_sub(*seen_at) <= self.replay_window_ms);
-        if entries.contains_key(replay_key) {
-            return Err(IngressSecurityError::Replay);
+fn emit_json_document<T: Serialize>(
+    command: &'static str,
+    result: Result<T, CommandFailure>,
+    status_of: impl FnOnce(&T) -> ExitStatus,
+) -> ExitStatus {
+    let document = CliJsonDocument::new(command, result, status_of);
+    match serde_json::to_string(&document) {
+        Ok(json) => println!("{json}"),
+        Err(error) => {
+            eprintln!("Failed to serialize JSON output: {error}");
+            return ExitStatus::Error;
         }
-        entries.insert(replay_key.to_string(), now_unix_ms);
-        trim_replay_entries(&mut entries, self.max_entries);
-        save_replay_entries_to_file(path, &entries)
     }
+    document.exit_status()
 }
 
-fn trim_replay_entries(entries: &mut HashMap<String, u64>, max_entries: usize) {
-    while entries.len() > max_entries {
-        let Some(oldest_key) = entries
-            .iter()
-            .min_by_key(|(_, seen_at)| **seen_at)
-            .map(|(key, _)| key.clone())
-        else {
-            break;
-        };
-        entries.remove(&oldest_key);
+fn print_cli_failure(failure: &CommandFailure) {
+    use nettoolskit_ui::Color;
+    use owo_colors::OwoColorize;
+
+    println!(
+        "{}",
+        format!("✗ {}", failure.message).color(Color::RED).bold()
+    );
+    for hint in &failure.hints {
+        println!("{}", hint.color(Color::YELLOW));
     }
 }
 
-fn replay_lock_path(path: &std::path::Path) -> std::path::PathBuf {
-    let file_name = path
-        .file_name()
-        .and_then(std::ffi::OsStr::to_str)
-        .unwrap_or("ingress-replay-cache.json");
-    path.with_file_name(format!("{file_name}.lock"))
-}
+/// Run a manifest subcommand with its structured output captured as the JSON `data`.
+///
+/// Failures reported through an error block become the document `error`; other
+/// failures (for example a manifest with validation errors) keep their `data`.
+async fn execute_manifest_json_command(command: ManifestCommand) -> ExitStatus {
+    let name = command.json_name();
+    let (status, output) = capture_output(async move {
+        match command {
+            ManifestCommand::List => headless::manifest_list().await,
+            ManifestCommand::Check { path, template } => {
+                headless::manifest_check(path, template).await
+            }
+            ManifestCommand::Render {
+                path, output_dir, ..
+            } => headless::manifest_render(path, output_dir).await,
+            ManifestCommand::Apply { .. } | ManifestCommand::Import { .. } => ExitStatus::Error,
+        }
+    })
+    .await;
 
-#[derive(Debug)]
-struct ReplayFileLockGuard {
-    lock_path: std::path::PathBuf,
+    emit_json_document(name, command_output_result(status, output), |_| status)
+}
+
+fn command_output_result(
+    status: ExitStatus,
+    output: CommandOutput,
+) -> Result<CommandOutput, CommandFailure> {
+    if status == ExitStatus::Success {
+        return Ok(output);
+    }
+    let failure = output.blocks.iter().find_map(|block| match block {
+        OutputBlock::Error { message, hints } => Some(CommandFailure {
+            message: message.clone(),
+            hints: hints.clone(),
+        }),
+        _ => None,
+    });
+    match failure {
+        Some(failure) => Err(failure),
+        None => Ok(output),
+    }
+}
+
+async fn execute_ai_command(command: AiCommand, output: OutputFormat) -> ExitStatus {
+    let name = command.json_name();
+    let (intent, words, options) = match command {
+        AiCommand::Ask { prompt } => (AiIntent::Ask, prompt, AiRunOptions::default()),
+        AiCommand::Plan { goal } => (AiIntent::Plan, goal, AiRunOptions::default()),
+        AiCommand::Explain { topic } => (AiIntent::Explain, topic, AiRunOptions::default()),
+        AiCommand::Apply {
+            dry_run,
+            approve_write,
+            instruction,
+        } => (
+            AiIntent::ApplyDryRun,
+            instruction,
+            AiRunOptions {
+                dry_run,
+                approve_write,
+            },
+        ),
+    };
+    let prompt = words.join(" ");
+
+    match output {
+        OutputFormat::Text => {
+            match headless::run_ai(intent, &prompt, options, ConsoleMode::Text).await {
+                Ok(_) => ExitStatus::Success,
+                Err(_) => ExitStatus::Error,
+            }
+        }
+        OutputFormat::Json => emit_json_document(
+            name,
+            headless::run_ai(intent, &prompt, options, ConsoleMode::Silent).await,
+            |_| ExitStatus::Success,
+        ),
+    }
+}
+
+async fn execute_task_command(command: TaskCommand, output: OutputFormat) -> ExitStatus {
+    if !matches!(command, TaskCommand::Submit { .. }) {
+        // This process admitted no tasks; only a running service has records to show.
+        require_remote_task_records();
+    }
+    match command {
+        TaskCommand::Submit { intent, payload } => {
+            let payload = payload.join(" ");
+            match output {
+                OutputFormat::Text => {
+                    match headless::submit_task(&intent, &payload, ConsoleMode::Text).await {
+                        Ok(snapshot) => snapshot.exit_status(),
+                        Err(_) => ExitStatus::Error,
+                    }
+                }
+                OutputFormat::Json => emit_json_document(
+                    "task.submit",
+                    headless::submit_task(&intent, &payload, ConsoleMode::Silent).await,
+                    TaskSnapshot::exit_status,
+                ),
+            }
+        }
+        TaskCommand::List => render_task_list(
+            "task.list",
+            headless::list_tasks(output.console_mode()).await,
+            output,
+        ),
+        TaskCommand::Watch { id } => render_task_result(
+            "task.watch",
+            headless::get_task(&id, output.console_mode()).await,
+            output,
+        ),
+        TaskCommand::Cancel { id } => render_task_result(
+            "task.cancel",
+            headless::cancel_task_by_id(&id, output.console_mode()).await,
+            output,
+        ),
+        TaskCommand::Approvals => render_task_list(
+            "task.approvals",
+            headless::list_pending_approvals(output.console_mode()).await,
+            output,
+        ),
+        TaskCommand::Approve { id, note } => render_task_result(
+            "task.approve",
+            headless::approve_task(&id, note.as_deref(), None, output.console_mode()).await,
+            output,
+        ),
+        TaskCommand::Reject { id, reason } => render_task_result(
+            "task.reject",
+            headless::reject_task(&id, reason.as_deref(), None, output.console_mode()).await,
+            output,
+        ),
+    }
+}
+
+fn render_task_list(
+    command: &'static str,
+    tasks: Result<Vec<TaskSnapshot>, CommandFailure>,
+    output: OutputFormat,
+) -> ExitStatus {
+    match output {
+        OutputFormat::Text => match tasks {
+            Ok(tasks) => {
+                print_task_list_text(&tasks);
+                ExitStatus::Success
+            }
+            Err(failure) => {
+                print_cli_failure(&failure);
+                ExitStatus::Error
+            }
+        },
+        OutputFormat::Json => emit_json_document(command, tasks, |_| ExitStatus::Success),
+    }
+}
+
+fn render_task_result(
+    command: &'static str,
+    result: Result<TaskSnapshot, CommandFailure>,
+    output: OutputFormat,
+) -> ExitStatus {
+    match output {
+        OutputFormat::Text => match result {
+            Ok(snapshot) => {
+                print_task_detail_text(&snapshot);
+                ExitStatus::Success
+            }
+            Err(failure) => {
+                print_cli_failure(&failure);
+                ExitStatus::Error
+            }
+        },
+        OutputFormat::Json => emit_json_document(command, result, |_| ExitStatus::Success),
+    }
+}
+
+fn print_task_list_text(tasks: &[TaskSnapshot]) {
+    if tasks.is_empty() {
+        println!("No task records found.");
+        return;
+    }
+    for task in tasks {
+        println!(
+            "{} | {} | {} | mode:{} | target:{} | attempts:{}/{}",
+            task.id,
+            task.status.as_str(),
+            task.intent,
+            task.runtime_mode,
+            task.execution_target,
+            task.attempts,
+            task.max_attempts
+        );
+    }
+}
+
+fn print_task_detail_text(task: &TaskSnapshot) {
+    println!("id: {}", task.id);
+    println!("intent: {}", task.intent);
+    println!("payload: {}", task.payload);
+    println!("status: {}", task.status.as_str());
+    println!("mode: {}", task.runtime_mode);
+    println!("target: {}", task.execution_target);
+    println!("attempts: {}/{}", task.attempts, task.max_attempts);
+    println!("detail: {}", task.detail);
+    for event in &task.audit_events {
+        println!("  - [{}] {}", event.status.as_str(), event.message);
+    }
+}
+
+async fn execute_config_command(command: ConfigCommand, output: OutputFormat) -> ExitStatus {
+    let name = command.json_name();
+    let result = match &command {
+        ConfigCommand::Get { key } => headless::config_get(key).await,
+        ConfigCommand::Set { key, value } => headless::config_set(key, &value.join(" ")).await,
+        ConfigCommand::Unset { key } => headless::config_unset(key).await,
+    };
+
+    match output {
+        OutputFormat::Json => emit_json_document(name, result, |_| ExitStatus::Success),
+        OutputFormat::Text => match result {
+            Ok(report) => {
+                print_config_report_text(&command, &report);
+                ExitStatus::Success
+            }
+            Err(failure) => {
+                print_cli_failure(&failure);
+                ExitStatus::Error
+            }
+        },
+    }
+}
+
+fn print_config_report_text(command: &ConfigCommand, report: &ConfigValueReport) {
+    match command {
+        ConfigCommand::Get { .. } => println!("{}", report.value),
+        ConfigCommand::Set { .. } => {
+            println!("Updated {}={}", report.key, report.value);
+            println!("  {}", report.path.display());
+        }
+        ConfigCommand::Unset { .. } => {
+            println!("Reset {} (now: {})", report.key, report.value);
+            println!("  {}", report.path.display());
+        }
+    }
+}
+
+/// Result of a `ntk secrets` subcommand.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum SecretsReport {
+    Stored {
+        name: String,
+        backend: SecretBackend,
+        reference: String,
+    },
+    Value {
+        name: String,
+        value: String,
+    },
+    Listed {
+        secrets: Vec<SecretListEntry>,
+    },
+    Removed {
+        name: String,
+    },
+}
+
+#[derive(Debug, Serialize)]
+struct SecretListEntry {
+    name: String,
+    backend: SecretBackend,
+}
+
+fn execute_secrets_command(command: SecretsCommand, output: OutputFormat) -> ExitStatus {
+    let name = command.json_name();
+    let store = SecretStore::open();
+    let result = run_secrets_command(&store, command);
+    match output {
+        OutputFormat::Json => emit_json_document(name, result, |_| ExitStatus::Success),
+        OutputFormat::Text => match result {
+            Ok(report) => {
+                print_secrets_report_text(&report);
+                if matches!(
+                    report,
+                    SecretsReport::Stored {
+                        backend: SecretBackend::File,
+                        ..
+                    }
+                ) && !store.is_passphrase_protected()
+                {
+                    eprintln!(
+                        "Warning: the file store key sits next to the values; set {NTK_SECRETS_PASSPHRASE_ENV} to encrypt them at rest"
+                    );
+                }
+                ExitStatus::Success
+            }
+            Err(failure) => {
+                print_cli_failure(&failure);
+                ExitStatus::Error
+            }
+        },
+    }
+}
+
+fn run_secrets_command(
+    store: &SecretStore,
+    command: SecretsCommand,
+) -> Result<SecretsReport, CommandFailure> {
+    let failure = |message: String| CommandFailure {
+        message,
+        hints: vec![format!(
+            "Set {NTK_SECRETS_BACKEND_ENV}=file to use the encrypted file store"
+        )],
+    };
+    match command {
+        SecretsCommand::Set { name, value } => {
+            let value = match value {
+                Some(value) => value,
+                None => read_secret_from_stdin().map_err(|error| CommandFailure {
+                    message: format!("failed to read secret from stdin: {error}"),
+                    hints: Vec::new(),
+                })?,
+            };
+            if value.trim().is_empty() {
+                return Err(CommandFailure {
+                    message: "secret value is empty".to_string(),
+                    hints: Vec::new(),
+                });
+            }
+            let name = SecretStore::qualified_name(&name);
+            let backend = store.set(&name, value.trim()).map_err(failure)?;
+            Ok(SecretsReport::Stored {
+                reference: format!("keyring:{name}"),
+                name,
+                backend,
+            })
+        }
+        SecretsCommand::Get { name } => {
+            let name = SecretStore::qualified_name(&name);
+            match store.get(&name).map_err(failure)? {
+                Some(value) => Ok(SecretsReport::Value { name, value }),
+                None => Err(CommandFailure {
+                    message: format!("no secret named `{name}`"),
+                    hints: vec!["Run `ntk secrets list` to see stored names".to_string()],
+                }),
+            }
+        }
+        SecretsCommand::List => Ok(SecretsReport::Listed {
+            secrets: store
+                .list()
+                .map_err(failure)?
+                .into_iter()
+                .map(|(name, backend)| SecretListEntry { name, backend })
+                .collect(),
+        }),
+        SecretsCommand::Rm { name } => {
+            let name = SecretStore::qualified_name(&name);
+            if store.remove(&name).map_err(failure)? {
+                Ok(SecretsReport::Removed { name })
+            } else {
+                Err(CommandFailure {
+                    message: format!("no secret named `{name}`"),
+                    hints: Vec::new(),
+                })
+            }
+        }
+    }
+}
+
+fn read_secret_from_stdin() -> std::io::Result<String> {
+    use std::io::{BufRead, IsTerminal, Write};
+
+    if std::io::stdin().is_terminal() {
+        eprint!("Secret value: ");
+        std::io::stderr().flush()?;
+    }
+    let mut value = String::new();
+    std::io::stdin().lock().read_line(&mut value)?;
+    Ok(value.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn print_secrets_report_text(report: &SecretsReport) {
+    match report {
+        SecretsReport::Stored {
+            name,
+            backend,
+            reference,
+        } => {
+            println!("Stored {name} ({backend})");
+            println!("  Reference: {reference}");
+        }
+        SecretsReport::Value { value, .. } => println!("{value}"),
+        SecretsReport::Listed { secrets } if secrets.is_empty() => {
+            println!("No stored secrets");
+        }
+        SecretsReport::Listed { secrets } => {
+            for entry in secrets {
+                println!("{}\t{}", entry.name, entry.backend);
+            }
+        }
+        SecretsReport::Removed { name } => println!("Removed {name}"),
+    }
+}
+
+impl ServiceIngressSecurityConfig {
+    fn from_env() -> Result<Self, String> {
+        let source = PolicySource::load();
+        let telegram_secret_token = source.secret(NTK_CHATOPS_TELEGRAM_WEBHOOK_SECRET_TOKEN_ENV)?;
+
+        let discord_verifying_key =
+            match source.var(NTK_CHATOPS_DISCORD_INTERACTIONS_PUBLIC_KEY_ENV) {
+                Some(value) if !value.trim().is_empty() => {
+                    Some(parse_discord_public_key(value.trim())?)
+                }
+                _ => None,
+            };
+
+        let replay_window = source
+            .var(NTK_CHATOPS_INGRESS_REPLAY_WINDOW_SECONDS_ENV)
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(|seconds| std::time::Duration::from_secs(seconds.max(1)))
+            .unwrap_or_else(|| {
+                std::time::Duration::from_secs(DEFAULT_CHATOPS_INGRESS_REPLAY_WINDOW_SECONDS)
+            });
+        let replay_max_entries = source
+            .var(NTK_CHATOPS_INGRESS_REPLAY_MAX_ENTRIES_ENV)
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .map(|entries| entries.max(128))
+            .unwrap_or(DEFAULT_CHATOPS_INGRESS_REPLAY_MAX_ENTRIES);
+        let replay_backend = resolve_replay_backend_from_env()?;
+
+        Ok(Self {
+            telegram_secret_token,
+            discord_verifying_key,
+            replay_window,
+            replay_max_entries,
+            replay_backend,
+        })
+    }
+
+    const fn has_telegram_signature_validation(&self) -> bool {
+        self.telegram_secret_token.is_some()
+    }
+
+    const fn has_discord_signature_validation(&self) -> bool {
+        self.discord_verifying_key.is_some()
+    }
+
+    fn replay_backend_description(&self) -> String {
+        match &self.replay_backend {
+            IngressReplayBackendConfig::Memory => "memory".to_string(),
+            IngressReplayBackendConfig::File { path } => format!("file ({})", path.display()),
+        }
+    }
+}
+
+fn resolve_replay_backend_from_env() -> Result<IngressReplayBackendConfig, String> {
+    let source = PolicySource::load();
+    let backend_value = source
+        .var(NTK_CHATOPS_INGRESS_REPLAY_BACKEND_ENV)
+        .unwrap_or_else(|| "memory".to_string());
+    match backend_value.trim().to_ascii_lowercase().as_str() {
+        "" | "memory" | "in_memory" | "in-memory" => Ok(IngressReplayBackendConfig::Memory),
+        "file" => {
+            let configured_path = source
+                .var(NTK_CHATOPS_INGRESS_REPLAY_FILE_PATH_ENV)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty());
+            let path = configured_path
+                .map(std::path::PathBuf::from)
+                .unwrap_or_else(default_replay_file_path);
+            Ok(IngressReplayBackendConfig::File { path })
+        }
+        other => Err(format!(
+            "unsupported replay backend `{other}` (expected `memory` or `file`)"
+        )),
+    }
+}
+
+fn default_replay_file_path() -> std::path::PathBuf {
+    AppConfig::default_data_dir()
+        .map(|base| base.join("chatops").join("ingress-replay-cache.json"))
+        .unwrap_or_else(|| {
+            std::path::PathBuf::from(".temp")
+                .join("chatops")
+                .join("ingress-replay-cache.json")
+        })
+}
+
+impl IngressReplayGuard {
+    fn with_backend(
+        window: std::time::Duration,
+        max_entries: usize,
+        backend: IngressReplayBackendConfig,
+    ) -> Self {
+        Self {
+            replay_window_ms: u64::try_from(window.as_millis()).unwrap_or(u64::MAX).max(1),
+            max_entries: max_entries.max(128),
+            backend: match backend {
+                IngressReplayBackendConfig::Memory => IngressReplayBackend::Memory {
+                    entries: Mutex::new(HashMap::new()),
+                },
+                IngressReplayBackendConfig::File { path } => IngressReplayBackend::File { path },
+            },
+        }
+    }
+
+    fn check_and_record(
+        &self,
+        replay_key: &str,
+        now_unix_ms: u64,
+    ) -> Result<(), IngressSecurityError> {
+        match &self.backend {
+            IngressReplayBackend::Memory { entries } => {
+                let mut entries = entries
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                entries.retain(|_, seen_at| {
+                    now_unix_ms.saturating_sub(*seen_at) <= self.replay_window_ms
+                });
+                if entries.contains_key(replay_key) {
+                    return Err(IngressSecurityError::Replay);
+                }
+                entries.insert(replay_key.to_string(), now_unix_ms);
+                trim_replay_entries(&mut entries, self.max_entries);
+                Ok(())
+            }
+            IngressReplayBackend::File { path } => {
+                self.check_and_record_file(path, replay_key, now_unix_ms)
+            }
+        }
+    }
+
+    fn check_and_record_file(
+        &self,
+        path: &std::path::Path,
+        replay_key: &str,
+        now_unix_ms: u64,
+    ) -> Result<(), IngressSecurityError> {
+        let lock_path = replay_lock_path(path);
+        let _lock = acquire_replay_file_lock(
+            &lock_path,
+            std::time::Duration::from_millis(DEFAULT_CHATOPS_INGRESS_REPLAY_FILE_LOCK_WAIT_MS),
+            std::time::Duration::from_secs(DEFAULT_CHATOPS_INGRESS_REPLAY_FILE_LOCK_STALE_SECONDS),
+        )?;
+
+        let mut entries = load_replay_entries_from_file(path)?;
+        entries.retain(|_, seen_at| now_unix_ms.saturating_sub(*seen_at) <= self.replay_window_ms);
+        if entries.contains_key(replay_key) {
+            return Err(IngressSecurityError::Replay);
+        }
+        entries.insert(replay_key.to_string(), now_unix_ms);
+        trim_replay_entries(&mut entries, self.max_entries);
+        save_replay_entries_to_file(path, &entries)
+    }
+}
+
+fn trim_replay_entries(entries: &mut HashMap<String, u64>, max_entries: usize) {
+    while entries.len() > max_entries {
+        let Some(oldest_key) = entries
+            .iter()
+            .min_by_key(|(_, seen_at)| **seen_at)
+            .map(|(key, _)| key.clone())
+        else {
+            break;
+        };
+        entries.remove(&oldest_key);
+    }
+}
+
+fn replay_lock_path(path: &std::path::Path) -> std::path::PathBuf {
+    let file_name = path
+        .file_name()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap_or("ingress-replay-cache.json");
+    path.with_file_name(format!("{file_name}.lock"))
+}
+
+#[derive(Debug)]
+struct ReplayFileLockGuard {
+    lock_path: std::path::PathBuf,
 }
 
 impl Drop for ReplayFileLockGuard {
@@ -688,6 +1500,13 @@ fn verify_telegram_ingress_security(
         if header_secret != expected_secret {
             return Err(IngressSecurityError::Unauthorized);
         }
+    } else {
+        // Approvals act with the sender's authority; without the webhook secret
+        // anyone who can reach the endpoint could forge the sender, whether the
+        // decision arrives as a button click or as an `approve` text command.
+        if telegram_webhook_decides_approval(body) {
+            return Err(IngressSecurityError::Unauthorized);
+        }
     }
 
     let replay_key = telegram_replay_key(body);
@@ -732,6 +1551,23 @@ fn verify_discord_interaction_ingress_security(
         state
             .replay_guard
             .check_and_record(&replay_key, current_unix_timestamp_ms())?;
+    } else {
+        // Unsigned approve/reject clicks or commands could be forged by anyone who
+        // can reach the endpoint, so they require the interactions public key.
+        if discord_interaction_decides_approval(body) {
+            return Err(IngressSecurityError::Unauthorized);
+        }
+    }
+
+    // Button clicks decide approvals, so a redelivered component interaction must
+    // not be applied twice even when signature verification is disabled.
+    if let Ok(payload) = serde_json::from_str::<DiscordInteractionReplayEnvelope>(body) {
+        if let (Some(id), Some(3)) = (payload.id, payload.kind) {
+            state.replay_guard.check_and_record(
+                &format!("discord:interaction:{id}"),
+                current_unix_timestamp_ms(),
+            )?;
+        }
     }
 
     Ok(())
@@ -751,19 +1587,89 @@ fn request_body(request: &str) -> &str {
     request.split_once("\r\n\r\n").map_or("", |(_, body)| body)
 }
 
-#[cfg(test)]
-fn build_http_response(status: &str, content_type: &str, body: &str) -> String {
-    format!(
-        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
-        body.len()
-    )
+#[cfg(test)]
+fn build_http_response(status: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+fn service_auth_token_from_env() -> Result<Option<String>, String> {
+    PolicySource::load().secret(NTK_SERVICE_AUTH_TOKEN_ENV)
+}
+
+fn service_operator_tokens_from_env() -> Result<Vec<ServiceOperatorToken>, String> {
+    match PolicySource::load().secret(NTK_SERVICE_OPERATOR_TOKENS_ENV)? {
+        Some(value) => parse_service_operator_tokens(&value),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Parse comma-separated `id[:kind]=token` entries.
+///
+/// `kind` is `human` (the default) or `automation`. Each token must be unique
+/// so a credential always resolves to exactly one operator.
+fn parse_service_operator_tokens(value: &str) -> Result<Vec<ServiceOperatorToken>, String> {
+    let mut operators: Vec<ServiceOperatorToken> = Vec::new();
+    for entry in value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+    {
+        let Some((identity, token)) = entry.split_once('=') else {
+            return Err(format!(
+                "{NTK_SERVICE_OPERATOR_TOKENS_ENV}: expected `id[:kind]=token`, got an entry without `=`"
+            ));
+        };
+        let (operator_id, kind) = match identity.split_once(':') {
+            Some((operator_id, kind)) => (operator_id.trim(), kind.trim()),
+            None => (identity.trim(), "human"),
+        };
+        let operator_kind = match kind.to_ascii_lowercase().as_str() {
+            "human" => OperatorKind::RemoteHuman,
+            "automation" => OperatorKind::Automation,
+            other => {
+                return Err(format!(
+                    "{NTK_SERVICE_OPERATOR_TOKENS_ENV}: unsupported kind `{other}` for `{operator_id}` (expected `human` or `automation`)"
+                ));
+            }
+        };
+        let token = token.trim();
+        if operator_id.is_empty() || token.is_empty() {
+            return Err(format!(
+                "{NTK_SERVICE_OPERATOR_TOKENS_ENV}: operator id and token must both be non-empty"
+            ));
+        }
+        if operators.iter().any(|operator| operator.token == token) {
+            return Err(format!(
+                "{NTK_SERVICE_OPERATOR_TOKENS_ENV}: token for `{operator_id}` is already bound to another operator"
+            ));
+        }
+        operators.push(ServiceOperatorToken {
+            operator_id: operator_id.to_string(),
+            operator_kind,
+            token: token.to_string(),
+        });
+    }
+    Ok(operators)
 }
 
-fn service_auth_token_from_env() -> Option<String> {
-    std::env::var(NTK_SERVICE_AUTH_TOKEN_ENV)
-        .ok()
-        .map(|value| value.trim().to_string())
-        .filter(|value| !value.is_empty())
+/// Resolve whether `GET /metrics` requires the service bearer token.
+///
+/// Defaults to `bearer` (protected whenever a service token is configured);
+/// `none` exposes metrics without authentication for local scrapers.
+fn service_metrics_require_auth_from_env() -> Result<bool, String> {
+    let value = PolicySource::load()
+        .var(NTK_SERVICE_METRICS_AUTH_ENV)
+        .unwrap_or_default();
+    match value.trim().to_ascii_lowercase().as_str() {
+        "" | "bearer" | "token" | "required" => Ok(true),
+        "none" | "disabled" | "public" => Ok(false),
+        other => Err(format!(
+            "unsupported {NTK_SERVICE_METRICS_AUTH_ENV} value `{other}` (expected `bearer` or `none`)"
+        )),
+    }
 }
 
 fn parse_bearer_token(value: &str) -> Option<&str> {
@@ -788,6 +1694,15 @@ fn service_task_intent_kind(intent: &str) -> TaskIntentKind {
     TaskIntentKind::from_alias(intent).unwrap_or(TaskIntentKind::CommandExecution)
 }
 
+/// Trace context for work admitted by this request.
+///
+/// Prefers the live HTTP request span (so queued work nests under it) and falls
+/// back to the caller's `traceparent`/`tracestate` headers.
+fn service_trace_context(headers: &HashMap<String, String>) -> Option<TraceContext> {
+    nettoolskit_otel::span_trace_context(&tracing::Span::current())
+        .or_else(|| nettoolskit_otel::extract_trace_context(|name| request_header(headers, name)))
+}
+
 fn service_request_context_from_headers(headers: &AxumHeaderMap) -> ServiceRequestContext {
     let request_id = headers
         .get("x-request-id")
@@ -828,15 +1743,23 @@ fn build_service_control_policy() -> ControlPolicyContext {
     ControlPolicyContext::new(ApprovalState::NotRequired, true)
 }
 
-fn build_service_control_envelope(
-    request_context: &ServiceRequestContext,
+/// Operator identity of an HTTP caller.
+///
+/// The operator kind comes only from a per-operator token binding; the
+/// `x-ntk-operator-kind` header is ignored so callers cannot pick the approval
+/// rule that suits them. Unbound bearer callers count as automation and plain
+/// loopback callers as the local human operator.
+fn build_service_operator(
+    state: &ServiceRuntimeState,
     headers: &HashMap<String, String>,
-    intent: &str,
-    payload: &str,
-) -> ControlEnvelope {
+) -> OperatorContext {
+    if let Some(bound) = request_bearer_token(headers).and_then(|token| state.bound_operator(token))
+    {
+        return bound_service_operator(bound, headers);
+    }
     let has_bearer_token = request_bearer_token(headers).is_some();
     let operator_kind = if has_bearer_token {
-        OperatorKind::RemoteHuman
+        OperatorKind::Automation
     } else {
         OperatorKind::LocalHuman
     };
@@ -845,14 +1768,64 @@ fn build_service_control_envelope(
     } else {
         "local-service-operator"
     });
-    let operator = OperatorContext::new(operator_kind, operator_id, IngressTransport::ServiceHttp)
+    OperatorContext::new(operator_kind, operator_id, IngressTransport::ServiceHttp)
         .with_channel_id(request_header(headers, "x-ntk-channel-id").unwrap_or_default())
         .with_authentication(if has_bearer_token {
             "bearer_token"
         } else {
             "loopback_local"
         })
-        .with_scopes(parse_service_operator_scopes(headers));
+        .with_scopes(parse_service_operator_scopes(headers))
+}
+
+fn bound_service_operator(
+    bound: &ServiceOperatorToken,
+    headers: &HashMap<String, String>,
+) -> OperatorContext {
+    OperatorContext::new(
+        bound.operator_kind,
+        bound.operator_id.clone(),
+        IngressTransport::ServiceHttp,
+    )
+    .with_channel_id(request_header(headers, "x-ntk-channel-id").unwrap_or_default())
+    .with_authentication("operator_token")
+    .with_scopes(parse_service_operator_scopes(headers))
+}
+
+/// Approver identity for `/task/{id}/approve` and `/task/{id}/reject`.
+///
+/// Decisions never trust caller-supplied identity headers: with bearer auth
+/// enabled they require a per-operator token, and on an unauthenticated
+/// loopback bind every caller is the local service operator.
+fn service_decision_operator(
+    state: &ServiceRuntimeState,
+    headers: &HashMap<String, String>,
+) -> Result<OperatorContext, String> {
+    if let Some(bound) = request_bearer_token(headers).and_then(|token| state.bound_operator(token))
+    {
+        return Ok(bound_service_operator(bound, headers));
+    }
+    if state.requires_bearer_auth() {
+        return Err(format!(
+            "task decisions over HTTP require a per-operator token ({NTK_SERVICE_OPERATOR_TOKENS_ENV})"
+        ));
+    }
+    Ok(OperatorContext::new(
+        OperatorKind::LocalHuman,
+        "local-service-operator",
+        IngressTransport::ServiceHttp,
+    )
+    .with_authentication("loopback_local"))
+}
+
+fn build_service_control_envelope(
+    state: &ServiceRuntimeState,
+    request_context: &ServiceRequestContext,
+    headers: &HashMap<String, String>,
+    intent: &str,
+    payload: &str,
+) -> ControlEnvelope {
+    let operator = build_service_operator(state, headers);
     let session = SessionContext::new(
         SessionKind::ServiceRequest,
         request_header(headers, "x-ntk-session-id")
@@ -877,6 +1850,7 @@ fn build_service_control_envelope(
     )
     .with_correlation_id(request_context.correlation_id.clone().unwrap_or_default())
     .with_policy(build_service_control_policy())
+    .with_trace_context(service_trace_context(headers))
 }
 
 fn is_loopback_bind_host(host: &str) -> bool {
@@ -886,16 +1860,13 @@ fn is_loopback_bind_host(host: &str) -> bool {
     )
 }
 
-fn validate_service_bind_security(
-    host: &str,
-    service_auth_token: Option<&str>,
-) -> Result<(), String> {
-    if is_loopback_bind_host(host) || service_auth_token.is_some() {
+fn validate_service_bind_security(host: &str, bearer_auth_configured: bool) -> Result<(), String> {
+    if is_loopback_bind_host(host) || bearer_auth_configured {
         return Ok(());
     }
 
     Err(format!(
-        "non-loopback service bind `{host}` requires {NTK_SERVICE_AUTH_TOKEN_ENV} to be configured"
+        "non-loopback service bind `{host}` requires {NTK_SERVICE_AUTH_TOKEN_ENV} or {NTK_SERVICE_OPERATOR_TOKENS_ENV} to be configured"
     ))
 }
 
@@ -1089,8 +2060,8 @@ fn next_service_request_id() -> String {
 }
 
 fn service_http_timeout() -> std::time::Duration {
-    let millis = std::env::var(NTK_SERVICE_HTTP_TIMEOUT_MS_ENV)
-        .ok()
+    let millis = PolicySource::load()
+        .var(NTK_SERVICE_HTTP_TIMEOUT_MS_ENV)
         .and_then(|value| value.trim().parse::<u64>().ok())
         .map(|value| value.clamp(MIN_SERVICE_HTTP_TIMEOUT_MS, MAX_SERVICE_HTTP_TIMEOUT_MS))
         .unwrap_or(DEFAULT_SERVICE_HTTP_TIMEOUT_MS);
@@ -1112,7 +2083,20 @@ async fn service_request_context_middleware(mut request: Request, next: Next) ->
     let started_at = std::time::Instant::now();
     request.extensions_mut().insert(request_context.clone());
 
-    let mut response = next.run(request).await;
+    let request_span = tracing::info_span!(
+        "service.http_request",
+        request_id = %request_context.request_id,
+        method = %method,
+        path = %path,
+    );
+    let headers = normalize_http_headers(request.headers());
+    if let Some(trace_context) =
+        nettoolskit_otel::extract_trace_context(|name| request_header(&headers, name))
+    {
+        nettoolskit_otel::set_span_parent(&request_span, &trace_context);
+    }
+
+    let mut response = next.run(request).instrument(request_span).await;
     if let Ok(header_value) = HeaderValue::from_str(&request_context.request_id) {
         response.headers_mut().insert("x-request-id", header_value);
     }
@@ -1149,26 +2133,39 @@ async fn service_bearer_auth_middleware(
     request: Request,
     next: Next,
 ) -> Response {
-    let Some(expected_token) = state.service_auth_token.as_deref() else {
+    if !state.requires_bearer_auth() {
         return next.run(request).await;
-    };
+    }
 
     let headers = normalize_http_headers(request.headers());
-    if request_bearer_token(&headers) == Some(expected_token) {
+    if request_bearer_token(&headers).is_some_and(|token| state.accepts_bearer_token(token)) {
         next.run(request).await
     } else {
+        let path = request.uri().path().to_string();
         (
             StatusCode::UNAUTHORIZED,
-            "missing or invalid bearer token for /task/submit",
+            format!("missing or invalid bearer token for {path}"),
         )
             .into_response()
     }
 }
 
+async fn service_metrics_auth_middleware(
+    State(state): State<Arc<ServiceRuntimeState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if state.metrics_require_auth {
+        service_bearer_auth_middleware(State(state), request, next).await
+    } else {
+        next.run(request).await
+    }
+}
+
 async fn service_root() -> impl IntoResponse {
     (
         StatusCode::OK,
-        "NetToolsKit service mode is running.\nUse GET /health or GET /ready.",
+        "NetToolsKit service mode is running.\nUse GET /health, GET /ready or GET /metrics.",
     )
 }
 
@@ -1195,8 +2192,22 @@ async fn service_ready(State(state): State<Arc<ServiceRuntimeState>>) -> impl In
     (status, Json(payload))
 }
 
+async fn service_metrics() -> Response {
+    let metrics = nettoolskit_orchestrator::runtime_metrics();
+    let body = nettoolskit_otel::render_prometheus(metrics, &[("service", "ntk")]);
+    (
+        StatusCode::OK,
+        [(
+            axum::http::header::CONTENT_TYPE,
+            nettoolskit_otel::PROMETHEUS_CONTENT_TYPE,
+        )],
+        body,
+    )
+        .into_response()
+}
+
 async fn service_task_submit(
-    State(_state): State<Arc<ServiceRuntimeState>>,
+    State(state): State<Arc<ServiceRuntimeState>>,
     Extension(request_context): Extension<ServiceRequestContext>,
     headers: AxumHeaderMap,
     payload: Result<Json<ServiceTaskSubmitRequest>, axum::extract::rejection::JsonRejection>,
@@ -1225,6 +2236,7 @@ async fn service_task_submit(
 
     let normalized_headers = normalize_http_headers(&headers);
     let control_envelope = build_service_control_envelope(
+        &state,
         &request_context,
         &normalized_headers,
         payload.intent.as_str(),
@@ -1245,6 +2257,8 @@ async fn service_task_submit(
         accepted: submission.exit_status != ExitStatus::Error,
         exit_status: exit_status_label(submission.exit_status).to_string(),
         task_id: submission.task_id,
+        task_status: submission.task_status,
+        rejection_reason: submission.rejection_reason,
         request_id: control_envelope.request_id,
         correlation_id: control_envelope.correlation_id,
         operator_id: control_envelope.operator.id,
@@ -1261,6 +2275,104 @@ async fn service_task_submit(
     (status_code, Json(response_payload)).into_response()
 }
 
+async fn service_task_list() -> Response {
+    match headless::list_tasks(ConsoleMode::Silent).await {
+        Ok(tasks) => (StatusCode::OK, Json(tasks)).into_response(),
+        Err(failure) => (StatusCode::INTERNAL_SERVER_ERROR, failure.message).into_response(),
+    }
+}
+
+async fn service_task_get(Path(task_id): Path<String>) -> Response {
+    match headless::get_task(&task_id, ConsoleMode::Silent).await {
+        Ok(task) => (StatusCode::OK, Json(task)).into_response(),
+        Err(failure) => (StatusCode::NOT_FOUND, failure.message).into_response(),
+    }
+}
+
+async fn service_task_cancel(Path(task_id): Path<String>) -> Response {
+    if let Err(failure) = headless::get_task(&task_id, ConsoleMode::Silent).await {
+        return (StatusCode::NOT_FOUND, failure.message).into_response();
+    }
+    match headless::cancel_task_by_id(&task_id, ConsoleMode::Silent).await {
+        Ok(task) => (StatusCode::OK, Json(task)).into_response(),
+        Err(failure) => (StatusCode::CONFLICT, failure.message).into_response(),
+    }
+}
+
+async fn service_task_approve(
+    State(state): State<Arc<ServiceRuntimeState>>,
+    Path(task_id): Path<String>,
+    headers: AxumHeaderMap,
+    body: Bytes,
+) -> Response {
+    service_task_decision(&state, &task_id, &headers, &body, true).await
+}
+
+async fn service_task_reject(
+    State(state): State<Arc<ServiceRuntimeState>>,
+    Path(task_id): Path<String>,
+    headers: AxumHeaderMap,
+    body: Bytes,
+) -> Response {
+    service_task_decision(&state, &task_id, &headers, &body, false).await
+}
+
+async fn service_task_decision(
+    state: &ServiceRuntimeState,
+    task_id: &str,
+    headers: &AxumHeaderMap,
+    body: &[u8],
+    approve: bool,
+) -> Response {
+    // The body is optional; `{}` and an empty body both mean "no note".
+    let request = if body.iter().all(u8::is_ascii_whitespace) {
+        RemoteTaskDecisionRequest::default()
+    } else {
+        match serde_json::from_slice::<RemoteTaskDecisionRequest>(body) {
+            Ok(request) => request,
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    "invalid JSON payload for task decision",
+                )
+                    .into_response();
+            }
+        }
+    };
+    let task = match headless::get_task(task_id, ConsoleMode::Silent).await {
+        Ok(task) => task,
+        Err(failure) => return (StatusCode::NOT_FOUND, failure.message).into_response(),
+    };
+
+    let operator = match service_decision_operator(state, &normalize_http_headers(headers)) {
+        Ok(operator) => operator,
+        Err(reason) => return (StatusCode::FORBIDDEN, reason).into_response(),
+    };
+    if let Err(reason) = TaskApprovalPolicy::from_env().authorize_approver(&operator) {
+        return (StatusCode::FORBIDDEN, reason).into_response();
+    }
+    if task.operator.as_deref() == Some(operator.id.as_str()) {
+        return (
+            StatusCode::FORBIDDEN,
+            format!(
+                "operator `{}` submitted this task and cannot decide its own approval",
+                operator.id
+            ),
+        )
+            .into_response();
+    }
+    let note = request.note.as_deref();
+    let result = if approve {
+        headless::approve_task(task_id, note, Some(operator), ConsoleMode::Silent).await
+    } else {
+        headless::reject_task(task_id, note, Some(operator), ConsoleMode::Silent).await
+    };
+    match result {
+        Ok(task) => (StatusCode::OK, Json(task)).into_response(),
+        Err(failure) => (StatusCode::CONFLICT, failure.message).into_response(),
+    }
+}
+
 async fn service_telegram_webhook(
     State(state): State<Arc<ServiceRuntimeState>>,
     headers: AxumHeaderMap,
@@ -1304,7 +2416,7 @@ async fn service_telegram_webhook(
         }
     }
 
-    match runtime.enqueue_telegram_webhook_payload(body) {
+    match runtime.enqueue_telegram_webhook_payload(body, service_trace_context(&headers)) {
         Ok(queued) => (
             StatusCode::ACCEPTED,
             Json(ServiceTelegramWebhookResponse {
@@ -1364,7 +2476,7 @@ async fn service_discord_interactions(
         }
     }
 
-    match runtime.enqueue_discord_interaction_payload(body) {
+    match runtime.enqueue_discord_interaction_payload(body, service_trace_context(&headers)) {
         Ok(outcome) if outcome.ping => (
             StatusCode::OK,
             Json(ServiceDiscordInteractionResponse {
@@ -1400,6 +2512,13 @@ fn service_router(state: Arc<ServiceRuntimeState>) -> Router {
         .route("/", get(service_root))
         .route("/health", get(service_health))
         .route("/ready", get(service_ready))
+        .route(
+            "/metrics",
+            get(service_metrics).route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                service_metrics_auth_middleware,
+            )),
+        )
         .route(
             "/task/submit",
             post(service_task_submit).route_layer(middleware::from_fn_with_state(
@@ -1407,6 +2526,41 @@ fn service_router(state: Arc<ServiceRuntimeState>) -> Router {
                 service_bearer_auth_middleware,
             )),
         )
+        .route(
+            "/task/list",
+            get(service_task_list).route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                service_bearer_auth_middleware,
+            )),
+        )
+        .route(
+            "/task/{id}",
+            get(service_task_get).route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                service_bearer_auth_middleware,
+            )),
+        )
+        .route(
+            "/task/{id}/cancel",
+            post(service_task_cancel).route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                service_bearer_auth_middleware,
+            )),
+        )
+        .route(
+            "/task/{id}/approve",
+            post(service_task_approve).route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                service_bearer_auth_middleware,
+            )),
+        )
+        .route(
+            "/task/{id}/reject",
+            post(service_task_reject).route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                service_bearer_auth_middleware,
+            )),
+        )
         .route("/chatops/telegram/webhook", post(service_telegram_webhook))
         .route(
             "/chatops/discord/interactions",
@@ -1427,12 +2581,42 @@ fn service_router(state: Arc<ServiceRuntimeState>) -> Router {
 }
 
 async fn run_service_mode(host: String, port: u16) -> ExitStatus {
-    let service_auth_token = service_auth_token_from_env();
-    if let Err(error) = validate_service_bind_security(&host, service_auth_token.as_deref()) {
+    // The service owns its task registry; never forward its own task calls to another endpoint.
+    disable_remote_service_dispatch();
+    if let Err(error) = PolicySource::try_load() {
+        eprintln!("Refusing to start service mode: {error}");
+        return ExitStatus::Error;
+    }
+    let service_auth_token = match service_auth_token_from_env() {
+        Ok(token) => token,
+        Err(error) => {
+            eprintln!("Refusing to start service mode: {error}");
+            return ExitStatus::Error;
+        }
+    };
+    let operator_tokens = match service_operator_tokens_from_env() {
+        Ok(tokens) => tokens,
+        Err(error) => {
+            eprintln!("Refusing to start service mode: {error}");
+            return ExitStatus::Error;
+        }
+    };
+    if let Err(error) = validate_service_bind_security(
+        &host,
+        service_auth_token.is_some() || !operator_tokens.is_empty(),
+    ) {
         eprintln!("Refusing to start service mode: {error}");
         return ExitStatus::Error;
     }
 
+    let metrics_require_auth = match service_metrics_require_auth_from_env() {
+        Ok(value) => value,
+        Err(error) => {
+            eprintln!("Refusing to start service mode: {error}");
+            return ExitStatus::Error;
+        }
+    };
+
     let bind_addr = format!("{host}:{port}");
     let listener = match TcpListener::bind(&bind_addr).await {
         Ok(listener) => listener,
@@ -1446,8 +2630,19 @@ async fn run_service_mode(host: String, port: u16) -> ExitStatus {
     println!("Health endpoint: GET /health");
     println!("Readiness endpoint: GET /ready");
     println!("Task submit endpoint: POST /task/submit");
-    if service_auth_token.is_some() {
+    println!("Task query endpoints: GET /task/list, GET /task/{{id}}, POST /task/{{id}}/cancel");
+    println!("Metrics endpoint: GET /metrics (Prometheus text format)");
+    if service_auth_token.is_some() || !operator_tokens.is_empty() {
         println!("Task submit auth: bearer token enabled");
+        println!(
+            "Task decision auth: {} per-operator token(s)",
+            operator_tokens.len()
+        );
+        if metrics_require_auth {
+            println!("Metrics auth: bearer token enabled");
+        } else {
+            println!("Metrics auth: disabled ({NTK_SERVICE_METRICS_AUTH_ENV}=none)");
+        }
     } else {
         println!("Task submit auth: disabled (loopback-only bind)");
     }
@@ -1490,6 +2685,8 @@ async fn run_service_mode(host: String, port: u16) -> ExitStatus {
             ingress_security.replay_backend.clone(),
         )),
         service_auth_token,
+        operator_tokens,
+        metrics_require_auth,
         data_dir: AppConfig::default_data_dir(),
         ingress_security,
     });
@@ -1553,6 +2750,7 @@ fn spawn_chatops_runtime_loop(runtime: Arc<nettoolskit_orchestrator::ChatOpsRunt
             if summary.envelopes_received > 0
                 || summary.ingress_errors > 0
                 || summary.notification_errors > 0
+                || summary.task_notifications_sent > 0
             {
                 tracing::info!(
                     envelopes_received = summary.envelopes_received,
@@ -1561,6 +2759,7 @@ fn spawn_chatops_runtime_loop(runtime: Arc<nettoolskit_orchestrator::ChatOpsRunt
                     rate_limited = summary.rate_limited,
                     ingress_errors = summary.ingress_errors,
                     notification_errors = summary.notification_errors,
+                    task_notifications_sent = summary.task_notifications_sent,
                     "chatops runtime tick summary"
                 );
             }
@@ -1588,12 +2787,92 @@ struct Cli {
     pub subcommand: Option<Commands>,
 }
 
+/// Ask before honouring trust-gated keys of an untrusted `.ntk/config.toml`.
+///
+/// Without a terminal to ask on, the keys are ignored with a warning.
+fn confirm_project_trust() {
+    use std::io::{BufRead, IsTerminal, Write};
+
+    let layers = ConfigLayers::discover();
+    let Some(project) = layers.project.as_ref() else {
+        return;
+    };
+    let ignored = layers.ignored_project_keys();
+    if ignored.is_empty() {
+        return;
+    }
+
+    let path = project.path.display();
+    if !(std::io::stdin().is_terminal() && std::io::stderr().is_terminal()) {
+        eprintln!(
+            "Warning: ignoring {} from untrusted project config {path}; run `ntk` in a terminal to review it.",
+            ignored.join(", ")
+        );
+        return;
+    }
+
+    eprintln!("Project config {path} sets keys that run commands or reach the network:");
+    for key in &ignored {
+        eprintln!("  {key}");
+    }
+    eprint!("Trust this project config? [y/N] ");
+    let _ = std::io::stderr().flush();
+    let mut answer = String::new();
+    let _ = std::io::stdin().lock().read_line(&mut answer);
+    if !matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes") {
+        eprintln!("Ignoring those keys; the rest of the project config still applies.");
+        return;
+    }
+    if let Err(error) = TrustStore::load().trust(project) {
+        eprintln!("Warning: failed to record project trust: {error}");
+    }
+}
+
+/// Parse arguments, pointing scripts at the renamed manifest path flags.
+fn parse_cli() -> Cli {
+    Cli::try_parse().unwrap_or_else(|err| {
+        let args: Vec<String> = std::env::args().collect();
+        let renamed = (err.kind() == clap::error::ErrorKind::InvalidValue)
+            .then(|| renamed_manifest_output_flag(&args))
+            .flatten();
+        let _ = err.print();
+        if let Some(flag) = renamed {
+            eprintln!(
+                "\nnote: `ntk manifest` takes `{flag} <path>`; `--output` now selects the output format (text, json)"
+            );
+        }
+        std::process::exit(err.exit_code());
+    })
+}
+
+/// The flag that replaced `--output <path>` when `args` run a manifest subcommand with it.
+fn renamed_manifest_output_flag(args: &[String]) -> Option<&'static str> {
+    let manifest = args.iter().position(|arg| arg == "manifest")?;
+    let flag = match args.get(manifest + 1)?.as_str() {
+        "render" | "apply" => "--output-dir",
+        "import" => "--output-file",
+        _ => return None,
+    };
+    let value = args.iter().enumerate().find_map(|(index, arg)| {
+        if arg == "--output" {
+            args.get(index + 1).map(String::as_str)
+        } else {
+            arg.strip_prefix("--output=")
+        }
+    })?;
+    OutputFormat::from_str(value, true).is_err().then_some(flag)
+}
+
 #[tokio::main(flavor = "multi_thread", worker_threads = 4)]
 async fn main() {
     // Parse command line arguments
-    let cli = Cli::parse();
+    let cli = parse_cli();
+
+    if cli.global.config.is_none() {
+        confirm_project_trust();
+    }
 
-    // Load configuration (file → env → defaults), then apply CLI overrides
+    // Load configuration (file → project → env → defaults), then apply CLI overrides
     let config = match &cli.global.config {
         Some(path) => {
             let p = std::path::Path::new(path);
@@ -1631,6 +2910,13 @@ async fn main() {
         UnicodeMode::Auto => {} // capabilities auto-detect
     }
 
+    if let Some(endpoint) = &cli.global.remote {
+        if let Err(error) = set_remote_service_endpoint(endpoint) {
+            eprintln!("Invalid --remote endpoint `{endpoint}`: {error}");
+            std::process::exit(ExitStatus::Error.into());
+        }
+    }
+
     let run_interactive = cli.subcommand.is_none();
 
     if !run_interactive {
@@ -1657,7 +2943,11 @@ async fn main() {
                 "Starting non-interactive CLI execution"
             );
 
-            let status = command.execute().await;
+            if cli.global.output == OutputFormat::Json {
+                // Footer logs fall back to stdout outside the interactive layout.
+                nettoolskit_ui::set_footer_output_enabled(false);
+            }
+            let status = command.execute(cli.global.output).await;
             info!(
                 correlation_id = %execution_correlation_id,
                 final_status = ?status,
@@ -1691,11 +2981,199 @@ mod tests {
     use super::*;
     use axum::body::to_bytes;
     use ed25519_dalek::{Signer, SigningKey};
-    use nettoolskit_orchestrator::{build_chatops_runtime, ChatOpsRuntime, ChatOpsRuntimeConfig};
+    use nettoolskit_orchestrator::{
+        build_chatops_runtime, ChatOpsRuntime, ChatOpsRuntimeConfig, OutputTone,
+    };
     use serde::de::DeserializeOwned;
     use serial_test::serial;
     use std::sync::{Mutex, OnceLock};
 
+    #[test]
+    fn cli_accepts_global_output_flag_after_subcommand() {
+        let cli = Cli::try_parse_from(["ntk", "task", "list", "--output", "json"])
+            .expect("global --output should parse after the subcommand");
+        assert_eq!(cli.global.output, OutputFormat::Json);
+        assert!(matches!(
+            cli.subcommand,
+            Some(Commands::Task {
+                command: TaskCommand::List
+            })
+        ));
+    }
+
+    #[test]
+    fn manifest_path_flags_do_not_shadow_global_output_flag() {
+        let cli = Cli::try_parse_from([
+            "ntk",
+            "manifest",
+            "import",
+            "src",
+            "--output-file",
+            "draft.yml",
+            "--output",
+            "json",
+        ])
+        .expect("import --output-file should parse alongside global --output");
+        assert_eq!(cli.global.output, OutputFormat::Json);
+        let Some(Commands::Manifest {
+            command: Some(ManifestCommand::Import { output_file, .. }),
+        }) = cli.subcommand
+        else {
+            panic!("expected manifest import");
+        };
+        assert_eq!(output_file, Some(std::path::PathBuf::from("draft.yml")));
+
+        let cli = Cli::try_parse_from(["ntk", "manifest", "apply", "m.yml", "--output-dir", "out"])
+            .expect("apply --output-dir should parse");
+        assert!(matches!(
+            cli.subcommand,
+            Some(Commands::Manifest {
+                command: Some(ManifestCommand::Apply {
+                    output_dir: Some(_),
+                    ..
+                }),
+            })
+        ));
+    }
+
+    #[test]
+    fn manifest_output_path_points_at_renamed_flag() {
+        let args = |line: &str| line.split(' ').map(str::to_string).collect::<Vec<_>>();
+
+        assert!(Cli::try_parse_from(args("ntk manifest apply m.yml --output out")).is_err());
+        assert_eq!(
+            renamed_manifest_output_flag(&args("ntk manifest apply m.yml --output out")),
+            Some("--output-dir")
+        );
+        assert_eq!(
+            renamed_manifest_output_flag(&args("ntk manifest import src --output=draft.yml")),
+            Some("--output-file")
+        );
+        assert_eq!(
+            renamed_manifest_output_flag(&args("ntk manifest render m.yml --output json")),
+            None
+        );
+        assert_eq!(
+            renamed_manifest_output_flag(&args("ntk task list --output out")),
+            None
+        );
+    }
+
+    #[test]
+    fn secrets_command_round_trips_through_file_store() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = SecretStore::new(dir.path(), nettoolskit_core::SecretBackendMode::File);
+        let cli = Cli::try_parse_from(["ntk", "secrets", "set", "telegram", "123:abc-token"])
+            .expect("secrets set should parse");
+        let Some(Commands::Secrets { command }) = cli.subcommand else {
+            panic!("expected secrets command");
+        };
+
+        let Ok(SecretsReport::Stored {
+            name,
+            backend,
+            reference,
+        }) = run_secrets_command(&store, command)
+        else {
+            panic!("expected stored report");
+        };
+        assert_eq!(name, "nettoolskit/telegram");
+        assert_eq!(backend, SecretBackend::File);
+        assert_eq!(reference, "keyring:nettoolskit/telegram");
+
+        let report = run_secrets_command(
+            &store,
+            SecretsCommand::Get {
+                name: "telegram".to_string(),
+            },
+        );
+        assert!(
+            matches!(report, Ok(SecretsReport::Value { value, .. }) if value == "123:abc-token")
+        );
+
+        let removed = run_secrets_command(
+            &store,
+            SecretsCommand::Rm {
+                name: "telegram".to_string(),
+            },
+        );
+        assert!(matches!(removed, Ok(SecretsReport::Removed { .. })));
+        assert!(run_secrets_command(&store, SecretsCommand::List).is_ok_and(
+            |report| matches!(report, SecretsReport::Listed { secrets } if secrets.is_empty())
+        ));
+        assert!(run_secrets_command(
+            &store,
+            SecretsCommand::Get {
+                name: "telegram".to_string(),
+            },
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn cli_joins_ai_prompt_words_and_flags() {
+        let cli = Cli::try_parse_from(["ntk", "ai", "apply", "--dry-run", "rename", "crate"])
+            .expect("ai apply should parse");
+        let Some(Commands::Ai {
+            command:
+                AiCommand::Apply {
+                    dry_run,
+                    approve_write,
+                    instruction,
+                },
+        }) = cli.subcommand
+        else {
+            panic!("expected ai apply");
+        };
+        assert!(dry_run);
+        assert!(!approve_write);
+        assert_eq!(instruction.join(" "), "rename crate");
+        assert_eq!(cli.global.output, OutputFormat::Text);
+    }
+
+    #[test]
+    fn cli_json_document_maps_failures_to_error_exit_code() {
+        let document = CliJsonDocument::<()>::new(
+            "config.get",
+            Err(CommandFailure {
+                message: "boom".to_string(),
+                hints: Vec::new(),
+            }),
+            |_| ExitStatus::Success,
+        );
+        assert_eq!(document.exit_code, 1);
+        assert_eq!(document.exit_status(), ExitStatus::Error);
+
+        let json = serde_json::to_value(&document).expect("serialize");
+        assert_eq!(json["status"], "error");
+        assert_eq!(json["error"]["message"], "boom");
+        assert!(json.get("data").is_none());
+    }
+
+    #[test]
+    fn command_output_result_prefers_error_blocks_and_keeps_other_failures_as_data() {
+        let failed = CommandOutput::new()
+            .heading("✅ Validating Manifest", OutputTone::Info)
+            .error("Manifest validation failed: missing file", Vec::new());
+        let failure = command_output_result(ExitStatus::Error, failed)
+            .expect_err("error blocks map to the document error");
+        assert_eq!(failure.message, "Manifest validation failed: missing file");
+
+        let invalid =
+            CommandOutput::new().text("✗ m.yaml has validation errors", OutputTone::Error);
+        let data = command_output_result(ExitStatus::Error, invalid.clone())
+            .expect("failures without an error block keep their data");
+        assert_eq!(data, invalid);
+
+        let supported =
+            Cli::try_parse_from(["ntk", "manifest", "check", "m.yaml", "--output", "json"])
+                .ok()
+                .and_then(|cli| cli.subcommand)
+                .expect("manifest check should parse");
+        assert!(supported.supports_json_output());
+        assert_eq!(supported.json_command_name(), "manifest.check");
+    }
+
     #[test]
     fn parse_http_request_line_extracts_method_and_path() {
         let raw = "GET /health HTTP/1.1\r\nHost: localhost\r\n\r\n";
@@ -1773,11 +3251,25 @@ mod tests {
                 ingress_security.replay_backend.clone(),
             )),
             service_auth_token: service_auth_token.map(ToOwned::to_owned),
+            operator_tokens: Vec::new(),
+            metrics_require_auth: true,
             data_dir,
             ingress_security,
         })
     }
 
+    fn test_service_state_with_operator_tokens(spec: &str) -> Arc<ServiceRuntimeState> {
+        let mut state = Arc::unwrap_or_clone(test_service_state_with_security(
+            disabled_chatops_runtime(),
+            default_test_ingress_security(),
+            Some("shared-token"),
+            Some(unique_test_path("service-operator-tokens")),
+        ));
+        state.operator_tokens =
+            parse_service_operator_tokens(spec).expect("operator tokens should parse");
+        Arc::new(state)
+    }
+
     fn test_service_state(
         chatops_runtime: Option<Arc<ChatOpsRuntime>>,
     ) -> Arc<ServiceRuntimeState> {
@@ -1997,14 +3489,14 @@ mod tests {
 
     #[test]
     fn validate_service_bind_security_rejects_remote_bind_without_token() {
-        let error = validate_service_bind_security("0.0.0.0", None)
+        let error = validate_service_bind_security("0.0.0.0", false)
             .expect_err("non-loopback bind should require token");
         assert!(error.contains(NTK_SERVICE_AUTH_TOKEN_ENV));
     }
 
     #[test]
     fn validate_service_bind_security_allows_remote_bind_with_token() {
-        validate_service_bind_security("0.0.0.0", Some("service-token"))
+        validate_service_bind_security("0.0.0.0", true)
             .expect("remote bind should be allowed when token is configured");
     }
 
@@ -2154,6 +3646,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_service_control_envelope_captures_w3c_trace_context() {
+        let request_context = ServiceRequestContext {
+            request_id: "req-trace".to_string(),
+            correlation_id: None,
+        };
+        let headers = HashMap::from([
+            (
+                "traceparent".to_string(),
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_string(),
+            ),
+            ("tracestate".to_string(), "vendor=abc".to_string()),
+        ]);
+
+        let state = test_service_state(None);
+        let envelope = build_service_control_envelope(
+            &state,
+            &request_context,
+            &headers,
+            "ai-plan",
+            "trace me",
+        );
+        let trace = envelope
+            .trace_context
+            .expect("trace context should be captured");
+        assert_eq!(trace.trace_id(), "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(trace.tracestate.as_deref(), Some("vendor=abc"));
+
+        let headers = HashMap::from([("traceparent".to_string(), "garbage".to_string())]);
+        let envelope = build_service_control_envelope(
+            &state,
+            &request_context,
+            &headers,
+            "ai-plan",
+            "trace me",
+        );
+        assert_eq!(envelope.trace_context, None);
+    }
+
     #[tokio::test]
     async fn service_router_enforces_default_body_limit() {
         let oversized_payload = format!(
@@ -2374,131 +3905,504 @@ mod tests {
             response.starts_with("HTTP/1.1 202 Accepted"),
             "valid submit should be accepted"
         );
-        assert!(
-            response.contains("\"accepted\":true"),
-            "accepted response should be true"
+        assert!(
+            response.contains("\"accepted\":true"),
+            "accepted response should be true"
+        );
+    }
+
+    #[tokio::test]
+    async fn service_mode_task_submit_rejects_missing_bearer_token_when_required() {
+        let request = concat!(
+            "POST /task/submit HTTP/1.1\r\n",
+            "Host: localhost\r\n",
+            "Content-Type: application/json\r\n",
+            "\r\n",
+            "{\"intent\":\"ai-plan\",\"payload\":\"validate dual runtime gate\"}"
+        );
+        let response = execute_service_request_with_state(
+            request,
+            test_service_state_with_security(
+                disabled_chatops_runtime(),
+                default_test_ingress_security(),
+                Some("expected-token"),
+                Some(unique_test_path("service-auth")),
+            ),
+        )
+        .await;
+        assert!(response.starts_with("HTTP/1.1 401 Unauthorized"));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn service_mode_task_submit_accepts_valid_bearer_token_when_required() {
+        let _guard = EnvVarGuard::set(&[("NTK_TOOL_SCOPE_ALLOWED_TOOLS", Some("ai.plan"))]);
+        let request = concat!(
+            "POST /task/submit HTTP/1.1\r\n",
+            "Host: localhost\r\n",
+            "Content-Type: application/json\r\n",
+            "Authorization: Bearer expected-token\r\n",
+            "\r\n",
+            "{\"intent\":\"ai-plan\",\"payload\":\"validate dual runtime gate\"}"
+        );
+        let response = execute_service_request_with_state(
+            request,
+            test_service_state_with_security(
+                disabled_chatops_runtime(),
+                default_test_ingress_security(),
+                Some("expected-token"),
+                Some(unique_test_path("service-auth-valid")),
+            ),
+        )
+        .await;
+        assert!(response.starts_with("HTTP/1.1 202 Accepted"));
+    }
+
+    #[tokio::test]
+    async fn service_mode_metrics_endpoint_returns_prometheus_text() {
+        nettoolskit_orchestrator::runtime_metrics().increment_counter("runtime_commands_total");
+        let request = concat!("GET /metrics HTTP/1.1\r\n", "Host: localhost\r\n", "\r\n");
+        let response = execute_service_request_with_state(request, test_service_state(None)).await;
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("# TYPE ntk_runtime_commands_total counter"));
+        assert!(response.contains("ntk_runtime_commands_total{service=\"ntk\"}"));
+    }
+
+    #[tokio::test]
+    async fn service_mode_metrics_endpoint_requires_bearer_token_when_configured() {
+        let unauthenticated = concat!("GET /metrics HTTP/1.1\r\n", "Host: localhost\r\n", "\r\n");
+        let authenticated = concat!(
+            "GET /metrics HTTP/1.1\r\n",
+            "Host: localhost\r\n",
+            "Authorization: Bearer expected-token\r\n",
+            "\r\n"
+        );
+        let state = test_service_state_with_security(
+            disabled_chatops_runtime(),
+            default_test_ingress_security(),
+            Some("expected-token"),
+            Some(unique_test_path("service-metrics-auth")),
+        );
+
+        let rejected = execute_service_request_with_state(unauthenticated, state.clone()).await;
+        assert!(rejected.starts_with("HTTP/1.1 401 Unauthorized"));
+        assert!(rejected.contains("missing or invalid bearer token for /metrics"));
+
+        let accepted = execute_service_request_with_state(authenticated, state).await;
+        assert!(accepted.starts_with("HTTP/1.1 200 OK"));
+    }
+
+    #[tokio::test]
+    async fn service_mode_metrics_endpoint_allows_anonymous_scrape_when_auth_disabled() {
+        let request = concat!("GET /metrics HTTP/1.1\r\n", "Host: localhost\r\n", "\r\n");
+        let state = test_service_state_with_security(
+            disabled_chatops_runtime(),
+            default_test_ingress_security(),
+            Some("expected-token"),
+            Some(unique_test_path("service-metrics-public")),
+        );
+        let mut state = Arc::try_unwrap(state).unwrap_or_else(|_| panic!("state should be unique"));
+        state.metrics_require_auth = false;
+
+        let response = execute_service_request_with_state(request, Arc::new(state)).await;
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+    }
+
+    #[test]
+    #[serial]
+    fn service_metrics_require_auth_from_env_parses_supported_values() {
+        let _guard = env_lock().lock().expect("env lock should not be poisoned");
+        {
+            let _env = EnvVarGuard::set(&[(NTK_SERVICE_METRICS_AUTH_ENV, None)]);
+            assert_eq!(service_metrics_require_auth_from_env(), Ok(true));
+        }
+        {
+            let _env = EnvVarGuard::set(&[(NTK_SERVICE_METRICS_AUTH_ENV, Some("none"))]);
+            assert_eq!(service_metrics_require_auth_from_env(), Ok(false));
+        }
+        {
+            let _env = EnvVarGuard::set(&[(NTK_SERVICE_METRICS_AUTH_ENV, Some("sometimes"))]);
+            assert!(service_metrics_require_auth_from_env().is_err());
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn service_mode_task_submit_returns_control_plane_metadata() {
+        let _guard = EnvVarGuard::set(&[("NTK_TOOL_SCOPE_ALLOWED_TOOLS", Some("ai.plan"))]);
+        let response = execute_service_request_direct(
+            Request::builder()
+                .method("POST")
+                .uri("/task/submit")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    r#"{"intent":"ai-plan","payload":"validate service envelope"}"#,
+                ))
+                .expect("request should build"),
+            test_service_state(None),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        let payload: ServiceTaskSubmitResponse = parse_response_json(response).await;
+        assert!(payload.accepted);
+        assert!(payload
+            .task_id
+            .as_deref()
+            .is_some_and(|task_id| task_id.starts_with("task-")));
+        assert!(payload.request_id.starts_with("req-"));
+        assert_eq!(payload.correlation_id, None);
+        assert_eq!(payload.operator_id, "local-service-operator");
+        assert_eq!(payload.operator_kind, "local_human");
+        assert_eq!(
+            payload.session_id,
+            format!("service-request-{}", payload.request_id)
+        );
+        assert_eq!(payload.transport, "service_http");
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn service_mode_task_submit_honors_supplied_operator_session_and_correlation_headers() {
+        let _guard = EnvVarGuard::set(&[("NTK_TOOL_SCOPE_ALLOWED_TOOLS", Some("ai.plan"))]);
+        let response = execute_service_request_direct(
+            Request::builder()
+                .method("POST")
+                .uri("/task/submit")
+                .header("content-type", "application/json")
+                .header("authorization", "Bearer expected-token")
+                .header("x-request-id", "req-user-123")
+                .header("x-correlation-id", "corr-user-456")
+                .header("x-ntk-operator-id", "remote-dev")
+                .header("x-ntk-session-id", "session-user-789")
+                .body(Body::from(
+                    r#"{"intent":"ai-plan","payload":"review enterprise gaps"}"#,
+                ))
+                .expect("request should build"),
+            test_service_state_with_security(
+                disabled_chatops_runtime(),
+                default_test_ingress_security(),
+                Some("expected-token"),
+                Some(unique_test_path("service-envelope-auth")),
+            ),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        let payload: ServiceTaskSubmitResponse = parse_response_json(response).await;
+        assert!(payload
+            .task_id
+            .as_deref()
+            .is_some_and(|task_id| task_id.starts_with("task-")));
+        assert_eq!(payload.request_id, "req-user-123");
+        assert_eq!(payload.correlation_id.as_deref(), Some("corr-user-456"));
+        assert_eq!(payload.operator_id, "remote-dev");
+        assert_eq!(payload.operator_kind, "automation");
+        assert_eq!(payload.session_id, "session-user-789");
+        assert_eq!(payload.transport, "service_http");
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn service_mode_task_query_routes_return_submitted_task() {
+        let _guard = EnvVarGuard::set(&[("NTK_TOOL_SCOPE_ALLOWED_TOOLS", Some("ai.plan"))]);
+        let state = test_service_state(None);
+        let response = execute_service_request_direct(
+            Request::builder()
+                .method("POST")
+                .uri("/task/submit")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    r#"{"intent":"ai-plan","payload":"remote query"}"#,
+                ))
+                .expect("request should build"),
+            state.clone(),
+        )
+        .await;
+        let submitted: ServiceTaskSubmitResponse = parse_response_json(response).await;
+        let task_id = submitted.task_id.expect("task should be admitted");
+        assert!(submitted.task_status.is_some());
+
+        let response = execute_service_request_direct(
+            Request::builder()
+                .uri(format!("/task/{task_id}"))
+                .body(Body::empty())
+                .expect("request should build"),
+            state.clone(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let task: TaskSnapshot = parse_response_json(response).await;
+        assert_eq!(task.id, task_id);
+        assert_eq!(task.runtime_mode, RuntimeMode::Service);
+        assert!(!task.audit_events.is_empty());
+
+        let response = execute_service_request_direct(
+            Request::builder()
+                .uri("/task/list")
+                .body(Body::empty())
+                .expect("request should build"),
+            state,
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let tasks: Vec<TaskSnapshot> = parse_response_json(response).await;
+        assert!(tasks.iter().any(|task| task.id == task_id));
+    }
+
+    #[tokio::test]
+    async fn service_mode_task_query_routes_report_unknown_tasks() {
+        let response = execute_service_request_direct(
+            Request::builder()
+                .uri("/task/task-missing")
+                .body(Body::empty())
+                .expect("request should build"),
+            test_service_state(None),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = execute_service_request_direct(
+            Request::builder()
+                .method("POST")
+                .uri("/task/task-missing/cancel")
+                .body(Body::empty())
+                .expect("request should build"),
+            test_service_state(None),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn service_mode_task_decision_routes_enforce_approver_policy() {
+        let state = test_service_state_with_operator_tokens(
+            "ops-bot:automation=bot-token,ops-lead=lead-token",
+        );
+        let response = execute_service_request_direct(
+            Request::builder()
+                .method("POST")
+                .uri("/task/task-missing/approve")
+                .header("authorization", "Bearer lead-token")
+                .body(Body::empty())
+                .expect("request should build"),
+            state.clone(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let parked = headless::submit_task(
+            "repo-workflow",
+            "repo=https://example.invalid/acme/app.git;branch=main;command=cargo test;push=true;dry_run=false",
+            ConsoleMode::Silent,
+        )
+        .await
+        .expect("gated task should be admitted");
+        assert_eq!(parked.status, TaskExecutionStatus::AwaitingApproval);
+
+        let response = execute_service_request_direct(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/task/{}/approve", parked.id))
+                .header("authorization", "Bearer bot-token")
+                .header("x-ntk-operator-kind", "human")
+                .body(Body::empty())
+                .expect("request should build"),
+            state.clone(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = execute_service_request_direct(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/task/{}/reject", parked.id))
+                .header("content-type", "application/json")
+                .header("authorization", "Bearer lead-token")
+                .header("x-ntk-operator-id", "someone-else")
+                .body(Body::from(r#"{"note":"not during freeze"}"#))
+                .expect("request should build"),
+            state,
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let task: TaskSnapshot = parse_response_json(response).await;
+        assert_eq!(task.status, TaskExecutionStatus::Cancelled);
+        assert_eq!(task.detail, "Rejected by ops-lead: not during freeze");
+    }
+
+    #[test]
+    fn service_operator_kind_ignores_caller_supplied_header() {
+        let state = test_service_state_with_operator_tokens("ops-lead=lead-token");
+        let headers = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect::<HashMap<_, _>>()
+        };
+
+        let unbound = build_service_operator(
+            &state,
+            &headers(&[
+                ("authorization", "Bearer shared-token"),
+                ("x-ntk-operator-kind", "human"),
+            ]),
+        );
+        assert_eq!(unbound.kind, OperatorKind::Automation);
+
+        let bound = build_service_operator(
+            &state,
+            &headers(&[
+                ("authorization", "Bearer lead-token"),
+                ("x-ntk-operator-kind", "automation"),
+            ]),
+        );
+        assert_eq!(bound.kind, OperatorKind::RemoteHuman);
+        assert_eq!(bound.id, "ops-lead");
+
+        let local = build_service_operator(
+            &test_service_state(None),
+            &headers(&[("x-ntk-operator-kind", "automation")]),
         );
+        assert_eq!(local.kind, OperatorKind::LocalHuman);
     }
 
     #[tokio::test]
-    async fn service_mode_task_submit_rejects_missing_bearer_token_when_required() {
-        let request = concat!(
-            "POST /task/submit HTTP/1.1\r\n",
-            "Host: localhost\r\n",
-            "Content-Type: application/json\r\n",
-            "\r\n",
-            "{\"intent\":\"ai-plan\",\"payload\":\"validate dual runtime gate\"}"
-        );
-        let response = execute_service_request_with_state(
-            request,
-            test_service_state_with_security(
-                disabled_chatops_runtime(),
-                default_test_ingress_security(),
-                Some("expected-token"),
-                Some(unique_test_path("service-auth")),
-            ),
+    async fn service_mode_task_decision_routes_refuse_shared_token_and_identity_headers() {
+        let parked = headless::submit_task(
+            "repo-workflow",
+            "repo=https://example.invalid/acme/app.git;branch=main;command=cargo test;push=true;dry_run=false",
+            ConsoleMode::Silent,
+        )
+        .await
+        .expect("gated task should be admitted");
+        assert_eq!(parked.status, TaskExecutionStatus::AwaitingApproval);
+
+        let response = execute_service_request_direct(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/task/{}/approve", parked.id))
+                .header("authorization", "Bearer shared-token")
+                .header("x-ntk-operator-id", "ops-lead")
+                .body(Body::empty())
+                .expect("request should build"),
+            test_service_state_with_operator_tokens("ops-lead=lead-token"),
         )
         .await;
-        assert!(response.starts_with("HTTP/1.1 401 Unauthorized"));
-    }
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = String::from_utf8(
+            axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("body should read")
+                .to_vec(),
+        )
+        .expect("body should be UTF-8");
+        assert!(body.contains(NTK_SERVICE_OPERATOR_TOKENS_ENV));
 
-    #[tokio::test]
-    #[serial]
-    async fn service_mode_task_submit_accepts_valid_bearer_token_when_required() {
-        let _guard = EnvVarGuard::set(&[("NTK_TOOL_SCOPE_ALLOWED_TOOLS", Some("ai.plan"))]);
-        let request = concat!(
-            "POST /task/submit HTTP/1.1\r\n",
-            "Host: localhost\r\n",
-            "Content-Type: application/json\r\n",
-            "Authorization: Bearer expected-token\r\n",
-            "\r\n",
-            "{\"intent\":\"ai-plan\",\"payload\":\"validate dual runtime gate\"}"
-        );
-        let response = execute_service_request_with_state(
-            request,
-            test_service_state_with_security(
-                disabled_chatops_runtime(),
-                default_test_ingress_security(),
-                Some("expected-token"),
-                Some(unique_test_path("service-auth-valid")),
-            ),
+        // Without bearer auth the caller is the local operator whatever the headers say.
+        let response = execute_service_request_direct(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/task/{}/reject", parked.id))
+                .header("x-ntk-operator-id", "ops-lead")
+                .header("x-ntk-operator-kind", "automation")
+                .body(Body::empty())
+                .expect("request should build"),
+            test_service_state(None),
         )
         .await;
-        assert!(response.starts_with("HTTP/1.1 202 Accepted"));
+        assert_eq!(response.status(), StatusCode::OK);
+        let task: TaskSnapshot = parse_response_json(response).await;
+        assert!(task
+            .detail
+            .starts_with("Rejected by local-service-operator"));
     }
 
     #[tokio::test]
     #[serial]
-    async fn service_mode_task_submit_returns_control_plane_metadata() {
-        let _guard = EnvVarGuard::set(&[("NTK_TOOL_SCOPE_ALLOWED_TOOLS", Some("ai.plan"))]);
+    async fn service_mode_task_decision_routes_refuse_self_approval() {
+        let _guard = EnvVarGuard::set(&[
+            (
+                "NTK_TOOL_SCOPE_ALLOWED_TOOLS",
+                Some("repo.workflow.execute,repo.workflow.push"),
+            ),
+            ("NTK_SERVICE_ALLOWED_INTENTS", Some("repo-workflow")),
+        ]);
+        let state =
+            test_service_state_with_operator_tokens("ops-lead=lead-token,ops-peer=peer-token");
         let response = execute_service_request_direct(
             Request::builder()
                 .method("POST")
                 .uri("/task/submit")
                 .header("content-type", "application/json")
+                .header("authorization", "Bearer lead-token")
                 .body(Body::from(
-                    r#"{"intent":"ai-plan","payload":"validate service envelope"}"#,
+                    r#"{"intent":"repo-workflow","payload":"repo=https://example.invalid/acme/app.git;branch=main;command=cargo test;push=true;dry_run=false"}"#,
                 ))
                 .expect("request should build"),
-            test_service_state(None),
+            state.clone(),
         )
         .await;
         assert_eq!(response.status(), StatusCode::ACCEPTED);
-        let payload: ServiceTaskSubmitResponse = parse_response_json(response).await;
-        assert!(payload.accepted);
-        assert!(payload
-            .task_id
-            .as_deref()
-            .is_some_and(|task_id| task_id.starts_with("task-")));
-        assert!(payload.request_id.starts_with("req-"));
-        assert_eq!(payload.correlation_id, None);
-        assert_eq!(payload.operator_id, "local-service-operator");
-        assert_eq!(payload.operator_kind, "local_human");
-        assert_eq!(
-            payload.session_id,
-            format!("service-request-{}", payload.request_id)
-        );
-        assert_eq!(payload.transport, "service_http");
+        let submitted: ServiceTaskSubmitResponse = parse_response_json(response).await;
+        assert_eq!(submitted.operator_id, "ops-lead");
+        let task_id = submitted.task_id.expect("task should be admitted");
+
+        let response = execute_service_request_direct(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/task/{task_id}/approve"))
+                .header("authorization", "Bearer lead-token")
+                .body(Body::empty())
+                .expect("request should build"),
+            state.clone(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = execute_service_request_direct(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/task/{task_id}/reject"))
+                .header("authorization", "Bearer peer-token")
+                .body(Body::empty())
+                .expect("request should build"),
+            state,
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let task: TaskSnapshot = parse_response_json(response).await;
+        assert!(task.detail.starts_with("Rejected by ops-peer"));
+    }
+
+    #[test]
+    fn parse_service_operator_tokens_binds_identity_and_rejects_bad_entries() {
+        let tokens = parse_service_operator_tokens("ops-lead=t1, deploy-bot:automation=t2")
+            .expect("tokens should parse");
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].operator_id, "ops-lead");
+        assert_eq!(tokens[0].operator_kind, OperatorKind::RemoteHuman);
+        assert_eq!(tokens[1].operator_kind, OperatorKind::Automation);
+
+        assert!(parse_service_operator_tokens("ops-lead").is_err());
+        assert!(parse_service_operator_tokens("ops-lead:robot=t1").is_err());
+        assert!(parse_service_operator_tokens("a=t1,b=t1").is_err());
     }
 
     #[tokio::test]
-    #[serial]
-    async fn service_mode_task_submit_honors_supplied_operator_session_and_correlation_headers() {
-        let _guard = EnvVarGuard::set(&[("NTK_TOOL_SCOPE_ALLOWED_TOOLS", Some("ai.plan"))]);
+    async fn service_mode_task_query_routes_require_bearer_token() {
         let response = execute_service_request_direct(
             Request::builder()
-                .method("POST")
-                .uri("/task/submit")
-                .header("content-type", "application/json")
-                .header("authorization", "Bearer expected-token")
-                .header("x-request-id", "req-user-123")
-                .header("x-correlation-id", "corr-user-456")
-                .header("x-ntk-operator-id", "remote-dev")
-                .header("x-ntk-session-id", "session-user-789")
-                .body(Body::from(
-                    r#"{"intent":"ai-plan","payload":"review enterprise gaps"}"#,
-                ))
+                .uri("/task/list")
+                .body(Body::empty())
                 .expect("request should build"),
             test_service_state_with_security(
                 disabled_chatops_runtime(),
                 default_test_ingress_security(),
                 Some("expected-token"),
-                Some(unique_test_path("service-envelope-auth")),
+                Some(unique_test_path("task-list-auth")),
             ),
         )
         .await;
-        assert_eq!(response.status(), StatusCode::ACCEPTED);
-        let payload: ServiceTaskSubmitResponse = parse_response_json(response).await;
-        assert!(payload
-            .task_id
-            .as_deref()
-            .is_some_and(|task_id| task_id.starts_with("task-")));
-        assert_eq!(payload.request_id, "req-user-123");
-        assert_eq!(payload.correlation_id.as_deref(), Some("corr-user-456"));
-        assert_eq!(payload.operator_id, "remote-dev");
-        assert_eq!(payload.operator_kind, "remote_human");
-        assert_eq!(payload.session_id, "session-user-789");
-        assert_eq!(payload.transport, "service_http");
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
 
     #[tokio::test]
@@ -2812,6 +4716,209 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn service_mode_discord_interactions_accepts_button_click_once() {
+        let (runtime, config) = test_discord_interactions_runtime(true);
+        let signing_key = SigningKey::from_bytes(&[13_u8; 32]);
+        let state = test_service_state_with_security(
+            initialized_chatops_runtime(Some(runtime), config, None),
+            Arc::new(ServiceIngressSecurityConfig {
+                telegram_secret_token: None,
+                discord_verifying_key: Some(signing_key.verifying_key()),
+                replay_window: std::time::Duration::from_secs(300),
+                replay_max_entries: 1_024,
+                replay_backend: IngressReplayBackendConfig::Memory,
+            }),
+            None,
+            Some(unique_test_path("discord-component")),
+        );
+        let body = "{\"id\":\"901\",\"type\":3,\"channel_id\":\"555\",\"member\":{\"user\":{\"id\":\"777\"}},\"data\":{\"component_type\":2,\"custom_id\":\"ntk:approve:task-42\"}}";
+        let signed_request = |timestamp: u64| {
+            let timestamp = timestamp.to_string();
+            let mut message = timestamp.as_bytes().to_vec();
+            message.extend_from_slice(body.as_bytes());
+            let signature_hex = hex::encode(signing_key.sign(&message).to_bytes());
+            format!(
+                "POST /chatops/discord/interactions HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nX-Signature-Ed25519: {signature_hex}\r\nX-Signature-Timestamp: {timestamp}\r\n\r\n{body}"
+            )
+        };
+        let now = current_unix_timestamp_seconds();
+
+        let first = execute_service_request_with_state(&signed_request(now), state.clone()).await;
+        assert!(
+            first.starts_with("HTTP/1.1 200 OK"),
+            "button interaction should return 200"
+        );
+        assert!(
+            first.contains("\"type\":4"),
+            "button interaction should return deferred message response"
+        );
+
+        // Discord redelivers with a fresh signature; the interaction id still dedupes it.
+        let replay = execute_service_request_with_state(&signed_request(now - 1), state).await;
+        assert!(
+            replay.starts_with("HTTP/1.1 409 Conflict"),
+            "redelivered button interaction should be rejected"
+        );
+    }
+
+    #[tokio::test]
+    async fn service_mode_discord_interactions_rejects_unsigned_approval_click() {
+        let (runtime, config) = test_discord_interactions_runtime(true);
+        let request = concat!(
+            "POST /chatops/discord/interactions HTTP/1.1\r\n",
+            "Host: localhost\r\n",
+            "Content-Type: application/json\r\n",
+            "\r\n",
+            "{\"id\":\"903\",\"type\":3,\"channel_id\":\"555\",\"member\":{\"user\":{\"id\":\"777\"}},\"data\":{\"component_type\":2,\"custom_id\":\"ntk:reject:task-42\"}}"
+        );
+        let response = execute_service_request_with_state(
+            request,
+            test_service_state_with_security(
+                initialized_chatops_runtime(Some(runtime), config, None),
+                default_test_ingress_security(),
+                None,
+                Some(unique_test_path("discord-component-unsigned")),
+            ),
+        )
+        .await;
+        assert!(
+            response.starts_with("HTTP/1.1 401 Unauthorized"),
+            "unsigned approval click must be refused without a public key"
+        );
+    }
+
+    #[tokio::test]
+    async fn service_mode_telegram_webhook_requires_secret_for_approval_callbacks() {
+        let body = "{\"update_id\":31,\"callback_query\":{\"id\":\"cb-9\",\"from\":{\"id\":777},\"data\":\"ntk:approve:task-42\",\"message\":{\"date\":1737200000,\"chat\":{\"id\":555}}}}";
+        let (runtime, config) = test_telegram_webhook_runtime(true);
+        let response = execute_service_request_with_state(
+            &format!(
+                "POST /chatops/telegram/webhook HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\n\r\n{body}"
+            ),
+            test_service_state_with_security(
+                initialized_chatops_runtime(Some(runtime), config, None),
+                default_test_ingress_security(),
+                None,
+                Some(unique_test_path("telegram-callback-unsigned")),
+            ),
+        )
+        .await;
+        assert!(
+            response.starts_with("HTTP/1.1 401 Unauthorized"),
+            "approval callback must be refused without a webhook secret"
+        );
+
+        let (runtime, config) = test_telegram_webhook_runtime(true);
+        let response = execute_service_request_with_state(
+            &format!(
+                "POST /chatops/telegram/webhook HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nX-Telegram-Bot-Api-Secret-Token: hook-secret\r\n\r\n{body}"
+            ),
+            test_service_state_with_security(
+                initialized_chatops_runtime(Some(runtime), config, None),
+                Arc::new(ServiceIngressSecurityConfig {
+                    telegram_secret_token: Some("hook-secret".to_string()),
+                    discord_verifying_key: None,
+                    replay_window: std::time::Duration::from_secs(300),
+                    replay_max_entries: 1_024,
+                    replay_backend: IngressReplayBackendConfig::Memory,
+                }),
+                None,
+                Some(unique_test_path("telegram-callback-signed")),
+            ),
+        )
+        .await;
+        assert!(
+            response.starts_with("HTTP/1.1 202 Accepted"),
+            "approval callback with the webhook secret should be queued"
+        );
+    }
+
+    #[tokio::test]
+    async fn service_mode_chatops_ingress_refuses_unverified_text_approvals() {
+        let telegram_request = |text: &str| {
+            let body = format!(
+                "{{\"update_id\":41,\"message\":{{\"message_id\":1,\"date\":1737200000,\"text\":\"{text}\",\"chat\":{{\"id\":555}},\"from\":{{\"id\":777}}}}}}"
+            );
+            format!(
+                "POST /chatops/telegram/webhook HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\n\r\n{body}"
+            )
+        };
+        for (text, expected) in [
+            ("approve task-42", "HTTP/1.1 401 Unauthorized"),
+            (
+                "/ntk task reject task-42 not now",
+                "HTTP/1.1 401 Unauthorized",
+            ),
+            ("list", "HTTP/1.1 202 Accepted"),
+        ] {
+            let (runtime, config) = test_telegram_webhook_runtime(true);
+            let response = execute_service_request_with_state(
+                &telegram_request(text),
+                test_service_state_with_security(
+                    initialized_chatops_runtime(Some(runtime), config, None),
+                    default_test_ingress_security(),
+                    None,
+                    Some(unique_test_path("telegram-text-unsigned")),
+                ),
+            )
+            .await;
+            assert!(
+                response.starts_with(expected),
+                "`{text}` without a webhook secret: {response}"
+            );
+        }
+
+        let (runtime, config) = test_discord_interactions_runtime(true);
+        let request = concat!(
+            "POST /chatops/discord/interactions HTTP/1.1\r\n",
+            "Host: localhost\r\n",
+            "Content-Type: application/json\r\n",
+            "\r\n",
+            "{\"type\":2,\"channel_id\":\"555\",\"member\":{\"user\":{\"id\":\"777\"}},\"data\":{\"name\":\"approve\",\"options\":[{\"type\":3,\"name\":\"task_id\",\"value\":\"task-42\"}]}}"
+        );
+        let response = execute_service_request_with_state(
+            request,
+            test_service_state_with_security(
+                initialized_chatops_runtime(Some(runtime), config, None),
+                default_test_ingress_security(),
+                None,
+                Some(unique_test_path("discord-command-unsigned")),
+            ),
+        )
+        .await;
+        assert!(
+            response.starts_with("HTTP/1.1 401 Unauthorized"),
+            "unsigned approve command must be refused without a public key"
+        );
+    }
+
+    #[tokio::test]
+    async fn service_mode_discord_interactions_rejects_unknown_button() {
+        let (runtime, config) = test_discord_interactions_runtime(true);
+        let request = concat!(
+            "POST /chatops/discord/interactions HTTP/1.1\r\n",
+            "Host: localhost\r\n",
+            "Content-Type: application/json\r\n",
+            "\r\n",
+            "{\"id\":\"902\",\"type\":3,\"channel_id\":\"555\",\"member\":{\"user\":{\"id\":\"777\"}},\"data\":{\"component_type\":2,\"custom_id\":\"other:thing\"}}"
+        );
+        let response = execute_service_request_with_state(
+            request,
+            test_service_state_with_security(
+                initialized_chatops_runtime(Some(runtime), config, None),
+                default_test_ingress_security(),
+                None,
+                Some(unique_test_path("discord-component-unknown")),
+            ),
+        )
+        .await;
+        assert!(
+            response.starts_with("HTTP/1.1 400 Bad Request"),
+            "unknown button payload should be rejected"
+        );
+    }
+
     #[tokio::test]
     async fn service_mode_discord_interactions_rejects_invalid_payload() {
         let (runtime, config) = test_discord_interactions_runtime(true);
@@ -0,0 +1,174 @@
+//! Command palette contents for the interactive loops.
+//!
+//! The palette lists the registered commands, their subcommands and manifest
+//! commands the operator ran recently, ranked by frecency computed from the
+//! persisted [`CliState`] history.
+
+use crate::input::LOCAL_COMMANDS;
+use crate::state::{now_epoch_ms, CliState, HistoryEntry, HistoryEntryKind};
+use nettoolskit_core::MenuEntry;
+use nettoolskit_orchestrator::registered_command_specs;
+use nettoolskit_ui::{CommandPalette, Frecency};
+
+/// Recent manifest commands offered in the palette, newest first.
+const RECENT_MANIFEST_LIMIT: usize = 5;
+
+/// Manifest subcommands whose trailing argument is a manifest path.
+const MANIFEST_PATH_SUBCOMMANDS: &[&str] =
+    &["check", "render", "render-async", "apply", "apply-async"];
+
+/// One palette row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PaletteCommand {
+    label: String,
+    description: String,
+}
+
+impl MenuEntry for PaletteCommand {
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+/// Palette over roots, subcommands and recent manifest commands, ranked by
+/// the frecency of `state`'s history.
+pub(crate) fn build_command_palette(state: &CliState) -> CommandPalette {
+    CommandPalette::new(palette_commands(state)).with_frecency(history_frecency(state))
+}
+
+fn palette_commands(state: &CliState) -> Vec<PaletteCommand> {
+    let specs = registered_command_specs();
+    let mut commands: Vec<PaletteCommand> = specs
+        .iter()
+        .map(|spec| PaletteCommand {
+            label: spec.name.to_string(),
+            description: spec.description.to_string(),
+        })
+        .collect();
+    for spec in &specs {
+        commands.extend(spec.subcommands.iter().map(|subcommand| PaletteCommand {
+            label: format!("{} {}", spec.name, subcommand.name),
+            description: subcommand.description.to_string(),
+        }));
+    }
+    commands.extend(
+        LOCAL_COMMANDS
+            .iter()
+            .map(|(label, description)| PaletteCommand {
+                label: (*label).to_string(),
+                description: (*description).to_string(),
+            }),
+    );
+    for command in recent_manifest_commands(state) {
+        if !commands
+            .iter()
+            .any(|existing| existing.label == command.label)
+        {
+            commands.push(command);
+        }
+    }
+    commands
+}
+
+fn recent_manifest_commands(state: &CliState) -> Vec<PaletteCommand> {
+    let mut recent: Vec<PaletteCommand> = Vec::new();
+    for entry in state.history.iter().rev() {
+        if recent.len() == RECENT_MANIFEST_LIMIT {
+            break;
+        }
+        if entry.kind() != HistoryEntryKind::Command {
+            continue;
+        }
+        let tokens: Vec<&str> = entry.value().split_whitespace().collect();
+        let [root, subcommand, args @ ..] = tokens.as_slice() else {
+            continue;
+        };
+        if !root.eq_ignore_ascii_case("/manifest")
+            || !MANIFEST_PATH_SUBCOMMANDS.contains(subcommand)
+        {
+            continue;
+        }
+        let Some(path) = args.iter().rev().find(|arg| !arg.starts_with('-')) else {
+            continue;
+        };
+        let label = tokens.join(" ");
+        if recent.iter().any(|command| command.label == label) {
+            continue;
+        }
+        recent.push(PaletteCommand {
+            label,
+            description: format!("Recent manifest: {path}"),
+        });
+    }
+    recent
+}
+
+fn history_frecency(state: &CliState) -> Frecency {
+    Frecency::from_history(
+        state
+            .history
+            .iter()
+            .map(|entry| (entry.value(), entry.timestamp_ms())),
+        now_epoch_ms(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nettoolskit_core::AppConfig;
+
+    fn state_with(commands: &[&str]) -> CliState {
+        let mut state = CliState::new("palette-test", AppConfig::default(), 50);
+        for command in commands {
+            state.push_command(command);
+        }
+        state
+    }
+
+    #[test]
+    fn palette_commands_include_roots_subcommands_and_recent_manifests() {
+        let state = state_with(&[
+            "/manifest apply ./a.yml --dry-run",
+            "/manifest check ./b.yml",
+            "/manifest apply ./a.yml --dry-run",
+            "/manifest list",
+        ]);
+        let labels: Vec<String> = palette_commands(&state)
+            .into_iter()
+            .map(|command| command.label)
+            .collect();
+
+        assert!(labels.contains(&"/help".to_string()));
+        assert!(labels.contains(&"/task top".to_string()));
+        let recent: Vec<&String> = labels
+            .iter()
+            .filter(|label| label.contains(".yml"))
+            .collect();
+        assert_eq!(
+            recent,
+            vec![
+                "/manifest apply ./a.yml --dry-run",
+                "/manifest check ./b.yml"
+            ]
+        );
+    }
+
+    #[test]
+    fn build_command_palette_ranks_recent_commands_first() {
+        let state = state_with(&["/task list", "/task list", "/ai ask hello"]);
+        let palette = build_command_palette(&state);
+        let labels: Vec<&str> = palette
+            .matching_entries("")
+            .into_iter()
+            .map(|matched| matched.label)
+            .take(3)
+            .collect();
+
+        assert_eq!(labels, vec!["/task", "/task list", "/ai"]);
+    }
+}
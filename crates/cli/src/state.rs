@@ -99,7 +99,7 @@ pub struct LocalSessionSnapshot {
 }
 
 /// Serializable interactive session state.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CliState {
     /// Session metadata.
     pub session: SessionMetadata,
@@ -356,7 +356,7 @@ impl LocalSessionSnapshot {
     }
 }
 
-fn now_epoch_ms() -> u64 {
+pub(crate) fn now_epoch_ms() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map(|duration| duration.as_millis() as u64)
@@ -0,0 +1,449 @@
+//! Full-screen TUI state and key handling.
+//!
+//! Everything here is pure state: the run loop feeds in tasks, AI exchanges and
+//! footer logs, forwards key presses, and executes the [`TuiAction`]s that come
+//! back. That keeps the whole interface testable without a real terminal.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use nettoolskit_core::MenuProvider;
+use nettoolskit_orchestrator::{
+    AiSessionExchange, CommandOutput, ExitStatus, MainAction, TaskSnapshot,
+};
+use nettoolskit_ui::{CommandPalette, FilePicker, HistoryViewer, StatusBar};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Executed commands kept in the output pane.
+const OUTPUT_HISTORY_CAPACITY: usize = 50;
+/// Footer log lines kept for the logs pane.
+const FOOTER_LOG_CAPACITY: usize = 200;
+/// Lines scrolled per PageUp/PageDown in the output pane.
+const OUTPUT_SCROLL_STEP: u16 = 10;
+
+/// One executed command and the structured output it produced.
+#[derive(Debug, Clone)]
+pub(crate) struct OutputEntry {
+    /// Command or text as submitted.
+    pub(crate) input: String,
+    /// Final status.
+    pub(crate) status: ExitStatus,
+    /// Wall-clock execution time.
+    pub(crate) duration: Duration,
+    /// Captured output blocks.
+    pub(crate) output: CommandOutput,
+}
+
+/// Pop-up list rendered above the panes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OverlayKind {
+    /// Command palette (`/` on an empty prompt or Ctrl+P).
+    Palette,
+    /// Session history (Ctrl+R or `/history`).
+    History,
+    /// File picker (Ctrl+O); inserts the selected path into the prompt.
+    Files,
+}
+
+impl OverlayKind {
+    pub(crate) const fn title(self) -> &'static str {
+        match self {
+            Self::Palette => "Commands",
+            Self::History => "Session History",
+            Self::Files => "Files",
+        }
+    }
+}
+
+/// Open overlay with its filter query and highlighted row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OverlayState {
+    pub(crate) kind: OverlayKind,
+    pub(crate) query: String,
+    pub(crate) selected: usize,
+}
+
+/// One row of an open overlay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OverlayItem {
+    pub(crate) label: String,
+    pub(crate) detail: String,
+    /// Char indices in `label` matched by the filter, highlighted when drawn.
+    pub(crate) matched: Vec<usize>,
+}
+
+impl OverlayItem {
+    fn plain(label: String) -> Self {
+        Self {
+            label,
+            detail: String::new(),
+            matched: Vec::new(),
+        }
+    }
+}
+
+/// What the run loop should do after a key press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TuiAction {
+    /// Nothing beyond redrawing.
+    None,
+    /// Execute a command or free-text input.
+    Submit(String),
+    /// Leave the TUI.
+    Quit,
+}
+
+/// State of the full-screen interface.
+pub(crate) struct TuiApp {
+    pub(crate) input: String,
+    pub(crate) outputs: VecDeque<OutputEntry>,
+    /// Lines scrolled up from the bottom of the output pane.
+    pub(crate) output_scroll: u16,
+    pub(crate) running: Option<String>,
+    pub(crate) tasks: Vec<TaskSnapshot>,
+    pub(crate) ai_session_id: Option<String>,
+    pub(crate) ai_exchanges: Vec<AiSessionExchange>,
+    pub(crate) footer_logs: VecDeque<String>,
+    pub(crate) status_bar: StatusBar,
+    pub(crate) overlay: Option<OverlayState>,
+    pub(crate) directory: String,
+    palette: CommandPalette,
+    history: VecDeque<String>,
+    file_picker: FilePicker,
+    file_candidates: Option<Vec<PathBuf>>,
+}
+
+impl TuiApp {
+    /// New app seeded with the persisted session history.
+    pub(crate) fn new(history: VecDeque<String>, directory: impl Into<String>) -> Self {
+        let directory = directory.into();
+        Self {
+            input: String::new(),
+            outputs: VecDeque::new(),
+            output_scroll: 0,
+            running: None,
+            tasks: Vec::new(),
+            ai_session_id: None,
+            ai_exchanges: Vec::new(),
+            footer_logs: VecDeque::new(),
+            status_bar: StatusBar::new()
+                .with_input_backend("ratatui")
+                .with_max_notifications(10),
+            overlay: None,
+            palette: CommandPalette::new(MainAction::all_variants()),
+            history,
+            file_picker: FilePicker::new(&directory),
+            directory,
+            file_candidates: None,
+        }
+    }
+
+    /// Replace the default root-only palette, e.g. with one ranked by history.
+    pub(crate) fn with_palette(mut self, palette: CommandPalette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Session history, oldest first.
+    pub(crate) fn history_mut(&mut self) -> &mut VecDeque<String> {
+        &mut self.history
+    }
+
+    /// Use `paths` as file picker candidates instead of scanning the directory.
+    #[cfg(test)]
+    pub(crate) fn set_file_candidates(&mut self, paths: Vec<PathBuf>) {
+        self.file_candidates = Some(paths);
+    }
+
+    /// Replace the task pane contents, most recently updated first.
+    pub(crate) fn set_tasks(&mut self, mut tasks: Vec<TaskSnapshot>) {
+        tasks.sort_by(|a, b| b.updated_at_unix_ms.cmp(&a.updated_at_unix_ms));
+        self.tasks = tasks;
+    }
+
+    /// Replace the AI conversation pane contents.
+    pub(crate) fn set_ai_session(
+        &mut self,
+        session_id: Option<String>,
+        exchanges: Vec<AiSessionExchange>,
+    ) {
+        self.ai_session_id = session_id;
+        self.ai_exchanges = exchanges;
+    }
+
+    /// Append footer log lines, dropping the oldest past capacity.
+    pub(crate) fn push_footer_logs(&mut self, lines: impl IntoIterator<Item = String>) {
+        for line in lines {
+            if self.footer_logs.len() == FOOTER_LOG_CAPACITY {
+                self.footer_logs.pop_front();
+            }
+            self.footer_logs.push_back(line);
+        }
+    }
+
+    /// Record a finished command and scroll the output pane back to the bottom.
+    pub(crate) fn record_output(&mut self, entry: OutputEntry) {
+        if self.outputs.len() == OUTPUT_HISTORY_CAPACITY {
+            self.outputs.pop_front();
+        }
+        self.outputs.push_back(entry);
+        self.output_scroll = 0;
+        self.running = None;
+    }
+
+    /// Open `kind` with an empty filter.
+    pub(crate) fn open_overlay(&mut self, kind: OverlayKind) {
+        if kind == OverlayKind::Files && self.file_candidates.is_none() {
+            self.file_candidates = Some(self.file_picker.discover_files().unwrap_or_default());
+        }
+        self.overlay = Some(OverlayState {
+            kind,
+            query: String::new(),
+            selected: 0,
+        });
+    }
+
+    /// Rows of the open overlay, best match first.
+    pub(crate) fn overlay_items(&self) -> Vec<OverlayItem> {
+        let Some(overlay) = &self.overlay else {
+            return Vec::new();
+        };
+        match overlay.kind {
+            OverlayKind::Palette => self
+                .palette
+                .matching_entries(&overlay.query)
+                .into_iter()
+                .map(|matched| OverlayItem {
+                    label: matched.label.to_string(),
+                    detail: matched.description.to_string(),
+                    matched: matched.positions,
+                })
+                .collect(),
+            OverlayKind::History => {
+                let mut viewer = HistoryViewer::new(self.history.iter().rev().cloned().collect());
+                viewer.set_query(Some(overlay.query.clone()));
+                viewer
+                    .filtered_entries()
+                    .into_iter()
+                    .map(OverlayItem::plain)
+                    .collect()
+            }
+            OverlayKind::Files => self
+                .file_picker
+                .rank_paths(
+                    self.file_candidates.as_deref().unwrap_or_default(),
+                    &overlay.query,
+                )
+                .into_iter()
+                .map(|path| OverlayItem::plain(self.file_picker.display_path(&path)))
+                .collect(),
+        }
+    }
+
+    /// Apply one key press.
+    pub(crate) fn handle_key(&mut self, key: KeyEvent) -> TuiAction {
+        if self.overlay.is_some() {
+            return self.handle_overlay_key(key);
+        }
+
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('c') if ctrl => {
+                if self.input.is_empty() {
+                    return TuiAction::Quit;
+                }
+                self.input.clear();
+            }
+            KeyCode::Char('d') if ctrl && self.input.is_empty() => return TuiAction::Quit,
+            KeyCode::Char('p') if ctrl => self.open_overlay(OverlayKind::Palette),
+            KeyCode::Char('r') if ctrl => self.open_overlay(OverlayKind::History),
+            KeyCode::Char('o') if ctrl => self.open_overlay(OverlayKind::Files),
+            KeyCode::Char('u') if ctrl => self.input.clear(),
+            KeyCode::Char('/') if self.input.is_empty() => self.open_overlay(OverlayKind::Palette),
+            KeyCode::Char(ch) if !ctrl => self.input.push(ch),
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Esc => self.input.clear(),
+            KeyCode::PageUp => {
+                self.output_scroll = self.output_scroll.saturating_add(OUTPUT_SCROLL_STEP);
+            }
+            KeyCode::PageDown => {
+                self.output_scroll = self.output_scroll.saturating_sub(OUTPUT_SCROLL_STEP);
+            }
+            KeyCode::Enter => {
+                let submitted = self.input.trim().to_string();
+                self.input.clear();
+                if !submitted.is_empty() {
+                    return TuiAction::Submit(submitted);
+                }
+            }
+            _ => {}
+        }
+        TuiAction::None
+    }
+
+    fn handle_overlay_key(&mut self, key: KeyEvent) -> TuiAction {
+        let item_count = self.overlay_items().len();
+        let Some(overlay) = self.overlay.as_mut() else {
+            return TuiAction::None;
+        };
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => self.overlay = None,
+            KeyCode::Char('c') if ctrl => self.overlay = None,
+            KeyCode::Up => overlay.selected = overlay.selected.saturating_sub(1),
+            KeyCode::Down => {
+                overlay.selected = (overlay.selected + 1).min(item_count.saturating_sub(1));
+            }
+            KeyCode::Backspace => {
+                overlay.query.pop();
+                overlay.selected = 0;
+            }
+            KeyCode::Char(ch) if !ctrl => {
+                overlay.query.push(ch);
+                overlay.selected = 0;
+            }
+            KeyCode::Enter => return self.accept_overlay_selection(),
+            _ => {}
+        }
+        TuiAction::None
+    }
+
+    fn accept_overlay_selection(&mut self) -> TuiAction {
+        let items = self.overlay_items();
+        let Some(overlay) = self.overlay.take() else {
+            return TuiAction::None;
+        };
+        let Some(OverlayItem { label, .. }) = items.into_iter().nth(overlay.selected) else {
+            return TuiAction::None;
+        };
+        match overlay.kind {
+            OverlayKind::Palette => {
+                self.input.clear();
+                TuiAction::Submit(label)
+            }
+            OverlayKind::History => {
+                self.input = label;
+                TuiAction::None
+            }
+            OverlayKind::Files => {
+                if !self.input.is_empty() && !self.input.ends_with(' ') {
+                    self.input.push(' ');
+                }
+                self.input.push_str(&label);
+                TuiAction::None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn ctrl(ch: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(ch), KeyModifiers::CONTROL)
+    }
+
+    fn type_text(app: &mut TuiApp, text: &str) {
+        for ch in text.chars() {
+            app.handle_key(key(KeyCode::Char(ch)));
+        }
+    }
+
+    #[test]
+    fn enter_submits_trimmed_input_and_clears_prompt() {
+        let mut app = TuiApp::new(VecDeque::new(), ".");
+        type_text(&mut app, "explain the task flow ");
+        assert_eq!(
+            app.handle_key(key(KeyCode::Enter)),
+            TuiAction::Submit("explain the task flow".to_string())
+        );
+        assert!(app.input.is_empty());
+        assert_eq!(app.handle_key(key(KeyCode::Enter)), TuiAction::None);
+    }
+
+    #[test]
+    fn slash_on_empty_prompt_opens_palette_and_enter_runs_selection() {
+        let mut app = TuiApp::new(VecDeque::new(), ".");
+        app.handle_key(key(KeyCode::Char('/')));
+        assert_eq!(
+            app.overlay.as_ref().map(|overlay| overlay.kind),
+            Some(OverlayKind::Palette)
+        );
+
+        type_text(&mut app, "tas");
+        let items = app.overlay_items();
+        assert_eq!(items.first().map(|item| item.label.as_str()), Some("/task"));
+        assert_eq!(
+            app.handle_key(key(KeyCode::Enter)),
+            TuiAction::Submit("/task".to_string())
+        );
+        assert!(app.overlay.is_none());
+    }
+
+    #[test]
+    fn history_overlay_lists_newest_first_and_fills_prompt() {
+        let history = VecDeque::from(vec!["/help".to_string(), "/task list".to_string()]);
+        let mut app = TuiApp::new(history, ".");
+        app.handle_key(ctrl('r'));
+
+        let items = app.overlay_items();
+        assert_eq!(items[0].label, "/task list");
+        app.handle_key(key(KeyCode::Down));
+        assert_eq!(app.handle_key(key(KeyCode::Enter)), TuiAction::None);
+        assert_eq!(app.input, "/help");
+    }
+
+    #[test]
+    fn file_overlay_appends_selected_path_to_prompt() {
+        let mut app = TuiApp::new(VecDeque::new(), "repo");
+        app.set_file_candidates(vec![
+            PathBuf::from("repo").join("ntk-api.yml"),
+            PathBuf::from("repo").join("readme.md"),
+        ]);
+        app.input = "/manifest check".to_string();
+        app.handle_key(ctrl('o'));
+        type_text(&mut app, "ntk");
+        app.handle_key(key(KeyCode::Enter));
+
+        assert_eq!(app.input, "/manifest check ntk-api.yml");
+    }
+
+    #[test]
+    fn escape_closes_overlay_and_ctrl_c_quits_on_empty_prompt() {
+        let mut app = TuiApp::new(VecDeque::new(), ".");
+        app.handle_key(ctrl('p'));
+        app.handle_key(key(KeyCode::Esc));
+        assert!(app.overlay.is_none());
+
+        type_text(&mut app, "draft");
+        assert_eq!(app.handle_key(ctrl('c')), TuiAction::None);
+        assert!(app.input.is_empty());
+        assert_eq!(app.handle_key(ctrl('c')), TuiAction::Quit);
+    }
+
+    #[test]
+    fn record_output_caps_history_and_resets_scroll() {
+        let mut app = TuiApp::new(VecDeque::new(), ".");
+        app.handle_key(key(KeyCode::PageUp));
+        assert_eq!(app.output_scroll, OUTPUT_SCROLL_STEP);
+
+        for index in 0..=OUTPUT_HISTORY_CAPACITY {
+            app.record_output(OutputEntry {
+                input: format!("/task watch {index}"),
+                status: ExitStatus::Success,
+                duration: Duration::from_millis(5),
+                output: CommandOutput::new(),
+            });
+        }
+        assert_eq!(app.outputs.len(), OUTPUT_HISTORY_CAPACITY);
+        assert_eq!(app.outputs[0].input, "/task watch 1");
+        assert_eq!(app.output_scroll, 0);
+    }
+}
@@ -0,0 +1,229 @@
+//! Full-screen ratatui interface.
+//!
+//! Enabled by building with the `modern-tui` feature or setting
+//! `NTK_USE_MODERN_TUI=1`. It drives the same orchestrator entry points as the
+//! line-based loop but keeps command output, the live task list, the active AI
+//! conversation, the status bar and footer logs on screen at once.
+
+mod app;
+mod view;
+
+use crate::palette::build_command_palette;
+use crate::state::{HistoryEntryKind, SharedCliState};
+use crate::{
+    handle_ai_resume_with_picker, initialize_runtime_state, is_ai_resume_command,
+    is_history_command, persist_runtime_state_snapshot, record_session_history,
+    record_state_history_entry, record_status_outcome, seed_session_history_from_state,
+    AttentionConfig, InteractiveOptions,
+};
+use app::{OutputEntry, OverlayKind, TuiAction, TuiApp};
+use crossterm::event::{self, Event, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use nettoolskit_orchestrator::execution::headless;
+use nettoolskit_orchestrator::{
+    active_ai_session_id, capture_output, get_main_action, process_command_with_output,
+    process_text, CommandOutput, ConsoleMode, ExitStatus, LocalAiSessionState, MainAction,
+};
+use nettoolskit_ui::{drain_pending_footer_logs, StatusBarMode, StatusNotificationLevel};
+use ratatui::backend::CrosstermBackend;
+use ratatui::Terminal;
+use std::io::{self, Stdout};
+use std::time::{Duration, Instant};
+use tracing::info;
+
+/// How long to wait for a key before refreshing panes.
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Minimum delay between task list refreshes.
+const TASK_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+type TuiTerminal = Terminal<CrosstermBackend<Stdout>>;
+
+/// Puts the terminal back into cooked mode on the main screen when dropped.
+struct TerminalRestoreGuard;
+
+impl TerminalRestoreGuard {
+    fn enter() -> io::Result<Self> {
+        enable_raw_mode()?;
+        if let Err(err) = execute!(io::stdout(), EnterAlternateScreen) {
+            let _ = disable_raw_mode();
+            return Err(err);
+        }
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalRestoreGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        let _ = disable_raw_mode();
+    }
+}
+
+/// Run the full-screen interface until the operator quits.
+pub(crate) async fn run_modern_tui(options: InteractiveOptions) -> io::Result<ExitStatus> {
+    info!("Starting full-screen TUI");
+    let attention = AttentionConfig {
+        enabled: options.attention_bell,
+        desktop_notification: options.attention_desktop_notification,
+        unfocused_only: options.attention_unfocused_only,
+    };
+    let state = initialize_runtime_state(&options);
+    let directory = std::env::current_dir()
+        .ok()
+        .and_then(|path| path.to_str().map(String::from))
+        .unwrap_or_else(|| String::from("."));
+    let palette = {
+        let guard = state
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        build_command_palette(&guard)
+    };
+    let mut app =
+        TuiApp::new(seed_session_history_from_state(&state), directory).with_palette(palette);
+
+    let result = {
+        let _restore = TerminalRestoreGuard::enter()?;
+        let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+        terminal.clear()?;
+        run_event_loop(&mut terminal, &mut app, &state, attention).await
+    };
+    persist_runtime_state_snapshot(&state);
+    result
+}
+
+async fn run_event_loop(
+    terminal: &mut TuiTerminal,
+    app: &mut TuiApp,
+    state: &SharedCliState,
+    attention: AttentionConfig,
+) -> io::Result<ExitStatus> {
+    refresh_ai_session(app);
+    let mut last_task_refresh: Option<Instant> = None;
+
+    loop {
+        if last_task_refresh.is_none_or(|at| at.elapsed() >= TASK_REFRESH_INTERVAL) {
+            refresh_tasks(app).await;
+            last_task_refresh = Some(Instant::now());
+        }
+        app.push_footer_logs(drain_pending_footer_logs());
+        app.status_bar.set_mode(if app.overlay.is_some() {
+            StatusBarMode::Menu
+        } else {
+            StatusBarMode::Ready
+        });
+        terminal.draw(|frame| view::draw(frame, app))?;
+
+        if !event::poll(EVENT_POLL_INTERVAL)? {
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match app.handle_key(key) {
+            TuiAction::None => {}
+            TuiAction::Quit => return Ok(ExitStatus::Success),
+            TuiAction::Submit(line) => {
+                record_session_history(app.history_mut(), &line);
+                let kind = if line.starts_with('/') {
+                    HistoryEntryKind::Command
+                } else {
+                    HistoryEntryKind::Text
+                };
+                record_state_history_entry(state, kind, &line);
+
+                if is_history_command(&line) {
+                    app.open_overlay(OverlayKind::History);
+                    continue;
+                }
+                if let Some(MainAction::Quit) = get_main_action(&line) {
+                    return Ok(ExitStatus::Success);
+                }
+                if is_ai_resume_command(&line) {
+                    // The resume picker is a line-based prompt; hand it the real screen.
+                    execute!(io::stdout(), LeaveAlternateScreen)?;
+                    disable_raw_mode()?;
+                    handle_ai_resume_with_picker(&mut app.status_bar);
+                    enable_raw_mode()?;
+                    execute!(io::stdout(), EnterAlternateScreen)?;
+                    terminal.clear()?;
+                    refresh_ai_session(app);
+                    continue;
+                }
+
+                app.running = Some(line.clone());
+                app.status_bar.set_mode(if line.starts_with('/') {
+                    StatusBarMode::Command
+                } else {
+                    StatusBarMode::Text
+                });
+                terminal.draw(|frame| view::draw(frame, app))?;
+
+                let started = Instant::now();
+                let (status, output) = execute_captured(&line).await;
+                let duration = started.elapsed();
+
+                // Commands may prompt or toggle raw mode on their own; take the screen back.
+                enable_raw_mode()?;
+                terminal.clear()?;
+                record_status_outcome(&mut app.status_bar, status, duration, &line, attention);
+                app.record_output(OutputEntry {
+                    input: line,
+                    status,
+                    duration,
+                    output,
+                });
+                refresh_ai_session(app);
+                last_task_refresh = None;
+            }
+        }
+    }
+}
+
+/// Execute a command or free-text line, capturing its structured output.
+async fn execute_captured(line: &str) -> (ExitStatus, CommandOutput) {
+    let owned = line.to_string();
+    let task = tokio::spawn(async move {
+        if owned.starts_with('/') {
+            process_command_with_output(&owned).await
+        } else {
+            capture_output(process_text(&owned)).await
+        }
+    });
+    match task.await {
+        Ok(result) => result,
+        Err(err) => (
+            ExitStatus::Error,
+            CommandOutput::new().error(
+                format!("execution task failed: {err}"),
+                vec!["The session is still usable; retry the command.".to_string()],
+            ),
+        ),
+    }
+}
+
+async fn refresh_tasks(app: &mut TuiApp) {
+    match headless::list_tasks(ConsoleMode::Silent).await {
+        Ok(tasks) => app.set_tasks(tasks),
+        Err(err) => app.status_bar.push_notification(
+            StatusNotificationLevel::Warning,
+            format!("task list unavailable: {}", err.message),
+        ),
+    }
+}
+
+fn refresh_ai_session(app: &mut TuiApp) {
+    let session_id = active_ai_session_id();
+    let exchanges = session_id
+        .as_deref()
+        .and_then(|id| LocalAiSessionState::load_local_snapshot(id).ok().flatten())
+        .map(|session| session.exchanges.into_iter().collect())
+        .unwrap_or_default();
+    app.set_ai_session(session_id, exchanges);
+}
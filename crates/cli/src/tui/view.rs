@@ -0,0 +1,721 @@
+//! Full-screen TUI layout and rendering.
+//!
+//! ```text
+//! ┌ header ──────────────────────────────────────────────┐
+//! │ Output                          │ Tasks              │
+//! │                                 ├────────────────────┤
+//! │                                 │ AI Conversation    │
+//! ├─────────────────────────────────┴────────────────────┤
+//! │ › prompt                                             │
+//! │ status bar                                           │
+//! │ Logs                                                 │
+//! └──────────────────────────────────────────────────────┘
+//! ```
+
+use super::app::{OutputEntry, TuiApp};
+use nettoolskit_core::TaskExecutionStatus;
+use nettoolskit_orchestrator::{ExitStatus, OutputBlock, OutputTone};
+use nettoolskit_ui::Color as UiColor;
+use owo_colors::Rgb;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+    Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table,
+};
+use ratatui::Frame;
+
+/// Footer log lines shown at once.
+const FOOTER_LOG_ROWS: u16 = 4;
+/// Minimum width of the output pane before the side panes are dropped.
+const SIDE_PANES_MIN_WIDTH: u16 = 90;
+
+const fn rgb(color: Rgb) -> Color {
+    Color::Rgb(color.0, color.1, color.2)
+}
+
+fn pane(title: &str) -> Block<'_> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(rgb(UiColor::PURPLE_DARK)))
+        .title(Span::styled(
+            format!(" {title} "),
+            Style::default()
+                .fg(rgb(UiColor::PURPLE_LIGHT))
+                .add_modifier(Modifier::BOLD),
+        ))
+}
+
+/// Draw the whole interface.
+pub(crate) fn draw(frame: &mut Frame<'_>, app: &TuiApp) {
+    let [header, body, prompt, status, logs] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(6),
+            Constraint::Length(3),
+            Constraint::Length(1),
+            Constraint::Length(FOOTER_LOG_ROWS + 2),
+        ])
+        .areas(frame.area());
+
+    draw_header(frame, app, header);
+    if body.width >= SIDE_PANES_MIN_WIDTH {
+        let [output, side] = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .areas(body);
+        let [tasks, ai] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .areas(side);
+        draw_output(frame, app, output);
+        draw_tasks(frame, app, tasks);
+        draw_ai_conversation(frame, app, ai);
+    } else {
+        draw_output(frame, app, body);
+    }
+    draw_prompt(frame, app, prompt);
+    draw_status(frame, app, status);
+    draw_logs(frame, app, logs);
+
+    if app.overlay.is_some() {
+        draw_overlay(frame, app, body);
+    }
+}
+
+fn draw_header(frame: &mut Frame<'_>, app: &TuiApp, area: Rect) {
+    let line = Line::from(vec![
+        Span::styled(
+            " NetToolsKit ",
+            Style::default()
+                .fg(rgb(UiColor::WHITE))
+                .bg(rgb(UiColor::PURPLE))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" {} ", app.directory),
+            Style::default().fg(rgb(UiColor::GRAY_LIGHT)),
+        ),
+        Span::styled(
+            " / or Ctrl+P commands · Ctrl+R history · Ctrl+O files · PgUp/PgDn scroll · Ctrl+C quit",
+            Style::default().fg(rgb(UiColor::GRAY)),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn draw_output(frame: &mut Frame<'_>, app: &TuiApp, area: Rect) {
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    for entry in &app.outputs {
+        lines.extend(output_entry_lines(entry));
+        lines.push(Line::default());
+    }
+    if let Some(running) = &app.running {
+        lines.push(Line::from(Span::styled(
+            format!("› {running}  running…"),
+            Style::default().fg(rgb(UiColor::CYAN)),
+        )));
+    }
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "Type a command (/help) or a question and press Enter.",
+            Style::default().fg(rgb(UiColor::GRAY)),
+        )));
+    }
+
+    // Keep the newest output at the bottom unless the operator scrolled up.
+    let visible = area.height.saturating_sub(2);
+    let total = u16::try_from(lines.len()).unwrap_or(u16::MAX);
+    let max_scroll = total.saturating_sub(visible);
+    let offset = max_scroll.saturating_sub(app.output_scroll.min(max_scroll));
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(pane("Output"))
+            .scroll((offset, 0)),
+        area,
+    );
+}
+
+fn status_style(status: ExitStatus) -> (Style, &'static str) {
+    match status {
+        ExitStatus::Success => (Style::default().fg(rgb(UiColor::GREEN)), "ok"),
+        ExitStatus::Error => (Style::default().fg(rgb(UiColor::RED)), "error"),
+        ExitStatus::Interrupted => (Style::default().fg(rgb(UiColor::YELLOW)), "interrupted"),
+    }
+}
+
+fn tone_style(tone: OutputTone) -> Style {
+    let style = Style::default();
+    match tone {
+        OutputTone::Plain => style,
+        OutputTone::Info => style.fg(rgb(UiColor::CYAN)),
+        OutputTone::Success => style.fg(rgb(UiColor::GREEN)),
+        OutputTone::Warning => style.fg(rgb(UiColor::YELLOW)),
+        OutputTone::Error => style.fg(rgb(UiColor::RED)),
+    }
+}
+
+/// Styled lines for one executed command: a `› input [status · ms]` header
+/// followed by its output blocks.
+pub(crate) fn output_entry_lines(entry: &OutputEntry) -> Vec<Line<'static>> {
+    let (style, label) = status_style(entry.status);
+    let mut lines = vec![Line::from(vec![
+        Span::styled(
+            format!("› {}", entry.input),
+            Style::default()
+                .fg(rgb(UiColor::PURPLE_LIGHT))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  [{label} · {}ms]", entry.duration.as_millis()),
+            style,
+        ),
+    ])];
+
+    if entry.output.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "(no structured output)",
+            Style::default().fg(rgb(UiColor::GRAY)),
+        )));
+    }
+
+    for block in &entry.output.blocks {
+        match block {
+            OutputBlock::Heading { text, tone } => lines.push(Line::from(Span::styled(
+                text.clone(),
+                tone_style(*tone).add_modifier(Modifier::BOLD),
+            ))),
+            OutputBlock::Text { text, tone } => {
+                lines.extend(
+                    text.lines()
+                        .map(|line| Line::from(Span::styled(line.to_string(), tone_style(*tone)))),
+                );
+            }
+            OutputBlock::KeyValues { entries } => {
+                let width = entries
+                    .iter()
+                    .map(|entry| entry.key.chars().count())
+                    .max()
+                    .unwrap_or(0);
+                lines.extend(entries.iter().map(|entry| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{:width$}  ", entry.key),
+                            Style::default().fg(rgb(UiColor::GRAY_LIGHT)),
+                        ),
+                        Span::raw(entry.value.clone()),
+                    ])
+                }));
+            }
+            OutputBlock::Table { headers, rows } => {
+                let widths = column_widths(headers, rows);
+                lines.push(Line::from(Span::styled(
+                    pad_row(headers, &widths),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )));
+                lines.extend(rows.iter().map(|row| Line::raw(pad_row(row, &widths))));
+            }
+            OutputBlock::Markdown { markdown } => {
+                lines.extend(markdown.lines().map(|line| Line::raw(line.to_string())));
+            }
+            OutputBlock::Diff { path, diff } => {
+                if let Some(path) = path {
+                    lines.push(Line::from(Span::styled(
+                        path.clone(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )));
+                }
+                lines.extend(diff.lines().map(|line| {
+                    let style = if line.starts_with('+') {
+                        Style::default().fg(rgb(UiColor::GREEN))
+                    } else if line.starts_with('-') {
+                        Style::default().fg(rgb(UiColor::RED))
+                    } else {
+                        Style::default()
+                    };
+                    Line::from(Span::styled(line.to_string(), style))
+                }));
+            }
+            OutputBlock::Error { message, hints } => {
+                lines.push(Line::from(Span::styled(
+                    format!("✗ {message}"),
+                    Style::default().fg(rgb(UiColor::RED)),
+                )));
+                lines.extend(hints.iter().map(|hint| {
+                    Line::from(Span::styled(
+                        format!("  {hint}"),
+                        Style::default().fg(rgb(UiColor::GRAY)),
+                    ))
+                }));
+            }
+        }
+    }
+    lines
+}
+
+fn column_widths(headers: &[String], rows: &[Vec<String>]) -> Vec<usize> {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (index, cell) in row.iter().enumerate() {
+            let width = cell.chars().count();
+            match widths.get_mut(index) {
+                Some(current) => *current = (*current).max(width),
+                None => widths.push(width),
+            }
+        }
+    }
+    widths
+}
+
+fn pad_row(cells: &[String], widths: &[usize]) -> String {
+    cells
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| {
+            let width = widths.get(index).copied().unwrap_or(0);
+            format!("{cell:width$}")
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+        .trim_end()
+        .to_string()
+}
+
+fn task_status_color(status: TaskExecutionStatus) -> Color {
+    match status {
+        TaskExecutionStatus::Queued => rgb(UiColor::GRAY_LIGHT),
+        TaskExecutionStatus::AwaitingApproval => rgb(UiColor::YELLOW),
+        TaskExecutionStatus::Running => rgb(UiColor::CYAN),
+        TaskExecutionStatus::Succeeded => rgb(UiColor::GREEN),
+        TaskExecutionStatus::Failed => rgb(UiColor::RED),
+        TaskExecutionStatus::Cancelled => rgb(UiColor::ORANGE),
+    }
+}
+
+fn draw_tasks(frame: &mut Frame<'_>, app: &TuiApp, area: Rect) {
+    let title = format!("Tasks ({})", app.tasks.len());
+    if app.tasks.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "No tasks yet. /task submit <intent> <payload>",
+                Style::default().fg(rgb(UiColor::GRAY)),
+            ))
+            .block(pane(&title)),
+            area,
+        );
+        return;
+    }
+
+    let rows = app.tasks.iter().map(|task| {
+        Row::new(vec![
+            Cell::from(task.id.clone()),
+            Cell::from(task.intent.clone()),
+            Cell::from(Span::styled(
+                task.status.as_str(),
+                Style::default().fg(task_status_color(task.status)),
+            )),
+            Cell::from(format!("{}/{}", task.attempts, task.max_attempts)),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(12),
+            Constraint::Length(16),
+            Constraint::Length(17),
+            Constraint::Length(5),
+        ],
+    )
+    .header(
+        Row::new(vec!["ID", "INTENT", "STATUS", "TRY"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .block(pane(&title));
+    frame.render_widget(table, area);
+}
+
+/// Greedy word wrap used to bottom-align the conversation pane.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut wrapped = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate_len =
+                line.chars().count() + usize::from(!line.is_empty()) + word.chars().count();
+            if !line.is_empty() && candidate_len > width {
+                wrapped.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        wrapped.push(line);
+    }
+    wrapped
+}
+
+fn draw_ai_conversation(frame: &mut Frame<'_>, app: &TuiApp, area: Rect) {
+    let title = match &app.ai_session_id {
+        Some(session_id) => format!("AI Conversation · {session_id}"),
+        None => "AI Conversation".to_string(),
+    };
+    let width = usize::from(area.width.saturating_sub(4));
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    for exchange in &app.ai_exchanges {
+        let speakers = [
+            ("you", &exchange.user_prompt, rgb(UiColor::CYAN)),
+            (
+                "ai",
+                &exchange.assistant_response,
+                rgb(UiColor::PURPLE_LIGHT),
+            ),
+        ];
+        for (speaker, text, color) in speakers {
+            lines.push(Line::from(Span::styled(
+                format!("{speaker}:"),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            )));
+            lines.extend(
+                wrap_text(text, width)
+                    .into_iter()
+                    .map(|line| Line::raw(format!("  {line}"))),
+            );
+        }
+    }
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No AI exchanges in this session. Try /ai ask <question>.",
+            Style::default().fg(rgb(UiColor::GRAY)),
+        )));
+    }
+
+    let visible = usize::from(area.height.saturating_sub(2));
+    let skip = lines.len().saturating_sub(visible);
+    let lines: Vec<Line<'static>> = lines.into_iter().skip(skip).collect();
+    frame.render_widget(Paragraph::new(lines).block(pane(&title)), area);
+}
+
+fn draw_prompt(frame: &mut Frame<'_>, app: &TuiApp, area: Rect) {
+    let prompt = Line::from(vec![
+        Span::styled("› ", Style::default().fg(rgb(UiColor::PURPLE))),
+        Span::raw(app.input.clone()),
+    ]);
+    frame.render_widget(Paragraph::new(prompt).block(pane("Prompt")), area);
+    if app.overlay.is_none() {
+        let cursor_x = area
+            .x
+            .saturating_add(3)
+            .saturating_add(u16::try_from(app.input.chars().count()).unwrap_or(u16::MAX))
+            .min(area.right().saturating_sub(2));
+        frame.set_cursor_position((cursor_x, area.y + 1));
+    }
+}
+
+fn draw_status(frame: &mut Frame<'_>, app: &TuiApp, area: Rect) {
+    let line = app
+        .status_bar
+        .format_line_for_width(usize::from(area.width));
+    frame.render_widget(
+        Paragraph::new(line).style(
+            Style::default()
+                .fg(rgb(UiColor::GRAY_LIGHT))
+                .bg(rgb(UiColor::GRAY_DARK)),
+        ),
+        area,
+    );
+}
+
+fn draw_logs(frame: &mut Frame<'_>, app: &TuiApp, area: Rect) {
+    let visible = usize::from(area.height.saturating_sub(2));
+    let skip = app.footer_logs.len().saturating_sub(visible);
+    let lines: Vec<Line<'static>> = app
+        .footer_logs
+        .iter()
+        .skip(skip)
+        .map(|line| {
+            Line::from(Span::styled(
+                line.clone(),
+                Style::default().fg(rgb(UiColor::GRAY)),
+            ))
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(pane("Logs")), area);
+}
+
+fn centered(area: Rect, width_percent: u16, height_percent: u16) -> Rect {
+    let width = area.width * width_percent / 100;
+    let height = area.height * height_percent / 100;
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+fn draw_overlay(frame: &mut Frame<'_>, app: &TuiApp, area: Rect) {
+    let Some(overlay) = &app.overlay else {
+        return;
+    };
+    let popup = centered(area, 70, 80);
+    frame.render_widget(Clear, popup);
+
+    let block = pane(overlay.kind.title());
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+    let [query, list] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .areas(inner);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("filter: ", Style::default().fg(rgb(UiColor::GRAY))),
+            Span::raw(overlay.query.clone()),
+        ])),
+        query,
+    );
+
+    let items: Vec<ListItem<'static>> = app
+        .overlay_items()
+        .into_iter()
+        .map(|item| {
+            let mut spans = highlighted_label(&item.label, &item.matched);
+            if !item.detail.is_empty() {
+                spans.push(Span::styled(
+                    format!("  {}", item.detail),
+                    Style::default().fg(rgb(UiColor::GRAY)),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    let empty = items.is_empty();
+    let list_widget = List::new(items).highlight_symbol("❯ ").highlight_style(
+        Style::default()
+            .fg(rgb(UiColor::PURPLE_LIGHT))
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut state = ListState::default().with_selected((!empty).then_some(overlay.selected));
+    frame.render_stateful_widget(list_widget, list, &mut state);
+    frame.set_cursor_position((
+        query
+            .x
+            .saturating_add(8)
+            .saturating_add(u16::try_from(overlay.query.chars().count()).unwrap_or(u16::MAX))
+            .min(query.right().saturating_sub(1)),
+        query.y,
+    ));
+}
+
+/// Split `label` into spans, styling the chars at `matched` positions.
+fn highlighted_label(label: &str, matched: &[usize]) -> Vec<Span<'static>> {
+    let highlight = Style::default()
+        .fg(rgb(UiColor::YELLOW))
+        .add_modifier(Modifier::BOLD);
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (index, ch) in label.chars().enumerate() {
+        let is_matched = matched.contains(&index);
+        if is_matched != run_matched && !run.is_empty() {
+            let text = std::mem::take(&mut run);
+            spans.push(if run_matched {
+                Span::styled(text, highlight)
+            } else {
+                Span::raw(text)
+            });
+        }
+        run_matched = is_matched;
+        run.push(ch);
+    }
+    if !run.is_empty() {
+        spans.push(if run_matched {
+            Span::styled(run, highlight)
+        } else {
+            Span::raw(run)
+        });
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::app::OverlayKind;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use nettoolskit_core::RuntimeMode;
+    use nettoolskit_orchestrator::{AiSessionExchange, CommandOutput, TaskSnapshot};
+    use ratatui::backend::TestBackend;
+    use ratatui::buffer::Buffer;
+    use ratatui::Terminal;
+    use std::collections::VecDeque;
+    use std::time::Duration;
+
+    fn render(app: &TuiApp, width: u16, height: u16) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("test terminal");
+        terminal.draw(|frame| draw(frame, app)).expect("draw");
+        buffer_text(terminal.backend().buffer())
+    }
+
+    fn buffer_text(buffer: &Buffer) -> String {
+        let area = buffer.area;
+        let mut text = String::new();
+        for y in 0..area.height {
+            for x in 0..area.width {
+                text.push_str(buffer[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    fn task(id: &str, status: TaskExecutionStatus) -> TaskSnapshot {
+        TaskSnapshot {
+            id: id.to_string(),
+            intent: "ai-plan".to_string(),
+            title: "plan".to_string(),
+            payload: "split processor".to_string(),
+            status,
+            runtime_mode: RuntimeMode::Cli,
+            execution_target: "background-worker".to_string(),
+            detail: String::new(),
+            attempts: 1,
+            max_attempts: 3,
+            created_at_unix_ms: 1,
+            updated_at_unix_ms: 2,
+            request_id: None,
+            correlation_id: None,
+            operator: None,
+            audit_events: Vec::new(),
+            output: None,
+            approval_gate: None,
+        }
+    }
+
+    #[test]
+    fn draw_renders_all_panes_with_live_data() {
+        let mut app = TuiApp::new(VecDeque::new(), "/work/repo");
+        app.set_tasks(vec![task("task-7", TaskExecutionStatus::AwaitingApproval)]);
+        app.set_ai_session(
+            Some("ai-1".to_string()),
+            vec![AiSessionExchange::new(
+                "ask",
+                "mock",
+                "what is queued?",
+                "One plan awaits approval.",
+            )],
+        );
+        app.push_footer_logs(vec!["INFO worker started".to_string()]);
+        app.record_output(OutputEntry {
+            input: "/task list".to_string(),
+            status: ExitStatus::Success,
+            duration: Duration::from_millis(12),
+            output: CommandOutput::new().table(
+                ["ID", "STATUS"],
+                vec![vec!["task-7".to_string(), "awaiting_approval".to_string()]],
+            ),
+        });
+        app.input = "/ai ask next".to_string();
+
+        let screen = render(&app, 140, 40);
+        for expected in [
+            " Output ",
+            " Tasks (1) ",
+            "AI Conversation · ai-1",
+            " Prompt ",
+            " Logs ",
+            "› /task list  [ok · 12ms]",
+            "awaiting_approval",
+            "what is queued?",
+            "One plan awaits approval.",
+            "INFO worker started",
+            "› /ai ask next",
+            "mode:READY │ input:ratatui",
+        ] {
+            assert!(
+                screen.contains(expected),
+                "missing {expected:?} in\n{screen}"
+            );
+        }
+    }
+
+    #[test]
+    fn draw_drops_side_panes_on_narrow_terminals() {
+        let app = TuiApp::new(VecDeque::new(), ".");
+        let screen = render(&app, 80, 30);
+        assert!(screen.contains(" Output "));
+        assert!(!screen.contains(" Tasks "));
+    }
+
+    #[test]
+    fn draw_palette_overlay_lists_filtered_commands() {
+        let mut app = TuiApp::new(VecDeque::new(), ".");
+        app.open_overlay(OverlayKind::Palette);
+        for ch in "man".chars() {
+            app.handle_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+
+        let screen = render(&app, 120, 40);
+        assert!(screen.contains(" Commands "));
+        assert!(screen.contains("filter: man"));
+        assert!(screen.contains("❯ /manifest"));
+        assert!(!screen.contains("/quit"));
+    }
+
+    #[test]
+    fn highlighted_label_styles_matched_runs() {
+        let spans = highlighted_label("/task", &[1, 2, 4]);
+        let text: Vec<&str> = spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(text, vec!["/", "ta", "s", "k"]);
+        assert_eq!(spans[1].style.fg, Some(rgb(UiColor::YELLOW)));
+        assert_eq!(spans[2].style.fg, None);
+    }
+
+    #[test]
+    fn output_entry_lines_render_errors_and_missing_output() {
+        let failed = OutputEntry {
+            input: "/task watch nope".to_string(),
+            status: ExitStatus::Error,
+            duration: Duration::from_millis(3),
+            output: CommandOutput::new()
+                .error("task not found", vec!["Run /task list".to_string()]),
+        };
+        let text: Vec<String> = output_entry_lines(&failed)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            text,
+            vec![
+                "› /task watch nope  [error · 3ms]".to_string(),
+                "✗ task not found".to_string(),
+                "  Run /task list".to_string(),
+            ]
+        );
+
+        let silent = OutputEntry {
+            output: CommandOutput::new(),
+            ..failed
+        };
+        assert!(output_entry_lines(&silent)[1]
+            .to_string()
+            .contains("no structured output"));
+    }
+
+    #[test]
+    fn wrap_text_breaks_on_word_boundaries() {
+        assert_eq!(
+            wrap_text("one two three four", 9),
+            vec![
+                "one two".to_string(),
+                "three".to_string(),
+                "four".to_string()
+            ]
+        );
+        assert!(wrap_text("", 10).is_empty());
+    }
+}
@@ -176,6 +176,54 @@ fn manifest_check_invalid_file_returns_error() {
         .stdout(predicate::str::contains("validation errors"));
 }
 
+#[test]
+fn manifest_check_json_output_returns_structured_findings() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    let assert = ntk()
+        .current_dir(dir.path())
+        .args([
+            "--output",
+            "json",
+            "manifest",
+            "check",
+            "missing.manifest.yaml",
+        ])
+        .assert()
+        .code(1);
+
+    let document = json_stdout(&assert);
+    assert_eq!(document["command"], "manifest.check");
+    assert_eq!(document["status"], "error");
+    let blocks = document["data"]["blocks"]
+        .as_array()
+        .expect("validation findings stay in data");
+    let table = blocks
+        .iter()
+        .find(|block| block["kind"] == "table")
+        .expect("findings table");
+    assert_eq!(table["rows"][0][0], "error");
+    assert!(table["rows"][0][2]
+        .as_str()
+        .is_some_and(|message| message.contains("File not found")));
+}
+
+#[test]
+fn manifest_render_json_output_lists_planned_files() {
+    let (dir, manifest_path) = create_manifest_fixture();
+    let manifest = manifest_path.to_string_lossy().to_string();
+    let assert = ntk()
+        .current_dir(dir.path())
+        .args(["--output", "json", "manifest", "render", &manifest])
+        .assert()
+        .success();
+
+    let document = json_stdout(&assert);
+    assert_eq!(document["command"], "manifest.render");
+    assert!(document["data"]["blocks"]
+        .as_array()
+        .is_some_and(|blocks| blocks.iter().any(|block| block["kind"] == "heading")));
+}
+
 #[test]
 fn manifest_render_dry_run_subcommand_returns_zero() {
     let (dir, manifest_path) = create_manifest_fixture();
@@ -187,3 +235,381 @@ fn manifest_render_dry_run_subcommand_returns_zero() {
         .success()
         .stdout(predicate::str::contains("Render preview completed"));
 }
+
+#[test]
+fn manifest_check_accepts_path_with_spaces() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    let manifest_path = write_fixture(
+        dir.path(),
+        "my manifests/ntk-manifest.yml",
+        valid_manifest_yaml(),
+    );
+    let manifest = manifest_path.to_string_lossy().to_string();
+    ntk()
+        .current_dir(dir.path())
+        .args(["manifest", "check", &manifest])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("is valid"));
+}
+
+// ─── ai / task / config subcommands with --output json ────────────────
+
+fn ntk_isolated(home: &Path) -> Command {
+    let mut command = ntk();
+    command
+        .env("HOME", home)
+        .env("XDG_CONFIG_HOME", home.join(".config"))
+        .env("XDG_DATA_HOME", home.join(".local/share"))
+        .env("NTK_AI_PROVIDER", "mock")
+        .current_dir(home);
+    command
+}
+
+fn json_stdout(assert: &assert_cmd::assert::Assert) -> serde_json::Value {
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+    serde_json::from_str(stdout.trim()).unwrap_or_else(|error| {
+        panic!("stdout should be a single JSON document ({error}): {stdout}")
+    })
+}
+
+#[test]
+fn ai_ask_json_output_returns_report() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    let assert = ntk_isolated(home.path())
+        .args(["--output", "json", "ai", "ask", "what", "is", "a", "trait"])
+        .assert()
+        .success();
+
+    let document = json_stdout(&assert);
+    assert_eq!(document["schema_version"], 1);
+    assert_eq!(document["command"], "ai.ask");
+    assert_eq!(document["status"], "success");
+    assert_eq!(document["exit_code"], 0);
+    assert_eq!(document["data"]["provider"], "mock");
+    assert!(document["data"]["output"]
+        .as_str()
+        .expect("output string")
+        .contains("what is a trait"));
+}
+
+#[test]
+fn ai_apply_without_approval_flags_fails_with_json_error() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    let assert = ntk_isolated(home.path())
+        .args(["--output", "json", "ai", "apply", "rename", "module"])
+        .assert()
+        .code(1);
+
+    let document = json_stdout(&assert);
+    assert_eq!(document["status"], "error");
+    assert!(document["error"]["message"]
+        .as_str()
+        .expect("error message")
+        .contains("approval"));
+}
+
+#[test]
+fn task_submit_json_output_includes_audit_trail() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    let assert = ntk_isolated(home.path())
+        .args([
+            "--output", "json", "task", "submit", "ai-plan", "ship", "v2",
+        ])
+        .assert()
+        .success();
+
+    let document = json_stdout(&assert);
+    assert_eq!(document["command"], "task.submit");
+    assert_eq!(document["data"]["intent"], "ai-plan");
+    assert_eq!(document["data"]["status"], "succeeded");
+    assert!(!document["data"]["audit_events"]
+        .as_array()
+        .expect("audit events")
+        .is_empty());
+    assert!(document["data"]["output"]["blocks"]
+        .as_array()
+        .expect("task output")
+        .iter()
+        .any(|block| block["kind"] == "markdown"));
+}
+
+#[test]
+fn task_submit_unknown_intent_returns_error_exit_code() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    let assert = ntk_isolated(home.path())
+        .args(["--output", "json", "task", "submit", "bogus", "payload"])
+        .assert()
+        .code(1);
+
+    let document = json_stdout(&assert);
+    assert_eq!(
+        document["error"]["message"],
+        "Unsupported task intent: bogus"
+    );
+}
+
+#[test]
+fn task_list_without_service_endpoint_is_rejected() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    let assert = ntk_isolated(home.path())
+        .args(["--output", "json", "task", "list"])
+        .assert()
+        .code(1);
+
+    let document = json_stdout(&assert);
+    assert!(document["error"]["message"]
+        .as_str()
+        .is_some_and(|message| message.contains("no task service endpoint configured")));
+}
+
+#[test]
+fn task_watch_does_not_fall_back_to_local_registry_when_service_is_unreachable() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("free port should be available")
+        .port();
+
+    let assert = ntk_isolated(home.path())
+        .args([
+            "--remote",
+            &format!("http://127.0.0.1:{port}"),
+            "--output",
+            "json",
+            "task",
+            "watch",
+            "task-1",
+        ])
+        .assert()
+        .code(1);
+
+    let document = json_stdout(&assert);
+    assert!(document["error"]["message"]
+        .as_str()
+        .is_some_and(|message| message.contains("unreachable")));
+}
+
+#[test]
+fn config_set_then_get_round_trips_value() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    ntk_isolated(home.path())
+        .args(["--output", "json", "config", "set", "log_level", "warn"])
+        .assert()
+        .success();
+
+    ntk_isolated(home.path())
+        .args(["config", "get", "log_level"])
+        .assert()
+        .success()
+        .stdout("warn\n");
+
+    let assert = ntk_isolated(home.path())
+        .args(["--output", "json", "config", "unset", "log_level"])
+        .assert()
+        .success();
+    assert_eq!(json_stdout(&assert)["data"]["value"], "info");
+}
+
+#[test]
+fn config_get_unknown_key_fails() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    ntk_isolated(home.path())
+        .args(["config", "get", "not_a_key"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("unsupported key"));
+}
+
+#[test]
+fn json_output_is_rejected_for_mutating_manifest_commands() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    let assert = ntk_isolated(home.path())
+        .args(["--output", "json", "manifest", "apply", "missing.yaml"])
+        .assert()
+        .code(1);
+
+    let document = json_stdout(&assert);
+    assert_eq!(document["command"], "manifest.apply");
+    assert!(document["error"]["message"].is_string());
+}
+
+// ─── remote client mode (--remote / general.service_endpoint) ─────────
+
+/// `ntk service` child process, killed on drop.
+struct ServiceProcess {
+    child: std::process::Child,
+    endpoint: String,
+}
+
+impl ServiceProcess {
+    fn start(home: &Path) -> Self {
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .expect("free port should be available")
+            .port();
+        let child = std::process::Command::new(env!("CARGO_BIN_EXE_ntk"))
+            .args([
+                "service",
+                "--host",
+                "127.0.0.1",
+                "--port",
+                &port.to_string(),
+            ])
+            .env("HOME", home)
+            .env("XDG_CONFIG_HOME", home.join(".config"))
+            .env("XDG_DATA_HOME", home.join(".local/share"))
+            .env("NTK_AI_PROVIDER", "mock")
+            .env("NTK_SERVICE_AUTH_TOKEN", "e2e-token")
+            .env("NTK_TOOL_SCOPE_ALLOWED_TOOLS", "ai.ask")
+            .current_dir(home)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .expect("service should spawn");
+        let service = Self {
+            child,
+            endpoint: format!("http://127.0.0.1:{port}"),
+        };
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(20);
+        while std::net::TcpStream::connect(("127.0.0.1", port)).is_err() {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "service did not start listening on port {port}"
+            );
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        service
+    }
+}
+
+impl Drop for ServiceProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn remote_task_commands_round_trip_through_running_service() {
+    let service_home = TempDir::new().expect("failed to create temp dir");
+    let client_home = TempDir::new().expect("failed to create temp dir");
+    let service = ServiceProcess::start(service_home.path());
+
+    let assert = ntk_isolated(client_home.path())
+        .env("NTK_SERVICE_AUTH_TOKEN", "e2e-token")
+        .env("NTK_OPERATOR_ID", "dev-laptop")
+        .args([
+            "--remote",
+            &service.endpoint,
+            "--output",
+            "json",
+            "task",
+            "submit",
+            "ai-ask",
+            "remote",
+            "question",
+        ])
+        .assert()
+        .success();
+    let submitted = json_stdout(&assert);
+    assert_eq!(submitted["data"]["runtime_mode"], "service");
+    let task_id = submitted["data"]["id"]
+        .as_str()
+        .expect("task id should be present")
+        .to_string();
+
+    // A fresh client process sees the task because it lives in the service registry.
+    let assert = ntk_isolated(client_home.path())
+        .env("NTK_SERVICE_AUTH_TOKEN", "e2e-token")
+        .env("NTK_TASK_SERVICE_ENDPOINT", &service.endpoint)
+        .args(["--output", "json", "task", "list"])
+        .assert()
+        .success();
+    let listed = json_stdout(&assert);
+    let tasks = listed["data"]
+        .as_array()
+        .expect("task list should be an array");
+    assert!(tasks.iter().any(|task| task["id"] == task_id.as_str()));
+
+    let assert = ntk_isolated(client_home.path())
+        .env("NTK_SERVICE_AUTH_TOKEN", "e2e-token")
+        .args([
+            "--remote",
+            &service.endpoint,
+            "--output",
+            "json",
+            "task",
+            "watch",
+            &task_id,
+        ])
+        .assert()
+        .success();
+    let watched = json_stdout(&assert);
+    assert_eq!(watched["data"]["id"], task_id.as_str());
+    assert!(watched["data"]["audit_events"]
+        .as_array()
+        .is_some_and(|events| !events.is_empty()));
+}
+
+#[test]
+fn remote_task_commands_report_missing_credentials() {
+    let service_home = TempDir::new().expect("failed to create temp dir");
+    let client_home = TempDir::new().expect("failed to create temp dir");
+    let service = ServiceProcess::start(service_home.path());
+
+    let assert = ntk_isolated(client_home.path())
+        .env_remove("NTK_SERVICE_AUTH_TOKEN")
+        .args([
+            "--remote",
+            &service.endpoint,
+            "--output",
+            "json",
+            "task",
+            "list",
+        ])
+        .assert()
+        .code(1);
+    let document = json_stdout(&assert);
+    assert!(document["error"]["message"]
+        .as_str()
+        .is_some_and(|message| message.contains("HTTP 401")));
+}
+
+#[test]
+fn remote_task_submit_falls_back_to_local_when_service_is_unreachable() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("free port should be available")
+        .port();
+
+    let assert = ntk_isolated(home.path())
+        .args([
+            "--remote",
+            &format!("http://127.0.0.1:{port}"),
+            "--output",
+            "json",
+            "task",
+            "submit",
+            "ai-ask",
+            "offline",
+            "question",
+        ])
+        .assert()
+        .success();
+    let document = json_stdout(&assert);
+    assert_eq!(document["data"]["runtime_mode"], "cli");
+    assert_eq!(document["data"]["execution_target"], "local-fallback");
+}
+
+#[test]
+fn invalid_remote_endpoint_is_rejected() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    ntk_isolated(home.path())
+        .args(["--remote", "agent-box:8080", "task", "list"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("Invalid --remote endpoint"));
+}
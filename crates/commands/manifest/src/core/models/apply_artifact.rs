@@ -1,16 +1,16 @@
 //! Apply artifact configuration
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Apply artifact configuration
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ApplyArtifact {
     /// Artifact kind identifier.
     pub kind: String,
     /// Optional bounded-context scope.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub context: Option<String>,
     /// Optional artifact name override.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
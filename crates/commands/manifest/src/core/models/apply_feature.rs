@@ -1,14 +1,14 @@
 //! Apply feature configuration
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Apply feature configuration
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ApplyFeature {
     /// Optional bounded-context scope.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub context: Option<String>,
     /// Feature names to include.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub include: Vec<String>,
 }
@@ -1,11 +1,11 @@
 //! Apply layer configuration
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Apply layer configuration
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ApplyLayer {
     /// Layer names to include.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub include: Vec<String>,
 }
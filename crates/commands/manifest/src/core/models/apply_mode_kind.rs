@@ -1,9 +1,9 @@
 //! Apply mode enumeration
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Apply mode
-#[derive(Debug, Deserialize, Clone, Copy)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum ApplyModeKind {
     /// Apply a single artifact.
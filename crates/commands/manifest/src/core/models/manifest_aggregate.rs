@@ -5,26 +5,34 @@ use super::manifest_entity::ManifestEntity;
 use super::manifest_enum::ManifestEnum;
 use super::manifest_repository::ManifestRepository;
 use super::manifest_value_object::ManifestValueObject;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// DDD aggregate root
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestAggregate {
     /// Aggregate name.
     pub name: String,
     /// Value objects owned by this aggregate.
-    #[serde(default, rename = "valueObjects")]
+    #[serde(
+        default,
+        rename = "valueObjects",
+        skip_serializing_if = "Vec::is_empty"
+    )]
     pub value_objects: Vec<ManifestValueObject>,
     /// Child entities.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub entities: Vec<ManifestEntity>,
     /// Domain events raised by this aggregate.
-    #[serde(default, rename = "domainEvents")]
+    #[serde(
+        default,
+        rename = "domainEvents",
+        skip_serializing_if = "Vec::is_empty"
+    )]
     pub domain_events: Vec<ManifestDomainEvent>,
     /// Optional repository interface.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub repository: Option<ManifestRepository>,
     /// Enumerations scoped to this aggregate.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub enums: Vec<ManifestEnum>,
 }
@@ -4,20 +4,20 @@ use super::apply_artifact::ApplyArtifact;
 use super::apply_feature::ApplyFeature;
 use super::apply_layer::ApplyLayer;
 use super::apply_mode_kind::ApplyModeKind;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Apply configuration
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ManifestApply {
     /// Apply mode (artifact, feature, or layer).
     pub mode: ApplyModeKind,
     /// Artifact-specific configuration.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub artifact: Option<ApplyArtifact>,
     /// Feature-specific configuration.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub feature: Option<ApplyFeature>,
     /// Layer-specific configuration.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub layer: Option<ApplyLayer>,
 }
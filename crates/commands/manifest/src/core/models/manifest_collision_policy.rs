@@ -1,9 +1,9 @@
 //! File collision handling policy
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// File collision handling policy
-#[derive(Debug, Deserialize, Clone, Copy)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum ManifestCollisionPolicy {
     /// Abort on collision.
@@ -2,17 +2,17 @@
 
 use super::manifest_aggregate::ManifestAggregate;
 use super::manifest_use_case::ManifestUseCase;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// DDD bounded context
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestContext {
     /// Context name.
     pub name: String,
     /// Aggregates within this context.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub aggregates: Vec<ManifestAggregate>,
     /// Use cases within this context.
-    #[serde(default, rename = "useCases")]
+    #[serde(default, rename = "useCases", skip_serializing_if = "Vec::is_empty")]
     pub use_cases: Vec<ManifestUseCase>,
 }
@@ -1,10 +1,10 @@
 //! Naming and code generation conventions
 
 use super::manifest_policy::ManifestPolicy;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Naming and code generation conventions
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ManifestConventions {
     /// Root namespace for generated code.
     #[serde(rename = "namespaceRoot")]
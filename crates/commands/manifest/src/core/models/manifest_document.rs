@@ -10,11 +10,11 @@ use super::manifest_project::ManifestProject;
 use super::manifest_render::ManifestRender;
 use super::manifest_solution::ManifestSolution;
 use super::manifest_templates::ManifestTemplates;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 /// Manifest document root
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ManifestDocument {
     /// Manifest schema version.
     #[serde(rename = "apiVersion")]
@@ -31,10 +31,10 @@ pub struct ManifestDocument {
     #[serde(default)]
     pub guards: ManifestGuards,
     /// Project definitions keyed by identifier.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub projects: BTreeMap<String, ManifestProject>,
     /// DDD bounded contexts.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub contexts: Vec<ManifestContext>,
     /// Template mapping configuration.
     #[serde(default)]
@@ -1,9 +1,9 @@
 //! DDD domain event
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// DDD domain event
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestDomainEvent {
     /// Event name.
     pub name: String,
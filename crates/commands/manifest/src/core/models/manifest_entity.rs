@@ -1,14 +1,14 @@
 //! DDD entity
 
 use super::manifest_field::ManifestField;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// DDD entity
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestEntity {
     /// Entity name.
     pub name: String,
     /// Entity fields.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<ManifestField>,
 }
@@ -1,14 +1,14 @@
 //! Enum definition
 
 use super::manifest_enum_value::ManifestEnumValue;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Enum definition
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestEnum {
     /// Enum name.
     pub name: String,
     /// Enum members.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub values: Vec<ManifestEnumValue>,
 }
@@ -1,9 +1,9 @@
 //! Enum value
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Enum value
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestEnumValue {
     /// Member name.
     pub name: String,
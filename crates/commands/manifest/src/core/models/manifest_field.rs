@@ -1,9 +1,9 @@
 //! Field definition
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Field definition
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestField {
     /// Field name.
     pub name: String,
@@ -11,12 +11,16 @@ pub struct ManifestField {
     #[serde(rename = "type")]
     pub r#type: String,
     /// Whether this field is a primary key.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub key: bool,
     /// Whether this field allows null values.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub nullable: bool,
     /// Optional database column name override.
-    #[serde(default, rename = "columnName")]
+    #[serde(
+        default,
+        rename = "columnName",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub column_name: Option<String>,
 }
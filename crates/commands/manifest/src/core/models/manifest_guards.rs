@@ -1,15 +1,23 @@
 //! Guards for validation and safety checks
 
 use super::missing_project_action::MissingProjectAction;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Guards for validation and safety checks
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestGuards {
     /// Whether all declared projects must exist on disk.
-    #[serde(default, rename = "requireExistingProjects")]
+    #[serde(
+        default,
+        rename = "requireExistingProjects",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub require_existing_projects: bool,
     /// Action to take when a declared project is missing.
-    #[serde(default, rename = "onMissingProject")]
+    #[serde(
+        default,
+        rename = "onMissingProject",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub on_missing_project: Option<MissingProjectAction>,
 }
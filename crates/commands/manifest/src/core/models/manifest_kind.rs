@@ -1,9 +1,9 @@
 //! Manifest document kind enumeration
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Manifest kind (currently only Solution supported)
-#[derive(Debug, Deserialize, Clone, Copy)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum ManifestKind {
     /// Solution-level manifest.
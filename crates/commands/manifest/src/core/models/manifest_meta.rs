@@ -1,16 +1,16 @@
 //! Manifest metadata
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Manifest metadata
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ManifestMeta {
     /// Manifest name.
     pub name: String,
     /// Optional description.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// Optional author.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub author: Option<String>,
 }
@@ -1,9 +1,9 @@
 //! Method argument
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Method argument
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestMethodArgument {
     /// Argument name.
     pub name: String,
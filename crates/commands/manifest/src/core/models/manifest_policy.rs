@@ -1,18 +1,22 @@
 //! Code generation policies
 
 use super::manifest_collision_policy::ManifestCollisionPolicy;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Code generation policies
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestPolicy {
     /// File collision resolution policy.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub collision: Option<ManifestCollisionPolicy>,
     /// Insert TODO markers for missing code sections.
-    #[serde(default, rename = "insertTodoWhenMissing")]
+    #[serde(
+        default,
+        rename = "insertTodoWhenMissing",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub insert_todo_when_missing: bool,
     /// Enable strict validation mode.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub strict: bool,
 }
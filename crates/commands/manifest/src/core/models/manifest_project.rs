@@ -1,11 +1,11 @@
 //! Project definition
 
 use super::manifest_project_kind::ManifestProjectKind;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 /// Project definition
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ManifestProject {
     /// Project type/kind.
     #[serde(rename = "type")]
@@ -1,9 +1,9 @@
 //! Project kind/type enumeration
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Project kind/type
-#[derive(Debug, Deserialize, Clone, Copy, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum ManifestProjectKind {
     /// Domain layer project.
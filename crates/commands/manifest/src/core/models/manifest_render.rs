@@ -1,12 +1,12 @@
 //! Render rules configuration
 
 use super::render_rule::RenderRule;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Render rules configuration
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestRender {
     /// Collection of render rules.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub rules: Vec<RenderRule>,
 }
@@ -1,14 +1,14 @@
 //! Repository definition
 
 use super::manifest_repository_method::ManifestRepositoryMethod;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Repository definition
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestRepository {
     /// Repository name.
     pub name: String,
     /// Repository methods.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub methods: Vec<ManifestRepositoryMethod>,
 }
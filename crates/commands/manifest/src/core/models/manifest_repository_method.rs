@@ -1,17 +1,17 @@
 //! Repository method
 
 use super::manifest_method_argument::ManifestMethodArgument;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Repository method
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestRepositoryMethod {
     /// Method name.
     pub name: String,
     /// Method arguments.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub args: Vec<ManifestMethodArgument>,
     /// Optional return type.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub returns: Option<String>,
 }
@@ -1,10 +1,10 @@
 //! Solution configuration
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 /// Solution configuration
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ManifestSolution {
     /// Solution root directory path.
     pub root: PathBuf,
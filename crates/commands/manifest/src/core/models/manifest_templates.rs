@@ -2,14 +2,14 @@
 
 use super::artifact_kind::ArtifactKind;
 use super::template_mapping::TemplateMapping;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 /// Template mappings configuration
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestTemplates {
     /// Template-to-artifact mappings.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub mapping: Vec<TemplateMapping>,
 }
 
@@ -1,10 +1,10 @@
 //! Use case definition
 
 use super::manifest_field::ManifestField;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Use case definition
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestUseCase {
     /// Use case name.
     pub name: String,
@@ -12,9 +12,9 @@ pub struct ManifestUseCase {
     #[serde(rename = "type")]
     pub use_case_type: String,
     /// Input fields.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub input: Vec<ManifestField>,
     /// Output fields.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub output: Vec<ManifestField>,
 }
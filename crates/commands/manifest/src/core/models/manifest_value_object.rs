@@ -1,14 +1,14 @@
 //! DDD value object
 
 use super::manifest_field::ManifestField;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// DDD value object
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ManifestValueObject {
     /// Value object name.
     pub name: String,
     /// Value object fields.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<ManifestField>,
 }
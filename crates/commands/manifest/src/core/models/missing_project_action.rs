@@ -1,9 +1,9 @@
 //! Action to take when project is missing
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Action to take when project is missing
-#[derive(Debug, Deserialize, Clone, Copy)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum MissingProjectAction {
     /// Abort execution with an error.
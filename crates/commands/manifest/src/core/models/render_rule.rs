@@ -1,9 +1,9 @@
 //! Render rule definition
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Render rule definition
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct RenderRule {
     /// Expression to expand during rendering.
     pub expand: String,
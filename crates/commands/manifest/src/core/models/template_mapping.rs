@@ -1,9 +1,9 @@
 //! Template mapping definition
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Template mapping definition
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TemplateMapping {
     /// Artifact kind identifier.
     pub artifact: String,
@@ -0,0 +1,412 @@
+//! Manifest helpers for the AI assistant
+//!
+//! This module lets `/ai` work on the manifest model instead of generated code:
+//! - Locate the manifest nearest to the working directory
+//! - Render a compact summary of contexts, aggregates, use cases and templates
+//! - Apply a YAML merge patch proposed by the model and review it as a diff
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use nettoolskit_core::context_index::find_workspace_root;
+use nettoolskit_core::file_search::FileFilters;
+use serde_yaml::{Mapping, Value};
+
+use crate::core::models::{ManifestDocument, ManifestField};
+use crate::handlers::check::{check_manifest_str, ValidationResult};
+use crate::parsing::ManifestParser;
+
+/// Key that removes a named sequence item in a manifest patch.
+pub const PATCH_REMOVE_KEY: &str = "_remove";
+
+/// Find the manifest closest to `start`.
+///
+/// Directories are searched from `start` upwards to the workspace root (or only
+/// `start` outside a workspace). `ntk-*.yml` files win over other `*manifest*.yml`
+/// names, and files that do not parse as `ntk/v1` manifests are skipped.
+pub fn find_nearest_manifest(start: &Path) -> Option<(PathBuf, ManifestDocument)> {
+    let boundary = find_workspace_root(start);
+    for dir in start.ancestors() {
+        if let Some(found) = manifest_in_dir(dir) {
+            return Some(found);
+        }
+        if boundary.as_deref().is_none_or(|root| root == dir) {
+            break;
+        }
+    }
+    None
+}
+
+fn manifest_in_dir(dir: &Path) -> Option<(PathBuf, ManifestDocument)> {
+    let mut candidates = std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && is_manifest_candidate(path))
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|path| (!FileFilters::is_manifest(path), path.clone()));
+    candidates
+        .into_iter()
+        .find_map(|path| ManifestParser::from_file(&path).ok().map(|doc| (path, doc)))
+}
+
+fn is_manifest_candidate(path: &Path) -> bool {
+    if FileFilters::is_manifest(path) {
+        return true;
+    }
+    let has_yaml_ext = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext, "yml" | "yaml"));
+    has_yaml_ext
+        && path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.contains("manifest"))
+}
+
+/// Compact, line-oriented summary of the domain model and template mappings.
+pub fn summarize_manifest(manifest: &ManifestDocument) -> String {
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "manifest {} (kind: {}, apply: {})",
+        manifest.meta.name,
+        format!("{:?}", manifest.kind).to_lowercase(),
+        format!("{:?}", manifest.apply.mode).to_lowercase()
+    );
+    if let Some(description) = &manifest.meta.description {
+        let _ = write!(out, " — {}", description.trim());
+    }
+    out.push('\n');
+    let _ = writeln!(
+        out,
+        "namespaceRoot: {}, targetFramework: {}",
+        manifest.conventions.namespace_root, manifest.conventions.target_framework
+    );
+    if !manifest.projects.is_empty() {
+        let projects = manifest.projects.keys().cloned().collect::<Vec<_>>();
+        let _ = writeln!(out, "projects: {}", projects.join(", "));
+    }
+
+    out.push_str("contexts:\n");
+    if manifest.contexts.is_empty() {
+        out.push_str("  (none)\n");
+    }
+    for context in &manifest.contexts {
+        let _ = writeln!(out, "- {}", context.name);
+        for aggregate in &context.aggregates {
+            let mut parts = Vec::new();
+            if !aggregate.entities.is_empty() {
+                let entities = aggregate
+                    .entities
+                    .iter()
+                    .map(|entity| format!("{}({})", entity.name, summarize_fields(&entity.fields)))
+                    .collect::<Vec<_>>();
+                parts.push(format!("entities {}", entities.join(", ")));
+            }
+            if !aggregate.value_objects.is_empty() {
+                let value_objects = aggregate
+                    .value_objects
+                    .iter()
+                    .map(|vo| format!("{}({})", vo.name, summarize_fields(&vo.fields)))
+                    .collect::<Vec<_>>();
+                parts.push(format!("value objects {}", value_objects.join(", ")));
+            }
+            if !aggregate.domain_events.is_empty() {
+                let events = aggregate
+                    .domain_events
+                    .iter()
+                    .map(|event| event.name.as_str())
+                    .collect::<Vec<_>>();
+                parts.push(format!("events {}", events.join(", ")));
+            }
+            if let Some(repository) = &aggregate.repository {
+                parts.push(format!(
+                    "repository {} ({} methods)",
+                    repository.name,
+                    repository.methods.len()
+                ));
+            }
+            if !aggregate.enums.is_empty() {
+                let enums = aggregate
+                    .enums
+                    .iter()
+                    .map(|e| e.name.as_str())
+                    .collect::<Vec<_>>();
+                parts.push(format!("enums {}", enums.join(", ")));
+            }
+            let _ = writeln!(out, "  aggregate {}: {}", aggregate.name, parts.join("; "));
+        }
+        for use_case in &context.use_cases {
+            let _ = writeln!(
+                out,
+                "  use case {} [{}] in({}) out({})",
+                use_case.name,
+                use_case.use_case_type,
+                summarize_fields(&use_case.input),
+                summarize_fields(&use_case.output)
+            );
+        }
+    }
+
+    out.push_str("templates:\n");
+    if manifest.templates.mapping.is_empty() {
+        out.push_str("  (none)\n");
+    }
+    for mapping in &manifest.templates.mapping {
+        let _ = writeln!(
+            out,
+            "- {}: {} -> {}",
+            mapping.artifact, mapping.template, mapping.dst
+        );
+    }
+    out
+}
+
+fn summarize_fields(fields: &[ManifestField]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            let mut text = format!("{}: {}", field.name, field.r#type);
+            if field.nullable {
+                text.push('?');
+            }
+            if field.key {
+                text.push_str(" key");
+            }
+            text
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Pull the YAML patch out of a model response.
+///
+/// The first fenced block (```yaml, ```yml or a bare fence) wins; without a fence
+/// the whole response is taken as YAML. Returns `None` for empty responses.
+pub fn extract_yaml_patch(response: &str) -> Option<String> {
+    let mut inside = false;
+    let mut block = Vec::new();
+    for line in response.lines() {
+        let trimmed = line.trim_start();
+        if let Some(info) = trimmed.strip_prefix("```") {
+            if inside {
+                return Some(block.join("\n")).filter(|text| !text.trim().is_empty());
+            }
+            if matches!(info.trim(), "" | "yaml" | "yml") {
+                inside = true;
+            }
+            continue;
+        }
+        if inside {
+            block.push(line);
+        }
+    }
+    if inside {
+        return Some(block.join("\n")).filter(|text| !text.trim().is_empty());
+    }
+
+    let trimmed = response.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// Merge `patch` into `target`.
+///
+/// Mappings merge key by key and a `null` value removes the key. Sequences whose
+/// items all carry a `name` merge item by item: matching names merge recursively,
+/// new names are appended and an item with `_remove: true` is deleted. Any other
+/// value replaces the target.
+pub fn apply_manifest_patch(target: &mut Value, patch: Value) {
+    match (target, patch) {
+        (Value::Mapping(target), Value::Mapping(patch)) => merge_mapping(target, patch),
+        (Value::Sequence(target), Value::Sequence(patch))
+            if is_named_sequence(target) && is_named_sequence(&patch) =>
+        {
+            merge_named_sequence(target, patch);
+        }
+        (target, patch) => *target = patch,
+    }
+}
+
+fn merge_mapping(target: &mut Mapping, patch: Mapping) {
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(&key);
+            continue;
+        }
+        match target.get_mut(&key) {
+            Some(existing) => apply_manifest_patch(existing, value),
+            None => {
+                target.insert(key, value);
+            }
+        }
+    }
+}
+
+fn item_name(value: &Value) -> Option<&str> {
+    value.as_mapping()?.get("name")?.as_str()
+}
+
+fn is_named_sequence(items: &[Value]) -> bool {
+    !items.is_empty() && items.iter().all(|item| item_name(item).is_some())
+}
+
+fn merge_named_sequence(target: &mut Vec<Value>, patch: Vec<Value>) {
+    for mut item in patch {
+        let name = item_name(&item).unwrap_or_default().to_string();
+        let remove = item
+            .as_mapping_mut()
+            .and_then(|map| map.remove(PATCH_REMOVE_KEY))
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false);
+        let position = target
+            .iter()
+            .position(|existing| item_name(existing) == Some(name.as_str()));
+        match (position, remove) {
+            (Some(index), true) => {
+                target.remove(index);
+            }
+            (Some(index), false) => apply_manifest_patch(&mut target[index], item),
+            (None, true) => {}
+            (None, false) => target.push(item),
+        }
+    }
+}
+
+/// A proposed manifest change, normalized and validated but not written.
+#[derive(Debug)]
+pub struct ManifestPatchReview {
+    /// Original manifest, re-serialized so the diff only shows real changes.
+    pub before: String,
+    /// Manifest with the patch applied.
+    pub after: String,
+    /// `/manifest check` findings for the patched manifest.
+    pub validation: ValidationResult,
+}
+
+impl ManifestPatchReview {
+    /// `true` when the patch changes nothing.
+    pub fn is_unchanged(&self) -> bool {
+        self.before == self.after
+    }
+
+    /// Unified diff from `before` to `after`.
+    pub fn diff(&self, path: &str) -> String {
+        unified_diff(&self.before, &self.after, path)
+    }
+}
+
+/// Apply `patch_yaml` to `original_yaml` and validate the result.
+pub fn review_manifest_patch(
+    original_yaml: &str,
+    patch_yaml: &str,
+) -> Result<ManifestPatchReview, String> {
+    let mut document: Value = serde_yaml::from_str(original_yaml)
+        .map_err(|err| format!("current manifest is not valid YAML: {err}"))?;
+    let patch: Value = serde_yaml::from_str(patch_yaml)
+        .map_err(|err| format!("proposed patch is not valid YAML: {err}"))?;
+    if !patch.is_mapping() {
+        return Err("proposed patch must be a YAML mapping".to_string());
+    }
+
+    let before = serde_yaml::to_string(&document).map_err(|err| err.to_string())?;
+    apply_manifest_patch(&mut document, patch);
+    let after = serde_yaml::to_string(&document).map_err(|err| err.to_string())?;
+    let validation = check_manifest_str(&after);
+    Ok(ManifestPatchReview {
+        before,
+        after,
+        validation,
+    })
+}
+
+const DIFF_CONTEXT_LINES: usize = 3;
+
+/// Line-based unified diff (`a/<path>` → `b/<path>`); empty when the texts match.
+pub fn unified_diff(before: &str, after: &str, path: &str) -> String {
+    let old = before.lines().collect::<Vec<_>>();
+    let new = after.lines().collect::<Vec<_>>();
+
+    // Longest common subsequence table, filled from the end.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    // (tag, old index, new index) for every line of the edit script.
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push((' ', i, j));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push(('-', i, j));
+            i += 1;
+        } else {
+            ops.push(('+', i, j));
+            j += 1;
+        }
+    }
+
+    let changed = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (tag, _, _))| *tag != ' ')
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    if changed.is_empty() {
+        return String::new();
+    }
+
+    // Group changes whose context windows overlap into hunks.
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for index in changed {
+        let start = index.saturating_sub(DIFF_CONTEXT_LINES);
+        let end = (index + DIFF_CONTEXT_LINES + 1).min(ops.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut out = format!("--- a/{path}\n+++ b/{path}\n");
+    for (start, end) in hunks {
+        let slice = &ops[start..end];
+        let old_count = slice.iter().filter(|(tag, _, _)| *tag != '+').count();
+        let new_count = slice.iter().filter(|(tag, _, _)| *tag != '-').count();
+        let (_, old_start, new_start) = slice[0];
+        let _ = writeln!(
+            out,
+            "@@ -{},{} +{},{} @@",
+            hunk_start(old_start, old_count),
+            old_count,
+            hunk_start(new_start, new_count),
+            new_count
+        );
+        for (tag, old_index, new_index) in slice {
+            let line = if *tag == '+' {
+                new[*new_index]
+            } else {
+                old[*old_index]
+            };
+            let _ = writeln!(out, "{tag}{line}");
+        }
+    }
+    out
+}
+
+fn hunk_start(index: usize, count: usize) -> usize {
+    if count == 0 {
+        index
+    } else {
+        index + 1
+    }
+}
@@ -8,7 +8,7 @@ use anyhow::Result;
 use owo_colors::OwoColorize;
 use std::path::Path;
 
-use crate::core::models::ApplyModeKind;
+use crate::core::models::{ApplyModeKind, ManifestDocument};
 use crate::parsing::ManifestParser;
 
 /// Validation error details
@@ -103,25 +103,47 @@ pub async fn check_file(path: &Path, is_template: bool) -> Result<ValidationResu
         }
     };
 
+    validate_document(&manifest, &mut result);
+
+    Ok(result)
+}
+
+/// Check a parsed manifest with the same rules `/manifest check` applies to files.
+pub fn check_document(manifest: &ManifestDocument) -> ValidationResult {
+    let mut result = ValidationResult::default();
+    validate_document(manifest, &mut result);
+    result
+}
+
+/// Check manifest YAML text that has not been written to disk.
+pub fn check_manifest_str(content: &str) -> ValidationResult {
+    let mut result = ValidationResult::default();
+    match ManifestParser::parse_str(content) {
+        Ok(manifest) => validate_document(&manifest, &mut result),
+        Err(e) => {
+            let msg = e.to_string();
+            let line = extract_yaml_error_line(&msg);
+            result.push_error(line, msg);
+        }
+    }
+    result
+}
+
+// ── Private helpers ──────────────────────────────────────────────────────
+
+fn validate_document(manifest: &ManifestDocument, result: &mut ValidationResult) {
     // ── Structural validation (ManifestParser::validate) ───────────────
-    if let Err(e) = ManifestParser::validate(&manifest) {
+    if let Err(e) = ManifestParser::validate(manifest) {
         result.push_error(None, e.to_string());
         // Continue — collect as many issues as possible
     }
 
     // ── Constraint validation ──────────────────────────────────────────
-    validate_constraints(&manifest, &mut result);
-
-    Ok(result)
+    validate_constraints(manifest, result);
 }
 
-// ── Private helpers ──────────────────────────────────────────────────────
-
 /// Validate manifest constraints beyond basic schema.
-fn validate_constraints(
-    manifest: &crate::core::models::ManifestDocument,
-    result: &mut ValidationResult,
-) {
+fn validate_constraints(manifest: &ManifestDocument, result: &mut ValidationResult) {
     // ── meta ───────────────────────────────────────────────────────────
     if manifest.meta.name.trim().is_empty() {
         result.push_error(None, "meta.name is required and cannot be empty");
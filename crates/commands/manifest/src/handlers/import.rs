@@ -0,0 +1,862 @@
+//! Manifest import handler
+//!
+//! Reverse-engineers a draft [`ManifestDocument`] from an existing .NET
+//! codebase. C# sources are scanned with tree-sitter and classified using the
+//! shapes produced by `templates/dotnet/src/domain`:
+//!
+//! - records/classes deriving from `BaseEntity` (or another entity base) → entities
+//! - `record struct` / `ValueObjects` namespace → value objects
+//! - `I*Repository` interfaces → repository contracts (and aggregate roots)
+//! - `enum` declarations → enums
+//! - `IDomainEvent` / `Events` namespace → domain events
+//!
+//! Anything that cannot be classified is reported instead of guessed.
+
+use crate::core::error::{ManifestError, ManifestResult};
+use crate::core::models::{
+    ApplyFeature, ApplyModeKind, ManifestAggregate, ManifestApply, ManifestCollisionPolicy,
+    ManifestContext, ManifestConventions, ManifestDocument, ManifestDomainEvent, ManifestEntity,
+    ManifestEnum, ManifestEnumValue, ManifestField, ManifestGuards, ManifestKind, ManifestMeta,
+    ManifestMethodArgument, ManifestPolicy, ManifestProject, ManifestProjectKind, ManifestRender,
+    ManifestRepository, ManifestRepositoryMethod, ManifestSolution, ManifestTemplates,
+    ManifestValueObject, MissingProjectAction, RenderRule, TemplateMapping,
+};
+use crate::parsing::csharp::{scan_file, CSharpMember, CSharpTypeDeclaration, CSharpTypeKind};
+use nettoolskit_core::ExitStatus;
+use owo_colors::OwoColorize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Base types that mark a declaration as an entity.
+const ENTITY_BASE_TYPES: &[&str] = &[
+    "BaseEntity",
+    "Entity",
+    "IEntity",
+    "AuditableEntity",
+    "AggregateRoot",
+    "IAggregateRoot",
+];
+
+/// Base types that mark an entity as an aggregate root.
+const AGGREGATE_ROOT_BASE_TYPES: &[&str] = &["AggregateRoot", "IAggregateRoot"];
+
+/// Base types that mark a declaration as a value object.
+const VALUE_OBJECT_BASE_TYPES: &[&str] = &["ValueObject", "IValueObject"];
+
+/// Base types that mark a declaration as a domain event.
+const DOMAIN_EVENT_BASE_TYPES: &[&str] = &["DomainEvent", "IDomainEvent", "INotification"];
+
+/// Namespace segments used by the templates to group artifacts, never context names.
+const DOMAIN_FOLDER_SEGMENTS: &[&str] = &[
+    "Abstractions",
+    "Aggregates",
+    "Entities",
+    "Enums",
+    "Events",
+    "Helpers",
+    "Interfaces",
+    "Repositories",
+    "ValueObjects",
+];
+
+/// Directories skipped while scanning sources.
+const IGNORED_DIRECTORIES: &[&str] = &["bin", "obj", "node_modules", "packages", "target"];
+
+const DEFAULT_TARGET_FRAMEWORK: &str = "net8.0";
+
+/// A type declaration the importer could not map to a manifest artifact.
+#[derive(Debug, Clone)]
+pub struct UnclassifiedType {
+    /// Type name.
+    pub name: String,
+    /// Enclosing namespace.
+    pub namespace: String,
+    /// Source file.
+    pub file: PathBuf,
+    /// Why the type was not classified.
+    pub reason: String,
+}
+
+/// A source file or value the importer skipped instead of guessing.
+#[derive(Debug, Clone)]
+pub struct ImportWarning {
+    /// Source file the warning refers to.
+    pub file: PathBuf,
+    /// What was skipped and why.
+    pub message: String,
+}
+
+/// Result of importing a codebase into a draft manifest.
+#[derive(Debug)]
+pub struct ImportReport {
+    /// Draft manifest document.
+    pub document: ManifestDocument,
+    /// Number of `.cs` files scanned.
+    pub scanned_files: usize,
+    /// Types that were found but could not be classified.
+    pub unclassified: Vec<UnclassifiedType>,
+    /// Unreadable files and unresolved enum values left out of the draft.
+    pub warnings: Vec<ImportWarning>,
+}
+
+impl ImportReport {
+    /// Total number of classified artifacts across all contexts.
+    pub fn artifact_count(&self) -> usize {
+        self.document
+            .contexts
+            .iter()
+            .flat_map(|context| &context.aggregates)
+            .map(|aggregate| {
+                aggregate.entities.len()
+                    + aggregate.value_objects.len()
+                    + aggregate.enums.len()
+                    + aggregate.domain_events.len()
+                    + usize::from(aggregate.repository.is_some())
+            })
+            .sum()
+    }
+
+    /// Serialize the draft manifest to YAML.
+    pub fn to_yaml(&self) -> ManifestResult<String> {
+        let body = serde_yaml::to_string(&self.document)?;
+        let mut yaml = String::from(
+            "# Draft manifest generated by `ntk manifest import`.\n\
+             # Review contexts, aggregates and template mappings before applying.\n",
+        );
+        for item in &self.unclassified {
+            yaml.push_str(&format!(
+                "# unclassified: {} ({}) - {}\n",
+                item.name,
+                item.file.display(),
+                item.reason
+            ));
+        }
+        for warning in &self.warnings {
+            yaml.push_str(&format!(
+                "# warning: {} - {}\n",
+                warning.file.display(),
+                warning.message
+            ));
+        }
+        yaml.push_str(&body);
+        Ok(yaml)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Classification {
+    Entity { aggregate_root: bool },
+    ValueObject,
+    DomainEvent,
+    Enum,
+    Repository,
+    Abstraction,
+}
+
+#[derive(Default)]
+struct ContextDraft {
+    entities: Vec<(CSharpTypeDeclaration, bool)>,
+    value_objects: Vec<CSharpTypeDeclaration>,
+    enums: Vec<CSharpTypeDeclaration>,
+    events: Vec<CSharpTypeDeclaration>,
+    repositories: Vec<CSharpTypeDeclaration>,
+}
+
+/// Scan `root` for C# sources and build a draft manifest.
+pub fn import_directory(root: &Path) -> ManifestResult<ImportReport> {
+    if !root.is_dir() {
+        return Err(ManifestError::SolutionNotFound {
+            path: root.to_path_buf(),
+        });
+    }
+
+    let source_files = collect_files(root, "cs");
+    let mut declarations = Vec::new();
+    let mut warnings = Vec::new();
+    for file in &source_files {
+        match scan_file(file) {
+            Ok(found) => declarations.extend(found),
+            Err(err) => warnings.push(ImportWarning {
+                file: file.clone(),
+                message: format!("skipped: {err}"),
+            }),
+        }
+    }
+
+    let entity_bases = resolve_entity_bases(&declarations);
+    let abstractions: HashMap<String, &CSharpTypeDeclaration> = declarations
+        .iter()
+        .filter(|decl| decl.has_modifier("abstract") && entity_bases.contains(&decl.name))
+        .map(|decl| (decl.name.clone(), decl))
+        .collect();
+
+    let mut unclassified = Vec::new();
+    let mut namespace_roots: BTreeMap<String, usize> = BTreeMap::new();
+    let mut contexts: BTreeMap<String, ContextDraft> = BTreeMap::new();
+
+    for decl in &declarations {
+        let classification = match classify(decl, &entity_bases) {
+            Ok(classification) => classification,
+            Err(reason) => {
+                unclassified.push(unclassified_type(decl, reason));
+                continue;
+            }
+        };
+        if classification == Classification::Abstraction {
+            continue;
+        }
+
+        let (namespace_root, context_name) = split_namespace(&decl.namespace);
+        if let Some(namespace_root) = namespace_root {
+            *namespace_roots.entry(namespace_root).or_default() += 1;
+        }
+        let draft = contexts
+            .entry(context_name.unwrap_or_default())
+            .or_default();
+
+        match classification {
+            Classification::Entity { aggregate_root } => {
+                let mut entity = decl.clone();
+                inherit_key_members(&mut entity, &abstractions);
+                draft.entities.push((entity, aggregate_root));
+            }
+            Classification::ValueObject => draft.value_objects.push(decl.clone()),
+            Classification::DomainEvent => draft.events.push(decl.clone()),
+            Classification::Enum => draft.enums.push(decl.clone()),
+            Classification::Repository => draft.repositories.push(decl.clone()),
+            Classification::Abstraction => {}
+        }
+    }
+
+    let namespace_root = namespace_roots
+        .iter()
+        .max_by(|left, right| left.1.cmp(right.1).then_with(|| right.0.cmp(left.0)))
+        .map(|(root, _)| root.clone())
+        .unwrap_or_else(|| directory_name(root));
+    let default_context = namespace_root
+        .rsplit('.')
+        .next()
+        .unwrap_or(&namespace_root)
+        .to_string();
+
+    let mut manifest_contexts = Vec::new();
+    for (name, draft) in contexts {
+        let name = if name.is_empty() {
+            default_context.clone()
+        } else {
+            name
+        };
+        manifest_contexts.push(build_context(name, draft, &mut unclassified, &mut warnings));
+    }
+
+    let document = build_document(root, namespace_root, manifest_contexts);
+
+    Ok(ImportReport {
+        document,
+        scanned_files: source_files.len(),
+        unclassified,
+        warnings,
+    })
+}
+
+/// Execute `/manifest import`, printing the draft or writing it to `output`.
+pub fn execute_import(source_root: PathBuf, output: Option<PathBuf>) -> ExitStatus {
+    println!("Source root: {}", source_root.display());
+
+    let report = match import_directory(&source_root) {
+        Ok(report) => report,
+        Err(err) => {
+            println!("{} {err}", "✗ Manifest import failed:".red().bold());
+            return ExitStatus::Error;
+        }
+    };
+
+    println!(
+        "Scanned {} file(s), classified {} artifact(s) in {} context(s)",
+        report.scanned_files,
+        report.artifact_count(),
+        report.document.contexts.len()
+    );
+
+    if !report.unclassified.is_empty() {
+        println!();
+        println!(
+            "{}",
+            format!(
+                "⚠ {} type(s) could not be classified:",
+                report.unclassified.len()
+            )
+            .yellow()
+        );
+        for item in &report.unclassified {
+            println!(
+                "  - {} ({}): {}",
+                item.name,
+                item.file.display(),
+                item.reason
+            );
+        }
+    }
+
+    if !report.warnings.is_empty() {
+        println!();
+        println!(
+            "{}",
+            format!("⚠ {} item(s) left out of the draft:", report.warnings.len()).yellow()
+        );
+        for warning in &report.warnings {
+            println!("  - {}: {}", warning.file.display(), warning.message);
+        }
+    }
+
+    let yaml = match report.to_yaml() {
+        Ok(yaml) => yaml,
+        Err(err) => {
+            println!("{} {err}", "✗ Manifest serialization failed:".red().bold());
+            return ExitStatus::Error;
+        }
+    };
+
+    println!();
+    match output {
+        Some(path) => match std::fs::write(&path, yaml) {
+            Ok(()) => {
+                println!(
+                    "{} {}",
+                    "✓ Draft manifest written to".green(),
+                    path.display()
+                );
+                ExitStatus::Success
+            }
+            Err(err) => {
+                println!(
+                    "{} {}: {err}",
+                    "✗ Failed to write manifest".red().bold(),
+                    path.display()
+                );
+                ExitStatus::Error
+            }
+        },
+        None => {
+            println!("{yaml}");
+            ExitStatus::Success
+        }
+    }
+}
+
+fn collect_files(root: &Path, extension: &str) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|entry| {
+            if entry.depth() == 0 || !entry.file_type().is_dir() {
+                return true;
+            }
+            let name = entry.file_name().to_string_lossy();
+            !name.starts_with('.') && !IGNORED_DIRECTORIES.contains(&name.as_ref())
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some(extension))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Expand the known entity bases with abstract types declared in the sources.
+fn resolve_entity_bases(declarations: &[CSharpTypeDeclaration]) -> BTreeSet<String> {
+    let mut bases: BTreeSet<String> = ENTITY_BASE_TYPES.iter().map(|s| s.to_string()).collect();
+    loop {
+        let before = bases.len();
+        for decl in declarations {
+            if decl.has_modifier("abstract") && decl.base_types.iter().any(|b| bases.contains(b)) {
+                bases.insert(decl.name.clone());
+            }
+        }
+        if bases.len() == before {
+            return bases;
+        }
+    }
+}
+
+fn classify(
+    decl: &CSharpTypeDeclaration,
+    entity_bases: &BTreeSet<String>,
+) -> Result<Classification, String> {
+    let last_segment = decl.namespace.rsplit('.').next().unwrap_or_default();
+    let extends_entity = decl
+        .base_types
+        .iter()
+        .any(|base| entity_bases.contains(base));
+
+    match decl.kind {
+        CSharpTypeKind::Enum => Ok(Classification::Enum),
+        CSharpTypeKind::Interface => {
+            if repository_target(&decl.name).is_some() {
+                Ok(Classification::Repository)
+            } else if ENTITY_BASE_TYPES.contains(&decl.name.as_str())
+                || VALUE_OBJECT_BASE_TYPES.contains(&decl.name.as_str())
+                || DOMAIN_EVENT_BASE_TYPES.contains(&decl.name.as_str())
+            {
+                Ok(Classification::Abstraction)
+            } else {
+                Err("interface is not an `I*Repository` contract".to_string())
+            }
+        }
+        _ if entity_bases.contains(&decl.name) && decl.has_modifier("abstract") => {
+            Ok(Classification::Abstraction)
+        }
+        _ if decl.extends_any(DOMAIN_EVENT_BASE_TYPES) || last_segment == "Events" => {
+            Ok(Classification::DomainEvent)
+        }
+        _ if extends_entity => Ok(Classification::Entity {
+            aggregate_root: decl.extends_any(AGGREGATE_ROOT_BASE_TYPES),
+        }),
+        CSharpTypeKind::RecordStruct => Ok(Classification::ValueObject),
+        _ if decl.extends_any(VALUE_OBJECT_BASE_TYPES) || last_segment == "ValueObjects" => {
+            Ok(Classification::ValueObject)
+        }
+        _ => Err("no entity base class, value-object shape or domain namespace".to_string()),
+    }
+}
+
+fn unclassified_type(decl: &CSharpTypeDeclaration, reason: String) -> UnclassifiedType {
+    UnclassifiedType {
+        name: decl.name.clone(),
+        namespace: decl.namespace.clone(),
+        file: decl.file.clone(),
+        reason,
+    }
+}
+
+/// Resolved enum values; members without a literal value are reported, not guessed.
+fn enum_values(
+    enumeration: &CSharpTypeDeclaration,
+    warnings: &mut Vec<ImportWarning>,
+) -> Vec<ManifestEnumValue> {
+    let mut values = Vec::new();
+    for member in &enumeration.enum_members {
+        match member.value {
+            Some(value) => values.push(ManifestEnumValue {
+                name: member.name.clone(),
+                value,
+            }),
+            None => warnings.push(ImportWarning {
+                file: enumeration.file.clone(),
+                message: match &member.initializer {
+                    Some(initializer) => format!(
+                        "{}.{} = `{initializer}` is not an integer literal; add its value by hand",
+                        enumeration.name, member.name
+                    ),
+                    None => format!(
+                        "{}.{} follows an unresolved member; add its value by hand",
+                        enumeration.name, member.name
+                    ),
+                },
+            }),
+        }
+    }
+    values
+}
+
+/// `IOrderRepository` → `Order`.
+fn repository_target(name: &str) -> Option<&str> {
+    let target = name.strip_prefix('I')?.strip_suffix("Repository")?;
+    let starts_upper = target.chars().next().is_some_and(char::is_uppercase);
+    starts_upper.then_some(target)
+}
+
+/// Split `Acme.Sales.Domain.Orders.Entities` into (`Acme.Sales`, `Orders`).
+fn split_namespace(namespace: &str) -> (Option<String>, Option<String>) {
+    let segments: Vec<&str> = namespace.split('.').filter(|s| !s.is_empty()).collect();
+    let Some(domain_index) = segments.iter().position(|segment| *segment == "Domain") else {
+        return (None, None);
+    };
+    let root = (domain_index > 0).then(|| segments[..domain_index].join("."));
+    let context = segments
+        .get(domain_index + 1)
+        .filter(|segment| !DOMAIN_FOLDER_SEGMENTS.contains(segment))
+        .map(|segment| segment.to_string());
+    (root, context)
+}
+
+fn inherit_key_members(
+    entity: &mut CSharpTypeDeclaration,
+    abstractions: &HashMap<String, &CSharpTypeDeclaration>,
+) {
+    if entity.members.iter().any(is_key_member) {
+        return;
+    }
+    let mut pending: Vec<String> = entity.base_types.clone();
+    let mut visited = BTreeSet::new();
+    while let Some(base) = pending.pop() {
+        if !visited.insert(base.clone()) {
+            continue;
+        }
+        let Some(abstraction) = abstractions.get(&base) else {
+            continue;
+        };
+        let keys: Vec<CSharpMember> = abstraction
+            .members
+            .iter()
+            .filter(|member| is_key_member(member))
+            .cloned()
+            .collect();
+        if !keys.is_empty() {
+            entity.members.splice(0..0, keys);
+            return;
+        }
+        pending.extend(abstraction.base_types.iter().cloned());
+    }
+}
+
+fn is_key_member(member: &CSharpMember) -> bool {
+    member.name == "Id" || member.attributes.iter().any(|attr| attr == "Key")
+}
+
+fn to_field(member: &CSharpMember) -> ManifestField {
+    let (type_name, nullable) = match member.type_name.strip_suffix('?') {
+        Some(inner) => (inner.to_string(), true),
+        None => (member.type_name.clone(), false),
+    };
+    ManifestField {
+        name: member.name.clone(),
+        r#type: type_name,
+        key: is_key_member(member),
+        nullable,
+        column_name: member.column_name.clone(),
+    }
+}
+
+/// Type identifiers referenced by a declared type (`List<OrderLine>?` → `List`, `OrderLine`).
+fn referenced_types(type_name: &str) -> impl Iterator<Item = &str> {
+    type_name
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|segment| !segment.is_empty())
+}
+
+fn build_context(
+    name: String,
+    draft: ContextDraft,
+    unclassified: &mut Vec<UnclassifiedType>,
+    warnings: &mut Vec<ImportWarning>,
+) -> ManifestContext {
+    // Declaration order, so the imported manifest is stable across runs.
+    let repositories: Vec<(String, CSharpTypeDeclaration)> = draft
+        .repositories
+        .into_iter()
+        .filter_map(|repo| repository_target(&repo.name).map(|t| (t.to_string(), repo.clone())))
+        .collect();
+
+    // Aggregate roots: explicit `AggregateRoot` bases, or entities with a repository.
+    let mut aggregates: Vec<ManifestAggregate> = Vec::new();
+    let mut children = Vec::new();
+    for (entity, explicit_root) in draft.entities {
+        if explicit_root
+            || repositories
+                .iter()
+                .any(|(target, _)| target == &entity.name)
+        {
+            aggregates.push(ManifestAggregate {
+                name: entity.name.clone(),
+                entities: vec![to_entity(&entity)],
+                ..Default::default()
+            });
+        } else {
+            children.push(entity);
+        }
+    }
+
+    // Repositories whose entity lives elsewhere still produce an aggregate.
+    for (target, repository) in &repositories {
+        if !aggregates.iter().any(|aggregate| &aggregate.name == target) {
+            aggregates.push(ManifestAggregate {
+                name: target.clone(),
+                ..Default::default()
+            });
+        }
+        if let Some(aggregate) = aggregates.iter_mut().find(|a| &a.name == target) {
+            aggregate.repository = Some(to_repository(repository));
+        }
+    }
+
+    // Child entities join the aggregate that references them, or become their own root.
+    for entity in children {
+        match aggregate_referencing(&aggregates, &entity.name) {
+            Some(index) => aggregates[index].entities.push(to_entity(&entity)),
+            None => aggregates.push(ManifestAggregate {
+                name: entity.name.clone(),
+                entities: vec![to_entity(&entity)],
+                ..Default::default()
+            }),
+        }
+    }
+
+    if aggregates.is_empty()
+        && !(draft.value_objects.is_empty() && draft.enums.is_empty() && draft.events.is_empty())
+    {
+        aggregates.push(ManifestAggregate {
+            name: name.clone(),
+            ..Default::default()
+        });
+    }
+
+    for value_object in draft.value_objects {
+        match owning_aggregate(&aggregates, &value_object.name) {
+            Some(index) => aggregates[index].value_objects.push(ManifestValueObject {
+                name: value_object.name.clone(),
+                fields: value_object.members.iter().map(to_field).collect(),
+            }),
+            None => unclassified.push(unclassified_type(
+                &value_object,
+                "value object is not referenced by any aggregate".to_string(),
+            )),
+        }
+    }
+
+    for enumeration in draft.enums {
+        match owning_aggregate(&aggregates, &enumeration.name) {
+            Some(index) => aggregates[index].enums.push(ManifestEnum {
+                name: enumeration.name.clone(),
+                values: enum_values(&enumeration, warnings),
+            }),
+            None => unclassified.push(unclassified_type(
+                &enumeration,
+                "enum is not referenced by any aggregate".to_string(),
+            )),
+        }
+    }
+
+    for event in draft.events {
+        let owner = aggregates
+            .iter()
+            .enumerate()
+            .filter(|(_, aggregate)| event.name.starts_with(&aggregate.name))
+            .max_by_key(|(_, aggregate)| aggregate.name.len())
+            .map(|(index, _)| index)
+            .or_else(|| (aggregates.len() == 1).then_some(0));
+        match owner {
+            Some(index) => aggregates[index].domain_events.push(ManifestDomainEvent {
+                name: event.name.clone(),
+            }),
+            None => unclassified.push(unclassified_type(
+                &event,
+                "domain event does not match any aggregate name".to_string(),
+            )),
+        }
+    }
+
+    ManifestContext {
+        name,
+        aggregates,
+        use_cases: Vec::new(),
+    }
+}
+
+fn aggregate_referencing(aggregates: &[ManifestAggregate], type_name: &str) -> Option<usize> {
+    aggregates.iter().position(|aggregate| {
+        aggregate
+            .entities
+            .iter()
+            .flat_map(|entity| &entity.fields)
+            .chain(aggregate.value_objects.iter().flat_map(|vo| &vo.fields))
+            .any(|field| referenced_types(&field.r#type).any(|t| t == type_name))
+    })
+}
+
+fn owning_aggregate(aggregates: &[ManifestAggregate], type_name: &str) -> Option<usize> {
+    aggregate_referencing(aggregates, type_name).or_else(|| (aggregates.len() == 1).then_some(0))
+}
+
+fn to_entity(decl: &CSharpTypeDeclaration) -> ManifestEntity {
+    ManifestEntity {
+        name: decl.name.clone(),
+        fields: decl.members.iter().map(to_field).collect(),
+    }
+}
+
+fn to_repository(decl: &CSharpTypeDeclaration) -> ManifestRepository {
+    ManifestRepository {
+        name: decl.name.clone(),
+        methods: decl
+            .methods
+            .iter()
+            .map(|method| ManifestRepositoryMethod {
+                name: method.name.clone(),
+                args: method
+                    .parameters
+                    .iter()
+                    .map(|param| ManifestMethodArgument {
+                        name: param.name.clone(),
+                        r#type: param.type_name.clone(),
+                    })
+                    .collect(),
+                returns: (method.returns != "void").then(|| method.returns.clone()),
+            })
+            .collect(),
+    }
+}
+
+fn directory_name(root: &Path) -> String {
+    root.canonicalize()
+        .ok()
+        .as_deref()
+        .unwrap_or(root)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "Imported".to_string())
+}
+
+fn project_kind_for(name: &str) -> ManifestProjectKind {
+    let suffix = name.rsplit('.').next().unwrap_or(name).to_ascii_lowercase();
+    match suffix.as_str() {
+        "domain" => ManifestProjectKind::Domain,
+        "application" => ManifestProjectKind::Application,
+        "infrastructure" => ManifestProjectKind::Infrastructure,
+        "api" | "webapi" => ManifestProjectKind::Api,
+        "worker" => ManifestProjectKind::Worker,
+        _ => ManifestProjectKind::Unknown,
+    }
+}
+
+fn read_target_framework(csproj: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(csproj).ok()?;
+    let start = content.find("<TargetFramework>")? + "<TargetFramework>".len();
+    let end = content[start..].find("</TargetFramework>")? + start;
+    let value = content[start..end].trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn build_document(
+    root: &Path,
+    namespace_root: String,
+    contexts: Vec<ManifestContext>,
+) -> ManifestDocument {
+    let mut projects = BTreeMap::new();
+    let mut target_framework = None;
+    for csproj in collect_files(root, "csproj") {
+        let Some(name) = csproj.file_stem().map(|s| s.to_string_lossy().to_string()) else {
+            continue;
+        };
+        if target_framework.is_none() {
+            target_framework = read_target_framework(&csproj);
+        }
+        let path = csproj
+            .parent()
+            .and_then(|dir| dir.strip_prefix(root).ok())
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let kind = project_kind_for(&name);
+        let key = match kind {
+            ManifestProjectKind::Unknown => name.clone(),
+            _ => format!("{kind:?}"),
+        };
+        projects
+            .entry(key)
+            .or_insert(ManifestProject { kind, name, path });
+    }
+
+    let sln_file = collect_files(root, "sln")
+        .into_iter()
+        .next()
+        .and_then(|path| path.strip_prefix(root).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| PathBuf::from(format!("{namespace_root}.sln")));
+
+    let domain_dir = projects
+        .values()
+        .find(|project| matches!(project.kind, ManifestProjectKind::Domain))
+        .map(|project| project.path.clone())
+        .unwrap_or_else(|| PathBuf::from(format!("{namespace_root}.Domain")));
+    let domain_dst = root.join(domain_dir).to_string_lossy().replace('\\', "/");
+
+    let mapping = [
+        (
+            "value-object",
+            "dotnet/src/domain/value-object.hbs",
+            "ValueObjects",
+        ),
+        (
+            "entity",
+            "dotnet/src/domain/Entities/entity.hbs",
+            "Entities",
+        ),
+        (
+            "domain-event",
+            "dotnet/src/domain/domain-event.hbs",
+            "Events",
+        ),
+        (
+            "repository-interface",
+            "dotnet/src/domain/repository-interface.hbs",
+            "Repositories",
+        ),
+        ("enum", "dotnet/src/domain/Enums/enum.hbs", "Enums"),
+    ]
+    .into_iter()
+    .map(|(artifact, template, folder)| TemplateMapping {
+        artifact: artifact.to_string(),
+        template: template.to_string(),
+        dst: format!("{domain_dst}/{folder}/{{Name}}.cs"),
+    })
+    .collect();
+
+    let rules = [
+        ("contexts[*].aggregates[*].valueObjects[*]", "value-object"),
+        ("contexts[*].aggregates[*].entities[*]", "entity"),
+        ("contexts[*].aggregates[*].domainEvents[*]", "domain-event"),
+        (
+            "contexts[*].aggregates[*].repository",
+            "repository-interface",
+        ),
+        ("contexts[*].aggregates[*].enums[*]", "enum"),
+    ]
+    .into_iter()
+    .map(|(expand, alias)| RenderRule {
+        expand: expand.to_string(),
+        alias: alias.to_string(),
+    })
+    .collect();
+
+    ManifestDocument {
+        api_version: "ntk/v1".to_string(),
+        kind: ManifestKind::Solution,
+        meta: ManifestMeta {
+            name: namespace_root.clone(),
+            description: Some(format!("Draft manifest imported from {}", root.display())),
+            author: None,
+        },
+        conventions: ManifestConventions {
+            namespace_root,
+            target_framework: target_framework
+                .unwrap_or_else(|| DEFAULT_TARGET_FRAMEWORK.to_string()),
+            policy: ManifestPolicy {
+                collision: Some(ManifestCollisionPolicy::Fail),
+                insert_todo_when_missing: true,
+                strict: false,
+            },
+        },
+        solution: ManifestSolution {
+            root: root.to_path_buf(),
+            sln_file,
+        },
+        guards: ManifestGuards {
+            require_existing_projects: false,
+            on_missing_project: Some(MissingProjectAction::Skip),
+        },
+        projects,
+        contexts,
+        templates: ManifestTemplates { mapping },
+        render: ManifestRender { rules },
+        apply: ManifestApply {
+            mode: ApplyModeKind::Feature,
+            artifact: None,
+            feature: Some(ApplyFeature {
+                context: None,
+                include: vec!["domain".to_string()],
+            }),
+            layer: None,
+        },
+    }
+}
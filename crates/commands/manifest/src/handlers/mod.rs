@@ -4,7 +4,17 @@
 
 /// Apply manifest handler.
 pub mod apply;
+/// Manifest summaries and patch review for the AI assistant.
+pub mod assist;
 pub mod check;
+/// Manifest import (reverse-engineering) handler.
+pub mod import;
 
 pub use apply::execute_apply;
-pub use check::{check_file, ValidationError, ValidationResult};
+pub use assist::{
+    find_nearest_manifest, review_manifest_patch, summarize_manifest, ManifestPatchReview,
+};
+pub use check::{
+    check_document, check_file, check_manifest_str, ValidationError, ValidationResult,
+};
+pub use import::{execute_import, import_directory, ImportReport, ImportWarning, UnclassifiedType};
@@ -54,7 +54,7 @@ pub mod ui;
 // Public API — externally consumed types
 pub use core::{ManifestError, ManifestResult};
 pub use execution::{ExecutionConfig, ManifestExecutor};
-pub use handlers::execute_apply;
+pub use handlers::{execute_apply, execute_import};
 pub use models::ManifestAction;
 pub use parsing::ManifestParser;
 pub use ui::{show_apply_menu, show_menu};
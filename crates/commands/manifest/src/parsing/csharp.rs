@@ -0,0 +1,414 @@
+//! C# source scanning
+//!
+//! Extracts type declarations (classes, records, structs, interfaces and enums)
+//! from C# sources using the tree-sitter grammar. The result is a flat,
+//! language-level view of each declaration; domain classification happens in
+//! [`crate::handlers::import`].
+
+use crate::core::error::{ManifestError, ManifestResult};
+use std::path::{Path, PathBuf};
+use tree_sitter::{Node, Parser};
+
+/// Syntactic kind of a scanned C# type declaration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CSharpTypeKind {
+    /// `class` declaration.
+    Class,
+    /// `record` or `record class` declaration.
+    Record,
+    /// `record struct` declaration.
+    RecordStruct,
+    /// `struct` declaration.
+    Struct,
+    /// `interface` declaration.
+    Interface,
+    /// `enum` declaration.
+    Enum,
+}
+
+/// Property or primary-constructor parameter of a scanned type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CSharpMember {
+    /// Member name.
+    pub name: String,
+    /// Declared type as written in source (including `?` when nullable).
+    pub type_name: String,
+    /// Raw attribute names applied to the member (e.g. `Key`, `Column`).
+    pub attributes: Vec<String>,
+    /// Column name from a `[Column("...")]` attribute, when present.
+    pub column_name: Option<String>,
+}
+
+/// Method signature declared on a scanned type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CSharpMethod {
+    /// Method name.
+    pub name: String,
+    /// Declared return type.
+    pub returns: String,
+    /// Declared parameters, in order.
+    pub parameters: Vec<CSharpMember>,
+}
+
+/// Enum member with its resolved numeric value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CSharpEnumMember {
+    /// Member name.
+    pub name: String,
+    /// Explicit or implicit numeric value; `None` when the initializer (or the
+    /// one an implicit value counts up from) is not an integer literal.
+    pub value: Option<i32>,
+    /// Raw initializer text when one was written.
+    pub initializer: Option<String>,
+}
+
+/// Type declaration extracted from a C# source file.
+#[derive(Debug, Clone)]
+pub struct CSharpTypeDeclaration {
+    /// Type name (without generic parameters).
+    pub name: String,
+    /// Enclosing namespace, empty for the global namespace.
+    pub namespace: String,
+    /// Declaration kind.
+    pub kind: CSharpTypeKind,
+    /// Modifiers such as `public`, `abstract`, `sealed`, `readonly`.
+    pub modifiers: Vec<String>,
+    /// Base types and implemented interfaces (generic arguments stripped).
+    pub base_types: Vec<String>,
+    /// Primary-constructor parameters followed by instance properties.
+    pub members: Vec<CSharpMember>,
+    /// Method signatures (interfaces and classes).
+    pub methods: Vec<CSharpMethod>,
+    /// Enum members (enums only).
+    pub enum_members: Vec<CSharpEnumMember>,
+    /// Source file the declaration was found in.
+    pub file: PathBuf,
+}
+
+impl CSharpTypeDeclaration {
+    /// Returns `true` when the declaration carries the given modifier.
+    pub fn has_modifier(&self, modifier: &str) -> bool {
+        self.modifiers.iter().any(|m| m == modifier)
+    }
+
+    /// Returns `true` when any base type matches one of `names`.
+    pub fn extends_any(&self, names: &[&str]) -> bool {
+        self.base_types
+            .iter()
+            .any(|base| names.iter().any(|name| base == name))
+    }
+}
+
+/// Parse a single C# source text into type declarations.
+pub fn scan_source(source: &str, file: &Path) -> ManifestResult<Vec<CSharpTypeDeclaration>> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_c_sharp::language())
+        .map_err(|err| ManifestError::Other(format!("failed to load C# grammar: {err}")))?;
+    let tree = parser.parse(source, None).ok_or_else(|| {
+        ManifestError::Other(format!("failed to parse C# source: {}", file.display()))
+    })?;
+
+    let mut declarations = Vec::new();
+    let mut scanner = Scanner {
+        source: source.as_bytes(),
+        file,
+        namespace: String::new(),
+        declarations: &mut declarations,
+    };
+    scanner.visit(tree.root_node());
+    Ok(declarations)
+}
+
+/// Parse a C# source file into type declarations.
+pub fn scan_file(path: &Path) -> ManifestResult<Vec<CSharpTypeDeclaration>> {
+    let source = std::fs::read_to_string(path).map_err(|err| ManifestError::ReadError {
+        path: path.display().to_string(),
+        source: err,
+    })?;
+    scan_source(&source, path)
+}
+
+struct Scanner<'a> {
+    source: &'a [u8],
+    file: &'a Path,
+    namespace: String,
+    declarations: &'a mut Vec<CSharpTypeDeclaration>,
+}
+
+impl Scanner<'_> {
+    fn text(&self, node: Node<'_>) -> String {
+        node.utf8_text(self.source).unwrap_or_default().to_string()
+    }
+
+    fn visit(&mut self, node: Node<'_>) {
+        match node.kind() {
+            "file_scoped_namespace_declaration" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    self.namespace = self.text(name);
+                }
+                self.visit_children(node);
+            }
+            "namespace_declaration" => {
+                let previous = self.namespace.clone();
+                if let Some(name) = node.child_by_field_name("name") {
+                    let name = self.text(name);
+                    self.namespace = if previous.is_empty() {
+                        name
+                    } else {
+                        format!("{previous}.{name}")
+                    };
+                }
+                self.visit_children(node);
+                self.namespace = previous;
+            }
+            "class_declaration" => self.push_type(node, CSharpTypeKind::Class),
+            "struct_declaration" => self.push_type(node, CSharpTypeKind::Struct),
+            "interface_declaration" => self.push_type(node, CSharpTypeKind::Interface),
+            "enum_declaration" => self.push_type(node, CSharpTypeKind::Enum),
+            "record_declaration" | "record_struct_declaration" => {
+                let mut cursor = node.walk();
+                let is_struct = node.kind() == "record_struct_declaration"
+                    || node.children(&mut cursor).any(|c| c.kind() == "struct");
+                let kind = if is_struct {
+                    CSharpTypeKind::RecordStruct
+                } else {
+                    CSharpTypeKind::Record
+                };
+                self.push_type(node, kind);
+            }
+            _ => self.visit_children(node),
+        }
+    }
+
+    fn visit_children(&mut self, node: Node<'_>) {
+        let mut cursor = node.walk();
+        let children: Vec<Node<'_>> = node.named_children(&mut cursor).collect();
+        for child in children {
+            self.visit(child);
+        }
+    }
+
+    fn push_type(&mut self, node: Node<'_>, kind: CSharpTypeKind) {
+        let Some(name_node) = node.child_by_field_name("name") else {
+            return;
+        };
+        let mut declaration = CSharpTypeDeclaration {
+            name: self.text(name_node),
+            namespace: self.namespace.clone(),
+            kind,
+            modifiers: Vec::new(),
+            base_types: Vec::new(),
+            members: Vec::new(),
+            methods: Vec::new(),
+            enum_members: Vec::new(),
+            file: self.file.to_path_buf(),
+        };
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                "modifier" | "readonly" | "sealed" | "abstract" | "static" | "partial" => {
+                    declaration.modifiers.push(self.text(child));
+                }
+                "base_list" => declaration.base_types = self.base_types(child),
+                "parameter_list" => {
+                    declaration.members.extend(self.parameters(child));
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(body) = node.child_by_field_name("body") {
+            if kind == CSharpTypeKind::Enum {
+                declaration.enum_members = self.enum_members(body);
+            } else {
+                self.collect_body(body, &mut declaration);
+            }
+        }
+
+        self.declarations.push(declaration);
+
+        // Nested types are reported as independent declarations.
+        if let Some(body) = node.child_by_field_name("body") {
+            let mut cursor = body.walk();
+            let nested: Vec<Node<'_>> = body
+                .named_children(&mut cursor)
+                .filter(|child| {
+                    matches!(
+                        child.kind(),
+                        "class_declaration"
+                            | "struct_declaration"
+                            | "interface_declaration"
+                            | "enum_declaration"
+                            | "record_declaration"
+                            | "record_struct_declaration"
+                    )
+                })
+                .collect();
+            for child in nested {
+                self.visit(child);
+            }
+        }
+    }
+
+    fn base_types(&self, node: Node<'_>) -> Vec<String> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor)
+            .filter(|child| child.kind() != "argument_list")
+            .map(|child| strip_generic_arguments(&self.text(child)))
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    fn parameters(&self, node: Node<'_>) -> Vec<CSharpMember> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor)
+            .filter(|child| child.kind() == "parameter")
+            .filter_map(|param| {
+                let name = param.child_by_field_name("name")?;
+                let type_name = param.child_by_field_name("type")?;
+                let (attributes, column_name) = self.attributes(param);
+                Some(CSharpMember {
+                    name: self.text(name),
+                    type_name: self.text(type_name),
+                    attributes,
+                    column_name,
+                })
+            })
+            .collect()
+    }
+
+    fn collect_body(&self, body: Node<'_>, declaration: &mut CSharpTypeDeclaration) {
+        let mut cursor = body.walk();
+        for child in body.named_children(&mut cursor) {
+            match child.kind() {
+                "property_declaration" => {
+                    if self.has_child_modifier(child, "static") {
+                        continue;
+                    }
+                    let (Some(name), Some(type_name)) = (
+                        child.child_by_field_name("name"),
+                        child.child_by_field_name("type"),
+                    ) else {
+                        continue;
+                    };
+                    let (attributes, column_name) = self.attributes(child);
+                    declaration.members.push(CSharpMember {
+                        name: self.text(name),
+                        type_name: self.text(type_name),
+                        attributes,
+                        column_name,
+                    });
+                }
+                "method_declaration" => {
+                    if self.has_child_modifier(child, "static") {
+                        continue;
+                    }
+                    let (Some(name), Some(returns)) = (
+                        child.child_by_field_name("name"),
+                        child.child_by_field_name("returns"),
+                    ) else {
+                        continue;
+                    };
+                    let parameters = child
+                        .child_by_field_name("parameters")
+                        .map(|params| self.parameters(params))
+                        .unwrap_or_default();
+                    declaration.methods.push(CSharpMethod {
+                        name: self.text(name),
+                        returns: self.text(returns),
+                        parameters,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn enum_members(&self, body: Node<'_>) -> Vec<CSharpEnumMember> {
+        let mut members = Vec::new();
+        let mut next_value = Some(0i32);
+        let mut cursor = body.walk();
+        for child in body.named_children(&mut cursor) {
+            if child.kind() != "enum_member_declaration" {
+                continue;
+            }
+            let Some(name) = child.child_by_field_name("name") else {
+                continue;
+            };
+            let initializer = child
+                .child_by_field_name("value")
+                .map(|value| self.text(value));
+            let value = match &initializer {
+                Some(text) => parse_integer_literal(text),
+                None => next_value,
+            };
+            next_value = value.map(|value| value.saturating_add(1));
+            members.push(CSharpEnumMember {
+                name: self.text(name),
+                value,
+                initializer,
+            });
+        }
+        members
+    }
+
+    fn has_child_modifier(&self, node: Node<'_>, modifier: &str) -> bool {
+        let mut cursor = node.walk();
+        let found = node
+            .children(&mut cursor)
+            .any(|child| child.kind() == "modifier" && self.text(child) == modifier);
+        found
+    }
+
+    fn attributes(&self, node: Node<'_>) -> (Vec<String>, Option<String>) {
+        let mut names = Vec::new();
+        let mut column_name = None;
+        let mut cursor = node.walk();
+        for list in node.named_children(&mut cursor) {
+            if list.kind() != "attribute_list" {
+                continue;
+            }
+            let mut list_cursor = list.walk();
+            for attribute in list.named_children(&mut list_cursor) {
+                if attribute.kind() != "attribute" {
+                    continue;
+                }
+                let Some(name) = attribute.child_by_field_name("name") else {
+                    continue;
+                };
+                let name = self.text(name);
+                let short = name.rsplit('.').next().unwrap_or(&name).to_string();
+                if short == "Column" || short == "ColumnAttribute" {
+                    let raw = self.text(attribute);
+                    column_name = raw
+                        .split('"')
+                        .nth(1)
+                        .map(str::to_string)
+                        .filter(|value| !value.is_empty());
+                }
+                names.push(short);
+            }
+        }
+        (names, column_name)
+    }
+}
+
+fn strip_generic_arguments(name: &str) -> String {
+    let name = name.split('(').next().unwrap_or(name);
+    let name = name.split('<').next().unwrap_or(name);
+    let name = name.trim();
+    name.rsplit('.').next().unwrap_or(name).to_string()
+}
+
+fn parse_integer_literal(value: &str) -> Option<i32> {
+    let value = value.trim().replace('_', "");
+    if let Some(hex) = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        return i32::from_str_radix(hex, 16).ok();
+    }
+    value.parse::<i32>().ok()
+}
@@ -3,6 +3,8 @@
 //! This module provides functionality to parse YAML manifest files
 //! into strongly-typed Rust structures.
 
+/// C# source scanning used by manifest import.
+pub mod csharp;
 /// YAML manifest parser implementation.
 pub mod parser;
 
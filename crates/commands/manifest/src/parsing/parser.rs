@@ -14,7 +14,12 @@ impl ManifestParser {
             source: err,
         })?;
 
-        let manifest: ManifestDocument = serde_yaml::from_str(&content)?;
+        Self::parse_str(&content)
+    }
+
+    /// Parse manifest from YAML text
+    pub fn parse_str(content: &str) -> ManifestResult<ManifestDocument> {
+        let manifest: ManifestDocument = serde_yaml::from_str(content)?;
 
         // Validate API version
         if manifest.api_version != "ntk/v1" {
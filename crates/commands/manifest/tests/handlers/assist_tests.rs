@@ -0,0 +1,189 @@
+//! Assist handler tests
+//!
+//! Tests for manifest summaries, nearest-manifest discovery and patch review.
+//! Category: Unit
+
+use nettoolskit_manifest::handlers::assist::{
+    apply_manifest_patch, extract_yaml_patch, find_nearest_manifest, review_manifest_patch,
+    summarize_manifest, unified_diff,
+};
+use nettoolskit_manifest::ManifestParser;
+use std::fs;
+use tempfile::TempDir;
+
+// ── Helpers ────────────────────────────────────────────────────────────────
+
+fn orders_manifest_yaml() -> &'static str {
+    r#"apiVersion: ntk/v1
+kind: solution
+meta:
+  name: orders
+solution:
+  root: ./
+  slnFile: Orders.sln
+conventions:
+  namespaceRoot: Acme.Orders
+  targetFramework: net9.0
+  policy:
+    collision: fail
+    insertTodoWhenMissing: true
+    strict: false
+apply:
+  mode: artifact
+  artifact:
+    kind: Entity
+contexts:
+  - name: Sales
+    aggregates:
+      - name: Order
+        entities:
+          - name: OrderLine
+            fields:
+              - name: Id
+                type: Guid
+                key: true
+              - name: Quantity
+                type: int
+        domainEvents:
+          - name: OrderPlaced
+    useCases:
+      - name: PlaceOrder
+        type: command
+        input:
+          - name: CustomerId
+            type: Guid
+templates:
+  mapping:
+    - artifact: Entity
+      template: entity.hbs
+      dst: "{{project}}/Entities/{{Name}}.cs"
+"#
+}
+
+// ── Summary ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_summarize_manifest_lists_domain_model_and_templates() {
+    let manifest = ManifestParser::parse_str(orders_manifest_yaml()).unwrap();
+    let summary = summarize_manifest(&manifest);
+
+    assert!(summary.starts_with("manifest orders (kind: solution, apply: artifact)"));
+    assert!(summary.contains("- Sales\n"));
+    assert!(summary.contains(
+        "  aggregate Order: entities OrderLine(Id: Guid key, Quantity: int); events OrderPlaced"
+    ));
+    assert!(summary.contains("  use case PlaceOrder [command] in(CustomerId: Guid) out()"));
+    assert!(summary.contains("- Entity: entity.hbs -> {{project}}/Entities/{{Name}}.cs"));
+}
+
+// ── Discovery ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_find_nearest_manifest_walks_up_to_workspace_root() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join(".git")).unwrap();
+    fs::write(dir.path().join("ntk-orders.yml"), orders_manifest_yaml()).unwrap();
+    fs::write(
+        dir.path().join("deploy-manifest.yaml"),
+        "kind: Deployment\n",
+    )
+    .unwrap();
+    let nested = dir.path().join("src").join("Orders.Api");
+    fs::create_dir_all(&nested).unwrap();
+
+    let (path, manifest) = find_nearest_manifest(&nested).expect("manifest found");
+    assert_eq!(path, dir.path().join("ntk-orders.yml"));
+    assert_eq!(manifest.meta.name, "orders");
+}
+
+#[test]
+fn test_find_nearest_manifest_skips_non_ntk_yaml() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join(".git")).unwrap();
+    fs::write(dir.path().join("k8s-manifest.yaml"), "kind: Deployment\n").unwrap();
+
+    assert!(find_nearest_manifest(dir.path()).is_none());
+}
+
+// ── Patch extraction and merge ─────────────────────────────────────────────
+
+#[test]
+fn test_extract_yaml_patch_prefers_fenced_block() {
+    let response = "Here is the patch:\n```yaml\nmeta:\n  description: Orders\n```\nDone.";
+    assert_eq!(
+        extract_yaml_patch(response).as_deref(),
+        Some("meta:\n  description: Orders")
+    );
+    assert_eq!(
+        extract_yaml_patch("meta:\n  name: x\n").as_deref(),
+        Some("meta:\n  name: x")
+    );
+    assert_eq!(extract_yaml_patch("  \n"), None);
+}
+
+#[test]
+fn test_apply_manifest_patch_merges_named_items() {
+    let mut target: serde_yaml::Value = serde_yaml::from_str(
+        "contexts:\n  - name: Sales\n    aggregates:\n      - name: Order\n  - name: Billing\nmeta:\n  name: x\n  author: me\n",
+    )
+    .unwrap();
+    let patch: serde_yaml::Value = serde_yaml::from_str(
+        "contexts:\n  - name: Sales\n    aggregates:\n      - name: Customer\n  - name: Billing\n    _remove: true\nmeta:\n  author: null\n",
+    )
+    .unwrap();
+
+    apply_manifest_patch(&mut target, patch);
+
+    let expected: serde_yaml::Value = serde_yaml::from_str(
+        "contexts:\n  - name: Sales\n    aggregates:\n      - name: Order\n      - name: Customer\nmeta:\n  name: x\n",
+    )
+    .unwrap();
+    assert_eq!(target, expected);
+}
+
+// ── Review ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_review_manifest_patch_adds_aggregate_and_validates() {
+    let patch = "contexts:\n  - name: Sales\n    aggregates:\n      - name: Customer\n        entities:\n          - name: Customer\n";
+    let review = review_manifest_patch(orders_manifest_yaml(), patch).unwrap();
+
+    assert!(!review.is_unchanged());
+    assert!(
+        review.validation.is_valid(),
+        "{:?}",
+        review.validation.errors
+    );
+    let diff = review.diff("ntk-orders.yml");
+    assert!(diff.starts_with("--- a/ntk-orders.yml\n+++ b/ntk-orders.yml\n@@ "));
+    assert!(diff.contains("\n+    - name: Customer\n"));
+    assert!(!diff.contains("\n-"));
+}
+
+#[test]
+fn test_review_manifest_patch_reports_check_errors() {
+    let patch = "conventions:\n  namespaceRoot: ''\n";
+    let review = review_manifest_patch(orders_manifest_yaml(), patch).unwrap();
+
+    assert!(!review.validation.is_valid());
+    assert!(review
+        .validation
+        .errors
+        .iter()
+        .any(|error| error.message.contains("namespaceRoot")));
+}
+
+#[test]
+fn test_review_manifest_patch_rejects_non_mapping_patch() {
+    let error = review_manifest_patch(orders_manifest_yaml(), "- just\n- a list\n").unwrap_err();
+    assert!(error.contains("mapping"));
+}
+
+#[test]
+fn test_unified_diff_hunks() {
+    assert_eq!(unified_diff("a\nb\n", "a\nb\n", "f"), "");
+    assert_eq!(
+        unified_diff("a\nb\nc\n", "a\nx\nc\n", "f"),
+        "--- a/f\n+++ b/f\n@@ -1,3 +1,3 @@\n a\n-b\n+x\n c\n"
+    );
+}
@@ -0,0 +1,345 @@
+//! Import handler tests
+//!
+//! Tests for reverse-engineering draft manifests from C# sources.
+//! Category: Unit
+
+use nettoolskit_manifest::handlers::import::import_directory;
+use nettoolskit_manifest::parsing::csharp::{scan_source, CSharpTypeKind};
+use nettoolskit_manifest::ManifestParser;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+// ── Helpers ────────────────────────────────────────────────────────────────
+
+fn write_source(root: &Path, relative: &str, content: &str) {
+    let path = root.join(relative);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// Sources shaped like the output of `templates/dotnet/src/domain`.
+fn write_template_shaped_solution(root: &Path) {
+    write_source(
+        root,
+        "Rent.Service.Domain/Rent.Service.Domain.csproj",
+        "<Project Sdk=\"Microsoft.NET.Sdk\"><PropertyGroup><TargetFramework>net9.0</TargetFramework></PropertyGroup></Project>",
+    );
+    write_source(
+        root,
+        "Rent.Service.Domain/Entities/Abstractions/BaseEntity.cs",
+        r#"using NetToolsKit.Core.Domain.Entities;
+namespace Rent.Service.Domain.Entities.Abstractions;
+
+public abstract record BaseEntity : IEntity
+{
+    [Key]
+    [Required]
+    [Column("id")]
+    public required string Id { get; init; }
+
+    [Column("created_at")]
+    public DateTime CreatedAt { get; init; } = DateTime.UtcNow;
+}
+"#,
+    );
+    write_source(
+        root,
+        "Rent.Service.Domain/Entities/Rental.cs",
+        r#"namespace Rent.Service.Domain.Entities;
+
+[Table("rentals")]
+public record Rental : BaseEntity
+{
+    [Column("start_date")]
+    public required DateTime StartDate { get; init; }
+    public Money Price { get; init; }
+    public RentalStatus Status { get; init; }
+    public DateTime? ReturnedAt { get; init; }
+}
+"#,
+    );
+    write_source(
+        root,
+        "Rent.Service.Domain/ValueObjects/Money.cs",
+        r#"namespace Rent.Service.Domain.ValueObjects;
+
+public readonly record struct Money(
+    decimal Amount,
+    string Currency
+);
+"#,
+    );
+    write_source(
+        root,
+        "Rent.Service.Domain/Enums/RentalStatus.cs",
+        r#"namespace Rent.Service.Domain.Enums;
+
+public enum RentalStatus
+{
+    Pending = 0,
+    Active,
+    Completed = 5
+}
+"#,
+    );
+    write_source(
+        root,
+        "Rent.Service.Domain/Events/RentalCreated.cs",
+        "namespace Rent.Service.Domain.Events;\n\npublic sealed record RentalCreated();\n",
+    );
+    write_source(
+        root,
+        "Rent.Service.Domain/Repositories/IRentalRepository.cs",
+        r#"namespace Rent.Service.Domain.Repositories;
+
+public interface IRentalRepository
+{
+    Task<Rental?> GetByIdAsync(string id);
+    void Remove(Rental rental);
+}
+"#,
+    );
+    write_source(
+        root,
+        "Rent.Service.Domain/Helpers/Clock.cs",
+        "namespace Rent.Service.Domain.Helpers;\n\npublic static class Clock { }\n",
+    );
+}
+
+// ── Scanner tests ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_scan_source_detects_record_struct_and_enum_values() {
+    let source = r#"
+namespace Acme.Domain.ValueObjects
+{
+    public readonly record struct Email(string Value);
+    public enum Level { Low, High = 10, Max }
+}
+"#;
+    let declarations = scan_source(source, Path::new("Email.cs")).unwrap();
+
+    let email = declarations.iter().find(|d| d.name == "Email").unwrap();
+    assert_eq!(email.kind, CSharpTypeKind::RecordStruct);
+    assert_eq!(email.namespace, "Acme.Domain.ValueObjects");
+    assert_eq!(email.members.len(), 1);
+    assert_eq!(email.members[0].type_name, "string");
+
+    let level = declarations.iter().find(|d| d.name == "Level").unwrap();
+    let values: Vec<Option<i32>> = level.enum_members.iter().map(|m| m.value).collect();
+    assert_eq!(values, vec![Some(0), Some(10), Some(11)]);
+}
+
+#[test]
+fn test_scan_source_leaves_non_literal_enum_initializers_unresolved() {
+    let source = r#"
+namespace Acme.Domain.Enums
+{
+    [Flags]
+    public enum Access { None = 0, Read = 1 << 0, Write = 1 << 1, Both = Read | Write, Next }
+}
+"#;
+    let declarations = scan_source(source, Path::new("Access.cs")).unwrap();
+
+    let access = &declarations[0];
+    let values: Vec<Option<i32>> = access.enum_members.iter().map(|m| m.value).collect();
+    assert_eq!(values, vec![Some(0), None, None, None, None]);
+    assert_eq!(
+        access.enum_members[3].initializer.as_deref(),
+        Some("Read | Write")
+    );
+    assert_eq!(access.enum_members[4].initializer, None);
+}
+
+#[test]
+fn test_scan_source_strips_generic_base_arguments() {
+    let source =
+        "namespace Acme.Domain;\npublic class Order : AggregateRoot<Guid>, IAuditable { }\n";
+    let declarations = scan_source(source, Path::new("Order.cs")).unwrap();
+
+    assert_eq!(declarations[0].kind, CSharpTypeKind::Class);
+    assert_eq!(
+        declarations[0].base_types,
+        vec!["AggregateRoot", "IAuditable"]
+    );
+}
+
+// ── Import tests ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_import_directory_classifies_template_shapes() {
+    let dir = TempDir::new().unwrap();
+    write_template_shaped_solution(dir.path());
+
+    let report = import_directory(dir.path()).unwrap();
+    let document = &report.document;
+
+    assert_eq!(document.conventions.namespace_root, "Rent.Service");
+    assert_eq!(document.conventions.target_framework, "net9.0");
+    assert!(document.projects.contains_key("Domain"));
+    assert_eq!(document.contexts.len(), 1);
+    assert_eq!(document.contexts[0].name, "Service");
+
+    let aggregate = &document.contexts[0].aggregates[0];
+    assert_eq!(aggregate.name, "Rental");
+    assert_eq!(aggregate.entities[0].name, "Rental");
+    assert_eq!(aggregate.value_objects[0].name, "Money");
+    assert_eq!(aggregate.enums[0].values[1].value, 1);
+    assert_eq!(aggregate.domain_events[0].name, "RentalCreated");
+
+    let repository = aggregate.repository.as_ref().unwrap();
+    assert_eq!(repository.name, "IRentalRepository");
+    assert_eq!(
+        repository.methods[0].returns.as_deref(),
+        Some("Task<Rental?>")
+    );
+    assert_eq!(repository.methods[1].returns, None);
+}
+
+#[test]
+fn test_import_directory_inherits_key_and_maps_nullable_fields() {
+    let dir = TempDir::new().unwrap();
+    write_template_shaped_solution(dir.path());
+
+    let report = import_directory(dir.path()).unwrap();
+    let fields = &report.document.contexts[0].aggregates[0].entities[0].fields;
+
+    assert_eq!(fields[0].name, "Id");
+    assert!(fields[0].key);
+    assert_eq!(fields[0].column_name.as_deref(), Some("id"));
+
+    let start = fields.iter().find(|f| f.name == "StartDate").unwrap();
+    assert_eq!(start.column_name.as_deref(), Some("start_date"));
+
+    let returned = fields.iter().find(|f| f.name == "ReturnedAt").unwrap();
+    assert_eq!(returned.r#type, "DateTime");
+    assert!(returned.nullable);
+}
+
+#[test]
+fn test_import_directory_reports_unclassified_types() {
+    let dir = TempDir::new().unwrap();
+    write_template_shaped_solution(dir.path());
+
+    let report = import_directory(dir.path()).unwrap();
+
+    assert_eq!(report.unclassified.len(), 1);
+    assert_eq!(report.unclassified[0].name, "Clock");
+    assert!(report.to_yaml().unwrap().contains("# unclassified: Clock"));
+}
+
+#[test]
+fn test_import_directory_skips_unreadable_sources() {
+    let dir = TempDir::new().unwrap();
+    write_template_shaped_solution(dir.path());
+    let broken = dir.path().join("Rent.Service.Domain/Entities/Broken.cs");
+    fs::write(&broken, [0x66, 0x6f, 0xff, 0xfe, 0x6f]).unwrap();
+
+    let report = import_directory(dir.path()).unwrap();
+
+    assert_eq!(report.document.contexts[0].aggregates[0].name, "Rental");
+    assert_eq!(report.warnings.len(), 1);
+    assert_eq!(report.warnings[0].file, broken);
+    assert!(report.warnings[0].message.starts_with("skipped:"));
+    assert!(report.to_yaml().unwrap().contains("# warning:"));
+}
+
+#[test]
+fn test_import_directory_reports_unresolved_enum_values() {
+    let dir = TempDir::new().unwrap();
+    write_template_shaped_solution(dir.path());
+    write_source(
+        dir.path(),
+        "Rent.Service.Domain/Enums/RentalStatus.cs",
+        r#"namespace Rent.Service.Domain.Enums;
+
+public enum RentalStatus
+{
+    Pending = 0,
+    Active = 1 << 2,
+    Completed
+}
+"#,
+    );
+
+    let report = import_directory(dir.path()).unwrap();
+    let values = &report.document.contexts[0].aggregates[0].enums[0].values;
+
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].name, "Pending");
+    assert_eq!(report.warnings.len(), 2);
+    assert!(report.warnings[0]
+        .message
+        .contains("RentalStatus.Active = `1 << 2`"));
+    assert!(report.warnings[1]
+        .message
+        .contains("RentalStatus.Completed follows an unresolved member"));
+}
+
+#[test]
+fn test_import_directory_uses_namespace_segment_as_context() {
+    let dir = TempDir::new().unwrap();
+    write_source(
+        dir.path(),
+        "Fleet/Vehicle.cs",
+        "namespace Acme.Domain.Fleet.Entities;\npublic record Vehicle : BaseEntity { public string Plate { get; init; } }\n",
+    );
+    write_source(
+        dir.path(),
+        "Billing/Invoice.cs",
+        "namespace Acme.Domain.Billing;\npublic class Invoice : AggregateRoot { }\n",
+    );
+
+    let report = import_directory(dir.path()).unwrap();
+    let names: Vec<&str> = report
+        .document
+        .contexts
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect();
+
+    assert_eq!(names, vec!["Billing", "Fleet"]);
+    assert_eq!(report.document.conventions.namespace_root, "Acme");
+}
+
+#[test]
+fn test_import_directory_orders_repository_aggregates_by_declaration() {
+    let dir = TempDir::new().unwrap();
+    write_source(
+        dir.path(),
+        "Repositories/Repositories.cs",
+        "namespace Acme.Domain.Sales.Repositories;\npublic interface IZoneRepository { }\npublic interface IAccountRepository { }\npublic interface IMeterRepository { }\n",
+    );
+
+    for _ in 0..8 {
+        let report = import_directory(dir.path()).unwrap();
+        let names: Vec<&str> = report.document.contexts[0]
+            .aggregates
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Zone", "Account", "Meter"]);
+    }
+}
+
+#[test]
+fn test_import_directory_yaml_round_trips_through_parser() {
+    let dir = TempDir::new().unwrap();
+    write_template_shaped_solution(dir.path());
+
+    let report = import_directory(dir.path()).unwrap();
+    let manifest_path = dir.path().join("ntk-manifest.yml");
+    fs::write(&manifest_path, report.to_yaml().unwrap()).unwrap();
+
+    let parsed = ManifestParser::from_file(&manifest_path).unwrap();
+    ManifestParser::validate(&parsed).unwrap();
+    assert_eq!(parsed.contexts[0].aggregates[0].name, "Rental");
+}
+
+#[test]
+fn test_import_directory_rejects_missing_directory() {
+    let dir = TempDir::new().unwrap();
+    let result = import_directory(&dir.path().join("missing"));
+    assert!(result.is_err());
+}
@@ -1,3 +1,5 @@
 //! Handlers module tests
 
+pub mod assist_tests;
 pub mod check_tests;
+pub mod import_tests;
@@ -4,8 +4,9 @@
 //!
 //! 1. **CLI arguments** (highest) — e.g. `--verbose`, `--config`
 //! 2. **Environment variables** — `NTK_VERBOSE`, `NTK_COLOR`, etc.
-//! 3. **Config file** — `~/.ntk/config.toml`
-//! 4. **Defaults** (lowest)
+//! 3. **Project file** — nearest `.ntk/config.toml` (see [`crate::project`])
+//! 4. **Config file** — `~/.ntk/config.toml`
+//! 5. **Defaults** (lowest)
 //!
 //! # Config File Location
 //!
@@ -36,18 +37,27 @@
 //! [shell]
 //! default_shell = "bash"  # "bash", "zsh", "fish", "powershell"
 //! ```
+//!
+//! Policy sections (`[ai]`, `[chatops]`, `[service]`, `[tool_scope]`,
+//! `[repo_workflow]`, `[cache]`, `[plugins]`, `[task_approval]`) are described in
+//! [`crate::policy`].
 
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
-use tracing::{debug, info, warn};
+use tracing::info;
 
+use crate::policy::{
+    AiConfig, CacheConfig, ChatOpsConfig, PluginsConfig, RepoWorkflowConfig, ServiceConfig,
+    TaskApprovalConfig, ToolScopeConfig,
+};
+use crate::project::ConfigLayers;
 use crate::runtime::{resolve_runtime_mode, RuntimeMode};
 
 /// Primary application configuration
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 #[serde(default)]
 pub struct AppConfig {
     /// General settings
@@ -61,6 +71,38 @@ pub struct AppConfig {
 
     /// Shell preference
     pub shell: ShellConfig,
+
+    /// AI provider, budget and model-selection policy
+    #[serde(skip_serializing_if = "is_default")]
+    pub ai: AiConfig,
+
+    /// ChatOps runtime and ingress policy
+    #[serde(skip_serializing_if = "is_default")]
+    pub chatops: ChatOpsConfig,
+
+    /// Service-mode automation and HTTP policy
+    #[serde(skip_serializing_if = "is_default")]
+    pub service: ServiceConfig,
+
+    /// Tool allowlists per task intent
+    #[serde(skip_serializing_if = "is_default")]
+    pub tool_scope: ToolScopeConfig,
+
+    /// Repository workflow automation policy
+    #[serde(skip_serializing_if = "is_default")]
+    pub repo_workflow: RepoWorkflowConfig,
+
+    /// Persistent command-result cache policy
+    #[serde(skip_serializing_if = "is_default")]
+    pub cache: CacheConfig,
+
+    /// Plugin command policy
+    #[serde(skip_serializing_if = "is_default")]
+    pub plugins: PluginsConfig,
+
+    /// Task approval queue policy
+    #[serde(skip_serializing_if = "is_default")]
+    pub task_approval: TaskApprovalConfig,
 }
 
 /// General application settings
@@ -93,6 +135,9 @@ pub struct GeneralConfig {
 
     /// Number of local AI session snapshots retained on disk
     pub ai_session_retention: usize,
+
+    /// Base URL of a running `ntk service` that CLI `/task` commands dispatch to
+    pub service_endpoint: Option<String>,
 }
 
 /// Display and rendering settings
@@ -162,6 +207,7 @@ impl Default for GeneralConfig {
             attention_unfocused_only: false,
             predictive_input: true,
             ai_session_retention: 20,
+            service_endpoint: None,
         }
     }
 }
@@ -199,54 +245,33 @@ impl fmt::Display for UnicodeMode {
 
 impl AppConfig {
     /// Load configuration with full fallback chain:
-    /// config file → environment variables → defaults
+    /// user file → project `.ntk/config.toml` → environment variables → defaults
     ///
-    /// CLI arguments should be applied after calling this.
+    /// CLI arguments should be applied after calling this. See
+    /// [`crate::project`] for how the project file is discovered and trusted.
     #[must_use]
     pub fn load() -> Self {
-        let mut config = Self::load_from_file().unwrap_or_default();
-        config.apply_env_overrides();
-        config
+        ConfigLayers::discover().resolve()
     }
 
     /// Load configuration from a specific file path
     ///
     /// # Errors
     ///
-    /// Returns `Err` if the file cannot be read or parsed.
+    /// Returns `Err` if the file cannot be read, parsed or validated.
     pub fn load_from(path: &Path) -> crate::Result<Self> {
         let content = fs::read_to_string(path)?;
         let config: Self = toml::from_str(&content)?;
+        config
+            .validate()
+            .map_err(|error| anyhow::anyhow!("{}: {error}", path.display()))?;
 
         info!(path = %path.display(), "Configuration loaded from file");
         Ok(config)
     }
 
-    /// Attempt to load config from the default file location.
-    /// Returns `None` if no config file exists (this is normal).
-    fn load_from_file() -> Option<Self> {
-        let path = Self::default_config_path()?;
-
-        if !path.exists() {
-            debug!(path = %path.display(), "No config file found, using defaults");
-            return None;
-        }
-
-        match Self::load_from(&path) {
-            Ok(config) => Some(config),
-            Err(e) => {
-                warn!(
-                    path = %path.display(),
-                    error = %e,
-                    "Failed to load config file, using defaults"
-                );
-                None
-            }
-        }
-    }
-
     /// Apply environment variable overrides on top of file/default config
-    fn apply_env_overrides(&mut self) {
+    pub(crate) fn apply_env_overrides(&mut self) {
         if let Ok(val) = env::var("NTK_VERBOSE") {
             if let Some(parsed) = parse_bool_value(&val) {
                 self.general.verbose = parsed;
@@ -298,6 +323,13 @@ impl AppConfig {
             }
         }
 
+        if let Ok(val) = env::var("NTK_TASK_SERVICE_ENDPOINT") {
+            let trimmed = val.trim();
+            if !trimmed.is_empty() {
+                self.general.service_endpoint = Some(trimmed.to_string());
+            }
+        }
+
         if let Ok(val) = env::var("NTK_COLOR") {
             match val.to_lowercase().as_str() {
                 "always" | "1" | "true" => self.display.color = ColorMode::Always,
@@ -511,7 +543,7 @@ fn default_data_base_dir() -> Option<PathBuf> {
     }
 }
 
-fn resolve_home_dir() -> Option<PathBuf> {
+pub(crate) fn resolve_home_dir() -> Option<PathBuf> {
     #[cfg(windows)]
     {
         env::var_os("USERPROFILE").map(PathBuf::from).or_else(|| {
@@ -542,8 +574,12 @@ fn expand_tilde(path: &str) -> PathBuf {
     PathBuf::from(path)
 }
 
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
 /// Check if a string value is truthy
-fn parse_bool_value(val: &str) -> Option<bool> {
+pub(crate) fn parse_bool_value(val: &str) -> Option<bool> {
     match val.trim().to_ascii_lowercase().as_str() {
         "1" | "true" | "yes" | "on" => Some(true),
         "0" | "false" | "no" | "off" => Some(false),
@@ -592,6 +628,7 @@ mod tests {
                 attention_unfocused_only: true,
                 predictive_input: false,
                 ai_session_retention: 5,
+                service_endpoint: None,
             },
             display: DisplayConfig {
                 color: ColorMode::Always,
@@ -603,6 +640,17 @@ mod tests {
             shell: ShellConfig {
                 default_shell: Some("zsh".to_string()),
             },
+            ai: AiConfig {
+                provider_chain: Some(vec!["openai".to_string(), "mock".to_string()]),
+                cost_budget_usd_per_request: Some(0.25),
+                ..AiConfig::default()
+            },
+            chatops: ChatOpsConfig {
+                enabled: Some(true),
+                rate_limit_per_user: Some(10),
+                ..ChatOpsConfig::default()
+            },
+            ..AppConfig::default()
         };
 
         let toml_str = toml::to_string_pretty(&config).unwrap();
@@ -626,6 +674,7 @@ attention_desktop_notification = true
 attention_unfocused_only = true
 predictive_input = false
 ai_session_retention = 7
+service_endpoint = "https://agent-box.internal:8443"
 
 [display]
 color = "never"
@@ -650,6 +699,10 @@ default_shell = "fish"
         assert!(config.general.attention_unfocused_only);
         assert!(!config.general.predictive_input);
         assert_eq!(config.general.ai_session_retention, 7);
+        assert_eq!(
+            config.general.service_endpoint.as_deref(),
+            Some("https://agent-box.internal:8443")
+        );
         assert_eq!(config.display.color, ColorMode::Never);
         assert_eq!(config.display.unicode, UnicodeMode::Always);
         assert_eq!(
@@ -659,6 +712,21 @@ default_shell = "fish"
         assert_eq!(config.shell.default_shell, Some("fish".to_string()));
     }
 
+    #[test]
+    fn config_load_from_rejects_invalid_policy_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "[service]\nautomation_profile = \"lenient\"\n").unwrap();
+
+        let error = AppConfig::load_from(&path).unwrap_err().to_string();
+        assert!(
+            error.ends_with(
+                "[service] automation_profile: expected strict|balanced|open, got `lenient`"
+            ),
+            "{error}"
+        );
+    }
+
     #[test]
     fn config_save_and_reload() {
         let dir = tempfile::tempdir().unwrap();
@@ -675,6 +743,7 @@ default_shell = "fish"
                 attention_unfocused_only: true,
                 predictive_input: false,
                 ai_session_retention: 9,
+                service_endpoint: Some("http://agent-box:8080".to_string()),
             },
             ..AppConfig::default()
         };
@@ -702,6 +771,7 @@ verbose = true
         assert!(!config.general.attention_unfocused_only); // default
         assert!(config.general.predictive_input); // default
         assert_eq!(config.general.ai_session_retention, 20); // default
+        assert_eq!(config.general.service_endpoint, None); // default
         assert_eq!(config.display.color, ColorMode::Auto); // default
     }
 
@@ -0,0 +1,897 @@
+//! Local lexical index over the workspace for AI context retrieval
+//!
+//! [`ContextIndex`] splits workspace files into chunks and scores them
+//! against a prompt with BM25. Everything stays on disk under the NTK data
+//! directory; nothing leaves the machine.
+//!
+//! - Files are discovered with the `ignore` crate, so `.gitignore`, hidden
+//!   files and [`FileFilters::should_ignore_directory`] are honoured.
+//! - Rust, C# and TypeScript files are chunked on tree-sitter symbol
+//!   boundaries (functions, types, impls, methods); long symbols are split
+//!   by lines. Markdown is chunked by heading, everything else by lines.
+//! - [`ContextIndex::refresh`] is incremental: only files whose size or
+//!   modification time changed are re-chunked.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use ignore::WalkBuilder;
+use serde::{Deserialize, Serialize};
+use tree_sitter::{Language, Node, Parser};
+
+use crate::config::{resolve_home_dir, AppConfig};
+use crate::file_search::FileFilters;
+
+/// Directory under the NTK data root holding one index per workspace.
+pub const CONTEXT_INDEX_DIR_NAME: &str = "ai-context-index";
+
+const INDEX_VERSION: u32 = 1;
+const MAX_INDEXED_FILE_BYTES: u64 = 512 * 1024;
+const MAX_INDEXED_FILES: usize = 20_000;
+const MAX_CHUNK_LINES: usize = 60;
+/// Groups this short are glued to the symbol that follows them.
+const MAX_HEADER_LINES: usize = 3;
+const BM25_K1: f64 = 1.2;
+const BM25_B: f64 = 0.75;
+const SKIPPED_FILE_NAMES: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "packages.lock.json",
+];
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "do", "does", "for", "from", "how", "in",
+    "is", "it", "of", "on", "or", "the", "this", "to", "what", "where", "which", "why", "with",
+];
+
+/// Byte and line range of one chunk within its file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkSpan {
+    /// First byte of the chunk.
+    pub start_byte: usize,
+    /// Byte after the last one of the chunk.
+    pub end_byte: usize,
+    /// First line, 1-based.
+    pub start_line: usize,
+    /// Last line, 1-based and inclusive.
+    pub end_line: usize,
+    /// Enclosing symbol (`Processor::run`) or Markdown heading.
+    pub symbol: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexedChunk {
+    span: ChunkSpan,
+    terms: BTreeMap<String, u32>,
+    length: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexedFile {
+    modified_ms: u64,
+    len: u64,
+    chunks: Vec<IndexedChunk>,
+}
+
+/// What [`ContextIndex::refresh`] changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RefreshStats {
+    /// Files indexed for the first time.
+    pub added: usize,
+    /// Files re-chunked because they changed.
+    pub updated: usize,
+    /// Files dropped because they disappeared or became ignored.
+    pub removed: usize,
+    /// Files reused from the previous index.
+    pub unchanged: usize,
+}
+
+/// Chunk matching a query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContextHit {
+    /// Path relative to the workspace root.
+    pub path: PathBuf,
+    /// Location of the chunk.
+    pub span: ChunkSpan,
+    /// BM25 score; higher is more relevant.
+    pub score: f64,
+}
+
+/// BM25 index over the chunks of a workspace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextIndex {
+    version: u32,
+    files: BTreeMap<String, IndexedFile>,
+}
+
+impl Default for ContextIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Nearest ancestor of `start` holding `.git` or `.ntk`, never the home directory.
+///
+/// Retrieval only indexes a recognisable workspace, so running the CLI from
+/// `$HOME` or `/` does not crawl the whole disk.
+#[must_use]
+pub fn find_workspace_root(start: &Path) -> Option<PathBuf> {
+    let home = resolve_home_dir();
+    start
+        .ancestors()
+        .filter(|dir| home.as_deref() != Some(*dir))
+        .find(|dir| dir.join(".git").exists() || dir.join(".ntk").is_dir())
+        .map(Path::to_path_buf)
+}
+
+impl ContextIndex {
+    /// Empty index.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            version: INDEX_VERSION,
+            files: BTreeMap::new(),
+        }
+    }
+
+    /// Where the index of `workspace_root` is stored under the data directory.
+    #[must_use]
+    pub fn default_path(workspace_root: &Path) -> Option<PathBuf> {
+        let canonical = workspace_root
+            .canonicalize()
+            .unwrap_or_else(|_| workspace_root.to_path_buf());
+        let key = fnv1a(canonical.to_string_lossy().as_bytes());
+        AppConfig::default_data_dir().map(|dir| {
+            dir.join(CONTEXT_INDEX_DIR_NAME)
+                .join(format!("{key:016x}.json"))
+        })
+    }
+
+    /// Load a stored index; a missing, unreadable or outdated file yields an
+    /// empty index that the next [`Self::refresh`] rebuilds.
+    #[must_use]
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|text| serde_json::from_str::<Self>(&text).ok())
+            .filter(|index| index.version == INDEX_VERSION)
+            .unwrap_or_default()
+    }
+
+    /// Persist the index to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the directory cannot be created or the file written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let payload = serde_json::to_string(self).map_err(io::Error::other)?;
+        fs::write(path, payload)
+    }
+
+    /// Number of indexed files.
+    #[must_use]
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Number of indexed chunks.
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.files.values().map(|file| file.chunks.len()).sum()
+    }
+
+    /// Bring the index in line with the files under `workspace_root`.
+    pub fn refresh(&mut self, workspace_root: &Path) -> RefreshStats {
+        let mut stats = RefreshStats::default();
+        let mut seen = HashSet::new();
+
+        let walker = WalkBuilder::new(workspace_root)
+            .hidden(true)
+            .require_git(false)
+            .filter_entry(|entry| {
+                !(entry.file_type().is_some_and(|kind| kind.is_dir())
+                    && FileFilters::should_ignore_directory(entry.path()))
+            })
+            .build();
+
+        for entry in walker.flatten() {
+            if !entry.file_type().is_some_and(|kind| kind.is_file()) {
+                continue;
+            }
+            if seen.len() >= MAX_INDEXED_FILES {
+                break;
+            }
+            let path = entry.path();
+            let skipped_name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| SKIPPED_FILE_NAMES.contains(&name));
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if skipped_name || metadata.len() > MAX_INDEXED_FILE_BYTES {
+                continue;
+            }
+            let Some(key) = relative_key(workspace_root, path) else {
+                continue;
+            };
+            let modified_ms = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |elapsed| {
+                    u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+                });
+
+            if let Some(existing) = self.files.get(&key) {
+                if existing.modified_ms == modified_ms && existing.len == metadata.len() {
+                    seen.insert(key);
+                    stats.unchanged += 1;
+                    continue;
+                }
+            }
+
+            let Some(text) = read_text(path) else {
+                continue;
+            };
+            let path_terms = tokenize(&key);
+            let chunks = chunk_source(path, &text)
+                .into_iter()
+                .map(|span| index_chunk(&text, span, &path_terms))
+                .collect();
+            let replaced = self.files.insert(
+                key.clone(),
+                IndexedFile {
+                    modified_ms,
+                    len: metadata.len(),
+                    chunks,
+                },
+            );
+            if replaced.is_some() {
+                stats.updated += 1;
+            } else {
+                stats.added += 1;
+            }
+            seen.insert(key);
+        }
+
+        let before = self.files.len();
+        self.files.retain(|key, _| seen.contains(key));
+        stats.removed = before - self.files.len();
+        stats
+    }
+
+    /// The `limit` chunks scoring highest against `query`, best first.
+    #[must_use]
+    pub fn search(&self, query: &str, limit: usize) -> Vec<ContextHit> {
+        let mut query_terms = tokenize(query);
+        query_terms.sort();
+        query_terms.dedup();
+        let total_chunks = self.chunk_count();
+        if query_terms.is_empty() || total_chunks == 0 || limit == 0 {
+            return Vec::new();
+        }
+
+        let chunks = self
+            .files
+            .iter()
+            .flat_map(|(path, file)| file.chunks.iter().map(move |chunk| (path, chunk)));
+        let total_length: f64 = chunks
+            .clone()
+            .map(|(_, chunk)| f64::from(chunk.length))
+            .sum();
+        let average_length = (total_length / total_chunks as f64).max(1.0);
+
+        let idf = query_terms
+            .iter()
+            .map(|term| {
+                let frequency = chunks
+                    .clone()
+                    .filter(|(_, chunk)| chunk.terms.contains_key(term))
+                    .count() as f64;
+                let weight = ((total_chunks as f64 - frequency + 0.5) / (frequency + 0.5)).ln_1p();
+                (term.as_str(), weight)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut hits = chunks
+            .filter_map(|(path, chunk)| {
+                let length_norm = 1.0 - BM25_B + BM25_B * f64::from(chunk.length) / average_length;
+                let score = query_terms
+                    .iter()
+                    .filter_map(|term| {
+                        let frequency = f64::from(*chunk.terms.get(term)?);
+                        Some(
+                            idf[term.as_str()] * frequency * (BM25_K1 + 1.0)
+                                / (frequency + BM25_K1 * length_norm),
+                        )
+                    })
+                    .sum::<f64>();
+                (score > 0.0).then(|| ContextHit {
+                    path: PathBuf::from(path),
+                    span: chunk.span.clone(),
+                    score,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        hits.sort_by(|left, right| {
+            right
+                .score
+                .total_cmp(&left.score)
+                .then_with(|| left.path.cmp(&right.path))
+                .then_with(|| left.span.start_byte.cmp(&right.span.start_byte))
+        });
+        hits.truncate(limit);
+        hits
+    }
+}
+
+fn index_chunk(text: &str, span: ChunkSpan, path_terms: &[String]) -> IndexedChunk {
+    let mut terms = BTreeMap::new();
+    let mut length = 0_u32;
+    let chunk_terms = tokenize(&text[span.start_byte..span.end_byte]);
+    for term in chunk_terms.into_iter().chain(path_terms.iter().cloned()) {
+        *terms.entry(term).or_insert(0) += 1;
+        length += 1;
+    }
+    IndexedChunk {
+        span,
+        terms,
+        length,
+    }
+}
+
+fn relative_key(root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(root).ok()?;
+    let parts = relative
+        .components()
+        .map(|component| component.as_os_str().to_str())
+        .collect::<Option<Vec<_>>>()?;
+    Some(parts.join("/"))
+}
+
+fn read_text(path: &Path) -> Option<String> {
+    let bytes = fs::read(path).ok()?;
+    if bytes.contains(&0) {
+        return None;
+    }
+    String::from_utf8(bytes).ok()
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Lower-cased search terms of `text`.
+///
+/// Identifiers are kept whole and also split on `snake_case` and
+/// `camelCase` boundaries, so `AiContextBudget` matches a prompt about the
+/// "context budget".
+#[must_use]
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    for word in text.split(|ch: char| !(ch.is_alphanumeric() || ch == '_')) {
+        let word = word.trim_matches('_');
+        if word.is_empty() {
+            continue;
+        }
+        let parts = split_identifier(word);
+        if parts.len() > 1 {
+            terms.extend(parts.into_iter().filter(|part| keep_term(part)));
+        }
+        let whole = word.to_lowercase();
+        if keep_term(&whole) {
+            terms.push(whole);
+        }
+    }
+    terms
+}
+
+fn keep_term(term: &str) -> bool {
+    term.chars().count() > 1
+        && !term.chars().all(|ch| ch.is_ascii_digit())
+        && !STOPWORDS.contains(&term)
+}
+
+fn split_identifier(word: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    for segment in word.split('_').filter(|segment| !segment.is_empty()) {
+        let chars = segment.chars().collect::<Vec<_>>();
+        let mut current = String::new();
+        for (index, ch) in chars.iter().enumerate() {
+            let previous = index.checked_sub(1).map(|at| chars[at]);
+            let next = chars.get(index + 1);
+            let boundary = ch.is_uppercase()
+                && previous.is_some_and(|previous| {
+                    previous.is_lowercase()
+                        || previous.is_ascii_digit()
+                        || (previous.is_uppercase() && next.is_some_and(|next| next.is_lowercase()))
+                });
+            if boundary && !current.is_empty() {
+                parts.push(current.to_lowercase());
+                current.clear();
+            }
+            current.push(*ch);
+        }
+        if !current.is_empty() {
+            parts.push(current.to_lowercase());
+        }
+    }
+    parts
+}
+
+/// Split `text` into chunks, choosing the strategy from the extension of `path`.
+#[must_use]
+pub fn chunk_source(path: &Path, text: &str) -> Vec<ChunkSpan> {
+    let lines = LineIndex::new(text);
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase);
+    let language = match extension.as_deref() {
+        Some("rs") => Some(tree_sitter_rust::language()),
+        Some("cs") => Some(tree_sitter_c_sharp::language()),
+        Some("ts") => Some(tree_sitter_typescript::language_typescript()),
+        Some("tsx") => Some(tree_sitter_typescript::language_tsx()),
+        _ => None,
+    };
+
+    if let Some(chunks) = language.and_then(|language| syntax_chunks(text, &lines, &language)) {
+        if !chunks.is_empty() {
+            return chunks;
+        }
+    }
+    if matches!(extension.as_deref(), Some("md" | "markdown")) {
+        return markdown_chunks(text, &lines);
+    }
+    let mut chunks = Vec::new();
+    push_span(&mut chunks, text, &lines, 0, text.len(), None);
+    chunks
+}
+
+struct LineIndex {
+    starts: Vec<usize>,
+}
+
+impl LineIndex {
+    fn new(text: &str) -> Self {
+        let mut starts = vec![0];
+        starts.extend(text.match_indices('\n').map(|(index, _)| index + 1));
+        Self { starts }
+    }
+
+    /// 1-based line holding `byte`.
+    fn line_of(&self, byte: usize) -> usize {
+        self.starts.partition_point(|start| *start <= byte)
+    }
+
+    fn start_of(&self, line: usize) -> usize {
+        self.starts[line - 1]
+    }
+}
+
+/// Push `start..end` (trimmed), split into windows of [`MAX_CHUNK_LINES`].
+fn push_span(
+    chunks: &mut Vec<ChunkSpan>,
+    text: &str,
+    lines: &LineIndex,
+    start: usize,
+    end: usize,
+    symbol: Option<String>,
+) {
+    let slice = &text[start..end];
+    let content_start = start + (slice.len() - slice.trim_start().len());
+    let end = content_start + slice.trim().len();
+    if content_start >= end {
+        return;
+    }
+    // Keep the indentation of the first line.
+    let line_start = lines.start_of(lines.line_of(content_start));
+    let start = if line_start >= start || text[line_start..content_start].trim().is_empty() {
+        line_start
+    } else {
+        content_start
+    };
+
+    let first_line = lines.line_of(start);
+    let last_line = lines.line_of(end - 1);
+    let mut window_start = start;
+    let mut line = first_line;
+    while line <= last_line {
+        let window_last = (line + MAX_CHUNK_LINES - 1).min(last_line);
+        let window_end = if window_last == last_line {
+            end
+        } else {
+            lines.start_of(window_last + 1)
+        };
+        let window = &text[window_start..window_end];
+        if !window.trim().is_empty() {
+            let trimmed_end = window_start + window.trim_end().len();
+            chunks.push(ChunkSpan {
+                start_byte: window_start,
+                end_byte: trimmed_end,
+                start_line: lines.line_of(window_start),
+                end_line: lines.line_of(trimmed_end - 1),
+                symbol: symbol.clone(),
+            });
+        }
+        window_start = window_end;
+        line = window_last + 1;
+    }
+}
+
+fn markdown_chunks(text: &str, lines: &LineIndex) -> Vec<ChunkSpan> {
+    let mut chunks = Vec::new();
+    let mut section_start = 0;
+    let mut heading = None;
+    let mut in_fence = false;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+        } else if !in_fence && trimmed.starts_with('#') {
+            push_span(
+                &mut chunks,
+                text,
+                lines,
+                section_start,
+                offset,
+                heading.take(),
+            );
+            section_start = offset;
+            heading = Some(trimmed.trim_start_matches('#').trim().to_string());
+        }
+        offset += line.len();
+    }
+    push_span(&mut chunks, text, lines, section_start, text.len(), heading);
+    chunks
+}
+
+fn syntax_chunks(text: &str, lines: &LineIndex, language: &Language) -> Option<Vec<ChunkSpan>> {
+    let mut parser = Parser::new();
+    parser.set_language(language).ok()?;
+    let tree = parser.parse(text, None)?;
+    let mut chunks = Vec::new();
+    let mut walker = SymbolWalker {
+        text,
+        lines,
+        chunks: &mut chunks,
+    };
+    walker.walk(tree.root_node(), None, None);
+    Some(chunks)
+}
+
+/// Run of adjacent non-symbol nodes (imports, comments, fields).
+#[derive(Debug, Clone, Copy)]
+struct Group {
+    start: usize,
+    end: usize,
+    /// Start of the trailing run of comments and attributes, if any.
+    trivia_start: Option<usize>,
+}
+
+struct SymbolWalker<'a> {
+    text: &'a str,
+    lines: &'a LineIndex,
+    chunks: &'a mut Vec<ChunkSpan>,
+}
+
+impl SymbolWalker<'_> {
+    fn line_count(&self, start: usize, end: usize) -> usize {
+        self.lines.line_of(end.max(start + 1) - 1) - self.lines.line_of(start) + 1
+    }
+
+    fn flush(&mut self, start: usize, end: usize, scope: Option<&str>) {
+        push_span(
+            self.chunks,
+            self.text,
+            self.lines,
+            start,
+            end,
+            scope.map(str::to_string),
+        );
+    }
+
+    /// Start of the chunk for a symbol at `symbol_start`, flushing whatever
+    /// part of `group` does not belong to it.
+    ///
+    /// Doc comments, attributes and short headers (`impl Foo {`) are kept
+    /// with the symbol that follows them.
+    fn lead_start(
+        &mut self,
+        group: Option<Group>,
+        symbol_start: usize,
+        scope: Option<&str>,
+    ) -> usize {
+        let Some(group) = group else {
+            return symbol_start;
+        };
+        if self.line_count(group.start, group.end) <= MAX_HEADER_LINES {
+            return group.start;
+        }
+        match group.trivia_start {
+            Some(trivia_start) => {
+                self.flush(group.start, trivia_start, scope);
+                trivia_start
+            }
+            None => {
+                self.flush(group.start, group.end, scope);
+                symbol_start
+            }
+        }
+    }
+
+    fn walk(&mut self, node: Node<'_>, scope: Option<&str>, carry: Option<Group>) {
+        let mut group = carry;
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            let (start, end) = (child.start_byte(), child.end_byte());
+            let oversized = self.line_count(start, end) > MAX_CHUNK_LINES;
+            let nested = oversized && has_nested_symbol(child);
+
+            if !(is_symbol(child.kind()) || nested) {
+                let trivia = is_trivia(child.kind());
+                let mut current = group.unwrap_or(Group {
+                    start,
+                    end,
+                    trivia_start: None,
+                });
+                current.end = end;
+                current.trivia_start = if trivia {
+                    current.trivia_start.or(Some(start))
+                } else {
+                    None
+                };
+                group = if self.line_count(current.start, current.end) > MAX_CHUNK_LINES {
+                    self.flush(current.start, current.end, scope);
+                    None
+                } else {
+                    Some(current)
+                };
+                continue;
+            }
+
+            let chunk_start = self.lead_start(group.take(), start, scope);
+            let name = symbol_name(child, self.text).map(|name| match scope {
+                Some(scope) => format!("{scope}::{name}"),
+                None => name,
+            });
+            let inner_scope = name.as_deref().or(scope);
+
+            if nested {
+                let carry = Group {
+                    start: chunk_start,
+                    end: start,
+                    trivia_start: None,
+                };
+                self.walk(child, inner_scope, Some(carry));
+            } else {
+                self.flush(chunk_start, end, inner_scope);
+            }
+        }
+        if let Some(group) = group {
+            self.flush(group.start, group.end, scope);
+        }
+    }
+}
+
+fn has_nested_symbol(node: Node<'_>) -> bool {
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .any(|child| is_symbol(child.kind()) || has_nested_symbol(child));
+    found
+}
+
+fn is_trivia(kind: &str) -> bool {
+    kind.contains("comment") || matches!(kind, "attribute_item" | "attribute_list" | "decorator")
+}
+
+fn is_symbol(kind: &str) -> bool {
+    matches!(
+        kind,
+        // Rust
+        "function_item"
+            | "function_signature_item"
+            | "struct_item"
+            | "enum_item"
+            | "union_item"
+            | "trait_item"
+            | "impl_item"
+            | "mod_item"
+            | "const_item"
+            | "static_item"
+            | "type_item"
+            | "macro_definition"
+            // C#
+            | "namespace_declaration"
+            | "file_scoped_namespace_declaration"
+            | "class_declaration"
+            | "struct_declaration"
+            | "interface_declaration"
+            | "record_declaration"
+            | "enum_declaration"
+            | "delegate_declaration"
+            | "method_declaration"
+            | "constructor_declaration"
+            | "property_declaration"
+            | "event_declaration"
+            // TypeScript
+            | "function_declaration"
+            | "generator_function_declaration"
+            | "abstract_class_declaration"
+            | "type_alias_declaration"
+            | "internal_module"
+            | "method_definition"
+            | "export_statement"
+            | "lexical_declaration"
+    )
+}
+
+fn symbol_name(node: Node<'_>, text: &str) -> Option<String> {
+    let source = text.as_bytes();
+    let named = |node: Node<'_>, field: &str| {
+        node.child_by_field_name(field)
+            .and_then(|child| child.utf8_text(source).ok())
+            .map(str::to_string)
+    };
+    match node.kind() {
+        "impl_item" => named(node, "type"),
+        "export_statement" => node
+            .child_by_field_name("declaration")
+            .and_then(|declaration| symbol_name(declaration, text)),
+        "lexical_declaration" => {
+            let mut cursor = node.walk();
+            let declarator = node
+                .named_children(&mut cursor)
+                .find(|child| child.kind() == "variable_declarator");
+            declarator.and_then(|declarator| named(declarator, "name"))
+        }
+        _ => named(node, "name"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_splits_identifiers_and_drops_stopwords() {
+        let terms = tokenize("How is the AiContextBudget used by collect_workspace_context?");
+
+        for expected in [
+            "aicontextbudget",
+            "ai",
+            "context",
+            "budget",
+            "collect_workspace_context",
+            "collect",
+            "workspace",
+            "used",
+        ] {
+            assert!(terms.iter().any(|term| term == expected), "{expected}");
+        }
+        assert!(!terms.iter().any(|term| term == "the" || term == "is"));
+        assert_eq!(
+            split_identifier("HTTPServer2Config"),
+            ["http", "server2", "config"]
+        );
+    }
+
+    #[test]
+    fn rust_sources_are_chunked_on_symbol_boundaries() {
+        let methods = (0..40)
+            .map(|index| format!("    fn method_{index}(&self) -> usize {{ {index} }}\n"))
+            .collect::<Vec<_>>()
+            .join("    \n");
+        let source = format!(
+            "use std::fmt;\n\n/// Budget docs.\n#[derive(Debug)]\npub struct Budget {{\n    max: usize,\n}}\n\nimpl Budget {{\n{methods}}}\n\nfn free() {{}}\n"
+        );
+
+        let chunks = chunk_source(Path::new("src/budget.rs"), &source);
+        let text_of = |chunk: &ChunkSpan| &source[chunk.start_byte..chunk.end_byte];
+
+        let budget = chunks
+            .iter()
+            .find(|chunk| chunk.symbol.as_deref() == Some("Budget"))
+            .expect("struct chunk");
+        assert!(text_of(budget).starts_with("/// Budget docs.\n#[derive(Debug)]"));
+        assert_eq!(text_of(&chunks[0]), "use std::fmt;");
+        let method = chunks
+            .iter()
+            .find(|chunk| chunk.symbol.as_deref() == Some("Budget::method_7"))
+            .expect("method chunk inside the oversized impl");
+        assert_eq!(text_of(method), "    fn method_7(&self) -> usize { 7 }");
+        assert!(chunks
+            .iter()
+            .any(|chunk| chunk.symbol.as_deref() == Some("free")));
+        assert!(chunks
+            .iter()
+            .all(|chunk| chunk.end_line - chunk.start_line < MAX_CHUNK_LINES));
+    }
+
+    #[test]
+    fn csharp_and_typescript_symbols_are_named() {
+        let csharp = "namespace Orders;\n\npublic class OrderService\n{\n    public void Place(Order order) { }\n}\n";
+        let typescript = "export function placeOrder(order: Order): void {}\n\nexport interface Order {\n  id: string;\n}\n";
+
+        let csharp_symbols = chunk_source(Path::new("OrderService.cs"), csharp)
+            .into_iter()
+            .filter_map(|chunk| chunk.symbol)
+            .collect::<Vec<_>>();
+        let typescript_symbols = chunk_source(Path::new("orders.ts"), typescript)
+            .into_iter()
+            .filter_map(|chunk| chunk.symbol)
+            .collect::<Vec<_>>();
+
+        assert!(csharp_symbols
+            .iter()
+            .any(|symbol| symbol.contains("OrderService")));
+        assert_eq!(typescript_symbols, ["placeOrder", "Order"]);
+    }
+
+    #[test]
+    fn refresh_is_incremental_and_search_ranks_relevant_chunks() {
+        let temp = tempfile::tempdir().expect("tempdir should be created");
+        let root = temp.path();
+        fs::create_dir_all(root.join("src")).expect("src dir");
+        fs::create_dir_all(root.join("target")).expect("target dir");
+        fs::write(root.join(".gitignore"), "generated/\n").expect("gitignore");
+        fs::create_dir_all(root.join("generated")).expect("generated dir");
+        fs::write(
+            root.join("src/billing.rs"),
+            "/// Compute the invoice total with tax.\npub fn invoice_total(lines: &[u64]) -> u64 {\n    lines.iter().sum()\n}\n",
+        )
+        .expect("billing source");
+        fs::write(
+            root.join("src/shipping.rs"),
+            "pub fn shipping_label(address: &str) -> String {\n    address.to_string()\n}\n",
+        )
+        .expect("shipping source");
+        fs::write(root.join("target/invoice.rs"), "fn invoice_total() {}\n").expect("target");
+        fs::write(root.join("generated/invoice.rs"), "fn invoice_total() {}\n").expect("ignored");
+
+        let mut index = ContextIndex::new();
+        let first = index.refresh(root);
+        assert_eq!(first.added, 2, "{first:?}");
+        assert_eq!(index.file_count(), 2);
+
+        let hits = index.search("where is the invoice total computed?", 5);
+        assert_eq!(hits[0].path, PathBuf::from("src/billing.rs"));
+        assert_eq!(hits[0].span.symbol.as_deref(), Some("invoice_total"));
+        assert!(hits
+            .iter()
+            .all(|hit| !hit.path.starts_with("target") && !hit.path.starts_with("generated")));
+
+        fs::remove_file(root.join("src/shipping.rs")).expect("remove shipping");
+        let second = index.refresh(root);
+        assert_eq!((second.removed, second.unchanged), (1, 1));
+
+        assert!(find_workspace_root(&root.join("src")).is_none());
+        fs::create_dir_all(root.join(".ntk")).expect("ntk dir");
+        assert_eq!(
+            find_workspace_root(&root.join("src")).as_deref(),
+            Some(root)
+        );
+
+        let path = root.join("index.json");
+        index.save(&path).expect("index should save");
+        let loaded = ContextIndex::load(&path);
+        assert_eq!(loaded.chunk_count(), index.chunk_count());
+        assert_eq!(
+            ContextIndex::load(&root.join("missing.json")).file_count(),
+            0
+        );
+    }
+}
@@ -18,12 +18,23 @@ pub mod features;
 /// User configuration with layered loading (file → env → defaults)
 pub mod config;
 
+/// Typed policy sections (`[ai]`, `[chatops]`, ...) with env-over-file lookup
+pub mod policy;
+
+pub mod project;
+
 /// AI workspace-context collection and redaction utilities
 pub mod ai_context;
 
+pub mod context_index;
+
 /// Shared runtime contracts for CLI and background-service execution modes.
 pub mod runtime;
 
+pub mod secrets;
+
+pub mod secret_detectors;
+
 /// String manipulation utilities
 #[path = "string-utils/lib.rs"]
 pub mod string_utils;
@@ -45,8 +56,25 @@ pub use config::{AppConfig, ColorMode, UnicodeMode};
 pub use exit_status::ExitStatus;
 pub use features::Features;
 pub use menu::{CommandEntry, MenuEntry, MenuProvider};
+pub use policy::{
+    policy_setting, render_policy_value, AiConfig, CacheConfig, ChatOpsConfig, PluginsConfig,
+    PolicySetting, PolicySource, PolicyValueKind, RepoWorkflowConfig, ServiceConfig,
+    TaskApprovalConfig, ToolScopeConfig, POLICY_SECTIONS, POLICY_SETTINGS,
+};
+pub use project::{
+    config_env_var, requires_trust, ConfigLayers, ConfigOrigin, ProjectConfig, TrustStore,
+    PROJECT_CONFIG_PATH, TRUST_REQUIRED_KEYS,
+};
 pub use runtime::{
     resolve_runtime_mode, ApprovalState, ControlEnvelope, ControlPolicyContext, IngressTransport,
     OperatorContext, OperatorKind, RuntimeMode, SessionContext, SessionKind, TaskAuditEvent,
-    TaskExecutionStatus, TaskIntent, TaskIntentKind, NTK_RUNTIME_MODE_ENV,
+    TaskExecutionStatus, TaskIntent, TaskIntentKind, TraceContext, NTK_RUNTIME_MODE_ENV,
+    TRACEPARENT_HEADER, TRACESTATE_HEADER,
+};
+pub use secret_detectors::{
+    EntropyThresholds, Redaction, RegexDetector, SecretDetector, SecretRedactor,
+};
+pub use secrets::{
+    redact_registered_secrets, register_secret, resolve_secret, SecretBackend, SecretBackendMode,
+    SecretRef, SecretStore, NTK_SECRETS_PASSPHRASE_ENV,
 };
@@ -0,0 +1,1310 @@
+//! Typed policy sections of `config.toml`.
+//!
+//! `[ai]`, `[chatops]`, `[service]`, `[tool_scope]`, `[repo_workflow]`,
+//! `[cache]`, `[plugins]` and `[task_approval]` describe policies that were historically environment-only. Every key has a
+//! matching `NTK_*` variable listed in [`POLICY_SETTINGS`]: the variable still
+//! wins over the file, and the file wins over the built-in default, which
+//! stays with the component that owns the policy. Policy readers resolve
+//! values through [`PolicySource`] instead of `std::env::var`.
+//!
+//! # Example
+//!
+//! ```toml
+//! [ai]
+//! provider_chain = ["openai", "mock"]
+//! token_budget_total_per_request = 12000
+//! model_selection_enabled = true
+//! model_selection_reasoning_intents = ["plan", "explain"]
+//!
+//! [chatops]
+//! enabled = true
+//! allowed_users = ["U123", "U456"]
+//! rate_limit_strategy = "token_bucket"
+//!
+//! [service]
+//! automation_profile = "strict"
+//! allowed_intents = ["ai-ask", "ai-plan"]
+//!
+//! [tool_scope]
+//! enabled = true
+//! allowed_tools = ["ai.ask", "ai.plan"]
+//!
+//! [repo_workflow]
+//! enabled = true
+//! allowed_hosts = ["github.com"]
+//!
+//! [cache]
+//! persistent = true
+//! persistent_max_bytes = 33554432
+//!
+//! [plugins]
+//! allowed_scopes = ["greet"]
+//!
+//! [task_approval]
+//! rules = ["repo-workflow-push", "intent:ai-apply-dry-run"]
+//! approvers = ["alice", "telegram:12345"]
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+use std::path::Path;
+
+use crate::config::{parse_bool_value, AppConfig};
+use crate::project::ConfigLayers;
+use crate::runtime::TaskIntentKind;
+use crate::secrets::{resolve_secret, SecretRef};
+
+/// Shape of a policy value, used for validation, `/config set` and completion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyValueKind {
+    /// `true` or `false`.
+    Bool,
+    /// Integer greater than zero.
+    Positive,
+    /// Number greater than zero.
+    Amount,
+    /// Number between 0 and 100.
+    Percent,
+    /// Non-empty free text.
+    Text,
+    /// Non-empty credential, masked when displayed.
+    Secret,
+    /// Filesystem path.
+    Path,
+    /// One value out of a fixed set.
+    Choice(&'static [&'static str]),
+    /// List of non-empty entries.
+    List,
+    /// List of values out of a fixed set.
+    ChoiceList(&'static [&'static str]),
+    /// List of task intents (labels or aliases), or `*` for all of them.
+    Intents,
+}
+
+impl PolicyValueKind {
+    /// Values offered for completion; empty for free-form kinds.
+    #[must_use]
+    pub const fn values(self) -> &'static [&'static str] {
+        match self {
+            Self::Bool => &["true", "false"],
+            Self::Choice(values) | Self::ChoiceList(values) => values,
+            _ => &[],
+        }
+    }
+
+    /// Short description of accepted values (`integer > 0`).
+    #[must_use]
+    pub fn describe(self) -> String {
+        match self {
+            Self::Bool => "true|false".to_string(),
+            Self::Positive => "integer > 0".to_string(),
+            Self::Amount => "number > 0".to_string(),
+            Self::Percent => "number 0-100".to_string(),
+            Self::Text => "text".to_string(),
+            Self::Secret => "secret or env:|file:|keyring: reference".to_string(),
+            Self::Path => "path".to_string(),
+            Self::Choice(values) => values.join("|"),
+            Self::List => "list".to_string(),
+            Self::ChoiceList(values) => format!("list of {}", values.join("|")),
+            Self::Intents => "list of task intents or *".to_string(),
+        }
+    }
+
+    /// Parse a `/config set` or environment-style value (lists are
+    /// comma-separated) into its TOML form.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the accepted values when `raw` does not fit.
+    pub fn parse(self, raw: &str) -> Result<toml::Value, String> {
+        let trimmed = raw.trim();
+        let value = match self {
+            Self::Bool => toml::Value::Boolean(
+                parse_bool_value(trimmed).ok_or_else(|| self.expected(trimmed))?,
+            ),
+            Self::Positive => {
+                toml::Value::Integer(trimmed.parse::<i64>().map_err(|_| self.expected(trimmed))?)
+            }
+            Self::Amount | Self::Percent => {
+                toml::Value::Float(trimmed.parse::<f64>().map_err(|_| self.expected(trimmed))?)
+            }
+            Self::Text | Self::Secret | Self::Path => toml::Value::String(trimmed.to_string()),
+            Self::Choice(_) => toml::Value::String(trimmed.to_ascii_lowercase()),
+            Self::List | Self::ChoiceList(_) | Self::Intents => toml::Value::Array(
+                trimmed
+                    .split([',', ';'])
+                    .map(str::trim)
+                    .filter(|entry| !entry.is_empty())
+                    .map(|entry| toml::Value::String(entry.to_string()))
+                    .collect(),
+            ),
+        };
+        self.check(&value)?;
+        Ok(value)
+    }
+
+    /// Check a TOML value against this kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the accepted values when `value` does not fit.
+    pub fn check(self, value: &toml::Value) -> Result<(), String> {
+        let rendered = render_policy_value(value);
+        let valid = match (self, value) {
+            (Self::Bool, toml::Value::Boolean(_)) => true,
+            (Self::Positive, toml::Value::Integer(number)) => *number > 0,
+            (Self::Amount, toml::Value::Float(number)) => *number > 0.0,
+            (Self::Amount, toml::Value::Integer(number)) => *number > 0,
+            (Self::Percent, toml::Value::Float(number)) => (0.0..=100.0).contains(number),
+            (Self::Percent, toml::Value::Integer(number)) => (0..=100).contains(number),
+            (Self::Secret, toml::Value::String(text)) if !text.trim().is_empty() => {
+                return SecretRef::parse(text).map(drop);
+            }
+            (Self::Text | Self::Secret | Self::Path, toml::Value::String(text)) => {
+                !text.trim().is_empty()
+            }
+            (Self::Choice(values), toml::Value::String(text)) => {
+                values.contains(&text.trim().to_ascii_lowercase().as_str())
+            }
+            (Self::List | Self::ChoiceList(_) | Self::Intents, toml::Value::Array(entries)) => {
+                return self.check_entries(entries);
+            }
+            _ => false,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(self.expected(&rendered))
+        }
+    }
+
+    fn check_entries(self, entries: &[toml::Value]) -> Result<(), String> {
+        if entries.is_empty() {
+            return Err(format!("expected a non-empty {}", self.describe()));
+        }
+        for entry in entries {
+            let Some(text) = entry
+                .as_str()
+                .map(str::trim)
+                .filter(|text| !text.is_empty())
+            else {
+                return Err(format!(
+                    "expected {}, got entry `{}`",
+                    self.describe(),
+                    render_policy_value(entry)
+                ));
+            };
+            let normalized = text.to_ascii_lowercase();
+            let known = match self {
+                Self::ChoiceList(values) => values.contains(&normalized.as_str()),
+                Self::Intents => {
+                    matches!(normalized.as_str(), "*" | "all")
+                        || TaskIntentKind::from_alias(&normalized).is_some()
+                }
+                _ => true,
+            };
+            if !known {
+                return Err(format!("expected {}, got entry `{text}`", self.describe()));
+            }
+        }
+        Ok(())
+    }
+
+    fn expected(self, got: &str) -> String {
+        format!("expected {}, got `{got}`", self.describe())
+    }
+}
+
+/// One `[section] key` policy setting and the variable that overrides it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolicySetting {
+    /// Dotted key (`chatops.rate_limit_strategy`).
+    pub key: &'static str,
+    /// Environment variable that overrides the file value.
+    pub env: &'static str,
+    /// Accepted value shape.
+    pub kind: PolicyValueKind,
+}
+
+impl PolicySetting {
+    /// Section name (`chatops`).
+    #[must_use]
+    pub fn section(&self) -> &'static str {
+        self.key
+            .split_once('.')
+            .map_or(self.key, |(section, _)| section)
+    }
+
+    /// Key within the section (`rate_limit_strategy`).
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.key.split_once('.').map_or(self.key, |(_, name)| name)
+    }
+}
+
+/// Sections holding policy settings, in file order.
+pub const POLICY_SECTIONS: &[&str] = &[
+    "ai",
+    "chatops",
+    "service",
+    "tool_scope",
+    "repo_workflow",
+    "cache",
+    "plugins",
+    "task_approval",
+];
+
+const AI_PROVIDERS: &[&str] = &["mock", "openai", "openai-compatible"];
+
+const fn setting(key: &'static str, env: &'static str, kind: PolicyValueKind) -> PolicySetting {
+    PolicySetting { key, env, kind }
+}
+
+/// Every policy setting, grouped by section.
+pub const POLICY_SETTINGS: &[PolicySetting] = {
+    use PolicyValueKind::{
+        Amount, Bool, Choice, ChoiceList, Intents, List, Path, Percent, Positive, Secret, Text,
+    };
+    &[
+        setting("ai.provider", "NTK_AI_PROVIDER", Choice(AI_PROVIDERS)),
+        setting(
+            "ai.provider_chain",
+            "NTK_AI_PROVIDER_CHAIN",
+            ChoiceList(AI_PROVIDERS),
+        ),
+        setting(
+            "ai.fallback_provider",
+            "NTK_AI_FALLBACK_PROVIDER",
+            Choice(AI_PROVIDERS),
+        ),
+        setting("ai.endpoint", "NTK_AI_ENDPOINT", Text),
+        setting("ai.api_key", "NTK_AI_API_KEY", Secret),
+        setting("ai.model", "NTK_AI_MODEL", Text),
+        setting("ai.timeout_ms", "NTK_AI_TIMEOUT_MS", Positive),
+        setting("ai.fallback_text", "NTK_AI_FALLBACK_TEXT", Text),
+        setting(
+            "ai.primary_timeout_ms",
+            "NTK_AI_PROVIDER_PRIMARY_TIMEOUT_MS",
+            Positive,
+        ),
+        setting(
+            "ai.secondary_timeout_ms",
+            "NTK_AI_PROVIDER_SECONDARY_TIMEOUT_MS",
+            Positive,
+        ),
+        setting("ai.max_retries", "NTK_AI_MAX_RETRIES", Positive),
+        setting("ai.retry_base_ms", "NTK_AI_RETRY_BASE_MS", Positive),
+        setting("ai.retry_max_ms", "NTK_AI_RETRY_MAX_MS", Positive),
+        setting(
+            "ai.request_timeout_ms",
+            "NTK_AI_REQUEST_TIMEOUT_MS",
+            Positive,
+        ),
+        setting(
+            "ai.rate_limit_requests",
+            "NTK_AI_RATE_LIMIT_REQUESTS",
+            Positive,
+        ),
+        setting(
+            "ai.rate_limit_window_seconds",
+            "NTK_AI_RATE_LIMIT_WINDOW_SECONDS",
+            Positive,
+        ),
+        setting(
+            "ai.cost_per_1k_input_usd",
+            "NTK_AI_COST_PER_1K_INPUT_USD",
+            Amount,
+        ),
+        setting(
+            "ai.cost_per_1k_output_usd",
+            "NTK_AI_COST_PER_1K_OUTPUT_USD",
+            Amount,
+        ),
+        setting("ai.price_table", "NTK_AI_PRICE_TABLE", List),
+        setting(
+            "ai.spend_cap_daily_usd",
+            "NTK_AI_SPEND_CAP_DAILY_USD",
+            Amount,
+        ),
+        setting(
+            "ai.spend_cap_monthly_usd",
+            "NTK_AI_SPEND_CAP_MONTHLY_USD",
+            Amount,
+        ),
+        setting("ai.spend_ledger_path", "NTK_AI_SPEND_LEDGER_PATH", Path),
+        setting("ai.context_max_files", "NTK_AI_CONTEXT_MAX_FILES", Positive),
+        setting(
+            "ai.context_max_file_bytes",
+            "NTK_AI_CONTEXT_MAX_FILE_BYTES",
+            Positive,
+        ),
+        setting("ai.context_max_bytes", "NTK_AI_CONTEXT_MAX_BYTES", Positive),
+        setting(
+            "ai.context_mode",
+            "NTK_AI_CONTEXT_MODE",
+            Choice(&["retrieval", "allowlist"]),
+        ),
+        setting("ai.context_paths", "NTK_AI_CONTEXT_PATHS", List),
+        setting("ai.manifest_context", "NTK_AI_MANIFEST_CONTEXT", Bool),
+        setting("ai.session_summary", "NTK_AI_SESSION_SUMMARY", Bool),
+        setting(
+            "ai.redaction_entropy_min_length",
+            "NTK_AI_REDACTION_ENTROPY_MIN_LENGTH",
+            Positive,
+        ),
+        setting(
+            "ai.redaction_entropy_min_bits",
+            "NTK_AI_REDACTION_ENTROPY_MIN_BITS",
+            Amount,
+        ),
+        setting(
+            "ai.token_budget_input_per_request",
+            "NTK_AI_TOKEN_BUDGET_INPUT_PER_REQUEST",
+            Positive,
+        ),
+        setting(
+            "ai.token_budget_output_per_request",
+            "NTK_AI_TOKEN_BUDGET_OUTPUT_PER_REQUEST",
+            Positive,
+        ),
+        setting(
+            "ai.token_budget_total_per_request",
+            "NTK_AI_TOKEN_BUDGET_TOTAL_PER_REQUEST",
+            Positive,
+        ),
+        setting(
+            "ai.token_budget_session_total",
+            "NTK_AI_TOKEN_BUDGET_SESSION_TOTAL",
+            Positive,
+        ),
+        setting(
+            "ai.cost_budget_usd_per_request",
+            "NTK_AI_COST_BUDGET_USD_PER_REQUEST",
+            Amount,
+        ),
+        setting(
+            "ai.prompt_compaction_tier",
+            "NTK_AI_PROMPT_COMPACTION_TIER",
+            Choice(&["off", "balanced", "aggressive"]),
+        ),
+        setting("ai.cache_first_enabled", "NTK_AI_CACHE_FIRST_ENABLED", Bool),
+        setting(
+            "ai.slo_max_p95_latency_ms",
+            "NTK_AI_SLO_MAX_P95_LATENCY_MS",
+            Amount,
+        ),
+        setting(
+            "ai.slo_min_success_rate_pct",
+            "NTK_AI_SLO_MIN_SUCCESS_RATE_PCT",
+            Percent,
+        ),
+        setting(
+            "ai.slo_max_tokens_per_task",
+            "NTK_AI_SLO_MAX_TOKENS_PER_TASK",
+            Amount,
+        ),
+        setting(
+            "ai.slo_max_cost_usd_per_task",
+            "NTK_AI_SLO_MAX_COST_USD_PER_TASK",
+            Amount,
+        ),
+        setting(
+            "ai.model_selection_enabled",
+            "NTK_AI_MODEL_SELECTION_ENABLED",
+            Bool,
+        ),
+        setting(
+            "ai.model_selection_cheap_model",
+            "NTK_AI_MODEL_SELECTION_CHEAP_MODEL",
+            Text,
+        ),
+        setting(
+            "ai.model_selection_reasoning_model",
+            "NTK_AI_MODEL_SELECTION_REASONING_MODEL",
+            Text,
+        ),
+        setting(
+            "ai.model_selection_cheap_intents",
+            "NTK_AI_MODEL_SELECTION_CHEAP_INTENTS",
+            Intents,
+        ),
+        setting(
+            "ai.model_selection_reasoning_intents",
+            "NTK_AI_MODEL_SELECTION_REASONING_INTENTS",
+            Intents,
+        ),
+        setting(
+            "ai.model_selection_cheap_cost_multiplier",
+            "NTK_AI_MODEL_SELECTION_CHEAP_COST_MULTIPLIER",
+            Amount,
+        ),
+        setting(
+            "ai.model_selection_reasoning_cost_multiplier",
+            "NTK_AI_MODEL_SELECTION_REASONING_COST_MULTIPLIER",
+            Amount,
+        ),
+        setting(
+            "ai.model_selection_cheap_cost_cap_usd",
+            "NTK_AI_MODEL_SELECTION_CHEAP_COST_CAP_USD",
+            Amount,
+        ),
+        setting(
+            "ai.model_selection_reasoning_cost_cap_usd",
+            "NTK_AI_MODEL_SELECTION_REASONING_COST_CAP_USD",
+            Amount,
+        ),
+        setting(
+            "ai.model_selection_fallback_to_cheap_on_guardrail",
+            "NTK_AI_MODEL_SELECTION_FALLBACK_TO_CHEAP_ON_GUARDRAIL",
+            Bool,
+        ),
+        setting("chatops.enabled", "NTK_CHATOPS_ENABLED", Bool),
+        setting(
+            "chatops.poll_interval_ms",
+            "NTK_CHATOPS_POLL_INTERVAL_MS",
+            Positive,
+        ),
+        setting("chatops.max_batch", "NTK_CHATOPS_MAX_BATCH", Positive),
+        setting("chatops.allowed_users", "NTK_CHATOPS_ALLOWED_USERS", List),
+        setting(
+            "chatops.allowed_channels",
+            "NTK_CHATOPS_ALLOWED_CHANNELS",
+            List,
+        ),
+        setting(
+            "chatops.allowed_commands",
+            "NTK_CHATOPS_ALLOWED_COMMANDS",
+            List,
+        ),
+        setting(
+            "chatops.rate_limit_per_user",
+            "NTK_CHATOPS_RATE_LIMIT_PER_USER",
+            Positive,
+        ),
+        setting(
+            "chatops.rate_limit_per_channel",
+            "NTK_CHATOPS_RATE_LIMIT_PER_CHANNEL",
+            Positive,
+        ),
+        setting(
+            "chatops.rate_limit_strategy",
+            "NTK_CHATOPS_RATE_LIMIT_STRATEGY",
+            Choice(&["fixed_window", "token_bucket"]),
+        ),
+        setting(
+            "chatops.rate_limit_burst_per_user",
+            "NTK_CHATOPS_RATE_LIMIT_BURST_PER_USER",
+            Positive,
+        ),
+        setting(
+            "chatops.rate_limit_burst_per_channel",
+            "NTK_CHATOPS_RATE_LIMIT_BURST_PER_CHANNEL",
+            Positive,
+        ),
+        setting(
+            "chatops.rate_limit_autotune_profile",
+            "NTK_CHATOPS_RATE_LIMIT_AUTOTUNE_PROFILE",
+            Choice(&["disabled", "conservative", "balanced", "aggressive"]),
+        ),
+        setting(
+            "chatops.rate_limit_window_seconds",
+            "NTK_CHATOPS_RATE_LIMIT_WINDOW_SECONDS",
+            Positive,
+        ),
+        setting(
+            "chatops.telegram_token",
+            "NTK_CHATOPS_TELEGRAM_TOKEN",
+            Secret,
+        ),
+        setting(
+            "chatops.telegram_api_base",
+            "NTK_CHATOPS_TELEGRAM_API_BASE",
+            Text,
+        ),
+        setting(
+            "chatops.telegram_webhook_enabled",
+            "NTK_CHATOPS_TELEGRAM_WEBHOOK_ENABLED",
+            Bool,
+        ),
+        setting(
+            "chatops.telegram_webhook_secret_token",
+            "NTK_CHATOPS_TELEGRAM_WEBHOOK_SECRET_TOKEN",
+            Secret,
+        ),
+        setting("chatops.discord_token", "NTK_CHATOPS_DISCORD_TOKEN", Secret),
+        setting(
+            "chatops.discord_api_base",
+            "NTK_CHATOPS_DISCORD_API_BASE",
+            Text,
+        ),
+        setting(
+            "chatops.discord_interactions_enabled",
+            "NTK_CHATOPS_DISCORD_INTERACTIONS_ENABLED",
+            Bool,
+        ),
+        setting(
+            "chatops.discord_interactions_public_key",
+            "NTK_CHATOPS_DISCORD_INTERACTIONS_PUBLIC_KEY",
+            Text,
+        ),
+        setting(
+            "chatops.discord_channels",
+            "NTK_CHATOPS_DISCORD_CHANNELS",
+            List,
+        ),
+        setting("chatops.audit_path", "NTK_CHATOPS_AUDIT_PATH", Path),
+        setting(
+            "chatops.ingress_replay_backend",
+            "NTK_CHATOPS_INGRESS_REPLAY_BACKEND",
+            Choice(&["memory", "file"]),
+        ),
+        setting(
+            "chatops.ingress_replay_file_path",
+            "NTK_CHATOPS_INGRESS_REPLAY_FILE_PATH",
+            Path,
+        ),
+        setting(
+            "chatops.ingress_replay_window_seconds",
+            "NTK_CHATOPS_INGRESS_REPLAY_WINDOW_SECONDS",
+            Positive,
+        ),
+        setting(
+            "chatops.ingress_replay_max_entries",
+            "NTK_CHATOPS_INGRESS_REPLAY_MAX_ENTRIES",
+            Positive,
+        ),
+        setting(
+            "service.automation_profile",
+            "NTK_SERVICE_AUTOMATION_PROFILE",
+            Choice(&["strict", "balanced", "open"]),
+        ),
+        setting(
+            "service.allowed_intents",
+            "NTK_SERVICE_ALLOWED_INTENTS",
+            Intents,
+        ),
+        setting(
+            "service.max_payload_bytes",
+            "NTK_SERVICE_MAX_PAYLOAD_BYTES",
+            Positive,
+        ),
+        setting(
+            "service.submit_budget",
+            "NTK_SERVICE_SUBMIT_BUDGET",
+            Positive,
+        ),
+        setting(
+            "service.submit_window_seconds",
+            "NTK_SERVICE_SUBMIT_WINDOW_SECONDS",
+            Positive,
+        ),
+        setting(
+            "service.max_inflight_tasks",
+            "NTK_SERVICE_MAX_INFLIGHT_TASKS",
+            Positive,
+        ),
+        setting("service.auth_token", "NTK_SERVICE_AUTH_TOKEN", Secret),
+        setting(
+            "service.operator_tokens",
+            "NTK_SERVICE_OPERATOR_TOKENS",
+            Secret,
+        ),
+        setting(
+            "service.metrics_auth",
+            "NTK_SERVICE_METRICS_AUTH",
+            Choice(&["bearer", "none"]),
+        ),
+        setting(
+            "service.http_timeout_ms",
+            "NTK_SERVICE_HTTP_TIMEOUT_MS",
+            Positive,
+        ),
+        setting("tool_scope.enabled", "NTK_TOOL_SCOPE_ENABLED", Bool),
+        setting(
+            "tool_scope.allowed_tools",
+            "NTK_TOOL_SCOPE_ALLOWED_TOOLS",
+            List,
+        ),
+        setting("tool_scope.audit_path", "NTK_TOOL_SCOPE_AUDIT_PATH", Path),
+        setting(
+            "tool_scope.intent_command_execution_tools",
+            "NTK_TOOL_SCOPE_INTENT_COMMAND_EXECUTION_TOOLS",
+            List,
+        ),
+        setting(
+            "tool_scope.intent_ai_ask_tools",
+            "NTK_TOOL_SCOPE_INTENT_AI_ASK_TOOLS",
+            List,
+        ),
+        setting(
+            "tool_scope.intent_ai_plan_tools",
+            "NTK_TOOL_SCOPE_INTENT_AI_PLAN_TOOLS",
+            List,
+        ),
+        setting(
+            "tool_scope.intent_ai_explain_tools",
+            "NTK_TOOL_SCOPE_INTENT_AI_EXPLAIN_TOOLS",
+            List,
+        ),
+        setting(
+            "tool_scope.intent_ai_apply_dry_run_tools",
+            "NTK_TOOL_SCOPE_INTENT_AI_APPLY_DRY_RUN_TOOLS",
+            List,
+        ),
+        setting(
+            "tool_scope.intent_repo_workflow_tools",
+            "NTK_TOOL_SCOPE_INTENT_REPO_WORKFLOW_TOOLS",
+            List,
+        ),
+        setting("repo_workflow.enabled", "NTK_REPO_WORKFLOW_ENABLED", Bool),
+        setting(
+            "repo_workflow.allowed_hosts",
+            "NTK_REPO_WORKFLOW_ALLOWED_HOSTS",
+            List,
+        ),
+        setting(
+            "repo_workflow.allowed_commands",
+            "NTK_REPO_WORKFLOW_ALLOWED_COMMANDS",
+            List,
+        ),
+        setting(
+            "repo_workflow.allow_push",
+            "NTK_REPO_WORKFLOW_ALLOW_PUSH",
+            Bool,
+        ),
+        setting("repo_workflow.allow_pr", "NTK_REPO_WORKFLOW_ALLOW_PR", Bool),
+        setting("repo_workflow.base_dir", "NTK_REPO_WORKFLOW_BASE_DIR", Path),
+        setting("cache.persistent", "NTK_CACHE_PERSISTENT", Bool),
+        setting(
+            "cache.persistent_max_bytes",
+            "NTK_CACHE_PERSISTENT_MAX_BYTES",
+            Positive,
+        ),
+        setting(
+            "cache.persistent_ai_ttl_seconds",
+            "NTK_CACHE_PERSISTENT_AI_TTL_SECONDS",
+            Positive,
+        ),
+        setting(
+            "cache.persistent_manifest_ttl_seconds",
+            "NTK_CACHE_PERSISTENT_MANIFEST_TTL_SECONDS",
+            Positive,
+        ),
+        setting("plugins.allowed_scopes", "NTK_PLUGIN_ALLOWED_SCOPES", List),
+        setting("task_approval.rules", "NTK_TASK_APPROVAL_RULES", List),
+        setting("task_approval.approvers", "NTK_TASK_APPROVERS", List),
+    ]
+};
+
+/// Policy setting for a dotted key, accepting `-` for `_` (`chatops.rate-limit-strategy`).
+#[must_use]
+pub fn policy_setting(key: &str) -> Option<&'static PolicySetting> {
+    let normalized = key.trim().to_ascii_lowercase().replace('-', "_");
+    POLICY_SETTINGS
+        .iter()
+        .find(|setting| setting.key == normalized)
+}
+
+/// Render a TOML value the way the matching environment variable spells it.
+#[must_use]
+pub fn render_policy_value(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(text) => text.clone(),
+        toml::Value::Array(entries) => entries
+            .iter()
+            .map(render_policy_value)
+            .collect::<Vec<_>>()
+            .join(","),
+        other => other.to_string(),
+    }
+}
+
+/// `[ai]` section: provider routing, retries, context and token economy.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct AiConfig {
+    /// Primary provider (`NTK_AI_PROVIDER`).
+    pub provider: Option<String>,
+    /// Ordered provider chain (`NTK_AI_PROVIDER_CHAIN`).
+    pub provider_chain: Option<Vec<String>>,
+    /// Provider used after the primary fails (`NTK_AI_FALLBACK_PROVIDER`).
+    pub fallback_provider: Option<String>,
+    /// OpenAI-compatible endpoint (`NTK_AI_ENDPOINT`).
+    pub endpoint: Option<String>,
+    /// Provider API key (`NTK_AI_API_KEY`).
+    pub api_key: Option<String>,
+    /// Default model (`NTK_AI_MODEL`).
+    pub model: Option<String>,
+    /// Provider HTTP timeout (`NTK_AI_TIMEOUT_MS`).
+    pub timeout_ms: Option<u64>,
+    /// Text returned when the provider is unreachable (`NTK_AI_FALLBACK_TEXT`).
+    pub fallback_text: Option<String>,
+    /// Timeout budget of the primary route (`NTK_AI_PROVIDER_PRIMARY_TIMEOUT_MS`).
+    pub primary_timeout_ms: Option<u64>,
+    /// Timeout budget of the fallback route (`NTK_AI_PROVIDER_SECONDARY_TIMEOUT_MS`).
+    pub secondary_timeout_ms: Option<u64>,
+    /// Retries per request (`NTK_AI_MAX_RETRIES`).
+    pub max_retries: Option<u64>,
+    /// First retry delay (`NTK_AI_RETRY_BASE_MS`).
+    pub retry_base_ms: Option<u64>,
+    /// Retry delay cap (`NTK_AI_RETRY_MAX_MS`).
+    pub retry_max_ms: Option<u64>,
+    /// Overall request timeout (`NTK_AI_REQUEST_TIMEOUT_MS`).
+    pub request_timeout_ms: Option<u64>,
+    /// Requests allowed per rate-limit window (`NTK_AI_RATE_LIMIT_REQUESTS`).
+    pub rate_limit_requests: Option<u64>,
+    /// Rate-limit window (`NTK_AI_RATE_LIMIT_WINDOW_SECONDS`).
+    pub rate_limit_window_seconds: Option<u64>,
+    /// Input price per 1k tokens (`NTK_AI_COST_PER_1K_INPUT_USD`).
+    pub cost_per_1k_input_usd: Option<f64>,
+    /// Output price per 1k tokens (`NTK_AI_COST_PER_1K_OUTPUT_USD`).
+    pub cost_per_1k_output_usd: Option<f64>,
+    /// Per-model prices as `provider/model=input:output` per 1k tokens (`NTK_AI_PRICE_TABLE`).
+    pub price_table: Option<Vec<String>>,
+    /// Spend allowed per UTC day (`NTK_AI_SPEND_CAP_DAILY_USD`).
+    pub spend_cap_daily_usd: Option<f64>,
+    /// Spend allowed per UTC month (`NTK_AI_SPEND_CAP_MONTHLY_USD`).
+    pub spend_cap_monthly_usd: Option<f64>,
+    /// Spend ledger file (`NTK_AI_SPEND_LEDGER_PATH`).
+    pub spend_ledger_path: Option<String>,
+    /// Workspace files attached as context (`NTK_AI_CONTEXT_MAX_FILES`).
+    pub context_max_files: Option<u64>,
+    /// Bytes read per context file (`NTK_AI_CONTEXT_MAX_FILE_BYTES`).
+    pub context_max_file_bytes: Option<u64>,
+    /// Total context bytes (`NTK_AI_CONTEXT_MAX_BYTES`).
+    pub context_max_bytes: Option<u64>,
+    /// How context files are picked (`NTK_AI_CONTEXT_MODE`).
+    pub context_mode: Option<String>,
+    /// Extra context allowlist paths (`NTK_AI_CONTEXT_PATHS`).
+    pub context_paths: Option<Vec<String>>,
+    /// Attach a summary of the nearest manifest (`NTK_AI_MANIFEST_CONTEXT`).
+    pub manifest_context: Option<bool>,
+    /// Summarize old exchanges once a session hits its budget (`NTK_AI_SESSION_SUMMARY`).
+    pub session_summary: Option<bool>,
+    /// Shortest token checked for entropy (`NTK_AI_REDACTION_ENTROPY_MIN_LENGTH`).
+    pub redaction_entropy_min_length: Option<u64>,
+    /// Bits per character that flag a token (`NTK_AI_REDACTION_ENTROPY_MIN_BITS`).
+    pub redaction_entropy_min_bits: Option<f64>,
+    /// Input tokens per request (`NTK_AI_TOKEN_BUDGET_INPUT_PER_REQUEST`).
+    pub token_budget_input_per_request: Option<u64>,
+    /// Output tokens per request (`NTK_AI_TOKEN_BUDGET_OUTPUT_PER_REQUEST`).
+    pub token_budget_output_per_request: Option<u64>,
+    /// Total tokens per request (`NTK_AI_TOKEN_BUDGET_TOTAL_PER_REQUEST`).
+    pub token_budget_total_per_request: Option<u64>,
+    /// Total tokens per session (`NTK_AI_TOKEN_BUDGET_SESSION_TOTAL`).
+    pub token_budget_session_total: Option<u64>,
+    /// Cost cap per request (`NTK_AI_COST_BUDGET_USD_PER_REQUEST`).
+    pub cost_budget_usd_per_request: Option<f64>,
+    /// Prompt compaction tier (`NTK_AI_PROMPT_COMPACTION_TIER`).
+    pub prompt_compaction_tier: Option<String>,
+    /// Serve cached answers before calling the provider (`NTK_AI_CACHE_FIRST_ENABLED`).
+    pub cache_first_enabled: Option<bool>,
+    /// p95 latency objective (`NTK_AI_SLO_MAX_P95_LATENCY_MS`).
+    pub slo_max_p95_latency_ms: Option<f64>,
+    /// Success-rate objective (`NTK_AI_SLO_MIN_SUCCESS_RATE_PCT`).
+    pub slo_min_success_rate_pct: Option<f64>,
+    /// Token objective per task (`NTK_AI_SLO_MAX_TOKENS_PER_TASK`).
+    pub slo_max_tokens_per_task: Option<f64>,
+    /// Cost objective per task (`NTK_AI_SLO_MAX_COST_USD_PER_TASK`).
+    pub slo_max_cost_usd_per_task: Option<f64>,
+    /// Route intents to cheap or reasoning models (`NTK_AI_MODEL_SELECTION_ENABLED`).
+    pub model_selection_enabled: Option<bool>,
+    /// Cheap-tier model (`NTK_AI_MODEL_SELECTION_CHEAP_MODEL`).
+    pub model_selection_cheap_model: Option<String>,
+    /// Reasoning-tier model (`NTK_AI_MODEL_SELECTION_REASONING_MODEL`).
+    pub model_selection_reasoning_model: Option<String>,
+    /// Intents routed to the cheap tier (`NTK_AI_MODEL_SELECTION_CHEAP_INTENTS`).
+    pub model_selection_cheap_intents: Option<Vec<String>>,
+    /// Intents routed to the reasoning tier (`NTK_AI_MODEL_SELECTION_REASONING_INTENTS`).
+    pub model_selection_reasoning_intents: Option<Vec<String>>,
+    /// Cost multiplier of the cheap tier (`NTK_AI_MODEL_SELECTION_CHEAP_COST_MULTIPLIER`).
+    pub model_selection_cheap_cost_multiplier: Option<f64>,
+    /// Cost multiplier of the reasoning tier (`NTK_AI_MODEL_SELECTION_REASONING_COST_MULTIPLIER`).
+    pub model_selection_reasoning_cost_multiplier: Option<f64>,
+    /// Cost cap of the cheap tier (`NTK_AI_MODEL_SELECTION_CHEAP_COST_CAP_USD`).
+    pub model_selection_cheap_cost_cap_usd: Option<f64>,
+    /// Cost cap of the reasoning tier (`NTK_AI_MODEL_SELECTION_REASONING_COST_CAP_USD`).
+    pub model_selection_reasoning_cost_cap_usd: Option<f64>,
+    /// Retry on the cheap tier after a guardrail rejection
+    /// (`NTK_AI_MODEL_SELECTION_FALLBACK_TO_CHEAP_ON_GUARDRAIL`).
+    pub model_selection_fallback_to_cheap_on_guardrail: Option<bool>,
+}
+
+/// `[chatops]` section: runtime, authorization, rate limits and ingress.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ChatOpsConfig {
+    /// Start the ChatOps runtime (`NTK_CHATOPS_ENABLED`).
+    pub enabled: Option<bool>,
+    /// Poll interval (`NTK_CHATOPS_POLL_INTERVAL_MS`).
+    pub poll_interval_ms: Option<u64>,
+    /// Messages processed per tick (`NTK_CHATOPS_MAX_BATCH`).
+    pub max_batch: Option<u64>,
+    /// Allowed user ids (`NTK_CHATOPS_ALLOWED_USERS`).
+    pub allowed_users: Option<Vec<String>>,
+    /// Allowed channel ids (`NTK_CHATOPS_ALLOWED_CHANNELS`).
+    pub allowed_channels: Option<Vec<String>>,
+    /// Allowed command scopes (`NTK_CHATOPS_ALLOWED_COMMANDS`).
+    pub allowed_commands: Option<Vec<String>>,
+    /// Requests per user and window (`NTK_CHATOPS_RATE_LIMIT_PER_USER`).
+    pub rate_limit_per_user: Option<u64>,
+    /// Requests per channel and window (`NTK_CHATOPS_RATE_LIMIT_PER_CHANNEL`).
+    pub rate_limit_per_channel: Option<u64>,
+    /// Rate-limit algorithm (`NTK_CHATOPS_RATE_LIMIT_STRATEGY`).
+    pub rate_limit_strategy: Option<String>,
+    /// Token-bucket burst per user (`NTK_CHATOPS_RATE_LIMIT_BURST_PER_USER`).
+    pub rate_limit_burst_per_user: Option<u64>,
+    /// Token-bucket burst per channel (`NTK_CHATOPS_RATE_LIMIT_BURST_PER_CHANNEL`).
+    pub rate_limit_burst_per_channel: Option<u64>,
+    /// Rate-limit auto-tuning profile (`NTK_CHATOPS_RATE_LIMIT_AUTOTUNE_PROFILE`).
+    pub rate_limit_autotune_profile: Option<String>,
+    /// Rate-limit window (`NTK_CHATOPS_RATE_LIMIT_WINDOW_SECONDS`).
+    pub rate_limit_window_seconds: Option<u64>,
+    /// Telegram bot token (`NTK_CHATOPS_TELEGRAM_TOKEN`).
+    pub telegram_token: Option<String>,
+    /// Telegram API base URL (`NTK_CHATOPS_TELEGRAM_API_BASE`).
+    pub telegram_api_base: Option<String>,
+    /// Accept Telegram webhooks (`NTK_CHATOPS_TELEGRAM_WEBHOOK_ENABLED`).
+    pub telegram_webhook_enabled: Option<bool>,
+    /// Telegram webhook secret (`NTK_CHATOPS_TELEGRAM_WEBHOOK_SECRET_TOKEN`).
+    pub telegram_webhook_secret_token: Option<String>,
+    /// Discord bot token (`NTK_CHATOPS_DISCORD_TOKEN`).
+    pub discord_token: Option<String>,
+    /// Discord API base URL (`NTK_CHATOPS_DISCORD_API_BASE`).
+    pub discord_api_base: Option<String>,
+    /// Accept Discord interactions (`NTK_CHATOPS_DISCORD_INTERACTIONS_ENABLED`).
+    pub discord_interactions_enabled: Option<bool>,
+    /// Discord interaction verifying key (`NTK_CHATOPS_DISCORD_INTERACTIONS_PUBLIC_KEY`).
+    pub discord_interactions_public_key: Option<String>,
+    /// Discord channels polled for commands (`NTK_CHATOPS_DISCORD_CHANNELS`).
+    pub discord_channels: Option<Vec<String>>,
+    /// Audit log path (`NTK_CHATOPS_AUDIT_PATH`).
+    pub audit_path: Option<String>,
+    /// Ingress replay cache backend (`NTK_CHATOPS_INGRESS_REPLAY_BACKEND`).
+    pub ingress_replay_backend: Option<String>,
+    /// File replay cache path (`NTK_CHATOPS_INGRESS_REPLAY_FILE_PATH`).
+    pub ingress_replay_file_path: Option<String>,
+    /// Replay detection window (`NTK_CHATOPS_INGRESS_REPLAY_WINDOW_SECONDS`).
+    pub ingress_replay_window_seconds: Option<u64>,
+    /// Replay cache capacity (`NTK_CHATOPS_INGRESS_REPLAY_MAX_ENTRIES`).
+    pub ingress_replay_max_entries: Option<u64>,
+}
+
+/// `[service]` section: automation policy and HTTP surface of `ntk service`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServiceConfig {
+    /// Automation profile (`NTK_SERVICE_AUTOMATION_PROFILE`).
+    pub automation_profile: Option<String>,
+    /// Intents accepted by `/task/submit` (`NTK_SERVICE_ALLOWED_INTENTS`).
+    pub allowed_intents: Option<Vec<String>>,
+    /// Submission payload cap (`NTK_SERVICE_MAX_PAYLOAD_BYTES`).
+    pub max_payload_bytes: Option<u64>,
+    /// Submissions per window (`NTK_SERVICE_SUBMIT_BUDGET`).
+    pub submit_budget: Option<u64>,
+    /// Submission budget window (`NTK_SERVICE_SUBMIT_WINDOW_SECONDS`).
+    pub submit_window_seconds: Option<u64>,
+    /// Concurrent service tasks (`NTK_SERVICE_MAX_INFLIGHT_TASKS`).
+    pub max_inflight_tasks: Option<u64>,
+    /// Bearer token for the HTTP API (`NTK_SERVICE_AUTH_TOKEN`).
+    pub auth_token: Option<String>,
+    /// Per-operator bearer tokens, `id[:kind]=token,...`
+    /// (`NTK_SERVICE_OPERATOR_TOKENS`).
+    pub operator_tokens: Option<String>,
+    /// `GET /metrics` authentication (`NTK_SERVICE_METRICS_AUTH`).
+    pub metrics_auth: Option<String>,
+    /// Request handling timeout (`NTK_SERVICE_HTTP_TIMEOUT_MS`).
+    pub http_timeout_ms: Option<u64>,
+}
+
+/// `[tool_scope]` section: tool allowlists per task intent.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ToolScopeConfig {
+    /// Enforce tool scopes (`NTK_TOOL_SCOPE_ENABLED`).
+    pub enabled: Option<bool>,
+    /// Globally allowed tools (`NTK_TOOL_SCOPE_ALLOWED_TOOLS`).
+    pub allowed_tools: Option<Vec<String>>,
+    /// Audit log path (`NTK_TOOL_SCOPE_AUDIT_PATH`).
+    pub audit_path: Option<String>,
+    /// Tools for `command` tasks (`NTK_TOOL_SCOPE_INTENT_COMMAND_EXECUTION_TOOLS`).
+    pub intent_command_execution_tools: Option<Vec<String>>,
+    /// Tools for `ai-ask` tasks (`NTK_TOOL_SCOPE_INTENT_AI_ASK_TOOLS`).
+    pub intent_ai_ask_tools: Option<Vec<String>>,
+    /// Tools for `ai-plan` tasks (`NTK_TOOL_SCOPE_INTENT_AI_PLAN_TOOLS`).
+    pub intent_ai_plan_tools: Option<Vec<String>>,
+    /// Tools for `ai-explain` tasks (`NTK_TOOL_SCOPE_INTENT_AI_EXPLAIN_TOOLS`).
+    pub intent_ai_explain_tools: Option<Vec<String>>,
+    /// Tools for `ai-apply-dry-run` tasks (`NTK_TOOL_SCOPE_INTENT_AI_APPLY_DRY_RUN_TOOLS`).
+    pub intent_ai_apply_dry_run_tools: Option<Vec<String>>,
+    /// Tools for `repo-workflow` tasks (`NTK_TOOL_SCOPE_INTENT_REPO_WORKFLOW_TOOLS`).
+    pub intent_repo_workflow_tools: Option<Vec<String>>,
+}
+
+/// `[repo_workflow]` section: clone/branch/commit automation policy.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct RepoWorkflowConfig {
+    /// Allow repository workflows (`NTK_REPO_WORKFLOW_ENABLED`).
+    pub enabled: Option<bool>,
+    /// Allowed clone hosts (`NTK_REPO_WORKFLOW_ALLOWED_HOSTS`).
+    pub allowed_hosts: Option<Vec<String>>,
+    /// Allowed command prefixes (`NTK_REPO_WORKFLOW_ALLOWED_COMMANDS`).
+    pub allowed_commands: Option<Vec<String>>,
+    /// Allow `git push` (`NTK_REPO_WORKFLOW_ALLOW_PUSH`).
+    pub allow_push: Option<bool>,
+    /// Allow opening pull requests (`NTK_REPO_WORKFLOW_ALLOW_PR`).
+    pub allow_pr: Option<bool>,
+    /// Workspace root for clones (`NTK_REPO_WORKFLOW_BASE_DIR`).
+    pub base_dir: Option<String>,
+}
+
+/// `[cache]` section: persistent tier of the command-result cache.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Keep AI responses and manifest listings on disk (`NTK_CACHE_PERSISTENT`).
+    pub persistent: Option<bool>,
+    /// Disk budget of the persistent tier (`NTK_CACHE_PERSISTENT_MAX_BYTES`).
+    pub persistent_max_bytes: Option<u64>,
+    /// Lifetime of persisted AI responses (`NTK_CACHE_PERSISTENT_AI_TTL_SECONDS`).
+    pub persistent_ai_ttl_seconds: Option<u64>,
+    /// Lifetime of persisted manifest listings (`NTK_CACHE_PERSISTENT_MANIFEST_TTL_SECONDS`).
+    pub persistent_manifest_ttl_seconds: Option<u64>,
+}
+
+/// `[plugins]` section: which plugin commands may run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PluginsConfig {
+    /// Command scopes plugins may run; unset or `*` allows all (`NTK_PLUGIN_ALLOWED_SCOPES`).
+    pub allowed_scopes: Option<Vec<String>>,
+}
+
+/// `[task_approval]` section: which tasks wait for an operator and who decides.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct TaskApprovalConfig {
+    /// Approval gates; empty or `none` disables them (`NTK_TASK_APPROVAL_RULES`).
+    pub rules: Option<Vec<String>>,
+    /// Operator ids allowed to approve or reject (`NTK_TASK_APPROVERS`).
+    pub approvers: Option<Vec<String>>,
+}
+
+impl AppConfig {
+    /// File values of every policy setting that is set, in table order.
+    #[must_use]
+    pub fn policy_values(&self) -> Vec<(&'static PolicySetting, toml::Value)> {
+        let Ok(root) = toml::Value::try_from(self) else {
+            return Vec::new();
+        };
+        POLICY_SETTINGS
+            .iter()
+            .filter_map(|setting| {
+                root.get(setting.section())
+                    .and_then(|section| section.get(setting.name()))
+                    .map(|value| (setting, value.clone()))
+            })
+            .collect()
+    }
+
+    /// File value of `setting`, rendered like its environment variable.
+    #[must_use]
+    pub fn policy_value(&self, setting: &PolicySetting) -> Option<String> {
+        self.policy_values()
+            .into_iter()
+            .find(|(candidate, _)| candidate.key == setting.key)
+            .map(|(_, value)| render_policy_value(&value))
+    }
+
+    /// Set `setting` from a `/config set` value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the key and the accepted values when `raw`
+    /// does not fit the setting.
+    pub fn set_policy_value(&mut self, setting: &PolicySetting, raw: &str) -> Result<(), String> {
+        let value = setting
+            .kind
+            .parse(raw)
+            .map_err(|error| format!("{}: {error}", setting.key))?;
+        self.replace_policy_value(setting, Some(value))
+    }
+
+    /// Remove `setting` from the file so its default applies again.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the updated configuration cannot be rebuilt.
+    pub fn unset_policy_value(&mut self, setting: &PolicySetting) -> Result<(), String> {
+        self.replace_policy_value(setting, None)
+    }
+
+    fn replace_policy_value(
+        &mut self,
+        setting: &PolicySetting,
+        value: Option<toml::Value>,
+    ) -> Result<(), String> {
+        let mut root = toml::Value::try_from(&*self).map_err(|error| error.to_string())?;
+        let table = root
+            .as_table_mut()
+            .ok_or_else(|| "configuration is not a table".to_string())?;
+        let section = table
+            .entry(setting.section())
+            .or_insert_with(|| toml::Value::Table(toml::map::Map::new()))
+            .as_table_mut()
+            .ok_or_else(|| format!("[{}] is not a table", setting.section()))?;
+        match value {
+            Some(value) => {
+                section.insert(setting.name().to_string(), value);
+            }
+            None => {
+                section.remove(setting.name());
+            }
+        }
+        *self = root
+            .try_into()
+            .map_err(|error: toml::de::Error| format!("{}: {}", setting.key, error.message()))?;
+        Ok(())
+    }
+
+    /// Check every policy value against its setting.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first offending key with the accepted values, for example
+    /// ``[chatops] rate_limit_strategy: expected fixed_window|token_bucket, got `burst` ``.
+    pub fn validate(&self) -> Result<(), String> {
+        for (setting, value) in self.policy_values() {
+            setting
+                .kind
+                .check(&value)
+                .map_err(|error| format!("[{}] {}: {error}", setting.section(), setting.name()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Environment-over-file lookup for policy settings.
+///
+/// Holds the file values of the policy sections; [`PolicySource::var`]
+/// returns the environment variable when it is set and the file value
+/// otherwise, so callers keep their existing parsing and defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PolicySource {
+    file: HashMap<&'static str, String>,
+}
+
+impl PolicySource {
+    /// Policy values of the user and project config files; invalid files
+    /// are logged and skipped.
+    #[must_use]
+    pub fn load() -> Self {
+        Self::from_config(&ConfigLayers::discover().file_config())
+    }
+
+    /// Policy values of the user and project config files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when either file exists but cannot be read, parsed or validated.
+    pub fn try_load() -> crate::Result<Self> {
+        ConfigLayers::try_discover().map(|layers| Self::from_config(&layers.file_config()))
+    }
+
+    /// Policy values of the config file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the file cannot be read, parsed or validated.
+    pub fn try_load_from(path: &Path) -> crate::Result<Self> {
+        AppConfig::load_from(path).map(|config| Self::from_config(&config))
+    }
+
+    /// Policy values of `config`.
+    #[must_use]
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            file: config
+                .policy_values()
+                .into_iter()
+                .map(|(setting, value)| (setting.env, render_policy_value(&value)))
+                .collect(),
+        }
+    }
+
+    /// Value of `env`: the environment variable when set, else the file value.
+    #[must_use]
+    pub fn var(&self, env: &str) -> Option<String> {
+        env::var(env).ok().or_else(|| self.file_var(env))
+    }
+
+    /// Secret value of `env`, with `env:`, `file:` and `keyring:` references
+    /// resolved and the result registered for redaction; `None` when unset
+    /// or blank.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` naming `env` when a reference cannot be resolved.
+    pub fn secret(&self, env: &str) -> Result<Option<String>, String> {
+        match self.var(env) {
+            Some(raw) if !raw.trim().is_empty() => resolve_secret(&raw)
+                .map(Some)
+                .map_err(|error| format!("{env}: {error}")),
+            _ => Ok(None),
+        }
+    }
+
+    /// File value for `env`, ignoring the environment.
+    #[must_use]
+    pub fn file_var(&self, env: &str) -> Option<String> {
+        self.file.get(env).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_settings_are_unique_and_map_to_struct_fields() {
+        let mut config = AppConfig::default();
+        for (index, setting) in POLICY_SETTINGS.iter().enumerate() {
+            assert!(
+                POLICY_SECTIONS.contains(&setting.section()),
+                "{}",
+                setting.key
+            );
+            assert!(
+                POLICY_SETTINGS[index + 1..]
+                    .iter()
+                    .all(|other| other.key != setting.key && other.env != setting.env),
+                "duplicate {}",
+                setting.key
+            );
+            let sample = match setting.kind {
+                PolicyValueKind::Bool => "true",
+                PolicyValueKind::Positive | PolicyValueKind::Amount | PolicyValueKind::Percent => {
+                    "5"
+                }
+                PolicyValueKind::Choice(values) | PolicyValueKind::ChoiceList(values) => values[0],
+                PolicyValueKind::Intents => "ai-ask",
+                _ => "sample",
+            };
+            config
+                .set_policy_value(setting, sample)
+                .unwrap_or_else(|error| panic!("{error}"));
+            assert!(config.policy_value(setting).is_some(), "{}", setting.key);
+        }
+        assert_eq!(config.policy_values().len(), POLICY_SETTINGS.len());
+        config.validate().expect("sample values are valid");
+    }
+
+    #[test]
+    fn set_and_unset_policy_values_roundtrip_through_toml() {
+        let mut config = AppConfig::default();
+        let strategy = policy_setting("chatops.rate-limit-strategy").expect("known key");
+        let users = policy_setting("chatops.allowed_users").expect("known key");
+
+        config.set_policy_value(strategy, "Token_Bucket").unwrap();
+        config.set_policy_value(users, "U1, U2").unwrap();
+        assert_eq!(
+            config.chatops.rate_limit_strategy.as_deref(),
+            Some("token_bucket")
+        );
+        assert_eq!(config.policy_value(users).as_deref(), Some("U1,U2"));
+
+        let parsed: AppConfig = toml::from_str(&toml::to_string_pretty(&config).unwrap()).unwrap();
+        assert_eq!(parsed, config);
+
+        config.unset_policy_value(strategy).unwrap();
+        assert!(config.chatops.rate_limit_strategy.is_none());
+    }
+
+    #[test]
+    fn set_policy_value_reports_accepted_values() {
+        let mut config = AppConfig::default();
+        let error = config
+            .set_policy_value(policy_setting("service.submit_budget").unwrap(), "0")
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "service.submit_budget: expected integer > 0, got `0`"
+        );
+
+        let error = config
+            .set_policy_value(
+                policy_setting("service.allowed_intents").unwrap(),
+                "ai-ask,deploy",
+            )
+            .unwrap_err();
+        assert!(error.contains("got entry `deploy`"), "{error}");
+    }
+
+    #[test]
+    fn validate_names_section_and_key() {
+        let config: AppConfig = toml::from_str(
+            r#"
+[chatops]
+rate_limit_strategy = "burst"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.validate().unwrap_err(),
+            "[chatops] rate_limit_strategy: expected fixed_window|token_bucket, got `burst`"
+        );
+
+        let config: AppConfig = toml::from_str("[ai]\nslo_min_success_rate_pct = 120\n").unwrap();
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .starts_with("[ai] slo_min_success_rate_pct"));
+    }
+
+    #[test]
+    fn unknown_policy_keys_are_rejected_at_parse_time() {
+        let error = toml::from_str::<AppConfig>("[service]\nsubmit_budgt = 3\n").unwrap_err();
+        assert!(error.to_string().contains("submit_budgt"), "{error}");
+    }
+
+    #[test]
+    fn policy_source_prefers_environment_over_file() {
+        let config: AppConfig = toml::from_str(
+            r#"
+[repo_workflow]
+allowed_hosts = ["github.com", "gitlab.com"]
+allow_push = true
+"#,
+        )
+        .unwrap();
+        let source = PolicySource::from_config(&config);
+
+        assert_eq!(
+            source
+                .file_var("NTK_REPO_WORKFLOW_ALLOWED_HOSTS")
+                .as_deref(),
+            Some("github.com,gitlab.com")
+        );
+        assert_eq!(
+            source.file_var("NTK_REPO_WORKFLOW_ALLOW_PUSH").as_deref(),
+            Some("true")
+        );
+        assert!(source.file_var("NTK_REPO_WORKFLOW_ALLOW_PR").is_none());
+
+        env::set_var("NTK_TEST_POLICY_SOURCE_ONLY", "from-env");
+        let source = PolicySource {
+            file: HashMap::from([("NTK_TEST_POLICY_SOURCE_ONLY", "from-file".to_string())]),
+        };
+        assert_eq!(
+            source.var("NTK_TEST_POLICY_SOURCE_ONLY").as_deref(),
+            Some("from-env")
+        );
+        env::remove_var("NTK_TEST_POLICY_SOURCE_ONLY");
+        assert_eq!(
+            source.var("NTK_TEST_POLICY_SOURCE_ONLY").as_deref(),
+            Some("from-file")
+        );
+    }
+}
@@ -0,0 +1,753 @@
+//! Project-local configuration layered over the user config file
+//!
+//! A repository can carry its own `.ntk/config.toml`. It is discovered by
+//! walking up from the current directory and merged between the user file
+//! and environment overrides:
+//!
+//! 1. **Environment variables** (highest)
+//! 2. **Project file** — nearest `.ntk/config.toml`
+//! 3. **User file** — [`AppConfig::default_config_path`]
+//! 4. **Defaults** (lowest)
+//!
+//! Keys that run commands or send workspace data elsewhere (see
+//! [`TRUST_REQUIRED_KEYS`]) are only honoured once the project is trusted.
+//! Trust is recorded in `trusted_projects.toml` next to the user config
+//! together with a fingerprint of the file, so editing a trusted project
+//! file asks again.
+//!
+//! ```toml
+//! # <repo>/.ntk/config.toml
+//! [templates]
+//! directory = "templates"   # relative to the repository root
+//!
+//! [ai]
+//! model = "gpt-4.1-mini"
+//! context_paths = ["docs", "crates/core/src"]
+//! ```
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+
+use crate::config::{resolve_home_dir, AppConfig};
+use crate::policy::POLICY_SETTINGS;
+
+/// Location of the project file relative to the project root.
+pub const PROJECT_CONFIG_PATH: &str = ".ntk/config.toml";
+
+/// Sections and keys a project file may only set once the project is trusted.
+///
+/// Entries without a dot cover the whole section.
+pub const TRUST_REQUIRED_KEYS: &[&str] = &[
+    "shell",
+    "chatops",
+    "service",
+    "tool_scope",
+    "repo_workflow",
+    "plugins",
+    "task_approval",
+    "general.service_endpoint",
+    "ai.provider",
+    "ai.provider_chain",
+    "ai.fallback_provider",
+    "ai.endpoint",
+    "ai.api_key",
+    "ai.price_table",
+    "ai.spend_cap_daily_usd",
+    "ai.spend_cap_monthly_usd",
+    "ai.spend_ledger_path",
+    "ai.cost_per_1k_input_usd",
+    "ai.cost_per_1k_output_usd",
+    "ai.cost_budget_usd_per_request",
+    "ai.token_budget_input_per_request",
+    "ai.token_budget_output_per_request",
+    "ai.token_budget_total_per_request",
+    "ai.token_budget_session_total",
+    "ai.slo_max_cost_usd_per_task",
+    "ai.redaction_entropy_min_length",
+    "ai.redaction_entropy_min_bits",
+];
+
+/// Environment variables overriding the non-policy keys.
+const CONFIG_ENV_VARS: &[(&str, &str)] = &[
+    ("general.verbose", "NTK_VERBOSE"),
+    ("general.log_level", "NTK_LOG_LEVEL"),
+    ("general.footer_output", "NTK_FOOTER_OUTPUT"),
+    ("general.runtime_mode", "NTK_RUNTIME_MODE"),
+    ("general.attention_bell", "NTK_ATTENTION_BELL"),
+    (
+        "general.attention_desktop_notification",
+        "NTK_ATTENTION_DESKTOP_NOTIFICATION",
+    ),
+    (
+        "general.attention_unfocused_only",
+        "NTK_ATTENTION_UNFOCUSED_ONLY",
+    ),
+    ("general.predictive_input", "NTK_PREDICTIVE_INPUT"),
+    ("general.ai_session_retention", "NTK_AI_SESSION_RETENTION"),
+    ("general.service_endpoint", "NTK_TASK_SERVICE_ENDPOINT"),
+    ("display.color", "NTK_COLOR"),
+    ("display.unicode", "NTK_UNICODE"),
+    ("templates.directory", "NTK_TEMPLATE_DIR"),
+    ("shell.default_shell", "NTK_SHELL"),
+];
+
+/// Whether a project file needs trust before `key` (`section.name`) applies.
+#[must_use]
+pub fn requires_trust(key: &str) -> bool {
+    let section = key.split_once('.').map_or(key, |(section, _)| section);
+    TRUST_REQUIRED_KEYS
+        .iter()
+        .any(|entry| *entry == key || *entry == section)
+}
+
+/// Environment variable that overrides `key` (`section.name`), if any.
+#[must_use]
+pub fn config_env_var(key: &str) -> Option<&'static str> {
+    CONFIG_ENV_VARS
+        .iter()
+        .find(|(candidate, _)| *candidate == key)
+        .map(|(_, env)| *env)
+        .or_else(|| {
+            POLICY_SETTINGS
+                .iter()
+                .find(|setting| setting.key == key)
+                .map(|setting| setting.env)
+        })
+}
+
+/// Layer that supplied an effective configuration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigOrigin {
+    /// Built-in default.
+    Default,
+    /// User config file.
+    User,
+    /// Project `.ntk/config.toml`.
+    Project,
+    /// Environment variable.
+    Env,
+}
+
+impl fmt::Display for ConfigOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::User => write!(f, "user"),
+            Self::Project => write!(f, "project"),
+            Self::Env => write!(f, "env"),
+        }
+    }
+}
+
+/// A parsed project `.ntk/config.toml`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectConfig {
+    /// Path of the project file.
+    pub path: PathBuf,
+    /// Directory holding `.ntk/`.
+    pub root: PathBuf,
+    values: toml::Value,
+    fingerprint: String,
+}
+
+impl ProjectConfig {
+    /// Nearest `.ntk/config.toml` at or above `start`.
+    ///
+    /// The home directory is skipped so a user-level `~/.ntk/config.toml`
+    /// is never mistaken for a project file.
+    #[must_use]
+    pub fn discover(start: &Path) -> Option<PathBuf> {
+        let home = resolve_home_dir();
+        start
+            .ancestors()
+            .filter(|dir| home.as_deref() != Some(*dir))
+            .map(|dir| dir.join(PROJECT_CONFIG_PATH))
+            .find(|path| path.is_file())
+    }
+
+    /// Parse and validate the project file at `path`.
+    ///
+    /// A relative `templates.directory` is resolved against the project root.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the file cannot be read, parsed or validated.
+    pub fn load(path: &Path) -> crate::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        let mut values: toml::Value = toml::from_str(&content)?;
+        let config: AppConfig = values.clone().try_into()?;
+        config
+            .validate()
+            .map_err(|error| anyhow::anyhow!("{}: {error}", path.display()))?;
+
+        let root = path
+            .parent()
+            .and_then(Path::parent)
+            .map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+        if let Some(directory) = values
+            .get_mut("templates")
+            .and_then(|section| section.get_mut("directory"))
+        {
+            if let Some(relative) = directory
+                .as_str()
+                .filter(|dir| !dir.starts_with('~') && Path::new(dir).is_relative())
+            {
+                *directory = toml::Value::String(root.join(relative).display().to_string());
+            }
+        }
+
+        info!(path = %path.display(), "Project configuration loaded");
+        Ok(Self {
+            path: path.to_path_buf(),
+            root,
+            values,
+            fingerprint: fingerprint(&content),
+        })
+    }
+
+    /// Every `section.name` key the file sets.
+    #[must_use]
+    pub fn keys(&self) -> Vec<String> {
+        layer_keys(&self.values)
+    }
+
+    /// Keys the file sets that only apply once the project is trusted.
+    #[must_use]
+    pub fn trust_required_keys(&self) -> Vec<String> {
+        self.keys()
+            .into_iter()
+            .filter(|key| requires_trust(key))
+            .collect()
+    }
+
+    /// The file values, without trust-gated keys unless `trusted`.
+    fn applied_values(&self, trusted: bool) -> toml::Value {
+        let mut values = self.values.clone();
+        if !trusted {
+            if let Some(table) = values.as_table_mut() {
+                for (section, entries) in table.iter_mut() {
+                    if let Some(entries) = entries.as_table_mut() {
+                        entries.retain(|name, _| !requires_trust(&format!("{section}.{name}")));
+                    }
+                }
+            }
+        }
+        values
+    }
+}
+
+/// Projects the user has trusted, keyed by project file path.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrustStore {
+    path: Option<PathBuf>,
+    projects: BTreeMap<String, String>,
+}
+
+impl TrustStore {
+    /// `trusted_projects.toml` next to the user config file.
+    #[must_use]
+    pub fn default_path() -> Option<PathBuf> {
+        AppConfig::default_config_path().map(|path| path.with_file_name("trusted_projects.toml"))
+    }
+
+    /// Store at [`TrustStore::default_path`].
+    #[must_use]
+    pub fn load() -> Self {
+        Self::default_path()
+            .map(|path| Self::load_from(&path))
+            .unwrap_or_default()
+    }
+
+    /// Store at `path`; empty when the file is missing or unreadable.
+    #[must_use]
+    pub fn load_from(path: &Path) -> Self {
+        let projects = match fs::read_to_string(path) {
+            Ok(content) => toml::from_str::<TrustFile>(&content)
+                .map(|file| file.projects)
+                .unwrap_or_else(|error| {
+                    warn!(path = %path.display(), error = %error, "Ignoring invalid trust store");
+                    BTreeMap::new()
+                }),
+            Err(_) => BTreeMap::new(),
+        };
+        Self {
+            path: Some(path.to_path_buf()),
+            projects,
+        }
+    }
+
+    /// Whether `project` was trusted with its current contents.
+    #[must_use]
+    pub fn is_trusted(&self, project: &ProjectConfig) -> bool {
+        self.projects.get(&store_key(&project.path)) == Some(&project.fingerprint)
+    }
+
+    /// Record `project` as trusted and persist the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the store location is unknown or cannot be written.
+    pub fn trust(&mut self, project: &ProjectConfig) -> crate::Result<()> {
+        let path = self
+            .path
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?;
+        self.projects
+            .insert(store_key(&project.path), project.fingerprint.clone());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = TrustFile {
+            projects: self.projects.clone(),
+        };
+        fs::write(&path, toml::to_string_pretty(&file)?)?;
+        info!(project = %project.path.display(), "Project configuration trusted");
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct TrustFile {
+    #[serde(default)]
+    projects: BTreeMap<String, String>,
+}
+
+/// User and project config files, merged below environment overrides.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigLayers {
+    /// User config file, when it exists.
+    pub user_path: Option<PathBuf>,
+    /// Project config file, when one was discovered.
+    pub project: Option<ProjectConfig>,
+    /// Whether the project file's trust-gated keys apply.
+    pub project_trusted: bool,
+    user: Option<toml::Value>,
+}
+
+impl ConfigLayers {
+    /// Layers for the current directory; invalid files are logged and skipped.
+    #[must_use]
+    pub fn discover() -> Self {
+        let user = AppConfig::default_config_path().filter(|path| path.exists());
+        let mut layers = Self::load_user(user.as_deref()).unwrap_or_else(|error| {
+            warn!(error = %error, "Failed to load config file, using defaults");
+            Self::default()
+        });
+        if let Some(path) = env::current_dir()
+            .ok()
+            .and_then(|dir| ProjectConfig::discover(&dir))
+        {
+            match ProjectConfig::load(&path) {
+                Ok(project) => layers.attach_project(project, &TrustStore::load()),
+                Err(error) => warn!(
+                    path = %path.display(),
+                    error = %error,
+                    "Failed to load project config file, ignoring it"
+                ),
+            }
+        }
+        layers
+    }
+
+    /// Layers for the current directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the user or project file exists but is invalid.
+    pub fn try_discover() -> crate::Result<Self> {
+        let start = env::current_dir()?;
+        let user = AppConfig::default_config_path().filter(|path| path.exists());
+        Self::try_load(user.as_deref(), Some(&start), &TrustStore::load())
+    }
+
+    /// Layers from the user file at `user` and the project file above `start`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when either file cannot be read, parsed or validated.
+    pub fn try_load(
+        user: Option<&Path>,
+        start: Option<&Path>,
+        trust: &TrustStore,
+    ) -> crate::Result<Self> {
+        let mut layers = Self::load_user(user)?;
+        if let Some(path) = start.and_then(ProjectConfig::discover) {
+            layers.attach_project(ProjectConfig::load(&path)?, trust);
+        }
+        Ok(layers)
+    }
+
+    fn load_user(path: Option<&Path>) -> crate::Result<Self> {
+        let Some(path) = path else {
+            debug!("No config file found, using defaults");
+            return Ok(Self::default());
+        };
+        AppConfig::load_from(path)?;
+        let values = toml::from_str(&fs::read_to_string(path)?)?;
+        Ok(Self {
+            user_path: Some(path.to_path_buf()),
+            user: Some(values),
+            ..Self::default()
+        })
+    }
+
+    fn attach_project(&mut self, project: ProjectConfig, trust: &TrustStore) {
+        if self.user_path.as_deref() == Some(project.path.as_path()) {
+            return;
+        }
+        self.project_trusted = trust.is_trusted(&project);
+        self.project = Some(project);
+    }
+
+    /// Project keys that are set but not applied because the project is untrusted.
+    #[must_use]
+    pub fn ignored_project_keys(&self) -> Vec<String> {
+        match &self.project {
+            Some(project) if !self.project_trusted => project.trust_required_keys(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// User and project files merged, without environment overrides.
+    #[must_use]
+    pub fn file_config(&self) -> AppConfig {
+        let mut merged = self
+            .user
+            .clone()
+            .unwrap_or_else(|| toml::Value::Table(toml::map::Map::new()));
+        if let Some(project) = &self.project {
+            merge_layer(&mut merged, project.applied_values(self.project_trusted));
+        }
+        merged.try_into().unwrap_or_else(|error: toml::de::Error| {
+            warn!(error = %error, "Failed to merge config layers, using defaults");
+            AppConfig::default()
+        })
+    }
+
+    /// Effective configuration: files merged, then environment overrides.
+    #[must_use]
+    pub fn resolve(&self) -> AppConfig {
+        let mut config = self.file_config();
+        config.apply_env_overrides();
+        config
+    }
+
+    /// Layer that supplies the effective value of `key` (`section.name`).
+    #[must_use]
+    pub fn origin(&self, key: &str) -> ConfigOrigin {
+        let key = key.trim().replace('-', "_");
+        if config_env_var(&key).is_some_and(|var| env::var_os(var).is_some())
+            || (key == "display.color" && env::var_os("NO_COLOR").is_some())
+        {
+            return ConfigOrigin::Env;
+        }
+        let in_project = self.project.as_ref().is_some_and(|project| {
+            lookup(&project.values, &key).is_some()
+                && (self.project_trusted || !requires_trust(&key))
+        });
+        if in_project {
+            ConfigOrigin::Project
+        } else if self
+            .user
+            .as_ref()
+            .is_some_and(|user| lookup(user, &key).is_some())
+        {
+            ConfigOrigin::User
+        } else {
+            ConfigOrigin::Default
+        }
+    }
+}
+
+fn layer_keys(values: &toml::Value) -> Vec<String> {
+    values
+        .as_table()
+        .into_iter()
+        .flatten()
+        .filter_map(|(section, entries)| entries.as_table().map(|entries| (section, entries)))
+        .flat_map(|(section, entries)| entries.keys().map(move |name| format!("{section}.{name}")))
+        .collect()
+}
+
+fn lookup<'a>(values: &'a toml::Value, key: &str) -> Option<&'a toml::Value> {
+    let (section, name) = key.split_once('.')?;
+    values.get(section)?.get(name)
+}
+
+fn merge_layer(base: &mut toml::Value, overlay: toml::Value) {
+    let (Some(base), toml::Value::Table(overlay)) = (base.as_table_mut(), overlay) else {
+        return;
+    };
+    for (section, entries) in overlay {
+        match (base.get_mut(&section), entries) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(entries)) => {
+                existing.extend(entries);
+            }
+            (_, entries) => {
+                base.insert(section, entries);
+            }
+        }
+    }
+}
+
+fn store_key(path: &Path) -> String {
+    fs::canonicalize(path)
+        .unwrap_or_else(|_| path.to_path_buf())
+        .display()
+        .to_string()
+}
+
+/// FNV-1a digest of the file contents; stable across toolchains.
+fn fingerprint(content: &str) -> String {
+    let hash = content
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("fnv1a64:{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn discover_walks_up_to_the_nearest_project_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path().join("repo").join(PROJECT_CONFIG_PATH);
+        write(&project, "[general]\nlog_level = \"debug\"\n");
+        let nested = dir.path().join("repo").join("src").join("deep");
+        fs::create_dir_all(&nested).unwrap();
+
+        assert_eq!(ProjectConfig::discover(&nested), Some(project));
+        assert_eq!(ProjectConfig::discover(dir.path()), None);
+    }
+
+    #[test]
+    fn project_layer_overrides_user_file_and_gates_untrusted_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let user = dir.path().join("user.toml");
+        write(
+            &user,
+            "[general]\nlog_level = \"warn\"\nverbose = true\n\n[ai]\nmodel = \"user-model\"\n",
+        );
+        let repo = dir.path().join("repo");
+        write(
+            &repo.join(PROJECT_CONFIG_PATH),
+            "[general]\nlog_level = \"debug\"\n\n[templates]\ndirectory = \"tpl\"\n\n\
+             [shell]\ndefault_shell = \"zsh\"\n\n[ai]\nmodel = \"project-model\"\n",
+        );
+        let store_path = dir.path().join("trusted_projects.toml");
+
+        let layers = ConfigLayers::try_load(
+            Some(&user),
+            Some(&repo),
+            &TrustStore::load_from(&store_path),
+        )
+        .unwrap();
+        assert!(!layers.project_trusted);
+        assert_eq!(layers.ignored_project_keys(), ["shell.default_shell"]);
+
+        let config = layers.file_config();
+        assert_eq!(config.general.log_level, "debug");
+        assert!(config.general.verbose);
+        assert_eq!(config.ai.model.as_deref(), Some("project-model"));
+        assert!(config.shell.default_shell.is_none());
+        assert_eq!(
+            config.templates.directory,
+            Some(repo.join("tpl").display().to_string())
+        );
+        assert_eq!(layers.origin("general.log_level"), ConfigOrigin::Project);
+        assert_eq!(layers.origin("general.verbose"), ConfigOrigin::User);
+        assert_eq!(layers.origin("shell.default_shell"), ConfigOrigin::Default);
+        assert_eq!(layers.origin("display.unicode"), ConfigOrigin::Default);
+
+        let mut store = TrustStore::load_from(&store_path);
+        store.trust(layers.project.as_ref().unwrap()).unwrap();
+        let trusted = ConfigLayers::try_load(
+            Some(&user),
+            Some(&repo),
+            &TrustStore::load_from(&store_path),
+        )
+        .unwrap();
+        assert!(trusted.project_trusted);
+        assert_eq!(
+            trusted.file_config().shell.default_shell.as_deref(),
+            Some("zsh")
+        );
+        assert_eq!(trusted.origin("shell.default_shell"), ConfigOrigin::Project);
+    }
+
+    #[test]
+    fn untrusted_project_cannot_change_ai_spend_policy() {
+        let dir = tempfile::tempdir().unwrap();
+        let user = dir.path().join("user.toml");
+        write(
+            &user,
+            "[ai]\nspend_cap_daily_usd = 5.0\nprice_table = [\"openai/gpt-4o=0.005:0.015\"]\n",
+        );
+        let repo = dir.path().join("repo");
+        write(
+            &repo.join(PROJECT_CONFIG_PATH),
+            "[ai]\nmodel = \"project-model\"\nprice_table = [\"openai/gpt-4o=0:0\"]\n\
+             spend_cap_daily_usd = 1000.0\nspend_cap_monthly_usd = 1000.0\n\
+             spend_ledger_path = \"/tmp/elsewhere.jsonl\"\n\
+             cost_per_1k_input_usd = 0.000001\ncost_per_1k_output_usd = 0.000001\n\
+             cost_budget_usd_per_request = 1000.0\n\
+             token_budget_input_per_request = 10000000\n\
+             token_budget_output_per_request = 10000000\n\
+             token_budget_total_per_request = 10000000\n\
+             token_budget_session_total = 10000000\n\
+             slo_max_cost_usd_per_task = 1000.0\n",
+        );
+
+        let layers = ConfigLayers::try_load(
+            Some(&user),
+            Some(&repo),
+            &TrustStore::load_from(&dir.path().join("trusted_projects.toml")),
+        )
+        .unwrap();
+        assert_eq!(
+            layers.ignored_project_keys(),
+            [
+                "ai.cost_budget_usd_per_request",
+                "ai.cost_per_1k_input_usd",
+                "ai.cost_per_1k_output_usd",
+                "ai.price_table",
+                "ai.slo_max_cost_usd_per_task",
+                "ai.spend_cap_daily_usd",
+                "ai.spend_cap_monthly_usd",
+                "ai.spend_ledger_path",
+                "ai.token_budget_input_per_request",
+                "ai.token_budget_output_per_request",
+                "ai.token_budget_session_total",
+                "ai.token_budget_total_per_request",
+            ]
+        );
+
+        let config = layers.file_config();
+        assert_eq!(config.ai.model.as_deref(), Some("project-model"));
+        assert_eq!(
+            config.ai.price_table,
+            Some(vec!["openai/gpt-4o=0.005:0.015".to_string()])
+        );
+        assert_eq!(config.ai.spend_cap_daily_usd, Some(5.0));
+        assert!(config.ai.spend_cap_monthly_usd.is_none());
+        assert!(config.ai.spend_ledger_path.is_none());
+        assert!(config.ai.cost_per_1k_input_usd.is_none());
+        assert!(config.ai.cost_per_1k_output_usd.is_none());
+        assert!(config.ai.cost_budget_usd_per_request.is_none());
+        assert!(config.ai.token_budget_input_per_request.is_none());
+        assert!(config.ai.token_budget_output_per_request.is_none());
+        assert!(config.ai.token_budget_total_per_request.is_none());
+        assert!(config.ai.token_budget_session_total.is_none());
+        assert!(config.ai.slo_max_cost_usd_per_task.is_none());
+    }
+
+    #[test]
+    fn untrusted_project_cannot_change_task_approval_policy() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("repo");
+        write(
+            &repo.join(PROJECT_CONFIG_PATH),
+            "[task_approval]\nrules = [\"none\"]\napprovers = [\"mallory\"]\n",
+        );
+
+        let layers = ConfigLayers::try_load(
+            None,
+            Some(&repo),
+            &TrustStore::load_from(&dir.path().join("trusted_projects.toml")),
+        )
+        .unwrap();
+        assert_eq!(
+            layers.ignored_project_keys(),
+            ["task_approval.approvers", "task_approval.rules"]
+        );
+        assert_eq!(
+            layers.file_config().task_approval,
+            crate::policy::TaskApprovalConfig::default()
+        );
+    }
+
+    #[test]
+    fn untrusted_project_cannot_weaken_ai_context_redaction() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("repo");
+        write(
+            &repo.join(PROJECT_CONFIG_PATH),
+            "[ai]\nredaction_entropy_min_length = 4096\nredaction_entropy_min_bits = 100.0\n",
+        );
+
+        let layers = ConfigLayers::try_load(
+            None,
+            Some(&repo),
+            &TrustStore::load_from(&dir.path().join("trusted_projects.toml")),
+        )
+        .unwrap();
+        assert_eq!(
+            layers.ignored_project_keys(),
+            [
+                "ai.redaction_entropy_min_bits",
+                "ai.redaction_entropy_min_length",
+            ]
+        );
+
+        let policy = crate::policy::PolicySource::from_config(&layers.file_config());
+        assert!(policy
+            .file_var("NTK_AI_REDACTION_ENTROPY_MIN_LENGTH")
+            .is_none());
+        assert!(policy
+            .file_var("NTK_AI_REDACTION_ENTROPY_MIN_BITS")
+            .is_none());
+    }
+
+    #[test]
+    fn editing_a_trusted_project_file_revokes_trust() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(PROJECT_CONFIG_PATH);
+        write(&path, "[shell]\ndefault_shell = \"bash\"\n");
+        let store_path = dir.path().join("trusted_projects.toml");
+
+        let mut store = TrustStore::load_from(&store_path);
+        store.trust(&ProjectConfig::load(&path).unwrap()).unwrap();
+        assert!(TrustStore::load_from(&store_path).is_trusted(&ProjectConfig::load(&path).unwrap()));
+
+        write(&path, "[shell]\ndefault_shell = \"sh -c 'curl evil'\"\n");
+        assert!(
+            !TrustStore::load_from(&store_path).is_trusted(&ProjectConfig::load(&path).unwrap())
+        );
+    }
+
+    #[test]
+    fn invalid_project_file_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(PROJECT_CONFIG_PATH);
+        write(&path, "[chatops]\nrate_limit_strategy = \"burst\"\n");
+
+        let error = ProjectConfig::load(&path).unwrap_err().to_string();
+        assert!(error.contains("rate_limit_strategy"));
+    }
+
+    #[test]
+    fn requires_trust_matches_sections_and_keys() {
+        assert!(requires_trust("repo_workflow.allowed_commands"));
+        assert!(requires_trust("ai.endpoint"));
+        assert!(requires_trust("ai.spend_cap_monthly_usd"));
+        assert!(!requires_trust("ai.model"));
+        assert!(!requires_trust("templates.directory"));
+        assert_eq!(config_env_var("display.color"), Some("NTK_COLOR"));
+        assert_eq!(
+            config_env_var("chatops.rate_limit_strategy"),
+            Some("NTK_CHATOPS_RATE_LIMIT_STRATEGY")
+        );
+    }
+}
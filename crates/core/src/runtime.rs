@@ -418,6 +418,85 @@ impl Default for ControlPolicyContext {
     }
 }
 
+/// W3C trace-context header carrying the caller's trace and parent span.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+/// W3C trace-context header carrying vendor-specific trace state.
+pub const TRACESTATE_HEADER: &str = "tracestate";
+
+const MAX_TRACESTATE_LEN: usize = 512;
+
+/// W3C trace context captured at ingress and carried across the task queue.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TraceContext {
+    /// Validated `traceparent` header value (lower-case).
+    pub traceparent: String,
+    /// Optional `tracestate` header value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracestate: Option<String>,
+}
+
+impl TraceContext {
+    /// Parse W3C `traceparent`/`tracestate` header values.
+    ///
+    /// Returns `None` when `traceparent` is missing or malformed; an oversized or
+    /// empty `tracestate` is dropped while the parent is kept.
+    #[must_use]
+    pub fn parse(traceparent: Option<&str>, tracestate: Option<&str>) -> Option<Self> {
+        let traceparent = traceparent?.trim().to_ascii_lowercase();
+        if !is_valid_traceparent(&traceparent) {
+            return None;
+        }
+        let tracestate = tracestate
+            .map(str::trim)
+            .filter(|value| !value.is_empty() && value.len() <= MAX_TRACESTATE_LEN)
+            .map(ToOwned::to_owned);
+
+        Some(Self {
+            traceparent,
+            tracestate,
+        })
+    }
+
+    /// 32-hex-digit trace identifier.
+    #[must_use]
+    pub fn trace_id(&self) -> &str {
+        &self.traceparent[3..35]
+    }
+
+    /// 16-hex-digit parent span identifier.
+    #[must_use]
+    pub fn parent_id(&self) -> &str {
+        &self.traceparent[36..52]
+    }
+
+    /// Returns `true` when the caller marked the trace as sampled.
+    #[must_use]
+    pub fn is_sampled(&self) -> bool {
+        u8::from_str_radix(&self.traceparent[53..55], 16).is_ok_and(|flags| flags & 0x01 == 0x01)
+    }
+}
+
+fn is_valid_traceparent(value: &str) -> bool {
+    let fields: Vec<&str> = value.split('-').collect();
+    if fields.len() < 4 {
+        return false;
+    }
+    let is_hex = |field: &str, len: usize| {
+        field.len() == len && field.bytes().all(|byte| byte.is_ascii_hexdigit())
+    };
+    let is_zero = |field: &str| field.bytes().all(|byte| byte == b'0');
+    let (version, trace_id, parent_id, flags) = (fields[0], fields[1], fields[2], fields[3]);
+
+    if !is_hex(version, 2) || version == "ff" || (version == "00" && fields.len() != 4) {
+        return false;
+    }
+    is_hex(trace_id, 32)
+        && !is_zero(trace_id)
+        && is_hex(parent_id, 16)
+        && !is_zero(parent_id)
+        && is_hex(flags, 2)
+}
+
 /// Transport-neutral control-plane envelope for admitted work.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ControlEnvelope {
@@ -435,6 +514,9 @@ pub struct ControlEnvelope {
     pub task: TaskIntent,
     /// Policy decision for the request.
     pub policy: ControlPolicyContext,
+    /// Optional W3C trace context captured at ingress.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_context: Option<TraceContext>,
 }
 
 impl ControlEnvelope {
@@ -455,6 +537,7 @@ impl ControlEnvelope {
             session,
             task,
             policy: ControlPolicyContext::default(),
+            trace_context: None,
         }
     }
 
@@ -471,6 +554,13 @@ impl ControlEnvelope {
         self
     }
 
+    /// Attach the W3C trace context captured at ingress.
+    #[must_use]
+    pub fn with_trace_context(mut self, trace_context: Option<TraceContext>) -> Self {
+        self.trace_context = trace_context;
+        self
+    }
+
     /// Attach an explicit policy context.
     #[must_use]
     pub fn with_policy(mut self, policy: ControlPolicyContext) -> Self {
@@ -492,6 +582,8 @@ pub enum TaskExecutionStatus {
     /// Task accepted and queued, waiting for worker pickup.
     #[default]
     Queued,
+    /// Task parked by an approval gate until an operator approves or rejects it.
+    AwaitingApproval,
     /// Task currently executing.
     Running,
     /// Task completed successfully.
@@ -503,6 +595,20 @@ pub enum TaskExecutionStatus {
 }
 
 impl TaskExecutionStatus {
+    /// Stable lowercase label (`queued`, `awaiting_approval`, `running`, `succeeded`, `failed`,
+    /// `cancelled`).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::AwaitingApproval => "awaiting_approval",
+            Self::Running => "running",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
     /// Returns `true` when task state is terminal.
     #[must_use]
     pub const fn is_terminal(self) -> bool {
@@ -513,7 +619,8 @@ impl TaskExecutionStatus {
     #[must_use]
     pub fn can_transition_to(self, next: Self) -> bool {
         match (self, next) {
-            (Self::Queued, Self::Running | Self::Cancelled) => true,
+            (Self::Queued, Self::Running | Self::AwaitingApproval | Self::Cancelled) => true,
+            (Self::AwaitingApproval, Self::Queued | Self::Cancelled) => true,
             (Self::Running, Self::Succeeded | Self::Failed | Self::Cancelled) => true,
             // Allow idempotent writes for status updates in persistence layers.
             (current, target) if current == target => true,
@@ -634,6 +741,70 @@ mod tests {
         assert_eq!(policy.audit_store, None);
     }
 
+    #[test]
+    fn trace_context_parses_valid_traceparent() {
+        let trace = TraceContext::parse(
+            Some(" 00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01 "),
+            Some("vendor=abc"),
+        )
+        .expect("traceparent should parse");
+
+        assert_eq!(trace.trace_id(), "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(trace.parent_id(), "00f067aa0ba902b7");
+        assert!(trace.is_sampled());
+        assert_eq!(trace.tracestate.as_deref(), Some("vendor=abc"));
+    }
+
+    #[test]
+    fn trace_context_rejects_malformed_traceparent() {
+        for invalid in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+            "00-4bf92f3577b34da6a3ce929d0e0e473z-00f067aa0ba902b7-01",
+        ] {
+            assert_eq!(TraceContext::parse(Some(invalid), None), None, "{invalid}");
+        }
+        assert_eq!(TraceContext::parse(None, Some("vendor=abc")), None);
+    }
+
+    #[test]
+    fn control_envelope_trace_context_round_trips_and_stays_optional() {
+        let trace = TraceContext::parse(
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00"),
+            None,
+        );
+        let envelope = ControlEnvelope::new(
+            "req-trace",
+            RuntimeMode::Service,
+            OperatorContext::new(
+                OperatorKind::RemoteHuman,
+                "service-http-operator",
+                IngressTransport::ServiceHttp,
+            ),
+            SessionContext::new(SessionKind::ServiceRequest, "service-request-1", false),
+            TaskIntent::new(TaskIntentKind::AiPlan, "plan", "payload"),
+        )
+        .with_trace_context(trace.clone());
+
+        let json = serde_json::to_string(&envelope).expect("envelope should serialize");
+        let parsed: ControlEnvelope =
+            serde_json::from_str(&json).expect("envelope should deserialize");
+        assert_eq!(parsed.trace_context, trace);
+        assert!(!parsed.trace_context.expect("trace").is_sampled());
+
+        let legacy = json.replace(
+            ",\"trace_context\":{\"traceparent\":\"00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00\"}",
+            "",
+        );
+        let parsed: ControlEnvelope =
+            serde_json::from_str(&legacy).expect("legacy envelope should deserialize");
+        assert_eq!(parsed.trace_context, None);
+    }
+
     #[test]
     fn runtime_mode_parsing_accepts_supported_values() {
         assert_eq!("cli".parse::<RuntimeMode>(), Ok(RuntimeMode::Cli));
@@ -678,6 +849,19 @@ mod tests {
         assert!(TaskExecutionStatus::Running.can_transition_to(TaskExecutionStatus::Cancelled));
         assert!(!TaskExecutionStatus::Queued.can_transition_to(TaskExecutionStatus::Succeeded));
         assert!(!TaskExecutionStatus::Succeeded.can_transition_to(TaskExecutionStatus::Running));
+        assert!(
+            TaskExecutionStatus::Queued.can_transition_to(TaskExecutionStatus::AwaitingApproval)
+        );
+        assert!(
+            TaskExecutionStatus::AwaitingApproval.can_transition_to(TaskExecutionStatus::Queued)
+        );
+        assert!(
+            TaskExecutionStatus::AwaitingApproval.can_transition_to(TaskExecutionStatus::Cancelled)
+        );
+        assert!(
+            !TaskExecutionStatus::AwaitingApproval.can_transition_to(TaskExecutionStatus::Running)
+        );
+        assert!(!TaskExecutionStatus::AwaitingApproval.is_terminal());
     }
 
     #[test]
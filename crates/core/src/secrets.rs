@@ -0,0 +1,738 @@
+//! Secret references and local secret storage
+//!
+//! Anywhere a secret is configured (bot tokens, the AI API key, the service
+//! bearer token, the Telegram webhook secret) the value may be a reference
+//! instead of the secret itself:
+//!
+//! - `env:NAME` — the environment variable `NAME`
+//! - `file:/run/secrets/token` — a file, as mounted by Docker or Kubernetes;
+//!   files writable by group or others are refused
+//! - `keyring:service/account` — the platform secret service (Keychain,
+//!   Credential Manager, Secret Service). When no secret service is
+//!   reachable, as on headless Linux, values live in an encrypted file in
+//!   [`AppConfig::default_data_dir`] instead.
+//!
+//! The file store's key comes from `NTK_SECRETS_PASSPHRASE` (Argon2id, with
+//! only the salt written to disk). Without a passphrase the key is generated
+//! into `secrets.key` next to the encrypted values, so anyone who can read
+//! the data directory can decrypt them: that fallback is not encryption at
+//! rest, only owner-only file permissions.
+//!
+//! Any other value is used as-is. Every resolved secret is registered with
+//! [`register_secret`] so [`redact_registered_secrets`] can mask it in AI
+//! context and log output.
+//!
+//! `NTK_SECRETS_BACKEND` selects the store used by `ntk secrets`: `auto`
+//! (default, keyring with file fallback), `keyring` or `file`.
+
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+use tracing::{debug, warn};
+
+use crate::config::AppConfig;
+
+/// Service name used when a secret name has no `service/` prefix.
+pub const DEFAULT_SECRET_SERVICE: &str = "nettoolskit";
+
+/// Environment variable selecting the secret store backend.
+pub const NTK_SECRETS_BACKEND_ENV: &str = "NTK_SECRETS_BACKEND";
+
+/// Environment variable holding the passphrase the file store key is derived from.
+pub const NTK_SECRETS_PASSPHRASE_ENV: &str = "NTK_SECRETS_PASSPHRASE";
+
+const REDACTION_MARKER: &str = "[REDACTED]";
+/// Shorter values are too likely to match ordinary text to be redacted.
+const MIN_REDACTED_SECRET_LEN: usize = 6;
+const STORE_INDEX_FILE: &str = "secrets.toml";
+const STORE_KEY_FILE: &str = "secrets.key";
+const STORE_SALT_FILE: &str = "secrets.salt";
+const STORE_SALT_LEN: usize = 16;
+
+/// A configured secret: a literal value or a reference to where it lives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretRef {
+    /// The value itself.
+    Literal(String),
+    /// `env:NAME`.
+    Env(String),
+    /// `file:/path`.
+    File(PathBuf),
+    /// `keyring:service/account`.
+    Keyring {
+        /// Keyring service.
+        service: String,
+        /// Keyring account.
+        account: String,
+    },
+}
+
+impl SecretRef {
+    /// Parse a configured value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` for a reference with an empty target or a `keyring:`
+    /// reference without `service/account`.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let raw = raw.trim();
+        if let Some(name) = raw.strip_prefix("env:") {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err("`env:` reference needs a variable name".to_string());
+            }
+            return Ok(Self::Env(name.to_string()));
+        }
+        if let Some(path) = raw.strip_prefix("file:") {
+            let path = path.trim();
+            if path.is_empty() {
+                return Err("`file:` reference needs a path".to_string());
+            }
+            return Ok(Self::File(PathBuf::from(path)));
+        }
+        if let Some(name) = raw.strip_prefix("keyring:") {
+            return match name.trim().split_once('/') {
+                Some((service, account)) if !service.is_empty() && !account.is_empty() => {
+                    Ok(Self::Keyring {
+                        service: service.to_string(),
+                        account: account.to_string(),
+                    })
+                }
+                _ => Err(format!(
+                    "`keyring:` reference must be `keyring:service/account`, got `{raw}`"
+                )),
+            };
+        }
+        Ok(Self::Literal(raw.to_string()))
+    }
+
+    /// Whether this is a reference rather than a literal value.
+    #[must_use]
+    pub const fn is_reference(&self) -> bool {
+        !matches!(self, Self::Literal(_))
+    }
+
+    /// Read the secret this value points at.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the variable, file or keyring entry is missing,
+    /// unreadable or empty.
+    pub fn resolve(&self) -> Result<String, String> {
+        let value = match self {
+            Self::Literal(value) => value.clone(),
+            Self::Env(name) => {
+                env::var(name).map_err(|_| format!("secret `{self}`: {name} is not set"))?
+            }
+            Self::File(path) => {
+                read_secret_file(path).map_err(|error| format!("secret `{self}`: {error}"))?
+            }
+            Self::Keyring { service, account } => SecretStore::open()
+                .get(&format!("{service}/{account}"))
+                .map_err(|error| format!("secret `{self}`: {error}"))?
+                .ok_or_else(|| format!("secret `{self}` not found"))?,
+        };
+        let value = value.trim().to_string();
+        if value.is_empty() {
+            return Err(format!("secret `{self}` is empty"));
+        }
+        Ok(value)
+    }
+}
+
+impl fmt::Display for SecretRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(_) => write!(f, "********"),
+            Self::Env(name) => write!(f, "env:{name}"),
+            Self::File(path) => write!(f, "file:{}", path.display()),
+            Self::Keyring { service, account } => write!(f, "keyring:{service}/{account}"),
+        }
+    }
+}
+
+/// Resolve a configured secret value and register it for redaction.
+///
+/// # Errors
+///
+/// Returns `Err` when the value is a reference that cannot be resolved.
+pub fn resolve_secret(raw: &str) -> Result<String, String> {
+    let value = SecretRef::parse(raw)?.resolve()?;
+    register_secret(&value);
+    Ok(value)
+}
+
+fn read_secret_file(path: &Path) -> Result<String, String> {
+    let metadata = fs::metadata(path).map_err(|error| format!("{}: {error}", path.display()))?;
+    if !metadata.is_file() {
+        return Err(format!("{} is not a file", path.display()));
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = metadata.permissions().mode() & 0o777;
+        if mode & 0o022 != 0 {
+            return Err(format!(
+                "refusing {}: writable by group or others (mode {mode:o})",
+                path.display()
+            ));
+        }
+        if mode & 0o004 != 0 {
+            warn!(path = %path.display(), mode = %format!("{mode:o}"), "Secret file is world-readable");
+        }
+    }
+    fs::read_to_string(path).map_err(|error| format!("{}: {error}", path.display()))
+}
+
+fn registry() -> &'static RwLock<BTreeSet<String>> {
+    static SECRETS: OnceLock<RwLock<BTreeSet<String>>> = OnceLock::new();
+    SECRETS.get_or_init(|| RwLock::new(BTreeSet::new()))
+}
+
+/// Mask `value` wherever [`redact_registered_secrets`] runs from now on.
+pub fn register_secret(value: &str) {
+    let value = value.trim();
+    if value.len() < MIN_REDACTED_SECRET_LEN {
+        return;
+    }
+    if let Ok(mut secrets) = registry().write() {
+        secrets.insert(value.to_string());
+    }
+}
+
+/// Snapshot of the registered secrets.
+pub(crate) fn registered_secrets() -> Vec<String> {
+    registry()
+        .read()
+        .map(|secrets| secrets.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Replace every registered secret in `input`, returning the redaction count.
+#[must_use]
+pub fn redact_registered_secrets(input: &str) -> (String, usize) {
+    let Ok(secrets) = registry().read() else {
+        return (input.to_string(), 0);
+    };
+    let mut output = input.to_string();
+    let mut redactions = 0;
+    // Longest first, so a secret containing another is masked whole.
+    let mut ordered = secrets.iter().collect::<Vec<_>>();
+    ordered.sort_by_key(|secret| std::cmp::Reverse(secret.len()));
+    for secret in ordered {
+        let count = output.matches(secret.as_str()).count();
+        if count > 0 {
+            redactions += count;
+            output = output.replace(secret.as_str(), REDACTION_MARKER);
+        }
+    }
+    (output, redactions)
+}
+
+/// Where a stored secret lives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecretBackend {
+    /// Platform secret service.
+    Keyring,
+    /// Encrypted file in the data directory.
+    File,
+}
+
+impl fmt::Display for SecretBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Keyring => write!(f, "keyring"),
+            Self::File => write!(f, "file"),
+        }
+    }
+}
+
+/// Which backends [`SecretStore`] may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SecretBackendMode {
+    /// Keyring, falling back to the encrypted file when it is unavailable.
+    #[default]
+    Auto,
+    /// Keyring only.
+    Keyring,
+    /// Encrypted file only.
+    File,
+}
+
+impl SecretBackendMode {
+    /// Mode from `NTK_SECRETS_BACKEND`; unknown values fall back to `Auto`.
+    #[must_use]
+    pub fn from_env() -> Self {
+        match env::var(NTK_SECRETS_BACKEND_ENV)
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "keyring" => Self::Keyring,
+            "file" => Self::File,
+            _ => Self::Auto,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StoreIndex {
+    #[serde(default)]
+    entries: BTreeMap<String, StoreEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoreEntry {
+    backend: SecretBackend,
+    /// `nonce:ciphertext` in hex, for file-backed entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+}
+
+/// Named secrets kept in the platform keyring or an encrypted file.
+///
+/// Names are `service/account`; a bare name uses [`DEFAULT_SECRET_SERVICE`].
+/// An index next to the encrypted file records which backend holds each
+/// name so `list` works without enumerating the platform keyring.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretStore {
+    dir: PathBuf,
+    mode: SecretBackendMode,
+    passphrase: Option<String>,
+}
+
+impl fmt::Debug for SecretStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretStore")
+            .field("dir", &self.dir)
+            .field("mode", &self.mode)
+            .field(
+                "passphrase",
+                &self.passphrase.as_ref().map(|_| REDACTION_MARKER),
+            )
+            .finish()
+    }
+}
+
+impl SecretStore {
+    /// Store in the default data directory, in the mode chosen by `NTK_SECRETS_BACKEND`
+    /// and keyed by `NTK_SECRETS_PASSPHRASE` when set.
+    #[must_use]
+    pub fn open() -> Self {
+        let dir = AppConfig::default_data_dir().unwrap_or_else(|| PathBuf::from(".ntk"));
+        let store = Self::new(dir, SecretBackendMode::from_env());
+        match env::var(NTK_SECRETS_PASSPHRASE_ENV) {
+            Ok(passphrase) if !passphrase.is_empty() => store.with_passphrase(passphrase),
+            _ => store,
+        }
+    }
+
+    /// Store whose index and encrypted file live in `dir`.
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>, mode: SecretBackendMode) -> Self {
+        Self {
+            dir: dir.into(),
+            mode,
+            passphrase: None,
+        }
+    }
+
+    /// Derive the file store key from `passphrase` instead of `secrets.key`.
+    #[must_use]
+    pub fn with_passphrase(mut self, passphrase: impl Into<String>) -> Self {
+        self.passphrase = Some(passphrase.into());
+        self
+    }
+
+    /// Whether file-backed values are encrypted with a passphrase-derived key.
+    ///
+    /// When `false` the key sits in `secrets.key` beside the values, which
+    /// only file permissions protect.
+    #[must_use]
+    pub const fn is_passphrase_protected(&self) -> bool {
+        self.passphrase.is_some()
+    }
+
+    /// Canonical `service/account` form of `name`.
+    #[must_use]
+    pub fn qualified_name(name: &str) -> String {
+        let name = name.trim();
+        if name.contains('/') {
+            name.to_string()
+        } else {
+            format!("{DEFAULT_SECRET_SERVICE}/{name}")
+        }
+    }
+
+    /// Store `value` under `name`, returning the backend that holds it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when no allowed backend can store the value.
+    pub fn set(&self, name: &str, value: &str) -> Result<SecretBackend, String> {
+        let name = Self::qualified_name(name);
+        let mut index = self.load_index()?;
+        let backend = if self.mode == SecretBackendMode::File {
+            SecretBackend::File
+        } else {
+            match keyring_entry(&name).and_then(|entry| entry.set_password(value)) {
+                Ok(()) => SecretBackend::Keyring,
+                Err(error) if self.mode == SecretBackendMode::Auto => {
+                    debug!(error = %error, "Keyring unavailable, using encrypted file store");
+                    SecretBackend::File
+                }
+                Err(error) => return Err(format!("keyring: {error}")),
+            }
+        };
+        let value = match backend {
+            SecretBackend::Keyring => None,
+            SecretBackend::File => Some(self.encrypt(value)?),
+        };
+        index.entries.insert(name, StoreEntry { backend, value });
+        self.save_index(&index)?;
+        Ok(backend)
+    }
+
+    /// Value stored under `name`, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the backend holding `name` cannot be read.
+    pub fn get(&self, name: &str) -> Result<Option<String>, String> {
+        let name = Self::qualified_name(name);
+        let index = self.load_index()?;
+        match index.entries.get(&name) {
+            Some(StoreEntry {
+                backend: SecretBackend::File,
+                value,
+            }) => value
+                .as_deref()
+                .map(|sealed| self.decrypt(sealed))
+                .transpose(),
+            Some(StoreEntry {
+                backend: SecretBackend::Keyring,
+                ..
+            }) => keyring_get(&name),
+            // Entries created by other tools are only in the keyring.
+            None if self.mode == SecretBackendMode::File => Ok(None),
+            None => match keyring_get(&name) {
+                Err(error) if self.mode == SecretBackendMode::Auto => {
+                    debug!(error = %error, "Keyring unavailable");
+                    Ok(None)
+                }
+                other => other,
+            },
+        }
+    }
+
+    /// Remove `name`, returning whether it existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the backend holding `name` cannot be updated.
+    pub fn remove(&self, name: &str) -> Result<bool, String> {
+        let name = Self::qualified_name(name);
+        let mut index = self.load_index()?;
+        let Some(entry) = index.entries.remove(&name) else {
+            return Ok(false);
+        };
+        if entry.backend == SecretBackend::Keyring {
+            match keyring_entry(&name).and_then(|entry| entry.delete_credential()) {
+                Ok(()) | Err(keyring::Error::NoEntry) => {}
+                Err(error) => return Err(format!("keyring: {error}")),
+            }
+        }
+        self.save_index(&index)?;
+        Ok(true)
+    }
+
+    /// Stored names and their backends.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the index cannot be read.
+    pub fn list(&self) -> Result<Vec<(String, SecretBackend)>, String> {
+        Ok(self
+            .load_index()?
+            .entries
+            .into_iter()
+            .map(|(name, entry)| (name, entry.backend))
+            .collect())
+    }
+
+    fn load_index(&self) -> Result<StoreIndex, String> {
+        let path = self.dir.join(STORE_INDEX_FILE);
+        match fs::read_to_string(&path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|error| format!("{}: {error}", path.display()))
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(StoreIndex::default()),
+            Err(error) => Err(format!("{}: {error}", path.display())),
+        }
+    }
+
+    fn save_index(&self, index: &StoreIndex) -> Result<(), String> {
+        let content = toml::to_string_pretty(index).map_err(|error| error.to_string())?;
+        write_private(&self.dir.join(STORE_INDEX_FILE), content.as_bytes())
+    }
+
+    fn cipher(&self) -> Result<ChaCha20Poly1305, String> {
+        let key = match &self.passphrase {
+            Some(passphrase) => self.passphrase_key(passphrase)?,
+            None => self.local_key()?,
+        };
+        Ok(ChaCha20Poly1305::new(&key))
+    }
+
+    /// Argon2id key from `passphrase` and the store's salt, created on first use.
+    fn passphrase_key(&self, passphrase: &str) -> Result<Key, String> {
+        let path = self.dir.join(STORE_SALT_FILE);
+        let salt = match fs::read_to_string(&path) {
+            Ok(encoded) => hex::decode(encoded.trim())
+                .ok()
+                .filter(|bytes| bytes.len() == STORE_SALT_LEN)
+                .ok_or_else(|| format!("{}: invalid salt", path.display()))?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                let mut salt = vec![0_u8; STORE_SALT_LEN];
+                OsRng.fill_bytes(&mut salt);
+                write_private(&path, hex::encode(&salt).as_bytes())?;
+                salt
+            }
+            Err(error) => return Err(format!("{}: {error}", path.display())),
+        };
+        let mut key = Key::default();
+        Argon2::default()
+            .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+            .map_err(|error| format!("failed to derive secrets key: {error}"))?;
+        Ok(key)
+    }
+
+    /// Key kept in `secrets.key`, generated on first use.
+    fn local_key(&self) -> Result<Key, String> {
+        let path = self.dir.join(STORE_KEY_FILE);
+        match fs::read_to_string(&path) {
+            Ok(encoded) => hex::decode(encoded.trim())
+                .ok()
+                .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                .map(Key::from)
+                .ok_or_else(|| format!("{}: invalid key", path.display())),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+                write_private(&path, hex::encode(key).as_bytes())?;
+                Ok(key)
+            }
+            Err(error) => Err(format!("{}: {error}", path.display())),
+        }
+    }
+
+    fn encrypt(&self, value: &str) -> Result<String, String> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let sealed = self
+            .cipher()?
+            .encrypt(&nonce, value.as_bytes())
+            .map_err(|_| "failed to encrypt secret".to_string())?;
+        Ok(format!("{}:{}", hex::encode(nonce), hex::encode(sealed)))
+    }
+
+    fn decrypt(&self, sealed: &str) -> Result<String, String> {
+        let invalid = || "stored secret is corrupt".to_string();
+        let (nonce, ciphertext) = sealed.split_once(':').ok_or_else(invalid)?;
+        let nonce = hex::decode(nonce)
+            .ok()
+            .and_then(|bytes| <[u8; 12]>::try_from(bytes).ok())
+            .map(Nonce::from)
+            .ok_or_else(invalid)?;
+        let ciphertext = hex::decode(ciphertext).map_err(|_| invalid())?;
+        let plain = self
+            .cipher()?
+            .decrypt(&nonce, ciphertext.as_slice())
+            .map_err(|_| {
+                format!(
+                    "stored secret cannot be decrypted with the current key (check {NTK_SECRETS_PASSPHRASE_ENV})"
+                )
+            })?;
+        String::from_utf8(plain).map_err(|_| invalid())
+    }
+}
+
+fn keyring_entry(name: &str) -> Result<keyring::Entry, keyring::Error> {
+    let (service, account) = name
+        .split_once('/')
+        .unwrap_or((DEFAULT_SECRET_SERVICE, name));
+    keyring::Entry::new(service, account)
+}
+
+fn keyring_get(name: &str) -> Result<Option<String>, String> {
+    match keyring_entry(name).and_then(|entry| entry.get_password()) {
+        Ok(value) => Ok(Some(value)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(error) => Err(format!("keyring: {error}")),
+    }
+}
+
+/// Write `content` to `path`, readable by the owner only.
+fn write_private(path: &Path, content: &[u8]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| format!("{}: {error}", parent.display()))?;
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(path)
+        .map_err(|error| format!("{}: {error}", path.display()))?;
+    // `mode` only applies when the file is created; tighten existing files too.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))
+            .map_err(|error| format!("{}: {error}", path.display()))?;
+    }
+    std::io::Write::write_all(&mut file, content)
+        .map_err(|error| format!("{}: {error}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_recognizes_reference_prefixes() {
+        assert_eq!(
+            SecretRef::parse("env:BOT_TOKEN"),
+            Ok(SecretRef::Env("BOT_TOKEN".to_string()))
+        );
+        assert_eq!(
+            SecretRef::parse("file:/run/secrets/bot"),
+            Ok(SecretRef::File(PathBuf::from("/run/secrets/bot")))
+        );
+        assert_eq!(
+            SecretRef::parse("keyring:ntk/telegram"),
+            Ok(SecretRef::Keyring {
+                service: "ntk".to_string(),
+                account: "telegram".to_string(),
+            })
+        );
+        assert_eq!(
+            SecretRef::parse(" plain-token "),
+            Ok(SecretRef::Literal("plain-token".to_string()))
+        );
+        assert!(SecretRef::parse("keyring:no-account").is_err());
+        assert!(SecretRef::parse("env:").is_err());
+    }
+
+    #[test]
+    fn env_reference_resolves_and_registers_for_redaction() {
+        env::set_var("NTK_TEST_SECRET_REF_VALUE", "sk-live-abcdef123456\n");
+        let value = resolve_secret("env:NTK_TEST_SECRET_REF_VALUE").unwrap();
+        assert_eq!(value, "sk-live-abcdef123456");
+
+        let (redacted, count) = redact_registered_secrets("key sk-live-abcdef123456 used");
+        assert_eq!(redacted, "key [REDACTED] used");
+        assert_eq!(count, 1);
+        env::remove_var("NTK_TEST_SECRET_REF_VALUE");
+
+        assert!(resolve_secret("env:NTK_TEST_SECRET_REF_MISSING")
+            .unwrap_err()
+            .contains("NTK_TEST_SECRET_REF_MISSING is not set"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn file_reference_refuses_group_writable_files() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token");
+        fs::write(&path, "file-secret-value\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+        let reference = format!("file:{}", path.display());
+        assert_eq!(resolve_secret(&reference).unwrap(), "file-secret-value");
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o664)).unwrap();
+        assert!(resolve_secret(&reference)
+            .unwrap_err()
+            .contains("writable by group or others"));
+    }
+
+    #[test]
+    fn file_store_round_trips_encrypted_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SecretStore::new(dir.path(), SecretBackendMode::File);
+
+        assert_eq!(
+            store.set("telegram", "123456:bot-token"),
+            Ok(SecretBackend::File)
+        );
+        assert_eq!(
+            store.get("nettoolskit/telegram"),
+            Ok(Some("123456:bot-token".to_string()))
+        );
+        let index = fs::read_to_string(dir.path().join(STORE_INDEX_FILE)).unwrap();
+        assert!(!index.contains("bot-token"));
+        assert_eq!(
+            store.list(),
+            Ok(vec![(
+                "nettoolskit/telegram".to_string(),
+                SecretBackend::File
+            )])
+        );
+
+        assert_eq!(store.remove("telegram"), Ok(true));
+        assert_eq!(store.get("telegram"), Ok(None));
+        assert_eq!(store.remove("telegram"), Ok(false));
+    }
+
+    #[test]
+    fn passphrase_store_keeps_no_key_on_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let store =
+            SecretStore::new(dir.path(), SecretBackendMode::File).with_passphrase("correct horse");
+        assert!(store.is_passphrase_protected());
+
+        store.set("api", "sk-live-value").unwrap();
+        assert_eq!(store.get("api"), Ok(Some("sk-live-value".to_string())));
+        assert!(!dir.path().join(STORE_KEY_FILE).exists());
+        assert!(dir.path().join(STORE_SALT_FILE).exists());
+        assert!(!format!("{store:?}").contains("correct horse"));
+
+        let wrong =
+            SecretStore::new(dir.path(), SecretBackendMode::File).with_passphrase("wrong horse");
+        assert!(wrong
+            .get("api")
+            .unwrap_err()
+            .contains("cannot be decrypted"));
+        let keyless = SecretStore::new(dir.path(), SecretBackendMode::File);
+        assert!(keyless.get("api").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn write_private_tightens_existing_files() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(STORE_INDEX_FILE);
+        fs::write(&path, "").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_private(&path, b"entries = {}").unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o600);
+    }
+}
@@ -0,0 +1,16 @@
+pub struct Tokenizer {
+    max_tokens: usize,
+    secret_ref: Option<String>,
+    password: String,
+}
+
+impl Tokenizer {
+    fn password_hint(&self) -> &str {
+        let token = self.next_token();
+        let password: String = prompt_password();
+        let token_budget = 4096;
+        const SECRET_KEY_SUFFIXES: [&str; 2] = ["token", "secret"];
+        tracing::debug!(tokens = token_budget, "bearer authentication configured");
+        &self.password
+    }
+}
@@ -0,0 +1,3 @@
+//! Tests for secret detectors module
+
+mod corpus_tests;
@@ -11,4 +11,5 @@ mod file_search;
 mod menu;
 #[path = "path-utils/mod.rs"]
 mod path_utils;
+mod secret_detectors;
 mod string_utils;
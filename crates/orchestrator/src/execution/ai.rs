@@ -10,6 +10,7 @@ use std::future::Future;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use tracing::Instrument;
 
 /// Boxed future returned by AI providers.
 pub type AiProviderFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
@@ -83,6 +84,14 @@ pub struct AiUsage {
     pub output_tokens: u32,
 }
 
+impl AiUsage {
+    /// `None` when the provider reported no token counts at all.
+    #[must_use]
+    pub fn reported(self) -> Option<Self> {
+        (self != Self::default()).then_some(self)
+    }
+}
+
 /// AI response payload.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AiResponse {
@@ -116,6 +125,23 @@ pub struct AiChunk {
     pub content: String,
     /// Indicates final chunk.
     pub done: bool,
+    /// Model that served the request, set on the final chunk when known.
+    pub model: Option<String>,
+    /// Provider-reported token usage, set on the final chunk when known.
+    pub usage: Option<AiUsage>,
+}
+
+impl AiChunk {
+    /// Final chunk carrying a complete response, its model and usage.
+    #[must_use]
+    pub fn from_response(response: AiResponse) -> Self {
+        Self {
+            content: response.output_text,
+            done: true,
+            model: Some(response.model),
+            usage: response.usage.reported(),
+        }
+    }
 }
 
 /// AI provider error contract.
@@ -170,10 +196,7 @@ pub trait AiProvider: Send + Sync {
     ) -> AiProviderFuture<'_, Result<Vec<AiChunk>, AiProviderError>> {
         Box::pin(async move {
             let response = self.complete(request).await?;
-            Ok(vec![AiChunk {
-                content: response.output_text,
-                done: true,
-            }])
+            Ok(vec![AiChunk::from_response(response)])
         })
     }
 }
@@ -352,6 +375,9 @@ impl OpenAiCompatibleProvider {
                 builder = builder.bearer_auth(api_key);
             }
         }
+        for (name, value) in nettoolskit_otel::current_trace_headers() {
+            builder = builder.header(name, value);
+        }
 
         let response = builder
             .json(payload)
@@ -420,18 +446,12 @@ impl AiProvider for MockAiProvider {
                 }
                 Some(MockAiOutcome::Complete(response)) => {
                     validate_response(&response)?;
-                    Ok(vec![AiChunk {
-                        content: response.output_text,
-                        done: true,
-                    }])
+                    Ok(vec![AiChunk::from_response(response)])
                 }
                 Some(MockAiOutcome::Error(error)) => Err(error),
                 None => {
                     validate_response(&self.default_response)?;
-                    Ok(vec![AiChunk {
-                        content: self.default_response.output_text.clone(),
-                        done: true,
-                    }])
+                    Ok(vec![AiChunk::from_response(self.default_response.clone())])
                 }
             }
         })
@@ -452,9 +472,16 @@ impl AiProvider for OpenAiCompatibleProvider {
             let model = self.resolve_model(&request);
 
             let payload = OpenAiChatCompletionRequest::from_request(&model, &request);
-            let result =
-                tokio::time::timeout(self.config.timeout, self.send_chat_completion(&payload))
-                    .await;
+            let request_span = tracing::info_span!(
+                "ai.provider.chat_completion",
+                provider = self.id(),
+                model = %model,
+            );
+            let result = tokio::time::timeout(
+                self.config.timeout,
+                self.send_chat_completion(&payload).instrument(request_span),
+            )
+            .await;
 
             let (status, body) = match result {
                 Ok(Ok(value)) => value,
@@ -536,10 +563,7 @@ impl AiProvider for OpenAiCompatibleProvider {
         Box::pin(async move {
             request.stream = true;
             let response = self.complete(request).await?;
-            Ok(vec![AiChunk {
-                content: response.output_text,
-                done: true,
-            }])
+            Ok(vec![AiChunk::from_response(response)])
         })
     }
 }
@@ -802,10 +826,14 @@ mod tests {
                 AiChunk {
                     content: "hello ".to_string(),
                     done: false,
+                    model: None,
+                    usage: None,
                 },
                 AiChunk {
                     content: "world".to_string(),
                     done: true,
+                    model: None,
+                    usage: None,
                 },
             ])],
         );
@@ -939,6 +967,86 @@ mod tests {
         assert_eq!(response.usage.output_tokens, 7);
     }
 
+    #[tokio::test]
+    async fn openai_provider_stream_reports_model_and_usage_on_final_chunk() {
+        let (endpoint, server_handle) =
+            spawn_single_response_server(200, openai_response_body("streamed"), None).await;
+        let provider =
+            OpenAiCompatibleProvider::new(openai_config(endpoint, Duration::from_secs(1), None))
+                .expect("provider config should be valid");
+
+        let chunks = provider
+            .stream(AiRequest::from_user_prompt("hello"))
+            .await
+            .expect("provider should stream response");
+        server_handle.await.expect("server should complete");
+
+        let last = chunks.last().expect("final chunk");
+        assert!(last.done);
+        assert_eq!(last.model.as_deref(), Some("gpt-4o-mini"));
+        assert_eq!(
+            last.usage,
+            Some(AiUsage {
+                input_tokens: 12,
+                output_tokens: 7,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn openai_provider_propagates_trace_context_headers() {
+        use opentelemetry::trace::TracerProvider as _;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("listener should bind");
+        let endpoint = format!(
+            "http://{}/v1/chat/completions",
+            listener.local_addr().expect("listener must have address")
+        );
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.expect("accept should pass");
+            let mut request_buffer = [0_u8; 4096];
+            let read = socket.read(&mut request_buffer).await.unwrap_or(0);
+            let body = openai_response_body("traced");
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+            let _ = socket.shutdown().await;
+            String::from_utf8_lossy(&request_buffer[..read]).to_ascii_lowercase()
+        });
+
+        let tracer_provider = opentelemetry_sdk::trace::SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer("ai-tests")));
+        let _subscriber_guard = tracing::subscriber::set_default(subscriber);
+
+        let caller = nettoolskit_core::TraceContext::parse(
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            None,
+        )
+        .expect("valid traceparent");
+        let worker_span = tracing::info_span!("orchestrator.task_execution");
+        assert!(nettoolskit_otel::set_span_parent(&worker_span, &caller));
+
+        let provider =
+            OpenAiCompatibleProvider::new(openai_config(endpoint, Duration::from_secs(1), None))
+                .expect("provider config should be valid");
+        provider
+            .complete(AiRequest::from_user_prompt("trace me"))
+            .instrument(worker_span)
+            .await
+            .expect("provider should parse response");
+        let raw_request = server.await.expect("server should complete");
+
+        assert!(raw_request.contains("traceparent: 00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+        assert!(!raw_request.contains("-00f067aa0ba902b7-"));
+    }
+
     #[tokio::test]
     async fn openai_provider_returns_timeout_error_without_fallback() {
         let (endpoint, server_handle) = spawn_single_response_server(
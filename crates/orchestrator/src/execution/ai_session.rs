@@ -22,6 +22,11 @@ const MAX_EXCHANGES_PER_SESSION: usize = 200;
 const DEFAULT_AI_SESSION_COMPRESSION_MAX_CHARS: usize = 1200;
 const DEFAULT_AI_SESSION_DELTA_MIN_SHARED_PREFIX_CHARS: usize = 80;
 const SUMMARY_COMPACTION_MARKER_FALLBACK: &str = "... [summary]";
+const SEARCH_SNIPPET_CONTEXT_CHARS: usize = 40;
+const TRUNCATED_SUMMARY_PROMPT_CHARS: usize = 160;
+const TRUNCATED_SUMMARY_RESPONSE_CHARS: usize = 240;
+/// Rolling summary source recorded when no provider could write the summary.
+pub const AI_SESSION_SUMMARY_TRUNCATION_SOURCE: &str = "truncation";
 /// Compression mode env for local AI session assistant responses (`off`, `delta`, `summary`).
 pub const NTK_AI_SESSION_COMPRESSION_MODE_ENV: &str = "NTK_AI_SESSION_COMPRESSION_MODE";
 /// Max chars for `summary` compression mode.
@@ -158,6 +163,71 @@ pub struct LocalAiSessionState {
     pub last_activity_ms: u64,
     /// Ordered exchange history for this session.
     pub exchanges: VecDeque<AiSessionExchange>,
+    /// Source session when this one was created by `/ai fork`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forked_from: Option<AiSessionOrigin>,
+    /// Condensed history of the oldest exchanges, injected instead of them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rolling_summary: Option<AiSessionRollingSummary>,
+}
+
+/// Rolling summary of leading exchanges that no longer fit the session budget.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AiSessionRollingSummary {
+    /// Summary text sent to the provider as a system message.
+    pub text: String,
+    /// Number of leading exchanges folded into `text`.
+    pub covered_exchanges: usize,
+    /// Provider that wrote the summary, or [`AI_SESSION_SUMMARY_TRUNCATION_SOURCE`].
+    pub source: String,
+    /// Last update time in epoch milliseconds.
+    pub updated_at_ms: u64,
+}
+
+/// Branch point of a forked AI session.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AiSessionOrigin {
+    /// Session the fork was taken from.
+    pub session_id: String,
+    /// Number of leading exchanges copied from the source session.
+    pub exchange_count: usize,
+}
+
+/// Part of an exchange matched by a session search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AiSessionSearchField {
+    /// The user prompt.
+    Prompt,
+    /// The stored assistant response.
+    Response,
+}
+
+impl AiSessionSearchField {
+    /// Stable lowercase label (`prompt`, `response`).
+    #[must_use]
+    pub fn as_label(self) -> &'static str {
+        match self {
+            Self::Prompt => "prompt",
+            Self::Response => "response",
+        }
+    }
+}
+
+/// One match of [`LocalAiSessionState::search_local_snapshots`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AiSessionSearchHit {
+    /// Session containing the match.
+    pub session_id: String,
+    /// 1-based exchange number within the session.
+    pub exchange: usize,
+    /// Intent label of the matching exchange.
+    pub intent: String,
+    /// Exchange timestamp in epoch milliseconds.
+    pub timestamp_ms: u64,
+    /// Whether the prompt or the response matched.
+    pub field: AiSessionSearchField,
+    /// Single-line excerpt around the first match.
+    pub snippet: String,
 }
 
 /// Metadata for local AI session picker/list operations.
@@ -257,6 +327,8 @@ impl LocalAiSessionState {
             started_at_ms: now,
             last_activity_ms: now,
             exchanges: VecDeque::new(),
+            forked_from: None,
+            rolling_summary: None,
         }
     }
 
@@ -278,6 +350,9 @@ impl LocalAiSessionState {
 
         if self.exchanges.len() == MAX_EXCHANGES_PER_SESSION {
             self.exchanges.pop_front();
+            if let Some(summary) = self.rolling_summary.as_mut() {
+                summary.covered_exchanges = summary.covered_exchanges.saturating_sub(1);
+            }
         }
 
         let compression_policy = ai_session_compression_policy_from_env();
@@ -301,6 +376,8 @@ impl LocalAiSessionState {
     }
 
     /// Build bounded conversation history as provider-ready messages.
+    ///
+    /// Exchanges already covered by the rolling summary are never repeated.
     #[must_use]
     pub fn recent_messages(&self, max_messages: usize) -> Vec<AiMessage> {
         if max_messages == 0 || self.exchanges.is_empty() {
@@ -308,7 +385,11 @@ impl LocalAiSessionState {
         }
 
         let max_turns = (max_messages / 2).max(1);
-        let start = self.exchanges.len().saturating_sub(max_turns);
+        let start = self
+            .exchanges
+            .len()
+            .saturating_sub(max_turns)
+            .max(self.summarized_exchange_count());
 
         let mut messages = Vec::with_capacity(max_turns * 2);
         for exchange in self.exchanges.iter().skip(start) {
@@ -330,6 +411,96 @@ impl LocalAiSessionState {
         }
     }
 
+    /// Number of leading exchanges represented by the rolling summary.
+    #[must_use]
+    pub fn summarized_exchange_count(&self) -> usize {
+        self.rolling_summary.as_ref().map_or(0, |summary| {
+            summary.covered_exchanges.min(self.exchanges.len())
+        })
+    }
+
+    /// Exchanges not yet folded into the rolling summary, oldest first.
+    pub fn unsummarized_exchanges(&self) -> impl Iterator<Item = &AiSessionExchange> {
+        self.exchanges.iter().skip(self.summarized_exchange_count())
+    }
+
+    /// End (exclusive) of the exchanges to fold into the summary so that only
+    /// the latest `keep_recent` stay verbatim.
+    ///
+    /// Returns `None` when there is nothing new to summarize.
+    #[must_use]
+    pub fn rolling_summary_cutoff(&self, keep_recent: usize) -> Option<usize> {
+        let end = self.exchanges.len().saturating_sub(keep_recent);
+        (end > self.summarized_exchange_count()).then_some(end)
+    }
+
+    /// Replace the rolling summary with `text` covering the first `covered_exchanges`.
+    pub fn set_rolling_summary(
+        &mut self,
+        text: impl Into<String>,
+        covered_exchanges: usize,
+        source: impl Into<String>,
+    ) {
+        self.rolling_summary = Some(AiSessionRollingSummary {
+            text: text.into(),
+            covered_exchanges: covered_exchanges.min(self.exchanges.len()),
+            source: source.into(),
+            updated_at_ms: now_epoch_ms(),
+        });
+    }
+
+    /// Fold exchanges up to `end` into the existing summary by truncation.
+    ///
+    /// Used when no provider is available; bounded by the `summary`
+    /// compression limit (`NTK_AI_SESSION_COMPRESSION_MAX_CHARS`).
+    #[must_use]
+    pub fn truncated_rolling_summary(&self, end: usize) -> String {
+        let mut lines = Vec::new();
+        if let Some(summary) = &self.rolling_summary {
+            lines.push(summary.text.trim().to_string());
+        }
+        for exchange in self
+            .exchanges
+            .iter()
+            .take(end)
+            .skip(self.summarized_exchange_count())
+        {
+            lines.push(format!(
+                "- {}: {} => {}",
+                exchange.intent,
+                summarize_response_text(
+                    &exchange.user_prompt.replace('\n', " "),
+                    TRUNCATED_SUMMARY_PROMPT_CHARS
+                ),
+                summarize_response_text(
+                    &exchange.assistant_response.replace('\n', " "),
+                    TRUNCATED_SUMMARY_RESPONSE_CHARS
+                )
+            ));
+        }
+
+        let max_chars = ai_session_compression_policy_from_env().summary_max_chars;
+        summarize_response_text(&lines.join("\n"), max_chars)
+    }
+
+    /// Rolling summary as a provider-ready system message.
+    #[must_use]
+    pub fn rolling_summary_message(&self) -> Option<AiMessage> {
+        let summary = self.rolling_summary.as_ref()?;
+        let covered = self.summarized_exchange_count();
+        if covered == 0 || summary.text.trim().is_empty() {
+            return None;
+        }
+
+        Some(AiMessage::new(
+            AiRole::System,
+            format!(
+                "Summary of the first {covered} exchanges of this session:\n{}",
+                summary.text.trim()
+            ),
+        ))
+    }
+
     /// Save this state into local application data directory.
     ///
     /// Returns `Ok(None)` when no OS data directory is available.
@@ -483,6 +654,224 @@ impl LocalAiSessionState {
 }
 
 impl LocalAiSessionState {
+    /// Branch a new session holding the first `at` exchanges (all when `None`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the session is empty or `at` is outside `1..=len`.
+    pub fn fork(&self, new_id: &str, at: Option<usize>) -> Result<Self, String> {
+        if self.exchanges.is_empty() {
+            return Err(format!("session {} has no exchanges to fork", self.id));
+        }
+        let keep = at.unwrap_or(self.exchanges.len());
+        if keep == 0 || keep > self.exchanges.len() {
+            return Err(format!(
+                "--at must be between 1 and {} for session {}",
+                self.exchanges.len(),
+                self.id
+            ));
+        }
+
+        let mut fork = Self::new(new_id);
+        fork.exchanges = self.exchanges.iter().take(keep).cloned().collect();
+        fork.forked_from = Some(AiSessionOrigin {
+            session_id: self.id.clone(),
+            exchange_count: keep,
+        });
+        fork.rolling_summary = self
+            .rolling_summary
+            .clone()
+            .filter(|summary| summary.covered_exchanges <= keep);
+        Ok(fork)
+    }
+
+    /// Render the conversation as Markdown with intents, providers and timestamps.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# AI session `{}`\n\n", self.id);
+        out.push_str(&format!(
+            "- Started: {}\n- Last activity: {}\n- Exchanges: {}\n",
+            format_epoch_ms_utc(self.started_at_ms),
+            format_epoch_ms_utc(self.last_activity_ms),
+            self.exchanges.len()
+        ));
+        if let Some(origin) = &self.forked_from {
+            out.push_str(&format!(
+                "- Forked from: `{}` after exchange {}\n",
+                origin.session_id, origin.exchange_count
+            ));
+        }
+        if let Some(summary) = &self.rolling_summary {
+            out.push_str(&format!(
+                "\n## Rolling summary (exchanges 1-{}, {})\n\n{}\n",
+                summary.covered_exchanges,
+                summary.source,
+                summary.text.trim_end()
+            ));
+        }
+
+        for (index, exchange) in self.exchanges.iter().enumerate() {
+            out.push_str(&format!(
+                "\n## {}. {} · {} · {}\n\n",
+                index + 1,
+                exchange.intent,
+                exchange.provider,
+                format_epoch_ms_utc(exchange.timestamp_ms)
+            ));
+            out.push_str("**Prompt**\n\n");
+            for line in exchange.user_prompt.lines() {
+                out.push_str(&format!("> {line}\n"));
+            }
+            out.push_str("\n**Response**");
+            if exchange.response_storage_mode != AiSessionCompressionMode::Off {
+                out.push_str(&format!(
+                    " _(stored as {}, {} chars originally)_",
+                    format!("{:?}", exchange.response_storage_mode).to_lowercase(),
+                    exchange.original_response_chars
+                ));
+            }
+            out.push_str("\n\n");
+            out.push_str(exchange.assistant_response.trim_end());
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Serialize the session as pretty JSON, the same shape as its snapshot file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when serialization fails.
+    pub fn to_json(&self) -> io::Result<String> {
+        serde_json::to_string_pretty(self).map_err(json_to_io_error)
+    }
+
+    /// Search prompts and responses of every local snapshot (case-insensitive).
+    ///
+    /// Returns `Ok(None)` when no OS data directory is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the sessions directory cannot be read.
+    pub fn search_local_snapshots(
+        query: &str,
+        limit: usize,
+    ) -> io::Result<Option<Vec<AiSessionSearchHit>>> {
+        let Some(base_dir) = AppConfig::default_data_dir() else {
+            return Ok(None);
+        };
+
+        Self::search_local_snapshots_in_dir(&base_dir, query, limit).map(Some)
+    }
+
+    /// Rename a local snapshot, keeping its history.
+    ///
+    /// Returns the new snapshot path, or `Ok(None)` when no OS data directory is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the source is missing, the target exists, or file I/O fails.
+    pub fn rename_local_snapshot(session_id: &str, new_id: &str) -> io::Result<Option<PathBuf>> {
+        let Some(base_dir) = AppConfig::default_data_dir() else {
+            return Ok(None);
+        };
+
+        Self::rename_local_snapshot_in_dir(&base_dir, session_id, new_id).map(Some)
+    }
+
+    /// Delete a local snapshot.
+    ///
+    /// Returns `Ok(Some(false))` when the session has no snapshot and `Ok(None)`
+    /// when no OS data directory is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the file cannot be removed.
+    pub fn delete_local_snapshot(session_id: &str) -> io::Result<Option<bool>> {
+        let Some(base_dir) = AppConfig::default_data_dir() else {
+            return Ok(None);
+        };
+
+        Self::delete_local_snapshot_in_dir(&base_dir, session_id).map(Some)
+    }
+
+    fn search_local_snapshots_in_dir(
+        base_dir: &Path,
+        query: &str,
+        limit: usize,
+    ) -> io::Result<Vec<AiSessionSearchHit>> {
+        let needle = query.trim().to_lowercase();
+        if needle.is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut hits = Vec::new();
+        for snapshot in Self::list_local_snapshots_in_dir(base_dir, usize::MAX)? {
+            let Ok(state) = Self::load_local_snapshot_from_path(&snapshot.path) else {
+                continue;
+            };
+            for (index, exchange) in state.exchanges.iter().enumerate() {
+                let fields = [
+                    (AiSessionSearchField::Prompt, &exchange.user_prompt),
+                    (AiSessionSearchField::Response, &exchange.assistant_response),
+                ];
+                for (field, text) in fields {
+                    let Some(snippet) = search_snippet(text, &needle) else {
+                        continue;
+                    };
+                    hits.push(AiSessionSearchHit {
+                        session_id: state.id.clone(),
+                        exchange: index + 1,
+                        intent: exchange.intent.clone(),
+                        timestamp_ms: exchange.timestamp_ms,
+                        field,
+                        snippet,
+                    });
+                    if hits.len() == limit {
+                        return Ok(hits);
+                    }
+                }
+            }
+        }
+        Ok(hits)
+    }
+
+    fn rename_local_snapshot_in_dir(
+        base_dir: &Path,
+        session_id: &str,
+        new_id: &str,
+    ) -> io::Result<PathBuf> {
+        let Some(mut state) = Self::load_local_snapshot_from_dir(base_dir, session_id)? else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("AI session not found: {session_id}"),
+            ));
+        };
+        let sessions_dir = base_dir.join(LOCAL_AI_SESSIONS_DIR_NAME);
+        let old_path = session_file_path(&sessions_dir, session_id);
+        let new_path = session_file_path(&sessions_dir, new_id);
+        if new_path.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("AI session already exists: {}", sanitize_session_id(new_id)),
+            ));
+        }
+
+        state.id = sanitize_session_id(new_id);
+        let path = state.save_local_snapshot_to_dir(base_dir)?;
+        fs::remove_file(old_path)?;
+        Ok(path)
+    }
+
+    fn delete_local_snapshot_in_dir(base_dir: &Path, session_id: &str) -> io::Result<bool> {
+        let path = session_file_path(&base_dir.join(LOCAL_AI_SESSIONS_DIR_NAME), session_id);
+        if !path.exists() {
+            return Ok(false);
+        }
+        fs::remove_file(path)?;
+        Ok(true)
+    }
+
     fn normalize_exchange_metadata(&mut self) {
         for exchange in &mut self.exchanges {
             exchange.ensure_response_metadata();
@@ -671,6 +1060,67 @@ fn compress_ai_session_response(
     }
 }
 
+/// Single-line excerpt of `text` around the first match of the lowercase `needle`.
+fn search_snippet(text: &str, needle: &str) -> Option<String> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let lowered = chars
+        .iter()
+        .map(|ch| ch.to_lowercase().next().unwrap_or(*ch))
+        .collect::<Vec<_>>();
+    let needle = needle.chars().collect::<Vec<_>>();
+    let position = lowered
+        .windows(needle.len())
+        .position(|window| window == needle.as_slice())?;
+
+    let start = position.saturating_sub(SEARCH_SNIPPET_CONTEXT_CHARS);
+    let end = (position + needle.len() + SEARCH_SNIPPET_CONTEXT_CHARS).min(chars.len());
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push('…');
+    }
+    snippet.extend(chars[start..end].iter().map(|ch| match ch {
+        '\n' | '\r' | '\t' => ' ',
+        other => *other,
+    }));
+    if end < chars.len() {
+        snippet.push('…');
+    }
+    Some(snippet)
+}
+
+/// Format epoch milliseconds as `YYYY-MM-DD HH:MM:SS UTC`.
+pub(crate) fn format_epoch_ms_utc(epoch_ms: u64) -> String {
+    let seconds = epoch_ms / 1000;
+    let time_of_day = seconds % 86_400;
+    let (year, month, day) = civil_date_from_epoch_ms(epoch_ms);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC",
+        time_of_day / 3_600,
+        (time_of_day % 3_600) / 60,
+        time_of_day % 60
+    )
+}
+
+/// UTC calendar date `(year, month, day)` of epoch milliseconds.
+pub(crate) fn civil_date_from_epoch_ms(epoch_ms: u64) -> (i64, i64, i64) {
+    let days = (epoch_ms / 1000 / 86_400) as i64;
+
+    // Civil-from-days conversion (proleptic Gregorian calendar).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
 fn sanitize_session_id(value: &str) -> String {
     let mut sanitized: String = value
         .trim()
@@ -763,6 +1213,53 @@ mod tests {
         assert_eq!(messages[3].content, "resp-three");
     }
 
+    #[test]
+    fn rolling_summary_replaces_covered_exchanges_in_history() {
+        let _guard = env_test_guard();
+        clear_ai_session_compression_env_vars();
+        let mut session = LocalAiSessionState::new("session-summary");
+        for turn in 1..=5 {
+            let _ =
+                session.append_exchange("ask", "mock", &format!("q{turn}"), &format!("a{turn}"));
+        }
+        assert!(session.rolling_summary_message().is_none());
+        assert_eq!(session.rolling_summary_cutoff(2), Some(3));
+
+        let text = session.truncated_rolling_summary(3);
+        assert!(text.contains("- ask: q1 => a1"), "{text}");
+        assert!(text.contains("- ask: q3 => a3"), "{text}");
+        assert!(!text.contains("q4"), "{text}");
+
+        session.set_rolling_summary("goals so far", 3, "mock");
+        assert_eq!(session.summarized_exchange_count(), 3);
+        assert_eq!(session.unsummarized_exchanges().count(), 2);
+        assert_eq!(session.rolling_summary_cutoff(2), None);
+        let message = session.rolling_summary_message().expect("summary message");
+        assert_eq!(message.role, AiRole::System);
+        assert!(message.content.contains("first 3 exchanges"));
+        assert!(message.content.ends_with("goals so far"));
+
+        let messages = session.recent_messages(12);
+        assert_eq!(messages.len(), 4);
+        assert_eq!(messages[0].content, "q4");
+
+        let folded = session.truncated_rolling_summary(4);
+        assert!(
+            folded.starts_with("goals so far\n- ask: q4 => a4"),
+            "{folded}"
+        );
+        assert!(session
+            .fork("early", Some(2))
+            .expect("fork")
+            .rolling_summary
+            .is_none());
+        assert!(session
+            .fork("late", Some(4))
+            .expect("fork")
+            .rolling_summary
+            .is_some());
+    }
+
     #[test]
     fn local_snapshot_roundtrip_in_custom_dir() {
         let temp = tempdir().expect("temp dir");
@@ -825,6 +1322,111 @@ mod tests {
         assert_eq!(snapshots[0].id, "session-3");
     }
 
+    #[test]
+    fn fork_copies_leading_exchanges_and_records_origin() {
+        let _guard = env_test_guard();
+        clear_ai_session_compression_env_vars();
+        let mut session = LocalAiSessionState::new("session-source");
+        for turn in 1..=3 {
+            let _ = session.append_exchange("ask", "mock", &format!("q{turn}"), "answer");
+        }
+
+        let fork = session.fork("session-branch", Some(2)).expect("fork");
+        assert_eq!(fork.id, "session-branch");
+        assert_eq!(fork.exchanges.len(), 2);
+        assert_eq!(fork.exchanges[1].user_prompt, "q2");
+        assert_eq!(
+            fork.forked_from,
+            Some(AiSessionOrigin {
+                session_id: "session-source".to_string(),
+                exchange_count: 2,
+            })
+        );
+        assert_eq!(session.exchanges.len(), 3);
+        assert!(session.fork("other", Some(4)).is_err());
+        assert!(session.fork("other", Some(0)).is_err());
+        assert_eq!(
+            session.fork("other", None).expect("full").exchanges.len(),
+            3
+        );
+    }
+
+    #[test]
+    fn to_markdown_renders_intents_providers_and_timestamps() {
+        let mut session = LocalAiSessionState::new("session-md");
+        session.started_at_ms = 1_760_000_000_000;
+        session.last_activity_ms = 1_760_000_000_000;
+        session.exchanges.push_back(AiSessionExchange {
+            timestamp_ms: 1_760_000_000_000,
+            ..AiSessionExchange::new("plan", "openai", "add cache\nwith ttl", "1. Do it")
+        });
+
+        let markdown = session.to_markdown();
+        assert!(markdown.starts_with("# AI session `session-md`\n"));
+        assert!(markdown.contains("- Started: 2025-10-09 08:53:20 UTC\n"));
+        assert!(markdown.contains("## 1. plan · openai · 2025-10-09 08:53:20 UTC\n"));
+        assert!(markdown.contains("> add cache\n> with ttl\n"));
+        assert!(markdown.ends_with("**Response**\n\n1. Do it\n"));
+    }
+
+    #[test]
+    fn format_epoch_ms_utc_handles_epoch_and_leap_days() {
+        assert_eq!(format_epoch_ms_utc(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(
+            format_epoch_ms_utc(951_782_400_000),
+            "2000-02-29 00:00:00 UTC"
+        );
+    }
+
+    #[test]
+    fn search_rename_and_delete_local_snapshots_in_dir() {
+        let _guard = env_test_guard();
+        clear_ai_session_compression_env_vars();
+        let temp = tempdir().expect("temp dir");
+        let mut first = LocalAiSessionState::new("session-a");
+        let _ = first.append_exchange("ask", "mock", "How does the Cache evict?", "By LRU.");
+        first.save_local_snapshot_to_dir(temp.path()).expect("save");
+        let mut second = LocalAiSessionState::new("session-b");
+        let _ = second.append_exchange("plan", "mock", "plan release", "Warm the cache first");
+        second
+            .save_local_snapshot_to_dir(temp.path())
+            .expect("save");
+
+        let mut hits = LocalAiSessionState::search_local_snapshots_in_dir(temp.path(), "CACHE", 10)
+            .expect("search");
+        hits.sort_by(|left, right| left.session_id.cmp(&right.session_id));
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].session_id, "session-a");
+        assert_eq!(hits[0].field, AiSessionSearchField::Prompt);
+        assert_eq!(hits[0].snippet, "How does the Cache evict?");
+        assert_eq!(hits[1].field, AiSessionSearchField::Response);
+        assert_eq!(hits[1].exchange, 1);
+
+        let renamed =
+            LocalAiSessionState::rename_local_snapshot_in_dir(temp.path(), "session-a", "cache-qa")
+                .expect("rename");
+        assert!(renamed.ends_with("cache-qa.json"));
+        let loaded = LocalAiSessionState::load_local_snapshot_from_path(&renamed).expect("load");
+        assert_eq!(loaded.id, "cache-qa");
+        assert_eq!(loaded.exchanges.len(), 1);
+        let clash =
+            LocalAiSessionState::rename_local_snapshot_in_dir(temp.path(), "cache-qa", "session-b")
+                .expect_err("target exists");
+        assert_eq!(clash.kind(), io::ErrorKind::AlreadyExists);
+
+        assert!(
+            LocalAiSessionState::delete_local_snapshot_in_dir(temp.path(), "session-b")
+                .expect("delete")
+        );
+        assert!(
+            !LocalAiSessionState::delete_local_snapshot_in_dir(temp.path(), "session-b")
+                .expect("delete again")
+        );
+        let remaining = LocalAiSessionState::list_local_snapshots_in_dir(temp.path(), usize::MAX)
+            .expect("list");
+        assert_eq!(remaining.len(), 1);
+    }
+
     #[test]
     fn sanitize_session_id_replaces_invalid_chars() {
         assert_eq!(sanitize_session_id("my-session"), "my-session");
@@ -0,0 +1,888 @@
+//! Local AI spend accounting.
+//!
+//! Every provider-served AI request is priced through an [`AiPriceTable`]
+//! (per provider and model, with the global per-1k rates as fallback) and
+//! appended to a JSONL ledger in local application data. The ledger feeds
+//! `/ai usage` reports and the daily/monthly hard caps checked before dispatch.
+//! Cap checks keep running day and month totals in a checkpoint beside the
+//! ledger, so each dispatch only reads the lines appended since the last one.
+
+use crate::execution::ai_session::civil_date_from_epoch_ms;
+use nettoolskit_core::AppConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::future::Future;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// Per-model price table entries (`provider/model=input:output`, USD per 1k tokens).
+pub const NTK_AI_PRICE_TABLE_ENV: &str = "NTK_AI_PRICE_TABLE";
+/// Spend allowed per UTC day, in USD.
+pub const NTK_AI_SPEND_CAP_DAILY_USD_ENV: &str = "NTK_AI_SPEND_CAP_DAILY_USD";
+/// Spend allowed per UTC month, in USD.
+pub const NTK_AI_SPEND_CAP_MONTHLY_USD_ENV: &str = "NTK_AI_SPEND_CAP_MONTHLY_USD";
+/// Override for the spend ledger file.
+pub const NTK_AI_SPEND_LEDGER_PATH_ENV: &str = "NTK_AI_SPEND_LEDGER_PATH";
+const PRICE_TABLE_WILDCARD: &str = "*";
+const CHECKPOINT_EXTENSION: &str = "totals.json";
+
+tokio::task_local! {
+    static AI_SPEND_OPERATOR: String;
+}
+
+/// Run `future` with `operator` recorded as the owner of AI spend it causes.
+pub(crate) async fn with_ai_spend_operator<F: Future>(operator: String, future: F) -> F::Output {
+    AI_SPEND_OPERATOR.scope(operator, future).await
+}
+
+/// Operator scoped by [`with_ai_spend_operator`], if any.
+pub(crate) fn scoped_ai_spend_operator() -> Option<String> {
+    AI_SPEND_OPERATOR.try_with(Clone::clone).ok()
+}
+
+/// Price of one model, in USD per 1k tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AiModelPrice {
+    /// Price per 1k input tokens.
+    pub input_per_1k_usd: f64,
+    /// Price per 1k output tokens.
+    pub output_per_1k_usd: f64,
+}
+
+impl AiModelPrice {
+    /// Build a price from per-1k input and output rates.
+    #[must_use]
+    pub const fn new(input_per_1k_usd: f64, output_per_1k_usd: f64) -> Self {
+        Self {
+            input_per_1k_usd,
+            output_per_1k_usd,
+        }
+    }
+
+    /// Cost of a request with the given token counts.
+    #[must_use]
+    pub fn cost_usd(&self, input_tokens: u64, output_tokens: u64) -> f64 {
+        (input_tokens as f64 / 1000.0) * self.input_per_1k_usd
+            + (output_tokens as f64 / 1000.0) * self.output_per_1k_usd
+    }
+}
+
+/// Prices keyed by provider and model; `*` matches any provider or model.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AiPriceTable {
+    entries: Vec<(String, String, AiModelPrice)>,
+    fallback: AiModelPrice,
+}
+
+impl AiPriceTable {
+    /// Empty table that prices everything at `fallback`.
+    #[must_use]
+    pub fn new(fallback: AiModelPrice) -> Self {
+        Self {
+            entries: Vec::new(),
+            fallback,
+        }
+    }
+
+    /// Add or replace the price of `provider/model`.
+    #[must_use]
+    pub fn with_price(
+        mut self,
+        provider: impl Into<String>,
+        model: impl Into<String>,
+        price: AiModelPrice,
+    ) -> Self {
+        let provider = provider.into().trim().to_ascii_lowercase();
+        let model = model.into().trim().to_ascii_lowercase();
+        self.entries.retain(|(known_provider, known_model, _)| {
+            (known_provider, known_model) != (&provider, &model)
+        });
+        self.entries.push((provider, model, price));
+        self
+    }
+
+    /// Add entries written as `provider/model=input:output`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first malformed entry.
+    pub fn with_entries<'a>(
+        mut self,
+        entries: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, String> {
+        for entry in entries.into_iter().map(str::trim) {
+            if entry.is_empty() {
+                continue;
+            }
+            let (provider, model, price) = parse_price_entry(entry)?;
+            self = self.with_price(provider, model, price);
+        }
+        Ok(self)
+    }
+
+    /// Price used when no entry matches.
+    #[must_use]
+    pub fn fallback(&self) -> AiModelPrice {
+        self.fallback
+    }
+
+    /// Number of explicit entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the table only has the fallback price.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Most specific price for `provider/model`: exact, `provider/*`, `*/model`, `*/*`, fallback.
+    #[must_use]
+    pub fn price_for(&self, provider: &str, model: &str) -> AiModelPrice {
+        let provider = provider.trim().to_ascii_lowercase();
+        let model = model.trim().to_ascii_lowercase();
+        let price = [
+            (provider.as_str(), model.as_str()),
+            (provider.as_str(), PRICE_TABLE_WILDCARD),
+            (PRICE_TABLE_WILDCARD, model.as_str()),
+            (PRICE_TABLE_WILDCARD, PRICE_TABLE_WILDCARD),
+        ]
+        .into_iter()
+        .find_map(|(provider, model)| {
+            self.entries
+                .iter()
+                .find(|(known_provider, known_model, _)| {
+                    known_provider == provider && known_model == model
+                })
+                .map(|(_, _, price)| *price)
+        });
+        price.unwrap_or(self.fallback)
+    }
+}
+
+fn parse_price_entry(entry: &str) -> Result<(String, String, AiModelPrice), String> {
+    let malformed =
+        || format!("invalid price entry `{entry}` (expected provider/model=input:output)");
+    let (target, rates) = entry.split_once('=').ok_or_else(malformed)?;
+    let (provider, model) = target.split_once('/').ok_or_else(malformed)?;
+    let (input, output) = rates.split_once(':').ok_or_else(malformed)?;
+    let parse_rate = |value: &str| {
+        value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|rate| rate.is_finite() && *rate >= 0.0)
+            .ok_or_else(malformed)
+    };
+    let (provider, model) = (provider.trim(), model.trim());
+    if provider.is_empty() || model.is_empty() {
+        return Err(malformed());
+    }
+
+    Ok((
+        provider.to_string(),
+        model.to_string(),
+        AiModelPrice::new(parse_rate(input)?, parse_rate(output)?),
+    ))
+}
+
+/// Where the token counts of a ledger entry came from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AiUsageSource {
+    /// Counts reported by the provider.
+    Provider,
+    /// Local 4-characters-per-token estimate; the provider reported nothing.
+    Estimate,
+}
+
+impl AiUsageSource {
+    /// Stable label used in reports.
+    #[must_use]
+    pub const fn as_label(self) -> &'static str {
+        match self {
+            Self::Provider => "provider",
+            Self::Estimate => "estimate",
+        }
+    }
+}
+
+/// One priced AI request in the spend ledger.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AiSpendEntry {
+    /// Completion time.
+    pub timestamp_unix_ms: u64,
+    /// Local AI session that issued the request.
+    pub session_id: String,
+    /// Operator on whose behalf the request ran.
+    pub operator: String,
+    /// Intent label (`ask`, `plan`, `summary`, ...).
+    pub intent: String,
+    /// Provider that served the request.
+    pub provider: String,
+    /// Model that served the request.
+    pub model: String,
+    /// Input tokens charged.
+    pub input_tokens: u64,
+    /// Output tokens charged.
+    pub output_tokens: u64,
+    /// Charged cost in USD.
+    pub cost_usd: f64,
+    /// Origin of the token counts.
+    pub usage_source: AiUsageSource,
+}
+
+impl AiSpendEntry {
+    /// UTC day of the entry (`YYYY-MM-DD`).
+    #[must_use]
+    pub fn day(&self) -> String {
+        utc_day_label(self.timestamp_unix_ms)
+    }
+
+    /// UTC month of the entry (`YYYY-MM`).
+    #[must_use]
+    pub fn month(&self) -> String {
+        utc_month_label(self.timestamp_unix_ms)
+    }
+}
+
+/// UTC day label (`YYYY-MM-DD`) of epoch milliseconds.
+#[must_use]
+pub fn utc_day_label(epoch_ms: u64) -> String {
+    let (year, month, day) = civil_date_from_epoch_ms(epoch_ms);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// UTC month label (`YYYY-MM`) of epoch milliseconds.
+#[must_use]
+pub fn utc_month_label(epoch_ms: u64) -> String {
+    let (year, month, _) = civil_date_from_epoch_ms(epoch_ms);
+    format!("{year:04}-{month:02}")
+}
+
+/// Append-only JSONL spend ledger.
+#[derive(Debug, Clone)]
+pub struct AiSpendLedger {
+    path: PathBuf,
+}
+
+impl AiSpendLedger {
+    /// Relative file path used under default data directory.
+    pub const DEFAULT_RELATIVE_PATH: &'static str = "ai/spend.jsonl";
+
+    /// Build ledger from explicit path.
+    #[must_use]
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Build ledger using default local data directory.
+    #[must_use]
+    pub fn from_default_data_dir() -> Option<Self> {
+        AppConfig::default_data_dir().map(|base| Self {
+            path: base.join(Self::DEFAULT_RELATIVE_PATH),
+        })
+    }
+
+    /// Access configured ledger path.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append one entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns error when path creation or write fails.
+    pub fn append(&self, entry: &AiSpendEntry) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let line = serde_json::to_string(entry)
+            .map_err(|err| io::Error::other(format!("serialize spend entry: {err}")))?;
+        writeln!(file, "{line}")?;
+        file.flush()
+    }
+
+    /// Entries recorded at or after `since_unix_ms` (malformed lines are skipped).
+    ///
+    /// # Errors
+    ///
+    /// Returns error when the file exists but cannot be read.
+    pub fn load_since(&self, since_unix_ms: u64) -> io::Result<Vec<AiSpendEntry>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let reader = BufReader::new(OpenOptions::new().read(true).open(&self.path)?);
+        let mut entries = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Ok(entry) = serde_json::from_str::<AiSpendEntry>(&line) {
+                if entry.timestamp_unix_ms >= since_unix_ms {
+                    entries.push(entry);
+                }
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Spend of the UTC day and month containing `now_unix_ms`.
+    ///
+    /// Starts from the running totals checkpointed beside the ledger, resetting
+    /// whichever of the day or month has rolled over, and reads only the lines
+    /// appended since. A missing or stale checkpoint (the ledger shrank) falls
+    /// back to a full read.
+    ///
+    /// # Errors
+    ///
+    /// Returns error when the ledger cannot be read.
+    pub fn totals_at(&self, now_unix_ms: u64) -> io::Result<AiSpendTotals> {
+        let today = utc_day_label(now_unix_ms);
+        let month = utc_month_label(now_unix_ms);
+        let ledger_len = match fs::metadata(&self.path) {
+            Ok(metadata) => metadata.len(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok(AiSpendTotals::default())
+            }
+            Err(err) => return Err(err),
+        };
+
+        let (offset, carried) = match self
+            .load_checkpoint()
+            .filter(|checkpoint| checkpoint.offset <= ledger_len)
+        {
+            Some(checkpoint) => (
+                checkpoint.offset,
+                AiSpendTotals {
+                    day_usd: if checkpoint.day == today {
+                        checkpoint.day_usd
+                    } else {
+                        0.0
+                    },
+                    month_usd: if checkpoint.month == month {
+                        checkpoint.month_usd
+                    } else {
+                        0.0
+                    },
+                },
+            ),
+            None => (0, AiSpendTotals::default()),
+        };
+        let (appended, end) = self.read_complete_lines_from(offset)?;
+        let fresh = AiSpendTotals::from_entries(&appended, now_unix_ms);
+        let totals = AiSpendTotals {
+            day_usd: carried.day_usd + fresh.day_usd,
+            month_usd: carried.month_usd + fresh.month_usd,
+        };
+
+        // The checkpoint only saves work; a failed write means a longer read next time.
+        let _ = self.save_checkpoint(&AiSpendCheckpoint {
+            offset: end,
+            day: today,
+            day_usd: totals.day_usd,
+            month,
+            month_usd: totals.month_usd,
+        });
+        Ok(totals)
+    }
+
+    /// Entries on complete lines from byte `offset`, and the offset after the last one.
+    ///
+    /// A trailing line without a newline may still be being written and is left
+    /// for the next read.
+    fn read_complete_lines_from(&self, offset: u64) -> io::Result<(Vec<AiSpendEntry>, u64)> {
+        let mut file = OpenOptions::new().read(true).open(&self.path)?;
+        file.seek(SeekFrom::Start(offset))?;
+        let mut tail = Vec::new();
+        file.read_to_end(&mut tail)?;
+
+        let Some(last_newline) = tail.iter().rposition(|byte| *byte == b'\n') else {
+            return Ok((Vec::new(), offset));
+        };
+        let entries = tail[..last_newline]
+            .split(|byte| *byte == b'\n')
+            .filter_map(|line| serde_json::from_slice::<AiSpendEntry>(line).ok())
+            .collect();
+        Ok((entries, offset + last_newline as u64 + 1))
+    }
+
+    fn checkpoint_path(&self) -> PathBuf {
+        self.path.with_extension(CHECKPOINT_EXTENSION)
+    }
+
+    fn load_checkpoint(&self) -> Option<AiSpendCheckpoint> {
+        let bytes = fs::read(self.checkpoint_path()).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    fn save_checkpoint(&self, checkpoint: &AiSpendCheckpoint) -> io::Result<()> {
+        let path = self.checkpoint_path();
+        let staging = path.with_extension("tmp");
+        let bytes = serde_json::to_vec(checkpoint)
+            .map_err(|err| io::Error::other(format!("serialize spend totals: {err}")))?;
+        fs::write(&staging, bytes)?;
+        fs::rename(&staging, &path)
+    }
+}
+
+/// Running totals of the ledger prefix ending at `offset`.
+#[derive(Debug, Serialize, Deserialize)]
+struct AiSpendCheckpoint {
+    offset: u64,
+    day: String,
+    day_usd: f64,
+    month: String,
+    month_usd: f64,
+}
+
+/// Spend of the current UTC day and month.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AiSpendTotals {
+    /// Spend of the current UTC day.
+    pub day_usd: f64,
+    /// Spend of the current UTC month.
+    pub month_usd: f64,
+}
+
+impl AiSpendTotals {
+    /// Sum the entries falling in the UTC day and month of `now_unix_ms`.
+    #[must_use]
+    pub fn from_entries(entries: &[AiSpendEntry], now_unix_ms: u64) -> Self {
+        let today = utc_day_label(now_unix_ms);
+        let month = utc_month_label(now_unix_ms);
+        entries.iter().fold(Self::default(), |mut totals, entry| {
+            if entry.month() == month {
+                totals.month_usd += entry.cost_usd;
+                if entry.day() == today {
+                    totals.day_usd += entry.cost_usd;
+                }
+            }
+            totals
+        })
+    }
+}
+
+/// Daily and monthly hard spend caps.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AiSpendCaps {
+    /// Spend allowed per UTC day.
+    pub daily_usd: Option<f64>,
+    /// Spend allowed per UTC month.
+    pub monthly_usd: Option<f64>,
+}
+
+impl AiSpendCaps {
+    /// Whether any cap is configured.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.daily_usd.is_some() || self.monthly_usd.is_some()
+    }
+
+    /// Reject a request whose projected cost would push spend past a cap.
+    ///
+    /// # Errors
+    ///
+    /// Returns which cap would be exceeded and by how much.
+    pub fn check(&self, totals: AiSpendTotals, projected_usd: f64) -> Result<(), String> {
+        let projected_usd = projected_usd.max(0.0);
+        if let Some(cap) = self.daily_usd {
+            if totals.day_usd + projected_usd > cap {
+                return Err(format!(
+                    "daily spend cap reached (${:.4} spent + ${projected_usd:.4} projected > ${cap:.4})",
+                    totals.day_usd
+                ));
+            }
+        }
+        if let Some(cap) = self.monthly_usd {
+            if totals.month_usd + projected_usd > cap {
+                return Err(format!(
+                    "monthly spend cap reached (${:.4} spent + ${projected_usd:.4} projected > ${cap:.4})",
+                    totals.month_usd
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Dimension used to group `/ai usage` rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AiSpendGrouping {
+    /// UTC day.
+    #[default]
+    Day,
+    /// `provider/model`.
+    Model,
+    /// Intent label.
+    Intent,
+    /// Operator id.
+    Operator,
+    /// Local AI session id.
+    Session,
+}
+
+impl AiSpendGrouping {
+    /// Accepted `--by` values.
+    pub const LABELS: &'static [&'static str] = &["day", "model", "intent", "operator", "session"];
+
+    /// Parse a `--by` value.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "day" | "date" => Some(Self::Day),
+            "model" => Some(Self::Model),
+            "intent" => Some(Self::Intent),
+            "operator" | "user" => Some(Self::Operator),
+            "session" => Some(Self::Session),
+            _ => None,
+        }
+    }
+
+    /// Stable label used in report headers.
+    #[must_use]
+    pub const fn as_label(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Model => "model",
+            Self::Intent => "intent",
+            Self::Operator => "operator",
+            Self::Session => "session",
+        }
+    }
+
+    fn key(self, entry: &AiSpendEntry) -> String {
+        match self {
+            Self::Day => entry.day(),
+            Self::Model => format!("{}/{}", entry.provider, entry.model),
+            Self::Intent => entry.intent.clone(),
+            Self::Operator => entry.operator.clone(),
+            Self::Session => entry.session_id.clone(),
+        }
+    }
+}
+
+/// Aggregated spend of one `/ai usage` row.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AiSpendBucket {
+    /// Grouping key.
+    pub key: String,
+    /// Requests in the bucket.
+    pub requests: u64,
+    /// Requests priced from estimated token counts.
+    pub estimated_requests: u64,
+    /// Input tokens charged.
+    pub input_tokens: u64,
+    /// Output tokens charged.
+    pub output_tokens: u64,
+    /// Charged cost in USD.
+    pub cost_usd: f64,
+}
+
+impl AiSpendBucket {
+    fn add(&mut self, entry: &AiSpendEntry) {
+        self.requests += 1;
+        if entry.usage_source == AiUsageSource::Estimate {
+            self.estimated_requests += 1;
+        }
+        self.input_tokens = self.input_tokens.saturating_add(entry.input_tokens);
+        self.output_tokens = self.output_tokens.saturating_add(entry.output_tokens);
+        self.cost_usd += entry.cost_usd;
+    }
+}
+
+/// Group entries by `grouping`; days sort chronologically, everything else by cost.
+#[must_use]
+pub fn summarize_ai_spend(
+    entries: &[AiSpendEntry],
+    grouping: AiSpendGrouping,
+) -> Vec<AiSpendBucket> {
+    let mut buckets = BTreeMap::<String, AiSpendBucket>::new();
+    for entry in entries {
+        let key = grouping.key(entry);
+        buckets
+            .entry(key.clone())
+            .or_insert_with(|| AiSpendBucket {
+                key,
+                ..AiSpendBucket::default()
+            })
+            .add(entry);
+    }
+
+    let mut rows = buckets.into_values().collect::<Vec<_>>();
+    if grouping != AiSpendGrouping::Day {
+        rows.sort_by(|left, right| {
+            right
+                .cost_usd
+                .total_cmp(&left.cost_usd)
+                .then_with(|| left.key.cmp(&right.key))
+        });
+    }
+    rows
+}
+
+/// Total of every bucket.
+#[must_use]
+pub fn total_ai_spend(entries: &[AiSpendEntry]) -> AiSpendBucket {
+    let mut total = AiSpendBucket {
+        key: "total".to_string(),
+        ..AiSpendBucket::default()
+    };
+    for entry in entries {
+        total.add(entry);
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2026-03-31T12:00:00Z and 2026-04-01T12:00:00Z.
+    const MARCH_31_MS: u64 = 1_774_958_400_000;
+    const DAY_MS: u64 = 86_400_000;
+    const APRIL_1_MS: u64 = MARCH_31_MS + DAY_MS;
+
+    fn entry(timestamp_unix_ms: u64, intent: &str, model: &str, cost_usd: f64) -> AiSpendEntry {
+        AiSpendEntry {
+            timestamp_unix_ms,
+            session_id: "session-a".to_string(),
+            operator: "local-cli-operator".to_string(),
+            intent: intent.to_string(),
+            provider: "openai".to_string(),
+            model: model.to_string(),
+            input_tokens: 100,
+            output_tokens: 50,
+            cost_usd,
+            usage_source: AiUsageSource::Provider,
+        }
+    }
+
+    #[test]
+    fn price_table_prefers_the_most_specific_entry() {
+        let table = AiPriceTable::new(AiModelPrice::new(0.5, 0.5))
+            .with_entries([
+                "openai/gpt-4.1-mini=0.0004:0.0016",
+                "openai/*=0.002:0.008",
+                "*/llama-3=0.0001:0.0001",
+            ])
+            .expect("valid entries");
+
+        assert_eq!(
+            table.price_for("OpenAI", "gpt-4.1-mini"),
+            AiModelPrice::new(0.0004, 0.0016)
+        );
+        assert_eq!(
+            table.price_for("openai", "gpt-4.1"),
+            AiModelPrice::new(0.002, 0.008)
+        );
+        assert_eq!(
+            table.price_for("ollama", "llama-3"),
+            AiModelPrice::new(0.0001, 0.0001)
+        );
+        assert_eq!(table.price_for("mock", "mock"), AiModelPrice::new(0.5, 0.5));
+
+        let cost = table
+            .price_for("openai", "gpt-4.1-mini")
+            .cost_usd(2_000, 500);
+        assert!((cost - 0.0016).abs() < 1e-12);
+    }
+
+    #[test]
+    fn price_table_rejects_malformed_entries() {
+        for entry in ["openai=1:2", "openai/gpt=1", "openai/gpt=a:2", "/gpt=1:2"] {
+            let error = AiPriceTable::default()
+                .with_entries([entry])
+                .expect_err("malformed entry");
+            assert!(error.contains(entry), "{error}");
+        }
+    }
+
+    #[test]
+    fn ledger_round_trips_entries_and_skips_malformed_lines() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let ledger = AiSpendLedger::from_path(dir.path().join("ai").join("spend.jsonl"));
+        ledger
+            .append(&entry(MARCH_31_MS, "ask", "gpt-4.1-mini", 0.25))
+            .expect("append");
+        fs::OpenOptions::new()
+            .append(true)
+            .open(ledger.path())
+            .and_then(|mut file| writeln!(file, "not json"))
+            .expect("corrupt line");
+        ledger
+            .append(&entry(APRIL_1_MS, "plan", "gpt-4.1", 1.0))
+            .expect("append");
+
+        assert_eq!(ledger.load_since(0).expect("load").len(), 2);
+        let recent = ledger.load_since(APRIL_1_MS).expect("load");
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].intent, "plan");
+
+        let totals = ledger.totals_at(APRIL_1_MS).expect("totals");
+        assert_eq!(
+            totals,
+            AiSpendTotals {
+                day_usd: 1.0,
+                month_usd: 1.0,
+            }
+        );
+    }
+
+    #[test]
+    fn ledger_totals_resume_from_checkpoint_and_roll_over() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let ledger = AiSpendLedger::from_path(dir.path().join("spend.jsonl"));
+        ledger
+            .append(&entry(MARCH_31_MS, "ask", "gpt-4.1", 0.5))
+            .expect("append");
+        assert_eq!(
+            ledger.totals_at(MARCH_31_MS).expect("totals"),
+            AiSpendTotals {
+                day_usd: 0.5,
+                month_usd: 0.5,
+            }
+        );
+
+        // Earlier lines are not re-read: a checkpoint total is trusted as-is.
+        let checkpoint_path = dir.path().join("spend.totals.json");
+        let mut checkpoint: AiSpendCheckpoint =
+            serde_json::from_slice(&fs::read(&checkpoint_path).expect("checkpoint"))
+                .expect("checkpoint json");
+        assert_eq!(
+            checkpoint.offset,
+            fs::metadata(ledger.path()).unwrap().len()
+        );
+        checkpoint.month_usd = 7.0;
+        fs::write(&checkpoint_path, serde_json::to_vec(&checkpoint).unwrap()).unwrap();
+        ledger
+            .append(&entry(MARCH_31_MS + 1, "plan", "gpt-4.1", 0.25))
+            .expect("append");
+        assert_eq!(
+            ledger.totals_at(MARCH_31_MS + 1).expect("totals"),
+            AiSpendTotals {
+                day_usd: 0.75,
+                month_usd: 7.25,
+            }
+        );
+
+        // A new day and month start from zero.
+        ledger
+            .append(&entry(APRIL_1_MS, "ask", "gpt-4.1", 1.0))
+            .expect("append");
+        assert_eq!(
+            ledger.totals_at(APRIL_1_MS).expect("totals"),
+            AiSpendTotals {
+                day_usd: 1.0,
+                month_usd: 1.0,
+            }
+        );
+
+        // A ledger shorter than the checkpoint offset is read in full again.
+        fs::write(ledger.path(), "").unwrap();
+        ledger
+            .append(&entry(APRIL_1_MS, "ask", "gpt-4.1", 0.1))
+            .expect("append");
+        let totals = ledger.totals_at(APRIL_1_MS).expect("totals");
+        assert!((totals.day_usd - 0.1).abs() < 1e-12, "{totals:?}");
+    }
+
+    #[test]
+    fn ledger_totals_skip_a_partially_written_line() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let ledger = AiSpendLedger::from_path(dir.path().join("spend.jsonl"));
+        ledger
+            .append(&entry(APRIL_1_MS, "ask", "gpt-4.1", 1.0))
+            .expect("append");
+        let line = serde_json::to_string(&entry(APRIL_1_MS, "ask", "gpt-4.1", 2.0)).unwrap();
+        let (head, rest) = line.split_at(line.len() / 2);
+        fs::OpenOptions::new()
+            .append(true)
+            .open(ledger.path())
+            .and_then(|mut file| write!(file, "{head}"))
+            .expect("partial line");
+        assert_eq!(ledger.totals_at(APRIL_1_MS).expect("totals").day_usd, 1.0);
+
+        fs::OpenOptions::new()
+            .append(true)
+            .open(ledger.path())
+            .and_then(|mut file| writeln!(file, "{rest}"))
+            .expect("finish line");
+        assert_eq!(ledger.totals_at(APRIL_1_MS).expect("totals").day_usd, 3.0);
+    }
+
+    #[test]
+    fn spend_caps_include_the_projected_request() {
+        let totals = AiSpendTotals {
+            day_usd: 0.9,
+            month_usd: 9.0,
+        };
+        let caps = AiSpendCaps {
+            daily_usd: Some(1.0),
+            monthly_usd: Some(10.0),
+        };
+
+        assert!(caps.check(totals, 0.05).is_ok());
+        let daily = caps.check(totals, 0.2).expect_err("daily cap");
+        assert!(daily.starts_with("daily spend cap reached"), "{daily}");
+
+        let monthly = AiSpendCaps {
+            daily_usd: None,
+            monthly_usd: Some(9.0),
+        }
+        .check(totals, 0.01)
+        .expect_err("monthly cap");
+        assert!(
+            monthly.starts_with("monthly spend cap reached"),
+            "{monthly}"
+        );
+        assert!(AiSpendCaps::default().check(totals, 1e9).is_ok());
+    }
+
+    #[test]
+    fn summarize_groups_by_dimension() {
+        let mut estimated = entry(APRIL_1_MS, "ask", "gpt-4.1-mini", 0.5);
+        estimated.usage_source = AiUsageSource::Estimate;
+        let entries = vec![
+            entry(MARCH_31_MS, "plan", "gpt-4.1", 2.0),
+            entry(APRIL_1_MS, "ask", "gpt-4.1-mini", 0.25),
+            estimated,
+        ];
+
+        let by_day = summarize_ai_spend(&entries, AiSpendGrouping::Day);
+        let days = by_day
+            .iter()
+            .map(|row| row.key.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(days, ["2026-03-31", "2026-04-01"]);
+
+        let by_model = summarize_ai_spend(&entries, AiSpendGrouping::Model);
+        assert_eq!(by_model[0].key, "openai/gpt-4.1");
+        assert_eq!(by_model[1].key, "openai/gpt-4.1-mini");
+        assert_eq!(by_model[1].requests, 2);
+        assert_eq!(by_model[1].estimated_requests, 1);
+        assert!((by_model[1].cost_usd - 0.75).abs() < 1e-12);
+
+        let total = total_ai_spend(&entries);
+        assert_eq!(total.requests, 3);
+        assert_eq!(total.input_tokens, 300);
+        assert!((total.cost_usd - 2.75).abs() < 1e-12);
+        assert_eq!(
+            AiSpendGrouping::parse("Operator"),
+            Some(AiSpendGrouping::Operator)
+        );
+        assert_eq!(AiSpendGrouping::parse("provider"), None);
+    }
+}
@@ -1,8 +1,14 @@
 //! Approval gateway for AI-driven side-effect operations.
 //!
 //! This module enforces explicit approval for mutating actions and writes a
-//! local audit trail for approved/denied decisions.
-
+//! local audit trail for approved/denied decisions. It also holds the
+//! policy that parks submitted tasks until a human approves them.
+
+use super::repo_workflow::parse_repo_workflow_payload;
+use nettoolskit_core::{
+    ControlEnvelope, IngressTransport, OperatorContext, OperatorKind, PolicySource, TaskIntent,
+    TaskIntentKind,
+};
 use serde::{Deserialize, Serialize};
 use std::fs::{self, OpenOptions};
 use std::io::{self, Write};
@@ -17,6 +23,8 @@ pub enum ApprovalActionKind {
     CommandExecution,
     /// Write/update file content.
     FileWrite,
+    /// Release a task parked in the approval queue.
+    TaskExecution,
 }
 
 impl ApprovalActionKind {
@@ -26,6 +34,7 @@ impl ApprovalActionKind {
         match self {
             Self::CommandExecution => "command_execution",
             Self::FileWrite => "file_write",
+            Self::TaskExecution => "task_execution",
         }
     }
 }
@@ -125,6 +134,192 @@ impl ApprovalDecision {
     }
 }
 
+/// Environment variable listing task approval gates (`none` disables them).
+pub const NTK_TASK_APPROVAL_RULES_ENV: &str = "NTK_TASK_APPROVAL_RULES";
+/// Environment variable listing operator ids allowed to approve or reject tasks.
+pub const NTK_TASK_APPROVERS_ENV: &str = "NTK_TASK_APPROVERS";
+
+const DEFAULT_TASK_APPROVAL_RULES: [TaskApprovalRule; 2] = [
+    TaskApprovalRule::RepoWorkflowPush,
+    TaskApprovalRule::AutomationCommand,
+];
+
+/// Condition that parks a submitted task until an operator approves it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskApprovalRule {
+    /// `repo-workflow` tasks that push outside dry-run.
+    RepoWorkflowPush,
+    /// `command` tasks submitted by an automation operator.
+    AutomationCommand,
+    /// Every task with the given intent.
+    Intent(TaskIntentKind),
+}
+
+impl TaskApprovalRule {
+    /// Parse a rule token (`repo-workflow-push`, `automation-command`, `intent:<alias>`).
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_lowercase();
+        match value.as_str() {
+            "repo-workflow-push" | "repo-push" => Some(Self::RepoWorkflowPush),
+            "automation-command" => Some(Self::AutomationCommand),
+            _ => value
+                .strip_prefix("intent:")
+                .and_then(TaskIntentKind::from_alias)
+                .map(Self::Intent),
+        }
+    }
+
+    /// Stable rule label used in task details and audit entries.
+    #[must_use]
+    pub fn label(self) -> String {
+        match self {
+            Self::RepoWorkflowPush => "repo-workflow-push".to_string(),
+            Self::AutomationCommand => "automation-command".to_string(),
+            Self::Intent(kind) => format!("intent:{}", kind.as_str()),
+        }
+    }
+
+    fn matches(self, intent: &TaskIntent, control: Option<&ControlEnvelope>) -> bool {
+        match self {
+            Self::RepoWorkflowPush => {
+                intent.kind == TaskIntentKind::RepoWorkflow
+                    && parse_repo_workflow_payload(&intent.payload)
+                        .is_ok_and(|request| request.push && !request.dry_run)
+            }
+            Self::AutomationCommand => {
+                intent.kind == TaskIntentKind::CommandExecution
+                    && control
+                        .is_some_and(|control| control.operator.kind == OperatorKind::Automation)
+            }
+            Self::Intent(kind) => intent.kind == kind,
+        }
+    }
+}
+
+/// Policy deciding which tasks wait for approval and who may decide on them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskApprovalPolicy {
+    /// Active approval gates.
+    pub rules: Vec<TaskApprovalRule>,
+    /// Operator ids allowed to decide (empty allows any non-automation operator).
+    pub approvers: Vec<String>,
+}
+
+impl Default for TaskApprovalPolicy {
+    fn default() -> Self {
+        Self {
+            rules: DEFAULT_TASK_APPROVAL_RULES.to_vec(),
+            approvers: Vec::new(),
+        }
+    }
+}
+
+impl TaskApprovalPolicy {
+    /// Build policy from `NTK_TASK_APPROVAL_RULES` and `NTK_TASK_APPROVERS`, or
+    /// the `[task_approval]` config section.
+    ///
+    /// Unknown rule tokens are ignored; an unset variable keeps the default gates.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::from_source(&PolicySource::load())
+    }
+
+    fn from_source(source: &PolicySource) -> Self {
+        let mut policy = Self::default();
+        if let Some(value) = source.var(NTK_TASK_APPROVAL_RULES_ENV) {
+            let value = value.trim();
+            policy.rules = if value.is_empty() || value.eq_ignore_ascii_case("none") {
+                Vec::new()
+            } else {
+                split_list(value)
+                    .filter_map(TaskApprovalRule::parse)
+                    .collect()
+            };
+        }
+        if let Some(value) = source.var(NTK_TASK_APPROVERS_ENV) {
+            policy.approvers = split_list(&value).map(ToOwned::to_owned).collect();
+        }
+        policy
+    }
+
+    /// Gate that parks the task, if any.
+    ///
+    /// Envelopes that already carry [`nettoolskit_core::ApprovalState::Required`] are
+    /// always gated.
+    #[must_use]
+    pub fn gate_for(
+        &self,
+        intent: &TaskIntent,
+        control: Option<&ControlEnvelope>,
+    ) -> Option<String> {
+        if control.is_some_and(ControlEnvelope::requires_approval) {
+            return Some("control-envelope".to_string());
+        }
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(intent, control))
+            .map(|rule| rule.label())
+    }
+
+    /// Check whether `operator` may approve or reject parked tasks.
+    ///
+    /// # Errors
+    ///
+    /// Returns the denial reason for automation identities and operators
+    /// missing from a configured approver allowlist.
+    pub fn authorize_approver(&self, operator: &OperatorContext) -> Result<(), String> {
+        if operator.kind == OperatorKind::Automation {
+            return Err(format!(
+                "automation operator `{}` cannot approve tasks",
+                operator.id
+            ));
+        }
+        if !self.approvers.is_empty() && !self.approvers.iter().any(|id| id == &operator.id) {
+            return Err(format!(
+                "operator `{}` is not listed in {NTK_TASK_APPROVERS_ENV}",
+                operator.id
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check that `approver` is not the operator who submitted the task.
+    ///
+    /// The local CLI has a single operator, so a task submitted there may be
+    /// decided from the local CLI; every other ingress needs a second operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the denial reason when the submitter decides its own task.
+    pub fn authorize_decision(
+        &self,
+        approver: &OperatorContext,
+        submitter: Option<&OperatorContext>,
+    ) -> Result<(), String> {
+        let Some(submitter) = submitter else {
+            return Ok(());
+        };
+        let local_cli = approver.transport == IngressTransport::Cli
+            && submitter.transport == IngressTransport::Cli;
+        if !local_cli && submitter.id == approver.id {
+            return Err(format!(
+                "operator `{}` submitted this task and cannot decide its own approval; \
+                 cancel it instead",
+                approver.id
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
 /// Local audit record serialized as JSONL.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct ApprovalAuditRecord {
@@ -137,6 +332,12 @@ struct ApprovalAuditRecord {
     decision: String,
     decision_reason: String,
     source: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    task_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    approver: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    approver_kind: Option<OperatorKind>,
 }
 
 /// Evaluate an approval request without side effects.
@@ -163,22 +364,54 @@ pub fn evaluate_approval(request: &ApprovalRequest) -> ApprovalDecision {
 #[must_use]
 pub fn request_approval(request: ApprovalRequest) -> ApprovalDecision {
     let decision = evaluate_approval(&request);
-    let _ = append_approval_audit(&request, &decision);
+    let _ = append_approval_audit(&request, &decision, None);
     decision
 }
 
-fn append_approval_audit(request: &ApprovalRequest, decision: &ApprovalDecision) -> io::Result<()> {
+/// Append an operator's decision on a parked task to the approval audit log.
+pub fn record_task_approval_decision(
+    task_id: &str,
+    gate: &str,
+    approver: &OperatorContext,
+    decision: &ApprovalDecision,
+) {
+    let request = ApprovalRequest {
+        action: ApprovalActionKind::TaskExecution,
+        target: task_id.to_string(),
+        reason: gate.to_string(),
+        dry_run: false,
+        explicit_approval: decision.is_approved(),
+        source: approver.transport.as_str().to_string(),
+    };
+    let _ = append_approval_audit(
+        &request,
+        decision,
+        Some(TaskApprovalAudit { task_id, approver }),
+    );
+}
+
+struct TaskApprovalAudit<'a> {
+    task_id: &'a str,
+    approver: &'a OperatorContext,
+}
+
+fn append_approval_audit(
+    request: &ApprovalRequest,
+    decision: &ApprovalDecision,
+    task: Option<TaskApprovalAudit<'_>>,
+) -> io::Result<()> {
     let Some(path) = resolve_approval_audit_path() else {
         return Ok(());
     };
 
-    append_approval_audit_to(&path, request, decision)
+    append_approval_audit_to(&path, request, decision, task)
 }
 
 fn append_approval_audit_to(
     path: &Path,
     request: &ApprovalRequest,
     decision: &ApprovalDecision,
+    task: Option<TaskApprovalAudit<'_>>,
 ) -> io::Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
@@ -195,6 +428,9 @@ fn append_approval_audit_to(
         decision: decision.status().to_string(),
         decision_reason: decision.reason().to_string(),
         source: request.source.clone(),
+        task_id: task.as_ref().map(|task| task.task_id.to_string()),
+        approver: task.as_ref().map(|task| task.approver.id.clone()),
+        approver_kind: task.as_ref().map(|task| task.approver.kind),
     };
 
     let line = serde_json::to_string(&record).map_err(io::Error::other)?;
@@ -281,7 +517,7 @@ mod tests {
             reason: "explicit approval provided".to_string(),
         };
 
-        append_approval_audit_to(&file_path, &request, &decision)
+        append_approval_audit_to(&file_path, &request, &decision, None)
             .expect("audit entry should be written");
 
         let content = fs::read_to_string(file_path).expect("audit file should be readable");
@@ -289,4 +525,145 @@ mod tests {
         assert!(content.contains("\"decision\":\"approved\""));
         assert!(content.contains("\"target\":\"echo hi\""));
     }
+
+    fn repo_push_intent(dry_run: bool) -> TaskIntent {
+        TaskIntent::new(
+            TaskIntentKind::RepoWorkflow,
+            "repo workflow",
+            format!(
+                "repo=https://github.com/acme/app.git;branch=main;command=cargo test;push=true;dry_run={dry_run}"
+            ),
+        )
+    }
+
+    #[test]
+    fn task_approval_policy_reads_config_section() {
+        let mut config = nettoolskit_core::AppConfig::default();
+        config.task_approval.rules = Some(vec!["intent:ai-plan".to_string()]);
+        config.task_approval.approvers =
+            Some(vec!["alice".to_string(), "telegram:12345".to_string()]);
+        let policy = TaskApprovalPolicy::from_source(&PolicySource::from_config(&config));
+        assert_eq!(
+            policy.rules,
+            vec![TaskApprovalRule::Intent(TaskIntentKind::AiPlan)]
+        );
+        assert_eq!(policy.approvers, vec!["alice", "telegram:12345"]);
+
+        config.task_approval.rules = Some(Vec::new());
+        let policy = TaskApprovalPolicy::from_source(&PolicySource::from_config(&config));
+        assert!(policy.rules.is_empty());
+    }
+
+    #[test]
+    fn task_approval_policy_default_gates_repo_push_but_not_dry_run() {
+        let policy = TaskApprovalPolicy::default();
+        assert_eq!(
+            policy.gate_for(&repo_push_intent(false), None).as_deref(),
+            Some("repo-workflow-push")
+        );
+        assert!(policy.gate_for(&repo_push_intent(true), None).is_none());
+
+        let plan = TaskIntent::new(TaskIntentKind::AiPlan, "plan", "refactor");
+        assert!(policy.gate_for(&plan, None).is_none());
+    }
+
+    #[test]
+    fn task_approval_policy_gates_automation_commands_only() {
+        let policy = TaskApprovalPolicy::default();
+        let intent = TaskIntent::new(TaskIntentKind::CommandExecution, "cmd", "/help");
+        let envelope = |kind| {
+            ControlEnvelope::new(
+                "req-1",
+                nettoolskit_core::RuntimeMode::Service,
+                OperatorContext::new(kind, "bot", nettoolskit_core::IngressTransport::ServiceHttp),
+                nettoolskit_core::SessionContext::new(
+                    nettoolskit_core::SessionKind::ServiceRequest,
+                    "session-1",
+                    false,
+                ),
+                intent.clone(),
+            )
+        };
+
+        let automation = envelope(OperatorKind::Automation);
+        assert_eq!(
+            policy.gate_for(&intent, Some(&automation)).as_deref(),
+            Some("automation-command")
+        );
+        let human = envelope(OperatorKind::RemoteHuman);
+        assert!(policy.gate_for(&intent, Some(&human)).is_none());
+    }
+
+    #[test]
+    fn task_approval_rule_parse_accepts_aliases_and_intents() {
+        assert_eq!(
+            TaskApprovalRule::parse("repo-push"),
+            Some(TaskApprovalRule::RepoWorkflowPush)
+        );
+        assert_eq!(
+            TaskApprovalRule::parse(" Automation-Command "),
+            Some(TaskApprovalRule::AutomationCommand)
+        );
+        let rule = TaskApprovalRule::parse("intent:ai-plan").expect("intent rule should parse");
+        assert_eq!(rule, TaskApprovalRule::Intent(TaskIntentKind::AiPlan));
+        assert_eq!(rule.label(), "intent:ai-plan");
+        assert!(TaskApprovalRule::parse("intent:unknown").is_none());
+        assert!(TaskApprovalRule::parse("everything").is_none());
+    }
+
+    #[test]
+    fn task_approval_policy_authorize_approver_rejects_automation_and_unlisted() {
+        let transport = nettoolskit_core::IngressTransport::ServiceHttp;
+        let open = TaskApprovalPolicy::default();
+        assert!(open
+            .authorize_approver(&OperatorContext::new(
+                OperatorKind::RemoteHuman,
+                "alice",
+                transport
+            ))
+            .is_ok());
+        let bot = OperatorContext::new(OperatorKind::Automation, "ci-bot", transport);
+        assert!(open
+            .authorize_approver(&bot)
+            .expect_err("automation should be denied")
+            .contains("automation operator"));
+
+        let restricted = TaskApprovalPolicy {
+            approvers: vec!["alice".to_string()],
+            ..TaskApprovalPolicy::default()
+        };
+        let mallory = OperatorContext::new(OperatorKind::RemoteHuman, "mallory", transport);
+        assert!(restricted
+            .authorize_approver(&mallory)
+            .expect_err("unlisted operator should be denied")
+            .contains(NTK_TASK_APPROVERS_ENV));
+    }
+
+    #[test]
+    fn task_approval_policy_authorize_decision_rejects_submitter_outside_local_cli() {
+        let policy = TaskApprovalPolicy::default();
+        let alice = OperatorContext::new(
+            OperatorKind::RemoteHuman,
+            "alice",
+            IngressTransport::ServiceHttp,
+        );
+        let bob = OperatorContext::new(
+            OperatorKind::RemoteHuman,
+            "bob",
+            IngressTransport::ServiceHttp,
+        );
+        assert!(policy
+            .authorize_decision(&alice, Some(&alice))
+            .expect_err("self-approval should be denied")
+            .contains("submitted this task"));
+        assert!(policy.authorize_decision(&bob, Some(&alice)).is_ok());
+        assert!(policy.authorize_decision(&bob, None).is_ok());
+
+        let local = OperatorContext::new(
+            OperatorKind::LocalHuman,
+            "local-cli-operator",
+            IngressTransport::Cli,
+        );
+        assert!(policy.authorize_decision(&local, Some(&local)).is_ok());
+    }
 }
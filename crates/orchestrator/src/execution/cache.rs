@@ -3,10 +3,26 @@
 //! The cache is intentionally small and lightweight because it is used by
 //! interactive command flows where predictable latency matters more than
 //! unlimited retention.
+//!
+//! An optional [`PersistentCacheTier`] keeps AI responses and manifest
+//! listings on disk between CLI runs. Entries are content-addressed JSON
+//! files named after a hash of their key. A hit bumps the file's mtime, so
+//! the disk budget evicts the least recently used files first. Manifest
+//! listings also record the modification time of every directory they were
+//! discovered from, captured before discovery runs, and are dropped as soon
+//! as one of those directories changes.
 
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::io;
 use std::path::{Path, PathBuf};
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const PERSISTENT_ENTRY_EXTENSION: &str = "json";
+const MANIFEST_WATCH_MAX_DEPTH: usize = 8;
+const MANIFEST_WATCH_MAX_DIRECTORIES: usize = 4_096;
+const MANIFEST_WATCH_SKIPPED_DIRS: &[&str] = &["target", "node_modules"];
 
 /// Supported cache partitions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -19,6 +35,22 @@ pub(crate) enum CacheKind {
     AiResponse,
 }
 
+impl CacheKind {
+    /// Stable label used in persistent entry file names.
+    pub(crate) const fn as_label(self) -> &'static str {
+        match self {
+            Self::Help => "help",
+            Self::ManifestList => "manifest-list",
+            Self::AiResponse => "ai-response",
+        }
+    }
+
+    /// Whether entries of this partition are written to the persistent tier.
+    pub(crate) const fn is_persistent(self) -> bool {
+        matches!(self, Self::ManifestList | Self::AiResponse)
+    }
+}
+
 /// Cache key composed of command partition + discriminator.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) struct CacheKey {
@@ -27,11 +59,11 @@ pub(crate) struct CacheKey {
 }
 
 impl CacheKey {
-    /// Build key for global help markdown.
-    pub(crate) fn help() -> Self {
+    /// Build key for help markdown listing the given registered commands.
+    pub(crate) fn help_for<'a>(command_names: impl IntoIterator<Item = &'a str>) -> Self {
         Self {
             kind: CacheKind::Help,
-            discriminator: "global".to_string(),
+            discriminator: command_names.into_iter().collect::<Vec<_>>().join(","),
         }
     }
 
@@ -59,10 +91,20 @@ impl CacheKey {
     pub(crate) fn kind(&self) -> CacheKind {
         self.kind
     }
+
+    /// Content address of this key (`ai-response-<fnv64>.json`).
+    fn file_name(&self) -> String {
+        format!(
+            "{}-{:016x}.{PERSISTENT_ENTRY_EXTENSION}",
+            self.kind.as_label(),
+            fnv1a_64(self.discriminator.as_bytes())
+        )
+    }
 }
 
 /// Value variants supported by command-result cache.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "kebab-case")]
 pub(crate) enum CacheValue {
     /// Render-ready help markdown.
     HelpMarkdown(String),
@@ -118,6 +160,18 @@ impl Default for CacheTtl {
     }
 }
 
+impl CacheTtl {
+    /// Default lifetimes of the persistent tier: one day for AI responses and
+    /// a week for manifest listings, which are also invalidated by mtime.
+    pub(crate) fn persistent() -> Self {
+        Self {
+            help: Duration::ZERO,
+            manifest_list: Duration::from_secs(7 * 24 * 60 * 60),
+            ai_response: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct CacheEntry {
     value: CacheValue,
@@ -140,6 +194,15 @@ pub(crate) struct CacheStats {
     pub(crate) size_bytes: usize,
 }
 
+/// Entries dropped by [`CommandResultCache::clear`], per tier.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct CacheClearSummary {
+    /// In-memory entries dropped.
+    pub(crate) memory_entries: usize,
+    /// Files removed from the persistent tier.
+    pub(crate) persistent_entries: usize,
+}
+
 /// In-memory LRU cache used by orchestrator command flows.
 pub(crate) struct CommandResultCache {
     entries: HashMap<CacheKey, CacheEntry>,
@@ -151,6 +214,7 @@ pub(crate) struct CommandResultCache {
     hits: u64,
     misses: u64,
     evictions: u64,
+    persistent: Option<PersistentCacheTier>,
 }
 
 impl CommandResultCache {
@@ -166,10 +230,25 @@ impl CommandResultCache {
             hits: 0,
             misses: 0,
             evictions: 0,
+            persistent: None,
         }
     }
 
+    /// Back AI responses and manifest listings with an on-disk tier.
+    pub(crate) fn with_persistent_tier(mut self, tier: PersistentCacheTier) -> Self {
+        self.persistent = Some(tier);
+        self
+    }
+
+    /// Persistent tier, when one is configured.
+    pub(crate) fn persistent_tier(&self) -> Option<&PersistentCacheTier> {
+        self.persistent.as_ref()
+    }
+
     /// Fetch a cache entry and refresh its recency on hit.
+    ///
+    /// Memory misses fall through to the persistent tier; disk hits are
+    /// promoted into memory.
     pub(crate) fn get(&mut self, key: &CacheKey) -> Option<CacheValue> {
         self.evict_expired();
 
@@ -184,6 +263,12 @@ impl CommandResultCache {
             return Some(value);
         }
 
+        if let Some(value) = self.persistent.as_mut().and_then(|tier| tier.get(key)) {
+            self.hits = self.hits.saturating_add(1);
+            self.insert_in_memory(key.clone(), value.clone());
+            return Some(value);
+        }
+
         self.misses = self.misses.saturating_add(1);
         None
     }
@@ -192,6 +277,47 @@ impl CommandResultCache {
     ///
     /// Returns `false` when value is larger than cache memory budget.
     pub(crate) fn insert(&mut self, key: CacheKey, value: CacheValue) -> bool {
+        if let Some(tier) = self.persistent.as_mut() {
+            tier.insert(&key, &value);
+        }
+        self.insert_in_memory(key, value)
+    }
+
+    /// Insert a manifest listing, persisting it against `snapshot` taken
+    /// before discovery; without a snapshot it is only kept in memory.
+    pub(crate) fn insert_manifest_listing(
+        &mut self,
+        key: CacheKey,
+        value: CacheValue,
+        snapshot: Option<&DirectorySnapshot>,
+    ) -> bool {
+        if let (Some(tier), Some(snapshot)) = (self.persistent.as_mut(), snapshot) {
+            tier.insert_manifest_listing(&key, &value, snapshot);
+        }
+        self.insert_in_memory(key, value)
+    }
+
+    /// Drop every entry from memory and from the persistent tier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when persistent entries cannot be listed or removed.
+    pub(crate) fn clear(&mut self) -> io::Result<CacheClearSummary> {
+        let memory_entries = self.entries.len();
+        self.entries.clear();
+        self.lru_order.clear();
+        self.current_size_bytes = 0;
+        let persistent_entries = match self.persistent.as_mut() {
+            Some(tier) => tier.clear()?,
+            None => 0,
+        };
+        Ok(CacheClearSummary {
+            memory_entries,
+            persistent_entries,
+        })
+    }
+
+    fn insert_in_memory(&mut self, key: CacheKey, value: CacheValue) -> bool {
         self.evict_expired();
 
         let size_bytes = value.estimate_size_bytes();
@@ -275,6 +401,351 @@ impl CommandResultCache {
     }
 }
 
+/// Observable state of the persistent tier.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct PersistentCacheStats {
+    /// Lookups answered from disk.
+    pub(crate) hits: u64,
+    /// Lookups that found no usable entry on disk.
+    pub(crate) misses: u64,
+    /// Entries removed for TTL or size budget.
+    pub(crate) evictions: u64,
+    /// Manifest listings dropped because a watched directory changed.
+    pub(crate) invalidations: u64,
+    /// Entries that could not be written.
+    pub(crate) write_errors: u64,
+    /// Entry files currently on disk.
+    pub(crate) entries: usize,
+    /// Bytes currently on disk.
+    pub(crate) size_bytes: u64,
+    /// Disk budget.
+    pub(crate) max_size_bytes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct WatchedDirectory {
+    path: PathBuf,
+    modified_ns: u64,
+}
+
+impl WatchedDirectory {
+    fn is_unchanged(&self) -> bool {
+        directory_modified_ns(&self.path) == Some(self.modified_ns)
+    }
+}
+
+/// Directory modification times of a manifest root, captured before discovery
+/// walks it so changes made while discovery runs invalidate the listing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DirectorySnapshot {
+    watched_dirs: Vec<WatchedDirectory>,
+}
+
+impl DirectorySnapshot {
+    /// Snapshot `root`; `None` when it has too many directories to watch.
+    pub(crate) fn capture(root: &Path) -> Option<Self> {
+        snapshot_directory_mtimes(root).map(|watched_dirs| Self { watched_dirs })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedCacheEntry {
+    kind: String,
+    key: String,
+    stored_at_ms: u64,
+    expires_at_ms: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    watched_dirs: Vec<WatchedDirectory>,
+    value: CacheValue,
+}
+
+struct PersistedEntryFile {
+    path: PathBuf,
+    size_bytes: u64,
+    /// File mtime, bumped on every hit.
+    last_used: SystemTime,
+}
+
+/// On-disk tier for AI responses and manifest listings.
+///
+/// Write failures are counted rather than surfaced so a read-only or full
+/// data directory never breaks the command that tried to cache a result.
+pub(crate) struct PersistentCacheTier {
+    dir: PathBuf,
+    max_size_bytes: u64,
+    ttl: CacheTtl,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+    invalidations: u64,
+    write_errors: u64,
+}
+
+impl PersistentCacheTier {
+    /// Create a tier storing entries under `dir` within `max_size_bytes`.
+    pub(crate) fn new(dir: impl Into<PathBuf>, max_size_bytes: u64, ttl: CacheTtl) -> Self {
+        Self {
+            dir: dir.into(),
+            max_size_bytes: max_size_bytes.max(1),
+            ttl,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+            invalidations: 0,
+            write_errors: 0,
+        }
+    }
+
+    /// Directory holding the entry files.
+    pub(crate) fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Read a live entry, removing it when expired, corrupt or stale.
+    pub(crate) fn get(&mut self, key: &CacheKey) -> Option<CacheValue> {
+        if !key.kind.is_persistent() {
+            return None;
+        }
+
+        let path = self.dir.join(key.file_name());
+        let Ok(bytes) = fs::read(&path) else {
+            self.misses = self.misses.saturating_add(1);
+            return None;
+        };
+
+        let entry = serde_json::from_slice::<PersistedCacheEntry>(&bytes)
+            .ok()
+            .filter(|entry| entry.kind == key.kind.as_label() && entry.key == key.discriminator);
+        let value = match entry {
+            Some(entry) if entry.expires_at_ms <= epoch_ms(SystemTime::now()) => {
+                self.evictions = self.evictions.saturating_add(1);
+                None
+            }
+            Some(entry)
+                if !entry
+                    .watched_dirs
+                    .iter()
+                    .all(WatchedDirectory::is_unchanged) =>
+            {
+                self.invalidations = self.invalidations.saturating_add(1);
+                None
+            }
+            Some(entry) => Some(entry.value),
+            None => None,
+        };
+
+        if value.is_some() {
+            self.hits = self.hits.saturating_add(1);
+            // Bump the mtime so budget eviction drops the least recently used entries.
+            let _ = fs::File::options()
+                .write(true)
+                .open(&path)
+                .and_then(|file| file.set_modified(SystemTime::now()));
+        } else {
+            self.misses = self.misses.saturating_add(1);
+            let _ = fs::remove_file(&path);
+        }
+        value
+    }
+
+    /// Write an entry, then evict the least recently used files beyond the disk budget.
+    ///
+    /// Returns `false` when the key is not persisted, is a manifest listing
+    /// (see [`Self::insert_manifest_listing`]), the entry exceeds the budget,
+    /// or the write failed.
+    pub(crate) fn insert(&mut self, key: &CacheKey, value: &CacheValue) -> bool {
+        if key.kind == CacheKind::ManifestList {
+            return false;
+        }
+        self.write(key, value, Vec::new())
+    }
+
+    /// Write a manifest listing that stays valid while the directories in
+    /// `snapshot`, taken before discovery, keep their modification times.
+    pub(crate) fn insert_manifest_listing(
+        &mut self,
+        key: &CacheKey,
+        value: &CacheValue,
+        snapshot: &DirectorySnapshot,
+    ) -> bool {
+        if key.kind != CacheKind::ManifestList {
+            return false;
+        }
+        self.write(key, value, snapshot.watched_dirs.clone())
+    }
+
+    fn write(
+        &mut self,
+        key: &CacheKey,
+        value: &CacheValue,
+        watched_dirs: Vec<WatchedDirectory>,
+    ) -> bool {
+        if !key.kind.is_persistent() {
+            return false;
+        }
+
+        let now = SystemTime::now();
+        let entry = PersistedCacheEntry {
+            kind: key.kind.as_label().to_string(),
+            key: key.discriminator.clone(),
+            stored_at_ms: epoch_ms(now),
+            expires_at_ms: epoch_ms(now + self.ttl.for_kind(key.kind)),
+            watched_dirs,
+            value: value.clone(),
+        };
+        let Ok(bytes) = serde_json::to_vec(&entry) else {
+            self.write_errors = self.write_errors.saturating_add(1);
+            return false;
+        };
+        if bytes.len() as u64 > self.max_size_bytes {
+            return false;
+        }
+
+        if self.write_entry(&key.file_name(), &bytes).is_err() {
+            self.write_errors = self.write_errors.saturating_add(1);
+            return false;
+        }
+        self.evict_to_budget();
+        true
+    }
+
+    /// Remove every entry file; returns how many were removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the directory cannot be listed or a file cannot be removed.
+    pub(crate) fn clear(&mut self) -> io::Result<usize> {
+        let files = self.entry_files()?;
+        for file in &files {
+            fs::remove_file(&file.path)?;
+        }
+        Ok(files.len())
+    }
+
+    /// Counters plus the current on-disk footprint.
+    pub(crate) fn stats(&self) -> PersistentCacheStats {
+        let files = self.entry_files().unwrap_or_default();
+        PersistentCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            invalidations: self.invalidations,
+            write_errors: self.write_errors,
+            entries: files.len(),
+            size_bytes: files.iter().map(|file| file.size_bytes).sum(),
+            max_size_bytes: self.max_size_bytes,
+        }
+    }
+
+    fn write_entry(&self, file_name: &str, bytes: &[u8]) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join(file_name);
+        let staging = path.with_extension("tmp");
+        fs::write(&staging, bytes)?;
+        fs::rename(&staging, &path)
+    }
+
+    fn evict_to_budget(&mut self) {
+        let Ok(mut files) = self.entry_files() else {
+            return;
+        };
+        let mut total: u64 = files.iter().map(|file| file.size_bytes).sum();
+        if total <= self.max_size_bytes {
+            return;
+        }
+
+        files.sort_by_key(|file| file.last_used);
+        for file in files {
+            if total <= self.max_size_bytes {
+                break;
+            }
+            if fs::remove_file(&file.path).is_ok() {
+                total = total.saturating_sub(file.size_bytes);
+                self.evictions = self.evictions.saturating_add(1);
+            }
+        }
+    }
+
+    fn entry_files(&self) -> io::Result<Vec<PersistedEntryFile>> {
+        let read_dir = match fs::read_dir(&self.dir) {
+            Ok(read_dir) => read_dir,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+
+        let mut files = Vec::new();
+        for entry in read_dir {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(PERSISTENT_ENTRY_EXTENSION) {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+            files.push(PersistedEntryFile {
+                path,
+                size_bytes: metadata.len(),
+                last_used: metadata.modified().unwrap_or(UNIX_EPOCH),
+            });
+        }
+        Ok(files)
+    }
+}
+
+/// Modification times of `root` and the directories manifest discovery
+/// walks below it; `None` when there are too many to watch.
+fn snapshot_directory_mtimes(root: &Path) -> Option<Vec<WatchedDirectory>> {
+    let mut watched = Vec::new();
+    let mut pending = vec![(root.to_path_buf(), 0_usize)];
+    while let Some((dir, depth)) = pending.pop() {
+        let modified_ns = directory_modified_ns(&dir)?;
+        watched.push(WatchedDirectory {
+            path: dir.clone(),
+            modified_ns,
+        });
+        if watched.len() > MANIFEST_WATCH_MAX_DIRECTORIES {
+            return None;
+        }
+        if depth >= MANIFEST_WATCH_MAX_DEPTH {
+            continue;
+        }
+
+        let Ok(read_dir) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in read_dir.flatten() {
+            let is_dir = entry.file_type().is_ok_and(|file_type| file_type.is_dir());
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if is_dir && !name.starts_with('.') && !MANIFEST_WATCH_SKIPPED_DIRS.contains(&&*name) {
+                pending.push((entry.path(), depth + 1));
+            }
+        }
+    }
+    Some(watched)
+}
+
+fn directory_modified_ns(path: &Path) -> Option<u64> {
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+    let nanos = modified.duration_since(UNIX_EPOCH).ok()?.as_nanos();
+    u64::try_from(nanos).ok()
+}
+
+fn epoch_ms(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |elapsed| {
+        u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+    })
+}
+
+/// FNV-1a, so entry names stay stable across toolchains (unlike `DefaultHasher`).
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,7 +762,7 @@ mod tests {
     #[test]
     fn lru_eviction_respects_recent_usage() {
         let mut cache = CommandResultCache::new(2, 1024 * 1024, CacheTtl::default());
-        let k1 = CacheKey::help();
+        let k1 = CacheKey::help_for(["/help"]);
         let k2 = CacheKey::manifest_list(Path::new("c:/workspace/a"));
         let k3 = CacheKey::manifest_list(Path::new("c:/workspace/b"));
 
@@ -321,7 +792,7 @@ mod tests {
     #[test]
     fn ttl_is_applied_per_cache_kind() {
         let mut cache = CommandResultCache::new(4, 1024 * 1024, short_ttl());
-        let help_key = CacheKey::help();
+        let help_key = CacheKey::help_for(["/help"]);
         let manifest_key = CacheKey::manifest_list(Path::new("c:/workspace"));
 
         assert!(cache.insert(
@@ -374,10 +845,184 @@ mod tests {
         );
     }
 
+    fn persistent_cache(dir: &Path, max_size_bytes: u64, ttl: CacheTtl) -> CommandResultCache {
+        CommandResultCache::new(4, 1024 * 1024, CacheTtl::default())
+            .with_persistent_tier(PersistentCacheTier::new(dir, max_size_bytes, ttl))
+    }
+
+    #[test]
+    fn persistent_tier_serves_entries_to_a_new_process() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let ai_key = CacheKey::ai_response("ask-00000000000000ff");
+        let help_key = CacheKey::help_for(["/help"]);
+
+        let mut first = persistent_cache(temp.path(), 1024 * 1024, CacheTtl::persistent());
+        assert!(first.insert(
+            ai_key.clone(),
+            CacheValue::AiResponseText("cached answer".to_string())
+        ));
+        assert!(first.insert(
+            help_key.clone(),
+            CacheValue::HelpMarkdown("help".to_string())
+        ));
+
+        let mut second = persistent_cache(temp.path(), 1024 * 1024, CacheTtl::persistent());
+        assert!(matches!(
+            second.get(&ai_key),
+            Some(CacheValue::AiResponseText(text)) if text == "cached answer"
+        ));
+        assert!(second.get(&help_key).is_none(), "Help is memory-only");
+        let disk = second.persistent_tier().expect("tier").stats();
+        assert_eq!(disk.entries, 1);
+        assert_eq!(disk.hits, 1);
+
+        let summary = second.clear().expect("clear");
+        assert_eq!(summary.memory_entries, 1);
+        assert_eq!(summary.persistent_entries, 1);
+        assert!(second.get(&ai_key).is_none());
+    }
+
+    #[test]
+    fn persistent_manifest_listing_is_invalidated_by_directory_changes() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let workspace = temp.path().join("workspace");
+        std::fs::create_dir_all(workspace.join("services/api")).expect("workspace");
+        let key = CacheKey::manifest_list(&workspace);
+        let listing = CacheValue::ManifestListEntries(vec![workspace.join("ntk-api.yml")]);
+
+        let mut tier = PersistentCacheTier::new(
+            temp.path().join("cache"),
+            1024 * 1024,
+            CacheTtl::persistent(),
+        );
+        assert!(!tier.insert(&key, &listing), "listings need a snapshot");
+        let snapshot = DirectorySnapshot::capture(&workspace).expect("snapshot");
+        assert!(tier.insert_manifest_listing(&key, &listing, &snapshot));
+        assert!(tier.get(&key).is_some());
+
+        sleep(Duration::from_millis(20));
+        std::fs::write(
+            workspace.join("services/api/ntk-orders.yml"),
+            "kind: solution",
+        )
+        .expect("new manifest");
+
+        assert!(tier.get(&key).is_none());
+        let stats = tier.stats();
+        assert_eq!(stats.invalidations, 1);
+        assert_eq!(stats.entries, 0, "Stale entry should be removed");
+    }
+
+    #[test]
+    fn persistent_manifest_listing_is_stale_when_the_tree_changed_during_discovery() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let workspace = temp.path().join("workspace");
+        std::fs::create_dir_all(workspace.join("services")).expect("workspace");
+        let key = CacheKey::manifest_list(&workspace);
+        let mut tier = PersistentCacheTier::new(
+            temp.path().join("cache"),
+            1024 * 1024,
+            CacheTtl::persistent(),
+        );
+
+        let snapshot = DirectorySnapshot::capture(&workspace).expect("snapshot");
+        sleep(Duration::from_millis(20));
+        // A manifest lands after the snapshot but before the listing is stored.
+        std::fs::write(workspace.join("services/ntk-late.yml"), "kind: solution")
+            .expect("late manifest");
+        let listing = CacheValue::ManifestListEntries(Vec::new());
+        assert!(tier.insert_manifest_listing(&key, &listing, &snapshot));
+
+        assert!(tier.get(&key).is_none());
+        assert_eq!(tier.stats().invalidations, 1);
+    }
+
+    #[test]
+    fn persistent_tier_evicts_least_recently_used_entries() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let value =
+            |index: usize| CacheValue::AiResponseText(format!("{index}-{}", "x".repeat(120)));
+        let entry_bytes = {
+            let mut probe = PersistentCacheTier::new(
+                temp.path().join("probe"),
+                1024 * 1024,
+                CacheTtl::persistent(),
+            );
+            assert!(probe.insert(&CacheKey::ai_response("ask-0"), &value(0)));
+            probe.stats().size_bytes
+        };
+        let mut tier = PersistentCacheTier::new(
+            temp.path().join("lru"),
+            entry_bytes * 3,
+            CacheTtl::persistent(),
+        );
+        for index in 0..3 {
+            sleep(Duration::from_millis(5));
+            assert!(tier.insert(
+                &CacheKey::ai_response(&format!("ask-{index}")),
+                &value(index)
+            ));
+        }
+
+        sleep(Duration::from_millis(5));
+        assert!(tier.get(&CacheKey::ai_response("ask-0")).is_some());
+        sleep(Duration::from_millis(5));
+        assert!(tier.insert(&CacheKey::ai_response("ask-3"), &value(3)));
+
+        assert_eq!(tier.stats().evictions, 1);
+        assert!(
+            tier.get(&CacheKey::ai_response("ask-0")).is_some(),
+            "recently read"
+        );
+        assert!(
+            tier.get(&CacheKey::ai_response("ask-1")).is_none(),
+            "least recently used"
+        );
+        assert!(tier.get(&CacheKey::ai_response("ask-2")).is_some());
+        assert!(tier.get(&CacheKey::ai_response("ask-3")).is_some());
+    }
+
+    #[test]
+    fn persistent_tier_applies_ttl_and_size_budget() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let mut expiring = PersistentCacheTier::new(
+            temp.path().join("ttl"),
+            1024 * 1024,
+            CacheTtl {
+                ai_response: Duration::from_millis(5),
+                ..CacheTtl::persistent()
+            },
+        );
+        let key = CacheKey::ai_response("ask-1");
+        assert!(expiring.insert(&key, &CacheValue::AiResponseText("short".to_string())));
+        sleep(Duration::from_millis(20));
+        assert!(expiring.get(&key).is_none());
+        assert_eq!(expiring.stats().evictions, 1);
+
+        let mut bounded =
+            PersistentCacheTier::new(temp.path().join("budget"), 400, CacheTtl::persistent());
+        for index in 0..4 {
+            sleep(Duration::from_millis(5));
+            let value = CacheValue::AiResponseText(format!("{index}-{}", "x".repeat(120)));
+            assert!(bounded.insert(&CacheKey::ai_response(&format!("ask-{index}")), &value));
+        }
+        let stats = bounded.stats();
+        assert!(stats.size_bytes <= 400, "{stats:?}");
+        assert!(stats.evictions >= 2, "{stats:?}");
+        assert!(bounded.get(&CacheKey::ai_response("ask-3")).is_some());
+        assert!(!bounded.insert(
+            &CacheKey::ai_response("ask-big"),
+            &CacheValue::AiResponseText("y".repeat(1024))
+        ));
+    }
+
     #[test]
     fn oversized_entry_is_rejected() {
         let mut cache = CommandResultCache::new(4, 64, CacheTtl::default());
-        let inserted = cache.insert(CacheKey::help(), CacheValue::HelpMarkdown("x".repeat(256)));
+        let inserted = cache.insert(
+            CacheKey::help_for(["/help"]),
+            CacheValue::HelpMarkdown("x".repeat(256)),
+        );
         assert!(
             !inserted,
             "Entry bigger than memory budget must be rejected"
@@ -4,12 +4,16 @@
 //! command ingress, notification dispatch, local audit persistence, and
 //! deterministic command execution through the existing `/task` pipeline.
 
-use super::processor::{process_command, process_control_envelope};
-use crate::models::ExitStatus;
+use super::output::{capture_output, ChatOpsTextSink, CommandOutput, OutputSink, OutputTone};
+use super::processor::{
+    process_command_with_output, process_control_envelope, process_task_approval_decision,
+    TaskApprovalVerdict,
+};
+use crate::models::{find_command_spec, ExitStatus};
 use nettoolskit_core::{
     AppConfig, ApprovalState, ControlEnvelope, ControlPolicyContext, IngressTransport,
-    OperatorContext, OperatorKind, RuntimeMode, SessionContext, SessionKind, TaskIntent,
-    TaskIntentKind,
+    OperatorContext, OperatorKind, RuntimeMode, SessionContext, SessionKind, TaskExecutionStatus,
+    TaskIntent, TaskIntentKind, TraceContext,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
@@ -19,7 +23,7 @@ use std::io::{BufRead, BufReader, Error, Write};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Supported ChatOps platforms.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -70,6 +74,9 @@ pub struct ChatOpsCommandEnvelope {
     pub message_text: String,
     /// UTC unix timestamp in milliseconds.
     pub received_at_unix_ms: u64,
+    /// Optional W3C trace context captured from the webhook request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_context: Option<TraceContext>,
 }
 
 impl ChatOpsCommandEnvelope {
@@ -91,6 +98,7 @@ impl ChatOpsCommandEnvelope {
             transport: default_chatops_transport(platform),
             message_text: message_text.into(),
             received_at_unix_ms,
+            trace_context: None,
         }
     }
 
@@ -125,6 +133,13 @@ impl ChatOpsCommandEnvelope {
         self
     }
 
+    /// Attach the W3C trace context captured at webhook ingress.
+    #[must_use]
+    pub fn with_trace_context(mut self, trace_context: Option<TraceContext>) -> Self {
+        self.trace_context = trace_context;
+        self
+    }
+
     fn resolved_request_id(&self) -> String {
         let request_id = self.request_id.trim();
         if request_id.is_empty() {
@@ -174,6 +189,22 @@ pub enum ChatOpsIntent {
         /// Stable task identifier.
         task_id: String,
     },
+    /// List tasks waiting for operator approval.
+    TaskApprovals,
+    /// Approve a parked task.
+    TaskApprove {
+        /// Stable task identifier.
+        task_id: String,
+        /// Optional note recorded with the approval.
+        note: Option<String>,
+    },
+    /// Reject a parked task.
+    TaskReject {
+        /// Stable task identifier.
+        task_id: String,
+        /// Optional reason recorded with the rejection.
+        reason: Option<String>,
+    },
     /// Show task command help.
     Help,
 }
@@ -189,6 +220,23 @@ impl ChatOpsIntent {
             Self::TaskList => Some("/task list".to_string()),
             Self::TaskWatch { task_id } => Some(format!("/task watch {task_id}")),
             Self::TaskCancel { task_id } => Some(format!("/task cancel {task_id}")),
+            Self::TaskApprovals => Some("/task approvals".to_string()),
+            Self::TaskApprove { task_id, note } => Some(
+                format!(
+                    "/task approve {task_id} {}",
+                    note.as_deref().unwrap_or_default()
+                )
+                .trim_end()
+                .to_string(),
+            ),
+            Self::TaskReject { task_id, reason } => Some(
+                format!(
+                    "/task reject {task_id} {}",
+                    reason.as_deref().unwrap_or_default()
+                )
+                .trim_end()
+                .to_string(),
+            ),
             Self::Help => None,
         }
     }
@@ -201,6 +249,9 @@ impl ChatOpsIntent {
             Self::TaskList => vec!["list".to_string()],
             Self::TaskWatch { .. } => vec!["watch".to_string()],
             Self::TaskCancel { .. } => vec!["cancel".to_string()],
+            Self::TaskApprovals => vec!["approvals".to_string()],
+            Self::TaskApprove { .. } => vec!["approve".to_string()],
+            Self::TaskReject { .. } => vec!["reject".to_string()],
             Self::TaskSubmit { intent, .. } => {
                 let normalized_intent = intent.trim().to_ascii_lowercase();
                 vec!["submit".to_string(), format!("submit:{normalized_intent}")]
@@ -238,6 +289,9 @@ impl std::error::Error for ChatOpsParseError {}
 /// - `list` or `task list`
 /// - `watch <task-id>` or `task watch <task-id>`
 /// - `cancel <task-id>` or `task cancel <task-id>`
+/// - `approvals` or `task approvals`
+/// - `approve <task-id> [note...]` or `task approve <task-id> [note...]`
+/// - `reject <task-id> [reason...]` or `task reject <task-id> [reason...]`
 /// - `submit <intent> <payload...>` or `task submit <intent> <payload...>`
 /// - Optional prefixes `/` and `/ntk`.
 pub fn parse_chatops_intent(message: &str) -> Result<ChatOpsIntent, ChatOpsParseError> {
@@ -296,6 +350,24 @@ pub fn parse_chatops_intent(message: &str) -> Result<ChatOpsIntent, ChatOpsParse
                 .to_string();
             Ok(ChatOpsIntent::TaskCancel { task_id })
         }
+        "approvals" => Ok(ChatOpsIntent::TaskApprovals),
+        "approve" | "reject" => {
+            let task_id = tokens
+                .get(offset)
+                .ok_or_else(|| {
+                    ChatOpsParseError::InvalidFormat(format!("{command} requires <task-id>"))
+                })?
+                .to_string();
+            let note = Some(tokens[offset + 1..].join(" ")).filter(|note| !note.is_empty());
+            if command == "approve" {
+                Ok(ChatOpsIntent::TaskApprove { task_id, note })
+            } else {
+                Ok(ChatOpsIntent::TaskReject {
+                    task_id,
+                    reason: note,
+                })
+            }
+        }
         "submit" => {
             if tokens.len() < offset + 2 {
                 return Err(ChatOpsParseError::InvalidFormat(
@@ -324,6 +396,7 @@ pub struct ChatOpsAuthorizationPolicy {
     ///
     /// Examples:
     /// - `list`, `watch`, `cancel`, `help`
+    /// - `approvals`, `approve`, `reject` (approval queue)
     /// - `submit` (all submit intents)
     /// - `submit:ai-plan` (specific submit intent)
     /// - `*` (all command scopes)
@@ -490,6 +563,121 @@ pub struct ChatOpsNotification {
     pub message_text: String,
     /// Severity marker.
     pub severity: ChatOpsNotificationSeverity,
+    /// Interactive buttons rendered under the message (Telegram inline keyboard,
+    /// Discord message components).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<ChatOpsAction>,
+}
+
+/// Prefix of button callback payloads emitted by this runtime.
+const CHATOPS_ACTION_CALLBACK_PREFIX: &str = "ntk";
+
+/// Interactive button kinds attached to ChatOps notifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatOpsActionKind {
+    /// Approve a parked task.
+    Approve,
+    /// Reject a parked task.
+    Reject,
+    /// Show task details.
+    Details,
+}
+
+impl ChatOpsActionKind {
+    /// Canonical lowercase label used in callback payloads.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Approve => "approve",
+            Self::Reject => "reject",
+            Self::Details => "details",
+        }
+    }
+}
+
+/// Button attached to a ChatOps notification.
+///
+/// Clicks come back as Telegram `callback_query` updates or Discord component
+/// interactions carrying [`ChatOpsAction::callback_data`], and are executed as
+/// the equivalent chat command so they pass through the same authorization policy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatOpsAction {
+    /// Button kind.
+    pub kind: ChatOpsActionKind,
+    /// Task the button acts on.
+    pub task_id: String,
+}
+
+impl ChatOpsAction {
+    /// Approve/Reject/Details buttons for a task waiting for approval.
+    #[must_use]
+    pub fn approval_prompt(task_id: &str) -> Vec<Self> {
+        [
+            ChatOpsActionKind::Approve,
+            ChatOpsActionKind::Reject,
+            ChatOpsActionKind::Details,
+        ]
+        .into_iter()
+        .map(|kind| Self {
+            kind,
+            task_id: task_id.trim().to_string(),
+        })
+        .collect()
+    }
+
+    /// Button label shown to operators.
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match self.kind {
+            ChatOpsActionKind::Approve => "✅ Approve",
+            ChatOpsActionKind::Reject => "🚫 Reject",
+            ChatOpsActionKind::Details => "🔎 Details",
+        }
+    }
+
+    /// Opaque callback payload (`ntk:<kind>:<task-id>`), well under Telegram's 64-byte limit.
+    #[must_use]
+    pub fn callback_data(&self) -> String {
+        format!(
+            "{CHATOPS_ACTION_CALLBACK_PREFIX}:{}:{}",
+            self.kind.as_str(),
+            self.task_id
+        )
+    }
+
+    /// Parse a callback payload produced by [`ChatOpsAction::callback_data`].
+    #[must_use]
+    pub fn from_callback_data(data: &str) -> Option<Self> {
+        let mut parts = data.trim().splitn(3, ':');
+        if parts.next()? != CHATOPS_ACTION_CALLBACK_PREFIX {
+            return None;
+        }
+        let kind = match parts.next()? {
+            "approve" => ChatOpsActionKind::Approve,
+            "reject" => ChatOpsActionKind::Reject,
+            "details" => ChatOpsActionKind::Details,
+            _ => return None,
+        };
+        let task_id = parts.next()?.trim();
+        if task_id.is_empty() || task_id.contains(char::is_whitespace) {
+            return None;
+        }
+        Some(Self {
+            kind,
+            task_id: task_id.to_string(),
+        })
+    }
+
+    /// Chat command equivalent to clicking the button.
+    #[must_use]
+    pub fn to_message_text(&self) -> String {
+        match self.kind {
+            ChatOpsActionKind::Approve => format!("approve {}", self.task_id),
+            ChatOpsActionKind::Reject => format!("reject {}", self.task_id),
+            ChatOpsActionKind::Details => format!("watch {}", self.task_id),
+        }
+    }
 }
 
 /// ChatOps ingress adapter contract.
@@ -762,6 +950,201 @@ impl ChatOpsLocalAuditStore {
     }
 }
 
+/// Maximum number of undelivered task completions kept for the ChatOps runtime.
+const MAX_PENDING_TASK_COMPLETIONS: usize = 256;
+
+static PENDING_TASK_COMPLETIONS: Mutex<VecDeque<ChatOpsTaskCompletion>> =
+    Mutex::new(VecDeque::new());
+
+/// Chat channel a task was submitted from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatOpsTaskOrigin {
+    /// Originating platform.
+    pub platform: ChatOpsPlatform,
+    /// Originating channel.
+    pub channel_id: String,
+}
+
+impl ChatOpsTaskOrigin {
+    /// Resolve the originating chat channel of a control envelope.
+    ///
+    /// Returns `None` for envelopes that did not arrive through a ChatOps transport.
+    #[must_use]
+    pub fn from_control_envelope(control: &ControlEnvelope) -> Option<Self> {
+        let platform = match control.operator.transport {
+            IngressTransport::TelegramWebhook | IngressTransport::TelegramPolling => {
+                ChatOpsPlatform::Telegram
+            }
+            IngressTransport::DiscordInteractions | IngressTransport::DiscordPolling => {
+                ChatOpsPlatform::Discord
+            }
+            IngressTransport::Cli | IngressTransport::ServiceHttp => return None,
+        };
+        let channel_id = control.operator.channel_id.clone()?;
+        Some(Self {
+            platform,
+            channel_id,
+        })
+    }
+}
+
+/// Terminal task event waiting to be announced in its originating chat channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ChatOpsTaskCompletion {
+    pub(crate) origin: ChatOpsTaskOrigin,
+    pub(crate) task_id: String,
+    pub(crate) status: TaskExecutionStatus,
+    pub(crate) detail: String,
+    pub(crate) attempts: usize,
+    pub(crate) max_attempts: usize,
+    pub(crate) duration: Duration,
+    pub(crate) output: Option<CommandOutput>,
+    pub(crate) control_envelope: Option<ControlEnvelope>,
+}
+
+impl ChatOpsTaskCompletion {
+    /// True when the task failed on its last allowed retry.
+    fn retries_exhausted(&self) -> bool {
+        self.status == TaskExecutionStatus::Failed
+            && self.max_attempts > 1
+            && self.attempts >= self.max_attempts
+    }
+
+    fn severity(&self) -> ChatOpsNotificationSeverity {
+        match self.status {
+            TaskExecutionStatus::Succeeded => ChatOpsNotificationSeverity::Success,
+            TaskExecutionStatus::Cancelled => ChatOpsNotificationSeverity::Warning,
+            TaskExecutionStatus::Failed
+            | TaskExecutionStatus::Queued
+            | TaskExecutionStatus::AwaitingApproval
+            | TaskExecutionStatus::Running => ChatOpsNotificationSeverity::Error,
+        }
+    }
+
+    fn headline(&self) -> String {
+        let duration = format_task_duration(self.duration);
+        match self.status {
+            TaskExecutionStatus::Succeeded => format!(
+                "✅ Task {} succeeded in {duration} (attempt {}/{})",
+                self.task_id, self.attempts, self.max_attempts
+            ),
+            TaskExecutionStatus::Cancelled => {
+                format!("⚠️ Task {} cancelled after {duration}", self.task_id)
+            }
+            _ if self.retries_exhausted() => format!(
+                "❌ Task {} failed after {duration}: retries exhausted ({}/{} attempts)",
+                self.task_id, self.attempts, self.max_attempts
+            ),
+            _ => format!(
+                "❌ Task {} failed after {duration} (attempt {}/{})",
+                self.task_id, self.attempts, self.max_attempts
+            ),
+        }
+    }
+
+    /// Build the notification posted back to the originating channel.
+    pub(crate) fn to_notification(&self) -> ChatOpsNotification {
+        let mut document = CommandOutput::new().text(self.headline(), OutputTone::Plain);
+        if !self.detail.trim().is_empty() {
+            document = document.text(self.detail.trim(), OutputTone::Plain);
+        }
+        if let Some(output) = &self.output {
+            document.extend(output.clone());
+        }
+
+        ChatOpsNotification {
+            platform: self.origin.platform,
+            channel_id: self.origin.channel_id.clone(),
+            message_text: ChatOpsTextSink::new(self.origin.platform).render(&document),
+            severity: self.severity(),
+            actions: Vec::new(),
+        }
+    }
+}
+
+/// Queue a task completion for delivery by the ChatOps runtime.
+///
+/// The queue is bounded; the oldest pending completion is dropped when full.
+pub(crate) fn queue_task_completion(completion: ChatOpsTaskCompletion) {
+    let mut pending = PENDING_TASK_COMPLETIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if pending.len() >= MAX_PENDING_TASK_COMPLETIONS {
+        if let Some(dropped) = pending.pop_front() {
+            tracing::warn!(
+                task_id = %dropped.task_id,
+                "chatops completion queue full; dropping oldest notification"
+            );
+        }
+    }
+    pending.push_back(completion);
+}
+
+/// Take the pending task completions accepted by `deliverable`; others stay queued.
+pub(crate) fn drain_task_completions(
+    deliverable: impl Fn(&ChatOpsTaskCompletion) -> bool,
+) -> Vec<ChatOpsTaskCompletion> {
+    let mut pending = PENDING_TASK_COMPLETIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let (taken, kept) = pending
+        .drain(..)
+        .partition(|completion| deliverable(completion));
+    *pending = kept;
+    taken.into()
+}
+
+/// Record a delivered task completion notification in the audit trail.
+pub(crate) fn append_task_completion_audit(
+    store: Option<&ChatOpsLocalAuditStore>,
+    completion: &ChatOpsTaskCompletion,
+    message_text: &str,
+    note: &str,
+) {
+    let Some(store) = store else {
+        return;
+    };
+    let control = completion.control_envelope.as_ref();
+    let operator_id = control.map(|control| control.operator.id.clone());
+    let platform_prefix = format!("{}:", completion.origin.platform.as_str());
+    let user_id = operator_id
+        .as_deref()
+        .map(|id| id.strip_prefix(&platform_prefix).unwrap_or(id).to_string())
+        .unwrap_or_default();
+    let exit_status = match completion.status {
+        TaskExecutionStatus::Succeeded => ExitStatus::Success,
+        TaskExecutionStatus::Cancelled => ExitStatus::Interrupted,
+        _ => ExitStatus::Error,
+    };
+    let entry = ChatOpsAuditEntry {
+        kind: ChatOpsAuditKind::NotificationSent,
+        platform: completion.origin.platform,
+        channel_id: completion.origin.channel_id.clone(),
+        user_id,
+        message_text: message_text.to_string(),
+        internal_command: None,
+        request_id: control.map(|control| control.request_id.clone()),
+        correlation_id: control.and_then(|control| control.correlation_id.clone()),
+        operator_id,
+        session_id: control.map(|control| control.session.id.clone()),
+        transport: control.map(|control| control.operator.transport),
+        task_id: Some(completion.task_id.clone()),
+        exit_status: Some(exit_status.to_string()),
+        note: note.to_string(),
+        timestamp_unix_ms: current_unix_timestamp_ms(),
+    };
+    let _ = store.append(&entry);
+}
+
+fn format_task_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds >= 60 {
+        format!("{}m {}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{:.1}s", duration.as_secs_f64())
+    }
+}
+
 /// Execution errors for ChatOps envelope processing.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ChatOpsExecutionError {
@@ -828,6 +1211,7 @@ pub async fn execute_chatops_envelope(
             channel_id: envelope.channel_id.clone(),
             message_text: format!("Access denied: {error}"),
             severity: ChatOpsNotificationSeverity::Error,
+            actions: Vec::new(),
         };
         notifier
             .send(&notification)
@@ -868,6 +1252,7 @@ pub async fn execute_chatops_envelope(
             channel_id: envelope.channel_id.clone(),
             message_text: format!("Access denied: {error}"),
             severity: ChatOpsNotificationSeverity::Error,
+            actions: Vec::new(),
         };
         notifier
             .send(&notification)
@@ -889,12 +1274,13 @@ pub async fn execute_chatops_envelope(
     let control_envelope = build_chatops_control_envelope(envelope, &intent, audit_store)?;
 
     if matches!(intent, ChatOpsIntent::Help) {
-        let help_message = "ChatOps commands: help | list | watch <task-id> | cancel <task-id> | submit <intent> <payload>";
+        let help_message = "ChatOps commands: help | list | watch <task-id> | cancel <task-id> | submit <intent> <payload> | approvals | approve <task-id> [note] | reject <task-id> [reason]";
         let notification = ChatOpsNotification {
             platform: envelope.platform,
             channel_id: envelope.channel_id.clone(),
             message_text: help_message.to_string(),
             severity: ChatOpsNotificationSeverity::Info,
+            actions: Vec::new(),
         };
         notifier
             .send(&notification)
@@ -917,21 +1303,60 @@ pub async fn execute_chatops_envelope(
     let internal_command = intent
         .to_internal_command()
         .expect("non-help intent should always map to command");
-    let (status, task_id, execution_note) = match intent {
+    let mut actions = Vec::new();
+    let (status, task_id, execution_note, output) = match intent {
         ChatOpsIntent::TaskSubmit { .. } => {
-            let submission = process_control_envelope(control_envelope.clone()).await;
+            let (submission, output) =
+                capture_output(process_control_envelope(control_envelope.clone())).await;
             let note = if submission.task_id.is_some() {
                 "command executed through typed ChatOps control plane"
             } else {
                 "typed ChatOps control plane rejected task admission"
             };
-            (submission.exit_status, submission.task_id, note)
+            if let (Some(task_id), Some(TaskExecutionStatus::AwaitingApproval)) =
+                (&submission.task_id, submission.task_status)
+            {
+                actions = ChatOpsAction::approval_prompt(task_id);
+            }
+            (submission.exit_status, submission.task_id, note, output)
+        }
+        ChatOpsIntent::TaskApprove {
+            ref task_id,
+            ref note,
+        }
+        | ChatOpsIntent::TaskReject {
+            ref task_id,
+            reason: ref note,
+        } => {
+            let verdict = if matches!(intent, ChatOpsIntent::TaskApprove { .. }) {
+                TaskApprovalVerdict::Approve
+            } else {
+                TaskApprovalVerdict::Reject
+            };
+            // Decide with the chat identity so the approval audit names the real approver.
+            let (status, output) = capture_output(process_task_approval_decision(
+                task_id,
+                verdict,
+                note.as_deref(),
+                &control_envelope.operator,
+            ))
+            .await;
+            (
+                status,
+                Some(task_id.clone()),
+                "approval decision recorded through typed ChatOps control plane",
+                output,
+            )
+        }
+        _ => {
+            let (status, output) = process_command_with_output(&internal_command).await;
+            (
+                status,
+                None,
+                "command executed through task pipeline with typed control metadata",
+                output,
+            )
         }
-        _ => (
-            process_command(&internal_command).await,
-            None,
-            "command executed through task pipeline with typed control metadata",
-        ),
     };
 
     append_audit(
@@ -955,8 +1380,15 @@ pub async fn execute_chatops_envelope(
     let notification = ChatOpsNotification {
         platform: envelope.platform,
         channel_id: envelope.channel_id.clone(),
-        message_text: build_chatops_status_message(&internal_command, status, task_id.as_deref()),
+        message_text: build_chatops_result_message(
+            envelope.platform,
+            &internal_command,
+            status,
+            task_id.as_deref(),
+            output,
+        ),
         severity,
+        actions,
     };
     notifier
         .send(&notification)
@@ -1048,6 +1480,22 @@ fn build_chatops_status_message(
     }
 }
 
+/// Status line followed by the command's structured output, formatted for `platform`.
+fn build_chatops_result_message(
+    platform: ChatOpsPlatform,
+    internal_command: &str,
+    status: ExitStatus,
+    task_id: Option<&str>,
+    output: CommandOutput,
+) -> String {
+    let mut document = CommandOutput::new().text(
+        build_chatops_status_message(internal_command, status, task_id),
+        OutputTone::Plain,
+    );
+    document.extend(output);
+    ChatOpsTextSink::new(platform).render(&document)
+}
+
 fn chatops_help_internal_command() -> &'static str {
     "help"
 }
@@ -1088,6 +1536,21 @@ fn build_chatops_control_envelope(
             "task cancel command".to_string(),
             format!("/task cancel {}", task_id.trim()),
         ),
+        ChatOpsIntent::TaskApprovals => (
+            TaskIntentKind::CommandExecution,
+            "task approvals command".to_string(),
+            "/task approvals".to_string(),
+        ),
+        ChatOpsIntent::TaskApprove { task_id, .. } => (
+            TaskIntentKind::CommandExecution,
+            "task approve command".to_string(),
+            format!("/task approve {}", task_id.trim()),
+        ),
+        ChatOpsIntent::TaskReject { task_id, .. } => (
+            TaskIntentKind::CommandExecution,
+            "task reject command".to_string(),
+            format!("/task reject {}", task_id.trim()),
+        ),
         ChatOpsIntent::Help => (
             TaskIntentKind::CommandExecution,
             "help command".to_string(),
@@ -1117,20 +1580,26 @@ fn build_chatops_control_envelope(
         .unwrap_or_else(|| request_id.clone());
     let mut control =
         ControlEnvelope::new(request_id, RuntimeMode::Service, operator, session, task)
-            .with_policy(build_chatops_control_policy(audit_store));
+            .with_policy(build_chatops_control_policy(audit_store))
+            .with_trace_context(envelope.trace_context.clone());
     control = control.with_correlation_id(correlation_id);
 
     Ok(control)
 }
 
+/// Operator scope the command registry declares for the `/task` subcommand behind `intent`.
 fn chatops_base_scope(intent: &ChatOpsIntent) -> &'static str {
-    match intent {
-        ChatOpsIntent::TaskSubmit { .. } => "task.submit",
-        ChatOpsIntent::TaskList
-        | ChatOpsIntent::TaskWatch { .. }
-        | ChatOpsIntent::TaskCancel { .. } => "task.manage",
-        ChatOpsIntent::Help => "help",
-    }
+    let (command, subcommand) = match intent {
+        ChatOpsIntent::TaskSubmit { .. } => ("/task", Some("submit")),
+        ChatOpsIntent::TaskList => ("/task", Some("list")),
+        ChatOpsIntent::TaskWatch { .. } => ("/task", Some("watch")),
+        ChatOpsIntent::TaskCancel { .. } => ("/task", Some("cancel")),
+        ChatOpsIntent::TaskApprovals => ("/task", Some("approvals")),
+        ChatOpsIntent::TaskApprove { .. } => ("/task", Some("approve")),
+        ChatOpsIntent::TaskReject { .. } => ("/task", Some("reject")),
+        ChatOpsIntent::Help => ("/help", None),
+    };
+    find_command_spec(command).map_or("help", |spec| spec.scope_for(subcommand))
 }
 
 fn build_chatops_control_policy(
@@ -1170,6 +1639,7 @@ fn current_unix_timestamp_ms() -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::execution::processor::ConsoleMode;
     use serial_test::serial;
 
     struct EnvVarGuard {
@@ -1216,6 +1686,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_chatops_intent_supports_approval_queue_commands() {
+        assert_eq!(
+            parse_chatops_intent("approvals"),
+            Ok(ChatOpsIntent::TaskApprovals)
+        );
+        assert_eq!(
+            parse_chatops_intent("/task approve task-7 looks good"),
+            Ok(ChatOpsIntent::TaskApprove {
+                task_id: "task-7".to_string(),
+                note: Some("looks good".to_string())
+            })
+        );
+        assert_eq!(
+            parse_chatops_intent("reject task-7"),
+            Ok(ChatOpsIntent::TaskReject {
+                task_id: "task-7".to_string(),
+                reason: None
+            })
+        );
+        assert!(matches!(
+            parse_chatops_intent("approve"),
+            Err(ChatOpsParseError::InvalidFormat(_))
+        ));
+    }
+
     #[test]
     fn parse_chatops_intent_rejects_invalid_submit_format() {
         let parsed = parse_chatops_intent("submit ai-plan");
@@ -1256,6 +1752,28 @@ mod tests {
         assert!(policy.authorize_intent(&allowed).is_ok());
     }
 
+    #[test]
+    fn control_envelope_carries_webhook_trace_context() {
+        let trace = TraceContext::parse(
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            Some("vendor=abc"),
+        );
+        let envelope = ChatOpsCommandEnvelope::new(
+            ChatOpsPlatform::Telegram,
+            "c1",
+            "u1",
+            "submit ai-plan trace me",
+            1,
+        )
+        .with_trace_context(trace.clone());
+        let intent = parse_chatops_intent(&envelope.message_text).expect("intent should parse");
+
+        let control = build_chatops_control_envelope(&envelope, &intent, None)
+            .expect("control envelope should build");
+
+        assert_eq!(control.trace_context, trace);
+    }
+
     #[test]
     fn local_audit_store_roundtrip_keeps_latest_entries() {
         let dir = tempfile::tempdir().expect("temp dir");
@@ -1314,6 +1832,11 @@ mod tests {
             notifications[0].severity,
             ChatOpsNotificationSeverity::Success
         );
+        let text = &notifications[0].message_text;
+        assert!(
+            text.contains("No task records found.") || text.contains("Local Task Records"),
+            "notification should carry the command output: {text}"
+        );
     }
 
     #[tokio::test]
@@ -1447,6 +1970,73 @@ mod tests {
         assert!(control.operator.scopes.iter().any(|scope| scope == "watch"));
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn execute_chatops_envelope_approve_records_chat_operator_as_approver() {
+        let parked = crate::execution::headless::submit_task(
+            "repo-workflow",
+            "repo=https://example.invalid/acme/app.git;branch=main;command=cargo test;push=true;dry_run=false",
+            ConsoleMode::Silent,
+        )
+        .await
+        .expect("gated task should be admitted");
+        assert_eq!(parked.status, TaskExecutionStatus::AwaitingApproval);
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let audit_store = ChatOpsLocalAuditStore::from_path(dir.path().join("chatops-audit.jsonl"));
+        let policy = ChatOpsAuthorizationPolicy::new_with_scopes(
+            vec!["user-1".to_string()],
+            vec!["channel-1".to_string()],
+            vec!["approve".to_string()],
+        );
+        let notifier = RecordingChatOpsNotifier::new();
+        let envelope = ChatOpsCommandEnvelope::new(
+            ChatOpsPlatform::Discord,
+            "channel-1",
+            "user-1",
+            format!("reject {} not today", parked.id),
+            7,
+        );
+        let denied = execute_chatops_envelope(&envelope, &policy, &notifier, None).await;
+        assert!(matches!(
+            denied,
+            Err(ChatOpsExecutionError::Unauthorized(_))
+        ));
+
+        let envelope = ChatOpsCommandEnvelope::new(
+            ChatOpsPlatform::Discord,
+            "channel-1",
+            "user-1",
+            format!("approve {} lgtm", parked.id),
+            8,
+        );
+        let status = execute_chatops_envelope(&envelope, &policy, &notifier, Some(&audit_store))
+            .await
+            .expect("authorized approval should execute");
+        assert_eq!(status, ExitStatus::Success);
+
+        let task = crate::execution::headless::get_task(&parked.id, ConsoleMode::Silent)
+            .await
+            .expect("task should exist");
+        assert!(task
+            .audit_events
+            .iter()
+            .any(|event| event.message == "Approved by discord:user-1: lgtm"));
+        let executed = audit_store
+            .load_latest(8)
+            .expect("audit entries should be readable")
+            .into_iter()
+            .find(|entry| entry.kind == ChatOpsAuditKind::CommandExecuted)
+            .expect("executed entry should exist");
+        assert_eq!(executed.task_id.as_deref(), Some(parked.id.as_str()));
+        let notifications = notifier.snapshot();
+        assert!(notifications
+            .last()
+            .expect("result notification")
+            .message_text
+            .contains("Task approved"));
+    }
+
     #[tokio::test]
     #[serial]
     async fn execute_chatops_envelope_submit_records_control_plane_metadata() {
@@ -1615,4 +2205,72 @@ mod tests {
         let remaining = ingress.pull_pending(10);
         assert_eq!(remaining.len(), 1);
     }
+
+    fn sample_completion(status: TaskExecutionStatus, attempts: usize) -> ChatOpsTaskCompletion {
+        ChatOpsTaskCompletion {
+            origin: ChatOpsTaskOrigin {
+                platform: ChatOpsPlatform::Telegram,
+                channel_id: "channel-1".to_string(),
+            },
+            task_id: "task-42".to_string(),
+            status,
+            detail: "AI plan completed".to_string(),
+            attempts,
+            max_attempts: 3,
+            duration: Duration::from_millis(2_500),
+            output: Some(CommandOutput::new().markdown("x".repeat(5_000))),
+            control_envelope: None,
+        }
+    }
+
+    #[test]
+    fn task_origin_resolves_only_chatops_transports() {
+        let envelope = ChatOpsCommandEnvelope::new(
+            ChatOpsPlatform::Discord,
+            "channel-9",
+            "user-9",
+            "submit ai-plan ship it",
+            1,
+        );
+        let intent = parse_chatops_intent(&envelope.message_text).expect("intent should parse");
+        let control =
+            build_chatops_control_envelope(&envelope, &intent, None).expect("control envelope");
+
+        let origin = ChatOpsTaskOrigin::from_control_envelope(&control).expect("chat origin");
+        assert_eq!(origin.platform, ChatOpsPlatform::Discord);
+        assert_eq!(origin.channel_id, "channel-9");
+
+        let mut service_control = control;
+        service_control.operator.transport = IngressTransport::ServiceHttp;
+        assert!(ChatOpsTaskOrigin::from_control_envelope(&service_control).is_none());
+    }
+
+    #[test]
+    fn task_completion_notification_reports_duration_and_truncates_output() {
+        let notification = sample_completion(TaskExecutionStatus::Succeeded, 1).to_notification();
+
+        assert_eq!(notification.platform, ChatOpsPlatform::Telegram);
+        assert_eq!(notification.channel_id, "channel-1");
+        assert_eq!(notification.severity, ChatOpsNotificationSeverity::Success);
+        assert!(notification
+            .message_text
+            .starts_with("✅ Task task-42 succeeded in 2.5s (attempt 1/3)"));
+        assert!(notification.message_text.ends_with("(output truncated)"));
+        assert_eq!(notification.message_text.chars().count(), 4096);
+    }
+
+    #[test]
+    fn task_completion_notification_flags_exhausted_retries_and_cancellation() {
+        let exhausted = sample_completion(TaskExecutionStatus::Failed, 3).to_notification();
+        assert_eq!(exhausted.severity, ChatOpsNotificationSeverity::Error);
+        assert!(exhausted
+            .message_text
+            .contains("retries exhausted (3/3 attempts)"));
+
+        let cancelled = sample_completion(TaskExecutionStatus::Cancelled, 1).to_notification();
+        assert_eq!(cancelled.severity, ChatOpsNotificationSeverity::Warning);
+        assert!(cancelled
+            .message_text
+            .starts_with("⚠️ Task task-42 cancelled after 2.5s"));
+    }
 }
@@ -5,12 +5,14 @@
 //! explicit authorization policy controls.
 
 use super::chatops::{
-    execute_chatops_envelope, ChatOpsAdapterError, ChatOpsAuditEntry, ChatOpsAuditKind,
-    ChatOpsAuthorizationPolicy, ChatOpsCommandEnvelope, ChatOpsLocalAuditStore,
-    ChatOpsNotification, ChatOpsNotificationSeverity, ChatOpsNotifier, ChatOpsPlatform,
+    append_task_completion_audit, drain_task_completions, execute_chatops_envelope,
+    parse_chatops_intent, ChatOpsAction, ChatOpsActionKind, ChatOpsAdapterError, ChatOpsAuditEntry,
+    ChatOpsAuditKind, ChatOpsAuthorizationPolicy, ChatOpsCommandEnvelope, ChatOpsIntent,
+    ChatOpsLocalAuditStore, ChatOpsNotification, ChatOpsNotificationSeverity, ChatOpsNotifier,
+    ChatOpsPlatform,
 };
 use crate::models::ExitStatus;
-use nettoolskit_core::IngressTransport;
+use nettoolskit_core::{IngressTransport, PolicySource, TraceContext};
 use serde::Deserialize;
 use std::collections::{HashMap, VecDeque};
 use std::future::Future;
@@ -23,6 +25,8 @@ const DEFAULT_CHATOPS_MAX_BATCH_SIZE: usize = 16;
 const DEFAULT_CHATOPS_RATE_LIMIT_WINDOW_SECONDS: u64 = 60;
 const DEFAULT_TELEGRAM_API_BASE: &str = "https://api.telegram.org";
 const DEFAULT_DISCORD_API_BASE: &str = "https://discord.com/api/v10";
+const TASK_COMPLETION_NOTIFY_MAX_ATTEMPTS: usize = 3;
+const TASK_COMPLETION_NOTIFY_RETRY_DELAY_MS: u64 = 250;
 
 /// Environment variable to enable ChatOps runtime.
 pub const NTK_CHATOPS_ENABLED_ENV: &str = "NTK_CHATOPS_ENABLED";
@@ -202,111 +206,109 @@ impl Default for ChatOpsRuntimeConfig {
 }
 
 impl ChatOpsRuntimeConfig {
-    /// Resolve runtime configuration from environment variables.
+    /// Resolve runtime configuration from environment variables, falling back
+    /// to the `[chatops]` section of the config file.
     #[must_use]
     pub fn from_env() -> Self {
+        let source = PolicySource::load();
         let mut config = Self::default();
 
-        if let Ok(value) = std::env::var(NTK_CHATOPS_ENABLED_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_ENABLED_ENV) {
             if let Some(parsed) = parse_bool(&value) {
                 config.enabled = parsed;
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_POLL_INTERVAL_MS_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_POLL_INTERVAL_MS_ENV) {
             if let Ok(parsed) = value.trim().parse::<u64>() {
                 config.poll_interval = Duration::from_millis(parsed.max(100));
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_MAX_BATCH_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_MAX_BATCH_ENV) {
             if let Ok(parsed) = value.trim().parse::<usize>() {
                 config.max_batch_size = parsed.max(1);
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_ALLOWED_USERS_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_ALLOWED_USERS_ENV) {
             config.allowed_user_ids = parse_list(&value);
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_ALLOWED_CHANNELS_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_ALLOWED_CHANNELS_ENV) {
             config.allowed_channel_ids = parse_list(&value);
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_ALLOWED_COMMANDS_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_ALLOWED_COMMANDS_ENV) {
             config.allowed_command_scopes = parse_list(&value)
                 .into_iter()
                 .map(|scope| scope.to_ascii_lowercase())
                 .collect();
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_RATE_LIMIT_PER_USER_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_RATE_LIMIT_PER_USER_ENV) {
             if let Ok(parsed) = value.trim().parse::<usize>() {
                 config.rate_limit_per_user = Some(parsed.max(1));
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_RATE_LIMIT_PER_CHANNEL_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_RATE_LIMIT_PER_CHANNEL_ENV) {
             if let Ok(parsed) = value.trim().parse::<usize>() {
                 config.rate_limit_per_channel = Some(parsed.max(1));
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_RATE_LIMIT_STRATEGY_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_RATE_LIMIT_STRATEGY_ENV) {
             if let Some(parsed) = parse_rate_limit_strategy(&value) {
                 config.rate_limit_strategy = parsed;
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_RATE_LIMIT_BURST_PER_USER_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_RATE_LIMIT_BURST_PER_USER_ENV) {
             if let Ok(parsed) = value.trim().parse::<usize>() {
                 config.rate_limit_burst_per_user = Some(parsed.max(1));
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_RATE_LIMIT_BURST_PER_CHANNEL_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_RATE_LIMIT_BURST_PER_CHANNEL_ENV) {
             if let Ok(parsed) = value.trim().parse::<usize>() {
                 config.rate_limit_burst_per_channel = Some(parsed.max(1));
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_RATE_LIMIT_AUTOTUNE_PROFILE_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_RATE_LIMIT_AUTOTUNE_PROFILE_ENV) {
             if let Some(parsed) = parse_rate_limit_auto_tune_profile(&value) {
                 config.rate_limit_auto_tune_profile = parsed;
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_RATE_LIMIT_WINDOW_SECONDS_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_RATE_LIMIT_WINDOW_SECONDS_ENV) {
             if let Ok(parsed) = value.trim().parse::<u64>() {
                 config.rate_limit_window = Duration::from_secs(parsed.max(1));
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_TELEGRAM_TOKEN_ENV) {
-            let token = value.trim();
-            if !token.is_empty() {
-                config.telegram_bot_token = Some(token.to_string());
-            }
+        match source.secret(NTK_CHATOPS_TELEGRAM_TOKEN_ENV) {
+            Ok(token) => config.telegram_bot_token = token,
+            Err(error) => tracing::warn!(error = %error, "Ignoring unresolved ChatOps token"),
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_TELEGRAM_API_BASE_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_TELEGRAM_API_BASE_ENV) {
             let base = value.trim();
             if !base.is_empty() {
                 config.telegram_api_base = base.to_string();
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_TELEGRAM_WEBHOOK_ENABLED_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_TELEGRAM_WEBHOOK_ENABLED_ENV) {
             if let Some(parsed) = parse_bool(&value) {
                 config.telegram_webhook_enabled = parsed;
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_DISCORD_TOKEN_ENV) {
-            let token = value.trim();
-            if !token.is_empty() {
-                config.discord_bot_token = Some(token.to_string());
-            }
+        match source.secret(NTK_CHATOPS_DISCORD_TOKEN_ENV) {
+            Ok(token) => config.discord_bot_token = token,
+            Err(error) => tracing::warn!(error = %error, "Ignoring unresolved ChatOps token"),
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_DISCORD_API_BASE_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_DISCORD_API_BASE_ENV) {
             let base = value.trim();
             if !base.is_empty() {
                 config.discord_api_base = base.to_string();
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_DISCORD_INTERACTIONS_ENABLED_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_DISCORD_INTERACTIONS_ENABLED_ENV) {
             if let Some(parsed) = parse_bool(&value) {
                 config.discord_interactions_enabled = parsed;
             }
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_DISCORD_CHANNELS_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_DISCORD_CHANNELS_ENV) {
             config.discord_channel_ids = parse_list(&value);
         }
-        if let Ok(value) = std::env::var(NTK_CHATOPS_AUDIT_PATH_ENV) {
+        if let Some(value) = source.var(NTK_CHATOPS_AUDIT_PATH_ENV) {
             let path = value.trim();
             if !path.is_empty() {
                 config.audit_path = Some(std::path::PathBuf::from(path));
@@ -332,6 +334,8 @@ pub struct ChatOpsTickSummary {
     pub ingress_errors: usize,
     /// Number of outbound notification dispatch failures.
     pub notification_errors: usize,
+    /// Number of task completion notifications delivered to originating channels.
+    pub task_notifications_sent: usize,
 }
 
 /// Result of a Discord interaction ingress payload handling attempt.
@@ -625,36 +629,42 @@ impl ChatOpsRuntime {
 
     /// Enqueue raw Telegram webhook payload for processing in the next runtime tick.
     ///
+    /// `trace_context` is the W3C context of the webhook request, if any.
+    ///
     /// # Errors
     ///
     /// Returns error when webhook mode is disabled or payload is invalid.
     pub fn enqueue_telegram_webhook_payload(
         &self,
         payload: &str,
+        trace_context: Option<TraceContext>,
     ) -> Result<usize, ChatOpsAdapterError> {
         let Some(ingress) = &self.telegram_webhook_ingress else {
             return Err(ChatOpsAdapterError::new(
                 "Telegram webhook mode is disabled in ChatOps runtime",
             ));
         };
-        ingress.enqueue_payload(payload)
+        ingress.enqueue_payload(payload, trace_context)
     }
 
     /// Enqueue raw Discord interaction payload for processing in the next runtime tick.
     ///
+    /// `trace_context` is the W3C context of the interaction request, if any.
+    ///
     /// # Errors
     ///
     /// Returns error when interaction mode is disabled or payload is invalid.
     pub fn enqueue_discord_interaction_payload(
         &self,
         payload: &str,
+        trace_context: Option<TraceContext>,
     ) -> Result<DiscordInteractionIngressOutcome, ChatOpsAdapterError> {
         let Some(ingress) = &self.discord_interaction_ingress else {
             return Err(ChatOpsAdapterError::new(
                 "Discord interaction mode is disabled in ChatOps runtime",
             ));
         };
-        ingress.enqueue_payload(payload)
+        ingress.enqueue_payload(payload, trace_context)
     }
 
     /// Execute one polling iteration across all configured adapters.
@@ -729,6 +739,7 @@ impl ChatOpsRuntime {
                             channel_id: envelope.channel_id.clone(),
                             message_text: rejection_message,
                             severity: ChatOpsNotificationSeverity::Warning,
+                            actions: Vec::new(),
                         };
                         if let Some(notifier) = self.notifier_for(envelope.platform) {
                             if let Err(error) = notifier.send(&notification).await {
@@ -797,9 +808,51 @@ impl ChatOpsRuntime {
             }
         }
 
+        self.deliver_task_completions(&mut summary).await;
         summary
     }
 
+    /// Post finished background tasks back to the chat channels they were submitted from.
+    async fn deliver_task_completions(&self, summary: &mut ChatOpsTickSummary) {
+        let completions = drain_task_completions(|completion| {
+            self.notifier_for(completion.origin.platform).is_some()
+                && self
+                    .policy
+                    .allowed_channel_ids
+                    .iter()
+                    .any(|id| id == completion.origin.channel_id.trim())
+        });
+
+        for completion in completions {
+            let Some(notifier) = self.notifier_for(completion.origin.platform) else {
+                continue;
+            };
+            let notification = completion.to_notification();
+            match send_with_retry(notifier.as_ref(), &notification).await {
+                Ok(attempt) => {
+                    summary.task_notifications_sent += 1;
+                    append_task_completion_audit(
+                        self.audit_store.as_ref(),
+                        &completion,
+                        &notification.message_text,
+                        &format!(
+                            "task completion notification sent (attempt {attempt}/{TASK_COMPLETION_NOTIFY_MAX_ATTEMPTS})"
+                        ),
+                    );
+                }
+                Err(error) => {
+                    summary.notification_errors += 1;
+                    tracing::warn!(
+                        platform = %completion.origin.platform,
+                        task_id = %completion.task_id,
+                        error = %error,
+                        "chatops task completion notification dispatch failed"
+                    );
+                }
+            }
+        }
+    }
+
     fn notifier_for(&self, platform: ChatOpsPlatform) -> Option<&Arc<dyn AsyncChatOpsNotifier>> {
         self.notifiers
             .iter()
@@ -886,7 +939,9 @@ pub fn build_chatops_runtime(
         notifiers.push((ChatOpsPlatform::Telegram, telegram_adapter.clone()));
 
         if config.telegram_webhook_enabled {
-            let webhook_ingress = Arc::new(TelegramWebhookIngressAdapter::default());
+            let webhook_ingress = Arc::new(TelegramWebhookIngressAdapter::with_acknowledger(
+                telegram_adapter.clone(),
+            ));
             ingresses.push(webhook_ingress.clone());
             telegram_webhook_ingress = Some(webhook_ingress);
         } else {
@@ -895,9 +950,11 @@ pub fn build_chatops_runtime(
     }
 
     if let Some(token) = config.discord_bot_token.clone() {
+        // Buttons only work when component clicks can reach the interactions endpoint.
         let notifier = Arc::new(DiscordChatOpsNotifierAdapter::new(
             token.clone(),
             config.discord_api_base.clone(),
+            config.discord_interactions_enabled,
         )?);
         notifiers.push((ChatOpsPlatform::Discord, notifier));
 
@@ -939,6 +996,30 @@ pub fn build_chatops_runtime(
     }))
 }
 
+/// Send one notification, retrying transient failures with linear backoff.
+///
+/// Returns the attempt number that succeeded.
+async fn send_with_retry(
+    notifier: &dyn AsyncChatOpsNotifier,
+    notification: &ChatOpsNotification,
+) -> Result<usize, ChatOpsAdapterError> {
+    let mut attempt = 1;
+    loop {
+        match notifier.send(notification).await {
+            Ok(()) => return Ok(attempt),
+            Err(error) if attempt >= TASK_COMPLETION_NOTIFY_MAX_ATTEMPTS => return Err(error),
+            Err(error) => {
+                tracing::debug!(attempt, error = %error, "retrying chatops notification");
+                tokio::time::sleep(Duration::from_millis(
+                    TASK_COMPLETION_NOTIFY_RETRY_DELAY_MS * attempt as u64,
+                ))
+                .await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
 type ChatOpsRuntimeFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 
 trait AsyncChatOpsIngress: Send + Sync {
@@ -984,20 +1065,44 @@ impl ChatOpsNotifier for QueuedNotificationNotifier {
 #[derive(Default)]
 struct TelegramWebhookIngressAdapter {
     queue: Mutex<VecDeque<ChatOpsCommandEnvelope>>,
+    pending_callback_acks: Mutex<Vec<String>>,
+    acknowledger: Option<Arc<TelegramChatOpsAdapter>>,
 }
 
 impl TelegramWebhookIngressAdapter {
-    fn enqueue_payload(&self, payload: &str) -> Result<usize, ChatOpsAdapterError> {
-        let parsed = parse_telegram_webhook_payload(payload)?;
-        if parsed.is_empty() {
+    fn with_acknowledger(acknowledger: Arc<TelegramChatOpsAdapter>) -> Self {
+        Self {
+            acknowledger: Some(acknowledger),
+            ..Self::default()
+        }
+    }
+
+    fn enqueue_payload(
+        &self,
+        payload: &str,
+        trace_context: Option<TraceContext>,
+    ) -> Result<usize, ChatOpsAdapterError> {
+        let batch = parse_telegram_webhook_payload(payload)?;
+        if !batch.callback_query_ids.is_empty() {
+            self.pending_callback_acks
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .extend(batch.callback_query_ids);
+        }
+        if batch.envelopes.is_empty() {
             return Ok(0);
         }
-        let parsed_len = parsed.len();
+        let parsed_len = batch.envelopes.len();
         let mut queue = self
             .queue
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
-        queue.extend(parsed);
+        queue.extend(
+            batch
+                .envelopes
+                .into_iter()
+                .map(|envelope| envelope.with_trace_context(trace_context.clone())),
+        );
         Ok(parsed_len)
     }
 }
@@ -1012,6 +1117,15 @@ impl AsyncChatOpsIngress for TelegramWebhookIngressAdapter {
         max_items: usize,
     ) -> ChatOpsRuntimeFuture<'_, Result<Vec<ChatOpsCommandEnvelope>, ChatOpsAdapterError>> {
         Box::pin(async move {
+            let callback_ids = std::mem::take(
+                &mut *self
+                    .pending_callback_acks
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner()),
+            );
+            if let Some(acknowledger) = &self.acknowledger {
+                acknowledger.answer_callback_queries(&callback_ids).await;
+            }
             if max_items == 0 {
                 return Ok(Vec::new());
             }
@@ -1041,6 +1155,7 @@ impl DiscordInteractionIngressAdapter {
     fn enqueue_payload(
         &self,
         payload: &str,
+        trace_context: Option<TraceContext>,
     ) -> Result<DiscordInteractionIngressOutcome, ChatOpsAdapterError> {
         match parse_discord_interaction_payload(payload)? {
             DiscordInteractionIngressPayload::Ping => Ok(DiscordInteractionIngressOutcome {
@@ -1052,7 +1167,7 @@ impl DiscordInteractionIngressAdapter {
                     .queue
                     .lock()
                     .unwrap_or_else(|poisoned| poisoned.into_inner());
-                queue.push_back(envelope);
+                queue.push_back(envelope.with_trace_context(trace_context));
                 Ok(DiscordInteractionIngressOutcome {
                     ping: false,
                     queued: 1,
@@ -1120,6 +1235,30 @@ impl TelegramChatOpsAdapter {
     fn endpoint(&self, method: &str) -> String {
         format!("{}/bot{}/{}", self.api_base, self.bot_token, method)
     }
+
+    /// Stop the loading spinner on clicked inline buttons. Best-effort: the click
+    /// itself is already queued, so failures are only logged.
+    async fn answer_callback_queries(&self, callback_query_ids: &[String]) {
+        for callback_query_id in callback_query_ids {
+            let result = self
+                .client
+                .post(self.endpoint("answerCallbackQuery"))
+                .json(&serde_json::json!({ "callback_query_id": callback_query_id }))
+                .send()
+                .await;
+            match result {
+                Ok(response) if response.status().is_success() => {}
+                Ok(response) => tracing::warn!(
+                    status = %response.status(),
+                    "telegram answerCallbackQuery failed"
+                ),
+                Err(error) => tracing::warn!(
+                    error = %error,
+                    "telegram answerCallbackQuery failed"
+                ),
+            }
+        }
+    }
 }
 
 impl AsyncChatOpsIngress for TelegramChatOpsAdapter {
@@ -1172,15 +1311,13 @@ impl AsyncChatOpsIngress for TelegramChatOpsAdapter {
                 ));
             }
 
-            let mut envelopes = Vec::new();
+            let mut batch = TelegramIngressBatch::default();
             let mut latest_update_id = offset;
             for update in payload.result {
                 if update.update_id > latest_update_id {
                     latest_update_id = update.update_id;
                 }
-                if let Some(envelope) = update.into_chatops_envelope() {
-                    envelopes.push(envelope);
-                }
+                batch.push(update);
             }
 
             if latest_update_id >= offset {
@@ -1191,7 +1328,9 @@ impl AsyncChatOpsIngress for TelegramChatOpsAdapter {
                 *guard = latest_update_id.saturating_add(1);
             }
 
-            Ok(envelopes)
+            self.answer_callback_queries(&batch.callback_query_ids)
+                .await;
+            Ok(batch.envelopes)
         })
     }
 }
@@ -1201,17 +1340,13 @@ impl AsyncChatOpsNotifier for TelegramChatOpsAdapter {
         &self,
         notification: &ChatOpsNotification,
     ) -> ChatOpsRuntimeFuture<'_, Result<(), ChatOpsAdapterError>> {
-        let channel_id = notification.channel_id.clone();
-        let text = notification.message_text.clone();
+        let body = telegram_send_message_body(notification);
 
         Box::pin(async move {
             let response = self
                 .client
                 .post(self.endpoint("sendMessage"))
-                .json(&serde_json::json!({
-                    "chat_id": channel_id,
-                    "text": text,
-                }))
+                .json(&body)
                 .send()
                 .await
                 .map_err(|error| ChatOpsAdapterError::new(error.to_string()))?;
@@ -1233,10 +1368,15 @@ struct DiscordChatOpsNotifierAdapter {
     client: reqwest::Client,
     bot_token: String,
     api_base: String,
+    components_enabled: bool,
 }
 
 impl DiscordChatOpsNotifierAdapter {
-    fn new(bot_token: String, api_base: String) -> Result<Self, ChatOpsAdapterError> {
+    fn new(
+        bot_token: String,
+        api_base: String,
+        components_enabled: bool,
+    ) -> Result<Self, ChatOpsAdapterError> {
         if bot_token.trim().is_empty() {
             return Err(ChatOpsAdapterError::new("Discord token must not be empty"));
         }
@@ -1250,6 +1390,7 @@ impl DiscordChatOpsNotifierAdapter {
             client: reqwest::Client::new(),
             bot_token,
             api_base: api_base.trim_end_matches('/').to_string(),
+            components_enabled,
         })
     }
 
@@ -1264,7 +1405,7 @@ impl AsyncChatOpsNotifier for DiscordChatOpsNotifierAdapter {
         notification: &ChatOpsNotification,
     ) -> ChatOpsRuntimeFuture<'_, Result<(), ChatOpsAdapterError>> {
         let channel_id = notification.channel_id.clone();
-        let text = notification.message_text.clone();
+        let body = discord_message_body(notification, self.components_enabled);
         let endpoint = self.messages_endpoint(&channel_id);
         let auth_header = format!("Bot {}", self.bot_token);
 
@@ -1274,9 +1415,7 @@ impl AsyncChatOpsNotifier for DiscordChatOpsNotifierAdapter {
                 .post(endpoint)
                 .header(reqwest::header::AUTHORIZATION, auth_header.as_str())
                 .header(reqwest::header::USER_AGENT, "nettoolskit-chatops/1.0")
-                .json(&serde_json::json!({
-                    "content": text,
-                }))
+                .json(&body)
                 .send()
                 .await
                 .map_err(|error| ChatOpsAdapterError::new(error.to_string()))?;
@@ -1443,6 +1582,27 @@ struct TelegramUpdatesResponse {
 struct TelegramUpdate {
     update_id: i64,
     message: Option<TelegramMessage>,
+    #[serde(default)]
+    callback_query: Option<TelegramCallbackQuery>,
+}
+
+/// Envelopes parsed from Telegram updates plus inline-button clicks to acknowledge.
+#[derive(Debug, Default)]
+struct TelegramIngressBatch {
+    envelopes: Vec<ChatOpsCommandEnvelope>,
+    callback_query_ids: Vec<String>,
+}
+
+impl TelegramIngressBatch {
+    fn push(&mut self, mut update: TelegramUpdate) {
+        if let Some(callback_query) = update.callback_query.take() {
+            self.callback_query_ids.push(callback_query.id.clone());
+            self.envelopes
+                .extend(callback_query.into_chatops_envelope());
+        } else {
+            self.envelopes.extend(update.into_chatops_envelope());
+        }
+    }
 }
 
 impl TelegramUpdate {
@@ -1473,6 +1633,36 @@ impl TelegramUpdate {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct TelegramCallbackQuery {
+    id: String,
+    from: TelegramUser,
+    #[serde(default)]
+    message: Option<TelegramMessage>,
+    #[serde(default)]
+    data: Option<String>,
+}
+
+impl TelegramCallbackQuery {
+    /// Map an inline-button click to the equivalent chat command.
+    ///
+    /// Clicks with unknown payloads, or on messages Telegram no longer returns, are dropped.
+    fn into_chatops_envelope(self) -> Option<ChatOpsCommandEnvelope> {
+        let action = ChatOpsAction::from_callback_data(self.data.as_deref()?)?;
+        let message = self.message?;
+        Some(
+            ChatOpsCommandEnvelope::new(
+                ChatOpsPlatform::Telegram,
+                message.chat.id.to_string(),
+                self.from.id.to_string(),
+                action.to_message_text(),
+                current_unix_timestamp_ms(),
+            )
+            .with_transport(IngressTransport::TelegramPolling),
+        )
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct TelegramMessage {
     date: i64,
@@ -1549,9 +1739,13 @@ struct DiscordInteractionUser {
 
 #[derive(Debug, Deserialize)]
 struct DiscordInteractionData {
+    #[serde(default)]
     name: String,
     #[serde(default)]
     options: Vec<DiscordInteractionOption>,
+    /// Button payload of message-component interactions.
+    #[serde(default)]
+    custom_id: Option<String>,
 }
 
 impl DiscordInteractionData {
@@ -1626,9 +1820,35 @@ fn discord_option_value_to_token(value: serde_json::Value) -> Option<String> {
     }
 }
 
+/// Whether a raw Telegram webhook update approves or rejects a task, as a
+/// button click or as an `approve`/`reject` text command.
+#[must_use]
+pub fn telegram_webhook_decides_approval(payload: &str) -> bool {
+    parse_telegram_webhook_payload(payload)
+        .is_ok_and(|batch| batch.envelopes.iter().any(envelope_decides_approval))
+}
+
+/// Whether a raw Discord interaction approves or rejects a task, as a button
+/// click or as an application command.
+#[must_use]
+pub fn discord_interaction_decides_approval(payload: &str) -> bool {
+    matches!(
+        parse_discord_interaction_payload(payload),
+        Ok(DiscordInteractionIngressPayload::Command(envelope))
+            if envelope_decides_approval(&envelope)
+    )
+}
+
+fn envelope_decides_approval(envelope: &ChatOpsCommandEnvelope) -> bool {
+    matches!(
+        parse_chatops_intent(&envelope.message_text),
+        Ok(ChatOpsIntent::TaskApprove { .. } | ChatOpsIntent::TaskReject { .. })
+    )
+}
+
 fn parse_telegram_webhook_payload(
     payload: &str,
-) -> Result<Vec<ChatOpsCommandEnvelope>, ChatOpsAdapterError> {
+) -> Result<TelegramIngressBatch, ChatOpsAdapterError> {
     if payload.trim().is_empty() {
         return Err(ChatOpsAdapterError::new(
             "Telegram webhook payload must not be empty",
@@ -1638,10 +1858,9 @@ fn parse_telegram_webhook_payload(
     let update: TelegramUpdate = serde_json::from_str(payload).map_err(|error| {
         ChatOpsAdapterError::new(format!("invalid Telegram webhook payload: {error}"))
     })?;
-    Ok(update
-        .into_chatops_envelope()
-        .map(|envelope| vec![envelope])
-        .unwrap_or_default())
+    let mut batch = TelegramIngressBatch::default();
+    batch.push(update);
+    Ok(batch)
 }
 
 fn parse_discord_interaction_payload(
@@ -1659,7 +1878,8 @@ fn parse_discord_interaction_payload(
         })?;
     match interaction.interaction_type {
         1 => Ok(DiscordInteractionIngressPayload::Ping),
-        2 => {
+        // 2: application command, 3: message component (button click).
+        kind @ (2 | 3) => {
             let user_id = interaction
                 .resolve_user_id()
                 .ok_or_else(|| ChatOpsAdapterError::new("Discord interaction missing user id"))?;
@@ -1669,9 +1889,20 @@ fn parse_discord_interaction_payload(
             let data = interaction.data.ok_or_else(|| {
                 ChatOpsAdapterError::new("Discord interaction missing command data")
             })?;
-            let message_text = data
-                .into_message_text()
-                .ok_or_else(|| ChatOpsAdapterError::new("Discord interaction command is empty"))?;
+            let message_text = if kind == 3 {
+                let custom_id = data.custom_id.unwrap_or_default();
+                ChatOpsAction::from_callback_data(&custom_id)
+                    .map(|action| action.to_message_text())
+                    .ok_or_else(|| {
+                        ChatOpsAdapterError::new(format!(
+                            "unsupported Discord component custom_id: {custom_id}"
+                        ))
+                    })?
+            } else {
+                data.into_message_text().ok_or_else(|| {
+                    ChatOpsAdapterError::new("Discord interaction command is empty")
+                })?
+            };
             Ok(DiscordInteractionIngressPayload::Command(
                 ChatOpsCommandEnvelope::new(
                     ChatOpsPlatform::Discord,
@@ -1689,6 +1920,59 @@ fn parse_discord_interaction_payload(
     }
 }
 
+/// `sendMessage` body; actions render as one row of inline keyboard buttons.
+fn telegram_send_message_body(notification: &ChatOpsNotification) -> serde_json::Value {
+    let mut body = serde_json::json!({
+        "chat_id": notification.channel_id,
+        "text": notification.message_text,
+    });
+    if !notification.actions.is_empty() {
+        let buttons: Vec<serde_json::Value> = notification
+            .actions
+            .iter()
+            .map(|action| {
+                serde_json::json!({
+                    "text": action.label(),
+                    "callback_data": action.callback_data(),
+                })
+            })
+            .collect();
+        body["reply_markup"] = serde_json::json!({ "inline_keyboard": [buttons] });
+    }
+    body
+}
+
+/// Channel message body; actions render as one action row of buttons when
+/// component clicks can be received.
+fn discord_message_body(
+    notification: &ChatOpsNotification,
+    components_enabled: bool,
+) -> serde_json::Value {
+    let mut body = serde_json::json!({ "content": notification.message_text });
+    if components_enabled && !notification.actions.is_empty() {
+        let buttons: Vec<serde_json::Value> = notification
+            .actions
+            .iter()
+            .map(|action| {
+                // Button styles: 2 secondary, 3 success, 4 danger.
+                let style = match action.kind {
+                    ChatOpsActionKind::Approve => 3,
+                    ChatOpsActionKind::Reject => 4,
+                    ChatOpsActionKind::Details => 2,
+                };
+                serde_json::json!({
+                    "type": 2,
+                    "style": style,
+                    "label": action.label(),
+                    "custom_id": action.callback_data(),
+                })
+            })
+            .collect();
+        body["components"] = serde_json::json!([{ "type": 1, "components": buttons }]);
+    }
+    body
+}
+
 fn parse_bool(value: &str) -> Option<bool> {
     match value.trim().to_ascii_lowercase().as_str() {
         "1" | "true" | "yes" | "on" => Some(true),
@@ -1861,6 +2145,9 @@ fn append_runtime_audit(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::execution::chatops::{
+        queue_task_completion, ChatOpsTaskCompletion, ChatOpsTaskOrigin,
+    };
     use tokio::sync::Mutex as AsyncMutex;
 
     static ENV_LOCK: std::sync::OnceLock<AsyncMutex<()>> = std::sync::OnceLock::new();
@@ -2197,8 +2484,9 @@ mod tests {
     #[test]
     fn telegram_webhook_payload_parses_text_message_to_envelope() {
         let payload = r#"{"update_id":10,"message":{"date":1737200000,"text":"list","chat":{"id":555},"from":{"id":777}}}"#;
-        let envelopes =
-            parse_telegram_webhook_payload(payload).expect("valid payload should parse");
+        let envelopes = parse_telegram_webhook_payload(payload)
+            .expect("valid payload should parse")
+            .envelopes;
         assert_eq!(envelopes.len(), 1);
         assert_eq!(envelopes[0].platform, ChatOpsPlatform::Telegram);
         assert_eq!(envelopes[0].channel_id, "555");
@@ -2242,17 +2530,82 @@ mod tests {
         assert!(parsed.is_err());
     }
 
+    #[test]
+    fn telegram_webhook_payload_maps_button_click_to_approval_command() {
+        let payload = r#"{"update_id":11,"callback_query":{"id":"cb-1","from":{"id":777},"data":"ntk:approve:task-42","message":{"date":1737200000,"chat":{"id":555}}}}"#;
+        let batch = parse_telegram_webhook_payload(payload).expect("callback query should parse");
+
+        assert_eq!(batch.callback_query_ids, vec!["cb-1".to_string()]);
+        assert_eq!(batch.envelopes.len(), 1);
+        assert_eq!(batch.envelopes[0].channel_id, "555");
+        assert_eq!(batch.envelopes[0].user_id, "777");
+        assert_eq!(batch.envelopes[0].message_text, "approve task-42");
+    }
+
+    #[test]
+    fn telegram_webhook_payload_acknowledges_but_drops_unknown_button() {
+        let payload = r#"{"update_id":12,"callback_query":{"id":"cb-2","from":{"id":777},"data":"other:thing","message":{"date":1737200000,"chat":{"id":555}}}}"#;
+        let batch = parse_telegram_webhook_payload(payload).expect("callback query should parse");
+
+        assert_eq!(batch.callback_query_ids, vec!["cb-2".to_string()]);
+        assert!(batch.envelopes.is_empty());
+    }
+
+    #[test]
+    fn discord_interaction_payload_maps_button_click_to_command() {
+        let payload = r#"{"id":"901","type":3,"channel_id":"555","member":{"user":{"id":"777"}},"data":{"component_type":2,"custom_id":"ntk:reject:task-42"}}"#;
+        let parsed =
+            parse_discord_interaction_payload(payload).expect("component interaction should parse");
+        let DiscordInteractionIngressPayload::Command(envelope) = parsed else {
+            panic!("expected command payload");
+        };
+
+        assert_eq!(envelope.user_id, "777");
+        assert_eq!(envelope.message_text, "reject task-42");
+        assert_eq!(envelope.transport, IngressTransport::DiscordInteractions);
+    }
+
+    #[test]
+    fn notification_actions_render_as_platform_buttons() {
+        let notification = ChatOpsNotification {
+            platform: ChatOpsPlatform::Telegram,
+            channel_id: "555".to_string(),
+            message_text: "awaiting approval".to_string(),
+            severity: ChatOpsNotificationSeverity::Info,
+            actions: ChatOpsAction::approval_prompt("task-42"),
+        };
+
+        let telegram = telegram_send_message_body(&notification);
+        let keyboard = &telegram["reply_markup"]["inline_keyboard"][0];
+        assert_eq!(keyboard.as_array().map(Vec::len), Some(3));
+        assert_eq!(keyboard[0]["callback_data"], "ntk:approve:task-42");
+        assert_eq!(keyboard[1]["callback_data"], "ntk:reject:task-42");
+
+        let discord = discord_message_body(&notification, true);
+        let row = &discord["components"][0];
+        assert_eq!(row["type"], 1);
+        assert_eq!(row["components"][0]["custom_id"], "ntk:approve:task-42");
+        assert_eq!(row["components"][1]["style"], 4);
+
+        let discord_plain = discord_message_body(&notification, false);
+        assert!(discord_plain.get("components").is_none());
+    }
+
     #[tokio::test]
     async fn webhook_ingress_queue_drains_in_fifo_order() {
         let ingress = TelegramWebhookIngressAdapter::default();
         let first = r#"{"update_id":10,"message":{"date":1737200000,"text":"list","chat":{"id":100},"from":{"id":200}}}"#;
         let second = r#"{"update_id":11,"message":{"date":1737200001,"text":"help","chat":{"id":101},"from":{"id":201}}}"#;
 
+        let trace = TraceContext::parse(
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            None,
+        );
         let added_first = ingress
-            .enqueue_payload(first)
+            .enqueue_payload(first, trace.clone())
             .expect("first payload should enqueue");
         let added_second = ingress
-            .enqueue_payload(second)
+            .enqueue_payload(second, None)
             .expect("second payload should enqueue");
         assert_eq!(added_first, 1);
         assert_eq!(added_second, 1);
@@ -2262,15 +2615,17 @@ mod tests {
 
         assert_eq!(first_batch.len(), 1);
         assert_eq!(first_batch[0].message_text, "list");
+        assert_eq!(first_batch[0].trace_context, trace);
         assert_eq!(second_batch.len(), 1);
         assert_eq!(second_batch[0].message_text, "help");
+        assert_eq!(second_batch[0].trace_context, None);
     }
 
     #[tokio::test]
     async fn discord_interaction_ingress_handles_ping_and_command_queue() {
         let ingress = DiscordInteractionIngressAdapter::default();
         let ping = ingress
-            .enqueue_payload(r#"{"type":1}"#)
+            .enqueue_payload(r#"{"type":1}"#, None)
             .expect("ping payload should parse");
         assert!(ping.ping);
         assert_eq!(ping.queued, 0);
@@ -2278,6 +2633,7 @@ mod tests {
         let command = ingress
             .enqueue_payload(
                 r#"{"type":2,"channel_id":"555","member":{"user":{"id":"777"}},"data":{"name":"list"}}"#,
+                None,
             )
             .expect("command payload should parse");
         assert!(!command.ping);
@@ -2306,6 +2662,7 @@ mod tests {
 
         let result = runtime.enqueue_telegram_webhook_payload(
             r#"{"update_id":10,"message":{"date":1737200000,"text":"list","chat":{"id":555},"from":{"id":777}}}"#,
+            None,
         );
         assert!(result.is_err());
     }
@@ -2327,6 +2684,7 @@ mod tests {
         let added = runtime
             .enqueue_telegram_webhook_payload(
                 r#"{"update_id":10,"message":{"date":1737200000,"text":"list","chat":{"id":555},"from":{"id":777}}}"#,
+                None,
             )
             .expect("payload should enqueue");
         assert_eq!(added, 1);
@@ -2350,6 +2708,7 @@ mod tests {
 
         let result = runtime.enqueue_discord_interaction_payload(
             r#"{"type":2,"channel_id":"555","member":{"user":{"id":"777"}},"data":{"name":"list"}}"#,
+            None,
         );
         assert!(result.is_err());
     }
@@ -2371,10 +2730,136 @@ mod tests {
         let outcome = runtime
             .enqueue_discord_interaction_payload(
                 r#"{"type":2,"channel_id":"555","member":{"user":{"id":"777"}},"data":{"name":"list"}}"#,
+                None,
             )
             .expect("payload should enqueue");
         assert!(!outcome.ping);
         assert_eq!(outcome.queued, 1);
         assert!(runtime.is_discord_interactions_enabled());
     }
+
+    struct FlakyNotifier {
+        failures_left: Mutex<usize>,
+        sent: Mutex<Vec<ChatOpsNotification>>,
+    }
+
+    impl AsyncChatOpsNotifier for FlakyNotifier {
+        fn send(
+            &self,
+            notification: &ChatOpsNotification,
+        ) -> ChatOpsRuntimeFuture<'_, Result<(), ChatOpsAdapterError>> {
+            let notification = notification.clone();
+            Box::pin(async move {
+                let mut failures_left = self.failures_left.lock().expect("lock");
+                if *failures_left > 0 {
+                    *failures_left -= 1;
+                    return Err(ChatOpsAdapterError::new("transient send failure"));
+                }
+                self.sent.lock().expect("lock").push(notification);
+                Ok(())
+            })
+        }
+    }
+
+    fn runtime_with_notifier(
+        notifier: Arc<FlakyNotifier>,
+        channel_id: &str,
+        audit_store: ChatOpsLocalAuditStore,
+    ) -> ChatOpsRuntime {
+        ChatOpsRuntime {
+            poll_interval: Duration::from_millis(DEFAULT_CHATOPS_POLL_INTERVAL_MS),
+            max_batch_size: DEFAULT_CHATOPS_MAX_BATCH_SIZE,
+            policy: ChatOpsAuthorizationPolicy::new(
+                vec!["777".to_string()],
+                vec![channel_id.to_string()],
+            ),
+            audit_store: Some(audit_store),
+            rate_limiter: None,
+            telegram_webhook_ingress: None,
+            discord_interaction_ingress: None,
+            ingresses: Vec::new(),
+            notifiers: vec![(ChatOpsPlatform::Telegram, notifier)],
+        }
+    }
+
+    fn queue_completion(channel_id: &str, task_id: &str) {
+        queue_task_completion(ChatOpsTaskCompletion {
+            origin: ChatOpsTaskOrigin {
+                platform: ChatOpsPlatform::Telegram,
+                channel_id: channel_id.to_string(),
+            },
+            task_id: task_id.to_string(),
+            status: nettoolskit_core::TaskExecutionStatus::Succeeded,
+            detail: "done".to_string(),
+            attempts: 1,
+            max_attempts: 1,
+            duration: Duration::from_millis(1_200),
+            output: None,
+            control_envelope: None,
+        });
+    }
+
+    #[tokio::test]
+    async fn tick_retries_task_completion_notifications_and_records_audit() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let audit_store = ChatOpsLocalAuditStore::from_path(dir.path().join("audit.jsonl"));
+        let notifier = Arc::new(FlakyNotifier {
+            failures_left: Mutex::new(1),
+            sent: Mutex::new(Vec::new()),
+        });
+        let runtime =
+            runtime_with_notifier(notifier.clone(), "completion-retry", audit_store.clone());
+        queue_completion("completion-retry", "task-retry-1");
+
+        let summary = runtime.tick().await;
+
+        assert_eq!(summary.task_notifications_sent, 1);
+        assert_eq!(summary.notification_errors, 0);
+        let sent = notifier.sent.lock().expect("lock").clone();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].channel_id, "completion-retry");
+        assert!(sent[0]
+            .message_text
+            .contains("Task task-retry-1 succeeded in 1.2s"));
+
+        let entries = audit_store.load_latest(8).expect("audit entries");
+        let entry = entries
+            .iter()
+            .find(|entry| entry.task_id.as_deref() == Some("task-retry-1"))
+            .expect("completion audit entry");
+        assert_eq!(entry.kind, ChatOpsAuditKind::NotificationSent);
+        assert!(entry.note.contains("attempt 2/3"));
+    }
+
+    #[tokio::test]
+    async fn tick_reports_task_completion_notifications_that_exhaust_retries() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let audit_store = ChatOpsLocalAuditStore::from_path(dir.path().join("audit.jsonl"));
+        let notifier = Arc::new(FlakyNotifier {
+            failures_left: Mutex::new(TASK_COMPLETION_NOTIFY_MAX_ATTEMPTS),
+            sent: Mutex::new(Vec::new()),
+        });
+        let runtime =
+            runtime_with_notifier(notifier.clone(), "completion-dead", audit_store.clone());
+        queue_completion("completion-dead", "task-dead-1");
+        queue_completion("completion-elsewhere", "task-elsewhere-1");
+
+        let summary = runtime.tick().await;
+
+        assert_eq!(summary.task_notifications_sent, 0);
+        assert_eq!(summary.notification_errors, 1);
+        assert!(notifier.sent.lock().expect("lock").is_empty());
+        assert!(audit_store
+            .load_latest(8)
+            .expect("audit entries")
+            .is_empty());
+
+        let leftover =
+            drain_task_completions(|completion| completion.task_id == "task-elsewhere-1");
+        assert_eq!(
+            leftover.len(),
+            1,
+            "completions for other channels stay queued"
+        );
+    }
 }
@@ -0,0 +1,429 @@
+//! Context-aware completion for slash command lines.
+//!
+//! Resolves the argument position under the cursor against the
+//! [`command_spec`](crate::models::command_spec) registry (plugin commands
+//! included) and offers the matching subcommands, flags or values. Values that
+//! depend on the environment (manifests, tasks, AI sessions, paths) come from
+//! a [`CompletionSource`], so the grammar walk stays testable.
+
+use super::ai_session::list_local_ai_session_snapshots;
+use super::plugins::{find_registered_command_spec, registered_command_specs};
+use super::processor::list_task_snapshots;
+use crate::models::command_spec::{
+    config_key_spec, config_keys, split_arguments, ArgKind, ArgSpec, FlagSpec, AI_SESSION_ACTIONS,
+    TASK_INTENTS,
+};
+use nettoolskit_core::file_search::find_manifests;
+use std::path::{Path, MAIN_SEPARATOR};
+
+/// AI sessions offered by `/ai resume`, newest first.
+const AI_SESSION_COMPLETION_LIMIT: usize = 20;
+
+/// One completion candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionCandidate {
+    /// Text that replaces the token under the cursor.
+    pub value: String,
+    /// Short context shown next to the value (may be empty).
+    pub description: String,
+}
+
+impl CompletionCandidate {
+    /// Candidate with a description.
+    #[must_use]
+    pub fn new(value: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            description: description.into(),
+        }
+    }
+}
+
+/// Candidates for the token that starts at byte offset `start`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandCompletion {
+    /// Byte offset in the line where the replaced token starts.
+    pub start: usize,
+    /// Candidates in display order.
+    pub candidates: Vec<CompletionCandidate>,
+}
+
+/// Environment-dependent values offered by the completer.
+pub trait CompletionSource {
+    /// Manifest files under the working directory, relative to it.
+    fn manifest_paths(&self) -> Vec<String>;
+
+    /// Task ids with their status; only non-terminal tasks when `active_only`.
+    fn task_ids(&self, active_only: bool) -> Vec<CompletionCandidate>;
+
+    /// Persisted local AI session ids, newest first.
+    fn ai_session_ids(&self) -> Vec<CompletionCandidate>;
+
+    /// Filesystem entries whose path starts with `prefix`.
+    fn paths(&self, prefix: &str, directories_only: bool) -> Vec<String>;
+}
+
+/// [`CompletionSource`] backed by the working directory, the task registry
+/// and the local AI session store.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LiveCompletionSource;
+
+impl CompletionSource for LiveCompletionSource {
+    fn manifest_paths(&self) -> Vec<String> {
+        let Ok(root) = std::env::current_dir() else {
+            return Vec::new();
+        };
+        let mut paths: Vec<String> = find_manifests(&root)
+            .unwrap_or_default()
+            .iter()
+            .map(|path| {
+                path.strip_prefix(&root)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    fn task_ids(&self, active_only: bool) -> Vec<CompletionCandidate> {
+        list_task_snapshots()
+            .into_iter()
+            .filter(|task| !active_only || !task.status.is_terminal())
+            .map(|task| CompletionCandidate::new(task.id, task.status.as_str()))
+            .collect()
+    }
+
+    fn ai_session_ids(&self) -> Vec<CompletionCandidate> {
+        list_local_ai_session_snapshots(AI_SESSION_COMPLETION_LIMIT)
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|session| {
+                CompletionCandidate::new(
+                    session.id,
+                    format!("{} exchanges", session.exchange_count),
+                )
+            })
+            .collect()
+    }
+
+    fn paths(&self, prefix: &str, directories_only: bool) -> Vec<String> {
+        let (base, partial) = match prefix.rfind(['/', MAIN_SEPARATOR]) {
+            Some(index) => prefix.split_at(index + 1),
+            None => ("", prefix),
+        };
+        let directory = if base.is_empty() {
+            Path::new(".")
+        } else {
+            Path::new(base)
+        };
+        let Ok(entries) = std::fs::read_dir(directory) else {
+            return Vec::new();
+        };
+        let mut paths: Vec<String> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if !name.starts_with(partial)
+                    || (name.starts_with('.') && !partial.starts_with('.'))
+                {
+                    return None;
+                }
+                let is_dir = entry.file_type().is_ok_and(|kind| kind.is_dir());
+                if directories_only && !is_dir {
+                    return None;
+                }
+                let separator = if is_dir { "/" } else { "" };
+                Some(format!("{base}{name}{separator}"))
+            })
+            .collect();
+        paths.sort();
+        paths
+    }
+}
+
+/// Complete `line` (text up to the cursor) against the live environment.
+#[must_use]
+pub fn complete_command_line(line: &str) -> CommandCompletion {
+    complete_command_line_with(line, &LiveCompletionSource)
+}
+
+/// Complete `line` (text up to the cursor) using `source` for dynamic values.
+///
+/// Only slash commands complete; free text yields no candidates.
+#[must_use]
+pub fn complete_command_line_with(line: &str, source: &dyn CompletionSource) -> CommandCompletion {
+    let start = line
+        .char_indices()
+        .rev()
+        .find(|(_, ch)| ch.is_whitespace())
+        .map_or(0, |(index, ch)| index + ch.len_utf8());
+    let current = &line[start..];
+    let mut previous: Vec<&str> = line[..start].split_whitespace().collect();
+    if previous.first() == Some(&"/") {
+        previous.remove(0);
+    }
+
+    let candidates = match previous.split_first() {
+        None if current.starts_with('/') => registered_command_specs()
+            .into_iter()
+            .filter(|spec| starts_with_ignore_case(spec.name, current))
+            .map(|spec| CompletionCandidate::new(spec.name, spec.description))
+            .collect(),
+        None => Vec::new(),
+        Some((command, rest)) => match find_registered_command_spec(command) {
+            None => Vec::new(),
+            Some(spec) if spec.subcommands.is_empty() => {
+                argument_candidates(spec.args, spec.flags, rest, current, "", source)
+            }
+            Some(spec) => match rest.split_first() {
+                None => spec
+                    .subcommands
+                    .iter()
+                    .filter(|subcommand| starts_with_ignore_case(subcommand.name, current))
+                    .map(|subcommand| {
+                        CompletionCandidate::new(subcommand.name, subcommand.description)
+                    })
+                    .collect(),
+                Some((name, rest)) => match spec.subcommand(name) {
+                    Some(subcommand) => argument_candidates(
+                        subcommand.args,
+                        subcommand.flags,
+                        rest,
+                        current,
+                        subcommand.name,
+                        source,
+                    ),
+                    None => Vec::new(),
+                },
+            },
+        },
+    };
+
+    CommandCompletion { start, candidates }
+}
+
+fn argument_candidates(
+    args: &[ArgSpec],
+    flags: &[FlagSpec],
+    rest: &[&str],
+    current: &str,
+    subcommand: &str,
+    source: &dyn CompletionSource,
+) -> Vec<CompletionCandidate> {
+    // A value-taking flag right before the cursor wants its value.
+    if let Some(kind) = rest.last().and_then(|last| {
+        flags
+            .iter()
+            .find(|flag| flag.name == *last)
+            .and_then(|flag| flag.value)
+    }) {
+        return value_candidates(kind, current, None, subcommand, source);
+    }
+
+    let parsed = split_arguments(rest, args, flags);
+    let flag_candidates = || -> Vec<CompletionCandidate> {
+        flags
+            .iter()
+            .filter(|flag| flag.name.starts_with(current) && !parsed.has_flag(flag.name))
+            .map(|flag| CompletionCandidate::new(flag.name, flag.description))
+            .collect()
+    };
+    if current.starts_with('-') {
+        return flag_candidates();
+    }
+
+    let next_arg = args
+        .get(parsed.positionals.len())
+        .filter(|arg| arg.kind != ArgKind::Text);
+    match next_arg {
+        Some(arg) => value_candidates(
+            arg.kind,
+            current,
+            parsed.positionals.first().copied(),
+            subcommand,
+            source,
+        ),
+        None if current.is_empty() => flag_candidates(),
+        None => Vec::new(),
+    }
+}
+
+fn value_candidates(
+    kind: ArgKind,
+    current: &str,
+    first_positional: Option<&str>,
+    subcommand: &str,
+    source: &dyn CompletionSource,
+) -> Vec<CompletionCandidate> {
+    let keyword = |value: &str| starts_with_ignore_case(value, current);
+    match kind {
+        ArgKind::ManifestPath => source
+            .manifest_paths()
+            .into_iter()
+            .filter(|path| path.starts_with(current.trim_start_matches("./")))
+            .map(|path| CompletionCandidate::new(path, "manifest"))
+            .collect(),
+        ArgKind::Directory | ArgKind::Path => source
+            .paths(current, kind == ArgKind::Directory)
+            .into_iter()
+            .map(|path| CompletionCandidate::new(path, ""))
+            .collect(),
+        ArgKind::TaskId => source
+            .task_ids(subcommand != "watch")
+            .into_iter()
+            .filter(|task| task.value.starts_with(current))
+            .collect(),
+        ArgKind::TaskIntent => TASK_INTENTS
+            .iter()
+            .filter(|intent| keyword(intent.label))
+            .map(|intent| CompletionCandidate::new(intent.label, "intent"))
+            .collect(),
+        ArgKind::AiSessionId if matches!(first_positional, Some("list" | "search")) => Vec::new(),
+        ArgKind::AiSessionId => source
+            .ai_session_ids()
+            .into_iter()
+            .filter(|session| session.value.starts_with(current))
+            .collect(),
+        ArgKind::AiSessionAction => AI_SESSION_ACTIONS
+            .iter()
+            .filter(|action| keyword(action))
+            .map(|action| CompletionCandidate::new(*action, "session action"))
+            .collect(),
+        ArgKind::ConfigKey => config_keys()
+            .filter(|key| keyword(key.name))
+            .map(|key| CompletionCandidate::new(key.name, key.values.join("|")))
+            .collect(),
+        ArgKind::ConfigValue => first_positional
+            .and_then(config_key_spec)
+            .map(|key| {
+                key.values
+                    .iter()
+                    .filter(|value| keyword(value))
+                    .map(|value| CompletionCandidate::new(*value, key.name))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        ArgKind::Text => Vec::new(),
+    }
+}
+
+fn starts_with_ignore_case(value: &str, prefix: &str) -> bool {
+    value
+        .get(..prefix.len())
+        .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedSource;
+
+    impl CompletionSource for FixedSource {
+        fn manifest_paths(&self) -> Vec<String> {
+            vec![
+                "app.manifest.yml".to_string(),
+                "svc/ntk-api.yml".to_string(),
+            ]
+        }
+
+        fn task_ids(&self, active_only: bool) -> Vec<CompletionCandidate> {
+            let mut tasks = vec![CompletionCandidate::new("task-1", "running")];
+            if !active_only {
+                tasks.push(CompletionCandidate::new("task-2", "succeeded"));
+            }
+            tasks
+        }
+
+        fn ai_session_ids(&self) -> Vec<CompletionCandidate> {
+            vec![CompletionCandidate::new("session-a", "3 exchanges")]
+        }
+
+        fn paths(&self, prefix: &str, _directories_only: bool) -> Vec<String> {
+            vec![format!("{prefix}out/")]
+        }
+    }
+
+    fn values(line: &str) -> (usize, Vec<String>) {
+        let completion = complete_command_line_with(line, &FixedSource);
+        (
+            completion.start,
+            completion
+                .candidates
+                .into_iter()
+                .map(|candidate| candidate.value)
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn completes_commands_and_subcommands() {
+        assert_eq!(values("/ta"), (0, vec!["/task".to_string()]));
+        assert_eq!(
+            values("/manifest re"),
+            (10, vec!["render".to_string(), "render-async".to_string()])
+        );
+        assert_eq!(values("hello wor"), (6, Vec::new()));
+    }
+
+    #[test]
+    fn completes_manifest_paths_then_flags() {
+        let (start, candidates) = values("/manifest apply svc");
+        assert_eq!(start, 16);
+        assert_eq!(candidates, vec!["svc/ntk-api.yml"]);
+
+        let (_, candidates) = values("/manifest apply app.manifest.yml ");
+        assert_eq!(candidates, vec!["--dry-run", "--output"]);
+
+        let (_, candidates) = values("/manifest apply app.manifest.yml --dry-run --");
+        assert_eq!(candidates, vec!["--output"]);
+    }
+
+    #[test]
+    fn completes_flag_values_by_kind() {
+        let (start, candidates) = values("/manifest render app.yml --output ./");
+        assert_eq!(start, 34);
+        assert_eq!(candidates, vec!["./out/"]);
+    }
+
+    #[test]
+    fn completes_task_ids_per_subcommand() {
+        assert_eq!(values("/task watch ").1, vec!["task-1", "task-2"]);
+        assert_eq!(values("/task cancel ").1, vec!["task-1"]);
+        assert_eq!(values("/task submit ai-p").1, vec!["ai-plan"]);
+    }
+
+    #[test]
+    fn completes_config_keys_and_enum_values() {
+        assert_eq!(values("/config set log").1, vec!["log_level"]);
+        assert_eq!(values("/config set color a").1, vec!["auto", "always"]);
+        assert_eq!(
+            values("/config set runtime-mode ").1,
+            vec!["cli", "service"]
+        );
+        assert!(values("/config set shell ").1.is_empty());
+        assert_eq!(
+            values("/config set service.automation").1,
+            vec!["service.automation_profile"]
+        );
+        assert_eq!(
+            values("/config set tool_scope.enabled ").1,
+            vec!["true", "false"]
+        );
+    }
+
+    #[test]
+    fn completes_ai_sessions_and_apply_flags() {
+        assert_eq!(values("/ai resume ").1, vec!["session-a"]);
+        assert_eq!(values("/ai apply --a").1, vec!["--approve-write"]);
+        assert_eq!(values("/ai apply ").1, vec!["--dry-run", "--approve-write"]);
+        assert_eq!(values("/ai session s").1, vec!["show", "search"]);
+        assert_eq!(values("/ai session export ").1, vec!["session-a"]);
+        assert!(values("/ai session search ").1.is_empty());
+        assert_eq!(values("/ai fork ").1, vec!["session-a"]);
+    }
+}
@@ -2,6 +2,7 @@
 ///
 /// Provides non-blocking command execution with progress tracking,
 /// cancellation support, and concurrent command handling.
+use super::output::inherit_output_capture;
 use std::future::Future;
 use std::pin::Pin;
 use tokio::sync::{mpsc, oneshot};
@@ -174,10 +175,10 @@ impl AsyncCommandExecutor {
     {
         let (tx, rx) = oneshot::channel();
 
-        let handle = tokio::spawn(async move {
+        let handle = tokio::spawn(inherit_output_capture(async move {
             let result = future.await;
             let _ = tx.send(result);
-        });
+        }));
 
         self.running.push(handle);
         self.cleanup_finished();
@@ -193,7 +194,7 @@ impl AsyncCommandExecutor {
         let (result_tx, result_rx) = oneshot::channel();
         let (cancel_tx, mut cancel_rx) = mpsc::channel(1);
 
-        let handle = tokio::spawn(async move {
+        let handle = tokio::spawn(inherit_output_capture(async move {
             tokio::pin!(future);
 
             tokio::select! {
@@ -204,7 +205,7 @@ impl AsyncCommandExecutor {
                     let _ = result_tx.send(Err("Command was cancelled".into()));
                 }
             }
-        });
+        }));
 
         self.running.push(handle);
         self.cleanup_finished();
@@ -225,11 +226,11 @@ impl AsyncCommandExecutor {
         let (progress_tx, progress_rx) = mpsc::unbounded_channel();
 
         let progress_tx_clone = progress_tx.clone();
-        let handle = tokio::spawn(async move {
+        let handle = tokio::spawn(inherit_output_capture(async move {
             let future = factory(progress_tx_clone);
             let result = future.await;
             let _ = result_tx.send(result);
-        });
+        }));
 
         self.running.push(handle);
         self.cleanup_finished();
@@ -0,0 +1,556 @@
+//! Headless command entrypoints
+//!
+//! Typed counterparts of `/ai`, `/task` and `/config` for scripts, CI and the
+//! `ntk ai|task|config` subcommands. Every call runs through the same metrics,
+//! plugin hooks and policy gates as the slash-command path, but returns
+//! structured results. With [`ConsoleMode::Text`] the AI and task-submission
+//! entrypoints also print progress and failures, as the slash commands do; with
+//! [`ConsoleMode::Silent`] nothing is written to stdout.
+//!
+//! Task entrypoints talk to a running `ntk service` when an endpoint is
+//! configured (see [`super::remote`]) and fall back to the in-process registry
+//! when it is unreachable.
+
+use super::output::CommandOutput;
+use super::processor::{
+    self, apply_ai_session_retention_policy, apply_runtime_ui_config,
+    build_cli_task_control_envelope, call_remote_task_service, cancel_task, decide_task_approval,
+    get_config_value, is_ai_session_retention_key, list_pending_approval_snapshots,
+    list_task_snapshots, load_persisted_or_default, local_cli_operator, parse_task_intent_kind,
+    print_command_failure, process_control_envelope_with_console, run_ai_request,
+    run_typed_command, runtime_metrics, set_config_value, task_snapshot,
+    task_status_to_exit_status, unset_config_value, AiCommandReport, AiIntent, CommandFailure,
+    ConsoleMode, TaskApprovalVerdict,
+};
+use super::remote::{RemoteServiceClient, RemoteServiceError};
+use crate::models::{task_intent_labels, ExitStatus, MainAction};
+use nettoolskit_core::{AppConfig, OperatorContext, RuntimeMode, TaskExecutionStatus};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Options for [`run_ai`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AiRunOptions {
+    /// Request a dry-run (required for `apply` unless `approve_write` is set).
+    pub dry_run: bool,
+    /// Explicit approval for mutating `apply` requests.
+    pub approve_write: bool,
+}
+
+/// Point-in-time view of a task record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskSnapshot {
+    /// Stable task identifier.
+    pub id: String,
+    /// Canonical intent label (for example `ai-plan`).
+    pub intent: String,
+    /// Task title.
+    pub title: String,
+    /// Task payload.
+    pub payload: String,
+    /// Lifecycle status.
+    pub status: TaskExecutionStatus,
+    /// Runtime mode the task was admitted in.
+    pub runtime_mode: RuntimeMode,
+    /// Execution target (`local-fallback`, `background-worker`, ...).
+    pub execution_target: String,
+    /// Latest status detail.
+    pub detail: String,
+    /// Attempts made so far.
+    pub attempts: usize,
+    /// Maximum attempts allowed.
+    pub max_attempts: usize,
+    /// Creation timestamp (Unix milliseconds).
+    pub created_at_unix_ms: u64,
+    /// Last update timestamp (Unix milliseconds).
+    pub updated_at_unix_ms: u64,
+    /// Control-plane request id, when admitted through an envelope.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Correlation id, when admitted through an envelope.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    /// Operator that submitted the task, when admitted through an envelope.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operator: Option<String>,
+    /// Audit trail, oldest first (only populated for single-task lookups).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audit_events: Vec<TaskAuditSnapshot>,
+    /// Structured result of the execution (only populated for single-task lookups).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<CommandOutput>,
+    /// Approval gate that parked the task, when it required operator approval.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval_gate: Option<String>,
+}
+
+impl TaskSnapshot {
+    /// Exit status a CLI should report for this task.
+    #[must_use]
+    pub fn exit_status(&self) -> ExitStatus {
+        task_status_to_exit_status(self.status)
+    }
+}
+
+/// One task lifecycle audit entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskAuditSnapshot {
+    /// Status emitted by the event.
+    pub status: TaskExecutionStatus,
+    /// Human-readable event detail.
+    pub message: String,
+    /// Event timestamp (Unix milliseconds).
+    pub timestamp_unix_ms: u64,
+}
+
+/// Configuration value read or written by the `config` entrypoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigValueReport {
+    /// Key as provided by the caller.
+    pub key: String,
+    /// Effective value after the operation (empty when unset).
+    pub value: String,
+    /// Configuration file consulted or written.
+    pub path: PathBuf,
+}
+
+/// Run one AI request (`ask`, `plan`, `explain`, `apply`).
+pub async fn run_ai(
+    intent: AiIntent,
+    prompt: &str,
+    options: AiRunOptions,
+    console: ConsoleMode,
+) -> Result<AiCommandReport, CommandFailure> {
+    let command = format!("/ai {}", intent.as_label());
+    run_typed_command(
+        &command,
+        MainAction::Ai,
+        Some(intent.as_label()),
+        result_exit_status,
+        async {
+            let prompt = prompt.trim();
+            let result = if prompt.is_empty() {
+                Err(CommandFailure::new(format!(
+                    "Missing prompt for ai {}.",
+                    intent.as_label()
+                )))
+            } else {
+                run_ai_request(
+                    intent,
+                    prompt,
+                    options.dry_run,
+                    options.approve_write,
+                    console,
+                )
+                .await
+            };
+            if let (Err(failure), ConsoleMode::Text) = (&result, console) {
+                print_command_failure(failure);
+            }
+            result
+        },
+    )
+    .await
+}
+
+/// Submit a task using the configured runtime mode.
+///
+/// Policy rejections are returned as errors; accepted tasks are returned even
+/// when their execution failed, so callers can inspect the final status.
+pub async fn submit_task(
+    intent: &str,
+    payload: &str,
+    console: ConsoleMode,
+) -> Result<TaskSnapshot, CommandFailure> {
+    run_typed_command(
+        "/task submit",
+        MainAction::Task,
+        Some("submit"),
+        |result: &Result<TaskSnapshot, CommandFailure>| match result {
+            Ok(snapshot) => snapshot.exit_status(),
+            Err(_) => ExitStatus::Error,
+        },
+        async {
+            let admission = match parse_task_intent_kind(intent) {
+                None => Err(
+                    CommandFailure::new(format!("Unsupported task intent: {intent}"))
+                        .with_hint(format!("Supported intents: {}", task_intent_labels())),
+                ),
+                Some(_) if payload.trim().is_empty() => {
+                    Err(CommandFailure::new("Task payload cannot be empty."))
+                }
+                Some(intent_kind) => Ok(intent_kind),
+            };
+            let intent_kind = match admission {
+                Ok(intent_kind) => intent_kind,
+                Err(failure) => {
+                    // Policy rejections below are printed by the submission pipeline itself.
+                    if console.is_text() {
+                        print_command_failure(&failure);
+                    }
+                    return Err(failure);
+                }
+            };
+            let payload = payload.trim();
+
+            let runtime_mode = AppConfig::load().general.runtime_mode;
+            let title = format!("{} task", intent_kind.as_str());
+            let envelope =
+                build_cli_task_control_envelope(intent_kind, title, payload, runtime_mode);
+            let outcome = process_control_envelope_with_console(envelope, console).await;
+
+            match (outcome.task_id, outcome.remote_endpoint) {
+                (Some(task_id), Some(endpoint)) => RemoteServiceClient::new(endpoint)
+                    .get_task(&task_id)
+                    .await
+                    .map_err(remote_failure),
+                (Some(task_id), None) => task_snapshot(&task_id).ok_or_else(|| {
+                    CommandFailure::new(format!("Task record disappeared: {task_id}"))
+                }),
+                (None, _) => Err(CommandFailure::new(
+                    outcome
+                        .rejection_reason
+                        .unwrap_or_else(|| "Task submission was rejected".to_string()),
+                )),
+            }
+        },
+    )
+    .await
+}
+
+/// List tasks, most recently updated first.
+///
+/// Reads the configured service when one is reachable, otherwise the
+/// in-process registry.
+pub async fn list_tasks(console: ConsoleMode) -> Result<Vec<TaskSnapshot>, CommandFailure> {
+    run_typed_command(
+        "/task list",
+        MainAction::Task,
+        Some("list"),
+        result_exit_status,
+        async {
+            match call_remote_task_service(
+                console,
+                |client| async move { client.list_tasks().await },
+            )
+            .await
+            {
+                Some(result) => result.map_err(remote_failure),
+                None => Ok(list_task_snapshots()),
+            }
+        },
+    )
+    .await
+}
+
+/// Look up one task with its audit trail.
+pub async fn get_task(task_id: &str, console: ConsoleMode) -> Result<TaskSnapshot, CommandFailure> {
+    let task_id = task_id.trim();
+    run_typed_command(
+        "/task watch",
+        MainAction::Task,
+        Some("watch"),
+        result_exit_status,
+        async {
+            match call_remote_task_service(console, |client| async move {
+                client.get_task(task_id).await
+            })
+            .await
+            {
+                Some(result) => result.map_err(remote_failure),
+                None => task_snapshot(task_id)
+                    .ok_or_else(|| CommandFailure::new(format!("Task not found: {task_id}"))),
+            }
+        },
+    )
+    .await
+}
+
+/// Cancel a queued or running task.
+pub async fn cancel_task_by_id(
+    task_id: &str,
+    console: ConsoleMode,
+) -> Result<TaskSnapshot, CommandFailure> {
+    let task_id = task_id.trim();
+    run_typed_command(
+        "/task cancel",
+        MainAction::Task,
+        Some("cancel"),
+        result_exit_status,
+        async {
+            match call_remote_task_service(console, |client| async move {
+                client.cancel_task(task_id).await
+            })
+            .await
+            {
+                Some(result) => result.map_err(remote_failure),
+                None => cancel_task(task_id).map_err(CommandFailure::new),
+            }
+        },
+    )
+    .await
+}
+
+/// List tasks waiting in the approval queue.
+pub async fn list_pending_approvals(
+    console: ConsoleMode,
+) -> Result<Vec<TaskSnapshot>, CommandFailure> {
+    run_typed_command(
+        "/task approvals",
+        MainAction::Task,
+        Some("approvals"),
+        result_exit_status,
+        async {
+            match call_remote_task_service(
+                console,
+                |client| async move { client.list_tasks().await },
+            )
+            .await
+            {
+                Some(result) => result
+                    .map(|tasks| {
+                        tasks
+                            .into_iter()
+                            .filter(|task| task.status == TaskExecutionStatus::AwaitingApproval)
+                            .collect()
+                    })
+                    .map_err(remote_failure),
+                None => Ok(list_pending_approval_snapshots()),
+            }
+        },
+    )
+    .await
+}
+
+/// Approve a task waiting in the approval queue and release it for execution.
+///
+/// `approver` defaults to the local CLI operator; the service passes the
+/// identity of the HTTP caller so the approval audit names the real approver.
+pub async fn approve_task(
+    task_id: &str,
+    note: Option<&str>,
+    approver: Option<OperatorContext>,
+    console: ConsoleMode,
+) -> Result<TaskSnapshot, CommandFailure> {
+    decide_task(
+        task_id,
+        TaskApprovalVerdict::Approve,
+        note,
+        approver,
+        console,
+    )
+    .await
+}
+
+/// Reject a task waiting in the approval queue; the task is cancelled.
+///
+/// See [`approve_task`] for the meaning of `approver`.
+pub async fn reject_task(
+    task_id: &str,
+    reason: Option<&str>,
+    approver: Option<OperatorContext>,
+    console: ConsoleMode,
+) -> Result<TaskSnapshot, CommandFailure> {
+    decide_task(
+        task_id,
+        TaskApprovalVerdict::Reject,
+        reason,
+        approver,
+        console,
+    )
+    .await
+}
+
+async fn decide_task(
+    task_id: &str,
+    verdict: TaskApprovalVerdict,
+    note: Option<&str>,
+    approver: Option<OperatorContext>,
+    console: ConsoleMode,
+) -> Result<TaskSnapshot, CommandFailure> {
+    let task_id = task_id.trim();
+    let subcommand = match verdict {
+        TaskApprovalVerdict::Approve => "approve",
+        TaskApprovalVerdict::Reject => "reject",
+    };
+    run_typed_command(
+        &format!("/task {subcommand}"),
+        MainAction::Task,
+        Some(subcommand),
+        result_exit_status,
+        async {
+            let remote = call_remote_task_service(console, |client| async move {
+                match verdict {
+                    TaskApprovalVerdict::Approve => client.approve_task(task_id, note).await,
+                    TaskApprovalVerdict::Reject => client.reject_task(task_id, note).await,
+                }
+            })
+            .await;
+            match remote {
+                Some(result) => result.map_err(remote_failure),
+                None => {
+                    let approver = approver.unwrap_or_else(local_cli_operator);
+                    decide_task_approval(task_id, verdict, note, &approver, console)
+                        .await
+                        .map_err(CommandFailure::new)
+                }
+            }
+        },
+    )
+    .await
+}
+
+/// Read the effective value of a configuration key (file, then env overrides).
+pub async fn config_get(key: &str) -> Result<ConfigValueReport, CommandFailure> {
+    run_typed_command(
+        "/config get",
+        MainAction::Config,
+        Some("get"),
+        result_exit_status,
+        async {
+            let path = config_path()?;
+            let value = get_config_value(&AppConfig::load(), key).map_err(invalid_key)?;
+            Ok(ConfigValueReport {
+                key: key.to_string(),
+                value,
+                path,
+            })
+        },
+    )
+    .await
+}
+
+/// Persist a configuration value.
+pub async fn config_set(key: &str, value: &str) -> Result<ConfigValueReport, CommandFailure> {
+    run_typed_command(
+        "/config set",
+        MainAction::Config,
+        Some("set"),
+        result_exit_status,
+        async { update_config(key, |config| set_config_value(config, key, value)) },
+    )
+    .await
+}
+
+/// Reset a configuration key to its default and persist the result.
+pub async fn config_unset(key: &str) -> Result<ConfigValueReport, CommandFailure> {
+    run_typed_command(
+        "/config unset",
+        MainAction::Config,
+        Some("unset"),
+        result_exit_status,
+        async { update_config(key, |config| unset_config_value(config, key)) },
+    )
+    .await
+}
+
+fn update_config(
+    key: &str,
+    apply: impl FnOnce(&mut AppConfig) -> Result<(), String>,
+) -> Result<ConfigValueReport, CommandFailure> {
+    let path = config_path()?;
+    let mut config = load_persisted_or_default(&path);
+    apply(&mut config).map_err(invalid_key)?;
+    config
+        .save_to(&path)
+        .map_err(|error| CommandFailure::new(format!("Failed to save config: {error}")))?;
+
+    apply_runtime_ui_config(&config);
+    if is_ai_session_retention_key(key) {
+        apply_ai_session_retention_policy(config.general.ai_session_retention, ConsoleMode::Silent);
+    }
+
+    let value = get_config_value(&config, key).map_err(invalid_key)?;
+    Ok(ConfigValueReport {
+        key: key.to_string(),
+        value,
+        path,
+    })
+}
+
+fn config_path() -> Result<PathBuf, CommandFailure> {
+    AppConfig::default_config_path().ok_or_else(|| {
+        CommandFailure::new("Could not determine configuration path on this system.")
+    })
+}
+
+fn invalid_key(error: String) -> CommandFailure {
+    CommandFailure::new(format!("Invalid config value: {error}"))
+        .with_hint("Run `ntk config get --help` or `/config help` for supported keys.")
+}
+
+fn remote_failure(error: RemoteServiceError) -> CommandFailure {
+    CommandFailure::new(format!("Remote task request failed: {error}"))
+}
+
+fn result_exit_status<T>(result: &Result<T, CommandFailure>) -> ExitStatus {
+    if result.is_ok() {
+        ExitStatus::Success
+    } else {
+        ExitStatus::Error
+    }
+}
+
+/// Discover manifests under the working directory.
+pub async fn manifest_list() -> ExitStatus {
+    run_typed_command(
+        "/manifest list",
+        MainAction::Manifest,
+        Some("list"),
+        |status| *status,
+        async { processor::manifest_list(runtime_metrics()) },
+    )
+    .await
+}
+
+/// Validate a manifest (or template, with `is_template`).
+pub async fn manifest_check(path: PathBuf, is_template: bool) -> ExitStatus {
+    run_typed_command(
+        "/manifest check",
+        MainAction::Manifest,
+        Some("check"),
+        |status| *status,
+        processor::manifest_check(Some(path), is_template),
+    )
+    .await
+}
+
+/// Preview the files a manifest would generate without writing them.
+pub async fn manifest_render(path: PathBuf, output_root: Option<PathBuf>) -> ExitStatus {
+    run_typed_command(
+        "/manifest render",
+        MainAction::Manifest,
+        Some("render"),
+        |status| *status,
+        processor::manifest_render(Some(path), output_root),
+    )
+    .await
+}
+
+/// Apply a manifest, optionally as a dry-run.
+pub async fn manifest_apply(
+    path: PathBuf,
+    output_root: Option<PathBuf>,
+    dry_run: bool,
+) -> ExitStatus {
+    run_typed_command(
+        "/manifest apply",
+        MainAction::Manifest,
+        Some("apply"),
+        |status| *status,
+        processor::manifest_apply(Some(path), output_root, dry_run),
+    )
+    .await
+}
+
+/// Draft a manifest from existing C# sources, writing to `output` or stdout.
+pub async fn manifest_import(source_root: PathBuf, output: Option<PathBuf>) -> ExitStatus {
+    run_typed_command(
+        "/manifest import",
+        MainAction::Manifest,
+        Some("import"),
+        |status| *status,
+        async { processor::manifest_import(Some(source_root), output) },
+    )
+    .await
+}
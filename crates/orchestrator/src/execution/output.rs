@@ -0,0 +1,521 @@
+//! Structured command output
+//!
+//! Handlers describe their results as a [`CommandOutput`] — headings, text,
+//! key/value lists, tables, Markdown, diffs and errors — instead of printing
+//! directly. Sinks turn the same document into terminal text
+//! ([`TerminalSink`]), JSON ([`JsonSink`]) or chat messages
+//! ([`ChatOpsTextSink`]), so the interactive CLI, `--output json`, the HTTP
+//! service and ChatOps notifiers all report identical results.
+//!
+//! Outside a capture scope [`emit`] renders straight to the terminal, which
+//! keeps the interactive experience unchanged. Inside [`capture_output`] the
+//! blocks are collected and handed back to the caller instead. The capture
+//! scope is task-local: work spawned onto another task only reports into it
+//! when its future is wrapped with [`inherit_output_capture`] (the async
+//! executor does this); anything else spawned prints to the terminal.
+
+use super::chatops::ChatOpsPlatform;
+use nettoolskit_ui::Color;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Discord rejects messages longer than 2000 characters.
+const DISCORD_MESSAGE_MAX_CHARS: usize = 2000;
+/// Telegram rejects messages longer than 4096 characters.
+const TELEGRAM_MESSAGE_MAX_CHARS: usize = 4096;
+const TRUNCATION_MARKER: &str = "\n… (output truncated)";
+
+tokio::task_local! {
+    static CAPTURED_OUTPUT: Arc<Mutex<CommandOutput>>;
+}
+
+/// Visual intent of a heading or text block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputTone {
+    /// Neutral text.
+    #[default]
+    Plain,
+    /// Informational (section titles, progress).
+    Info,
+    /// Completed successfully.
+    Success,
+    /// Needs operator attention.
+    Warning,
+    /// Failed.
+    Error,
+}
+
+/// One `key: value` pair of an [`OutputBlock::KeyValues`] block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputKeyValue {
+    /// Field label.
+    pub key: String,
+    /// Field value.
+    pub value: String,
+}
+
+/// One renderable unit of a [`CommandOutput`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OutputBlock {
+    /// Section title.
+    Heading {
+        /// Title text.
+        text: String,
+        /// Visual intent.
+        #[serde(default)]
+        tone: OutputTone,
+    },
+    /// Free-form line(s) of text.
+    Text {
+        /// Text content.
+        text: String,
+        /// Visual intent.
+        #[serde(default)]
+        tone: OutputTone,
+    },
+    /// Ordered `key: value` list.
+    KeyValues {
+        /// Entries in display order.
+        entries: Vec<OutputKeyValue>,
+    },
+    /// Table with a header row.
+    Table {
+        /// Column headers.
+        headers: Vec<String>,
+        /// Rows; each row has one cell per header.
+        rows: Vec<Vec<String>>,
+    },
+    /// Markdown document (for example an AI response).
+    Markdown {
+        /// Markdown source.
+        markdown: String,
+    },
+    /// Unified diff.
+    Diff {
+        /// File the diff applies to, when known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+        /// Diff body in unified format.
+        diff: String,
+    },
+    /// Failure with optional remediation hints.
+    Error {
+        /// What went wrong.
+        message: String,
+        /// Follow-up suggestions.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        hints: Vec<String>,
+    },
+}
+
+/// Structured result of a command, independent of how it is displayed.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CommandOutput {
+    /// Blocks in display order.
+    pub blocks: Vec<OutputBlock>,
+}
+
+impl CommandOutput {
+    /// Empty output.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a heading.
+    #[must_use]
+    pub fn heading(self, text: impl Into<String>, tone: OutputTone) -> Self {
+        self.with_block(OutputBlock::Heading {
+            text: text.into(),
+            tone,
+        })
+    }
+
+    /// Append a text block.
+    #[must_use]
+    pub fn text(self, text: impl Into<String>, tone: OutputTone) -> Self {
+        self.with_block(OutputBlock::Text {
+            text: text.into(),
+            tone,
+        })
+    }
+
+    /// Append a `key: value` list.
+    #[must_use]
+    pub fn key_values<K, V>(self, entries: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.with_block(OutputBlock::KeyValues {
+            entries: entries
+                .into_iter()
+                .map(|(key, value)| OutputKeyValue {
+                    key: key.into(),
+                    value: value.into(),
+                })
+                .collect(),
+        })
+    }
+
+    /// Append a table.
+    #[must_use]
+    pub fn table<H>(self, headers: impl IntoIterator<Item = H>, rows: Vec<Vec<String>>) -> Self
+    where
+        H: Into<String>,
+    {
+        self.with_block(OutputBlock::Table {
+            headers: headers.into_iter().map(Into::into).collect(),
+            rows,
+        })
+    }
+
+    /// Append a Markdown document.
+    #[must_use]
+    pub fn markdown(self, markdown: impl Into<String>) -> Self {
+        self.with_block(OutputBlock::Markdown {
+            markdown: markdown.into(),
+        })
+    }
+
+    /// Append a unified diff.
+    #[must_use]
+    pub fn diff(self, path: Option<String>, diff: impl Into<String>) -> Self {
+        self.with_block(OutputBlock::Diff {
+            path,
+            diff: diff.into(),
+        })
+    }
+
+    /// Append an error with remediation hints.
+    #[must_use]
+    pub fn error(self, message: impl Into<String>, hints: Vec<String>) -> Self {
+        self.with_block(OutputBlock::Error {
+            message: message.into(),
+            hints,
+        })
+    }
+
+    /// Append an arbitrary block.
+    #[must_use]
+    pub fn with_block(mut self, block: OutputBlock) -> Self {
+        self.blocks.push(block);
+        self
+    }
+
+    /// Append every block of `other`.
+    pub fn extend(&mut self, other: CommandOutput) {
+        self.blocks.extend(other.blocks);
+    }
+
+    /// `true` when no block was recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// First error block, if any.
+    #[must_use]
+    pub fn first_error(&self) -> Option<&str> {
+        self.blocks.iter().find_map(|block| match block {
+            OutputBlock::Error { message, .. } => Some(message.as_str()),
+            _ => None,
+        })
+    }
+}
+
+/// Renders a [`CommandOutput`] for one destination.
+pub trait OutputSink {
+    /// Render the whole document.
+    fn render(&self, output: &CommandOutput) -> String;
+}
+
+/// Colored terminal rendering through `nettoolskit_ui`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TerminalSink;
+
+impl TerminalSink {
+    pub(super) fn tone_color(tone: OutputTone) -> owo_colors::Rgb {
+        match tone {
+            OutputTone::Plain => Color::WHITE,
+            OutputTone::Info => Color::CYAN,
+            OutputTone::Success => Color::GREEN,
+            OutputTone::Warning => Color::YELLOW,
+            OutputTone::Error => Color::RED,
+        }
+    }
+
+    fn render_block(block: &OutputBlock, out: &mut String) {
+        match block {
+            OutputBlock::Heading { text, tone } => {
+                let color = match tone {
+                    OutputTone::Plain => Color::CYAN,
+                    other => Self::tone_color(*other),
+                };
+                push_line(out, text.color(color).bold().to_string());
+            }
+            OutputBlock::Text { text, tone } => match tone {
+                OutputTone::Plain => push_line(out, text.clone()),
+                other => push_line(out, text.color(Self::tone_color(*other)).to_string()),
+            },
+            OutputBlock::KeyValues { entries } => {
+                for entry in entries {
+                    push_line(
+                        out,
+                        format!("  {}: {}", entry.key, entry.value.color(Color::WHITE)),
+                    );
+                }
+            }
+            OutputBlock::Table { headers, rows } => {
+                let widths = column_widths(headers, rows);
+                push_line(
+                    out,
+                    format!("  {}", pad_row(headers, &widths))
+                        .bold()
+                        .to_string(),
+                );
+                for row in rows {
+                    push_line(out, format!("  {}", pad_row(row, &widths)));
+                }
+            }
+            OutputBlock::Markdown { markdown } => {
+                let rendered = nettoolskit_ui::render_markdown(markdown);
+                push_line(out, rendered.trim_end().to_string());
+            }
+            OutputBlock::Diff { path, diff } => {
+                if let Some(path) = path {
+                    push_line(out, path.color(Color::CYAN).bold().to_string());
+                }
+                for line in diff.lines() {
+                    let styled = if line.starts_with("@@") {
+                        line.color(Color::CYAN).to_string()
+                    } else if line.starts_with('+') && !line.starts_with("+++") {
+                        line.color(Color::GREEN).to_string()
+                    } else if line.starts_with('-') && !line.starts_with("---") {
+                        line.color(Color::RED).to_string()
+                    } else {
+                        line.to_string()
+                    };
+                    push_line(out, styled);
+                }
+            }
+            OutputBlock::Error { message, hints } => {
+                push_line(
+                    out,
+                    format!("✗ {message}").color(Color::RED).bold().to_string(),
+                );
+                for hint in hints {
+                    push_line(out, hint.color(Color::YELLOW).to_string());
+                }
+            }
+        }
+    }
+}
+
+impl OutputSink for TerminalSink {
+    fn render(&self, output: &CommandOutput) -> String {
+        let mut out = String::new();
+        for block in &output.blocks {
+            Self::render_block(block, &mut out);
+        }
+        out
+    }
+}
+
+/// Compact JSON rendering (one document per line).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonSink;
+
+impl OutputSink for JsonSink {
+    fn render(&self, output: &CommandOutput) -> String {
+        serde_json::to_string(output).unwrap_or_else(|_| r#"{"blocks":[]}"#.to_string())
+    }
+}
+
+/// Plain-text rendering for ChatOps notifiers, formatted for the target platform.
+///
+/// Discord receives Markdown (bold headings, fenced tables and diffs); Telegram
+/// receives plain text because notifications are sent without a parse mode.
+/// Messages are truncated to the platform limit.
+#[derive(Debug, Clone, Copy)]
+pub struct ChatOpsTextSink {
+    platform: ChatOpsPlatform,
+}
+
+impl ChatOpsTextSink {
+    /// Sink for `platform`.
+    #[must_use]
+    pub const fn new(platform: ChatOpsPlatform) -> Self {
+        Self { platform }
+    }
+
+    /// Maximum message length accepted by the platform.
+    #[must_use]
+    pub const fn max_chars(&self) -> usize {
+        match self.platform {
+            ChatOpsPlatform::Discord => DISCORD_MESSAGE_MAX_CHARS,
+            ChatOpsPlatform::Telegram => TELEGRAM_MESSAGE_MAX_CHARS,
+        }
+    }
+
+    fn fenced(&self, language: &str, body: &str, out: &mut String) {
+        match self.platform {
+            ChatOpsPlatform::Discord => {
+                push_line(out, format!("```{language}"));
+                push_line(out, body.trim_end().to_string());
+                push_line(out, "```".to_string());
+            }
+            ChatOpsPlatform::Telegram => push_line(out, body.trim_end().to_string()),
+        }
+    }
+
+    fn render_block(&self, block: &OutputBlock, out: &mut String) {
+        let discord = matches!(self.platform, ChatOpsPlatform::Discord);
+        match block {
+            OutputBlock::Heading { text, .. } if discord => push_line(out, format!("**{text}**")),
+            OutputBlock::Heading { text, .. } | OutputBlock::Text { text, .. } => {
+                push_line(out, text.clone());
+            }
+            OutputBlock::KeyValues { entries } => {
+                for entry in entries {
+                    push_line(out, format!("• {}: {}", entry.key, entry.value));
+                }
+            }
+            OutputBlock::Table { headers, rows } => {
+                let widths = column_widths(headers, rows);
+                let mut table = pad_row(headers, &widths);
+                for row in rows {
+                    table.push('\n');
+                    table.push_str(&pad_row(row, &widths));
+                }
+                self.fenced("", &table, out);
+            }
+            OutputBlock::Markdown { markdown } => push_line(out, markdown.trim_end().to_string()),
+            OutputBlock::Diff { path, diff } => {
+                if let Some(path) = path {
+                    push_line(out, path.clone());
+                }
+                self.fenced("diff", diff, out);
+            }
+            OutputBlock::Error { message, hints } => {
+                push_line(out, format!("✗ {message}"));
+                for hint in hints {
+                    push_line(out, hint.clone());
+                }
+            }
+        }
+    }
+}
+
+impl OutputSink for ChatOpsTextSink {
+    fn render(&self, output: &CommandOutput) -> String {
+        let mut out = String::new();
+        for block in &output.blocks {
+            self.render_block(block, &mut out);
+        }
+        truncate_chars(out.trim_end(), self.max_chars())
+    }
+}
+
+fn push_line(out: &mut String, line: String) {
+    out.push_str(&line);
+    out.push('\n');
+}
+
+fn column_widths(headers: &[String], rows: &[Vec<String>]) -> Vec<usize> {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (index, cell) in row.iter().enumerate() {
+            let width = cell.chars().count();
+            match widths.get_mut(index) {
+                Some(current) => *current = (*current).max(width),
+                None => widths.push(width),
+            }
+        }
+    }
+    widths
+}
+
+fn pad_row(cells: &[String], widths: &[usize]) -> String {
+    let mut line = String::new();
+    for (index, cell) in cells.iter().enumerate() {
+        if index > 0 {
+            line.push_str("  ");
+        }
+        line.push_str(cell);
+        let is_last = index + 1 == cells.len();
+        if !is_last {
+            let width = widths.get(index).copied().unwrap_or(0);
+            let padding = width.saturating_sub(cell.chars().count());
+            line.push_str(&" ".repeat(padding));
+        }
+    }
+    line
+}
+
+/// Truncate `text` to at most `max_chars` characters, marking the cut.
+pub(crate) fn truncate_chars(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let keep = max_chars.saturating_sub(TRUNCATION_MARKER.chars().count());
+    let mut truncated: String = text.chars().take(keep).collect();
+    truncated.push_str(TRUNCATION_MARKER);
+    truncated
+}
+
+/// Run `future`, collecting everything it [`emit`]s instead of printing it.
+///
+/// Nested scopes collect independently; the innermost scope wins.
+pub async fn capture_output<F>(future: F) -> (F::Output, CommandOutput)
+where
+    F: Future,
+{
+    let captured = Arc::new(Mutex::new(CommandOutput::new()));
+    let result = CAPTURED_OUTPUT.scope(Arc::clone(&captured), future).await;
+    let output = std::mem::take(&mut *captured.lock().unwrap_or_else(PoisonError::into_inner));
+    (result, output)
+}
+
+/// Make `future` report into the caller's [`capture_output`] scope, if any.
+///
+/// Wrap futures with this before handing them to `tokio::spawn`; blocks emitted
+/// after the capture scope has returned are dropped.
+pub fn inherit_output_capture<F>(future: F) -> impl Future<Output = F::Output>
+where
+    F: Future,
+{
+    let captured = CAPTURED_OUTPUT.try_with(Arc::clone).ok();
+    async move {
+        match captured {
+            Some(captured) => CAPTURED_OUTPUT.scope(captured, future).await,
+            None => future.await,
+        }
+    }
+}
+
+/// `true` when the current task runs inside [`capture_output`].
+#[must_use]
+pub fn is_capturing_output() -> bool {
+    CAPTURED_OUTPUT.try_with(|_| ()).is_ok()
+}
+
+/// Hand `output` to the active capture scope, or print it to the terminal.
+pub fn emit(output: CommandOutput) {
+    let mut pending = Some(output);
+    let _ = CAPTURED_OUTPUT.try_with(|captured| {
+        if let Some(output) = pending.take() {
+            captured
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .extend(output);
+        }
+    });
+    if let Some(output) = pending {
+        print!("{}", TerminalSink.render(&output));
+    }
+}
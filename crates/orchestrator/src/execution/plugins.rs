@@ -1,14 +1,21 @@
 //! Plugin foundation for command orchestration.
 //!
 //! This module provides a deterministic in-process plugin registry that can
-//! hook command execution before and after command dispatch.
+//! hook command execution before and after command dispatch, and lets plugins
+//! contribute their own slash commands to the command registry.
 
-use crate::models::ExitStatus;
+use super::output::CommandOutput;
+use crate::models::command_spec::{command_specs, find_command_spec, split_arguments};
+use crate::models::{CommandSpec, ExitStatus};
+use nettoolskit_core::PolicySource;
 use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
 use std::panic::{self, AssertUnwindSafe};
 use std::sync::{Arc, Mutex, OnceLock};
 
+/// Scopes plugin commands may run with (`*` or unset allows every scope).
+pub const NTK_PLUGIN_ALLOWED_SCOPES_ENV: &str = "NTK_PLUGIN_ALLOWED_SCOPES";
+
 /// Immutable plugin metadata used for registration and listing.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PluginMetadata {
@@ -56,6 +63,65 @@ pub struct CommandHookContext {
     pub status: Option<ExitStatus>,
 }
 
+/// Parsed invocation of a plugin-provided command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandInvocation {
+    /// Correlation id for current command execution.
+    pub correlation_id: String,
+    /// Raw command string.
+    pub command: String,
+    /// Registered command name (`/deploy`).
+    pub name: &'static str,
+    /// Matched subcommand, when the spec declares subcommands.
+    pub subcommand: Option<&'static str>,
+    /// Positional arguments after the command and subcommand.
+    pub positionals: Vec<String>,
+    /// Flags in order, with the value that followed value-taking ones.
+    pub flags: Vec<(String, Option<String>)>,
+}
+
+impl CommandInvocation {
+    /// Parse `command` against `spec`.
+    pub fn parse(spec: &'static CommandSpec, command: &str, correlation_id: &str) -> Self {
+        let parts: Vec<&str> = command.split_whitespace().collect();
+        let rest = parts.get(1..).unwrap_or_default();
+        let subcommand = rest.first().and_then(|name| spec.subcommand(name));
+        let parsed = match subcommand {
+            Some(subcommand) => split_arguments(&rest[1..], subcommand.args, subcommand.flags),
+            None => split_arguments(rest, spec.args, spec.flags),
+        };
+        Self {
+            correlation_id: correlation_id.to_string(),
+            command: command.to_string(),
+            name: spec.name,
+            subcommand: subcommand.map(|subcommand| subcommand.name),
+            positionals: parsed
+                .positionals
+                .iter()
+                .map(|part| (*part).to_string())
+                .collect(),
+            flags: parsed
+                .flags
+                .iter()
+                .map(|(name, value)| ((*name).to_string(), value.map(str::to_string)))
+                .collect(),
+        }
+    }
+
+    /// Whether `flag` was given.
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|(name, _)| name == flag)
+    }
+
+    /// Value given to `flag`, when present.
+    pub fn flag_value(&self, flag: &str) -> Option<&str> {
+        self.flags
+            .iter()
+            .find(|(name, _)| name == flag)
+            .and_then(|(_, value)| value.as_deref())
+    }
+}
+
 /// Hook invocation stats for metrics and diagnostics.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct PluginHookStats {
@@ -74,6 +140,10 @@ pub enum PluginRegistryError {
     DuplicateId(String),
     /// Plugin id was not found.
     NotFound(String),
+    /// A contributed command name is not a `/name` token.
+    InvalidCommand(String),
+    /// A contributed command is already provided by the CLI or another plugin.
+    CommandConflict(String),
 }
 
 impl Display for PluginRegistryError {
@@ -82,6 +152,10 @@ impl Display for PluginRegistryError {
             Self::InvalidId => write!(f, "plugin id must not be empty"),
             Self::DuplicateId(id) => write!(f, "plugin '{id}' is already registered"),
             Self::NotFound(id) => write!(f, "plugin '{id}' was not found"),
+            Self::InvalidCommand(name) => {
+                write!(f, "plugin command '{name}' must be a single /name token")
+            }
+            Self::CommandConflict(name) => write!(f, "command '{name}' is already registered"),
         }
     }
 }
@@ -102,6 +176,19 @@ pub trait CommandPlugin: Send + Sync {
     fn on_after_command(&self, _context: &CommandHookContext) -> Result<(), String> {
         Ok(())
     }
+
+    /// Slash commands this plugin adds to the command registry.
+    ///
+    /// They show up in help, completion and the palette like built-in
+    /// commands and are dispatched to [`Self::execute_command`].
+    fn commands(&self) -> &'static [CommandSpec] {
+        &[]
+    }
+
+    /// Run one of [`Self::commands`].
+    fn execute_command(&self, invocation: &CommandInvocation) -> Result<CommandOutput, String> {
+        Err(format!("plugin does not implement {}", invocation.name))
+    }
 }
 
 struct RegisteredPlugin {
@@ -130,6 +217,16 @@ impl PluginRegistry {
             return Err(PluginRegistryError::DuplicateId(metadata.id));
         }
 
+        for spec in plugin.commands() {
+            let bare = spec.name.strip_prefix('/').unwrap_or_default();
+            if bare.is_empty() || bare.contains(char::is_whitespace) {
+                return Err(PluginRegistryError::InvalidCommand(spec.name.to_string()));
+            }
+            if find_command_spec(spec.name).is_some() || self.find_command(spec.name).is_some() {
+                return Err(PluginRegistryError::CommandConflict(spec.name.to_string()));
+            }
+        }
+
         let key = metadata.id.clone();
         let registered = RegisteredPlugin {
             metadata: metadata.clone(),
@@ -173,6 +270,34 @@ impl PluginRegistry {
             .collect()
     }
 
+    /// Command specs contributed by enabled plugins, in plugin id order.
+    pub fn command_specs(&self) -> Vec<&'static CommandSpec> {
+        self.plugins
+            .values()
+            .filter(|plugin| plugin.enabled)
+            .flat_map(|plugin| plugin.plugin.commands())
+            .collect()
+    }
+
+    /// Enabled plugin providing the command named `name`.
+    pub fn find_command(
+        &self,
+        name: &str,
+    ) -> Option<(Arc<dyn CommandPlugin>, &'static CommandSpec)> {
+        let bare = name.trim().trim_start_matches('/');
+        self.plugins
+            .values()
+            .filter(|plugin| plugin.enabled)
+            .find_map(|plugin| {
+                plugin
+                    .plugin
+                    .commands()
+                    .iter()
+                    .find(|spec| spec.name[1..].eq_ignore_ascii_case(bare))
+                    .map(|spec| (Arc::clone(&plugin.plugin), spec))
+            })
+    }
+
     /// Execute before-command hooks for all enabled plugins.
     pub fn run_before_hooks(&self, context: &CommandHookContext) -> PluginHookStats {
         self.run_hooks("before", context, |plugin, hook_context| {
@@ -274,6 +399,57 @@ pub fn run_after_command_plugins(context: &CommandHookContext) -> PluginHookStat
     with_plugin_registry(|registry| registry.run_after_hooks(context))
 }
 
+/// Built-in command specs followed by those of enabled plugins.
+pub fn registered_command_specs() -> Vec<&'static CommandSpec> {
+    let mut specs: Vec<&'static CommandSpec> = command_specs().iter().collect();
+    specs.extend(with_plugin_registry(|registry| registry.command_specs()));
+    specs
+}
+
+/// Built-in or plugin command named `name`, with or without its slash.
+pub fn find_registered_command_spec(name: &str) -> Option<&'static CommandSpec> {
+    find_command_spec(name).or_else(|| {
+        with_plugin_registry(|registry| registry.find_command(name)).map(|(_, spec)| spec)
+    })
+}
+
+/// Run the plugin command named by the first token of `command`.
+///
+/// Returns `None` when no enabled plugin provides that command. Commands whose
+/// scope [`NTK_PLUGIN_ALLOWED_SCOPES_ENV`] does not grant are refused. The plugin
+/// runs outside the registry lock; a panic is reported as an error.
+pub fn run_plugin_command(
+    command: &str,
+    correlation_id: &str,
+) -> Option<Result<CommandOutput, String>> {
+    let name = command.split_whitespace().next()?;
+    let (plugin, spec) = with_plugin_registry(|registry| registry.find_command(name))?;
+    let invocation = CommandInvocation::parse(spec, command, correlation_id);
+    let scope = spec.scope_for(invocation.subcommand);
+    let allowed = PolicySource::load()
+        .var(NTK_PLUGIN_ALLOWED_SCOPES_ENV)
+        .unwrap_or_default();
+    if !plugin_scope_allowed(&allowed, scope) {
+        return Some(Err(format!(
+            "plugin command {} requires scope `{scope}`, which {NTK_PLUGIN_ALLOWED_SCOPES_ENV} does not grant",
+            spec.name
+        )));
+    }
+    let result = panic::catch_unwind(AssertUnwindSafe(|| plugin.execute_command(&invocation)));
+    Some(result.unwrap_or_else(|_| Err(format!("plugin command {} panicked", spec.name))))
+}
+
+/// Whether the comma-separated `allowed` list grants `scope`; an empty list or `*` grants all.
+fn plugin_scope_allowed(allowed: &str, scope: &str) -> bool {
+    let mut entries = allowed
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .peekable();
+    entries.peek().is_none()
+        || entries.any(|entry| entry == "*" || entry.eq_ignore_ascii_case(scope))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -416,6 +592,129 @@ mod tests {
         assert_eq!(after.load(Ordering::SeqCst), 0);
     }
 
+    struct CommandProvider {
+        id: &'static str,
+        commands: &'static [CommandSpec],
+    }
+
+    impl CommandPlugin for CommandProvider {
+        fn metadata(&self) -> PluginMetadata {
+            PluginMetadata::new(self.id, "Provider", "1.0.0", "test plugin")
+        }
+
+        fn commands(&self) -> &'static [CommandSpec] {
+            self.commands
+        }
+    }
+
+    const DEPLOY: &[CommandSpec] = &[CommandSpec {
+        name: "/deploy",
+        aliases: &[],
+        description: "Deploy the workspace",
+        args: &[],
+        flags: &[],
+        subcommands: &[],
+        scope: "deploy",
+        handler: None,
+    }];
+
+    #[test]
+    fn registry_rejects_conflicting_plugin_commands() {
+        const HELP: &[CommandSpec] = &[CommandSpec {
+            name: "/help",
+            ..DEPLOY[0]
+        }];
+        const BAD: &[CommandSpec] = &[CommandSpec {
+            name: "deploy now",
+            ..DEPLOY[0]
+        }];
+
+        let mut registry = PluginRegistry::default();
+        let builtin = registry.register(Arc::new(CommandProvider {
+            id: "com.example.help",
+            commands: HELP,
+        }));
+        assert_eq!(
+            builtin,
+            Err(PluginRegistryError::CommandConflict("/help".to_string()))
+        );
+        let invalid = registry.register(Arc::new(CommandProvider {
+            id: "com.example.bad",
+            commands: BAD,
+        }));
+        assert_eq!(
+            invalid,
+            Err(PluginRegistryError::InvalidCommand(
+                "deploy now".to_string()
+            ))
+        );
+
+        registry
+            .register(Arc::new(CommandProvider {
+                id: "com.example.deploy",
+                commands: DEPLOY,
+            }))
+            .expect("first provider registers");
+        let duplicate = registry.register(Arc::new(CommandProvider {
+            id: "com.example.deploy2",
+            commands: DEPLOY,
+        }));
+        assert_eq!(
+            duplicate,
+            Err(PluginRegistryError::CommandConflict("/deploy".to_string()))
+        );
+    }
+
+    #[test]
+    fn disabled_plugins_do_not_contribute_commands() {
+        let mut registry = PluginRegistry::default();
+        registry
+            .register(Arc::new(CommandProvider {
+                id: "com.example.deploy",
+                commands: DEPLOY,
+            }))
+            .expect("registration must pass");
+        assert_eq!(registry.command_specs().len(), 1);
+        assert!(registry.find_command("deploy").is_some());
+
+        registry
+            .set_enabled("com.example.deploy", false)
+            .expect("toggle must pass");
+        assert!(registry.command_specs().is_empty());
+        assert!(registry.find_command("/deploy").is_none());
+    }
+
+    #[test]
+    fn plugin_scope_allowlist_grants_listed_or_wildcard_scopes() {
+        assert!(plugin_scope_allowed("", "deploy"));
+        assert!(plugin_scope_allowed(" , ", "deploy"));
+        assert!(plugin_scope_allowed("*", "deploy"));
+        assert!(plugin_scope_allowed("greet; Deploy", "deploy"));
+        assert!(!plugin_scope_allowed("greet", "deploy"));
+    }
+
+    #[test]
+    fn command_invocation_parses_flags_per_spec() {
+        use crate::models::{ArgKind, FlagSpec};
+
+        const TARGET: &[CommandSpec] = &[CommandSpec {
+            flags: &[FlagSpec {
+                name: "--env",
+                value: Some(ArgKind::Text),
+                description: "Target environment",
+            }],
+            ..DEPLOY[0]
+        }];
+        let invocation =
+            CommandInvocation::parse(&TARGET[0], "/deploy --env prod api --force", "cmd-9");
+
+        assert_eq!(invocation.name, "/deploy");
+        assert_eq!(invocation.subcommand, None);
+        assert_eq!(invocation.positionals, vec!["api"]);
+        assert_eq!(invocation.flag_value("--env"), Some("prod"));
+        assert!(invocation.has_flag("--force"));
+    }
+
     #[test]
     fn hook_failures_are_counted_and_non_blocking() {
         let before = Arc::new(AtomicUsize::new(0));
@@ -1,39 +1,79 @@
 //! Command processor implementation
 
 use crate::execution::ai::{
-    AiChunk, AiMessage, AiProvider, AiProviderError, AiRequest, AiResponse, AiRole, MockAiProvider,
-    OpenAiCompatibleProvider, OpenAiCompatibleProviderConfig,
+    AiChunk, AiMessage, AiProvider, AiProviderError, AiRequest, AiResponse, AiRole, AiUsage,
+    MockAiProvider, OpenAiCompatibleProvider, OpenAiCompatibleProviderConfig,
 };
 use crate::execution::ai_session::{
+    active_ai_session_id, format_epoch_ms_utc, list_local_ai_session_snapshots,
     prune_local_ai_session_snapshots, resolve_active_ai_session_id, set_active_ai_session_id,
-    LocalAiSessionState, NTK_AI_SESSION_COMPRESSION_MAX_CHARS_ENV,
-    NTK_AI_SESSION_COMPRESSION_MODE_ENV, NTK_AI_SESSION_DELTA_MIN_SHARED_PREFIX_CHARS_ENV,
+    LocalAiSessionState, AI_SESSION_SUMMARY_TRUNCATION_SOURCE,
+    NTK_AI_SESSION_COMPRESSION_MAX_CHARS_ENV, NTK_AI_SESSION_COMPRESSION_MODE_ENV,
+    NTK_AI_SESSION_DELTA_MIN_SHARED_PREFIX_CHARS_ENV,
 };
-use crate::execution::approval::{request_approval, ApprovalDecision, ApprovalRequest};
-use crate::execution::cache::{CacheKey, CacheStats, CacheTtl, CacheValue, CommandResultCache};
+use crate::execution::ai_spend::{
+    scoped_ai_spend_operator, summarize_ai_spend, total_ai_spend, with_ai_spend_operator,
+    AiModelPrice, AiPriceTable, AiSpendCaps, AiSpendEntry, AiSpendGrouping, AiSpendLedger,
+    AiUsageSource, NTK_AI_PRICE_TABLE_ENV, NTK_AI_SPEND_CAP_DAILY_USD_ENV,
+    NTK_AI_SPEND_CAP_MONTHLY_USD_ENV, NTK_AI_SPEND_LEDGER_PATH_ENV,
+};
+use crate::execution::approval::{
+    record_task_approval_decision, request_approval, ApprovalDecision, ApprovalRequest,
+    TaskApprovalPolicy, NTK_TASK_APPROVAL_RULES_ENV, NTK_TASK_APPROVERS_ENV,
+};
+use crate::execution::cache::{
+    CacheKey, CacheStats, CacheTtl, CacheValue, CommandResultCache, DirectorySnapshot,
+    PersistentCacheTier,
+};
+use crate::execution::chatops::{queue_task_completion, ChatOpsTaskCompletion, ChatOpsTaskOrigin};
 use crate::execution::executor::{AsyncCommandExecutor, CommandProgress, ProgressSender};
+use crate::execution::headless::{TaskAuditSnapshot, TaskSnapshot};
+use crate::execution::output::{
+    capture_output, emit, is_capturing_output, CommandOutput, OutputTone,
+};
 use crate::execution::plugins::{
-    command_plugin_count, run_after_command_plugins, run_before_command_plugins, CommandHookContext,
+    command_plugin_count, find_registered_command_spec, registered_command_specs,
+    run_after_command_plugins, run_before_command_plugins, run_plugin_command, CommandHookContext,
+};
+use crate::execution::remote::{
+    normalize_service_endpoint, remote_task_records_required, RemoteRequestContext,
+    RemoteServiceClient, RemoteServiceError, RemoteTaskSubmission,
 };
 use crate::execution::repo_workflow::{
     execute_repo_workflow, parse_repo_workflow_payload, RepoWorkflowPolicy,
 };
+use crate::execution::task_dashboard::run_task_dashboard;
+use crate::models::command_spec::{
+    find_command_by_alias, find_command_spec, find_subcommand_shortcut, task_intent_labels,
+    task_intent_spec, CommandContext, CommandFuture, CommandHandler, CommandSpec, ParsedArguments,
+    CONFIG_KEYS, LOG_LEVEL_VALUES, TASK_INTENTS,
+};
 use crate::models::{ExitStatus, MainAction};
 use nettoolskit_core::ai_context::{
-    collect_workspace_context, render_context_system_message, AiContextBudget,
+    collect_retrieved_context, collect_workspace_context_with, render_context_system_message,
+    AiContextBudget, AiContextBundle, AiContextMode,
 };
+use nettoolskit_core::context_index::{find_workspace_root, ContextIndex, RefreshStats};
 use nettoolskit_core::file_search::{search_files, SearchConfig};
 use nettoolskit_core::{
-    AppConfig, ApprovalState, ColorMode, CommandEntry, ControlEnvelope, ControlPolicyContext,
-    IngressTransport, OperatorContext, OperatorKind, RuntimeMode, SessionContext, SessionKind,
-    TaskAuditEvent, TaskExecutionStatus, TaskIntent, TaskIntentKind, UnicodeMode,
+    config_env_var, policy_setting, AppConfig, ApprovalState, ColorMode, CommandEntry,
+    ConfigLayers, ConfigOrigin, ControlEnvelope, ControlPolicyContext, EntropyThresholds,
+    IngressTransport, OperatorContext, OperatorKind, PolicySetting, PolicySource, PolicyValueKind,
+    RuntimeMode, SecretRedactor, SecretRef, SessionContext, SessionKind, TaskAuditEvent,
+    TaskExecutionStatus, TaskIntent, TaskIntentKind, TraceContext, UnicodeMode, POLICY_SECTIONS,
+    POLICY_SETTINGS,
 };
-use nettoolskit_otel::{next_correlation_id, Metrics, Timer};
+use nettoolskit_manifest::core::models::ManifestDocument;
+use nettoolskit_manifest::handlers::assist::extract_yaml_patch;
+use nettoolskit_manifest::handlers::{
+    find_nearest_manifest, review_manifest_patch, summarize_manifest,
+};
+use nettoolskit_otel::{next_correlation_id, set_span_parent, span_trace_context, Metrics, Timer};
 use nettoolskit_task_worker::{
     TaskWorkerCallbacks, TaskWorkerFuture, TaskWorkerPolicy, TaskWorkerResult,
     TaskWorkerResultStatus, TaskWorkerRuntime, TaskWorkerSubmitError,
 };
-use owo_colors::OwoColorize;
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::fs::{self, OpenOptions};
 use std::future::Future;
@@ -45,8 +85,25 @@ use std::sync::OnceLock;
 use std::sync::{Arc, Mutex};
 use std::time::SystemTime;
 use std::time::{Duration, Instant};
-use strum::IntoEnumIterator;
-use tracing::{info, info_span, warn};
+use tokio::sync::watch;
+use tracing::{info, info_span, warn, Instrument};
+
+pub(crate) mod ai;
+pub(crate) mod cache;
+pub(crate) mod config;
+pub(crate) mod help;
+pub(crate) mod manifest;
+pub(crate) mod session;
+pub(crate) mod task;
+
+pub(super) use self::config::{
+    apply_ai_session_retention_policy, apply_runtime_ui_config, get_config_value,
+    is_ai_session_retention_key, load_persisted_or_default, set_config_value, unset_config_value,
+};
+pub(super) use self::manifest::{
+    manifest_apply, manifest_check, manifest_import, manifest_list, manifest_render,
+};
+use self::{config::*, manifest::*, task::*};
 
 static RUNTIME_METRICS: OnceLock<Metrics> = OnceLock::new();
 static COMMAND_CACHE: OnceLock<Mutex<CommandResultCache>> = OnceLock::new();
@@ -55,10 +112,13 @@ static SERVICE_SUBMISSION_BUDGET: OnceLock<Mutex<ServiceSubmissionBudgetState>>
 static TASK_REGISTRY: OnceLock<Mutex<HashMap<String, TaskRecord>>> = OnceLock::new();
 static TASK_AUDIT_REGISTRY: OnceLock<Mutex<HashMap<String, Vec<TaskAuditEvent>>>> = OnceLock::new();
 static TASK_WORKER_RUNTIME: OnceLock<TaskWorkerRuntime<QueuedTask>> = OnceLock::new();
+static TASK_CHANGES: OnceLock<watch::Sender<u64>> = OnceLock::new();
 static TASK_SEQUENCE: AtomicU64 = AtomicU64::new(1);
 const COMMAND_CACHE_MAX_ENTRIES: usize = 128;
 const COMMAND_CACHE_MAX_SIZE_BYTES: usize = 2 * 1024 * 1024;
 const COMMAND_CACHE_LOG_INTERVAL_SECONDS: u64 = 30;
+const COMMAND_CACHE_PERSISTENT_DIR_NAME: &str = "command-cache";
+const DEFAULT_COMMAND_CACHE_PERSISTENT_MAX_BYTES: u64 = 32 * 1024 * 1024;
 const TASK_AUDIT_MAX_EVENTS_PER_TASK: usize = 32;
 const DEFAULT_SERVICE_SUBMIT_BUDGET: usize = 60;
 const DEFAULT_SERVICE_SUBMIT_WINDOW_SECONDS: u64 = 60;
@@ -89,6 +149,12 @@ const AI_CONTEXT_DEFAULT_ALLOWLIST: &[&str] = &[
     ".temp/planning/enterprise-progress-tracker.md",
 ];
 const AI_SESSION_CONTEXT_MESSAGE_LIMIT: usize = 12;
+const AI_SESSION_SUMMARY_KEEP_RECENT_EXCHANGES: usize = AI_SESSION_CONTEXT_MESSAGE_LIMIT / 2;
+const AI_SESSION_SUMMARY_TRANSCRIPT_MAX_CHARS: usize = 24_000;
+const AI_MANIFEST_SUMMARY_MAX_CHARS: usize = 4_000;
+const AI_SESSION_LIST_LIMIT: usize = 50;
+const AI_SESSION_SEARCH_LIMIT: usize = 50;
+const AI_USAGE_DEFAULT_SINCE: &str = "7d";
 const DEFAULT_AI_RATE_LIMIT_REQUESTS: usize = 30;
 const DEFAULT_AI_RATE_LIMIT_WINDOW_SECONDS: u64 = 60;
 const DEFAULT_AI_MAX_RETRIES: usize = 2;
@@ -269,8 +335,13 @@ impl Default for AiModelSelectionPolicy {
             enabled: true,
             cheap_model: None,
             reasoning_model: None,
-            cheap_intents: vec![AiIntent::Ask],
-            reasoning_intents: vec![AiIntent::Plan, AiIntent::Explain, AiIntent::ApplyDryRun],
+            cheap_intents: vec![AiIntent::Ask, AiIntent::SessionSummary],
+            reasoning_intents: vec![
+                AiIntent::Plan,
+                AiIntent::Explain,
+                AiIntent::ApplyDryRun,
+                AiIntent::Manifest,
+            ],
             cheap_cost_multiplier: DEFAULT_AI_MODEL_SELECTION_CHEAP_COST_MULTIPLIER,
             reasoning_cost_multiplier: DEFAULT_AI_MODEL_SELECTION_REASONING_COST_MULTIPLIER,
             cheap_cost_cap_usd: None,
@@ -690,31 +761,16 @@ fn tool_scope_intent_env(intent: TaskIntentKind) -> &'static str {
     }
 }
 
+/// Tools the registry declares for `intent` (see [`TASK_INTENTS`]).
 fn default_tool_scope_for_intent(intent: TaskIntentKind) -> HashSet<ToolCapability> {
-    let mut scope = HashSet::new();
-    match intent {
-        TaskIntentKind::AiAsk => {
-            scope.insert(ToolCapability::AiAsk);
-        }
-        TaskIntentKind::AiPlan => {
-            scope.insert(ToolCapability::AiPlan);
-        }
-        TaskIntentKind::AiExplain => {
-            scope.insert(ToolCapability::AiExplain);
-        }
-        TaskIntentKind::AiApplyDryRun => {
-            scope.insert(ToolCapability::AiApplyDryRun);
-        }
-        TaskIntentKind::CommandExecution => {
-            scope.insert(ToolCapability::CommandExecution);
-        }
-        TaskIntentKind::RepoWorkflow => {
-            scope.insert(ToolCapability::RepoWorkflowExecute);
-            scope.insert(ToolCapability::RepoWorkflowPush);
-            scope.insert(ToolCapability::RepoWorkflowPullRequest);
-        }
-    }
-    scope
+    task_intent_spec(task_intent_kind_label(intent))
+        .map(|spec| {
+            spec.tools
+                .iter()
+                .filter_map(|tool| ToolCapability::parse(tool))
+                .collect()
+        })
+        .unwrap_or_default()
 }
 
 fn parse_tool_capability_set(value: &str) -> HashSet<ToolCapability> {
@@ -786,15 +842,16 @@ fn sorted_tool_capability_labels(scopes: &[ToolCapability]) -> Vec<String> {
 }
 
 fn tool_scope_policy_from_env(runtime_mode: RuntimeMode) -> ToolScopePolicy {
+    let source = PolicySource::load();
     let mut policy = ToolScopePolicy::for_runtime(runtime_mode);
 
-    if let Ok(value) = std::env::var(NTK_TOOL_SCOPE_ENABLED_ENV) {
+    if let Some(value) = source.var(NTK_TOOL_SCOPE_ENABLED_ENV) {
         if let Some(parsed) = parse_bool(&value) {
             policy.enabled = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_TOOL_SCOPE_ALLOWED_TOOLS_ENV) {
+    if let Some(value) = source.var(NTK_TOOL_SCOPE_ALLOWED_TOOLS_ENV) {
         policy.allowed_tools = parse_tool_capability_set(&value);
     }
 
@@ -806,7 +863,7 @@ fn tool_scope_policy_from_env(runtime_mode: RuntimeMode) -> ToolScopePolicy {
         TaskIntentKind::AiApplyDryRun,
         TaskIntentKind::RepoWorkflow,
     ] {
-        if let Ok(value) = std::env::var(tool_scope_intent_env(intent)) {
+        if let Some(value) = source.var(tool_scope_intent_env(intent)) {
             policy.set_intent_scope(intent, parse_tool_capability_set(&value));
         }
     }
@@ -858,7 +915,7 @@ fn evaluate_tool_scope_policy(
 }
 
 fn resolve_tool_scope_audit_path() -> Option<PathBuf> {
-    if let Ok(path_override) = std::env::var(NTK_TOOL_SCOPE_AUDIT_PATH_ENV) {
+    if let Some(path_override) = PolicySource::load().var(NTK_TOOL_SCOPE_AUDIT_PATH_ENV) {
         let trimmed = path_override.trim();
         if !trimmed.is_empty() {
             return Some(PathBuf::from(trimmed));
@@ -979,6 +1036,9 @@ struct TaskRecord {
     max_attempts: usize,
     created_at_unix_ms: u64,
     updated_at_unix_ms: u64,
+    output: Option<CommandOutput>,
+    chatops_origin: Option<ChatOpsTaskOrigin>,
+    approval_gate: Option<String>,
 }
 
 impl TaskRecord {
@@ -1001,13 +1061,64 @@ impl TaskRecord {
             max_attempts: max_attempts.max(1),
             created_at_unix_ms: now_unix_ms,
             updated_at_unix_ms: now_unix_ms,
+            output: None,
+            chatops_origin: None,
+            approval_gate: None,
         }
     }
 
     fn with_control_envelope(mut self, control_envelope: ControlEnvelope) -> Self {
+        self.chatops_origin = ChatOpsTaskOrigin::from_control_envelope(&control_envelope);
         self.control_envelope = Some(control_envelope);
         self
     }
+
+    fn snapshot(&self, audit_events: Vec<TaskAuditEvent>) -> TaskSnapshot {
+        TaskSnapshot {
+            id: self.id.clone(),
+            intent: task_intent_kind_label(self.intent.kind).to_string(),
+            title: self.intent.title.clone(),
+            payload: self.intent.payload.clone(),
+            status: self.status,
+            runtime_mode: self.runtime_mode,
+            execution_target: self.execution_target.clone(),
+            detail: self.status_message.clone(),
+            attempts: self.attempts,
+            max_attempts: self.max_attempts,
+            created_at_unix_ms: self.created_at_unix_ms,
+            updated_at_unix_ms: self.updated_at_unix_ms,
+            request_id: self
+                .control_envelope
+                .as_ref()
+                .map(|control| control.request_id.clone()),
+            correlation_id: self
+                .control_envelope
+                .as_ref()
+                .and_then(|control| control.correlation_id.clone()),
+            operator: self
+                .control_envelope
+                .as_ref()
+                .map(|control| control.operator.id.clone()),
+            audit_events: audit_events
+                .into_iter()
+                .map(|event| TaskAuditSnapshot {
+                    status: event.status,
+                    message: event.message,
+                    timestamp_unix_ms: event.timestamp_unix_ms,
+                })
+                .collect(),
+            output: self.output.clone(),
+            approval_gate: self.approval_gate.clone(),
+        }
+    }
+
+    /// Snapshot for list views: no audit trail and no execution output.
+    fn summary_snapshot(&self) -> TaskSnapshot {
+        TaskSnapshot {
+            output: None,
+            ..self.snapshot(Vec::new())
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1015,6 +1126,7 @@ struct QueuedTask {
     id: String,
     intent: TaskIntent,
     runtime_mode: RuntimeMode,
+    trace_context: Option<TraceContext>,
 }
 
 /// Structured outcome returned by typed task submission entrypoints.
@@ -1028,15 +1140,21 @@ pub struct TaskSubmissionOutcome {
     pub task_status: Option<TaskExecutionStatus>,
     /// Runtime mode used for submission.
     pub runtime_mode: RuntimeMode,
+    /// Policy or admission reason when the submission was rejected before a record existed.
+    pub rejection_reason: Option<String>,
+    /// Service endpoint that owns the task when it was dispatched remotely.
+    pub remote_endpoint: Option<String>,
 }
 
 impl TaskSubmissionOutcome {
-    fn rejected(runtime_mode: RuntimeMode, exit_status: ExitStatus) -> Self {
+    fn rejected(runtime_mode: RuntimeMode, exit_status: ExitStatus, reason: String) -> Self {
         Self {
             task_id: None,
             exit_status,
             task_status: None,
             runtime_mode,
+            rejection_reason: Some(reason),
+            remote_endpoint: None,
         }
     }
 
@@ -1051,24 +1169,210 @@ impl TaskSubmissionOutcome {
             exit_status,
             task_status: Some(task_status),
             runtime_mode,
+            rejection_reason: None,
+            remote_endpoint: None,
+        }
+    }
+
+    fn from_remote(submission: RemoteTaskSubmission, endpoint: &str) -> Self {
+        match submission.task_id {
+            Some(task_id) => {
+                let task_status = submission
+                    .task_status
+                    .unwrap_or(TaskExecutionStatus::Queued);
+                Self {
+                    remote_endpoint: Some(endpoint.to_string()),
+                    ..Self::accepted(
+                        task_id,
+                        task_status_to_exit_status(task_status),
+                        task_status,
+                        RuntimeMode::Service,
+                    )
+                }
+            }
+            None => Self {
+                remote_endpoint: Some(endpoint.to_string()),
+                ..Self::rejected(
+                    RuntimeMode::Service,
+                    ExitStatus::Error,
+                    submission
+                        .rejection_reason
+                        .unwrap_or_else(|| "Remote service rejected the task".to_string()),
+                )
+            },
+        }
+    }
+}
+
+/// Console behavior for typed command entrypoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConsoleMode {
+    /// Print progress and results for interactive terminals.
+    #[default]
+    Text,
+    /// Keep stdout clean; callers render the returned report themselves.
+    Silent,
+}
+
+impl ConsoleMode {
+    pub(super) fn is_text(self) -> bool {
+        matches!(self, Self::Text)
+    }
+}
+
+/// Structured result of a completed AI request.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AiCommandReport {
+    /// Intent label (`ask`, `plan`, `explain`, `apply`).
+    pub intent: String,
+    /// Active local AI session that recorded the exchange.
+    pub session_id: String,
+    /// Provider that produced the output (`cache` for cache hits).
+    pub provider: String,
+    /// Configured provider route, in failover order.
+    pub provider_route: String,
+    /// Selected model tier label.
+    pub model_tier: String,
+    /// Selected model label.
+    pub model: String,
+    /// Whether the output was served from the response cache.
+    pub cached: bool,
+    /// Full assistant output.
+    pub output: String,
+    /// Estimated input tokens charged against the budget.
+    pub estimated_input_tokens: u64,
+    /// Estimated output tokens charged against the budget.
+    pub estimated_output_tokens: u64,
+    /// Estimated request cost in USD.
+    pub estimated_cost_usd: f64,
+    /// Input tokens charged to the spend ledger (`0` for cache hits).
+    pub input_tokens: u64,
+    /// Output tokens charged to the spend ledger (`0` for cache hits).
+    pub output_tokens: u64,
+    /// Cost charged to the spend ledger in USD (`0` for cache hits).
+    pub cost_usd: f64,
+    /// Origin of the charged token counts (`provider`, `estimate` or `cache`).
+    pub usage_source: String,
+    /// Provider retries before success.
+    pub retries: u64,
+    /// Provider failovers before success.
+    pub failovers: u64,
+    /// Provider latency in milliseconds (`0` for cache hits).
+    pub latency_ms: u64,
+    /// Non-fatal warnings raised while serving the request.
+    pub warnings: Vec<String>,
+}
+
+impl AiCommandReport {
+    /// Structured form: route details, warnings and the response as Markdown.
+    #[must_use]
+    pub fn to_output(&self) -> CommandOutput {
+        let source = if self.cached { "cache" } else { "provider" };
+        let mut output = CommandOutput::new()
+            .heading(format!("🤖 AI {}", self.intent), OutputTone::Info)
+            .key_values([
+                ("provider", self.provider.clone()),
+                ("route", self.provider_route.clone()),
+                ("tier", self.model_tier.clone()),
+                ("model", self.model.clone()),
+                ("source", source.to_string()),
+                ("session", self.session_id.clone()),
+                (
+                    "usage",
+                    format!(
+                        "{} in / {} out tokens, ${:.4} ({})",
+                        self.input_tokens, self.output_tokens, self.cost_usd, self.usage_source
+                    ),
+                ),
+            ]);
+        for warning in &self.warnings {
+            output = output.text(format!("⚠ {warning}"), OutputTone::Warning);
+        }
+        output.markdown(self.output.clone())
+    }
+}
+
+/// Typed command failure with operator guidance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CommandFailure {
+    /// Human-readable failure reason.
+    pub message: String,
+    /// Remediation hints (env variables, flags).
+    pub hints: Vec<String>,
+}
+
+impl CommandFailure {
+    pub(super) fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            hints: Vec::new(),
         }
     }
+
+    pub(super) fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hints.push(hint.into());
+        self
+    }
+
+    /// Structured form: one error block carrying the hints.
+    #[must_use]
+    pub fn to_output(&self) -> CommandOutput {
+        CommandOutput::new().error(self.message.clone(), self.hints.clone())
+    }
 }
 
-fn runtime_metrics() -> &'static Metrics {
+/// Process-wide runtime metrics registry shared by command, AI and task execution.
+pub fn runtime_metrics() -> &'static Metrics {
     RUNTIME_METRICS.get_or_init(Metrics::new)
 }
 
 fn command_cache() -> &'static Mutex<CommandResultCache> {
     COMMAND_CACHE.get_or_init(|| {
-        Mutex::new(CommandResultCache::new(
+        let cache = CommandResultCache::new(
             COMMAND_CACHE_MAX_ENTRIES,
             COMMAND_CACHE_MAX_SIZE_BYTES,
             CacheTtl::default(),
-        ))
+        );
+        Mutex::new(match persistent_command_cache_tier_from_env() {
+            Some(tier) => cache.with_persistent_tier(tier),
+            None => cache,
+        })
     })
 }
 
+/// Disk tier for the command cache, when `NTK_CACHE_PERSISTENT` enables it.
+fn persistent_command_cache_tier_from_env() -> Option<PersistentCacheTier> {
+    let source = PolicySource::load();
+    let enabled = source
+        .var("NTK_CACHE_PERSISTENT")
+        .and_then(|value| parse_bool(&value))
+        .unwrap_or(false);
+    if !enabled {
+        return None;
+    }
+
+    let dir = AppConfig::default_data_dir()?.join(COMMAND_CACHE_PERSISTENT_DIR_NAME);
+    let max_size_bytes = source
+        .var("NTK_CACHE_PERSISTENT_MAX_BYTES")
+        .and_then(|value| parse_nonzero_u64(&value))
+        .unwrap_or(DEFAULT_COMMAND_CACHE_PERSISTENT_MAX_BYTES);
+    let mut ttl = CacheTtl::persistent();
+    if let Some(seconds) = source
+        .var("NTK_CACHE_PERSISTENT_AI_TTL_SECONDS")
+        .and_then(|value| parse_nonzero_u64(&value))
+    {
+        ttl.ai_response = Duration::from_secs(seconds);
+    }
+    if let Some(seconds) = source
+        .var("NTK_CACHE_PERSISTENT_MANIFEST_TTL_SECONDS")
+        .and_then(|value| parse_nonzero_u64(&value))
+    {
+        ttl.manifest_list = Duration::from_secs(seconds);
+    }
+
+    Some(PersistentCacheTier::new(dir, max_size_bytes, ttl))
+}
+
 fn ai_rate_limiter() -> &'static Mutex<AiRateLimitState> {
     AI_RATE_LIMITER.get_or_init(|| Mutex::new(AiRateLimitState::default()))
 }
@@ -1081,6 +1385,18 @@ fn task_audit_registry() -> &'static Mutex<HashMap<String, Vec<TaskAuditEvent>>>
     TASK_AUDIT_REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
 }
 
+fn task_changes() -> &'static watch::Sender<u64> {
+    TASK_CHANGES.get_or_init(|| watch::channel(0).0)
+}
+
+/// Subscribe to task registry changes.
+///
+/// The value is a revision bumped on every lifecycle audit event, so watchers
+/// (such as `/task top`) redraw from worker callbacks instead of polling.
+pub(super) fn subscribe_task_changes() -> watch::Receiver<u64> {
+    task_changes().subscribe()
+}
+
 fn with_command_cache<T>(f: impl FnOnce(&mut CommandResultCache) -> T) -> T {
     let mut guard = command_cache()
         .lock()
@@ -1136,6 +1452,7 @@ fn append_task_audit_event(
             events.drain(0..extra);
         }
     });
+    task_changes().send_modify(|revision| *revision = revision.wrapping_add(1));
 }
 
 fn list_task_audit_events(task_id: &str) -> Vec<TaskAuditEvent> {
@@ -1145,10 +1462,10 @@ fn list_task_audit_events(task_id: &str) -> Vec<TaskAuditEvent> {
 fn maybe_log_command_cache_stats(stats: CacheStats, metrics: &Metrics) {
     metrics.set_gauge("runtime_command_cache_entries", stats.entries as f64);
     metrics.set_gauge("runtime_command_cache_size_bytes", stats.size_bytes as f64);
-    metrics.set_gauge("runtime_command_cache_hits_total", stats.hits as f64);
-    metrics.set_gauge("runtime_command_cache_misses_total", stats.misses as f64);
+    metrics.set_gauge("runtime_command_cache_stats_hits", stats.hits as f64);
+    metrics.set_gauge("runtime_command_cache_stats_misses", stats.misses as f64);
     metrics.set_gauge(
-        "runtime_command_cache_evictions_total",
+        "runtime_command_cache_stats_evictions",
         stats.evictions as f64,
     );
 
@@ -1212,7 +1529,12 @@ fn command_metric_key(parsed: Option<MainAction>, subcommand: Option<&str>, cmd:
                 .split_whitespace()
                 .next()
                 .unwrap_or("unknown");
-            format!("unknown_{}", sanitize_metric_component(token))
+            let prefix = if find_registered_command_spec(token).is_some() {
+                "plugin"
+            } else {
+                "unknown"
+            };
+            format!("{prefix}_{}", sanitize_metric_component(token))
         }
     }
 }
@@ -1287,239 +1609,30 @@ fn update_runtime_latency_gauges(
     }
 }
 
-fn has_flag(parts: &[&str], flag: &str) -> bool {
-    parts.iter().any(|part| *part == flag)
-}
-
-fn first_positional_path(parts: &[&str], start_index: usize) -> Option<PathBuf> {
-    parts
-        .iter()
-        .skip(start_index)
-        .find(|part| !part.starts_with("--"))
-        .map(|part| PathBuf::from(*part))
-}
-
-fn first_manifest_positional_path(parts: &[&str]) -> Option<PathBuf> {
-    first_positional_path(parts, 2)
-}
-
-fn parse_output_root(parts: &[&str]) -> Option<PathBuf> {
-    parts.windows(2).find_map(|window| {
-        if window[0] == "--output" && !window[1].starts_with("--") {
-            Some(PathBuf::from(window[1]))
-        } else {
-            None
-        }
-    })
-}
-
-fn discover_manifest_files(root: &Path) -> Result<Vec<PathBuf>, String> {
-    let config = SearchConfig {
-        include_patterns: vec![
-            "*manifest*.yml".to_string(),
-            "*manifest*.yaml".to_string(),
-            "ntk-*.yml".to_string(),
-            "ntk-*.yaml".to_string(),
-        ],
-        exclude_patterns: vec!["**/target/**".to_string(), "**/node_modules/**".to_string()],
-        max_depth: Some(8),
-        follow_links: false,
-        include_hidden: false,
-    };
-
-    let mut manifests =
-        search_files(root, &config).map_err(|err| format!("manifest discovery failed: {err}"))?;
-    manifests.sort();
-    manifests.dedup();
-    Ok(manifests)
-}
-
-fn relative_path_for_display(root: &Path, path: &Path) -> String {
-    match path.strip_prefix(root) {
-        Ok(relative) => relative.display().to_string(),
-        Err(_) => path.display().to_string(),
-    }
-}
-
-fn print_manifest_validation(
-    path: &Path,
-    validation: &nettoolskit_manifest::handlers::ValidationResult,
-) {
-    use nettoolskit_ui::Color;
-
-    let location_text = path.display().to_string();
-    let location = location_text.color(Color::CYAN);
-    if validation.is_valid() {
-        println!(
-            "{} {} {}",
-            "✓".color(Color::GREEN),
-            location.bold(),
-            "is valid".color(Color::GREEN)
-        );
-    } else {
-        println!(
-            "{} {} {}",
-            "✗".color(Color::RED),
-            location.bold(),
-            "has validation errors".color(Color::RED)
-        );
-
-        for error in &validation.errors {
-            if let Some(line) = error.line {
-                println!(
-                    "  {} [line {}] {}",
-                    "error".color(Color::RED),
-                    line,
-                    error.message
-                );
-            } else {
-                println!("  {} {}", "error".color(Color::RED), error.message);
-            }
-        }
-    }
-
-    if !validation.warnings.is_empty() {
-        for warning in &validation.warnings {
-            if let Some(line) = warning.line {
-                println!(
-                    "  {} [line {}] {}",
-                    "warning".color(Color::YELLOW),
-                    line,
-                    warning.message
-                );
-            } else {
-                println!("  {} {}", "warning".color(Color::YELLOW), warning.message);
-            }
-        }
-    }
-
-    println!(
-        "  errors: {}, warnings: {}",
-        validation.error_count(),
-        validation.warning_count()
-    );
-    println!();
-}
-
-fn resolve_manifest_target_path_from(
-    parts: &[&str],
-    action_label: &str,
-    start_index: usize,
-) -> Result<PathBuf, ExitStatus> {
-    use nettoolskit_ui::Color;
-
-    if let Some(path) = first_positional_path(parts, start_index) {
-        return Ok(path);
-    }
-
-    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-    let manifests = match discover_manifest_files(&cwd) {
-        Ok(found) => found,
-        Err(err) => {
-            println!("{} {}", "✗".color(Color::RED), err.color(Color::RED));
-            return Err(ExitStatus::Error);
-        }
-    };
-
-    match manifests.len() {
-        0 => {
-            println!(
-                "{}",
-                format!("No manifest files found for {action_label}.").color(Color::YELLOW)
-            );
-            println!(
-                "{}",
-                format!("Provide an explicit path: /manifest {action_label} <manifest-file>")
-                    .color(Color::YELLOW)
-            );
-            Err(ExitStatus::Error)
-        }
-        1 => Ok(manifests[0].clone()),
-        _ => {
-            println!(
-                "{}",
-                format!(
-                    "Multiple manifests detected ({}). Specify which one to use:",
-                    manifests.len()
-                )
-                .color(Color::YELLOW)
-            );
-            for path in manifests {
-                println!("  - {}", relative_path_for_display(&cwd, &path));
-            }
-            println!();
-            println!(
-                "{}",
-                format!(
-                    "Use: /manifest {action_label} <manifest-file> [--dry-run] [--output <dir>]"
-                )
-                .color(Color::YELLOW)
-            );
-            Err(ExitStatus::Error)
-        }
-    }
-}
-
-fn resolve_manifest_target_path(parts: &[&str], action_label: &str) -> Result<PathBuf, ExitStatus> {
-    resolve_manifest_target_path_from(parts, action_label, 2)
+/// Manifest path given as the first positional of a manifest command.
+fn manifest_path_arg(args: &ParsedArguments<'_>) -> Option<PathBuf> {
+    args.positional(0).map(PathBuf::from)
 }
 
-fn print_execution_summary(summary: &nettoolskit_manifest::core::models::ExecutionSummary) {
-    use nettoolskit_ui::Color;
-
-    if !summary.created.is_empty() {
-        println!(
-            "{}",
-            format!("Files to create: {}", summary.created.len()).color(Color::GREEN)
-        );
-        for path in &summary.created {
-            println!("  + {}", path.display());
-        }
-        println!();
-    }
-
-    if !summary.updated.is_empty() {
-        println!(
-            "{}",
-            format!("Files to update: {}", summary.updated.len()).color(Color::GREEN)
-        );
-        for path in &summary.updated {
-            println!("  ~ {}", path.display());
-        }
-        println!();
-    }
-
-    if !summary.skipped.is_empty() {
-        println!(
-            "{}",
-            format!("Files to skip: {}", summary.skipped.len()).color(Color::YELLOW)
-        );
-        for (path, reason) in &summary.skipped {
-            println!("  - {} ({reason})", path.display());
-        }
-        println!();
-    }
-
-    if !summary.notes.is_empty() {
-        println!("{}", "Notes:".color(Color::CYAN));
-        for note in &summary.notes {
-            println!("  • {note}");
-        }
-        println!();
-    }
-
-    println!(
-        "Total artifacts: {}",
-        summary.created.len() + summary.updated.len()
-    );
+fn output_root_arg(args: &ParsedArguments<'_>) -> Option<PathBuf> {
+    args.flag_value("--output").map(PathBuf::from)
 }
 
+/// AI assistant intent selected by `/ai <subcommand>` or `ntk ai <subcommand>`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum AiIntent {
+pub enum AiIntent {
+    /// Free-form engineering question.
     Ask,
+    /// Step-by-step implementation plan.
     Plan,
+    /// Explanation of technical behavior.
     Explain,
+    /// Advisory patch proposal gated by the approval gateway.
     ApplyDryRun,
+    /// YAML patch to the nearest manifest, validated before it is shown.
+    Manifest,
+    /// Rolling summary of older session exchanges, requested internally.
+    SessionSummary,
 }
 
 impl AiIntent {
@@ -1533,12 +1646,16 @@ impl AiIntent {
         }
     }
 
-    fn as_label(self) -> &'static str {
+    /// Stable lowercase label (`ask`, `plan`, `explain`, `apply`, `manifest`, `summary`).
+    #[must_use]
+    pub fn as_label(self) -> &'static str {
         match self {
             Self::Ask => "ask",
             Self::Plan => "plan",
             Self::Explain => "explain",
             Self::ApplyDryRun => "apply",
+            Self::Manifest => "manifest",
+            Self::SessionSummary => "summary",
         }
     }
 
@@ -1556,30 +1673,16 @@ impl AiIntent {
             Self::ApplyDryRun => {
                 "You are NetToolsKit CLI dry-run assistant. Propose safe, non-destructive patch steps and explain expected impact."
             }
+            Self::Manifest => {
+                "You are NetToolsKit CLI manifest assistant. Change the ntk/v1 manifest, never generated code. Reply with one ```yaml block holding a merge patch: mappings merge by key and `null` removes a key; lists of items with `name` merge by name, new names are appended and `_remove: true` deletes an item; any other list replaces the original. Include only what changes."
+            }
+            Self::SessionSummary => {
+                "You are NetToolsKit CLI session summarizer. Merge the previous summary and the listed exchanges into one concise summary of goals, decisions, facts and open questions. Reply with the summary only, at most 12 bullet points."
+            }
         }
     }
 }
 
-fn ai_prompt_start(parts: &[&str]) -> usize {
-    if parts.first().copied() == Some("/") {
-        3
-    } else {
-        2
-    }
-}
-
-fn collect_ai_prompt(parts: &[&str], start_index: usize) -> String {
-    parts
-        .iter()
-        .skip(start_index)
-        .filter(|part| !part.starts_with("--"))
-        .copied()
-        .collect::<Vec<_>>()
-        .join(" ")
-        .trim()
-        .to_string()
-}
-
 fn parse_timeout_millis(value: &str) -> Option<u64> {
     let parsed = value.trim().parse::<u64>().ok()?;
     (parsed > 0).then_some(parsed)
@@ -1601,45 +1704,46 @@ fn parse_nonzero_u64(value: &str) -> Option<u64> {
 }
 
 fn ai_token_economy_policy_from_env() -> AiTokenEconomyPolicy {
+    let source = PolicySource::load();
     let mut policy = AiTokenEconomyPolicy::default();
 
-    if let Ok(value) = std::env::var(NTK_AI_TOKEN_BUDGET_INPUT_PER_REQUEST_ENV) {
+    if let Some(value) = source.var(NTK_AI_TOKEN_BUDGET_INPUT_PER_REQUEST_ENV) {
         if let Some(parsed) = parse_nonzero_u64(&value) {
             policy.max_input_tokens_per_request = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_TOKEN_BUDGET_OUTPUT_PER_REQUEST_ENV) {
+    if let Some(value) = source.var(NTK_AI_TOKEN_BUDGET_OUTPUT_PER_REQUEST_ENV) {
         if let Some(parsed) = parse_nonzero_u64(&value) {
             policy.max_output_tokens_per_request = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_TOKEN_BUDGET_TOTAL_PER_REQUEST_ENV) {
+    if let Some(value) = source.var(NTK_AI_TOKEN_BUDGET_TOTAL_PER_REQUEST_ENV) {
         if let Some(parsed) = parse_nonzero_u64(&value) {
             policy.max_total_tokens_per_request = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_TOKEN_BUDGET_SESSION_TOTAL_ENV) {
+    if let Some(value) = source.var(NTK_AI_TOKEN_BUDGET_SESSION_TOTAL_ENV) {
         if let Some(parsed) = parse_nonzero_u64(&value) {
             policy.max_session_tokens_total = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_COST_BUDGET_USD_PER_REQUEST_ENV) {
+    if let Some(value) = source.var(NTK_AI_COST_BUDGET_USD_PER_REQUEST_ENV) {
         if let Some(parsed) = parse_positive_f64(&value) {
             policy.max_cost_usd_per_request = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_PROMPT_COMPACTION_TIER_ENV) {
+    if let Some(value) = source.var(NTK_AI_PROMPT_COMPACTION_TIER_ENV) {
         if let Some(parsed) = AiPromptCompactionTier::parse(&value) {
             policy.prompt_compaction_tier = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_CACHE_FIRST_ENABLED_ENV) {
+    if let Some(value) = source.var(NTK_AI_CACHE_FIRST_ENABLED_ENV) {
         if let Some(parsed) = parse_bool(&value) {
             policy.cache_first_enabled = parsed;
         }
@@ -1649,27 +1753,28 @@ fn ai_token_economy_policy_from_env() -> AiTokenEconomyPolicy {
 }
 
 fn ai_slo_policy_from_env() -> AiSloPolicy {
+    let source = PolicySource::load();
     let mut policy = AiSloPolicy::default();
 
-    if let Ok(value) = std::env::var(NTK_AI_SLO_MAX_P95_LATENCY_MS_ENV) {
+    if let Some(value) = source.var(NTK_AI_SLO_MAX_P95_LATENCY_MS_ENV) {
         if let Some(parsed) = parse_positive_f64(&value) {
             policy.max_p95_latency_ms = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_SLO_MIN_SUCCESS_RATE_PCT_ENV) {
+    if let Some(value) = source.var(NTK_AI_SLO_MIN_SUCCESS_RATE_PCT_ENV) {
         if let Some(parsed) = parse_positive_f64(&value) {
             policy.min_success_rate_pct = parsed.clamp(0.0, 100.0);
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_SLO_MAX_TOKENS_PER_TASK_ENV) {
+    if let Some(value) = source.var(NTK_AI_SLO_MAX_TOKENS_PER_TASK_ENV) {
         if let Some(parsed) = parse_positive_f64(&value) {
             policy.max_tokens_per_task = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_SLO_MAX_COST_USD_PER_TASK_ENV) {
+    if let Some(value) = source.var(NTK_AI_SLO_MAX_COST_USD_PER_TASK_ENV) {
         if let Some(parsed) = parse_positive_f64(&value) {
             policy.max_cost_usd_per_task = parsed;
         }
@@ -1684,6 +1789,8 @@ fn parse_ai_model_selection_intent(value: &str) -> Option<AiIntent> {
         "plan" | "ai-plan" => Some(AiIntent::Plan),
         "explain" | "ai-explain" => Some(AiIntent::Explain),
         "apply" | "apply-dry-run" | "ai-apply-dry-run" => Some(AiIntent::ApplyDryRun),
+        "manifest" | "ai-manifest" => Some(AiIntent::Manifest),
+        "summary" | "session-summary" | "ai-summary" => Some(AiIntent::SessionSummary),
         _ => None,
     }
 }
@@ -1702,6 +1809,8 @@ fn parse_ai_model_selection_intents(value: &str) -> Vec<AiIntent> {
                 AiIntent::Plan,
                 AiIntent::Explain,
                 AiIntent::ApplyDryRun,
+                AiIntent::Manifest,
+                AiIntent::SessionSummary,
             ];
         }
 
@@ -1725,55 +1834,56 @@ fn parse_ai_model_selection_model(value: &str) -> Option<String> {
 }
 
 fn ai_model_selection_policy_from_env() -> AiModelSelectionPolicy {
+    let source = PolicySource::load();
     let mut policy = AiModelSelectionPolicy::default();
 
-    if let Ok(value) = std::env::var(NTK_AI_MODEL_SELECTION_ENABLED_ENV) {
+    if let Some(value) = source.var(NTK_AI_MODEL_SELECTION_ENABLED_ENV) {
         if let Some(parsed) = parse_bool(&value) {
             policy.enabled = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_MODEL_SELECTION_CHEAP_MODEL_ENV) {
+    if let Some(value) = source.var(NTK_AI_MODEL_SELECTION_CHEAP_MODEL_ENV) {
         policy.cheap_model = parse_ai_model_selection_model(&value);
     }
-    if let Ok(value) = std::env::var(NTK_AI_MODEL_SELECTION_REASONING_MODEL_ENV) {
+    if let Some(value) = source.var(NTK_AI_MODEL_SELECTION_REASONING_MODEL_ENV) {
         policy.reasoning_model = parse_ai_model_selection_model(&value);
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_MODEL_SELECTION_CHEAP_INTENTS_ENV) {
+    if let Some(value) = source.var(NTK_AI_MODEL_SELECTION_CHEAP_INTENTS_ENV) {
         let parsed = parse_ai_model_selection_intents(&value);
         if !parsed.is_empty() {
             policy.cheap_intents = parsed;
         }
     }
-    if let Ok(value) = std::env::var(NTK_AI_MODEL_SELECTION_REASONING_INTENTS_ENV) {
+    if let Some(value) = source.var(NTK_AI_MODEL_SELECTION_REASONING_INTENTS_ENV) {
         let parsed = parse_ai_model_selection_intents(&value);
         if !parsed.is_empty() {
             policy.reasoning_intents = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_MODEL_SELECTION_CHEAP_COST_MULTIPLIER_ENV) {
+    if let Some(value) = source.var(NTK_AI_MODEL_SELECTION_CHEAP_COST_MULTIPLIER_ENV) {
         if let Some(parsed) = parse_positive_f64(&value) {
             policy.cheap_cost_multiplier = parsed;
         }
     }
-    if let Ok(value) = std::env::var(NTK_AI_MODEL_SELECTION_REASONING_COST_MULTIPLIER_ENV) {
+    if let Some(value) = source.var(NTK_AI_MODEL_SELECTION_REASONING_COST_MULTIPLIER_ENV) {
         if let Some(parsed) = parse_positive_f64(&value) {
             policy.reasoning_cost_multiplier = parsed;
         }
     }
-    if let Ok(value) = std::env::var(NTK_AI_MODEL_SELECTION_CHEAP_COST_CAP_USD_ENV) {
+    if let Some(value) = source.var(NTK_AI_MODEL_SELECTION_CHEAP_COST_CAP_USD_ENV) {
         if let Some(parsed) = parse_positive_f64(&value) {
             policy.cheap_cost_cap_usd = Some(parsed);
         }
     }
-    if let Ok(value) = std::env::var(NTK_AI_MODEL_SELECTION_REASONING_COST_CAP_USD_ENV) {
+    if let Some(value) = source.var(NTK_AI_MODEL_SELECTION_REASONING_COST_CAP_USD_ENV) {
         if let Some(parsed) = parse_positive_f64(&value) {
             policy.reasoning_cost_cap_usd = Some(parsed);
         }
     }
-    if let Ok(value) = std::env::var(NTK_AI_MODEL_SELECTION_FALLBACK_TO_CHEAP_ON_GUARDRAIL_ENV) {
+    if let Some(value) = source.var(NTK_AI_MODEL_SELECTION_FALLBACK_TO_CHEAP_ON_GUARDRAIL_ENV) {
         if let Some(parsed) = parse_bool(&value) {
             policy.fallback_to_cheap_on_guardrail = parsed;
         }
@@ -1826,21 +1936,22 @@ fn apply_ai_model_selection_to_request(
 }
 
 fn ai_retry_policy_from_env() -> AiRetryPolicy {
+    let source = PolicySource::load();
     let mut policy = AiRetryPolicy::default();
 
-    if let Ok(value) = std::env::var("NTK_AI_MAX_RETRIES") {
+    if let Some(value) = source.var("NTK_AI_MAX_RETRIES") {
         if let Some(parsed) = parse_nonzero_usize(&value) {
             policy.max_retries = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var("NTK_AI_RETRY_BASE_MS") {
+    if let Some(value) = source.var("NTK_AI_RETRY_BASE_MS") {
         if let Some(parsed) = parse_timeout_millis(&value) {
             policy.base_delay = Duration::from_millis(parsed);
         }
     }
 
-    if let Ok(value) = std::env::var("NTK_AI_RETRY_MAX_MS") {
+    if let Some(value) = source.var("NTK_AI_RETRY_MAX_MS") {
         if let Some(parsed) = parse_timeout_millis(&value) {
             policy.max_delay = Duration::from_millis(parsed);
         }
@@ -1850,7 +1961,7 @@ fn ai_retry_policy_from_env() -> AiRetryPolicy {
         policy.max_delay = policy.base_delay;
     }
 
-    if let Ok(value) = std::env::var("NTK_AI_REQUEST_TIMEOUT_MS") {
+    if let Some(value) = source.var("NTK_AI_REQUEST_TIMEOUT_MS") {
         if let Some(parsed) = parse_timeout_millis(&value) {
             policy.request_timeout = Duration::from_millis(parsed);
         }
@@ -1860,15 +1971,16 @@ fn ai_retry_policy_from_env() -> AiRetryPolicy {
 }
 
 fn ai_rate_limit_policy_from_env() -> AiRateLimitPolicy {
+    let source = PolicySource::load();
     let mut policy = AiRateLimitPolicy::default();
 
-    if let Ok(value) = std::env::var("NTK_AI_RATE_LIMIT_REQUESTS") {
+    if let Some(value) = source.var("NTK_AI_RATE_LIMIT_REQUESTS") {
         if let Some(parsed) = parse_nonzero_usize(&value) {
             policy.max_requests = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var("NTK_AI_RATE_LIMIT_WINDOW_SECONDS") {
+    if let Some(value) = source.var("NTK_AI_RATE_LIMIT_WINDOW_SECONDS") {
         if let Some(parsed) = parse_timeout_millis(&value) {
             policy.window = Duration::from_secs(parsed);
         }
@@ -2117,7 +2229,8 @@ fn ai_intent_default_output_tokens(intent: AiIntent) -> u64 {
         AiIntent::Ask => 1024,
         AiIntent::Plan => 1400,
         AiIntent::Explain => 1200,
-        AiIntent::ApplyDryRun => 1600,
+        AiIntent::ApplyDryRun | AiIntent::Manifest => 1600,
+        AiIntent::SessionSummary => 600,
     }
 }
 
@@ -2192,14 +2305,17 @@ fn ai_model_selection_metric_tier_value(tier: AiModelSelectionTier) -> f64 {
 }
 
 fn estimate_ai_session_tokens(session: &LocalAiSessionState) -> u64 {
+    let summary_tokens = session
+        .rolling_summary_message()
+        .map_or(0, |message| estimate_token_count(&message.content));
     session
-        .exchanges
-        .iter()
+        .unsummarized_exchanges()
         .map(|exchange| {
             estimate_token_count(&exchange.user_prompt)
                 .saturating_add(estimate_token_count(&exchange.assistant_response))
         })
-        .sum()
+        .sum::<u64>()
+        .saturating_add(summary_tokens)
 }
 
 fn truncate_text_chars(value: &str, max_chars: usize) -> String {
@@ -2360,15 +2476,15 @@ fn add_gauge_value(metrics: &Metrics, name: &str, delta: f64) {
 }
 
 fn ai_input_cost_rate_per_1k_from_env() -> f64 {
-    std::env::var("NTK_AI_COST_PER_1K_INPUT_USD")
-        .ok()
+    PolicySource::load()
+        .var("NTK_AI_COST_PER_1K_INPUT_USD")
         .and_then(|value| parse_positive_f64(&value))
         .unwrap_or(0.0)
 }
 
 fn ai_output_cost_rate_per_1k_from_env() -> f64 {
-    std::env::var("NTK_AI_COST_PER_1K_OUTPUT_USD")
-        .ok()
+    PolicySource::load()
+        .var("NTK_AI_COST_PER_1K_OUTPUT_USD")
         .and_then(|value| parse_positive_f64(&value))
         .unwrap_or(0.0)
 }
@@ -2411,6 +2527,176 @@ fn record_ai_usage_estimates(metrics: &Metrics, request: &AiRequest, output: &st
     );
 }
 
+/// Price table from `NTK_AI_PRICE_TABLE`; the global per-1k rates price anything unlisted.
+fn ai_price_table_from_env() -> AiPriceTable {
+    let mut table = AiPriceTable::new(AiModelPrice::new(
+        ai_input_cost_rate_per_1k_from_env(),
+        ai_output_cost_rate_per_1k_from_env(),
+    ));
+    let Some(value) = PolicySource::load().var(NTK_AI_PRICE_TABLE_ENV) else {
+        return table;
+    };
+    for entry in value.split([',', ';']) {
+        match table.clone().with_entries([entry]) {
+            Ok(updated) => table = updated,
+            Err(error) => {
+                let _ = nettoolskit_ui::append_footer_log(&format!(
+                    "ai: ignoring {NTK_AI_PRICE_TABLE_ENV} entry - {error}"
+                ));
+            }
+        }
+    }
+    table
+}
+
+fn ai_spend_caps_from_env() -> AiSpendCaps {
+    let source = PolicySource::load();
+    AiSpendCaps {
+        daily_usd: source
+            .var(NTK_AI_SPEND_CAP_DAILY_USD_ENV)
+            .and_then(|value| parse_positive_f64(&value)),
+        monthly_usd: source
+            .var(NTK_AI_SPEND_CAP_MONTHLY_USD_ENV)
+            .and_then(|value| parse_positive_f64(&value)),
+    }
+}
+
+fn ai_spend_ledger_from_env() -> Option<AiSpendLedger> {
+    PolicySource::load()
+        .var(NTK_AI_SPEND_LEDGER_PATH_ENV)
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+        .map(AiSpendLedger::from_path)
+        .or_else(AiSpendLedger::from_default_data_dir)
+}
+
+fn ai_spend_env_hint() -> String {
+    format!(
+        "env: {NTK_AI_SPEND_CAP_DAILY_USD_ENV} | {NTK_AI_SPEND_CAP_MONTHLY_USD_ENV} | {NTK_AI_PRICE_TABLE_ENV} | {NTK_AI_SPEND_LEDGER_PATH_ENV}"
+    )
+}
+
+/// Operator charged for AI spend: the task operator when one is scoped, else the local CLI.
+fn ai_spend_operator_id() -> String {
+    scoped_ai_spend_operator().unwrap_or_else(|| local_cli_operator().id)
+}
+
+/// Projected cost of `request` on the first provider route, priced from the table.
+fn projected_ai_request_cost_usd(
+    provider_routes: &[AiProviderRoute],
+    request: &AiRequest,
+    intent: AiIntent,
+) -> f64 {
+    let provider_id = provider_routes
+        .first()
+        .map_or("unknown", |route| route.provider.id());
+    let model = request.model.as_deref().unwrap_or("provider-default");
+    ai_price_table_from_env()
+        .price_for(provider_id, model)
+        .cost_usd(
+            estimate_request_input_tokens(request),
+            estimate_request_expected_output_tokens(request, intent),
+        )
+}
+
+/// Reject dispatch when spend so far plus `projected_usd` would exceed a hard cap.
+///
+/// Fails closed when caps are configured but the ledger cannot be read.
+fn enforce_ai_spend_caps(projected_usd: f64) -> Result<(), String> {
+    let caps = ai_spend_caps_from_env();
+    if !caps.is_enabled() {
+        return Ok(());
+    }
+    let Some(ledger) = ai_spend_ledger_from_env() else {
+        return Err("spend caps are set but no spend ledger location is available".to_string());
+    };
+    let totals = ledger
+        .totals_at(current_unix_timestamp_ms())
+        .map_err(|error| {
+            format!(
+                "spend ledger {} is unreadable: {error}",
+                ledger.path().display()
+            )
+        })?;
+    caps.check(totals, projected_usd)
+}
+
+/// Price one provider-served request and append it to the spend ledger.
+///
+/// Provider-reported usage is charged when present; otherwise the token
+/// counts fall back to the local estimate.
+fn record_ai_spend(
+    metrics: &Metrics,
+    exchange: AiSpendExchange<'_>,
+    usage: Option<AiUsage>,
+) -> AiSpendEntry {
+    let (input_tokens, output_tokens, usage_source) = match usage {
+        Some(usage) => (
+            u64::from(usage.input_tokens),
+            u64::from(usage.output_tokens),
+            AiUsageSource::Provider,
+        ),
+        None => (
+            estimate_request_input_tokens(exchange.request),
+            estimate_token_count(exchange.output),
+            AiUsageSource::Estimate,
+        ),
+    };
+    let cost_usd = ai_price_table_from_env()
+        .price_for(exchange.provider_id, exchange.model)
+        .cost_usd(input_tokens, output_tokens);
+    let entry = AiSpendEntry {
+        timestamp_unix_ms: current_unix_timestamp_ms(),
+        session_id: exchange.session_id.to_string(),
+        operator: ai_spend_operator_id(),
+        intent: exchange.intent.as_label().to_string(),
+        provider: exchange.provider_id.to_string(),
+        model: exchange.model.to_string(),
+        input_tokens,
+        output_tokens,
+        cost_usd,
+        usage_source,
+    };
+
+    metrics.increment_counter(format!(
+        "runtime_ai_usage_{}_total",
+        usage_source.as_label()
+    ));
+    metrics.set_gauge("runtime_ai_last_cost_usd", cost_usd);
+    add_gauge_value(metrics, "runtime_ai_cost_total_usd", cost_usd);
+    if let Some(ledger) = ai_spend_ledger_from_env() {
+        if let Err(error) = ledger.append(&entry) {
+            metrics.increment_counter("runtime_ai_spend_ledger_write_errors_total");
+            let _ = nettoolskit_ui::append_footer_log(&format!(
+                "ai: failed to record spend in {}: {error}",
+                ledger.path().display()
+            ));
+        }
+    }
+    entry
+}
+
+/// Identity of one provider-served request, as charged by [`record_ai_spend`].
+struct AiSpendExchange<'a> {
+    session_id: &'a str,
+    intent: AiIntent,
+    provider_id: &'a str,
+    model: &'a str,
+    request: &'a AiRequest,
+    output: &'a str,
+}
+
+/// Model and usage reported on the final chunks of a routed stream.
+fn reported_ai_model_and_usage(chunks: &[AiChunk]) -> (Option<String>, Option<AiUsage>) {
+    let model = chunks
+        .iter()
+        .rev()
+        .find_map(|chunk| chunk.model.clone())
+        .filter(|model| !model.trim().is_empty());
+    let usage = chunks.iter().rev().find_map(|chunk| chunk.usage);
+    (model, usage)
+}
+
 fn ai_error_guidance_message(error: &AiProviderError) -> Option<&'static str> {
     match error {
         AiProviderError::Timeout { .. } => Some(
@@ -2581,21 +2867,22 @@ fn parse_ai_context_paths(value: &str) -> Vec<PathBuf> {
 }
 
 fn ai_context_budget_from_env() -> AiContextBudget {
+    let source = PolicySource::load();
     let mut budget = AiContextBudget::default();
 
-    if let Ok(value) = std::env::var("NTK_AI_CONTEXT_MAX_FILES") {
+    if let Some(value) = source.var("NTK_AI_CONTEXT_MAX_FILES") {
         if let Some(parsed) = parse_nonzero_usize(&value) {
             budget.max_files = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var("NTK_AI_CONTEXT_MAX_FILE_BYTES") {
+    if let Some(value) = source.var("NTK_AI_CONTEXT_MAX_FILE_BYTES") {
         if let Some(parsed) = parse_nonzero_usize(&value) {
             budget.max_file_bytes = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var("NTK_AI_CONTEXT_MAX_BYTES") {
+    if let Some(value) = source.var("NTK_AI_CONTEXT_MAX_BYTES") {
         if let Some(parsed) = parse_nonzero_usize(&value) {
             budget.max_total_bytes = parsed;
         }
@@ -2604,33 +2891,176 @@ fn ai_context_budget_from_env() -> AiContextBudget {
     budget
 }
 
+fn ai_context_redactor_from_env() -> SecretRedactor {
+    let source = PolicySource::load();
+    let mut thresholds = EntropyThresholds::default();
+
+    if let Some(value) = source.var("NTK_AI_REDACTION_ENTROPY_MIN_LENGTH") {
+        if let Some(parsed) = parse_nonzero_usize(&value) {
+            thresholds.min_length = parsed;
+        }
+    }
+
+    if let Some(value) = source.var("NTK_AI_REDACTION_ENTROPY_MIN_BITS") {
+        if let Ok(parsed) = value.trim().parse::<f64>() {
+            if parsed.is_finite() && parsed > 0.0 {
+                thresholds.min_bits_per_char = parsed;
+            }
+        }
+    }
+
+    SecretRedactor::with_entropy(thresholds)
+}
+
 fn ai_context_allowlist_paths() -> Vec<PathBuf> {
     let mut paths = AI_CONTEXT_DEFAULT_ALLOWLIST
         .iter()
         .map(PathBuf::from)
         .collect::<Vec<_>>();
 
-    if let Ok(extra_paths) = std::env::var("NTK_AI_CONTEXT_PATHS") {
+    if let Some(extra_paths) = PolicySource::load().var("NTK_AI_CONTEXT_PATHS") {
         paths.extend(parse_ai_context_paths(&extra_paths));
     }
 
     paths
 }
 
-fn build_ai_context_system_message() -> Option<String> {
-    let workspace_root = std::env::current_dir().ok()?;
-    let allowlist = ai_context_allowlist_paths();
-    if allowlist.is_empty() {
-        return None;
-    }
+fn ai_context_mode_from_env() -> AiContextMode {
+    PolicySource::load()
+        .var("NTK_AI_CONTEXT_MODE")
+        .and_then(|value| AiContextMode::parse(&value))
+        .unwrap_or_default()
+}
 
-    let bundle =
-        collect_workspace_context(&workspace_root, &allowlist, ai_context_budget_from_env());
-    if bundle.is_empty() {
-        return None;
-    }
+/// Workspace context picked for one prompt, plus how the retrieval index was refreshed.
+struct AiContextSelection {
+    bundle: AiContextBundle,
+    index: Option<(usize, usize, RefreshStats)>,
+}
+
+/// Load the stored retrieval index of `workspace_root`, refresh it and save it back
+/// when anything changed.
+fn refreshed_ai_context_index(workspace_root: &Path) -> (ContextIndex, RefreshStats) {
+    let path = ContextIndex::default_path(workspace_root);
+    let mut index = path.as_deref().map(ContextIndex::load).unwrap_or_default();
+    let stats = index.refresh(workspace_root);
+    if stats.added + stats.updated + stats.removed > 0 {
+        if let Some(path) = path {
+            if let Err(err) = index.save(&path) {
+                warn!(error = %err, path = %path.display(), "Failed to save AI context index");
+            }
+        }
+    }
+    (index, stats)
+}
+
+fn select_ai_context(prompt: &str) -> Option<AiContextSelection> {
+    let current_dir = std::env::current_dir().ok()?;
+    let budget = ai_context_budget_from_env();
+    let redactor = ai_context_redactor_from_env();
+    let retrieval_root = match ai_context_mode_from_env() {
+        AiContextMode::Retrieval => find_workspace_root(&current_dir),
+        AiContextMode::Allowlist => None,
+    };
+
+    let selection = match retrieval_root {
+        Some(workspace_root) => {
+            let pinned = PolicySource::load()
+                .var("NTK_AI_CONTEXT_PATHS")
+                .map(|paths| parse_ai_context_paths(&paths))
+                .unwrap_or_default();
+            let (index, stats) = refreshed_ai_context_index(&workspace_root);
+            AiContextSelection {
+                bundle: collect_retrieved_context(
+                    &workspace_root,
+                    &index,
+                    prompt,
+                    &pinned,
+                    budget,
+                    &redactor,
+                ),
+                index: Some((index.file_count(), index.chunk_count(), stats)),
+            }
+        }
+        None => {
+            let allowlist = ai_context_allowlist_paths();
+            if allowlist.is_empty() {
+                return None;
+            }
+            AiContextSelection {
+                bundle: collect_workspace_context_with(&current_dir, &allowlist, budget, &redactor),
+                index: None,
+            }
+        }
+    };
+
+    if selection.bundle.is_empty() {
+        return None;
+    }
+    for file in selection
+        .bundle
+        .files
+        .iter()
+        .filter(|file| file.redactions > 0)
+    {
+        tracing::debug!(
+            path = %file.relative_path.display(),
+            redactions = file.redactions,
+            detectors = %file.detectors.join(","),
+            "Redacted secrets from AI context"
+        );
+    }
+    Some(selection)
+}
+
+fn build_ai_context_system_message(prompt: &str) -> Option<String> {
+    select_ai_context(prompt).and_then(|selection| render_context_system_message(&selection.bundle))
+}
+
+fn ai_manifest_context_enabled() -> bool {
+    PolicySource::load()
+        .var("NTK_AI_MANIFEST_CONTEXT")
+        .and_then(|value| parse_bool(&value))
+        .unwrap_or(true)
+}
+
+/// Nearest manifest to the working directory, as `(display path, path, document)`.
+fn nearest_ai_manifest() -> Option<(String, PathBuf, ManifestDocument)> {
+    let current_dir = std::env::current_dir().ok()?;
+    let (path, manifest) = find_nearest_manifest(&current_dir)?;
+    Some((
+        relative_path_for_display(&current_dir, &path),
+        path,
+        manifest,
+    ))
+}
 
-    render_context_system_message(&bundle)
+/// System message describing the nearest manifest: the full redacted YAML for
+/// `/ai manifest`, a compact summary for every other intent.
+fn build_ai_manifest_system_message(intent: AiIntent) -> Option<String> {
+    if intent != AiIntent::Manifest && !ai_manifest_context_enabled() {
+        return None;
+    }
+    let (display, path, manifest) = nearest_ai_manifest()?;
+    let redactor = ai_context_redactor_from_env();
+
+    if intent == AiIntent::Manifest {
+        let content = fs::read_to_string(&path).ok()?;
+        let redacted = redactor.redact(&content);
+        return Some(format!(
+            "Current manifest `{display}` (secrets redacted):\n```yaml\n{}\n```",
+            redacted.text.trim_end()
+        ));
+    }
+
+    let summary = truncate_text_chars(
+        &summarize_manifest(&manifest),
+        AI_MANIFEST_SUMMARY_MAX_CHARS,
+    );
+    Some(format!(
+        "Nearest NetToolsKit manifest `{display}` (summary, secrets redacted). Code is generated from this model, so prefer changes to it over edits to generated files.\n{}",
+        redactor.redact(&summary).text.trim_end()
+    ))
 }
 
 fn mocked_ai_response(intent: AiIntent, prompt: &str) -> AiResponse {
@@ -2652,6 +3082,14 @@ fn mocked_ai_response(intent: AiIntent, prompt: &str) -> AiResponse {
         AiIntent::ApplyDryRun => format!(
             "Mock AI dry-run apply for `{preview}`:\n- Proposed file changes only\n- No mutation performed"
         ),
+        AiIntent::Manifest => format!(
+            "Mock AI manifest patch:\n```yaml\nmeta:\n  description: 'Mock patch for {}'\n```",
+            preview.replace('\'', "''")
+        ),
+        AiIntent::SessionSummary => format!(
+            "Mock AI session summary ({} chars of history):\n- Earlier exchanges condensed",
+            prompt.chars().count()
+        ),
     };
 
     AiResponse::new("mock-assistant", content)
@@ -2686,10 +3124,13 @@ fn parse_ai_provider_chain(value: &str) -> Result<Vec<AiProviderKind>, String> {
 }
 
 fn ai_provider_chain_from_env() -> Result<Vec<AiProviderKind>, String> {
-    let mut providers = if let Ok(raw_chain) = std::env::var(NTK_AI_PROVIDER_CHAIN_ENV) {
+    let source = PolicySource::load();
+    let mut providers = if let Some(raw_chain) = source.var(NTK_AI_PROVIDER_CHAIN_ENV) {
         parse_ai_provider_chain(&raw_chain)?
     } else {
-        let primary_name = std::env::var("NTK_AI_PROVIDER").unwrap_or_else(|_| "mock".to_string());
+        let primary_name = source
+            .var("NTK_AI_PROVIDER")
+            .unwrap_or_else(|| "mock".to_string());
         let primary = AiProviderKind::parse(&primary_name).ok_or_else(|| {
             format!(
                 "unsupported NTK_AI_PROVIDER `{}` (allowed: mock, openai, openai-compatible)",
@@ -2700,7 +3141,7 @@ fn ai_provider_chain_from_env() -> Result<Vec<AiProviderKind>, String> {
     };
 
     if providers.len() == 1 {
-        if let Ok(raw_fallback) = std::env::var(NTK_AI_FALLBACK_PROVIDER_ENV) {
+        if let Some(raw_fallback) = source.var(NTK_AI_FALLBACK_PROVIDER_ENV) {
             let fallback = AiProviderKind::parse(&raw_fallback).ok_or_else(|| {
                 format!(
                     "unsupported NTK_AI_FALLBACK_PROVIDER `{}` (allowed: mock, openai, openai-compatible)",
@@ -2722,18 +3163,19 @@ fn ai_provider_chain_from_env() -> Result<Vec<AiProviderKind>, String> {
 fn ai_provider_route_timeout_budget_from_env(
     default_timeout: Duration,
 ) -> AiProviderRouteTimeoutBudget {
+    let source = PolicySource::load();
     let mut budget = AiProviderRouteTimeoutBudget {
         primary: default_timeout,
         secondary: default_timeout,
     };
 
-    if let Ok(value) = std::env::var(NTK_AI_PROVIDER_PRIMARY_TIMEOUT_MS_ENV) {
+    if let Some(value) = source.var(NTK_AI_PROVIDER_PRIMARY_TIMEOUT_MS_ENV) {
         if let Some(parsed) = parse_timeout_millis(&value) {
             budget.primary = Duration::from_millis(parsed);
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_AI_PROVIDER_SECONDARY_TIMEOUT_MS_ENV) {
+    if let Some(value) = source.var(NTK_AI_PROVIDER_SECONDARY_TIMEOUT_MS_ENV) {
         if let Some(parsed) = parse_timeout_millis(&value) {
             budget.secondary = Duration::from_millis(parsed);
         }
@@ -2752,28 +3194,25 @@ fn ai_provider_from_kind(
             intent, prompt,
         )))),
         AiProviderKind::OpenAiCompatible => {
+            let source = PolicySource::load();
             let mut config = OpenAiCompatibleProviderConfig::default();
-            if let Ok(endpoint) = std::env::var("NTK_AI_ENDPOINT") {
+            if let Some(endpoint) = source.var("NTK_AI_ENDPOINT") {
                 if !endpoint.trim().is_empty() {
                     config.endpoint = endpoint;
                 }
             }
-            if let Ok(api_key) = std::env::var("NTK_AI_API_KEY") {
-                if !api_key.trim().is_empty() {
-                    config.api_key = Some(api_key);
-                }
-            }
-            if let Ok(model) = std::env::var("NTK_AI_MODEL") {
+            config.api_key = source.secret("NTK_AI_API_KEY")?;
+            if let Some(model) = source.var("NTK_AI_MODEL") {
                 if !model.trim().is_empty() {
                     config.default_model = model;
                 }
             }
-            if let Ok(timeout_ms) = std::env::var("NTK_AI_TIMEOUT_MS") {
+            if let Some(timeout_ms) = source.var("NTK_AI_TIMEOUT_MS") {
                 if let Some(value) = parse_timeout_millis(&timeout_ms) {
                     config.timeout = Duration::from_millis(value);
                 }
             }
-            if let Ok(fallback) = std::env::var("NTK_AI_FALLBACK_TEXT") {
+            if let Some(fallback) = source.var("NTK_AI_FALLBACK_TEXT") {
                 if !fallback.trim().is_empty() {
                     config.fallback_output_text = Some(fallback);
                 }
@@ -2841,10 +3280,14 @@ fn build_ai_request(intent: AiIntent, prompt: &str) -> AiRequest {
             request.temperature = Some(0.3);
             request.max_output_tokens = Some(1200);
         }
-        AiIntent::ApplyDryRun => {
+        AiIntent::ApplyDryRun | AiIntent::Manifest => {
             request.temperature = Some(0.1);
             request.max_output_tokens = Some(1600);
         }
+        AiIntent::SessionSummary => {
+            request.temperature = Some(0.2);
+            request.max_output_tokens = Some(600);
+        }
     }
 
     request
@@ -2874,7 +3317,10 @@ fn load_or_initialize_ai_session(session_id: &str) -> LocalAiSessionState {
 }
 
 fn inject_ai_session_context(request: &mut AiRequest, session: &LocalAiSessionState) {
-    let history_messages = session.recent_messages(AI_SESSION_CONTEXT_MESSAGE_LIMIT);
+    let mut history_messages = session.recent_messages(AI_SESSION_CONTEXT_MESSAGE_LIMIT);
+    if let Some(summary_message) = session.rolling_summary_message() {
+        history_messages.insert(0, summary_message);
+    }
     if history_messages.is_empty() {
         return;
     }
@@ -2885,6 +3331,163 @@ fn inject_ai_session_context(request: &mut AiRequest, session: &LocalAiSessionSt
         .splice(insertion_index..insertion_index, history_messages);
 }
 
+fn ai_session_summary_enabled() -> bool {
+    PolicySource::load()
+        .var("NTK_AI_SESSION_SUMMARY")
+        .and_then(|value| parse_bool(&value))
+        .unwrap_or(true)
+}
+
+fn build_ai_session_summary_prompt(session: &LocalAiSessionState, end: usize) -> String {
+    let mut prompt = String::new();
+    if let Some(summary) = &session.rolling_summary {
+        prompt.push_str("Previous summary:\n");
+        prompt.push_str(summary.text.trim());
+        prompt.push_str("\n\n");
+    }
+    prompt.push_str("Exchanges to fold in:\n");
+    for (index, exchange) in session
+        .exchanges
+        .iter()
+        .enumerate()
+        .take(end)
+        .skip(session.summarized_exchange_count())
+    {
+        prompt.push_str(&format!(
+            "\n[{}] {}\nUser: {}\nAssistant: {}\n",
+            index + 1,
+            exchange.intent,
+            exchange.user_prompt.trim(),
+            exchange.assistant_response.trim()
+        ));
+    }
+
+    truncate_text_chars(&prompt, AI_SESSION_SUMMARY_TRANSCRIPT_MAX_CHARS)
+}
+
+/// Ask the provider chain for a rolling summary on the cheap model tier.
+///
+/// Returns `(provider id, summary text)`.
+async fn request_ai_session_summary(
+    session: &LocalAiSessionState,
+    end: usize,
+    retry_policy: AiRetryPolicy,
+    metrics: &Metrics,
+) -> Result<(String, String), String> {
+    let intent = AiIntent::SessionSummary;
+    let prompt = build_ai_session_summary_prompt(session, end);
+    let provider_routes = ai_provider_routes_from_env(intent, &prompt, retry_policy)?;
+    let mut request = build_ai_request(intent, &prompt);
+    let model_selection_policy = ai_model_selection_policy_from_env();
+    let selection = AiModelSelectionDecision {
+        tier: AiModelSelectionTier::Cheap,
+        model: if model_selection_policy.enabled {
+            model_selection_policy.model_for_tier(AiModelSelectionTier::Cheap)
+        } else {
+            None
+        },
+    };
+    apply_ai_model_selection_to_request(&mut request, &selection);
+    enforce_ai_spend_caps(projected_ai_request_cost_usd(
+        &provider_routes,
+        &request,
+        intent,
+    ))?;
+
+    let routed = request_ai_stream_with_provider_fallback(
+        &provider_routes,
+        &request,
+        retry_policy,
+        metrics,
+        intent,
+    )
+    .await
+    .map_err(|failure| format!("{}: {}", failure.provider_id, failure.error))?;
+
+    let mut text = String::new();
+    for chunk in &routed.chunks {
+        text.push_str(&chunk.content);
+        if chunk.done {
+            break;
+        }
+    }
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(format!("{} returned an empty summary", routed.provider_id));
+    }
+    let (reported_model, reported_usage) = reported_ai_model_and_usage(&routed.chunks);
+    let model = reported_model.unwrap_or_else(|| selection.model_label().to_string());
+    record_ai_spend(
+        metrics,
+        AiSpendExchange {
+            session_id: &session.id,
+            intent,
+            provider_id: &routed.provider_id,
+            model: &model,
+            request: &request,
+            output: text,
+        },
+        reported_usage,
+    );
+
+    Ok((routed.provider_id, text.to_string()))
+}
+
+/// Fold older exchanges into the session's rolling summary once the session
+/// would exceed its token budget, keeping the latest exchanges verbatim.
+///
+/// Falls back to local truncation when no provider can produce the summary.
+async fn refresh_ai_session_rolling_summary(
+    session: &mut LocalAiSessionState,
+    request: &AiRequest,
+    intent: AiIntent,
+    token_policy: AiTokenEconomyPolicy,
+    retry_policy: AiRetryPolicy,
+    metrics: &Metrics,
+) {
+    if !ai_session_summary_enabled() {
+        return;
+    }
+
+    let projected = estimate_ai_session_tokens(session)
+        .saturating_add(estimate_request_input_tokens(request))
+        .saturating_add(estimate_request_expected_output_tokens(request, intent));
+    if projected <= token_policy.max_session_tokens_total {
+        return;
+    }
+    let Some(end) = session.rolling_summary_cutoff(AI_SESSION_SUMMARY_KEEP_RECENT_EXCHANGES) else {
+        return;
+    };
+
+    match request_ai_session_summary(session, end, retry_policy, metrics).await {
+        Ok((provider_id, text)) => {
+            session.set_rolling_summary(text, end, provider_id.as_str());
+            metrics.increment_counter("runtime_ai_session_summary_total");
+            let _ = nettoolskit_ui::append_footer_log(&format!(
+                "ai: session summary updated provider={provider_id} exchanges={end}"
+            ));
+        }
+        Err(reason) => {
+            let text = session.truncated_rolling_summary(end);
+            session.set_rolling_summary(text, end, AI_SESSION_SUMMARY_TRUNCATION_SOURCE);
+            metrics.increment_counter("runtime_ai_session_summary_fallback_total");
+            warn!(
+                error = %reason,
+                session_id = %session.id,
+                "AI session summary unavailable; truncated older exchanges instead"
+            );
+        }
+    }
+
+    if let Err(err) = session.save_local_snapshot() {
+        warn!(
+            error = %err,
+            session_id = %session.id,
+            "Failed to persist AI session summary"
+        );
+    }
+}
+
 fn persist_ai_session_exchange(
     session_id: &str,
     intent: AiIntent,
@@ -2939,149 +3542,45 @@ fn persist_ai_session_exchange(
     }
 }
 
-fn handle_ai_resume_subcommand(parts: &[&str]) -> ExitStatus {
-    use nettoolskit_ui::Color;
-
-    let prompt_start = ai_prompt_start(parts);
-    let session_id = collect_ai_prompt(parts, prompt_start);
-    if session_id.is_empty() {
-        println!(
-            "{}",
-            "Usage: /ai resume <session-id> (interactive picker available with `/ai resume` in CLI mode)"
-                .color(Color::YELLOW)
-        );
-        return ExitStatus::Error;
-    }
-
-    let active_session = set_active_ai_session_id(&session_id);
-    match LocalAiSessionState::load_local_snapshot(&active_session) {
-        Ok(Some(session)) => {
-            println!(
-                "{} {}",
-                "✅ Active AI session resumed:".color(Color::GREEN).bold(),
-                session.id.color(Color::CYAN)
-            );
-            println!(
-                "  exchanges:{} last:{}",
-                session.exchanges.len(),
-                session.last_activity_ms
-            );
-        }
-        Ok(None) => {
-            println!(
-                "{} {}",
-                "✅ Active AI session set:".color(Color::GREEN).bold(),
-                active_session.color(Color::CYAN)
-            );
-            println!(
-                "{}",
-                "No persisted history found for this id yet; a new local session snapshot will be created on next AI response."
-                    .color(Color::YELLOW)
-            );
-        }
-        Err(err) => {
-            println!(
-                "{} {}",
-                "⚠ Active AI session set, but failed to read local snapshot:"
-                    .color(Color::YELLOW)
-                    .bold(),
-                err.to_string().color(Color::YELLOW)
-            );
-        }
-    }
-
-    let _ =
-        nettoolskit_ui::append_footer_log(&format!("ai: active session set id={active_session}"));
-    ExitStatus::Success
+pub(super) fn print_command_failure(failure: &CommandFailure) {
+    emit(failure.to_output());
 }
 
-async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitStatus {
-    use nettoolskit_ui::Color;
-
-    let Some(raw_subcommand) = subcommand else {
-        println!("{}", "🤖 AI Assistant Commands".color(Color::CYAN).bold());
-        println!("  {}", "/ai ask <prompt>".color(Color::GREEN));
-        println!("  {}", "/ai plan <goal>".color(Color::GREEN));
-        println!("  {}", "/ai explain <topic>".color(Color::GREEN));
-        println!("  {}", "/ai resume <session-id>".color(Color::GREEN));
-        println!(
-            "  {}",
-            "/ai apply --dry-run <instruction>".color(Color::GREEN)
-        );
-        println!(
-            "  {}",
-            "/ai apply --approve-write <instruction>".color(Color::YELLOW)
-        );
-        println!();
-        println!(
-            "{}",
-            "Use NTK_AI_PROVIDER=openai to enable live provider calls (defaults to mock)."
-                .color(Color::YELLOW)
-        );
-        println!(
-            "{}",
-            "Operational controls: NTK_AI_PROVIDER_CHAIN/NTK_AI_FALLBACK_PROVIDER, NTK_AI_PROVIDER_PRIMARY_TIMEOUT_MS, NTK_AI_PROVIDER_SECONDARY_TIMEOUT_MS, NTK_AI_MAX_RETRIES, NTK_AI_REQUEST_TIMEOUT_MS, NTK_AI_RATE_LIMIT_REQUESTS, NTK_AI_RATE_LIMIT_WINDOW_SECONDS, NTK_AI_TOKEN_BUDGET_*, NTK_AI_COST_BUDGET_USD_PER_REQUEST, NTK_AI_PROMPT_COMPACTION_TIER, NTK_AI_CACHE_FIRST_ENABLED, NTK_AI_MODEL_SELECTION_*, NTK_AI_SESSION_COMPRESSION_*, NTK_AI_SLO_*."
-                .color(Color::YELLOW)
-        );
-        return ExitStatus::Success;
-    };
-
-    if raw_subcommand.trim().eq_ignore_ascii_case("resume") {
-        return handle_ai_resume_subcommand(parts);
-    }
-
-    let Some(intent) = AiIntent::from_subcommand(raw_subcommand) else {
-        println!(
-            "{} {}",
-            "✗ Unknown /ai subcommand:".color(Color::RED).bold(),
-            raw_subcommand.color(Color::YELLOW)
-        );
-        println!(
-            "{}",
-            "Valid subcommands: ask, plan, explain, resume, apply".color(Color::YELLOW)
-        );
-        return ExitStatus::Error;
-    };
-
-    let dry_run = has_flag(parts, "--dry-run");
-    let explicit_write_approval = has_flag(parts, "--approve-write");
-
-    let prompt = collect_ai_prompt(parts, ai_prompt_start(parts));
-    if prompt.is_empty() {
-        let usage = if matches!(intent, AiIntent::ApplyDryRun) {
-            "Usage: /ai apply --dry-run <instruction> | /ai apply --approve-write <instruction>"
-                .to_string()
-        } else {
-            format!("Usage: /ai {} <prompt>", intent.as_label())
-        };
+fn ai_token_budget_env_hint() -> String {
+    format!(
+        "env: {NTK_AI_TOKEN_BUDGET_INPUT_PER_REQUEST_ENV} | {NTK_AI_TOKEN_BUDGET_OUTPUT_PER_REQUEST_ENV} | {NTK_AI_TOKEN_BUDGET_TOTAL_PER_REQUEST_ENV} | {NTK_AI_TOKEN_BUDGET_SESSION_TOTAL_ENV} | {NTK_AI_COST_BUDGET_USD_PER_REQUEST_ENV}"
+    )
+}
 
-        println!(
-            "{}",
-            format!("✗ Missing prompt for /ai {}.", intent.as_label())
-                .color(Color::RED)
-                .bold()
-        );
-        println!("{}", usage.color(Color::YELLOW));
-        return ExitStatus::Error;
-    }
+fn ai_model_selection_env_hint() -> String {
+    format!(
+        "env: {NTK_AI_MODEL_SELECTION_ENABLED_ENV} | {NTK_AI_MODEL_SELECTION_CHEAP_COST_CAP_USD_ENV} | {NTK_AI_MODEL_SELECTION_REASONING_COST_CAP_USD_ENV} | {NTK_AI_MODEL_SELECTION_FALLBACK_TO_CHEAP_ON_GUARDRAIL_ENV}"
+    )
+}
 
+/// Execute one AI request through approval, rate-limit, budget, cache and provider routing.
+///
+/// With [`ConsoleMode::Text`] the banner and streamed chunks are printed as they arrive;
+/// failures are always returned to the caller for rendering.
+pub(super) async fn run_ai_request(
+    intent: AiIntent,
+    prompt: &str,
+    dry_run: bool,
+    explicit_write_approval: bool,
+    console: ConsoleMode,
+) -> Result<AiCommandReport, CommandFailure> {
     let ai_metrics = runtime_metrics().clone();
     ai_metrics.increment_counter("runtime_ai_requests_total");
     ai_metrics.increment_counter(format!("runtime_ai_intent_{}_total", intent.as_label()));
     if let Err(retry_after_seconds) = enforce_ai_rate_limit(&ai_metrics) {
         ai_metrics.increment_counter("runtime_ai_requests_error_total");
         update_ai_request_rate_gauges(&ai_metrics);
-        println!(
-            "{}",
-            format!(
-                "✗ AI rate limit reached. Try again in ~{retry_after_seconds}s, or adjust NTK_AI_RATE_LIMIT_REQUESTS/NTK_AI_RATE_LIMIT_WINDOW_SECONDS."
-            )
-            .color(Color::RED)
-            .bold()
-        );
-        return ExitStatus::Error;
+        return Err(CommandFailure::new(format!(
+            "AI rate limit reached. Try again in ~{retry_after_seconds}s, or adjust NTK_AI_RATE_LIMIT_REQUESTS/NTK_AI_RATE_LIMIT_WINDOW_SECONDS."
+        )));
     }
 
+    let mut warnings = Vec::new();
     if matches!(intent, AiIntent::ApplyDryRun) {
         let mut reason_preview = prompt.chars().take(96).collect::<String>();
         if prompt.chars().count() > 96 {
@@ -3103,66 +3602,72 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
                     "ai approval: action=file_write status=approved reason={reason}"
                 ));
                 if !dry_run {
-                    println!(
-                        "{}",
-                        "⚠ Explicit write approval acknowledged; current /ai apply flow remains advisory and does not mutate files directly."
-                            .color(Color::YELLOW)
-                    );
+                    let warning = "Explicit write approval acknowledged; current /ai apply flow remains advisory and does not mutate files directly.";
+                    if console.is_text() {
+                        emit(
+                            CommandOutput::new().text(format!("⚠ {warning}"), OutputTone::Warning),
+                        );
+                    }
+                    warnings.push(warning.to_string());
                 }
             }
             ApprovalDecision::Denied { reason } => {
                 update_ai_approval_metrics(&ai_metrics, false);
-                println!(
-                    "{} {}",
-                    "✗ AI apply blocked by approval gateway:"
-                        .color(Color::RED)
-                        .bold(),
-                    reason.color(Color::RED)
-                );
-                println!(
-                    "{}",
-                    "Use `--dry-run` (recommended) or `--approve-write` for explicit mutating approval."
-                        .color(Color::YELLOW)
-                );
                 let _ = nettoolskit_ui::append_footer_log(&format!(
                     "ai approval: action=file_write status=denied reason={reason}"
                 ));
                 ai_metrics.increment_counter("runtime_ai_requests_error_total");
                 update_ai_request_rate_gauges(&ai_metrics);
-                return ExitStatus::Error;
+                return Err(CommandFailure::new(format!(
+                    "AI apply blocked by approval gateway: {reason}"
+                ))
+                .with_hint(
+                    "Use `--dry-run` (recommended) or `--approve-write` for explicit mutating approval.",
+                ));
             }
         }
     }
 
     let retry_policy = ai_retry_policy_from_env();
-    let provider_routes = match ai_provider_routes_from_env(intent, &prompt, retry_policy) {
+    let provider_routes = match ai_provider_routes_from_env(intent, prompt, retry_policy) {
         Ok(routes) => routes,
         Err(error) => {
             ai_metrics.increment_counter("runtime_ai_requests_error_total");
             ai_metrics.set_gauge("runtime_ai_provider_health", 0.0);
             update_ai_request_rate_gauges(&ai_metrics);
-            println!(
-                "{} {}",
-                "✗ Failed to initialize AI provider route:"
-                    .color(Color::RED)
-                    .bold(),
-                error.color(Color::RED)
-            );
-            return ExitStatus::Error;
+            return Err(CommandFailure::new(format!(
+                "Failed to initialize AI provider route: {error}"
+            )));
         }
     };
     let provider_route_label = ai_provider_route_label(&provider_routes);
 
     let session_id = resolve_active_ai_session_id();
-    let active_session = load_or_initialize_ai_session(&session_id);
-    let mut request = build_ai_request(intent, &prompt);
+    let token_policy = ai_token_economy_policy_from_env();
+    let mut active_session = load_or_initialize_ai_session(&session_id);
+    let mut request = build_ai_request(intent, prompt);
+    refresh_ai_session_rolling_summary(
+        &mut active_session,
+        &request,
+        intent,
+        token_policy,
+        retry_policy,
+        &ai_metrics,
+    )
+    .await;
     inject_ai_session_context(&mut request, &active_session);
-    if let Some(context_message) = build_ai_context_system_message() {
+    if let Some(context_message) = build_ai_context_system_message(prompt) {
         request
             .messages
             .insert(1, AiMessage::new(AiRole::System, context_message));
         let _ = nettoolskit_ui::append_footer_log("ai: context bundle attached");
     }
+    if let Some(manifest_message) = build_ai_manifest_system_message(intent) {
+        request
+            .messages
+            .insert(1, AiMessage::new(AiRole::System, manifest_message));
+        let _ = nettoolskit_ui::append_footer_log("ai: manifest context attached");
+    }
 
     let model_selection_policy = ai_model_selection_policy_from_env();
     let mut model_selection = select_ai_model_for_intent(&model_selection_policy, intent);
@@ -3177,7 +3682,6 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
         ai_model_selection_metric_tier_value(model_selection.tier),
     );
 
-    let token_policy = ai_token_economy_policy_from_env();
     apply_ai_prompt_compaction(&mut request, token_policy, &ai_metrics);
     let mut budget_estimate =
         match evaluate_ai_request_budget(&request, intent, &active_session, token_policy) {
@@ -3186,23 +3690,10 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
                 ai_metrics.increment_counter("runtime_ai_requests_error_total");
                 ai_metrics.increment_counter("runtime_ai_budget_rejected_total");
                 update_ai_request_rate_gauges(&ai_metrics);
-                println!(
-                    "{} {}",
-                    "✗ AI request rejected by token-economy policy:"
-                        .color(Color::RED)
-                        .bold(),
-                    reason.color(Color::RED)
-                );
-                println!(
-                    "{} {} | {} | {} | {} | {}",
-                    "env:".color(Color::YELLOW),
-                    NTK_AI_TOKEN_BUDGET_INPUT_PER_REQUEST_ENV.color(Color::CYAN),
-                    NTK_AI_TOKEN_BUDGET_OUTPUT_PER_REQUEST_ENV.color(Color::CYAN),
-                    NTK_AI_TOKEN_BUDGET_TOTAL_PER_REQUEST_ENV.color(Color::CYAN),
-                    NTK_AI_TOKEN_BUDGET_SESSION_TOTAL_ENV.color(Color::CYAN),
-                    NTK_AI_COST_BUDGET_USD_PER_REQUEST_ENV.color(Color::CYAN)
-                );
-                return ExitStatus::Error;
+                return Err(CommandFailure::new(format!(
+                    "AI request rejected by token-economy policy: {reason}"
+                ))
+                .with_hint(ai_token_budget_env_hint()));
             }
         };
 
@@ -3214,107 +3705,64 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
     ) {
         Ok(cost) => cost,
         Err(reason) => {
-            if let Some(fallback_selection) =
+            let Some(fallback_selection) =
                 fallback_ai_model_selection_to_cheap(&model_selection_policy, &model_selection)
-            {
-                model_selection = fallback_selection;
-                apply_ai_model_selection_to_request(&mut request, &model_selection);
-                ai_metrics.increment_counter("runtime_ai_model_selection_guardrail_fallback_total");
-                ai_metrics.increment_counter("runtime_ai_model_selection_tier_cheap_total");
-                ai_metrics.set_gauge(
-                    "runtime_ai_model_selection_last_tier",
-                    ai_model_selection_metric_tier_value(model_selection.tier),
-                );
-                budget_estimate = match evaluate_ai_request_budget(
-                    &request,
-                    intent,
-                    &active_session,
-                    token_policy,
-                ) {
+            else {
+                ai_metrics.increment_counter("runtime_ai_requests_error_total");
+                ai_metrics.increment_counter("runtime_ai_model_selection_guardrail_rejected_total");
+                update_ai_request_rate_gauges(&ai_metrics);
+                return Err(CommandFailure::new(format!(
+                    "AI request rejected by model-selection guardrail: {reason}"
+                ))
+                .with_hint(ai_model_selection_env_hint()));
+            };
+
+            model_selection = fallback_selection;
+            apply_ai_model_selection_to_request(&mut request, &model_selection);
+            ai_metrics.increment_counter("runtime_ai_model_selection_guardrail_fallback_total");
+            ai_metrics.increment_counter("runtime_ai_model_selection_tier_cheap_total");
+            ai_metrics.set_gauge(
+                "runtime_ai_model_selection_last_tier",
+                ai_model_selection_metric_tier_value(model_selection.tier),
+            );
+            budget_estimate =
+                match evaluate_ai_request_budget(&request, intent, &active_session, token_policy) {
                     Ok(estimate) => estimate,
                     Err(recheck_reason) => {
                         ai_metrics.increment_counter("runtime_ai_requests_error_total");
                         ai_metrics.increment_counter("runtime_ai_budget_rejected_total");
                         update_ai_request_rate_gauges(&ai_metrics);
-                        println!(
-                            "{} {}",
-                            "✗ AI request rejected after model-tier fallback:"
-                                .color(Color::RED)
-                                .bold(),
-                            recheck_reason.color(Color::RED)
-                        );
-                        println!(
-                            "{} {} | {} | {} | {} | {}",
-                            "env:".color(Color::YELLOW),
-                            NTK_AI_TOKEN_BUDGET_INPUT_PER_REQUEST_ENV.color(Color::CYAN),
-                            NTK_AI_TOKEN_BUDGET_OUTPUT_PER_REQUEST_ENV.color(Color::CYAN),
-                            NTK_AI_TOKEN_BUDGET_TOTAL_PER_REQUEST_ENV.color(Color::CYAN),
-                            NTK_AI_TOKEN_BUDGET_SESSION_TOTAL_ENV.color(Color::CYAN),
-                            NTK_AI_COST_BUDGET_USD_PER_REQUEST_ENV.color(Color::CYAN)
-                        );
-                        return ExitStatus::Error;
+                        return Err(CommandFailure::new(format!(
+                            "AI request rejected after model-tier fallback: {recheck_reason}"
+                        ))
+                        .with_hint(ai_token_budget_env_hint()));
                     }
                 };
 
-                match evaluate_ai_model_selection_cost_guardrail(
-                    &request,
-                    intent,
-                    &model_selection,
-                    &model_selection_policy,
-                ) {
-                    Ok(fallback_cost) => {
-                        let _ = nettoolskit_ui::append_footer_log(&format!(
-                            "ai: downgraded model tier after guardrail ({} -> {})",
-                            AiModelSelectionTier::Reasoning.as_label(),
-                            AiModelSelectionTier::Cheap.as_label()
-                        ));
-                        fallback_cost
-                    }
-                    Err(fallback_reason) => {
-                        ai_metrics.increment_counter("runtime_ai_requests_error_total");
-                        ai_metrics.increment_counter(
-                            "runtime_ai_model_selection_guardrail_rejected_total",
-                        );
-                        update_ai_request_rate_gauges(&ai_metrics);
-                        println!(
-                            "{} {}",
-                            "✗ AI request rejected by model-selection guardrail:"
-                                .color(Color::RED)
-                                .bold(),
-                            fallback_reason.color(Color::RED)
-                        );
-                        println!(
-                            "{} {} | {} | {} | {}",
-                            "env:".color(Color::YELLOW),
-                            NTK_AI_MODEL_SELECTION_ENABLED_ENV.color(Color::CYAN),
-                            NTK_AI_MODEL_SELECTION_CHEAP_COST_CAP_USD_ENV.color(Color::CYAN),
-                            NTK_AI_MODEL_SELECTION_REASONING_COST_CAP_USD_ENV.color(Color::CYAN),
-                            NTK_AI_MODEL_SELECTION_FALLBACK_TO_CHEAP_ON_GUARDRAIL_ENV
-                                .color(Color::CYAN)
-                        );
-                        return ExitStatus::Error;
-                    }
+            match evaluate_ai_model_selection_cost_guardrail(
+                &request,
+                intent,
+                &model_selection,
+                &model_selection_policy,
+            ) {
+                Ok(fallback_cost) => {
+                    let _ = nettoolskit_ui::append_footer_log(&format!(
+                        "ai: downgraded model tier after guardrail ({} -> {})",
+                        AiModelSelectionTier::Reasoning.as_label(),
+                        AiModelSelectionTier::Cheap.as_label()
+                    ));
+                    fallback_cost
+                }
+                Err(fallback_reason) => {
+                    ai_metrics.increment_counter("runtime_ai_requests_error_total");
+                    ai_metrics
+                        .increment_counter("runtime_ai_model_selection_guardrail_rejected_total");
+                    update_ai_request_rate_gauges(&ai_metrics);
+                    return Err(CommandFailure::new(format!(
+                        "AI request rejected by model-selection guardrail: {fallback_reason}"
+                    ))
+                    .with_hint(ai_model_selection_env_hint()));
                 }
-            } else {
-                ai_metrics.increment_counter("runtime_ai_requests_error_total");
-                ai_metrics.increment_counter("runtime_ai_model_selection_guardrail_rejected_total");
-                update_ai_request_rate_gauges(&ai_metrics);
-                println!(
-                    "{} {}",
-                    "✗ AI request rejected by model-selection guardrail:"
-                        .color(Color::RED)
-                        .bold(),
-                    reason.color(Color::RED)
-                );
-                println!(
-                    "{} {} | {} | {} | {}",
-                    "env:".color(Color::YELLOW),
-                    NTK_AI_MODEL_SELECTION_ENABLED_ENV.color(Color::CYAN),
-                    NTK_AI_MODEL_SELECTION_CHEAP_COST_CAP_USD_ENV.color(Color::CYAN),
-                    NTK_AI_MODEL_SELECTION_REASONING_COST_CAP_USD_ENV.color(Color::CYAN),
-                    NTK_AI_MODEL_SELECTION_FALLBACK_TO_CHEAP_ON_GUARDRAIL_ENV.color(Color::CYAN)
-                );
-                return ExitStatus::Error;
             }
         }
     };
@@ -3350,13 +3798,45 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
             .unwrap_or(0.0),
     );
 
+    let report = |provider: &str,
+                  output: String,
+                  spend: Option<&AiSpendEntry>,
+                  latency: Duration,
+                  retries,
+                  failovers| AiCommandReport {
+        intent: intent.as_label().to_string(),
+        session_id: session_id.clone(),
+        provider: provider.to_string(),
+        provider_route: provider_route_label.clone(),
+        model_tier: model_selection.tier.as_label().to_string(),
+        model: spend.map_or_else(
+            || model_selection.model_label().to_string(),
+            |spend| spend.model.clone(),
+        ),
+        cached: spend.is_none(),
+        output,
+        estimated_input_tokens: budget_estimate.input_tokens as u64,
+        estimated_output_tokens: budget_estimate.output_tokens as u64,
+        estimated_cost_usd: budget_estimate.estimated_cost_usd,
+        input_tokens: spend.map_or(0, |spend| spend.input_tokens),
+        output_tokens: spend.map_or(0, |spend| spend.output_tokens),
+        cost_usd: spend.map_or(0.0, |spend| spend.cost_usd),
+        usage_source: spend
+            .map_or("cache", |spend| spend.usage_source.as_label())
+            .to_string(),
+        retries,
+        failovers,
+        latency_ms: latency.as_millis() as u64,
+        warnings: warnings.clone(),
+    };
+
     let ai_route_signature = format!(
         "{}|tier:{}|model:{}",
         provider_route_label,
         model_selection.tier.as_label(),
         model_selection.model_label()
     );
-    let ai_cache_key = ai_response_cache_key(intent, &prompt, &ai_route_signature);
+    let ai_cache_key = ai_response_cache_key(intent, prompt, &ai_route_signature);
     if token_policy.cache_first_enabled {
         match with_command_cache(|cache| cache.get(&ai_cache_key)) {
             Some(CacheValue::AiResponseText(cached_output)) => {
@@ -3371,26 +3851,25 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
                 record_ai_usage_estimates(&ai_metrics, &request, &cached_output);
                 set_ai_provider_health(&ai_metrics, "cache", true);
                 update_ai_request_rate_gauges(&ai_metrics);
-                println!(
-                    "{}",
-                    format!(
-                        "🤖 AI {} (route: {}, tier: {}, model: {}, source: cache)",
-                        intent.as_label(),
-                        provider_route_label,
-                        model_selection.tier.as_label(),
-                        model_selection.model_label()
-                    )
-                    .color(Color::CYAN)
-                    .bold()
-                );
-                print!("{cached_output}");
-                let _ = io::stdout().flush();
-                if !cached_output.ends_with('\n') {
-                    println!();
+                if console.is_text() {
+                    emit(
+                        CommandOutput::new()
+                            .heading(
+                                format!(
+                                    "🤖 AI {} (route: {}, tier: {}, model: {}, source: cache)",
+                                    intent.as_label(),
+                                    provider_route_label,
+                                    model_selection.tier.as_label(),
+                                    model_selection.model_label()
+                                ),
+                                OutputTone::Info,
+                            )
+                            .text(cached_output.trim_end(), OutputTone::Plain),
+                    );
                 }
-                persist_ai_session_exchange(&session_id, intent, "cache", &prompt, &cached_output);
+                persist_ai_session_exchange(&session_id, intent, "cache", prompt, &cached_output);
                 let _ = nettoolskit_ui::append_footer_log("ai: cache hit");
-                return ExitStatus::Success;
+                return Ok(report("cache", cached_output, None, Duration::ZERO, 0, 0));
             }
             Some(_) | None => {
                 ai_metrics.increment_counter("runtime_ai_cache_misses_total");
@@ -3403,18 +3882,32 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
         }
     }
 
-    println!(
-        "{}",
-        format!(
-            "🤖 AI {} (route: {}, tier: {}, model: {})",
-            intent.as_label(),
-            provider_route_label,
-            model_selection.tier.as_label(),
-            model_selection.model_label()
-        )
-        .color(Color::CYAN)
-        .bold()
-    );
+    if let Err(reason) = enforce_ai_spend_caps(projected_ai_request_cost_usd(
+        &provider_routes,
+        &request,
+        intent,
+    )) {
+        ai_metrics.increment_counter("runtime_ai_requests_error_total");
+        ai_metrics.increment_counter("runtime_ai_spend_cap_rejected_total");
+        update_ai_request_rate_gauges(&ai_metrics);
+        return Err(
+            CommandFailure::new(format!("AI request rejected by spend cap: {reason}"))
+                .with_hint(ai_spend_env_hint()),
+        );
+    }
+
+    if console.is_text() {
+        emit(CommandOutput::new().heading(
+            format!(
+                "🤖 AI {} (route: {}, tier: {}, model: {})",
+                intent.as_label(),
+                provider_route_label,
+                model_selection.tier.as_label(),
+                model_selection.model_label()
+            ),
+            OutputTone::Info,
+        ));
+    }
 
     let request_started = Instant::now();
 
@@ -3439,7 +3932,8 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
         Ok(routed) => {
             let provider_id = routed.provider_id.as_str();
             let provider_metric = sanitize_metric_component(provider_id);
-            ai_metrics.record_timing("runtime_ai_request_latency", request_started.elapsed());
+            let latency = request_started.elapsed();
+            ai_metrics.record_timing("runtime_ai_request_latency", latency);
             ai_metrics.set_gauge("runtime_ai_last_retry_count", routed.retries as f64);
             ai_metrics.set_gauge(
                 "runtime_ai_last_provider_failover_count",
@@ -3450,21 +3944,19 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
                 ai_metrics.increment_counter("runtime_ai_requests_empty_stream_total");
                 set_ai_provider_health(&ai_metrics, provider_id, false);
                 update_ai_request_rate_gauges(&ai_metrics);
-                println!(
-                    "{}",
-                    "✗ AI provider returned an empty stream"
-                        .color(Color::RED)
-                        .bold()
-                );
                 let _ = nettoolskit_ui::append_footer_log("ai: empty stream");
-                return ExitStatus::Error;
+                return Err(CommandFailure::new("AI provider returned an empty stream"));
             }
 
             let mut output = String::new();
-            for chunk in routed.chunks {
+            for chunk in &routed.chunks {
                 if !chunk.content.is_empty() {
-                    print!("{}", chunk.content);
-                    let _ = io::stdout().flush();
+                    // Tokens stream straight to the terminal as they arrive; callers
+                    // that capture output run silent and get the full text in the report.
+                    if console.is_text() {
+                        print!("{}", chunk.content);
+                        let _ = io::stdout().flush();
+                    }
                     output.push_str(&chunk.content);
                     let _ = nettoolskit_ui::append_footer_log(&format!(
                         "ai: stream chunk ({} chars)",
@@ -3476,20 +3968,18 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
                 }
             }
 
-            println!();
+            if console.is_text() {
+                println!();
+            }
             if output.trim().is_empty() {
                 ai_metrics.increment_counter("runtime_ai_requests_error_total");
                 ai_metrics.increment_counter("runtime_ai_requests_empty_output_total");
                 set_ai_provider_health(&ai_metrics, provider_id, false);
                 update_ai_request_rate_gauges(&ai_metrics);
-                println!(
-                    "{}",
-                    "✗ AI provider returned empty output content"
-                        .color(Color::RED)
-                        .bold()
-                );
                 let _ = nettoolskit_ui::append_footer_log("ai: empty output content");
-                return ExitStatus::Error;
+                return Err(CommandFailure::new(
+                    "AI provider returned empty output content",
+                ));
             }
 
             if token_policy.cache_first_enabled {
@@ -3506,8 +3996,22 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
                 }
             }
 
-            persist_ai_session_exchange(&session_id, intent, provider_id, &prompt, &output);
+            persist_ai_session_exchange(&session_id, intent, provider_id, prompt, &output);
             record_ai_usage_estimates(&ai_metrics, &request, &output);
+            let (reported_model, reported_usage) = reported_ai_model_and_usage(&routed.chunks);
+            let model = reported_model.unwrap_or_else(|| model_selection.model_label().to_string());
+            let spend = record_ai_spend(
+                &ai_metrics,
+                AiSpendExchange {
+                    session_id: &session_id,
+                    intent,
+                    provider_id,
+                    model: &model,
+                    request: &request,
+                    output: &output,
+                },
+                reported_usage,
+            );
             ai_metrics.increment_counter("runtime_ai_requests_success_total");
             ai_metrics.increment_counter(format!(
                 "runtime_ai_provider_{}_success_total",
@@ -3516,7 +4020,14 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
             set_ai_provider_health(&ai_metrics, provider_id, true);
             update_ai_request_rate_gauges(&ai_metrics);
             let _ = nettoolskit_ui::append_footer_log("ai: stream completed");
-            ExitStatus::Success
+            Ok(report(
+                provider_id,
+                output,
+                Some(&spend),
+                latency,
+                routed.retries as u64,
+                routed.failovers as u64,
+            ))
         }
         Err(routed_error) => {
             let provider_id = routed_error.provider_id.as_str();
@@ -3537,21 +4048,17 @@ async fn process_ai_command(parts: &[&str], subcommand: Option<&str>) -> ExitSta
             }
             set_ai_provider_health(&ai_metrics, provider_id, false);
             update_ai_request_rate_gauges(&ai_metrics);
-            println!(
-                "{} {}",
-                "✗ AI request failed:".color(Color::RED).bold(),
-                error.to_string().color(Color::RED)
-            );
+            let _ = nettoolskit_ui::append_footer_log(&format!("ai: request failed - {error}"));
+            let mut failure = CommandFailure::new(format!("AI request failed: {error}"));
             if let Some(guidance) = ai_error_guidance_message(&error) {
-                println!("{}", guidance.color(Color::YELLOW));
+                failure = failure.with_hint(guidance);
             }
-            let _ = nettoolskit_ui::append_footer_log(&format!("ai: request failed - {error}"));
-            ExitStatus::Error
+            Err(failure)
         }
     }
 }
 
-fn current_unix_timestamp_ms() -> u64 {
+pub(super) fn current_unix_timestamp_ms() -> u64 {
     SystemTime::now()
         .duration_since(SystemTime::UNIX_EPOCH)
         .map(|duration| duration.as_millis() as u64)
@@ -3567,7 +4074,7 @@ fn task_intent_kind_label(kind: TaskIntentKind) -> &'static str {
     kind.as_str()
 }
 
-fn parse_task_intent_kind(value: &str) -> Option<TaskIntentKind> {
+pub(super) fn parse_task_intent_kind(value: &str) -> Option<TaskIntentKind> {
     TaskIntentKind::from_alias(value)
 }
 
@@ -3654,30 +4161,20 @@ fn evaluate_ai_task_submit_budget(
     })
 }
 
-fn task_status_label(status: TaskExecutionStatus) -> &'static str {
-    match status {
-        TaskExecutionStatus::Queued => "queued",
-        TaskExecutionStatus::Running => "running",
-        TaskExecutionStatus::Succeeded => "succeeded",
-        TaskExecutionStatus::Failed => "failed",
-        TaskExecutionStatus::Cancelled => "cancelled",
-    }
-}
-
-fn task_service_endpoint_from_env() -> Option<String> {
-    std::env::var("NTK_TASK_SERVICE_ENDPOINT")
-        .ok()
-        .map(|value| value.trim().to_string())
-        .filter(|value| !value.is_empty())
+pub(super) fn task_status_label(status: TaskExecutionStatus) -> &'static str {
+    status.as_str()
 }
 
-fn resolve_task_execution_target(runtime_mode: RuntimeMode) -> (String, Option<String>) {
+fn resolve_task_execution_target(
+    runtime_mode: RuntimeMode,
+    remote_failure: Option<&RemoteServiceError>,
+) -> (String, Option<String>) {
     match runtime_mode {
-        RuntimeMode::Service => match task_service_endpoint_from_env() {
+        RuntimeMode::Service => match AppConfig::load().general.service_endpoint {
             Some(endpoint) => (
                 "background-worker-local".to_string(),
                 Some(format!(
-                    "Background service endpoint is configured ({endpoint}) but remote dispatch is not enabled yet; using embedded background worker."
+                    "Service runtime executes tasks on the embedded background worker; service endpoint ({endpoint}) only applies to CLI clients."
                 )),
             ),
             None => (
@@ -3690,15 +4187,33 @@ fn resolve_task_execution_target(runtime_mode: RuntimeMode) -> (String, Option<S
         },
         RuntimeMode::Cli => (
             "local-fallback".to_string(),
-            task_service_endpoint_from_env().map(|endpoint| {
-                format!(
-                    "CLI runtime keeps execution local; service endpoint ({endpoint}) ignored for now."
-                )
-            }),
+            remote_failure.map(|error| format!("Remote dispatch failed ({error}); executing locally.")),
         ),
     }
 }
 
+/// Client for the configured service endpoint, when `/task` commands should go remote.
+pub(super) fn remote_task_client() -> Option<RemoteServiceClient> {
+    RemoteServiceClient::from_environment()
+}
+
+/// Record a remote call that fell back to the local registry.
+fn note_remote_fallback(error: &RemoteServiceError, console: ConsoleMode) {
+    runtime_metrics().increment_counter("runtime_remote_task_fallback_total");
+    warn!(error = %error, "Remote task service unreachable; using local registry");
+    if console.is_text() {
+        emit(CommandOutput::new().text(
+            format!("⚠ {error}; using local task registry."),
+            OutputTone::Warning,
+        ));
+    }
+}
+
+fn print_remote_task_error(error: &RemoteServiceError) {
+    runtime_metrics().increment_counter("runtime_remote_task_errors_total");
+    emit(CommandOutput::new().error(format!("Remote task request failed: {error}"), Vec::new()));
+}
+
 fn task_worker_policy_from_env() -> TaskWorkerPolicy {
     let mut policy = TaskWorkerPolicy::default();
 
@@ -3755,14 +4270,15 @@ fn set_service_intent_override(policy: &mut ServiceAutomationPolicy, intent: Tas
 }
 
 fn service_automation_policy_from_env() -> ServiceAutomationPolicy {
-    let profile = std::env::var(NTK_SERVICE_AUTOMATION_PROFILE_ENV)
-        .ok()
+    let source = PolicySource::load();
+    let profile = source
+        .var(NTK_SERVICE_AUTOMATION_PROFILE_ENV)
         .as_deref()
         .and_then(ServiceAutomationProfile::parse)
         .unwrap_or(ServiceAutomationProfile::Balanced);
     let mut policy = ServiceAutomationPolicy::for_profile(profile);
 
-    if let Ok(value) = std::env::var(NTK_SERVICE_ALLOWED_INTENTS_ENV) {
+    if let Some(value) = source.var(NTK_SERVICE_ALLOWED_INTENTS_ENV) {
         let trimmed = value.trim();
         if !trimmed.is_empty() {
             if matches!(trimmed.to_ascii_lowercase().as_str(), "*" | "all") {
@@ -3785,25 +4301,25 @@ fn service_automation_policy_from_env() -> ServiceAutomationPolicy {
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_SERVICE_MAX_PAYLOAD_BYTES_ENV) {
+    if let Some(value) = source.var(NTK_SERVICE_MAX_PAYLOAD_BYTES_ENV) {
         if let Some(parsed) = parse_nonzero_usize(&value) {
             policy.max_payload_bytes = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_SERVICE_SUBMIT_BUDGET_ENV) {
+    if let Some(value) = source.var(NTK_SERVICE_SUBMIT_BUDGET_ENV) {
         if let Some(parsed) = parse_nonzero_usize(&value) {
             policy.max_submissions = parsed;
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_SERVICE_SUBMIT_WINDOW_SECONDS_ENV) {
+    if let Some(value) = source.var(NTK_SERVICE_SUBMIT_WINDOW_SECONDS_ENV) {
         if let Some(parsed) = parse_timeout_millis(&value) {
             policy.submission_window = Duration::from_secs(parsed);
         }
     }
 
-    if let Ok(value) = std::env::var(NTK_SERVICE_MAX_INFLIGHT_TASKS_ENV) {
+    if let Some(value) = source.var(NTK_SERVICE_MAX_INFLIGHT_TASKS_ENV) {
         if let Some(parsed) = parse_nonzero_usize(&value) {
             policy.max_inflight_tasks = parsed;
         }
@@ -3883,19 +4399,21 @@ impl TaskWorkerCallbacks<QueuedTask> for ProcessorTaskWorkerCallbacks {
     }
 
     fn on_cancelled_before_start(&self, task: &QueuedTask) {
-        let _ = update_task_record_status(
+        let record = update_task_record_status(
             &task.id,
             TaskExecutionStatus::Cancelled,
             "Task was cancelled before worker execution",
         );
+        notify_task_origin(record.as_ref());
     }
 
     fn on_cancelled_after_attempt(&self, task: &QueuedTask) {
-        let _ = update_task_record_status(
+        let record = update_task_record_status(
             &task.id,
             TaskExecutionStatus::Cancelled,
             "Cancellation requested while task was running",
         );
+        notify_task_origin(record.as_ref());
     }
 
     fn on_retry_scheduled(
@@ -3928,29 +4446,78 @@ impl TaskWorkerCallbacks<QueuedTask> for ProcessorTaskWorkerCallbacks {
             TaskWorkerResultStatus::Failed => TaskExecutionStatus::Failed,
             TaskWorkerResultStatus::Cancelled => TaskExecutionStatus::Cancelled,
         };
-        let _ = update_task_record_status(
+        let record = update_task_record_status(
             &task.id,
             status,
             format!("{} (attempt {attempt}/{max_attempts})", result.detail),
         );
+        notify_task_origin(record.as_ref());
     }
 
     fn execute(&self, task: &QueuedTask) -> TaskWorkerFuture {
+        let task_id = task.id.clone();
         let intent = task.intent.clone();
         let runtime_mode = task.runtime_mode;
-        Box::pin(async move {
-            let (status, detail) = execute_task_locally(&intent, runtime_mode).await;
-            match status {
-                TaskExecutionStatus::Succeeded => TaskWorkerResult::succeeded(detail),
-                TaskExecutionStatus::Cancelled => TaskWorkerResult::cancelled(detail),
-                TaskExecutionStatus::Failed
-                | TaskExecutionStatus::Queued
-                | TaskExecutionStatus::Running => TaskWorkerResult::failed(detail),
+        let execution_span = info_span!(
+            "orchestrator.task_execution",
+            task_id = %task.id,
+            runtime_mode = %runtime_mode,
+            task_intent = %task_intent_kind_label(intent.kind),
+        );
+        if let Some(trace_context) = &task.trace_context {
+            set_span_parent(&execution_span, trace_context);
+        }
+        Box::pin(
+            async move {
+                // Background workers have no terminal; the result is stored on the task.
+                let execution = execute_task_locally_for_operator(
+                    &task_id,
+                    &intent,
+                    runtime_mode,
+                    ConsoleMode::Silent,
+                )
+                .await;
+                set_task_output(&task_id, execution.output);
+                let detail = execution.detail;
+                match execution.status {
+                    TaskExecutionStatus::Succeeded => TaskWorkerResult::succeeded(detail),
+                    TaskExecutionStatus::Cancelled => TaskWorkerResult::cancelled(detail),
+                    TaskExecutionStatus::Failed
+                    | TaskExecutionStatus::Queued
+                    | TaskExecutionStatus::AwaitingApproval
+                    | TaskExecutionStatus::Running => TaskWorkerResult::failed(detail),
+                }
             }
-        })
+            .instrument(execution_span),
+        )
     }
 }
 
+/// Queue a completion notification for tasks submitted from a chat channel.
+fn notify_task_origin(record: Option<&TaskRecord>) {
+    let Some(record) = record else {
+        return;
+    };
+    let Some(origin) = record.chatops_origin.clone() else {
+        return;
+    };
+    queue_task_completion(ChatOpsTaskCompletion {
+        origin,
+        task_id: record.id.clone(),
+        status: record.status,
+        detail: record.status_message.clone(),
+        attempts: record.attempts,
+        max_attempts: record.max_attempts,
+        duration: Duration::from_millis(
+            record
+                .updated_at_unix_ms
+                .saturating_sub(record.created_at_unix_ms),
+        ),
+        output: record.output.clone(),
+        control_envelope: record.control_envelope.clone(),
+    });
+}
+
 fn task_worker_runtime() -> &'static TaskWorkerRuntime<QueuedTask> {
     TASK_WORKER_RUNTIME.get_or_init(|| {
         let policy = task_worker_policy_from_env();
@@ -3962,11 +4529,13 @@ fn submit_task_to_worker(
     task_id: String,
     intent: TaskIntent,
     runtime_mode: RuntimeMode,
+    trace_context: Option<TraceContext>,
 ) -> Result<(), String> {
     match task_worker_runtime().submit(QueuedTask {
         id: task_id,
         intent,
         runtime_mode,
+        trace_context,
     }) {
         Ok(()) => Ok(()),
         Err(TaskWorkerSubmitError::QueueFull) => Err(
@@ -4058,9 +4627,18 @@ fn set_task_execution_target(task_id: &str, execution_target: String) -> Option<
     updated
 }
 
-fn task_status_to_exit_status(status: TaskExecutionStatus) -> ExitStatus {
+fn set_task_output(task_id: &str, output: CommandOutput) {
+    with_task_registry(|registry| {
+        if let Some(record) = registry.get_mut(task_id) {
+            record.output = (!output.is_empty()).then_some(output);
+        }
+    });
+}
+
+pub(super) fn task_status_to_exit_status(status: TaskExecutionStatus) -> ExitStatus {
     match status {
         TaskExecutionStatus::Queued
+        | TaskExecutionStatus::AwaitingApproval
         | TaskExecutionStatus::Running
         | TaskExecutionStatus::Succeeded => ExitStatus::Success,
         TaskExecutionStatus::Cancelled => ExitStatus::Interrupted,
@@ -4068,10 +4646,52 @@ fn task_status_to_exit_status(status: TaskExecutionStatus) -> ExitStatus {
     }
 }
 
+/// Result of running a task intent in this process.
+struct LocalTaskExecution {
+    status: TaskExecutionStatus,
+    detail: String,
+    output: CommandOutput,
+}
+
+impl LocalTaskExecution {
+    fn succeeded(detail: impl Into<String>, output: CommandOutput) -> Self {
+        Self {
+            status: TaskExecutionStatus::Succeeded,
+            detail: detail.into(),
+            output,
+        }
+    }
+
+    /// Failure whose output is the detail itself, rendered as an error block.
+    fn failed(detail: impl Into<String>) -> Self {
+        let detail = detail.into();
+        Self {
+            status: TaskExecutionStatus::Failed,
+            output: CommandOutput::new().error(detail.clone(), Vec::new()),
+            detail,
+        }
+    }
+}
+
+/// Execute a task with its control-envelope operator charged for the AI spend it causes.
+async fn execute_task_locally_for_operator(
+    task_id: &str,
+    intent: &TaskIntent,
+    runtime_mode: RuntimeMode,
+    console: ConsoleMode,
+) -> LocalTaskExecution {
+    let execution = execute_task_locally(intent, runtime_mode, console);
+    match task_control_envelope(task_id) {
+        Some(control) => with_ai_spend_operator(control.operator.id, execution).await,
+        None => execution.await,
+    }
+}
+
 async fn execute_task_locally(
     intent: &TaskIntent,
     runtime_mode: RuntimeMode,
-) -> (TaskExecutionStatus, String) {
+    console: ConsoleMode,
+) -> LocalTaskExecution {
     let metrics = runtime_metrics().clone();
     let payload = intent.payload.trim().to_string();
     if let Err(reason) = enforce_task_tool_scope(
@@ -4082,587 +4702,208 @@ async fn execute_task_locally(
         &metrics,
     ) {
         metrics.increment_counter("runtime_tool_scope_rejected_execution_total");
-        return (
-            TaskExecutionStatus::Failed,
-            format!("Secure tool scope policy rejected execution: {reason}"),
-        );
+        return LocalTaskExecution::failed(format!(
+            "Secure tool scope policy rejected execution: {reason}"
+        ));
     }
 
     match intent.kind {
-        TaskIntentKind::AiAsk => {
-            let owned_parts = ["/ai".to_string(), "ask".to_string(), payload];
-            let refs = owned_parts.iter().map(String::as_str).collect::<Vec<_>>();
-            let status = process_ai_command(&refs, Some("ask")).await;
-            let outcome = match status {
-                ExitStatus::Success => TaskExecutionStatus::Succeeded,
-                ExitStatus::Interrupted => TaskExecutionStatus::Cancelled,
-                ExitStatus::Error => TaskExecutionStatus::Failed,
-            };
-            (
-                outcome,
-                format!("Delegated to `/ai ask` (status: {status:?})"),
-            )
-        }
-        TaskIntentKind::AiPlan => {
-            let owned_parts = ["/ai".to_string(), "plan".to_string(), payload];
-            let refs = owned_parts.iter().map(String::as_str).collect::<Vec<_>>();
-            let status = process_ai_command(&refs, Some("plan")).await;
-            let outcome = match status {
-                ExitStatus::Success => TaskExecutionStatus::Succeeded,
-                ExitStatus::Interrupted => TaskExecutionStatus::Cancelled,
-                ExitStatus::Error => TaskExecutionStatus::Failed,
-            };
-            (
-                outcome,
-                format!("Delegated to `/ai plan` (status: {status:?})"),
-            )
-        }
-        TaskIntentKind::AiExplain => {
-            let owned_parts = ["/ai".to_string(), "explain".to_string(), payload];
-            let refs = owned_parts.iter().map(String::as_str).collect::<Vec<_>>();
-            let status = process_ai_command(&refs, Some("explain")).await;
-            let outcome = match status {
-                ExitStatus::Success => TaskExecutionStatus::Succeeded,
-                ExitStatus::Interrupted => TaskExecutionStatus::Cancelled,
-                ExitStatus::Error => TaskExecutionStatus::Failed,
+        TaskIntentKind::AiAsk
+        | TaskIntentKind::AiPlan
+        | TaskIntentKind::AiExplain
+        | TaskIntentKind::AiApplyDryRun => {
+            let Some(ai_intent) = ai_intent_from_task_intent(intent.kind) else {
+                return LocalTaskExecution::failed("Task intent is not mapped to an AI intent");
             };
-            (
-                outcome,
-                format!("Delegated to `/ai explain` (status: {status:?})"),
-            )
-        }
-        TaskIntentKind::AiApplyDryRun => {
-            let owned_parts = [
-                "/ai".to_string(),
-                "apply".to_string(),
-                "--dry-run".to_string(),
-                payload,
-            ];
-            let refs = owned_parts.iter().map(String::as_str).collect::<Vec<_>>();
-            let status = process_ai_command(&refs, Some("apply")).await;
-            let outcome = match status {
-                ExitStatus::Success => TaskExecutionStatus::Succeeded,
-                ExitStatus::Interrupted => TaskExecutionStatus::Cancelled,
-                ExitStatus::Error => TaskExecutionStatus::Failed,
+            let command = match ai_intent {
+                AiIntent::ApplyDryRun => "/ai apply --dry-run".to_string(),
+                other => format!("/ai {}", other.as_label()),
             };
-            (
-                outcome,
-                format!("Delegated to `/ai apply --dry-run` (status: {status:?})"),
-            )
+            match run_ai_request(ai_intent, &payload, true, false, console).await {
+                Ok(report) => LocalTaskExecution::succeeded(
+                    format!("Delegated to `{command}` (status: Success)"),
+                    report.to_output(),
+                ),
+                Err(failure) => {
+                    if console.is_text() {
+                        print_command_failure(&failure);
+                    }
+                    LocalTaskExecution {
+                        status: TaskExecutionStatus::Failed,
+                        detail: format!("Delegated to `{command}` failed: {}", failure.message),
+                        output: failure.to_output(),
+                    }
+                }
+            }
         }
         TaskIntentKind::CommandExecution => {
             if payload.trim_start().starts_with("/task") || payload.starts_with("task ") {
-                return (
-                    TaskExecutionStatus::Failed,
-                    "Nested `/task` command execution is not allowed".to_string(),
+                return LocalTaskExecution::failed(
+                    "Nested `/task` command execution is not allowed",
                 );
             }
 
-            (
-                TaskExecutionStatus::Failed,
-                "Local fallback for `command` intent is not enabled yet; use ai-* intents or run the command directly."
-                    .to_string(),
+            LocalTaskExecution::failed(
+                "Local fallback for `command` intent is not enabled yet; use ai-* intents or run the command directly.",
             )
         }
         TaskIntentKind::RepoWorkflow => execute_repo_workflow_intent(&payload),
     }
 }
 
-fn execute_repo_workflow_intent(payload: &str) -> (TaskExecutionStatus, String) {
+fn execute_repo_workflow_intent(payload: &str) -> LocalTaskExecution {
     let request = match parse_repo_workflow_payload(payload) {
         Ok(parsed) => parsed,
         Err(error) => {
-            return (
-                TaskExecutionStatus::Failed,
-                format!("Repository workflow payload is invalid: {error}"),
-            );
+            return LocalTaskExecution::failed(format!(
+                "Repository workflow payload is invalid: {error}"
+            ));
         }
     };
 
     let policy = RepoWorkflowPolicy::from_env();
     match execute_repo_workflow(&request, &policy) {
-        Ok(result) => (TaskExecutionStatus::Succeeded, result.summary),
-        Err(error) => (
-            TaskExecutionStatus::Failed,
-            format!("Repository workflow was rejected: {error}"),
-        ),
+        Ok(result) => {
+            let steps = result
+                .plan
+                .steps
+                .iter()
+                .enumerate()
+                .map(|(index, step)| vec![(index + 1).to_string(), step.clone()])
+                .collect();
+            let output = CommandOutput::new()
+                .heading("🔧 Repository Workflow", OutputTone::Success)
+                .key_values([
+                    (
+                        "workspace",
+                        result.plan.workspace_path.display().to_string(),
+                    ),
+                    ("executed", result.executed.to_string()),
+                ])
+                .table(["step", "command"], steps)
+                .text(result.summary.clone(), OutputTone::Plain);
+            LocalTaskExecution::succeeded(result.summary, output)
+        }
+        Err(error) => {
+            LocalTaskExecution::failed(format!("Repository workflow was rejected: {error}"))
+        }
     }
 }
 
-fn print_task_usage() {
-    use nettoolskit_ui::Color;
-    println!("{}", "🗂️ Task Manager Commands".color(Color::CYAN).bold());
-    println!(
-        "  {}",
-        "/task submit <intent> <payload>".color(Color::GREEN)
-    );
-    println!("  {}", "/task list".color(Color::GREEN));
-    println!("  {}", "/task watch <task-id>".color(Color::GREEN));
-    println!("  {}", "/task cancel <task-id>".color(Color::GREEN));
-    println!();
-    println!("{}", "Supported intents:".color(Color::WHITE).bold());
-    println!("  {}", "command".color(Color::CYAN));
-    println!("  {}", "ai-ask".color(Color::CYAN));
-    println!("  {}", "ai-plan".color(Color::CYAN));
-    println!("  {}", "ai-explain".color(Color::CYAN));
-    println!("  {}", "ai-apply-dry-run".color(Color::CYAN));
-    println!("  {}", "repo-workflow".color(Color::CYAN));
-    println!();
-    println!(
-        "{}",
-        "Service automation policy env:".color(Color::WHITE).bold()
-    );
-    println!(
-        "  {}",
-        format!(
-            "{NTK_SERVICE_AUTOMATION_PROFILE_ENV}, {NTK_SERVICE_ALLOWED_INTENTS_ENV}, {NTK_SERVICE_SUBMIT_BUDGET_ENV}"
-        )
-        .color(Color::CYAN)
-    );
-    println!(
-        "  {}",
-        format!(
-            "{NTK_AI_TOKEN_BUDGET_INPUT_PER_REQUEST_ENV}, {NTK_AI_TOKEN_BUDGET_TOTAL_PER_REQUEST_ENV}, {NTK_AI_COST_BUDGET_USD_PER_REQUEST_ENV}"
-        )
-        .color(Color::CYAN)
-    );
-    println!(
-        "  {}",
-        format!(
-            "{NTK_AI_MODEL_SELECTION_ENABLED_ENV}, {NTK_AI_MODEL_SELECTION_CHEAP_MODEL_ENV}, {NTK_AI_MODEL_SELECTION_REASONING_MODEL_ENV}, {NTK_AI_MODEL_SELECTION_CHEAP_COST_CAP_USD_ENV}, {NTK_AI_MODEL_SELECTION_REASONING_COST_CAP_USD_ENV}"
-        )
-        .color(Color::CYAN)
-    );
-    println!(
-        "  {}",
-        format!(
-            "{NTK_AI_SESSION_COMPRESSION_MODE_ENV}, {NTK_AI_SESSION_COMPRESSION_MAX_CHARS_ENV}, {NTK_AI_SESSION_DELTA_MIN_SHARED_PREFIX_CHARS_ENV}"
-        )
-        .color(Color::CYAN)
-    );
-    println!(
-        "  {}",
-        format!(
-            "{NTK_AI_SLO_MAX_P95_LATENCY_MS_ENV}, {NTK_AI_SLO_MIN_SUCCESS_RATE_PCT_ENV}, {NTK_AI_SLO_MAX_TOKENS_PER_TASK_ENV}, {NTK_AI_SLO_MAX_COST_USD_PER_TASK_ENV}"
-        )
-        .color(Color::CYAN)
-    );
-    println!(
-        "  {}",
-        format!(
-            "{NTK_TOOL_SCOPE_ENABLED_ENV}, {NTK_TOOL_SCOPE_ALLOWED_TOOLS_ENV}, {}",
-            tool_scope_intent_env(TaskIntentKind::AiPlan)
-        )
-        .color(Color::CYAN)
-    );
-}
-
-fn print_task_list(records: &[TaskRecord]) {
-    use nettoolskit_ui::Color;
-    if records.is_empty() {
-        println!("{}", "No task records found.".color(Color::YELLOW));
-        return;
-    }
-
-    println!("{}", "🗂️ Local Task Records".color(Color::CYAN).bold());
-    for record in records {
-        println!(
-            "  {} | {} | {} | mode:{} | target:{} | attempts:{}/{}",
-            record.id.color(Color::WHITE),
-            task_status_label(record.status).color(Color::GREEN),
-            task_intent_kind_label(record.intent.kind).color(Color::CYAN),
-            record.runtime_mode.to_string().color(Color::WHITE),
-            record.execution_target.color(Color::YELLOW),
-            record.attempts,
-            record.max_attempts,
-        );
-    }
+/// Operator decision on a task waiting in the approval queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TaskApprovalVerdict {
+    /// Release the task for execution.
+    Approve,
+    /// Cancel the task without running it.
+    Reject,
 }
 
-async fn submit_task_intent(
-    intent: TaskIntent,
-    runtime_mode: RuntimeMode,
-    control_envelope: Option<ControlEnvelope>,
-) -> TaskSubmissionOutcome {
-    use nettoolskit_ui::Color;
-
-    let metrics = runtime_metrics().clone();
-    let intent_kind = intent.kind;
-    let payload = intent.payload.trim().to_string();
-
-    if let Some(control) = &control_envelope {
-        metrics.increment_counter("runtime_control_envelope_submissions_total");
-        info!(
-            request_id = %control.request_id,
-            correlation_id = control.correlation_id.as_deref().unwrap_or(""),
-            operator_id = %control.operator.id,
-            session_id = %control.session.id,
-            task_intent = %task_intent_kind_label(control.task.kind),
-            "Processing typed control envelope submission"
-        );
-    }
-
-    if let Err(reason) = enforce_task_tool_scope(
-        runtime_mode,
-        intent_kind,
-        payload.as_str(),
-        "task_submit",
-        &metrics,
-    ) {
-        metrics.increment_counter("runtime_tool_scope_rejected_submit_total");
-        println!(
-            "{} {}",
-            "✗ Task rejected by secure tool-scope policy:"
-                .color(Color::RED)
-                .bold(),
-            reason.color(Color::RED)
-        );
-        println!(
-            "{} {} | {} | {}",
-            "env:".color(Color::YELLOW),
-            NTK_TOOL_SCOPE_ENABLED_ENV.color(Color::CYAN),
-            NTK_TOOL_SCOPE_ALLOWED_TOOLS_ENV.color(Color::CYAN),
-            tool_scope_intent_env(intent_kind).color(Color::CYAN)
-        );
-        return TaskSubmissionOutcome::rejected(runtime_mode, ExitStatus::Error);
-    }
-
-    if let Some(ai_intent) = ai_intent_from_task_intent(intent_kind) {
-        let token_policy = ai_token_economy_policy_from_env();
-        let model_selection_policy = ai_model_selection_policy_from_env();
-        if let Err(reason) = evaluate_ai_task_submit_budget(
-            ai_intent,
-            payload.as_str(),
-            token_policy,
-            &model_selection_policy,
-        ) {
-            println!(
-                "{} {}",
-                "✗ Task rejected by AI budget/model policy:"
-                    .color(Color::RED)
-                    .bold(),
-                reason.color(Color::RED)
-            );
-            println!(
-                "{} {} | {} | {} | {} | {} | {}",
-                "env:".color(Color::YELLOW),
-                NTK_AI_TOKEN_BUDGET_INPUT_PER_REQUEST_ENV.color(Color::CYAN),
-                NTK_AI_TOKEN_BUDGET_OUTPUT_PER_REQUEST_ENV.color(Color::CYAN),
-                NTK_AI_TOKEN_BUDGET_TOTAL_PER_REQUEST_ENV.color(Color::CYAN),
-                NTK_AI_COST_BUDGET_USD_PER_REQUEST_ENV.color(Color::CYAN),
-                NTK_AI_MODEL_SELECTION_CHEAP_COST_CAP_USD_ENV.color(Color::CYAN),
-                NTK_AI_MODEL_SELECTION_REASONING_COST_CAP_USD_ENV.color(Color::CYAN)
-            );
-            return TaskSubmissionOutcome::rejected(runtime_mode, ExitStatus::Error);
-        }
-    }
-
-    if runtime_mode == RuntimeMode::Service {
-        let policy = service_automation_policy_from_env();
-
-        if !policy.allows_intent(intent_kind) {
-            metrics.increment_counter("runtime_service_task_submit_policy_rejected_total");
-            println!(
-                "{} {}",
-                "✗ Service automation policy rejected intent:"
-                    .color(Color::RED)
-                    .bold(),
-                task_intent_kind_label(intent_kind).color(Color::YELLOW)
-            );
-            println!("  profile: {}", policy.profile.as_str().color(Color::WHITE));
-            println!(
-                "  allowed: {}",
-                policy.allowed_intents_display().color(Color::WHITE)
-            );
-            println!(
-                "  env: {} / {}",
-                NTK_SERVICE_AUTOMATION_PROFILE_ENV.color(Color::CYAN),
-                NTK_SERVICE_ALLOWED_INTENTS_ENV.color(Color::CYAN)
-            );
-            return TaskSubmissionOutcome::rejected(runtime_mode, ExitStatus::Error);
-        }
+/// Apply an operator decision to a parked task and record it in the approval audit log.
+///
+/// Approved tasks are dispatched like a fresh submission; rejected tasks are cancelled.
+pub(super) async fn decide_task_approval(
+    task_id: &str,
+    verdict: TaskApprovalVerdict,
+    note: Option<&str>,
+    approver: &OperatorContext,
+    console: ConsoleMode,
+) -> Result<TaskSnapshot, String> {
+    let policy = TaskApprovalPolicy::from_env();
+    policy.authorize_approver(approver)?;
+
+    let note = note.map(str::trim).filter(|note| !note.is_empty());
+    let suffix = note.map(|note| format!(": {note}")).unwrap_or_default();
+    let (next_status, approval_state, message) = match verdict {
+        TaskApprovalVerdict::Approve => (
+            TaskExecutionStatus::Queued,
+            ApprovalState::Approved,
+            format!("Approved by {}{suffix}", approver.id),
+        ),
+        TaskApprovalVerdict::Reject => (
+            TaskExecutionStatus::Cancelled,
+            ApprovalState::Rejected,
+            format!("Rejected by {}{suffix}", approver.id),
+        ),
+    };
 
-        let payload_bytes = payload.len();
-        if payload_bytes > policy.max_payload_bytes {
-            metrics.increment_counter("runtime_service_task_submit_payload_rejected_total");
-            println!(
-                "{} {}",
-                "✗ Service payload exceeded automation policy budget:"
-                    .color(Color::RED)
-                    .bold(),
-                format!(
-                    "{} bytes > {} bytes",
-                    payload_bytes, policy.max_payload_bytes
-                )
-                .color(Color::YELLOW)
-            );
-            println!(
-                "  env: {}",
-                NTK_SERVICE_MAX_PAYLOAD_BYTES_ENV.color(Color::CYAN)
-            );
-            return TaskSubmissionOutcome::rejected(runtime_mode, ExitStatus::Error);
-        }
-
-        let inflight = service_inflight_task_count();
-        if inflight >= policy.max_inflight_tasks {
-            metrics.increment_counter("runtime_service_task_submit_inflight_rejected_total");
-            println!(
-                "{} {}",
-                "✗ Service in-flight task budget reached:"
-                    .color(Color::RED)
-                    .bold(),
-                format!("{inflight}/{}", policy.max_inflight_tasks).color(Color::YELLOW)
-            );
-            println!(
-                "  env: {}",
-                NTK_SERVICE_MAX_INFLIGHT_TASKS_ENV.color(Color::CYAN)
-            );
-            return TaskSubmissionOutcome::rejected(runtime_mode, ExitStatus::Error);
+    // Check and transition under one lock so concurrent decisions cannot both win.
+    let decided = with_task_registry(|registry| {
+        let record = registry
+            .get_mut(task_id)
+            .ok_or_else(|| format!("Task not found: {task_id}"))?;
+        if record.status != TaskExecutionStatus::AwaitingApproval {
+            return Err(format!(
+                "Task is not awaiting approval (status: {})",
+                task_status_label(record.status)
+            ));
         }
-
-        match enforce_service_submission_budget(policy) {
-            Ok(used_submissions) => {
-                metrics.increment_counter("runtime_service_task_submit_accepted_total");
-                metrics.set_gauge(
-                    "runtime_service_task_submit_budget_used",
-                    used_submissions as f64,
-                );
-            }
-            Err(retry_after_seconds) => {
-                metrics.increment_counter("runtime_service_task_submit_budget_rejected_total");
-                metrics.set_gauge(
-                    "runtime_service_task_submit_budget_retry_after_seconds",
-                    retry_after_seconds as f64,
-                );
-                println!(
-                    "{} {}",
-                    "✗ Service submit budget exceeded.".color(Color::RED).bold(),
-                    format!("Retry in ~{retry_after_seconds}s.").color(Color::YELLOW)
-                );
-                println!(
-                    "  env: {} / {}",
-                    NTK_SERVICE_SUBMIT_BUDGET_ENV.color(Color::CYAN),
-                    NTK_SERVICE_SUBMIT_WINDOW_SECONDS_ENV.color(Color::CYAN)
-                );
-                return TaskSubmissionOutcome::rejected(runtime_mode, ExitStatus::Error);
-            }
+        let submitter = record
+            .control_envelope
+            .as_ref()
+            .map(|control| &control.operator);
+        policy.authorize_decision(approver, submitter)?;
+        record.status = next_status;
+        record.status_message = message.clone();
+        record.updated_at_unix_ms = current_unix_timestamp_ms();
+        if let Some(control) = record.control_envelope.as_mut() {
+            control.policy.approval_state = approval_state;
         }
-    }
-
-    let worker_policy = task_worker_policy_from_env();
-    let max_attempts = match runtime_mode {
-        RuntimeMode::Service => worker_policy.max_retries.saturating_add(1),
-        RuntimeMode::Cli => 1,
-    };
-    let task_id = next_task_id();
-    let now = current_unix_timestamp_ms();
-    let mut record = TaskRecord::new(
-        task_id.clone(),
-        intent.clone(),
-        runtime_mode,
-        max_attempts,
-        now,
-    );
-    if let Some(control) = control_envelope {
-        record = record.with_control_envelope(control);
-    }
-
-    with_task_registry(|registry| {
-        registry.insert(task_id.clone(), record);
-    });
+        Ok(record.clone())
+    })?;
     append_task_audit_event(
-        &task_id,
-        runtime_mode,
-        TaskExecutionStatus::Queued,
-        format!(
-            "Task submitted (intent: {}, mode: {})",
-            task_intent_kind_label(intent_kind),
-            runtime_mode
-        ),
+        &decided.id,
+        decided.runtime_mode,
+        decided.status,
+        decided.status_message.clone(),
     );
 
-    let (execution_target, fallback_reason) = resolve_task_execution_target(runtime_mode);
-    let _ = set_task_execution_target(&task_id, execution_target.clone());
-    if let Some(reason) = fallback_reason {
-        println!(
-            "{} {}",
-            "⚠".color(Color::YELLOW),
-            reason.color(Color::YELLOW)
-        );
-        let _ = nettoolskit_ui::append_footer_log(&format!("task: {reason}"));
-    }
-
-    let intent_fallback = intent.clone();
-    let final_record = match runtime_mode {
-        RuntimeMode::Service => {
-            let submit_result = submit_task_to_worker(task_id.clone(), intent, runtime_mode);
-            match submit_result {
-                Ok(()) => with_task_registry(|registry| registry.get(&task_id).cloned())
-                    .unwrap_or_else(|| {
-                        let mut missing = TaskRecord::new(
-                            task_id.clone(),
-                            intent_fallback.clone(),
-                            runtime_mode,
-                            max_attempts,
-                            now,
-                        );
-                        if let Some(control) = task_control_envelope(&task_id) {
-                            missing = missing.with_control_envelope(control);
-                        }
-                        missing.execution_target = execution_target.clone();
-                        missing.status_message =
-                            "Task queued for background worker execution".to_string();
-                        missing
-                    }),
-                Err(error) => {
-                    let failed =
-                        update_task_record_status(&task_id, TaskExecutionStatus::Failed, &error)
-                            .unwrap_or_else(|| {
-                                let mut missing = TaskRecord::new(
-                                    task_id.clone(),
-                                    intent_fallback.clone(),
-                                    runtime_mode,
-                                    max_attempts,
-                                    now,
-                                );
-                                if let Some(control) = task_control_envelope(&task_id) {
-                                    missing = missing.with_control_envelope(control);
-                                }
-                                missing.status = TaskExecutionStatus::Failed;
-                                missing.execution_target = execution_target.clone();
-                                missing.status_message = error.clone();
-                                missing
-                            });
-                    println!(
-                        "{} {}",
-                        "✗".color(Color::RED).bold(),
-                        error.color(Color::RED)
-                    );
-                    return TaskSubmissionOutcome::accepted(
-                        failed.id,
-                        task_status_to_exit_status(failed.status),
-                        failed.status,
-                        runtime_mode,
-                    );
-                }
+    let gate = decided
+        .approval_gate
+        .clone()
+        .unwrap_or_else(|| "control-envelope".to_string());
+    let decision = match verdict {
+        TaskApprovalVerdict::Approve => ApprovalDecision::Approved {
+            reason: message.clone(),
+        },
+        TaskApprovalVerdict::Reject => ApprovalDecision::Denied {
+            reason: message.clone(),
+        },
+    };
+    record_task_approval_decision(task_id, &gate, approver, &decision);
+
+    let record = match verdict {
+        TaskApprovalVerdict::Approve => {
+            runtime_metrics().increment_counter("runtime_task_approval_approved_total");
+            match dispatch_admitted_task(decided, console).await {
+                Ok(record) | Err(record) => record,
             }
         }
-        RuntimeMode::Cli => {
-            let _ = update_task_record_status(
-                &task_id,
-                TaskExecutionStatus::Running,
-                format!(
-                    "Executing intent {} locally",
-                    task_intent_kind_label(intent_kind)
-                ),
-            );
-            let _ = update_task_attempt(&task_id, 1);
-            let (final_status, status_message) = execute_task_locally(&intent, runtime_mode).await;
-            update_task_record_status(&task_id, final_status, status_message).unwrap_or_else(|| {
-                let mut missing = TaskRecord::new(
-                    task_id.clone(),
-                    intent_fallback,
-                    runtime_mode,
-                    max_attempts,
-                    now,
-                );
-                if let Some(control) = task_control_envelope(&task_id) {
-                    missing = missing.with_control_envelope(control);
-                }
-                missing.status = final_status;
-                missing.attempts = 1;
-                missing.execution_target = execution_target;
-                missing
-            })
+        TaskApprovalVerdict::Reject => {
+            runtime_metrics().increment_counter("runtime_task_approval_rejected_total");
+            notify_task_origin(Some(&decided));
+            decided
         }
     };
-
-    println!("{}", "✅ Task submitted".color(Color::GREEN).bold());
-    println!("  id: {}", final_record.id.color(Color::CYAN));
-    println!(
-        "  intent: {}",
-        task_intent_kind_label(final_record.intent.kind).color(Color::WHITE)
-    );
-    println!(
-        "  status: {}",
-        task_status_label(final_record.status).color(Color::WHITE)
-    );
-    println!(
-        "  target: {}",
-        final_record.execution_target.color(Color::WHITE)
-    );
-    println!(
-        "  attempts: {}/{}",
-        final_record.attempts, final_record.max_attempts
-    );
-    println!(
-        "  detail: {}",
-        final_record.status_message.color(Color::WHITE)
-    );
-
-    TaskSubmissionOutcome::accepted(
-        final_record.id,
-        task_status_to_exit_status(final_record.status),
-        final_record.status,
-        runtime_mode,
-    )
+    Ok(record.snapshot(list_task_audit_events(task_id)))
 }
 
-async fn handle_task_submit(parts: &[&str]) -> ExitStatus {
-    use nettoolskit_ui::Color;
-
-    if parts.len() < 4 {
-        println!(
-            "{}",
-            "Usage: /task submit <intent> <payload>".color(Color::YELLOW)
-        );
-        return ExitStatus::Error;
-    }
-
-    let Some(intent_kind) = parse_task_intent_kind(parts[2]) else {
-        println!(
-            "{} {}",
-            "✗ Unsupported task intent:".color(Color::RED).bold(),
-            parts[2].color(Color::YELLOW)
-        );
-        println!(
-            "{}",
-            "Supported intents: command, ai-ask, ai-plan, ai-explain, ai-apply-dry-run, repo-workflow"
-                .color(Color::YELLOW)
-        );
-        return ExitStatus::Error;
-    };
-
-    let payload = parts[3..].join(" ");
-    if payload.trim().is_empty() {
-        println!(
-            "{}",
-            "✗ Task payload cannot be empty.".color(Color::RED).bold()
-        );
-        return ExitStatus::Error;
-    }
-
-    let runtime_mode = AppConfig::load().general.runtime_mode;
-    let title = format!("{} task", task_intent_kind_label(intent_kind));
-    let control_envelope =
-        build_cli_task_control_envelope(intent_kind, title, payload.as_str(), runtime_mode);
-    process_control_envelope(control_envelope).await.exit_status
+/// Tasks waiting in the in-process approval queue, most recently updated first.
+pub(super) fn list_pending_approval_snapshots() -> Vec<TaskSnapshot> {
+    list_task_snapshots()
+        .into_iter()
+        .filter(|task| task.status == TaskExecutionStatus::AwaitingApproval)
+        .collect()
 }
 
-fn build_cli_task_control_envelope(
+pub(super) fn build_cli_task_control_envelope(
     intent_kind: TaskIntentKind,
     title: String,
     payload: &str,
     runtime_mode: RuntimeMode,
 ) -> ControlEnvelope {
     let request_id = next_correlation_id("cli-task");
-    let operator = OperatorContext::new(
-        OperatorKind::LocalHuman,
-        "local-cli-operator",
-        IngressTransport::Cli,
-    )
-    .with_authentication("local_process")
-    .with_scopes([
+    let operator = local_cli_operator().with_scopes([
         "task.submit".to_string(),
         format!("submit:{}", task_intent_kind_label(intent_kind)),
     ]);
@@ -4678,444 +4919,123 @@ fn build_cli_task_control_envelope(
         .with_policy(ControlPolicyContext::new(ApprovalState::NotRequired, true))
 }
 
-fn handle_task_list() -> ExitStatus {
+fn sorted_task_records() -> Vec<TaskRecord> {
     let mut records: Vec<TaskRecord> =
         with_task_registry(|registry| registry.values().cloned().collect());
     records.sort_by(|left, right| right.updated_at_unix_ms.cmp(&left.updated_at_unix_ms));
-    print_task_list(&records);
-    ExitStatus::Success
+    records
+}
+
+/// Operator identity used for commands typed into the local CLI.
+pub(super) fn local_cli_operator() -> OperatorContext {
+    OperatorContext::new(
+        OperatorKind::LocalHuman,
+        "local-cli-operator",
+        IngressTransport::Cli,
+    )
+    .with_authentication("local_process")
 }
 
-fn handle_task_watch(parts: &[&str]) -> ExitStatus {
-    use nettoolskit_ui::Color;
+/// Snapshots of every task in the in-process registry, most recently updated first.
+pub(super) fn list_task_snapshots() -> Vec<TaskSnapshot> {
+    sorted_task_records()
+        .iter()
+        .map(TaskRecord::summary_snapshot)
+        .collect()
+}
 
-    if parts.len() < 3 {
-        println!("{}", "Usage: /task watch <task-id>".color(Color::YELLOW));
-        return ExitStatus::Error;
-    }
+/// Snapshot of one task including its audit trail.
+pub(super) fn task_snapshot(task_id: &str) -> Option<TaskSnapshot> {
+    let record = with_task_registry(|registry| registry.get(task_id).cloned())?;
+    Some(record.snapshot(list_task_audit_events(task_id)))
+}
 
-    let task_id = parts[2].trim();
-    let record = with_task_registry(|registry| registry.get(task_id).cloned());
-    let Some(record) = record else {
-        println!(
-            "{} {}",
-            "✗ Task not found:".color(Color::RED).bold(),
-            task_id.color(Color::YELLOW)
-        );
-        return ExitStatus::Error;
+/// Run `call` against the configured service.
+///
+/// Returns `None` when `/task` commands should use the local registry: no endpoint
+/// is configured, or the endpoint is unreachable (the fallback is reported).
+/// After [`super::remote::require_remote_task_records`] both cases are errors.
+pub(super) async fn call_remote_task_service<T, F, Fut>(
+    console: ConsoleMode,
+    call: F,
+) -> Option<Result<T, RemoteServiceError>>
+where
+    F: FnOnce(RemoteServiceClient) -> Fut,
+    Fut: Future<Output = Result<T, RemoteServiceError>>,
+{
+    let required = remote_task_records_required();
+    let Some(client) = remote_task_client() else {
+        return required.then_some(Err(RemoteServiceError::NotConfigured));
     };
-
-    println!("{}", "🔎 Task Details".color(Color::CYAN).bold());
-    println!("  id: {}", record.id.color(Color::CYAN));
-    println!(
-        "  intent: {}",
-        task_intent_kind_label(record.intent.kind).color(Color::WHITE)
-    );
-    println!("  payload: {}", record.intent.payload.color(Color::WHITE));
-    println!(
-        "  status: {}",
-        task_status_label(record.status).color(Color::WHITE)
-    );
-    println!(
-        "  mode: {}",
-        record.runtime_mode.to_string().color(Color::WHITE)
-    );
-    println!("  target: {}", record.execution_target.color(Color::WHITE));
-    println!("  attempts: {}/{}", record.attempts, record.max_attempts);
-    println!("  detail: {}", record.status_message.color(Color::WHITE));
-    println!("  created_at_ms: {}", record.created_at_unix_ms);
-    println!("  updated_at_ms: {}", record.updated_at_unix_ms);
-    if let Some(control) = &record.control_envelope {
-        println!("  request_id: {}", control.request_id.color(Color::WHITE));
-        if let Some(correlation_id) = &control.correlation_id {
-            println!("  correlation_id: {}", correlation_id.color(Color::WHITE));
-        }
-        println!("  operator_id: {}", control.operator.id.color(Color::WHITE));
-        println!(
-            "  operator_kind: {}",
-            control.operator.kind.to_string().color(Color::WHITE)
-        );
-        println!("  session_id: {}", control.session.id.color(Color::WHITE));
-        println!(
-            "  transport: {}",
-            control.operator.transport.to_string().color(Color::WHITE)
-        );
-    }
-    let audits = list_task_audit_events(task_id);
-    if !audits.is_empty() {
-        println!("  audit_events: {}", audits.len());
-        for event in audits.iter().rev().take(5).rev() {
-            println!(
-                "    - [{}] {}",
-                task_status_label(event.status),
-                event.message
-            );
+    match call(client).await {
+        Err(error) if error.is_unreachable() && !required => {
+            note_remote_fallback(&error, console);
+            None
         }
+        result => Some(result),
     }
-
-    ExitStatus::Success
 }
 
-fn handle_task_cancel(parts: &[&str]) -> ExitStatus {
-    use nettoolskit_ui::Color;
-
-    if parts.len() < 3 {
-        println!("{}", "Usage: /task cancel <task-id>".color(Color::YELLOW));
-        return ExitStatus::Error;
-    }
-
-    let task_id = parts[2].trim().to_string();
-    let current = with_task_registry(|registry| registry.get(&task_id).cloned());
-    let cancelled = match current {
-        Some(record) => {
-            if !matches!(
-                record.status,
-                TaskExecutionStatus::Queued | TaskExecutionStatus::Running
-            ) {
-                Err(format!(
-                    "Task is already terminal (status: {})",
-                    task_status_label(record.status)
-                ))
-            } else {
-                update_task_record_status(
-                    &task_id,
-                    TaskExecutionStatus::Cancelled,
-                    "Cancelled by user request",
-                )
-                .ok_or_else(|| format!("Task not found: {task_id}"))
-            }
-        }
-        None => Err(format!("Task not found: {task_id}")),
+/// Decide a parked task in this process and emit the result.
+///
+/// ChatOps passes the chat operator here so the audit log names the real approver.
+pub(super) async fn process_task_approval_decision(
+    task_id: &str,
+    verdict: TaskApprovalVerdict,
+    note: Option<&str>,
+    approver: &OperatorContext,
+) -> ExitStatus {
+    let console = if is_capturing_output() {
+        ConsoleMode::Silent
+    } else {
+        ConsoleMode::Text
     };
-
-    match cancelled {
-        Ok(record) => {
-            println!(
-                "{} {}",
-                "✅ Task cancelled:".color(Color::GREEN).bold(),
-                record.id.color(Color::CYAN)
-            );
+    match decide_task_approval(task_id, verdict, note, approver, console).await {
+        Ok(task) => {
+            emit(task_decision_output(verdict, &task));
             ExitStatus::Success
         }
         Err(error) => {
-            println!(
-                "{} {}",
-                "✗".color(Color::RED).bold(),
-                error.color(Color::RED)
-            );
+            emit(CommandOutput::new().error(error, Vec::new()));
             ExitStatus::Error
         }
     }
 }
 
-async fn process_task_command(parts: &[&str]) -> ExitStatus {
-    let Some(subcommand) = parts.get(1).copied() else {
-        print_task_usage();
-        return ExitStatus::Success;
-    };
-
-    match subcommand.trim().to_ascii_lowercase().as_str() {
-        "help" => {
-            print_task_usage();
-            ExitStatus::Success
-        }
-        "submit" => handle_task_submit(parts).await,
-        "list" => handle_task_list(),
-        "watch" => handle_task_watch(parts),
-        "cancel" => handle_task_cancel(parts),
-        _ => {
-            use nettoolskit_ui::Color;
-            println!(
-                "{} {}",
-                "✗ Unknown /task subcommand:".color(Color::RED).bold(),
-                subcommand.color(Color::YELLOW)
-            );
-            print_task_usage();
-            ExitStatus::Error
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum AsyncManifestAlias {
-    New,
-    Render,
-    Apply,
+/// Cancel a queued or running task, returning its updated snapshot.
+pub(super) fn cancel_task(task_id: &str) -> Result<TaskSnapshot, String> {
+    cancel_task_record(task_id).map(|record| record.snapshot(list_task_audit_events(&record.id)))
 }
 
-fn detect_async_manifest_alias(
-    parsed: Option<MainAction>,
-    parts: &[&str],
-    subcommand: Option<&str>,
-) -> Option<AsyncManifestAlias> {
-    if matches!(parsed, Some(MainAction::Manifest)) {
-        return match subcommand {
-            Some("new-async") => Some(AsyncManifestAlias::New),
-            Some("render-async") => Some(AsyncManifestAlias::Render),
-            Some("apply-async") => Some(AsyncManifestAlias::Apply),
-            _ => None,
-        };
-    }
-
-    let top_level = parts
-        .first()
-        .copied()
-        .unwrap_or_default()
-        .trim()
-        .trim_start_matches('/')
-        .to_ascii_lowercase();
-
-    match top_level.as_str() {
-        "new-async" => Some(AsyncManifestAlias::New),
-        "render-async" => Some(AsyncManifestAlias::Render),
-        "apply-async" => Some(AsyncManifestAlias::Apply),
-        _ => None,
-    }
-}
-
-fn async_alias_arg_start(parts: &[&str]) -> usize {
-    if parts.first().copied() == Some("/") {
-        if parts.get(1).copied() == Some("manifest") {
-            return 3;
-        }
-        return 2;
-    }
-
-    if matches!(parts.first().copied(), Some("/manifest" | "manifest")) {
-        2
-    } else {
-        1
-    }
-}
-
-fn encode_exit_status(status: ExitStatus) -> &'static str {
-    match status {
-        ExitStatus::Success => "success",
-        ExitStatus::Error => "error",
-        ExitStatus::Interrupted => "interrupted",
-    }
-}
-
-fn decode_exit_status(token: &str) -> ExitStatus {
-    match token {
-        "success" => ExitStatus::Success,
-        "interrupted" => ExitStatus::Interrupted,
-        _ => ExitStatus::Error,
-    }
-}
-
-fn format_async_progress(operation: &str, progress: &CommandProgress) -> String {
-    let mut segments = vec![format!("{operation}: {}", progress.message)];
-
-    if let Some(percent) = progress.percent {
-        segments.push(format!("{percent}%"));
-    }
-
-    if let (Some(completed), Some(total)) = (progress.completed, progress.total) {
-        segments.push(format!("{completed}/{total}"));
-    }
-
-    segments.join(" | ")
-}
-
-fn interrupt_requested(interrupted: Option<&AtomicBool>) -> bool {
-    interrupted.is_some_and(|flag| flag.load(Ordering::SeqCst))
-}
-
-async fn cancel_async_alias_execution(
-    operation: &str,
-    executor: &mut AsyncCommandExecutor,
-) -> ExitStatus {
-    let _ = nettoolskit_ui::append_footer_log(&format!(
-        "{operation}: Ctrl+C detected, cancelling async operation"
-    ));
-    executor.cancel_all().await;
-    let _ = nettoolskit_ui::append_footer_log(&format!("{operation}: async operation cancelled"));
-    ExitStatus::Interrupted
-}
-
-async fn run_async_alias_with_progress<F, Fut>(
-    operation: &str,
-    interrupted: Option<&AtomicBool>,
-    factory: F,
-) -> ExitStatus
-where
-    F: FnOnce(ProgressSender) -> Fut + Send + 'static,
-    Fut: Future<Output = ExitStatus> + Send + 'static,
-{
-    let mut executor = AsyncCommandExecutor::new();
-    let (handle, mut progress_rx) = executor.spawn_with_progress(move |progress_tx| async move {
-        let status = factory(progress_tx).await;
-        Ok(encode_exit_status(status).to_string())
-    });
-
-    if interrupt_requested(interrupted) {
-        return cancel_async_alias_execution(operation, &mut executor).await;
-    }
-
-    loop {
-        tokio::select! {
-            progress = progress_rx.recv() => {
-                match progress {
-                    Some(progress) => {
-                        let _ = nettoolskit_ui::append_footer_log(&format_async_progress(operation, &progress));
-                    }
-                    None => break,
-                }
-            }
-            _ = tokio::time::sleep(Duration::from_millis(25)), if interrupted.is_some() => {
-                if interrupt_requested(interrupted) {
-                    return cancel_async_alias_execution(operation, &mut executor).await;
+fn cancel_task_record(task_id: &str) -> Result<TaskRecord, String> {
+    let current = with_task_registry(|registry| registry.get(task_id).cloned());
+    match current {
+        Some(record) => {
+            if !matches!(
+                record.status,
+                TaskExecutionStatus::Queued
+                    | TaskExecutionStatus::AwaitingApproval
+                    | TaskExecutionStatus::Running
+            ) {
+                Err(format!(
+                    "Task is already terminal (status: {})",
+                    task_status_label(record.status)
+                ))
+            } else {
+                let cancelled = update_task_record_status(
+                    task_id,
+                    TaskExecutionStatus::Cancelled,
+                    "Cancelled by user request",
+                )
+                .ok_or_else(|| format!("Task not found: {task_id}"))?;
+                // Parked tasks never reach the worker, so report the outcome here.
+                if record.status == TaskExecutionStatus::AwaitingApproval {
+                    notify_task_origin(Some(&cancelled));
                 }
+                Ok(cancelled)
             }
         }
-    }
-
-    if interrupt_requested(interrupted) {
-        return cancel_async_alias_execution(operation, &mut executor).await;
-    }
-
-    let status = match handle.wait().await {
-        Ok(Ok(token)) => decode_exit_status(&token),
-        Ok(Err(err)) => {
-            let _ = nettoolskit_ui::append_footer_log(&format!(
-                "{operation}: async worker error: {err}"
-            ));
-            ExitStatus::Error
-        }
-        Err(err) => {
-            let _ = nettoolskit_ui::append_footer_log(&format!(
-                "{operation}: async result channel failed: {err}"
-            ));
-            ExitStatus::Error
-        }
-    };
-
-    executor.wait_all().await;
-    status
-}
-
-async fn process_async_manifest_alias(
-    alias: AsyncManifestAlias,
-    parts: &[&str],
-    interrupted: Option<&AtomicBool>,
-) -> ExitStatus {
-    use nettoolskit_ui::Color;
-
-    let arg_start = async_alias_arg_start(parts);
-    let output_override = parse_output_root(parts);
-    let dry_run = has_flag(parts, "--dry-run");
-
-    match alias {
-        AsyncManifestAlias::Render => {
-            let manifest_path = match resolve_manifest_target_path_from(parts, "render", arg_start)
-            {
-                Ok(path) => path,
-                Err(status) => return status,
-            };
-            let output_root =
-                output_override.unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
-
-            println!(
-                "{}",
-                "🎨 Rendering Preview (Async)".color(Color::CYAN).bold()
-            );
-            println!("Manifest: {}", manifest_path.display());
-            println!("Output root: {}", output_root.display());
-            println!(
-                "{}",
-                "DRY-RUN mode enabled (preview only)".color(Color::YELLOW)
-            );
-            println!();
-
-            run_async_alias_with_progress(
-                "render-async",
-                interrupted,
-                move |progress_tx| async move {
-                    let _ = progress_tx
-                        .send(CommandProgress::percent("Preparing manifest context", 15));
-                    let _ = progress_tx.send(CommandProgress::steps("Render stages", 1, 3));
-
-                    let config = nettoolskit_manifest::ExecutionConfig {
-                        manifest_path,
-                        output_root,
-                        dry_run: true,
-                    };
-
-                    let _ = progress_tx.send(CommandProgress::percent("Rendering preview", 60));
-                    let _ = progress_tx.send(CommandProgress::steps("Render stages", 2, 3));
-
-                    let executor = nettoolskit_manifest::ManifestExecutor::new();
-                    match executor.execute(config).await {
-                        Ok(summary) => {
-                            let _ = progress_tx.send(CommandProgress::percent("Completed", 100));
-                            let _ = progress_tx.send(CommandProgress::steps("Render stages", 3, 3));
-                            println!("{}", "✓ Render preview completed".color(Color::GREEN));
-                            println!();
-                            print_execution_summary(&summary);
-                            ExitStatus::Success
-                        }
-                        Err(err) => {
-                            let _ = progress_tx.send(CommandProgress::percent("Failed", 100));
-                            println!(
-                                "{} {err}",
-                                "✗ Render preview failed:".color(Color::RED).bold()
-                            );
-                            ExitStatus::Error
-                        }
-                    }
-                },
-            )
-            .await
-        }
-        AsyncManifestAlias::Apply | AsyncManifestAlias::New => {
-            let Some(manifest_path) = first_positional_path(parts, arg_start) else {
-                let command_name = if alias == AsyncManifestAlias::New {
-                    "/new-async"
-                } else {
-                    "/apply-async"
-                };
-                println!(
-                    "{} {}",
-                    "✗ Missing manifest path for".color(Color::RED).bold(),
-                    command_name.color(Color::CYAN)
-                );
-                println!(
-                    "{}",
-                    format!("Usage: {command_name} <manifest-file> [--dry-run] [--output <dir>]")
-                        .color(Color::YELLOW)
-                );
-                return ExitStatus::Error;
-            };
-
-            let operation = if alias == AsyncManifestAlias::New {
-                "new-async"
-            } else {
-                "apply-async"
-            };
-
-            run_async_alias_with_progress(operation, interrupted, move |progress_tx| async move {
-                let _ = progress_tx.send(CommandProgress::percent("Preparing apply plan", 20));
-                let _ = progress_tx.send(CommandProgress::steps("Apply stages", 1, 3));
-
-                let _ = progress_tx.send(CommandProgress::percent("Applying manifest", 65));
-                let _ = progress_tx.send(CommandProgress::steps("Apply stages", 2, 3));
-
-                let status =
-                    nettoolskit_manifest::execute_apply(manifest_path, output_override, dry_run)
-                        .await;
-
-                let completion = if matches!(status, ExitStatus::Success) {
-                    "Completed"
-                } else {
-                    "Finished with errors"
-                };
-                let _ = progress_tx.send(CommandProgress::percent(completion, 100));
-                let _ = progress_tx.send(CommandProgress::steps("Apply stages", 3, 3));
-                status
-            })
-            .await
-        }
+        None => Err(format!("Task not found: {task_id}")),
     }
 }
 
@@ -5136,975 +5056,306 @@ pub async fn process_command(cmd: &str) -> ExitStatus {
     process_command_with_interrupt(cmd, None).await
 }
 
-/// Process an already-admitted control-plane envelope without reparsing a slash command string.
-pub async fn process_control_envelope(envelope: ControlEnvelope) -> TaskSubmissionOutcome {
-    let execution_span = info_span!(
-        "orchestrator.control_envelope",
-        request_id = %envelope.request_id,
-        correlation_id = envelope.correlation_id.as_deref().unwrap_or(""),
-        operator_id = %envelope.operator.id,
-        session_id = %envelope.session.id,
-        task_intent = %task_intent_kind_label(envelope.task.kind),
-    );
-    let _execution_scope = execution_span.enter();
-
-    submit_task_intent(envelope.task.clone(), envelope.runtime_mode, Some(envelope)).await
-}
-
-/// Process slash commands from CLI and return appropriate status, with optional interrupt flag.
+/// Process a slash command and return its structured output instead of printing it.
 ///
-/// When `interrupted` is provided, long-running async aliases can react to Ctrl+C and
-/// return [`ExitStatus::Interrupted`] deterministically.
-pub async fn process_command_with_interrupt(
-    cmd: &str,
-    interrupted: Option<&AtomicBool>,
-) -> ExitStatus {
-    let correlation_id = next_correlation_id("cmd");
-    let execution_span =
-        info_span!("orchestrator.command", correlation_id = %correlation_id, command = %cmd);
-    let _execution_scope = execution_span.enter();
-
-    let metrics = runtime_metrics().clone();
-
-    // Log command usage with structured data
-    info!(
-        correlation_id = %correlation_id,
-        command = %cmd,
-        command_type = %cmd.trim_start_matches('/'),
-        "Processing CLI command"
-    );
-    metrics.increment_counter(format!("command_{}_usage", cmd.trim_start_matches('/')));
-
-    // Parse command - pass full command string to get_main_action
-    // It will handle "/ help", "/help", or "help" formats
-    let parts: Vec<&str> = cmd.split_whitespace().collect();
-
-    // If command is "/ help" (with space), parts = ["/", "help"], subcommand = parts[2]
-    // If command is "/help list", parts = ["/help", "list"], subcommand = parts[1]
-    let subcommand = if parts.first().copied() == Some("/") {
-        parts.get(2).copied()
-    } else {
-        parts.get(1).copied()
-    };
-
-    // Parse command using full original string
-    let parsed = crate::models::get_main_action(cmd);
-    let command_key = command_metric_key(parsed, subcommand, cmd);
-    let command_timing_name = format!("runtime_command_latency_{command_key}");
-    let timer = Timer::start(command_timing_name.clone(), metrics.clone());
-
-    metrics.increment_counter("runtime_commands_total");
-    metrics.increment_counter(format!("runtime_command_{command_key}_total"));
-    metrics.set_gauge(
-        "runtime_command_plugins_enabled",
-        command_plugin_count() as f64,
-    );
-
-    let before_hook_stats = run_before_command_plugins(&CommandHookContext {
-        correlation_id: correlation_id.clone(),
-        command: cmd.to_string(),
-        command_key: command_key.clone(),
-        status: None,
-    });
-    metrics.set_gauge(
-        "runtime_command_plugins_last_before_invoked",
-        before_hook_stats.invoked as f64,
-    );
-    metrics.set_gauge(
-        "runtime_command_plugins_last_before_failures",
-        before_hook_stats.failures as f64,
-    );
-    if before_hook_stats.failures > 0 {
-        metrics.increment_counter("runtime_command_plugins_hook_errors_total");
-    }
-
-    let result = if let Some(alias) = detect_async_manifest_alias(parsed, &parts, subcommand) {
-        process_async_manifest_alias(alias, &parts, interrupted).await
-    } else {
-        match parsed {
-            Some(MainAction::Help) => {
-                let help_key = CacheKey::help();
-                let help_markdown = match with_command_cache(|cache| cache.get(&help_key)) {
-                    Some(CacheValue::HelpMarkdown(markdown)) => {
-                        metrics.increment_counter("runtime_command_cache_hits_total");
-                        metrics.increment_counter("runtime_command_cache_help_hits_total");
-                        markdown
-                    }
-                    _ => {
-                        metrics.increment_counter("runtime_command_cache_misses_total");
-                        metrics.increment_counter("runtime_command_cache_help_misses_total");
-
-                        let mut commands_section = String::new();
-                        for command in MainAction::iter() {
-                            commands_section.push_str(&format!(
-                                "- `{}` - {}\n",
-                                command.slash_static(),
-                                command.description()
-                            ));
-                        }
-
-                        let generated = format!(
-                            "\
-# NetToolsKit CLI - Help
-
-## Available Commands
-{commands_section}
-## Usage
-- Type `/` to open the command palette
-- Type a command directly (for example `/help`)
-- Use `↑↓` to navigate in the palette
-- Press `Enter` to select a command
-
-## Examples
-- `/help` - Show this help
-- `/manifest` - Manage manifests
-- `/render-async <manifest>` - Run async render preview with progress
-- `/apply-async <manifest>` - Run async apply with progress
-- `/new-async <manifest>` - Run async scaffolding alias
-- `/config` - View or edit configuration
-- `/ai ask <prompt>` - Ask the AI assistant
-- `/ai plan <goal>` - Generate an implementation plan
-- `/ai explain <topic>` - Get a technical explanation
-- `/ai resume <session-id>` - Set active local AI session id for conversation continuity
-- `/ai apply --dry-run <instruction>` - Generate non-destructive patch guidance
-- `/ai apply --approve-write <instruction>` - Explicitly approve mutating apply intent
-- `/task submit <intent> <payload>` - Submit a task for managed execution (local fallback)
-- `/task list` - List local task records
-- `/task watch <task-id>` - Inspect task status/details
-- `/task cancel <task-id>` - Cancel queued/running task record
-- `/clear` - Clear and redraw terminal layout
-- `/quit` - Exit the CLI
-"
-                        );
-
-                        let _ = with_command_cache(|cache| {
-                            cache.insert(help_key, CacheValue::HelpMarkdown(generated.clone()))
-                        });
-
-                        generated
-                    }
-                };
-
-                println!("{}", nettoolskit_ui::render_markdown(&help_markdown));
-
-                ExitStatus::Success
-            }
-            Some(MainAction::Manifest) => {
-                use nettoolskit_ui::Color;
-                match subcommand {
-                    Some("list") => {
-                        let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-                        println!("{}", "📋 Manifest Discovery".color(Color::CYAN).bold());
-                        println!("Root: {}", cwd.display().to_string().color(Color::WHITE));
-                        println!();
-
-                        let cache_key = CacheKey::manifest_list(&cwd);
-                        let discovery = match with_command_cache(|cache| cache.get(&cache_key)) {
-                            Some(CacheValue::ManifestListEntries(entries)) => {
-                                metrics.increment_counter("runtime_command_cache_hits_total");
-                                metrics
-                                    .increment_counter("runtime_command_cache_manifest_hits_total");
-                                Ok(entries)
-                            }
-                            _ => {
-                                metrics.increment_counter("runtime_command_cache_misses_total");
-                                metrics.increment_counter(
-                                    "runtime_command_cache_manifest_misses_total",
-                                );
-                                match discover_manifest_files(&cwd) {
-                                    Ok(found) => {
-                                        let _ = with_command_cache(|cache| {
-                                            cache.insert(
-                                                cache_key,
-                                                CacheValue::ManifestListEntries(found.clone()),
-                                            )
-                                        });
-                                        Ok(found)
-                                    }
-                                    Err(err) => Err(err),
-                                }
-                            }
-                        };
-
-                        match discovery {
-                            Ok(found) if found.is_empty() => {
-                                println!("{}", "No manifest files found.".color(Color::YELLOW));
-                                ExitStatus::Error
-                            }
-                            Ok(found) => {
-                                println!(
-                                    "{}",
-                                    format!("Found {} manifest file(s):", found.len())
-                                        .color(Color::GREEN)
-                                );
-                                for path in found {
-                                    println!("  - {}", relative_path_for_display(&cwd, &path));
-                                }
-                                ExitStatus::Success
-                            }
-                            Err(err) => {
-                                println!(
-                                    "{} {err}",
-                                    "✗ Discovery failed:".color(Color::RED).bold()
-                                );
-                                ExitStatus::Error
-                            }
-                        }
-                    }
-                    Some("check") => {
-                        let is_template = has_flag(&parts, "--template");
-                        if is_template && first_manifest_positional_path(&parts).is_none() {
-                            println!(
-                            "{}",
-                            "Template validation requires a file path: /manifest check <file> --template"
-                                .color(Color::RED)
-                                .bold()
-                        );
-                            ExitStatus::Error
-                        } else {
-                            match resolve_manifest_target_path(&parts, "check") {
-                                Ok(target_path) => {
-                                    println!(
-                                        "{}",
-                                        "✅ Validating Manifest".color(Color::CYAN).bold()
-                                    );
-                                    println!("Target: {}", target_path.display());
-                                    println!();
-
-                                    match nettoolskit_manifest::handlers::check::check_file(
-                                        &target_path,
-                                        is_template,
-                                    )
-                                    .await
-                                    {
-                                        Ok(validation) => {
-                                            print_manifest_validation(&target_path, &validation);
-                                            if validation.is_valid() {
-                                                ExitStatus::Success
-                                            } else {
-                                                ExitStatus::Error
-                                            }
-                                        }
-                                        Err(err) => {
-                                            println!(
-                                                "{} {err}",
-                                                "✗ Manifest validation failed:"
-                                                    .color(Color::RED)
-                                                    .bold()
-                                            );
-                                            ExitStatus::Error
-                                        }
-                                    }
-                                }
-                                Err(status) => status,
-                            }
-                        }
-                    }
-                    Some("render") => match resolve_manifest_target_path(&parts, "render") {
-                        Ok(manifest_path) => {
-                            let output_root = parse_output_root(&parts).unwrap_or_else(|| {
-                                std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."))
-                            });
-                            let dry_run = true;
-
-                            println!("{}", "🎨 Rendering Preview".color(Color::CYAN).bold());
-                            println!("Manifest: {}", manifest_path.display());
-                            println!("Output root: {}", output_root.display());
-                            println!(
-                                "{}",
-                                "DRY-RUN mode enabled (preview only)".color(Color::YELLOW)
-                            );
-                            println!();
-
-                            let config = nettoolskit_manifest::ExecutionConfig {
-                                manifest_path,
-                                output_root,
-                                dry_run,
-                            };
-
-                            let executor = nettoolskit_manifest::ManifestExecutor::new();
-                            match executor.execute(config).await {
-                                Ok(summary) => {
-                                    println!(
-                                        "{}",
-                                        "✓ Render preview completed".color(Color::GREEN)
-                                    );
-                                    println!();
-                                    print_execution_summary(&summary);
-                                    ExitStatus::Success
-                                }
-                                Err(err) => {
-                                    println!(
-                                        "{} {err}",
-                                        "✗ Render preview failed:".color(Color::RED).bold()
-                                    );
-                                    ExitStatus::Error
-                                }
-                            }
-                        }
-                        Err(status) => status,
-                    },
-                    Some("apply") => {
-                        // Parse apply command arguments
-                        // Format: /manifest apply <PATH> [--dry-run] [--output DIR]
-
-                        let manifest_path = first_manifest_positional_path(&parts);
-                        let dry_run = has_flag(&parts, "--dry-run");
-                        let output_root = parse_output_root(&parts);
-
-                        match manifest_path {
-                            Some(path) => {
-                                // Execute apply handler
-                                nettoolskit_manifest::execute_apply(path, output_root, dry_run)
-                                    .await
-                            }
-                            None => {
-                                info!(
-                                "Manifest apply called without path; opening interactive apply flow"
-                            );
-                                nettoolskit_manifest::show_apply_menu().await
-                            }
-                        }
-                    }
-                    None => {
-                        // No subcommand provided - show interactive menu from manifest crate
-                        info!("Opening manifest interactive menu (no subcommand)");
-                        nettoolskit_manifest::show_menu().await
-                    }
-                    _ => {
-                        println!("{}", "📋 Manifest Commands".color(Color::CYAN).bold());
-                        println!("\nAvailable subcommands:");
-                        println!(
-                            "  {} - Discover available manifests in the workspace",
-                            "/manifest list".color(Color::GREEN)
-                        );
-                        println!(
-                            "  {} - Validate manifest structure and dependencies",
-                            "/manifest check".color(Color::GREEN)
-                        );
-                        println!(
-                            "  {} - Preview generated files without creating them",
-                            "/manifest render".color(Color::GREEN)
-                        );
-                        println!(
-                            "  {} - Async preview with progress updates",
-                            "/manifest render-async".color(Color::GREEN)
-                        );
-                        println!(
-                            "  {} - Apply manifest to generate/update project files",
-                            "/manifest apply".color(Color::GREEN)
-                        );
-                        println!(
-                            "  {} - Async apply with progress updates",
-                            "/manifest apply-async".color(Color::GREEN)
-                        );
-                        println!("\n{}", "💡 Type a subcommand to continue or just type /manifest for interactive menu".color(Color::YELLOW));
-                        ExitStatus::Success
-                    }
-                }
-            }
-            Some(MainAction::Ai) => process_ai_command(&parts, subcommand).await,
-            Some(MainAction::Task) => process_task_command(&parts).await,
-            Some(MainAction::Config) => process_config_command(&parts),
-            Some(MainAction::Clear) => match nettoolskit_ui::reset_layout() {
-                Ok(()) => ExitStatus::Success,
-                Err(err) => {
-                    use nettoolskit_ui::Color;
-                    println!(
-                        "{}: {err}",
-                        "Failed to reset terminal layout".color(Color::RED).bold()
-                    );
-                    ExitStatus::Error
-                }
-            },
-            Some(MainAction::Quit) => ExitStatus::Success, // Handled by CLI loop
-            None => {
-                use nettoolskit_ui::Color;
-                tracing::warn!("Unknown command attempted: {}", cmd);
-                metrics.increment_counter("unknown_command_attempts");
-                println!("{}: {}", "Unknown command".color(Color::RED), cmd);
-                ExitStatus::Error
-            }
-        }
-    };
-
-    let after_hook_stats = run_after_command_plugins(&CommandHookContext {
-        correlation_id: correlation_id.clone(),
-        command: cmd.to_string(),
-        command_key: command_key.clone(),
-        status: Some(result),
-    });
-    metrics.set_gauge(
-        "runtime_command_plugins_last_after_invoked",
-        after_hook_stats.invoked as f64,
-    );
-    metrics.set_gauge(
-        "runtime_command_plugins_last_after_failures",
-        after_hook_stats.failures as f64,
-    );
-    if after_hook_stats.failures > 0 {
-        metrics.increment_counter("runtime_command_plugins_hook_errors_total");
-    }
-
-    // Stop timer and log result with structured data
-    let duration = timer.stop();
-    update_runtime_latency_gauges(&metrics, &command_key, &command_timing_name, duration);
-
-    // Log and convert result to CLI status
-    let counter_name = match result {
-        ExitStatus::Success => "successful_commands",
-        ExitStatus::Error => "failed_commands",
-        ExitStatus::Interrupted => "interrupted_commands",
-    };
-    let status_str = record_command_outcome_metrics(&metrics, &command_key, result);
-    update_runtime_rate_gauges(&metrics);
-    let cache_stats = with_command_cache(|cache| cache.stats());
-    maybe_log_command_cache_stats(cache_stats, &metrics);
-
-    info!(
-        correlation_id = %correlation_id,
-        command = %cmd,
-        command_key = %command_key,
-        duration_ms = duration.as_millis(),
-        status = status_str,
-        "Command execution completed"
-    );
-    metrics.increment_counter(counter_name);
-
-    // Log metrics summary for this command
-    metrics.log_summary();
-    result
-}
-
-fn process_config_command(parts: &[&str]) -> ExitStatus {
-    use nettoolskit_ui::Color;
+/// Used by non-terminal ingresses (ChatOps, HTTP) so they report the same results
+/// as the interactive CLI. Progress that only makes sense on a terminal (AI
+/// streaming) is replaced by the final structured report.
+pub async fn process_command_with_output(cmd: &str) -> (ExitStatus, CommandOutput) {
+    capture_output(process_command(cmd)).await
+}
+
+/// Metrics, plugin hooks and logging shared by every command execution path.
+struct CommandRun {
+    correlation_id: String,
+    command: String,
+    command_key: String,
+    command_timing_name: String,
+    timer: Timer,
+    metrics: Metrics,
+}
+
+impl CommandRun {
+    fn start(
+        correlation_id: String,
+        cmd: &str,
+        parsed: Option<MainAction>,
+        subcommand: Option<&str>,
+    ) -> Self {
+        let metrics = runtime_metrics().clone();
 
-    let Some(config_path) = AppConfig::default_config_path() else {
-        println!(
-            "{}",
-            "Could not determine configuration path on this system."
-                .color(Color::RED)
-                .bold()
+        // Log command usage with structured data
+        info!(
+            correlation_id = %correlation_id,
+            command = %cmd,
+            command_type = %cmd.trim_start_matches('/'),
+            "Processing CLI command"
         );
-        return ExitStatus::Error;
-    };
+        metrics.increment_counter(format!("command_{}_usage", cmd.trim_start_matches('/')));
 
-    match parts.get(1).copied() {
-        None | Some("show") => {
-            print_effective_config(&config_path);
-            ExitStatus::Success
-        }
-        Some("path") => {
-            println!("{}", "📍 Configuration Path".color(Color::CYAN).bold());
-            println!(
-                "  {}",
-                config_path.display().to_string().color(Color::GREEN)
-            );
-            println!(
-                "  Exists: {}",
-                if config_path.exists() {
-                    "yes".color(Color::GREEN)
-                } else {
-                    "no".color(Color::YELLOW)
-                }
-            );
-            ExitStatus::Success
-        }
-        Some("init") => {
-            if config_path.exists() {
-                println!(
-                    "{}",
-                    "Configuration file already exists."
-                        .color(Color::YELLOW)
-                        .bold()
-                );
-                println!("  {}", config_path.display());
-                return ExitStatus::Success;
-            }
+        let command_key = command_metric_key(parsed, subcommand, cmd);
+        let command_timing_name = format!("runtime_command_latency_{command_key}");
+        let timer = Timer::start(command_timing_name.clone(), metrics.clone());
 
-            let config = AppConfig::default();
-            match config.save_to(&config_path) {
-                Ok(()) => {
-                    apply_runtime_ui_config(&config);
-                    println!(
-                        "{}",
-                        "✅ Configuration initialized".color(Color::GREEN).bold()
-                    );
-                    println!("  {}", config_path.display());
-                    ExitStatus::Success
-                }
-                Err(err) => {
-                    println!(
-                        "{}: {}",
-                        "Failed to initialize config".color(Color::RED),
-                        err
-                    );
-                    ExitStatus::Error
-                }
-            }
-        }
-        Some("set") => {
-            if parts.len() < 4 {
-                print_config_usage();
-                return ExitStatus::Error;
-            }
+        metrics.increment_counter("runtime_commands_total");
+        metrics.increment_counter(format!("runtime_command_{command_key}_total"));
+        metrics.set_gauge(
+            "runtime_command_plugins_enabled",
+            command_plugin_count() as f64,
+        );
 
-            let key = parts[2];
-            let value = parts[3..].join(" ");
-            let mut config = load_persisted_or_default(&config_path);
-
-            match set_config_value(&mut config, key, &value) {
-                Ok(()) => match config.save_to(&config_path) {
-                    Ok(()) => {
-                        apply_runtime_ui_config(&config);
-                        if is_ai_session_retention_key(key) {
-                            apply_ai_session_retention_policy(config.general.ai_session_retention);
-                        }
-                        println!(
-                            "{} {}={}",
-                            "✅ Updated".color(Color::GREEN).bold(),
-                            key.color(Color::CYAN),
-                            value.color(Color::WHITE)
-                        );
-                        println!("  {}", config_path.display());
-                        ExitStatus::Success
-                    }
-                    Err(err) => {
-                        println!("{}: {}", "Failed to save config".color(Color::RED), err);
-                        ExitStatus::Error
-                    }
-                },
-                Err(err) => {
-                    println!("{}: {}", "Invalid config value".color(Color::RED), err);
-                    print_supported_config_keys();
-                    ExitStatus::Error
-                }
-            }
+        let before_hook_stats = run_before_command_plugins(&CommandHookContext {
+            correlation_id: correlation_id.clone(),
+            command: cmd.to_string(),
+            command_key: command_key.clone(),
+            status: None,
+        });
+        metrics.set_gauge(
+            "runtime_command_plugins_last_before_invoked",
+            before_hook_stats.invoked as f64,
+        );
+        metrics.set_gauge(
+            "runtime_command_plugins_last_before_failures",
+            before_hook_stats.failures as f64,
+        );
+        if before_hook_stats.failures > 0 {
+            metrics.increment_counter("runtime_command_plugins_hook_errors_total");
         }
-        Some("unset") => {
-            if parts.len() < 3 {
-                print_config_usage();
-                return ExitStatus::Error;
-            }
 
-            let key = parts[2];
-            let mut config = load_persisted_or_default(&config_path);
-
-            match unset_config_value(&mut config, key) {
-                Ok(()) => match config.save_to(&config_path) {
-                    Ok(()) => {
-                        apply_runtime_ui_config(&config);
-                        if is_ai_session_retention_key(key) {
-                            apply_ai_session_retention_policy(config.general.ai_session_retention);
-                        }
-                        println!(
-                            "{} {}",
-                            "✅ Reset".color(Color::GREEN).bold(),
-                            key.color(Color::CYAN)
-                        );
-                        println!("  {}", config_path.display());
-                        ExitStatus::Success
-                    }
-                    Err(err) => {
-                        println!("{}: {}", "Failed to save config".color(Color::RED), err);
-                        ExitStatus::Error
-                    }
-                },
-                Err(err) => {
-                    println!("{}: {}", "Invalid config key".color(Color::RED), err);
-                    print_supported_config_keys();
-                    ExitStatus::Error
-                }
-            }
-        }
-        Some("reset") => {
-            let config = AppConfig::default();
-            match config.save_to(&config_path) {
-                Ok(()) => {
-                    apply_runtime_ui_config(&config);
-                    apply_ai_session_retention_policy(config.general.ai_session_retention);
-                    println!(
-                        "{}",
-                        "✅ Configuration reset to defaults"
-                            .color(Color::GREEN)
-                            .bold()
-                    );
-                    println!("  {}", config_path.display());
-                    ExitStatus::Success
-                }
-                Err(err) => {
-                    println!("{}: {}", "Failed to reset config".color(Color::RED), err);
-                    ExitStatus::Error
-                }
-            }
-        }
-        Some("help") => {
-            print_config_usage();
-            ExitStatus::Success
-        }
-        Some(_) => {
-            println!("{}", "Unknown /config subcommand".color(Color::RED).bold());
-            print_config_usage();
-            ExitStatus::Error
+        Self {
+            correlation_id,
+            command: cmd.to_string(),
+            command_key,
+            command_timing_name,
+            timer,
+            metrics,
         }
     }
-}
 
-fn print_effective_config(config_path: &Path) {
-    use nettoolskit_ui::Color;
+    fn finish(self, result: ExitStatus) -> ExitStatus {
+        let Self {
+            correlation_id,
+            command,
+            command_key,
+            command_timing_name,
+            timer,
+            metrics,
+        } = self;
 
-    let effective = AppConfig::load();
-    println!(
-        "{}",
-        "⚙️  NetToolsKit Configuration".color(Color::CYAN).bold()
-    );
-    println!(
-        "  File: {} ({})",
-        config_path.display().to_string().color(Color::GREEN),
-        if config_path.exists() {
-            "exists".color(Color::GREEN)
-        } else {
-            "not found, using defaults/env".color(Color::YELLOW)
+        let after_hook_stats = run_after_command_plugins(&CommandHookContext {
+            correlation_id: correlation_id.clone(),
+            command: command.clone(),
+            command_key: command_key.clone(),
+            status: Some(result),
+        });
+        metrics.set_gauge(
+            "runtime_command_plugins_last_after_invoked",
+            after_hook_stats.invoked as f64,
+        );
+        metrics.set_gauge(
+            "runtime_command_plugins_last_after_failures",
+            after_hook_stats.failures as f64,
+        );
+        if after_hook_stats.failures > 0 {
+            metrics.increment_counter("runtime_command_plugins_hook_errors_total");
         }
-    );
-    println!();
-    println!("{}", "[general]".color(Color::WHITE).bold());
-    println!("  verbose = {}", effective.general.verbose);
-    println!("  log_level = {}", effective.general.log_level);
-    println!("  footer_output = {}", effective.general.footer_output);
-    println!("  runtime_mode = {}", effective.general.runtime_mode);
-    println!("  attention_bell = {}", effective.general.attention_bell);
-    println!(
-        "  attention_desktop_notification = {}",
-        effective.general.attention_desktop_notification
-    );
-    println!(
-        "  attention_unfocused_only = {}",
-        effective.general.attention_unfocused_only
-    );
-    println!(
-        "  predictive_input = {}",
-        effective.general.predictive_input
-    );
-    println!(
-        "  ai_session_retention = {}",
-        effective.general.ai_session_retention
-    );
-    println!("{}", "[display]".color(Color::WHITE).bold());
-    println!("  color = {}", effective.display.color);
-    println!("  unicode = {}", effective.display.unicode);
-    println!("{}", "[templates]".color(Color::WHITE).bold());
-    println!(
-        "  directory = {}",
-        effective
-            .templates
-            .directory
-            .as_deref()
-            .unwrap_or("(default)")
-    );
-    println!("{}", "[shell]".color(Color::WHITE).bold());
-    println!(
-        "  default_shell = {}",
-        effective.shell.default_shell.as_deref().unwrap_or("(none)")
-    );
-    println!();
-    print_config_usage();
-}
-
-fn print_supported_config_keys() {
-    use nettoolskit_ui::Color;
-    println!("{}", "Supported keys:".color(Color::WHITE).bold());
-    println!("  {}", "verbose".color(Color::CYAN));
-    println!("  {}", "log_level".color(Color::CYAN));
-    println!("  {}", "footer_output".color(Color::CYAN));
-    println!("  {}", "runtime_mode".color(Color::CYAN));
-    println!("  {}", "attention_bell".color(Color::CYAN));
-    println!("  {}", "attention_desktop_notification".color(Color::CYAN));
-    println!("  {}", "attention_unfocused_only".color(Color::CYAN));
-    println!("  {}", "predictive_input".color(Color::CYAN));
-    println!("  {}", "ai_session_retention".color(Color::CYAN));
-    println!("  {}", "color".color(Color::CYAN));
-    println!("  {}", "unicode".color(Color::CYAN));
-    println!("  {}", "template_dir".color(Color::CYAN));
-    println!("  {}", "shell".color(Color::CYAN));
-}
-
-fn print_config_usage() {
-    use nettoolskit_ui::Color;
-    println!("{}", "Usage:".color(Color::WHITE).bold());
-    println!("  {}", "/config [show]".color(Color::GREEN));
-    println!("  {}", "/config path".color(Color::GREEN));
-    println!("  {}", "/config init".color(Color::GREEN));
-    println!("  {}", "/config set <key> <value>".color(Color::GREEN));
-    println!("  {}", "/config unset <key>".color(Color::GREEN));
-    println!("  {}", "/config reset".color(Color::GREEN));
-    println!();
-    print_supported_config_keys();
-}
-
-fn load_persisted_or_default(config_path: &Path) -> AppConfig {
-    if config_path.exists() {
-        AppConfig::load_from(config_path).unwrap_or_default()
-    } else {
-        AppConfig::default()
-    }
-}
 
-fn apply_runtime_ui_config(config: &AppConfig) {
-    nettoolskit_ui::set_footer_output_enabled(config.general.footer_output);
-}
+        // Stop timer and log result with structured data
+        let duration = timer.stop();
+        update_runtime_latency_gauges(&metrics, &command_key, &command_timing_name, duration);
 
-fn is_ai_session_retention_key(key: &str) -> bool {
-    matches!(
-        key.trim().to_ascii_lowercase().as_str(),
-        "ai_session_retention"
-            | "ai-session-retention"
-            | "general.ai_session_retention"
-            | "general.ai-session-retention"
-    )
-}
+        // Log and convert result to CLI status
+        let counter_name = match result {
+            ExitStatus::Success => "successful_commands",
+            ExitStatus::Error => "failed_commands",
+            ExitStatus::Interrupted => "interrupted_commands",
+        };
+        let status_str = record_command_outcome_metrics(&metrics, &command_key, result);
+        update_runtime_rate_gauges(&metrics);
+        let cache_stats = with_command_cache(|cache| cache.stats());
+        maybe_log_command_cache_stats(cache_stats, &metrics);
 
-fn apply_ai_session_retention_policy(retention: usize) {
-    let keep_latest = retention.max(1);
-    match prune_local_ai_session_snapshots(keep_latest) {
-        Ok(Some(removed)) if removed > 0 => {
-            println!(
-                "  {}",
-                format!("Pruned {removed} old local AI session snapshot(s)")
-                    .color(nettoolskit_ui::Color::YELLOW)
-            );
-        }
-        Ok(Some(_)) | Ok(None) => {}
-        Err(err) => {
-            warn!(error = %err, "Failed to apply AI session retention policy");
-            println!(
-                "  {}",
-                format!("Warning: failed to prune local AI sessions: {err}")
-                    .color(nettoolskit_ui::Color::YELLOW)
-            );
-        }
-    }
-}
+        info!(
+            correlation_id = %correlation_id,
+            command = %command,
+            command_key = %command_key,
+            duration_ms = duration.as_millis(),
+            status = status_str,
+            "Command execution completed"
+        );
+        metrics.increment_counter(counter_name);
 
-fn set_config_value(config: &mut AppConfig, key: &str, value: &str) -> Result<(), String> {
-    let normalized = key.trim().to_ascii_lowercase();
-    match normalized.as_str() {
-        "verbose" | "general.verbose" => {
-            let parsed = parse_bool(value).ok_or_else(|| {
-                "verbose must be one of: true, false, 1, 0, yes, no, on, off".to_string()
-            })?;
-            config.general.verbose = parsed;
-            Ok(())
-        }
-        "log_level" | "log-level" | "general.log_level" | "general.log-level" => {
-            config.general.log_level = parse_log_level(value)?;
-            Ok(())
-        }
-        "footer_output" | "footer-output" | "general.footer_output" | "general.footer-output" => {
-            let parsed = parse_bool(value).ok_or_else(|| {
-                "footer_output must be one of: true, false, 1, 0, yes, no, on, off".to_string()
-            })?;
-            config.general.footer_output = parsed;
-            Ok(())
-        }
-        "runtime_mode" | "runtime-mode" | "general.runtime_mode" | "general.runtime-mode" => {
-            let parsed = parse_runtime_mode(value)?;
-            config.general.runtime_mode = parsed;
-            Ok(())
-        }
-        "attention_bell"
-        | "attention-bell"
-        | "general.attention_bell"
-        | "general.attention-bell" => {
-            let parsed = parse_bool(value).ok_or_else(|| {
-                "attention_bell must be one of: true, false, 1, 0, yes, no, on, off".to_string()
-            })?;
-            config.general.attention_bell = parsed;
-            Ok(())
-        }
-        "attention_desktop_notification"
-        | "attention-desktop-notification"
-        | "general.attention_desktop_notification"
-        | "general.attention-desktop-notification" => {
-            let parsed = parse_bool(value).ok_or_else(|| {
-                "attention_desktop_notification must be one of: true, false, 1, 0, yes, no, on, off"
-                    .to_string()
-            })?;
-            config.general.attention_desktop_notification = parsed;
-            Ok(())
-        }
-        "attention_unfocused_only"
-        | "attention-unfocused-only"
-        | "general.attention_unfocused_only"
-        | "general.attention-unfocused-only" => {
-            let parsed = parse_bool(value).ok_or_else(|| {
-                "attention_unfocused_only must be one of: true, false, 1, 0, yes, no, on, off"
-                    .to_string()
-            })?;
-            config.general.attention_unfocused_only = parsed;
-            Ok(())
-        }
-        "predictive_input"
-        | "predictive-input"
-        | "general.predictive_input"
-        | "general.predictive-input" => {
-            let parsed = parse_bool(value).ok_or_else(|| {
-                "predictive_input must be one of: true, false, 1, 0, yes, no, on, off".to_string()
-            })?;
-            config.general.predictive_input = parsed;
-            Ok(())
-        }
-        "ai_session_retention"
-        | "ai-session-retention"
-        | "general.ai_session_retention"
-        | "general.ai-session-retention" => {
-            let parsed = parse_positive_usize(value).ok_or_else(|| {
-                "ai_session_retention must be a positive integer (>= 1)".to_string()
-            })?;
-            config.general.ai_session_retention = parsed;
-            Ok(())
-        }
-        "color" | "display.color" => {
-            let parsed = parse_color_mode(value)?;
-            config.display.color = parsed;
-            Ok(())
-        }
-        "unicode" | "display.unicode" => {
-            let parsed = parse_unicode_mode(value)?;
-            config.display.unicode = parsed;
-            Ok(())
-        }
-        "template_dir" | "template-dir" | "templates.directory" => {
-            config.templates.directory = Some(value.trim().to_string());
-            Ok(())
-        }
-        "shell" | "default_shell" | "default-shell" | "shell.default_shell" => {
-            config.shell.default_shell = Some(value.trim().to_string());
-            Ok(())
-        }
-        _ => Err(format!("unsupported key '{key}'")),
+        // Log metrics summary for this command
+        metrics.log_summary();
+        result
     }
 }
 
-fn unset_config_value(config: &mut AppConfig, key: &str) -> Result<(), String> {
-    let normalized = key.trim().to_ascii_lowercase();
-    match normalized.as_str() {
-        "verbose" | "general.verbose" => {
-            config.general.verbose = false;
-            Ok(())
-        }
-        "log_level" | "log-level" | "general.log_level" | "general.log-level" => {
-            config.general.log_level = "info".to_string();
-            Ok(())
-        }
-        "footer_output" | "footer-output" | "general.footer_output" | "general.footer-output" => {
-            config.general.footer_output = true;
-            Ok(())
-        }
-        "runtime_mode" | "runtime-mode" | "general.runtime_mode" | "general.runtime-mode" => {
-            config.general.runtime_mode = AppConfig::default().general.runtime_mode;
-            Ok(())
-        }
-        "attention_bell"
-        | "attention-bell"
-        | "general.attention_bell"
-        | "general.attention-bell" => {
-            config.general.attention_bell = false;
-            Ok(())
-        }
-        "attention_desktop_notification"
-        | "attention-desktop-notification"
-        | "general.attention_desktop_notification"
-        | "general.attention-desktop-notification" => {
-            config.general.attention_desktop_notification = false;
-            Ok(())
-        }
-        "attention_unfocused_only"
-        | "attention-unfocused-only"
-        | "general.attention_unfocused_only"
-        | "general.attention-unfocused-only" => {
-            config.general.attention_unfocused_only = false;
-            Ok(())
-        }
-        "predictive_input"
-        | "predictive-input"
-        | "general.predictive_input"
-        | "general.predictive-input" => {
-            config.general.predictive_input = true;
-            Ok(())
-        }
-        "ai_session_retention"
-        | "ai-session-retention"
-        | "general.ai_session_retention"
-        | "general.ai-session-retention" => {
-            config.general.ai_session_retention = default_ai_session_retention();
-            Ok(())
-        }
-        "color" | "display.color" => {
-            config.display.color = ColorMode::Auto;
-            Ok(())
-        }
-        "unicode" | "display.unicode" => {
-            config.display.unicode = UnicodeMode::Auto;
-            Ok(())
-        }
-        "template_dir" | "template-dir" | "templates.directory" => {
-            config.templates.directory = None;
-            Ok(())
-        }
-        "shell" | "default_shell" | "default-shell" | "shell.default_shell" => {
-            config.shell.default_shell = None;
-            Ok(())
-        }
-        _ => Err(format!("unsupported key '{key}'")),
-    }
+/// Run a typed (non slash-string) command with the same metrics, plugin hooks and
+/// logging as [`process_command`].
+///
+/// `cmd` is the canonical slash form used for metric keys and hook context.
+pub(super) async fn run_typed_command<T, F>(
+    cmd: &str,
+    action: MainAction,
+    subcommand: Option<&str>,
+    status_of: impl FnOnce(&T) -> ExitStatus,
+    command: F,
+) -> T
+where
+    F: Future<Output = T>,
+{
+    let correlation_id = next_correlation_id("cmd");
+    let execution_span =
+        info_span!("orchestrator.command", correlation_id = %correlation_id, command = %cmd);
+
+    let run = execution_span
+        .in_scope(|| CommandRun::start(correlation_id, cmd, Some(action), subcommand));
+    let output = command.instrument(execution_span.clone()).await;
+    execution_span.in_scope(|| run.finish(status_of(&output)));
+    output
 }
 
-fn parse_bool(value: &str) -> Option<bool> {
-    match value.trim().to_ascii_lowercase().as_str() {
-        "1" | "true" | "yes" | "on" => Some(true),
-        "0" | "false" | "no" | "off" => Some(false),
-        _ => None,
+/// Process an already-admitted control-plane envelope without reparsing a slash command string.
+pub async fn process_control_envelope(envelope: ControlEnvelope) -> TaskSubmissionOutcome {
+    process_control_envelope_with_console(envelope, ConsoleMode::Text).await
+}
+
+pub(super) async fn process_control_envelope_with_console(
+    envelope: ControlEnvelope,
+    console: ConsoleMode,
+) -> TaskSubmissionOutcome {
+    let execution_span = info_span!(
+        "orchestrator.control_envelope",
+        request_id = %envelope.request_id,
+        correlation_id = envelope.correlation_id.as_deref().unwrap_or(""),
+        operator_id = %envelope.operator.id,
+        session_id = %envelope.session.id,
+        task_intent = %task_intent_kind_label(envelope.task.kind),
+    );
+    if let Some(trace_context) = &envelope.trace_context {
+        set_span_parent(&execution_span, trace_context);
     }
+    let _execution_scope = execution_span.enter();
+
+    submit_task_intent(
+        envelope.task.clone(),
+        envelope.runtime_mode,
+        Some(envelope),
+        console,
+    )
+    .await
 }
 
-fn parse_positive_usize(value: &str) -> Option<usize> {
-    let parsed = value.trim().parse::<usize>().ok()?;
-    (parsed > 0).then_some(parsed)
+/// Comma-separated subcommand names of the registered command `command`.
+fn subcommand_names(command: &str) -> String {
+    find_command_spec(command)
+        .map(|spec| {
+            spec.subcommands
+                .iter()
+                .map(|subcommand| subcommand.name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .unwrap_or_default()
 }
 
-fn parse_log_level(value: &str) -> Result<String, String> {
-    match value.trim().to_ascii_lowercase().as_str() {
-        "off" | "error" | "warn" | "info" | "debug" | "trace" => {
-            Ok(value.trim().to_ascii_lowercase())
-        }
-        _ => Err("log_level must be one of: off, error, warn, info, debug, trace".to_string()),
+/// Append the subcommand usage lines of `command` (`/ai`, `/task`, ...) to `output`.
+fn with_subcommand_usage(output: CommandOutput, command: &str) -> CommandOutput {
+    match find_command_spec(command) {
+        Some(spec) => output.key_values(
+            spec.subcommands
+                .iter()
+                .map(|subcommand| (subcommand.usage(spec.name), subcommand.description)),
+        ),
+        None => output,
     }
 }
 
-fn parse_runtime_mode(value: &str) -> Result<RuntimeMode, String> {
-    value
-        .trim()
-        .parse::<RuntimeMode>()
-        .map_err(|_| "runtime_mode must be one of: cli, service".to_string())
+/// Registered handler for the command line split into `parts`, and the subcommand token.
+///
+/// Accepts `/cmd sub`, `/ cmd sub` and `cmd sub`; plugin commands have no handler.
+fn resolve_command_handler<'a>(parts: &[&'a str]) -> Option<(CommandHandler, Option<&'a str>)> {
+    let rest = match parts.first().copied() {
+        Some("/") => parts.get(1..)?,
+        _ => parts,
+    };
+    let spec = find_command_spec(rest.first()?)?;
+    let subcommand = rest.get(1).copied();
+    spec.handler_for(subcommand)
+        .map(|handler| (handler, subcommand))
 }
 
-fn parse_color_mode(value: &str) -> Result<ColorMode, String> {
-    match value.trim().to_ascii_lowercase().as_str() {
-        "auto" => Ok(ColorMode::Auto),
-        "always" => Ok(ColorMode::Always),
-        "never" => Ok(ColorMode::Never),
-        _ => Err("color must be one of: auto, always, never".to_string()),
-    }
+/// Run the registry handler for `command`, or `None` when no built-in command matches.
+async fn dispatch_command(
+    command: &str,
+    parts: &[&str],
+    interrupted: Option<&AtomicBool>,
+) -> Option<ExitStatus> {
+    let (handler, subcommand) = resolve_command_handler(parts)?;
+    Some(
+        handler(CommandContext {
+            command,
+            parts,
+            subcommand,
+            interrupted,
+        })
+        .await,
+    )
 }
 
-fn parse_unicode_mode(value: &str) -> Result<UnicodeMode, String> {
-    match value.trim().to_ascii_lowercase().as_str() {
-        "auto" => Ok(UnicodeMode::Auto),
-        "always" => Ok(UnicodeMode::Always),
-        "never" => Ok(UnicodeMode::Never),
-        _ => Err("unicode must be one of: auto, always, never".to_string()),
-    }
+/// Process slash commands from CLI and return appropriate status, with optional interrupt flag.
+///
+/// When `interrupted` is provided, long-running async aliases can react to Ctrl+C and
+/// return [`ExitStatus::Interrupted`] deterministically.
+pub async fn process_command_with_interrupt(
+    cmd: &str,
+    interrupted: Option<&AtomicBool>,
+) -> ExitStatus {
+    let correlation_id = next_correlation_id("cmd");
+    let execution_span =
+        info_span!("orchestrator.command", correlation_id = %correlation_id, command = %cmd);
+    let _execution_scope = execution_span.enter();
+
+    let metrics = runtime_metrics().clone();
+
+    // Parse command - pass full command string to get_main_action
+    // It will handle "/ help", "/help", or "help" formats
+    let parts: Vec<&str> = cmd.split_whitespace().collect();
+
+    // If command is "/ help" (with space), parts = ["/", "help"], subcommand = parts[2]
+    // If command is "/help list", parts = ["/help", "list"], subcommand = parts[1]
+    let subcommand = if parts.first().copied() == Some("/") {
+        parts.get(2).copied()
+    } else {
+        parts.get(1).copied()
+    };
+
+    // Parse command using full original string
+    let parsed = crate::models::get_main_action(cmd);
+    let run = CommandRun::start(correlation_id, cmd, parsed, subcommand);
+
+    let result = match dispatch_command(cmd, &parts, interrupted).await {
+        Some(status) => status,
+        None => match run_plugin_command(cmd, &run.correlation_id) {
+            Some(Ok(output)) => {
+                emit(output);
+                ExitStatus::Success
+            }
+            Some(Err(error)) => {
+                print_command_failure(&CommandFailure::new(error));
+                ExitStatus::Error
+            }
+            None => {
+                tracing::warn!("Unknown command attempted: {}", cmd);
+                metrics.increment_counter("unknown_command_attempts");
+                print_command_failure(&CommandFailure::new(format!("Unknown command: {cmd}")));
+                ExitStatus::Error
+            }
+        },
+    };
+
+    run.finish(result)
 }
 
 fn infer_command_from_text(text: &str) -> Option<String> {
@@ -6129,84 +5380,69 @@ fn infer_command_from_text(text: &str) -> Option<String> {
         .collect();
     let has = |keyword: &str| lowered.iter().any(|token| token == keyword);
 
-    match first.as_str() {
-        // direct command aliases (without slash)
-        "help" | "ajuda" => Some("/help".to_string()),
-        "manifest" | "manifests" => Some(format!("/{}", trimmed)),
-        "ai" => Some(format!("/{}", trimmed)),
-        "task" => Some(format!("/{}", trimmed)),
-        "tasks" | "tarefa" | "tarefas" => {
-            if tokens.len() > 1 {
-                Some(format!("/task {}", tokens[1..].join(" ")))
-            } else {
-                Some("/task".to_string())
-            }
-        }
-        "assistant" | "copilot" => {
-            if tokens.len() > 1 {
-                Some(format!("/ai {}", tokens[1..].join(" ")))
-            } else {
-                Some("/ai".to_string())
-            }
-        }
-        "clear" | "cls" | "limpar" => Some("/clear".to_string()),
-        "perguntar" | "ask" => {
-            if tokens.len() > 1 {
-                Some(format!("/ai ask {}", tokens[1..].join(" ")))
-            } else {
-                Some("/ai ask".to_string())
-            }
-        }
-        "explain" | "explicar" | "explique" => {
-            if tokens.len() > 1 {
-                Some(format!("/ai explain {}", tokens[1..].join(" ")))
-            } else {
-                Some("/ai explain".to_string())
-            }
+    // Direct command and subcommand aliases come from the command registry.
+    let rest = tokens[1..].join(" ");
+    let with_rest = |head: String| {
+        if rest.is_empty() {
+            head
+        } else {
+            format!("{head} {rest}")
         }
-        "plan" | "planejar" | "planeje" => {
-            if tokens.len() > 1 {
-                Some(format!("/ai plan {}", tokens[1..].join(" ")))
-            } else {
-                Some("/ai plan".to_string())
-            }
+    };
+    if let Some(spec) = find_command_by_alias(&first) {
+        // Commands without arguments ignore trailing words (`help me` → `/help`).
+        if spec.subcommands.is_empty() && spec.args.is_empty() {
+            return Some(spec.name.to_string());
         }
-        "config" | "configuracao" | "configuração" | "settings" => Some(format!("/{}", trimmed)),
-        _ => {
-            // keyword-based intent routing
-            if has("help") || has("ajuda") {
-                Some("/help".to_string())
-            } else if has("manifest") || has("manifests") || has("manifests:") {
-                if has("list") || has("listar") || has("discover") {
-                    Some("/manifest list".to_string())
-                } else if has("check") || has("validate") || has("validar") {
-                    Some("/manifest check".to_string())
-                } else if has("render") || has("preview") || has("previa") || has("prévia") {
-                    Some("/manifest render".to_string())
-                } else if has("apply") || has("aplicar") {
-                    Some("/manifest apply".to_string())
-                } else {
-                    Some("/manifest".to_string())
-                }
-            } else if has("config") || has("configuracao") || has("configuração") || has("settings")
-            {
-                Some("/config".to_string())
-            } else if has("ai") || has("assistant") || has("copilot") {
-                if has("plan") || has("planejar") || has("planeje") {
-                    Some("/ai plan".to_string())
-                } else if has("explain") || has("explicar") || has("explique") {
-                    Some("/ai explain".to_string())
-                } else if has("apply") || has("aplicar") {
-                    Some("/ai apply --dry-run".to_string())
-                } else {
-                    Some("/ai ask".to_string())
-                }
-            } else if has("clear") || has("limpar") || has("cls") {
-                Some("/clear".to_string())
-            } else {
-                None
-            }
+        return Some(with_rest(spec.name.to_string()));
+    }
+    if let Some((command, subcommand)) = find_subcommand_shortcut(&first) {
+        return Some(with_rest(format!("{} {}", command.name, subcommand.name)));
+    }
+
+    // Keyword routing: a command word (or alias) anywhere in the sentence.
+    let mentions = |command: &str| {
+        lowered
+            .iter()
+            .any(|token| find_command_by_alias(token).is_some_and(|spec| spec.name == command))
+    };
+    let mentions_shortcut = |command: &str, subcommand: &str| {
+        lowered.iter().any(|token| {
+            find_subcommand_shortcut(token)
+                .is_some_and(|(spec, sub)| spec.name == command && sub.name == subcommand)
+        })
+    };
+
+    if mentions("/help") {
+        Some("/help".to_string())
+    } else if mentions("/manifest") || has("manifests:") {
+        if has("list") || has("listar") || has("discover") {
+            Some("/manifest list".to_string())
+        } else if has("check") || has("validate") || has("validar") {
+            Some("/manifest check".to_string())
+        } else if has("render") || has("preview") || has("previa") || has("prévia") {
+            Some("/manifest render".to_string())
+        } else if has("apply") || has("aplicar") {
+            Some("/manifest apply".to_string())
+        } else {
+            Some("/manifest".to_string())
+        }
+    } else if mentions("/config") {
+        Some("/config".to_string())
+    } else if mentions("/ai") {
+        if mentions_shortcut("/ai", "plan") {
+            Some("/ai plan".to_string())
+        } else if mentions_shortcut("/ai", "explain") {
+            Some("/ai explain".to_string())
+        } else if has("apply") || has("aplicar") {
+            Some("/ai apply --dry-run".to_string())
+        } else {
+            Some("/ai ask".to_string())
         }
+    } else if mentions("/clear") {
+        Some("/clear".to_string())
+    } else {
+        None
     }
 }
 
@@ -6251,19 +5487,16 @@ pub async fn process_text(text: &str) -> ExitStatus {
     metrics.increment_counter("runtime_text_inputs_unrecognized_total");
     tracing::debug!(input = %trimmed, "Unrecognized text input");
 
-    use nettoolskit_ui::Color;
-    use owo_colors::OwoColorize;
-
-    println!(
-        "{}: {}",
-        "Unrecognized input".color(Color::YELLOW),
-        trimmed.color(Color::GRAY)
-    );
-    println!(
-        "  {} Type {} to see available commands, or {} to open the palette.",
-        "💡".color(Color::CYAN),
-        "/help".color(Color::GREEN),
-        "/".color(Color::GREEN)
+    emit(
+        CommandOutput::new()
+            .text(
+                format!("Unrecognized input: {trimmed}"),
+                OutputTone::Warning,
+            )
+            .text(
+                "💡 Type /help to see available commands, or / to open the palette.",
+                OutputTone::Info,
+            ),
     );
 
     ExitStatus::Success
@@ -6271,7 +5504,9 @@ pub async fn process_text(text: &str) -> ExitStatus {
 
 #[cfg(test)]
 mod tests {
+    use super::ai::parse_ai_usage_window_ms;
     use super::*;
+    use crate::models::command_spec::parse_arguments;
     use nettoolskit_core::{
         ApprovalState, ControlEnvelope, ControlPolicyContext, IngressTransport, OperatorContext,
         OperatorKind, SessionContext, SessionKind,
@@ -6284,6 +5519,12 @@ mod tests {
 
     static ENV_TEST_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
 
+    async fn dispatch_parts(parts: &[&str]) -> ExitStatus {
+        dispatch_command(&parts.join(" "), parts, None)
+            .await
+            .expect("built-in command")
+    }
+
     async fn env_test_guard() -> tokio::sync::MutexGuard<'static, ()> {
         ENV_TEST_LOCK.get_or_init(|| Mutex::new(())).lock().await
     }
@@ -6391,44 +5632,39 @@ mod tests {
     }
 
     #[test]
-    fn detect_async_manifest_alias_matches_top_level_commands() {
-        let parts = vec!["/render-async", "sample.manifest.yaml"];
-        let alias = detect_async_manifest_alias(None, &parts, None);
-        assert_eq!(alias, Some(AsyncManifestAlias::Render));
-
-        let parts = vec!["/apply-async", "sample.manifest.yaml"];
-        let alias = detect_async_manifest_alias(None, &parts, None);
-        assert_eq!(alias, Some(AsyncManifestAlias::Apply));
-
-        let parts = vec!["/new-async", "sample.manifest.yaml"];
-        let alias = detect_async_manifest_alias(None, &parts, None);
-        assert_eq!(alias, Some(AsyncManifestAlias::New));
-    }
-
-    #[test]
-    fn detect_async_manifest_alias_matches_manifest_subcommands() {
-        let parts = vec!["/manifest", "render-async", "sample.manifest.yaml"];
-        let alias =
-            detect_async_manifest_alias(Some(MainAction::Manifest), &parts, Some("render-async"));
-        assert_eq!(alias, Some(AsyncManifestAlias::Render));
+    fn resolve_command_handler_reads_subcommand_from_every_command_form() {
+        for parts in [
+            vec!["/manifest", "render-async", "sample.manifest.yaml"],
+            vec!["/", "manifest", "render-async", "sample.manifest.yaml"],
+            vec!["manifest", "render-async"],
+        ] {
+            let (_, subcommand) = resolve_command_handler(&parts).expect("built-in command");
+            assert_eq!(subcommand, Some("render-async"));
+        }
 
-        let parts = vec!["/manifest", "apply-async", "sample.manifest.yaml"];
-        let alias =
-            detect_async_manifest_alias(Some(MainAction::Manifest), &parts, Some("apply-async"));
-        assert_eq!(alias, Some(AsyncManifestAlias::Apply));
+        let (_, subcommand) = resolve_command_handler(&["/render-async", "sample.manifest.yaml"])
+            .expect("top-level async alias");
+        assert_eq!(subcommand, Some("sample.manifest.yaml"));
+        assert!(resolve_command_handler(&["/not-registered"]).is_none());
+        assert!(resolve_command_handler(&["/"]).is_none());
+        assert!(resolve_command_handler(&[]).is_none());
     }
 
-    #[test]
-    fn first_positional_path_respects_start_index() {
-        let parts = vec!["/render-async", "sample.manifest.yaml", "--output", "./out"];
-        let path = first_positional_path(&parts, 1);
+    #[tokio::test]
+    async fn dispatch_command_routes_async_manifest_aliases() {
+        for command in ["/new-async", "/manifest new-async", "/apply-async"] {
+            let parts: Vec<&str> = command.split_whitespace().collect();
+            let status = dispatch_command(command, &parts, None).await;
+            assert_eq!(
+                status,
+                Some(ExitStatus::Error),
+                "{command} needs a manifest"
+            );
+        }
         assert_eq!(
-            path.as_deref(),
-            Some(std::path::Path::new("sample.manifest.yaml"))
+            dispatch_command("/greet-nobody", &["/greet-nobody"], None).await,
+            None
         );
-
-        let path = first_positional_path(&parts, 2);
-        assert_eq!(path.as_deref(), Some(std::path::Path::new("./out")));
     }
 
     #[test]
@@ -6457,9 +5693,9 @@ mod tests {
     }
 
     #[test]
-    fn collect_ai_prompt_skips_flags_and_joins_prompt_tokens() {
+    fn ai_apply_prompt_skips_flags_and_joins_prompt_tokens() {
         let parts = vec!["/ai", "apply", "--dry-run", "create", "service", "layer"];
-        let prompt = collect_ai_prompt(&parts, 2);
+        let prompt = parse_arguments(&parts).text_from(0);
         assert_eq!(prompt, "create service layer");
     }
 
@@ -6625,11 +5861,241 @@ mod tests {
                 AiIntent::Ask,
                 AiIntent::Plan,
                 AiIntent::Explain,
-                AiIntent::ApplyDryRun
+                AiIntent::ApplyDryRun,
+                AiIntent::Manifest,
+                AiIntent::SessionSummary
             ]
         );
     }
 
+    #[test]
+    fn mocked_manifest_response_is_a_reviewable_patch() {
+        let manifest = "apiVersion: ntk/v1\nkind: solution\nmeta:\n  name: orders\nsolution:\n  root: ./\n  slnFile: Orders.sln\nconventions:\n  namespaceRoot: Acme.Orders\n  targetFramework: net9.0\n  policy:\n    collision: fail\n    insertTodoWhenMissing: true\n    strict: false\napply:\n  mode: feature\n  feature:\n    context: Sales\n";
+        let response = mocked_ai_response(AiIntent::Manifest, "add the customer's Order aggregate");
+        let patch = extract_yaml_patch(&response.output_text).expect("fenced patch");
+        let review = review_manifest_patch(manifest, &patch).expect("patch applies");
+
+        assert!(
+            review.validation.is_valid(),
+            "{:?}",
+            review.validation.errors
+        );
+        assert!(review
+            .diff("ntk-orders.yml")
+            .contains("+  description: Mock patch for add the customer's Order aggregate"));
+    }
+
+    fn ai_session_with_exchanges(session_id: &str, count: usize) -> LocalAiSessionState {
+        let mut session = LocalAiSessionState::new(session_id);
+        for turn in 1..=count {
+            let _ = session.append_exchange(
+                "ask",
+                "mock",
+                &format!("question {turn} about the build pipeline"),
+                &format!("answer {turn}: run cargo test before merging"),
+            );
+        }
+        session
+    }
+
+    #[tokio::test]
+    async fn refresh_ai_session_rolling_summary_asks_provider_once_over_budget() {
+        let _guard = env_test_guard().await;
+        clear_ai_provider_route_env_vars();
+        std::env::set_var("NTK_AI_PROVIDER", "mock");
+
+        let session_id = "test-rolling-summary-provider";
+        let mut session = ai_session_with_exchanges(session_id, 10);
+        let request = build_ai_request(AiIntent::Ask, "next question");
+        let metrics = runtime_metrics().clone();
+
+        let roomy = AiTokenEconomyPolicy::default();
+        refresh_ai_session_rolling_summary(
+            &mut session,
+            &request,
+            AiIntent::Ask,
+            roomy,
+            AiRetryPolicy::default(),
+            &metrics,
+        )
+        .await;
+        assert!(session.rolling_summary.is_none());
+
+        let tight = AiTokenEconomyPolicy {
+            max_session_tokens_total: 100,
+            ..AiTokenEconomyPolicy::default()
+        };
+        let tokens_before = estimate_ai_session_tokens(&session);
+        refresh_ai_session_rolling_summary(
+            &mut session,
+            &request,
+            AiIntent::Ask,
+            tight,
+            AiRetryPolicy::default(),
+            &metrics,
+        )
+        .await;
+        let _ = LocalAiSessionState::delete_local_snapshot(session_id);
+        clear_ai_provider_route_env_vars();
+
+        let summary = session.rolling_summary.clone().expect("rolling summary");
+        assert_eq!(summary.source, "mock");
+        assert_eq!(
+            summary.covered_exchanges,
+            10 - AI_SESSION_SUMMARY_KEEP_RECENT_EXCHANGES
+        );
+        assert!(summary.text.starts_with("Mock AI session summary"));
+        assert!(estimate_ai_session_tokens(&session) < tokens_before);
+
+        let mut next = build_ai_request(AiIntent::Ask, "next question");
+        inject_ai_session_context(&mut next, &session);
+        assert_eq!(next.messages[1].role, AiRole::System);
+        assert!(next.messages[1].content.contains("Mock AI session summary"));
+        assert!(!next
+            .messages
+            .iter()
+            .any(|message| message.content.contains("question 1 about")));
+    }
+
+    fn clear_ai_spend_env_vars() {
+        std::env::remove_var(NTK_AI_PRICE_TABLE_ENV);
+        std::env::remove_var(NTK_AI_SPEND_CAP_DAILY_USD_ENV);
+        std::env::remove_var(NTK_AI_SPEND_CAP_MONTHLY_USD_ENV);
+        std::env::remove_var(NTK_AI_SPEND_LEDGER_PATH_ENV);
+    }
+
+    #[tokio::test]
+    async fn record_ai_spend_prefers_provider_usage_and_prices_from_table() {
+        let _guard = env_test_guard().await;
+        let dir = tempfile::tempdir().expect("temp dir");
+        let ledger_path = dir.path().join("spend.jsonl");
+        std::env::set_var(NTK_AI_SPEND_LEDGER_PATH_ENV, &ledger_path);
+        std::env::set_var(
+            NTK_AI_PRICE_TABLE_ENV,
+            "openai/gpt-4.1-mini=1.0:2.0, not-an-entry",
+        );
+
+        let request = build_ai_request(AiIntent::Plan, "plan the rollout");
+        let exchange = || AiSpendExchange {
+            session_id: "spend-session",
+            intent: AiIntent::Plan,
+            provider_id: "openai",
+            model: "gpt-4.1-mini",
+            request: &request,
+            output: "step one",
+        };
+        let metrics = Metrics::new();
+        let reported = record_ai_spend(
+            &metrics,
+            exchange(),
+            Some(AiUsage {
+                input_tokens: 1_000,
+                output_tokens: 500,
+            }),
+        );
+        let estimated = with_ai_spend_operator("telegram:42".to_string(), async {
+            record_ai_spend(&metrics, exchange(), None)
+        })
+        .await;
+        let recorded = AiSpendLedger::from_path(&ledger_path)
+            .load_since(0)
+            .expect("ledger");
+        clear_ai_spend_env_vars();
+
+        assert_eq!(reported.usage_source, AiUsageSource::Provider);
+        assert!((reported.cost_usd - 2.0).abs() < 1e-9);
+        assert_eq!(reported.operator, "local-cli-operator");
+        assert_eq!(estimated.usage_source, AiUsageSource::Estimate);
+        assert_eq!(
+            estimated.input_tokens,
+            estimate_request_input_tokens(&request)
+        );
+        assert_eq!(estimated.operator, "telegram:42");
+        assert_eq!(recorded, vec![reported, estimated]);
+        assert_eq!(metrics.get_counter("runtime_ai_usage_provider_total"), 1);
+        assert_eq!(metrics.get_counter("runtime_ai_usage_estimate_total"), 1);
+    }
+
+    #[tokio::test]
+    async fn process_ai_command_records_spend_and_enforces_daily_cap() {
+        let _guard = env_test_guard().await;
+        clear_ai_provider_route_env_vars();
+        std::env::set_var("NTK_AI_PROVIDER", "mock");
+        std::env::set_var(NTK_AI_CACHE_FIRST_ENABLED_ENV, "false");
+        let dir = tempfile::tempdir().expect("temp dir");
+        let ledger_path = dir.path().join("spend.jsonl");
+        std::env::set_var(NTK_AI_SPEND_LEDGER_PATH_ENV, &ledger_path);
+        std::env::set_var(NTK_AI_PRICE_TABLE_ENV, "mock/*=1.0:1.0");
+        let ledger = AiSpendLedger::from_path(&ledger_path);
+
+        let parts = vec!["/ai", "ask", "how", "much", "did", "this", "cost"];
+        let first = dispatch_parts(&parts).await;
+        let after_first = ledger.load_since(0).expect("ledger");
+
+        std::env::set_var(NTK_AI_SPEND_CAP_DAILY_USD_ENV, "0.0001");
+        let capped = dispatch_parts(&parts).await;
+        let after_capped = ledger.load_since(0).expect("ledger");
+
+        std::env::remove_var(NTK_AI_CACHE_FIRST_ENABLED_ENV);
+        clear_ai_spend_env_vars();
+        clear_ai_provider_route_env_vars();
+        let _ = LocalAiSessionState::delete_local_snapshot(&resolve_active_ai_session_id());
+
+        assert_eq!(first, ExitStatus::Success);
+        assert_eq!(after_first.len(), 1);
+        assert_eq!(after_first[0].intent, "ask");
+        assert_eq!(after_first[0].provider, "mock");
+        assert_eq!(after_first[0].model, "mock-assistant");
+        assert_eq!(after_first[0].usage_source, AiUsageSource::Estimate);
+        assert!(after_first[0].cost_usd > 0.0);
+        assert_eq!(capped, ExitStatus::Error);
+        assert_eq!(after_capped.len(), 1, "capped request must not be charged");
+    }
+
+    #[test]
+    fn parse_ai_usage_window_ms_accepts_hours_days_and_weeks() {
+        assert_eq!(parse_ai_usage_window_ms("24h"), Some(86_400_000));
+        assert_eq!(parse_ai_usage_window_ms("7d"), Some(7 * 86_400_000));
+        assert_eq!(parse_ai_usage_window_ms("7"), Some(7 * 86_400_000));
+        assert_eq!(parse_ai_usage_window_ms("2W"), Some(14 * 86_400_000));
+        assert_eq!(parse_ai_usage_window_ms("0d"), None);
+        assert_eq!(parse_ai_usage_window_ms("d"), None);
+        assert_eq!(parse_ai_usage_window_ms(""), None);
+    }
+
+    #[tokio::test]
+    async fn refresh_ai_session_rolling_summary_falls_back_to_truncation() {
+        let _guard = env_test_guard().await;
+        clear_ai_provider_route_env_vars();
+        std::env::set_var("NTK_AI_PROVIDER", "unsupported-provider");
+
+        let session_id = "test-rolling-summary-fallback";
+        let mut session = ai_session_with_exchanges(session_id, 8);
+        let request = build_ai_request(AiIntent::Ask, "next question");
+        let tight = AiTokenEconomyPolicy {
+            max_session_tokens_total: 100,
+            ..AiTokenEconomyPolicy::default()
+        };
+        refresh_ai_session_rolling_summary(
+            &mut session,
+            &request,
+            AiIntent::Ask,
+            tight,
+            AiRetryPolicy::default(),
+            runtime_metrics(),
+        )
+        .await;
+        let _ = LocalAiSessionState::delete_local_snapshot(session_id);
+        clear_ai_provider_route_env_vars();
+
+        let summary = session.rolling_summary.expect("rolling summary");
+        assert_eq!(summary.source, AI_SESSION_SUMMARY_TRUNCATION_SOURCE);
+        assert_eq!(summary.covered_exchanges, 2);
+        assert!(summary
+            .text
+            .contains("- ask: question 1 about the build pipeline"));
+    }
+
     #[tokio::test]
     async fn ai_model_selection_policy_from_env_applies_overrides() {
         let _guard = env_test_guard().await;
@@ -7282,6 +6748,28 @@ mod tests {
         assert_eq!(metrics.get_counter("runtime_command_help_error_total"), 1);
     }
 
+    #[test]
+    fn set_config_value_validates_service_endpoint() {
+        let mut config = AppConfig::default();
+        assert!(set_config_value(&mut config, "service_endpoint", "agent-box:8080").is_err());
+        assert!(set_config_value(
+            &mut config,
+            "general.service-endpoint",
+            "https://agent-box/"
+        )
+        .is_ok());
+        assert_eq!(
+            config.general.service_endpoint.as_deref(),
+            Some("https://agent-box")
+        );
+        assert_eq!(
+            get_config_value(&config, "service_endpoint").as_deref(),
+            Ok("https://agent-box")
+        );
+        assert!(unset_config_value(&mut config, "service_endpoint").is_ok());
+        assert!(config.general.service_endpoint.is_none());
+    }
+
     #[test]
     fn set_config_value_updates_known_keys() {
         let mut config = AppConfig::default();
@@ -7326,6 +6814,35 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn config_value_round_trips_policy_keys() {
+        let mut config = AppConfig::default();
+        assert!(
+            set_config_value(&mut config, "chatops.rate-limit-strategy", "token_bucket").is_ok()
+        );
+        assert_eq!(
+            config.chatops.rate_limit_strategy.as_deref(),
+            Some("token_bucket")
+        );
+        assert_eq!(
+            get_config_value(&config, "chatops.rate_limit_strategy").as_deref(),
+            Ok("token_bucket")
+        );
+
+        let error = set_config_value(&mut config, "chatops.rate_limit_strategy", "burst")
+            .expect_err("unknown strategy is rejected");
+        assert!(error.contains("fixed_window|token_bucket"));
+
+        assert!(set_config_value(&mut config, "service.auth_token", "s3cret").is_ok());
+        assert_eq!(
+            get_config_value(&config, "service.auth_token").as_deref(),
+            Ok("********")
+        );
+
+        assert!(unset_config_value(&mut config, "chatops.rate_limit_strategy").is_ok());
+        assert!(config.chatops.rate_limit_strategy.is_none());
+    }
+
     #[test]
     fn unset_config_value_resets_known_keys() {
         let mut config = AppConfig::default();
@@ -7468,9 +6985,25 @@ mod tests {
     }
 
     #[test]
-    fn first_manifest_positional_path_extracts_apply_path() {
+    fn manifest_path_arg_extracts_apply_path() {
         let parts = vec!["/manifest", "apply", "feature.manifest.yaml", "--dry-run"];
-        let path = first_manifest_positional_path(&parts);
+        let path = manifest_path_arg(&parse_arguments(&parts));
+        assert_eq!(
+            path.as_deref(),
+            Some(std::path::Path::new("feature.manifest.yaml"))
+        );
+    }
+
+    #[test]
+    fn manifest_path_arg_skips_output_value() {
+        let parts = vec![
+            "/manifest",
+            "render",
+            "--output",
+            "./out",
+            "feature.manifest.yaml",
+        ];
+        let path = manifest_path_arg(&parse_arguments(&parts));
         assert_eq!(
             path.as_deref(),
             Some(std::path::Path::new("feature.manifest.yaml"))
@@ -7478,7 +7011,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_output_root_extracts_output_value() {
+    fn output_root_arg_extracts_output_value() {
         let parts = vec![
             "/manifest",
             "apply",
@@ -7486,7 +7019,7 @@ mod tests {
             "--output",
             "./src",
         ];
-        let output = parse_output_root(&parts);
+        let output = output_root_arg(&parse_arguments(&parts));
         assert_eq!(output.as_deref(), Some(std::path::Path::new("./src")));
     }
 
@@ -7495,7 +7028,7 @@ mod tests {
         let _guard = env_test_guard().await;
         clear_ai_provider_route_env_vars();
         let parts = vec!["/ai", "apply", "update", "service"];
-        let status = process_ai_command(&parts, Some("apply")).await;
+        let status = dispatch_parts(&parts).await;
         clear_ai_provider_route_env_vars();
         assert_eq!(status, ExitStatus::Error);
     }
@@ -7505,7 +7038,7 @@ mod tests {
         let _guard = env_test_guard().await;
         clear_ai_provider_route_env_vars();
         let parts = vec!["/ai", "apply", "--approve-write", "update", "service"];
-        let status = process_ai_command(&parts, Some("apply")).await;
+        let status = dispatch_parts(&parts).await;
         clear_ai_provider_route_env_vars();
         assert_eq!(status, ExitStatus::Success);
     }
@@ -7523,8 +7056,8 @@ mod tests {
         let baseline_cache_misses = metrics.get_counter("runtime_ai_cache_misses_total");
 
         let parts = vec!["/ai", "ask", "cache-first repeat prompt"];
-        let first_status = process_ai_command(&parts, Some("ask")).await;
-        let second_status = process_ai_command(&parts, Some("ask")).await;
+        let first_status = dispatch_parts(&parts).await;
+        let second_status = dispatch_parts(&parts).await;
 
         clear_ai_provider_route_env_vars();
 
@@ -7560,7 +7093,7 @@ mod tests {
             "migration",
             "strategy",
         ];
-        let status = process_ai_command(&parts, Some("plan")).await;
+        let status = dispatch_parts(&parts).await;
 
         std::env::remove_var("NTK_AI_COST_PER_1K_INPUT_USD");
         std::env::remove_var("NTK_AI_COST_PER_1K_OUTPUT_USD");
@@ -7589,7 +7122,7 @@ mod tests {
             metrics.get_counter("runtime_ai_model_selection_guardrail_fallback_total");
 
         let parts = vec!["/ai", "plan", "prepare", "service", "hardening", "plan"];
-        let status = process_ai_command(&parts, Some("plan")).await;
+        let status = dispatch_parts(&parts).await;
 
         std::env::remove_var("NTK_AI_COST_PER_1K_INPUT_USD");
         std::env::remove_var("NTK_AI_COST_PER_1K_OUTPUT_USD");
@@ -7605,14 +7138,14 @@ mod tests {
     #[tokio::test]
     async fn process_ai_command_resume_without_session_id_returns_error() {
         let parts = vec!["/ai", "resume"];
-        let status = process_ai_command(&parts, Some("resume")).await;
+        let status = dispatch_parts(&parts).await;
         assert_eq!(status, ExitStatus::Error);
     }
 
     #[tokio::test]
     async fn process_ai_command_resume_with_session_id_succeeds() {
         let parts = vec!["/ai", "resume", "session-dev"];
-        let status = process_ai_command(&parts, Some("resume")).await;
+        let status = dispatch_parts(&parts).await;
         assert_eq!(status, ExitStatus::Success);
     }
 
@@ -7823,7 +7356,7 @@ mod tests {
 
     #[test]
     fn resolve_task_execution_target_service_uses_background_worker_label() {
-        let (target, _note) = resolve_task_execution_target(RuntimeMode::Service);
+        let (target, _note) = resolve_task_execution_target(RuntimeMode::Service, None);
         assert_eq!(target, "background-worker-local");
     }
 
@@ -7835,7 +7368,7 @@ mod tests {
         reset_service_submission_budget_for_tests();
 
         let parts = vec!["/task", "submit", "ai-plan", "prepare", "deployment"];
-        let status = process_task_command(&parts).await;
+        let status = dispatch_parts(&parts).await;
 
         clear_service_policy_env_vars();
         assert_eq!(status, ExitStatus::Success);
@@ -7852,7 +7385,7 @@ mod tests {
 
         let payload_marker = format!("cli-control-envelope-test-{}", current_unix_timestamp_ms());
         let parts = vec!["/task", "submit", "ai-plan", payload_marker.as_str()];
-        let status = process_task_command(&parts).await;
+        let status = dispatch_parts(&parts).await;
 
         std::env::remove_var("NTK_AI_PROVIDER");
         std::env::remove_var(NTK_TOOL_SCOPE_ALLOWED_TOOLS_ENV);
@@ -7908,7 +7441,7 @@ mod tests {
 
         let payload_marker = format!("service-queue-test-{}", current_unix_timestamp_ms());
         let parts = vec!["/task", "submit", "ai-plan", payload_marker.as_str()];
-        let status = process_task_command(&parts).await;
+        let status = dispatch_parts(&parts).await;
 
         std::env::remove_var("NTK_AI_PROVIDER");
         std::env::remove_var("NTK_RUNTIME_MODE");
@@ -8022,8 +7555,7 @@ mod tests {
         clear_service_policy_env_vars();
         reset_service_submission_budget_for_tests();
 
-        let status =
-            process_task_command(&["/task", "submit", "ai-plan", "blocked-by-tool-scope"]).await;
+        let status = dispatch_parts(&["/task", "submit", "ai-plan", "blocked-by-tool-scope"]).await;
 
         clear_service_policy_env_vars();
         std::env::remove_var("NTK_RUNTIME_MODE");
@@ -8040,7 +7572,7 @@ mod tests {
         std::env::set_var(NTK_TOOL_SCOPE_ALLOWED_TOOLS_ENV, "repo.workflow.execute");
         reset_service_submission_budget_for_tests();
 
-        let status = process_task_command(&[
+        let status = dispatch_parts(&[
             "/task",
             "submit",
             "repo-workflow",
@@ -8063,8 +7595,7 @@ mod tests {
         std::env::set_var(NTK_TOOL_SCOPE_ALLOWED_TOOLS_ENV, "ai.plan");
         reset_service_submission_budget_for_tests();
 
-        let status =
-            process_task_command(&["/task", "submit", "ai-plan", "payload-over-budget"]).await;
+        let status = dispatch_parts(&["/task", "submit", "ai-plan", "payload-over-budget"]).await;
 
         clear_service_policy_env_vars();
         std::env::remove_var("NTK_RUNTIME_MODE");
@@ -8087,7 +7618,7 @@ mod tests {
             "ai-plan",
             "this payload should exceed the configured input token budget",
         ];
-        let status = process_task_command(&parts).await;
+        let status = dispatch_parts(&parts).await;
 
         clear_service_policy_env_vars();
         clear_ai_provider_route_env_vars();
@@ -8111,7 +7642,7 @@ mod tests {
             "repo-workflow",
             "repo=https://github.com/acme/demo.git;branch=feature/chatops;command=cargo test;dry_run=true",
         ];
-        let status = process_task_command(&parts).await;
+        let status = dispatch_parts(&parts).await;
 
         std::env::remove_var("NTK_REPO_WORKFLOW_ALLOW_PR");
         std::env::remove_var("NTK_REPO_WORKFLOW_ALLOW_PUSH");
@@ -8125,14 +7656,14 @@ mod tests {
     #[tokio::test]
     async fn process_task_command_submit_without_payload_returns_error() {
         let parts = vec!["/task", "submit", "ai-plan"];
-        let status = process_task_command(&parts).await;
+        let status = dispatch_parts(&parts).await;
         assert_eq!(status, ExitStatus::Error);
     }
 
     #[tokio::test]
     async fn process_task_command_watch_without_id_returns_error() {
         let parts = vec!["/task", "watch"];
-        let status = process_task_command(&parts).await;
+        let status = dispatch_parts(&parts).await;
         assert_eq!(status, ExitStatus::Error);
     }
 
@@ -0,0 +1,799 @@
+//! Handlers for `/ai` subcommands.
+
+use super::*;
+
+pub(super) async fn handle_ai_manifest_subcommand(args: &ParsedArguments<'_>) -> ExitStatus {
+    let instruction = args.text_from(0);
+    if instruction.is_empty() {
+        print_command_failure(
+            &CommandFailure::new("Missing instruction for /ai manifest.")
+                .with_hint("Usage: /ai manifest <instruction>"),
+        );
+        return ExitStatus::Error;
+    }
+    let Some((display, path, _)) = nearest_ai_manifest() else {
+        print_command_failure(
+            &CommandFailure::new("No ntk/v1 manifest found near the working directory.")
+                .with_hint("Run from a directory containing an ntk-*.yml manifest."),
+        );
+        return ExitStatus::Error;
+    };
+    let original = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) => {
+            print_command_failure(&CommandFailure::new(format!(
+                "Failed to read {display}: {err}"
+            )));
+            return ExitStatus::Error;
+        }
+    };
+
+    let report = match run_ai_request(
+        AiIntent::Manifest,
+        &instruction,
+        false,
+        false,
+        ConsoleMode::Silent,
+    )
+    .await
+    {
+        Ok(report) => report,
+        Err(failure) => {
+            print_command_failure(&failure);
+            return ExitStatus::Error;
+        }
+    };
+
+    let review = extract_yaml_patch(&report.output)
+        .ok_or_else(|| "AI response did not contain a YAML patch".to_string())
+        .and_then(|patch| review_manifest_patch(&original, &patch));
+    let review = match review {
+        Ok(review) => review,
+        Err(err) => {
+            print_command_failure(
+                &CommandFailure::new(format!("Manifest patch rejected: {err}"))
+                    .with_hint("Rephrase the instruction or run /ai ask for guidance."),
+            );
+            return ExitStatus::Error;
+        }
+    };
+
+    let mut output = CommandOutput::new().text(
+        format!(
+            "🧩 Proposed manifest patch for {display} (provider: {}, model: {})",
+            report.provider, report.model
+        ),
+        OutputTone::Info,
+    );
+    if review.is_unchanged() {
+        output = output.text(
+            "The patch leaves the manifest unchanged.",
+            OutputTone::Warning,
+        );
+    } else {
+        output = output.diff(Some(display.clone()), review.diff(&display));
+    }
+    output.extend(manifest_validation_output(&path, &review.validation));
+    emit(output.text(
+        format!("Nothing was written; apply the diff to {display} to keep it."),
+        OutputTone::Plain,
+    ));
+
+    if review.validation.is_valid() {
+        ExitStatus::Success
+    } else {
+        ExitStatus::Error
+    }
+}
+
+pub(super) fn handle_ai_context_subcommand(args: &ParsedArguments<'_>) -> ExitStatus {
+    let prompt = args.text_from(0);
+    if prompt.is_empty() && ai_context_mode_from_env() == AiContextMode::Retrieval {
+        print_command_failure(
+            &CommandFailure::new("Missing prompt for /ai context.")
+                .with_hint("Usage: /ai context <prompt>"),
+        );
+        return ExitStatus::Error;
+    }
+
+    emit(ai_context_preview_output(&prompt));
+    ExitStatus::Success
+}
+
+/// Files, sections and system message `/ai context` would attach to `prompt`.
+fn ai_context_preview_output(prompt: &str) -> CommandOutput {
+    let output = CommandOutput::new().heading("🧭 AI Context Preview", OutputTone::Info);
+    let Some(selection) = select_ai_context(prompt) else {
+        return output.text(
+            "No workspace context would be attached to this prompt.",
+            OutputTone::Warning,
+        );
+    };
+    let bundle = &selection.bundle;
+    let budget = ai_context_budget_from_env();
+
+    let mut summary = vec![
+        ("mode".to_string(), bundle.mode.as_str().to_string()),
+        (
+            "files".to_string(),
+            format!("{}/{}", bundle.files.len(), budget.max_files),
+        ),
+        (
+            "bytes".to_string(),
+            format!(
+                "{}/{}{}",
+                bundle.total_bytes,
+                budget.max_total_bytes,
+                if bundle.truncated { " (truncated)" } else { "" }
+            ),
+        ),
+    ];
+    if let Some((files, chunks, stats)) = selection.index {
+        summary.push((
+            "index".to_string(),
+            format!(
+                "{files} files, {chunks} chunks ({} added, {} updated, {} removed)",
+                stats.added, stats.updated, stats.removed
+            ),
+        ));
+    }
+    let mut output = output.key_values(summary);
+
+    if !bundle.files.is_empty() {
+        let rows = bundle
+            .files
+            .iter()
+            .map(|file| {
+                let mut notes = Vec::new();
+                if file.truncated {
+                    notes.push("truncated".to_string());
+                }
+                if file.redactions > 0 {
+                    notes.push(format!(
+                        "redactions:{} ({})",
+                        file.redactions,
+                        file.detectors.join(", ")
+                    ));
+                }
+                vec![
+                    file.relative_path.display().to_string(),
+                    format!("{}/{}", file.included_bytes, file.original_bytes),
+                    notes.join(" "),
+                ]
+            })
+            .collect();
+        output = output.table(["file", "bytes", "notes"], rows);
+    }
+
+    let sections: Vec<Vec<String>> = bundle
+        .files
+        .iter()
+        .flat_map(|file| {
+            file.sections.iter().map(move |section| {
+                vec![
+                    file.relative_path.display().to_string(),
+                    format!("{}-{}", section.start_line, section.end_line),
+                    section.symbol.clone().unwrap_or_else(|| "-".to_string()),
+                    format!("{:.2}", section.score),
+                ]
+            })
+        })
+        .collect();
+    if !sections.is_empty() {
+        output = output.table(["file", "lines", "symbol", "score"], sections);
+    }
+
+    if let Some(message) = render_context_system_message(bundle) {
+        output = output
+            .heading("System message sent with the prompt", OutputTone::Plain)
+            .markdown(message);
+    }
+    output
+}
+
+pub(super) fn handle_ai_resume_subcommand(args: &ParsedArguments<'_>) -> ExitStatus {
+    let Some(session_id) = args.positional(0) else {
+        emit(CommandOutput::new().text(
+            "Usage: /ai resume <session-id> (interactive picker available with `/ai resume` in CLI mode)",
+            OutputTone::Warning,
+        ));
+        return ExitStatus::Error;
+    };
+
+    let active_session = set_active_ai_session_id(session_id);
+    let output = match LocalAiSessionState::load_local_snapshot(&active_session) {
+        Ok(Some(session)) => CommandOutput::new()
+            .heading(
+                format!("✅ Active AI session resumed: {}", session.id),
+                OutputTone::Success,
+            )
+            .key_values([
+                ("exchanges", session.exchanges.len().to_string()),
+                ("last", session.last_activity_ms.to_string()),
+            ]),
+        Ok(None) => CommandOutput::new()
+            .heading(
+                format!("✅ Active AI session set: {active_session}"),
+                OutputTone::Success,
+            )
+            .text(
+                "No persisted history found for this id yet; a new local session snapshot will be created on next AI response.",
+                OutputTone::Warning,
+            ),
+        Err(err) => CommandOutput::new().text(
+            format!("⚠ Active AI session set, but failed to read local snapshot: {err}"),
+            OutputTone::Warning,
+        ),
+    };
+    emit(output);
+
+    let _ =
+        nettoolskit_ui::append_footer_log(&format!("ai: active session set id={active_session}"));
+    ExitStatus::Success
+}
+
+pub(super) fn ai_session_failure(message: impl Into<String>) -> ExitStatus {
+    print_command_failure(&CommandFailure::new(message).with_hint(
+        "Usage: /ai session list | show <id> | search <text> | export <id> [--format md|json] [--output <path>] | rename <id> <new-id> | delete <id>",
+    ));
+    ExitStatus::Error
+}
+
+/// Load a persisted session for `/ai session` and `/ai fork`, reporting failures.
+pub(super) fn load_ai_session_for_command(
+    session_id: &str,
+) -> Result<LocalAiSessionState, ExitStatus> {
+    match LocalAiSessionState::load_local_snapshot(session_id) {
+        Ok(Some(session)) => Ok(session),
+        Ok(None) => {
+            print_command_failure(
+                &CommandFailure::new(format!("AI session not found: {session_id}"))
+                    .with_hint("List saved sessions with /ai session list."),
+            );
+            Err(ExitStatus::Error)
+        }
+        Err(err) => {
+            print_command_failure(&CommandFailure::new(format!(
+                "Failed to read AI session {session_id}: {err}"
+            )));
+            Err(ExitStatus::Error)
+        }
+    }
+}
+
+pub(super) fn ai_session_list_output() -> Result<CommandOutput, String> {
+    let sessions = list_local_ai_session_snapshots(AI_SESSION_LIST_LIMIT)
+        .map_err(|err| format!("Failed to list AI sessions: {err}"))?
+        .unwrap_or_default();
+    if sessions.is_empty() {
+        return Ok(CommandOutput::new().text("No local AI sessions found.", OutputTone::Warning));
+    }
+
+    let active = active_ai_session_id();
+    let rows = sessions
+        .iter()
+        .map(|session| {
+            let mut id = session.id.clone();
+            if active.as_deref() == Some(session.id.as_str()) {
+                id.push_str(" (active)");
+            }
+            vec![
+                id,
+                session.exchange_count.to_string(),
+                format_epoch_ms_utc(session.started_at_ms),
+                format_epoch_ms_utc(session.last_activity_ms),
+            ]
+        })
+        .collect();
+    Ok(CommandOutput::new()
+        .heading("AI Sessions", OutputTone::Info)
+        .table(["id", "exchanges", "started", "last activity"], rows))
+}
+
+pub(super) fn ai_session_detail_output(session: &LocalAiSessionState) -> CommandOutput {
+    let mut details = vec![
+        ("id", session.id.clone()),
+        ("started", format_epoch_ms_utc(session.started_at_ms)),
+        (
+            "last activity",
+            format_epoch_ms_utc(session.last_activity_ms),
+        ),
+        ("exchanges", session.exchanges.len().to_string()),
+    ];
+    if let Some(origin) = &session.forked_from {
+        details.push((
+            "forked from",
+            format!(
+                "{} after exchange {}",
+                origin.session_id, origin.exchange_count
+            ),
+        ));
+    }
+    if let Some(summary) = &session.rolling_summary {
+        details.push((
+            "rolling summary",
+            format!(
+                "exchanges 1-{} by {}",
+                summary.covered_exchanges, summary.source
+            ),
+        ));
+    }
+
+    let rows = session
+        .exchanges
+        .iter()
+        .enumerate()
+        .map(|(index, exchange)| {
+            vec![
+                (index + 1).to_string(),
+                exchange.intent.clone(),
+                exchange.provider.clone(),
+                format_epoch_ms_utc(exchange.timestamp_ms),
+                truncate_text_chars(&exchange.user_prompt.replace('\n', " "), 60),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let output = CommandOutput::new()
+        .heading("AI Session", OutputTone::Info)
+        .key_values(details);
+    if rows.is_empty() {
+        output
+    } else {
+        output.table(["#", "intent", "provider", "time", "prompt"], rows)
+    }
+}
+
+pub(super) fn handle_ai_session_subcommand(parsed: &ParsedArguments<'_>) -> ExitStatus {
+    let action = parsed
+        .positionals
+        .first()
+        .map(|action| action.to_ascii_lowercase());
+    let session_arg = parsed.positionals.get(1).copied();
+
+    let output = match (action.as_deref(), session_arg) {
+        (None | Some("list"), _) => match ai_session_list_output() {
+            Ok(output) => output,
+            Err(message) => {
+                print_command_failure(&CommandFailure::new(message));
+                return ExitStatus::Error;
+            }
+        },
+        (Some("search"), _) => {
+            let query = parsed.text_from(1);
+            if query.trim().is_empty() {
+                return ai_session_failure("Missing search text for /ai session search.");
+            }
+            let hits = match LocalAiSessionState::search_local_snapshots(
+                &query,
+                AI_SESSION_SEARCH_LIMIT,
+            ) {
+                Ok(hits) => hits.unwrap_or_default(),
+                Err(err) => {
+                    print_command_failure(&CommandFailure::new(format!(
+                        "Failed to search AI sessions: {err}"
+                    )));
+                    return ExitStatus::Error;
+                }
+            };
+            if hits.is_empty() {
+                CommandOutput::new().text(
+                    format!("No AI session exchange matches `{query}`."),
+                    OutputTone::Warning,
+                )
+            } else {
+                let rows = hits
+                    .iter()
+                    .map(|hit| {
+                        vec![
+                            hit.session_id.clone(),
+                            hit.exchange.to_string(),
+                            hit.intent.clone(),
+                            hit.field.as_label().to_string(),
+                            hit.snippet.clone(),
+                        ]
+                    })
+                    .collect();
+                CommandOutput::new()
+                    .heading(format!("AI Session Search: {query}"), OutputTone::Info)
+                    .table(["session", "#", "intent", "field", "match"], rows)
+            }
+        }
+        (Some("show" | "export" | "rename" | "delete"), None) => {
+            return ai_session_failure(format!(
+                "Missing session id for /ai session {}.",
+                action.as_deref().unwrap_or_default()
+            ));
+        }
+        (Some("show"), Some(session_id)) => match load_ai_session_for_command(session_id) {
+            Ok(session) => ai_session_detail_output(&session),
+            Err(status) => return status,
+        },
+        (Some("export"), Some(session_id)) => {
+            let session = match load_ai_session_for_command(session_id) {
+                Ok(session) => session,
+                Err(status) => return status,
+            };
+            let format = parsed.flag_value("--format").unwrap_or("md");
+            let document = match format.to_ascii_lowercase().as_str() {
+                "md" | "markdown" => session.to_markdown(),
+                "json" => match session.to_json() {
+                    Ok(json) => json,
+                    Err(err) => {
+                        print_command_failure(&CommandFailure::new(format!(
+                            "Failed to serialize AI session {}: {err}",
+                            session.id
+                        )));
+                        return ExitStatus::Error;
+                    }
+                },
+                other => {
+                    return ai_session_failure(format!("Unsupported export format: {other}"));
+                }
+            };
+            match parsed.flag_value("--output") {
+                Some(path) => {
+                    if let Err(err) = fs::write(path, &document) {
+                        print_command_failure(&CommandFailure::new(format!(
+                            "Failed to write {path}: {err}"
+                        )));
+                        return ExitStatus::Error;
+                    }
+                    CommandOutput::new().text(
+                        format!("✓ Exported AI session {} to {path}", session.id),
+                        OutputTone::Success,
+                    )
+                }
+                None if format.eq_ignore_ascii_case("json") => {
+                    CommandOutput::new().text(document, OutputTone::Plain)
+                }
+                None => CommandOutput::new().markdown(document),
+            }
+        }
+        (Some("rename"), Some(session_id)) => {
+            let Some(new_id) = parsed.positionals.get(2).copied() else {
+                return ai_session_failure("Missing new id for /ai session rename.");
+            };
+            match LocalAiSessionState::rename_local_snapshot(session_id, new_id) {
+                Ok(Some(path)) => {
+                    let renamed = LocalAiSessionState::load_local_snapshot_from_path(&path)
+                        .map(|session| session.id)
+                        .unwrap_or_else(|_| new_id.to_string());
+                    if active_ai_session_id().as_deref() == Some(session_id) {
+                        let _ = set_active_ai_session_id(&renamed);
+                    }
+                    CommandOutput::new().text(
+                        format!("✓ Renamed AI session {session_id} to {renamed}"),
+                        OutputTone::Success,
+                    )
+                }
+                Ok(None) => {
+                    print_command_failure(&CommandFailure::new(
+                        "No local data directory is available for AI sessions.",
+                    ));
+                    return ExitStatus::Error;
+                }
+                Err(err) => {
+                    print_command_failure(&CommandFailure::new(format!(
+                        "Failed to rename AI session {session_id}: {err}"
+                    )));
+                    return ExitStatus::Error;
+                }
+            }
+        }
+        (Some("delete"), Some(session_id)) => {
+            match LocalAiSessionState::delete_local_snapshot(session_id) {
+                Ok(Some(true)) => CommandOutput::new().text(
+                    format!("✓ Deleted AI session {session_id}"),
+                    OutputTone::Success,
+                ),
+                Ok(Some(false) | None) => {
+                    print_command_failure(
+                        &CommandFailure::new(format!("AI session not found: {session_id}"))
+                            .with_hint("List saved sessions with /ai session list."),
+                    );
+                    return ExitStatus::Error;
+                }
+                Err(err) => {
+                    print_command_failure(&CommandFailure::new(format!(
+                        "Failed to delete AI session {session_id}: {err}"
+                    )));
+                    return ExitStatus::Error;
+                }
+            }
+        }
+        (Some(other), _) => {
+            return ai_session_failure(format!("Unknown /ai session action: {other}"));
+        }
+    };
+
+    emit(output);
+    ExitStatus::Success
+}
+
+pub(super) fn handle_ai_fork_subcommand(parsed: &ParsedArguments<'_>) -> ExitStatus {
+    let Some(session_id) = parsed.positionals.first().copied() else {
+        print_command_failure(
+            &CommandFailure::new("Missing session id for /ai fork.")
+                .with_hint("Usage: /ai fork <session-id> [--at <n>]"),
+        );
+        return ExitStatus::Error;
+    };
+    let at = match parsed.flag_value("--at") {
+        Some(value) => match parse_nonzero_usize(value) {
+            Some(at) => Some(at),
+            None => {
+                print_command_failure(
+                    &CommandFailure::new(format!("Invalid --at value: {value}"))
+                        .with_hint("--at takes a 1-based exchange number."),
+                );
+                return ExitStatus::Error;
+            }
+        },
+        None => None,
+    };
+
+    let source = match load_ai_session_for_command(session_id) {
+        Ok(session) => session,
+        Err(status) => return status,
+    };
+    let fork = match source.fork(&next_correlation_id("ai"), at) {
+        Ok(fork) => fork,
+        Err(message) => {
+            print_command_failure(&CommandFailure::new(format!("Cannot fork: {message}")));
+            return ExitStatus::Error;
+        }
+    };
+    if let Err(err) = fork.save_local_snapshot() {
+        print_command_failure(&CommandFailure::new(format!(
+            "Failed to save forked AI session: {err}"
+        )));
+        return ExitStatus::Error;
+    }
+
+    let active = set_active_ai_session_id(&fork.id);
+    let _ =
+        nettoolskit_ui::append_footer_log(&format!("ai: forked session {} -> {active}", source.id));
+    emit(
+        CommandOutput::new()
+            .text(
+                format!(
+                    "✓ Forked AI session {} after exchange {} into {active}",
+                    source.id,
+                    fork.exchanges.len()
+                ),
+                OutputTone::Success,
+            )
+            .text(
+                "The fork is now the active session; the original is unchanged.",
+                OutputTone::Plain,
+            ),
+    );
+    ExitStatus::Success
+}
+
+pub(super) fn ai_usage_failure(message: impl Into<String>) -> ExitStatus {
+    print_command_failure(&CommandFailure::new(message).with_hint(format!(
+        "Usage: /ai usage [--since 24h|7d|4w] [--by {}]",
+        AiSpendGrouping::LABELS.join("|")
+    )));
+    ExitStatus::Error
+}
+
+/// Parse a `--since` window (`24h`, `7d`, `4w`; bare numbers are days) into milliseconds.
+pub(super) fn parse_ai_usage_window_ms(value: &str) -> Option<u64> {
+    let value = value.trim().to_ascii_lowercase();
+    let (amount, unit_ms) = match value.char_indices().last()? {
+        (index, 'h') => (&value[..index], 3_600_000),
+        (index, 'd') => (&value[..index], 86_400_000),
+        (index, 'w') => (&value[..index], 7 * 86_400_000),
+        _ => (value.as_str(), 86_400_000),
+    };
+    parse_nonzero_u64(amount).map(|amount| amount.saturating_mul(unit_ms))
+}
+
+pub(super) fn handle_ai_usage_subcommand(parsed: &ParsedArguments<'_>) -> ExitStatus {
+    let since = parsed
+        .flag_value("--since")
+        .unwrap_or(AI_USAGE_DEFAULT_SINCE);
+    let Some(window_ms) = parse_ai_usage_window_ms(since) else {
+        return ai_usage_failure(format!("Invalid --since value: {since}"));
+    };
+    let grouping = match parsed.flag_value("--by") {
+        Some(value) => match AiSpendGrouping::parse(value) {
+            Some(grouping) => grouping,
+            None => return ai_usage_failure(format!("Invalid --by value: {value}")),
+        },
+        None => AiSpendGrouping::default(),
+    };
+    let Some(ledger) = ai_spend_ledger_from_env() else {
+        print_command_failure(&CommandFailure::new(
+            "No local data directory is available for the AI spend ledger.",
+        ));
+        return ExitStatus::Error;
+    };
+
+    let now = current_unix_timestamp_ms();
+    let loaded = ledger
+        .load_since(now.saturating_sub(window_ms))
+        .and_then(|entries| Ok((entries, ledger.totals_at(now)?)));
+    let (entries, totals) = match loaded {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            print_command_failure(&CommandFailure::new(format!(
+                "Failed to read AI spend ledger {}: {err}",
+                ledger.path().display()
+            )));
+            return ExitStatus::Error;
+        }
+    };
+
+    let caps = ai_spend_caps_from_env();
+    let against_cap = |spent: f64, cap: Option<f64>| match cap {
+        Some(cap) => format!("${spent:.4} of ${cap:.4} cap"),
+        None => format!("${spent:.4} (no cap)"),
+    };
+    let total = total_ai_spend(&entries);
+    let mut output = CommandOutput::new()
+        .heading(
+            format!("AI Usage (last {since}, by {})", grouping.as_label()),
+            OutputTone::Info,
+        )
+        .key_values([
+            ("requests", total.requests.to_string()),
+            (
+                "tokens",
+                format!("{} in / {} out", total.input_tokens, total.output_tokens),
+            ),
+            ("cost", format!("${:.4}", total.cost_usd)),
+            (
+                "estimated",
+                format!("{} requests", total.estimated_requests),
+            ),
+            ("today", against_cap(totals.day_usd, caps.daily_usd)),
+            (
+                "this month",
+                against_cap(totals.month_usd, caps.monthly_usd),
+            ),
+            ("ledger", ledger.path().display().to_string()),
+        ]);
+    if entries.is_empty() {
+        output = output.text(
+            format!("No AI spend recorded in the last {since}."),
+            OutputTone::Warning,
+        );
+    } else {
+        let rows = summarize_ai_spend(&entries, grouping)
+            .into_iter()
+            .map(|bucket| {
+                vec![
+                    bucket.key,
+                    bucket.requests.to_string(),
+                    bucket.input_tokens.to_string(),
+                    bucket.output_tokens.to_string(),
+                    format!("${:.4}", bucket.cost_usd),
+                    bucket.estimated_requests.to_string(),
+                ]
+            })
+            .collect();
+        output = output.table(
+            [
+                grouping.as_label(),
+                "requests",
+                "input",
+                "output",
+                "cost",
+                "estimated",
+            ],
+            rows,
+        );
+    }
+
+    emit(output);
+    ExitStatus::Success
+}
+
+/// `/ai`: the command overview, or a usage error for unknown subcommands.
+pub(crate) fn ai_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        let Some(raw_subcommand) = ctx.subcommand else {
+            let output = CommandOutput::new().heading("🤖 AI Assistant Commands", OutputTone::Info);
+            emit(
+        with_subcommand_usage(output, "/ai")
+            .text(
+                "Use NTK_AI_PROVIDER=openai to enable live provider calls (defaults to mock).",
+                OutputTone::Warning,
+            )
+            .text(
+                "Operational controls: NTK_AI_PROVIDER_CHAIN/NTK_AI_FALLBACK_PROVIDER, NTK_AI_PROVIDER_PRIMARY_TIMEOUT_MS, NTK_AI_PROVIDER_SECONDARY_TIMEOUT_MS, NTK_AI_MAX_RETRIES, NTK_AI_REQUEST_TIMEOUT_MS, NTK_AI_RATE_LIMIT_REQUESTS, NTK_AI_RATE_LIMIT_WINDOW_SECONDS, NTK_AI_TOKEN_BUDGET_*, NTK_AI_COST_BUDGET_USD_PER_REQUEST, NTK_AI_PROMPT_COMPACTION_TIER, NTK_AI_CACHE_FIRST_ENABLED, NTK_AI_MODEL_SELECTION_*, NTK_AI_SESSION_COMPRESSION_*, NTK_AI_SLO_*, NTK_AI_PRICE_TABLE, NTK_AI_SPEND_CAP_DAILY_USD/NTK_AI_SPEND_CAP_MONTHLY_USD.",
+                OutputTone::Warning,
+            ),
+    );
+            return ExitStatus::Success;
+        };
+        print_command_failure(
+            &CommandFailure::new(format!("Unknown /ai subcommand: {raw_subcommand}"))
+                .with_hint(format!("Valid subcommands: {}", subcommand_names("/ai"))),
+        );
+        ExitStatus::Error
+    })
+}
+
+/// `/ai context <prompt>`.
+pub(crate) fn ai_context_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move { handle_ai_context_subcommand(&ctx.args()) })
+}
+
+/// `/ai resume <session-id>`.
+pub(crate) fn ai_resume_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move { handle_ai_resume_subcommand(&ctx.args()) })
+}
+
+/// `/ai session <action> [session-id]`.
+pub(crate) fn ai_session_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move { handle_ai_session_subcommand(&ctx.args()) })
+}
+
+/// `/ai fork <session-id>`.
+pub(crate) fn ai_fork_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move { handle_ai_fork_subcommand(&ctx.args()) })
+}
+
+/// `/ai usage [--since <window>] [--by <group>]`.
+pub(crate) fn ai_usage_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move { handle_ai_usage_subcommand(&ctx.args()) })
+}
+
+/// `/ai manifest <instruction>`.
+pub(crate) fn ai_manifest_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move { handle_ai_manifest_subcommand(&ctx.args()).await })
+}
+
+/// `/ai ask|plan|explain|apply <prompt>`.
+pub(crate) fn ai_prompt_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        let Some(intent) = ctx.subcommand.and_then(AiIntent::from_subcommand) else {
+            return ai_command(ctx).await;
+        };
+        run_ai_prompt(&ctx.args(), intent).await
+    })
+}
+
+async fn run_ai_prompt(args: &ParsedArguments<'_>, intent: AiIntent) -> ExitStatus {
+    let dry_run = args.has_flag("--dry-run");
+    let explicit_write_approval = args.has_flag("--approve-write");
+
+    let prompt = args.text_from(0);
+    if prompt.is_empty() {
+        let usage = if matches!(intent, AiIntent::ApplyDryRun) {
+            "Usage: /ai apply --dry-run <instruction> | /ai apply --approve-write <instruction>"
+                .to_string()
+        } else {
+            format!("Usage: /ai {} <prompt>", intent.as_label())
+        };
+
+        print_command_failure(
+            &CommandFailure::new(format!("Missing prompt for /ai {}.", intent.as_label()))
+                .with_hint(usage),
+        );
+        return ExitStatus::Error;
+    }
+
+    // Streaming only makes sense on a terminal; captured runs get the full report instead.
+    let console = if is_capturing_output() {
+        ConsoleMode::Silent
+    } else {
+        ConsoleMode::Text
+    };
+    match run_ai_request(intent, &prompt, dry_run, explicit_write_approval, console).await {
+        Ok(report) => {
+            if !console.is_text() {
+                emit(report.to_output());
+            }
+            ExitStatus::Success
+        }
+        Err(failure) => {
+            print_command_failure(&failure);
+            ExitStatus::Error
+        }
+    }
+}
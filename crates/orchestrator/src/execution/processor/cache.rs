@@ -0,0 +1,91 @@
+//! Handlers for `/cache` subcommands.
+
+use super::*;
+
+pub(super) fn command_cache_stats_output(cache: &CommandResultCache) -> CommandOutput {
+    let stats = cache.stats();
+    let output = CommandOutput::new()
+        .heading("Command Cache", OutputTone::Info)
+        .key_values([
+            ("memory entries", stats.entries.to_string()),
+            ("memory bytes", stats.size_bytes.to_string()),
+            ("hits", stats.hits.to_string()),
+            ("misses", stats.misses.to_string()),
+            ("evictions", stats.evictions.to_string()),
+        ]);
+
+    let Some(tier) = cache.persistent_tier() else {
+        return output.text(
+            "Persistent tier disabled (set NTK_CACHE_PERSISTENT=true or [cache] persistent = true).",
+            OutputTone::Plain,
+        );
+    };
+    let disk = tier.stats();
+    output
+        .heading("Persistent Tier", OutputTone::Info)
+        .key_values([
+            ("directory", tier.dir().display().to_string()),
+            ("entries", disk.entries.to_string()),
+            (
+                "bytes",
+                format!("{} / {}", disk.size_bytes, disk.max_size_bytes),
+            ),
+            ("hits", disk.hits.to_string()),
+            ("misses", disk.misses.to_string()),
+            ("evictions", disk.evictions.to_string()),
+            ("invalidations", disk.invalidations.to_string()),
+            ("write errors", disk.write_errors.to_string()),
+        ])
+}
+
+/// `/cache`: statistics, or a usage error for unknown actions.
+pub(crate) fn cache_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        match ctx.subcommand {
+            None => emit_command_cache_stats(),
+            Some(other) => {
+                print_command_failure(
+                    &CommandFailure::new(format!("Unknown /cache action `{other}`"))
+                        .with_hint("Usage: /cache stats | clear"),
+                );
+                ExitStatus::Error
+            }
+        }
+    })
+}
+
+/// `/cache stats`.
+pub(crate) fn cache_stats_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async { emit_command_cache_stats() })
+}
+
+/// `/cache clear`: empty both cache tiers.
+pub(crate) fn cache_clear_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async {
+        match with_command_cache(CommandResultCache::clear) {
+            Ok(summary) => {
+                emit(CommandOutput::new().text(
+                    format!(
+                        "Cleared {} in-memory and {} persistent cache entries.",
+                        summary.memory_entries, summary.persistent_entries
+                    ),
+                    OutputTone::Success,
+                ));
+                ExitStatus::Success
+            }
+            Err(err) => {
+                print_command_failure(&CommandFailure::new(format!(
+                    "Failed to clear the persistent cache: {err}"
+                )));
+                ExitStatus::Error
+            }
+        }
+    })
+}
+
+fn emit_command_cache_stats() -> ExitStatus {
+    emit(with_command_cache(|cache| {
+        command_cache_stats_output(cache)
+    }));
+    ExitStatus::Success
+}
@@ -0,0 +1,790 @@
+//! Handlers for `/config` subcommands and configuration key editing.
+
+use super::*;
+
+/// `/config`: the effective configuration, `help`, or a usage error.
+pub(crate) fn config_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        match ctx.subcommand {
+            None => config_show_command(ctx).await,
+            Some(subcommand) if subcommand.eq_ignore_ascii_case("help") => {
+                emit(config_usage_output());
+                ExitStatus::Success
+            }
+            Some(_) => {
+                let mut output = CommandFailure::new("Unknown /config subcommand").to_output();
+                output.extend(config_usage_output());
+                emit(output);
+                ExitStatus::Error
+            }
+        }
+    })
+}
+
+/// `/config show [--origin]`.
+pub(crate) fn config_show_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        let Some(config_path) = config_path_or_fail() else {
+            return ExitStatus::Error;
+        };
+        run_config_show(&ctx.args(), &config_path)
+    })
+}
+
+fn run_config_show(args: &ParsedArguments<'_>, config_path: &Path) -> ExitStatus {
+    emit(effective_config_output(
+        config_path,
+        args.has_flag("--origin"),
+    ));
+    ExitStatus::Success
+}
+
+/// `/config path`.
+pub(crate) fn config_path_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async {
+        let Some(config_path) = config_path_or_fail() else {
+            return ExitStatus::Error;
+        };
+        run_config_path(&config_path)
+    })
+}
+
+fn run_config_path(config_path: &Path) -> ExitStatus {
+    let path_line = || config_path.display().to_string();
+    emit(
+        CommandOutput::new()
+            .heading("📍 Configuration Path", OutputTone::Info)
+            .key_values([
+                ("Path", path_line()),
+                (
+                    "Exists",
+                    if config_path.exists() { "yes" } else { "no" }.to_string(),
+                ),
+            ]),
+    );
+    ExitStatus::Success
+}
+
+/// `/config init`: write the defaults unless a file exists.
+pub(crate) fn config_init_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async {
+        let Some(config_path) = config_path_or_fail() else {
+            return ExitStatus::Error;
+        };
+        run_config_init(&config_path)
+    })
+}
+
+fn run_config_init(config_path: &Path) -> ExitStatus {
+    let path_line = || config_path.display().to_string();
+    if config_path.exists() {
+        emit(
+            CommandOutput::new()
+                .text("Configuration file already exists.", OutputTone::Warning)
+                .text(path_line(), OutputTone::Plain),
+        );
+        return ExitStatus::Success;
+    }
+
+    let config = AppConfig::default();
+    match config.save_to(config_path) {
+        Ok(()) => {
+            apply_runtime_ui_config(&config);
+            emit(
+                CommandOutput::new()
+                    .text("✅ Configuration initialized", OutputTone::Success)
+                    .text(path_line(), OutputTone::Plain),
+            );
+            ExitStatus::Success
+        }
+        Err(err) => {
+            print_command_failure(&CommandFailure::new(format!(
+                "Failed to initialize config: {err}"
+            )));
+            ExitStatus::Error
+        }
+    }
+}
+
+/// `/config set <key> <value>`.
+pub(crate) fn config_set_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        let Some(config_path) = config_path_or_fail() else {
+            return ExitStatus::Error;
+        };
+        run_config_set(&ctx.args(), &config_path)
+    })
+}
+
+fn run_config_set(args: &ParsedArguments<'_>, config_path: &Path) -> ExitStatus {
+    let path_line = || config_path.display().to_string();
+    let (Some(key), Some(_)) = (args.positional(0), args.positional(1)) else {
+        emit(config_usage_output());
+        return ExitStatus::Error;
+    };
+    let value = args.text_from(1);
+    let mut config = load_persisted_or_default(config_path);
+
+    match set_config_value(&mut config, key, &value) {
+        Ok(()) => match config.save_to(config_path) {
+            Ok(()) => {
+                apply_runtime_ui_config(&config);
+                if is_ai_session_retention_key(key) {
+                    apply_ai_session_retention_policy(
+                        config.general.ai_session_retention,
+                        ConsoleMode::Text,
+                    );
+                }
+                emit(
+                    CommandOutput::new()
+                        .text(format!("✅ Updated {key}={value}"), OutputTone::Success)
+                        .text(path_line(), OutputTone::Plain),
+                );
+                ExitStatus::Success
+            }
+            Err(err) => {
+                print_command_failure(&CommandFailure::new(format!(
+                    "Failed to save config: {err}"
+                )));
+                ExitStatus::Error
+            }
+        },
+        Err(err) => {
+            let mut output =
+                CommandFailure::new(format!("Invalid config value: {err}")).to_output();
+            output.extend(supported_config_keys_output());
+            emit(output);
+            ExitStatus::Error
+        }
+    }
+}
+
+/// `/config unset <key>`.
+pub(crate) fn config_unset_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        let Some(config_path) = config_path_or_fail() else {
+            return ExitStatus::Error;
+        };
+        run_config_unset(&ctx.args(), &config_path)
+    })
+}
+
+fn run_config_unset(args: &ParsedArguments<'_>, config_path: &Path) -> ExitStatus {
+    let path_line = || config_path.display().to_string();
+    let Some(key) = args.positional(0) else {
+        emit(config_usage_output());
+        return ExitStatus::Error;
+    };
+    let mut config = load_persisted_or_default(config_path);
+
+    match unset_config_value(&mut config, key) {
+        Ok(()) => match config.save_to(config_path) {
+            Ok(()) => {
+                apply_runtime_ui_config(&config);
+                if is_ai_session_retention_key(key) {
+                    apply_ai_session_retention_policy(
+                        config.general.ai_session_retention,
+                        ConsoleMode::Text,
+                    );
+                }
+                emit(
+                    CommandOutput::new()
+                        .text(format!("✅ Reset {key}"), OutputTone::Success)
+                        .text(path_line(), OutputTone::Plain),
+                );
+                ExitStatus::Success
+            }
+            Err(err) => {
+                print_command_failure(&CommandFailure::new(format!(
+                    "Failed to save config: {err}"
+                )));
+                ExitStatus::Error
+            }
+        },
+        Err(err) => {
+            let mut output = CommandFailure::new(format!("Invalid config key: {err}")).to_output();
+            output.extend(supported_config_keys_output());
+            emit(output);
+            ExitStatus::Error
+        }
+    }
+}
+
+/// `/config reset`: overwrite the file with defaults.
+pub(crate) fn config_reset_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async {
+        let Some(config_path) = config_path_or_fail() else {
+            return ExitStatus::Error;
+        };
+        run_config_reset(&config_path)
+    })
+}
+
+fn run_config_reset(config_path: &Path) -> ExitStatus {
+    let path_line = || config_path.display().to_string();
+    let config = AppConfig::default();
+    match config.save_to(config_path) {
+        Ok(()) => {
+            apply_runtime_ui_config(&config);
+            apply_ai_session_retention_policy(
+                config.general.ai_session_retention,
+                ConsoleMode::Text,
+            );
+            emit(
+                CommandOutput::new()
+                    .text("✅ Configuration reset to defaults", OutputTone::Success)
+                    .text(path_line(), OutputTone::Plain),
+            );
+            ExitStatus::Success
+        }
+        Err(err) => {
+            print_command_failure(&CommandFailure::new(format!(
+                "Failed to reset config: {err}"
+            )));
+            ExitStatus::Error
+        }
+    }
+}
+
+fn config_path_or_fail() -> Option<PathBuf> {
+    let path = AppConfig::default_config_path();
+    if path.is_none() {
+        print_command_failure(&CommandFailure::new(
+            "Could not determine configuration path on this system.",
+        ));
+    }
+    path
+}
+
+pub(super) fn effective_config_output(config_path: &Path, show_origin: bool) -> CommandOutput {
+    let layers = ConfigLayers::discover();
+    let effective = layers.resolve();
+
+    let file_state = if config_path.exists() {
+        "exists"
+    } else {
+        "not found, using defaults/env"
+    };
+    let mut sources = vec![(
+        "File".to_string(),
+        format!("{} ({file_state})", config_path.display()),
+    )];
+    if let Some(project) = &layers.project {
+        let ignored = layers.ignored_project_keys();
+        let state = if ignored.is_empty() {
+            "applied".to_string()
+        } else {
+            format!("untrusted, ignoring {}", ignored.join(", "))
+        };
+        sources.push((
+            "Project".to_string(),
+            format!("{} ({state})", project.path.display()),
+        ));
+    }
+    let mut output = CommandOutput::new()
+        .heading("⚙️  NetToolsKit Configuration", OutputTone::Info)
+        .key_values(sources);
+
+    let entry = |section: &str, name: &str, value: &dyn std::fmt::Display| {
+        let value = if show_origin {
+            let key = format!("{section}.{name}");
+            let origin = match layers.origin(&key) {
+                ConfigOrigin::Env => {
+                    format!("[env: {}]", config_env_var(&key).unwrap_or("NO_COLOR"))
+                }
+                origin => format!("[{origin}]"),
+            };
+            format!("{value} {origin}")
+        } else {
+            value.to_string()
+        };
+        (name.to_string(), value)
+    };
+
+    let general = &effective.general;
+    output = output.heading("[general]", OutputTone::Plain).key_values([
+        entry("general", "verbose", &general.verbose),
+        entry("general", "log_level", &general.log_level),
+        entry("general", "footer_output", &general.footer_output),
+        entry("general", "runtime_mode", &general.runtime_mode),
+        entry("general", "attention_bell", &general.attention_bell),
+        entry(
+            "general",
+            "attention_desktop_notification",
+            &general.attention_desktop_notification,
+        ),
+        entry(
+            "general",
+            "attention_unfocused_only",
+            &general.attention_unfocused_only,
+        ),
+        entry("general", "predictive_input", &general.predictive_input),
+        entry(
+            "general",
+            "ai_session_retention",
+            &general.ai_session_retention,
+        ),
+        entry(
+            "general",
+            "service_endpoint",
+            &general.service_endpoint.as_deref().unwrap_or("(none)"),
+        ),
+    ]);
+    output = output.heading("[display]", OutputTone::Plain).key_values([
+        entry("display", "color", &effective.display.color),
+        entry("display", "unicode", &effective.display.unicode),
+    ]);
+    output = output
+        .heading("[templates]", OutputTone::Plain)
+        .key_values([entry(
+            "templates",
+            "directory",
+            &effective
+                .templates
+                .directory
+                .as_deref()
+                .unwrap_or("(default)"),
+        )]);
+    output = output
+        .heading("[shell]", OutputTone::Plain)
+        .key_values([entry(
+            "shell",
+            "default_shell",
+            &effective.shell.default_shell.as_deref().unwrap_or("(none)"),
+        )]);
+
+    let policy = PolicySource::from_config(&effective);
+    for section in POLICY_SECTIONS {
+        output = output.heading(format!("[{section}]"), OutputTone::Plain);
+        let mut entries = Vec::new();
+        for setting in POLICY_SETTINGS
+            .iter()
+            .filter(|setting| setting.section() == *section)
+        {
+            let Some(value) = policy_display_value(&policy, setting) else {
+                continue;
+            };
+            if show_origin {
+                entries.push(entry(section, setting.name(), &value));
+            } else if std::env::var_os(setting.env).is_some() {
+                entries.push((
+                    setting.name().to_string(),
+                    format!("{value} (from {})", setting.env),
+                ));
+            } else {
+                entries.push((setting.name().to_string(), value));
+            }
+        }
+        output = if entries.is_empty() {
+            output.text("(defaults)", OutputTone::Plain)
+        } else {
+            output.key_values(entries)
+        };
+    }
+
+    output.extend(config_usage_output());
+    output
+}
+
+/// Effective value of a policy setting (environment over `config`), with
+/// secrets masked.
+pub(super) fn effective_policy_value(
+    config: &AppConfig,
+    setting: &PolicySetting,
+) -> Option<String> {
+    policy_display_value(&PolicySource::from_config(config), setting)
+}
+
+pub(super) fn policy_display_value(
+    source: &PolicySource,
+    setting: &PolicySetting,
+) -> Option<String> {
+    let value = source.var(setting.env)?;
+    if setting.kind != PolicyValueKind::Secret {
+        return Some(value);
+    }
+    // References name where the secret lives and are safe to show.
+    Some(match SecretRef::parse(&value) {
+        Ok(reference) => reference.to_string(),
+        Err(_) => "********".to_string(),
+    })
+}
+
+pub(super) fn supported_config_keys_output() -> CommandOutput {
+    let mut keys: Vec<String> = CONFIG_KEYS.iter().map(|key| key.name.to_string()).collect();
+    for section in POLICY_SECTIONS {
+        let names = POLICY_SETTINGS
+            .iter()
+            .filter(|setting| setting.section() == *section)
+            .map(PolicySetting::name)
+            .collect::<Vec<_>>();
+        keys.push(format!("{section}.* {}", names.join(", ")));
+    }
+    CommandOutput::new()
+        .heading("Supported keys:", OutputTone::Plain)
+        .text(keys.join("\n"), OutputTone::Info)
+}
+
+pub(super) fn config_usage_output() -> CommandOutput {
+    let output = CommandOutput::new().heading("Usage:", OutputTone::Plain);
+    let mut output = with_subcommand_usage(output, "/config");
+    output.extend(supported_config_keys_output());
+    output
+}
+
+pub(in crate::execution) fn load_persisted_or_default(config_path: &Path) -> AppConfig {
+    if config_path.exists() {
+        AppConfig::load_from(config_path).unwrap_or_default()
+    } else {
+        AppConfig::default()
+    }
+}
+
+pub(in crate::execution) fn apply_runtime_ui_config(config: &AppConfig) {
+    nettoolskit_ui::set_footer_output_enabled(config.general.footer_output);
+}
+
+pub(in crate::execution) fn is_ai_session_retention_key(key: &str) -> bool {
+    matches!(
+        key.trim().to_ascii_lowercase().as_str(),
+        "ai_session_retention"
+            | "ai-session-retention"
+            | "general.ai_session_retention"
+            | "general.ai-session-retention"
+    )
+}
+
+pub(in crate::execution) fn apply_ai_session_retention_policy(
+    retention: usize,
+    console: ConsoleMode,
+) {
+    let keep_latest = retention.max(1);
+    match prune_local_ai_session_snapshots(keep_latest) {
+        Ok(Some(removed)) if removed > 0 && console.is_text() => {
+            emit(CommandOutput::new().text(
+                format!("Pruned {removed} old local AI session snapshot(s)"),
+                OutputTone::Warning,
+            ));
+        }
+        Ok(Some(_)) | Ok(None) => {}
+        Err(err) => {
+            warn!(error = %err, "Failed to apply AI session retention policy");
+            if !console.is_text() {
+                return;
+            }
+            emit(CommandOutput::new().text(
+                format!("Warning: failed to prune local AI sessions: {err}"),
+                OutputTone::Warning,
+            ));
+        }
+    }
+}
+
+pub(in crate::execution) fn set_config_value(
+    config: &mut AppConfig,
+    key: &str,
+    value: &str,
+) -> Result<(), String> {
+    let normalized = key.trim().to_ascii_lowercase();
+    match normalized.as_str() {
+        "verbose" | "general.verbose" => {
+            let parsed = parse_bool(value).ok_or_else(|| {
+                "verbose must be one of: true, false, 1, 0, yes, no, on, off".to_string()
+            })?;
+            config.general.verbose = parsed;
+            Ok(())
+        }
+        "log_level" | "log-level" | "general.log_level" | "general.log-level" => {
+            config.general.log_level = parse_log_level(value)?;
+            Ok(())
+        }
+        "footer_output" | "footer-output" | "general.footer_output" | "general.footer-output" => {
+            let parsed = parse_bool(value).ok_or_else(|| {
+                "footer_output must be one of: true, false, 1, 0, yes, no, on, off".to_string()
+            })?;
+            config.general.footer_output = parsed;
+            Ok(())
+        }
+        "runtime_mode" | "runtime-mode" | "general.runtime_mode" | "general.runtime-mode" => {
+            let parsed = parse_runtime_mode(value)?;
+            config.general.runtime_mode = parsed;
+            Ok(())
+        }
+        "attention_bell"
+        | "attention-bell"
+        | "general.attention_bell"
+        | "general.attention-bell" => {
+            let parsed = parse_bool(value).ok_or_else(|| {
+                "attention_bell must be one of: true, false, 1, 0, yes, no, on, off".to_string()
+            })?;
+            config.general.attention_bell = parsed;
+            Ok(())
+        }
+        "attention_desktop_notification"
+        | "attention-desktop-notification"
+        | "general.attention_desktop_notification"
+        | "general.attention-desktop-notification" => {
+            let parsed = parse_bool(value).ok_or_else(|| {
+                "attention_desktop_notification must be one of: true, false, 1, 0, yes, no, on, off"
+                    .to_string()
+            })?;
+            config.general.attention_desktop_notification = parsed;
+            Ok(())
+        }
+        "attention_unfocused_only"
+        | "attention-unfocused-only"
+        | "general.attention_unfocused_only"
+        | "general.attention-unfocused-only" => {
+            let parsed = parse_bool(value).ok_or_else(|| {
+                "attention_unfocused_only must be one of: true, false, 1, 0, yes, no, on, off"
+                    .to_string()
+            })?;
+            config.general.attention_unfocused_only = parsed;
+            Ok(())
+        }
+        "predictive_input"
+        | "predictive-input"
+        | "general.predictive_input"
+        | "general.predictive-input" => {
+            let parsed = parse_bool(value).ok_or_else(|| {
+                "predictive_input must be one of: true, false, 1, 0, yes, no, on, off".to_string()
+            })?;
+            config.general.predictive_input = parsed;
+            Ok(())
+        }
+        "ai_session_retention"
+        | "ai-session-retention"
+        | "general.ai_session_retention"
+        | "general.ai-session-retention" => {
+            let parsed = parse_positive_usize(value).ok_or_else(|| {
+                "ai_session_retention must be a positive integer (>= 1)".to_string()
+            })?;
+            config.general.ai_session_retention = parsed;
+            Ok(())
+        }
+        "service_endpoint"
+        | "service-endpoint"
+        | "general.service_endpoint"
+        | "general.service-endpoint" => {
+            config.general.service_endpoint = Some(normalize_service_endpoint(value)?);
+            Ok(())
+        }
+        "color" | "display.color" => {
+            let parsed = parse_color_mode(value)?;
+            config.display.color = parsed;
+            Ok(())
+        }
+        "unicode" | "display.unicode" => {
+            let parsed = parse_unicode_mode(value)?;
+            config.display.unicode = parsed;
+            Ok(())
+        }
+        "template_dir" | "template-dir" | "templates.directory" => {
+            config.templates.directory = Some(value.trim().to_string());
+            Ok(())
+        }
+        "shell" | "default_shell" | "default-shell" | "shell.default_shell" => {
+            config.shell.default_shell = Some(value.trim().to_string());
+            Ok(())
+        }
+        _ => match policy_setting(key) {
+            Some(setting) => config.set_policy_value(setting, value),
+            None => Err(format!("unsupported key '{key}'")),
+        },
+    }
+}
+
+pub(in crate::execution) fn get_config_value(
+    config: &AppConfig,
+    key: &str,
+) -> Result<String, String> {
+    let normalized = key.trim().to_ascii_lowercase();
+    let value = match normalized.as_str() {
+        "verbose" | "general.verbose" => config.general.verbose.to_string(),
+        "log_level" | "log-level" | "general.log_level" | "general.log-level" => {
+            config.general.log_level.clone()
+        }
+        "footer_output" | "footer-output" | "general.footer_output" | "general.footer-output" => {
+            config.general.footer_output.to_string()
+        }
+        "runtime_mode" | "runtime-mode" | "general.runtime_mode" | "general.runtime-mode" => {
+            config.general.runtime_mode.to_string()
+        }
+        "attention_bell"
+        | "attention-bell"
+        | "general.attention_bell"
+        | "general.attention-bell" => config.general.attention_bell.to_string(),
+        "attention_desktop_notification"
+        | "attention-desktop-notification"
+        | "general.attention_desktop_notification"
+        | "general.attention-desktop-notification" => {
+            config.general.attention_desktop_notification.to_string()
+        }
+        "attention_unfocused_only"
+        | "attention-unfocused-only"
+        | "general.attention_unfocused_only"
+        | "general.attention-unfocused-only" => config.general.attention_unfocused_only.to_string(),
+        "predictive_input"
+        | "predictive-input"
+        | "general.predictive_input"
+        | "general.predictive-input" => config.general.predictive_input.to_string(),
+        "ai_session_retention"
+        | "ai-session-retention"
+        | "general.ai_session_retention"
+        | "general.ai-session-retention" => config.general.ai_session_retention.to_string(),
+        "service_endpoint"
+        | "service-endpoint"
+        | "general.service_endpoint"
+        | "general.service-endpoint" => config.general.service_endpoint.clone().unwrap_or_default(),
+        "color" | "display.color" => config.display.color.to_string(),
+        "unicode" | "display.unicode" => config.display.unicode.to_string(),
+        "template_dir" | "template-dir" | "templates.directory" => {
+            config.templates.directory.clone().unwrap_or_default()
+        }
+        "shell" | "default_shell" | "default-shell" | "shell.default_shell" => {
+            config.shell.default_shell.clone().unwrap_or_default()
+        }
+        _ => match policy_setting(key) {
+            Some(setting) => effective_policy_value(config, setting).unwrap_or_default(),
+            None => return Err(format!("unsupported key '{key}'")),
+        },
+    };
+    Ok(value)
+}
+
+pub(in crate::execution) fn unset_config_value(
+    config: &mut AppConfig,
+    key: &str,
+) -> Result<(), String> {
+    let normalized = key.trim().to_ascii_lowercase();
+    match normalized.as_str() {
+        "verbose" | "general.verbose" => {
+            config.general.verbose = false;
+            Ok(())
+        }
+        "log_level" | "log-level" | "general.log_level" | "general.log-level" => {
+            config.general.log_level = "info".to_string();
+            Ok(())
+        }
+        "footer_output" | "footer-output" | "general.footer_output" | "general.footer-output" => {
+            config.general.footer_output = true;
+            Ok(())
+        }
+        "runtime_mode" | "runtime-mode" | "general.runtime_mode" | "general.runtime-mode" => {
+            config.general.runtime_mode = AppConfig::default().general.runtime_mode;
+            Ok(())
+        }
+        "attention_bell"
+        | "attention-bell"
+        | "general.attention_bell"
+        | "general.attention-bell" => {
+            config.general.attention_bell = false;
+            Ok(())
+        }
+        "attention_desktop_notification"
+        | "attention-desktop-notification"
+        | "general.attention_desktop_notification"
+        | "general.attention-desktop-notification" => {
+            config.general.attention_desktop_notification = false;
+            Ok(())
+        }
+        "attention_unfocused_only"
+        | "attention-unfocused-only"
+        | "general.attention_unfocused_only"
+        | "general.attention-unfocused-only" => {
+            config.general.attention_unfocused_only = false;
+            Ok(())
+        }
+        "predictive_input"
+        | "predictive-input"
+        | "general.predictive_input"
+        | "general.predictive-input" => {
+            config.general.predictive_input = true;
+            Ok(())
+        }
+        "ai_session_retention"
+        | "ai-session-retention"
+        | "general.ai_session_retention"
+        | "general.ai-session-retention" => {
+            config.general.ai_session_retention = default_ai_session_retention();
+            Ok(())
+        }
+        "service_endpoint"
+        | "service-endpoint"
+        | "general.service_endpoint"
+        | "general.service-endpoint" => {
+            config.general.service_endpoint = None;
+            Ok(())
+        }
+        "color" | "display.color" => {
+            config.display.color = ColorMode::Auto;
+            Ok(())
+        }
+        "unicode" | "display.unicode" => {
+            config.display.unicode = UnicodeMode::Auto;
+            Ok(())
+        }
+        "template_dir" | "template-dir" | "templates.directory" => {
+            config.templates.directory = None;
+            Ok(())
+        }
+        "shell" | "default_shell" | "default-shell" | "shell.default_shell" => {
+            config.shell.default_shell = None;
+            Ok(())
+        }
+        _ => match policy_setting(key) {
+            Some(setting) => config.unset_policy_value(setting),
+            None => Err(format!("unsupported key '{key}'")),
+        },
+    }
+}
+
+pub(super) fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+pub(super) fn parse_positive_usize(value: &str) -> Option<usize> {
+    let parsed = value.trim().parse::<usize>().ok()?;
+    (parsed > 0).then_some(parsed)
+}
+
+pub(super) fn parse_log_level(value: &str) -> Result<String, String> {
+    let level = value.trim().to_ascii_lowercase();
+    if LOG_LEVEL_VALUES.contains(&level.as_str()) {
+        Ok(level)
+    } else {
+        Err(format!(
+            "log_level must be one of: {}",
+            LOG_LEVEL_VALUES.join(", ")
+        ))
+    }
+}
+
+pub(super) fn parse_runtime_mode(value: &str) -> Result<RuntimeMode, String> {
+    value
+        .trim()
+        .parse::<RuntimeMode>()
+        .map_err(|_| "runtime_mode must be one of: cli, service".to_string())
+}
+
+pub(super) fn parse_color_mode(value: &str) -> Result<ColorMode, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "auto" => Ok(ColorMode::Auto),
+        "always" => Ok(ColorMode::Always),
+        "never" => Ok(ColorMode::Never),
+        _ => Err("color must be one of: auto, always, never".to_string()),
+    }
+}
+
+pub(super) fn parse_unicode_mode(value: &str) -> Result<UnicodeMode, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "auto" => Ok(UnicodeMode::Auto),
+        "always" => Ok(UnicodeMode::Always),
+        "never" => Ok(UnicodeMode::Never),
+        _ => Err("unicode must be one of: auto, always, never".to_string()),
+    }
+}
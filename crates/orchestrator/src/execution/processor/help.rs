@@ -0,0 +1,72 @@
+//! Handlers for `/help`.
+
+use super::*;
+
+/// `/help`.
+pub(crate) fn help_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async { process_help_command(runtime_metrics()) })
+}
+
+/// Render `/help` from the command registry (plugin commands included).
+pub(super) fn process_help_command(metrics: &Metrics) -> ExitStatus {
+    let specs = registered_command_specs();
+    let help_key = CacheKey::help_for(specs.iter().map(|spec| spec.name));
+    let help_markdown = match with_command_cache(|cache| cache.get(&help_key)) {
+        Some(CacheValue::HelpMarkdown(markdown)) => {
+            metrics.increment_counter("runtime_command_cache_hits_total");
+            metrics.increment_counter("runtime_command_cache_help_hits_total");
+            markdown
+        }
+        _ => {
+            metrics.increment_counter("runtime_command_cache_misses_total");
+            metrics.increment_counter("runtime_command_cache_help_misses_total");
+
+            let generated = help_markdown(&specs);
+            let _ = with_command_cache(|cache| {
+                cache.insert(help_key, CacheValue::HelpMarkdown(generated.clone()))
+            });
+            generated
+        }
+    };
+
+    emit(CommandOutput::new().markdown(help_markdown));
+    ExitStatus::Success
+}
+
+pub(super) fn help_markdown(specs: &[&CommandSpec]) -> String {
+    let mut commands_section = String::new();
+    let mut usage_section = String::new();
+    for spec in specs {
+        commands_section.push_str(&format!("- `{}` - {}\n", spec.name, spec.description));
+        if spec.subcommands.is_empty() {
+            if !spec.args.is_empty() || !spec.flags.is_empty() {
+                usage_section.push_str(&format!("- `{}` - {}\n", spec.usage(), spec.description));
+            }
+            continue;
+        }
+        for subcommand in spec.subcommands {
+            usage_section.push_str(&format!(
+                "- `{}` - {}\n",
+                subcommand.usage(spec.name),
+                subcommand.description
+            ));
+        }
+    }
+
+    format!(
+        "\
+# NetToolsKit CLI - Help
+
+## Available Commands
+{commands_section}
+## Usage
+- Type `/` to open the command palette
+- Type a command directly (for example `/help`)
+- Use `↑↓` to navigate in the palette
+- Press `Enter` to select a command
+- Press `Tab` to complete subcommands, flags and arguments
+
+## Subcommands
+{usage_section}"
+    )
+}
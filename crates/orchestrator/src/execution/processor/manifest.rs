@@ -0,0 +1,668 @@
+//! Handlers for `/manifest` and the async manifest aliases.
+
+use super::*;
+
+pub(super) fn discover_manifest_files(root: &Path) -> Result<Vec<PathBuf>, String> {
+    let config = SearchConfig {
+        include_patterns: vec![
+            "*manifest*.yml".to_string(),
+            "*manifest*.yaml".to_string(),
+            "ntk-*.yml".to_string(),
+            "ntk-*.yaml".to_string(),
+        ],
+        exclude_patterns: vec!["**/target/**".to_string(), "**/node_modules/**".to_string()],
+        max_depth: Some(8),
+        follow_links: false,
+        include_hidden: false,
+    };
+
+    let mut manifests =
+        search_files(root, &config).map_err(|err| format!("manifest discovery failed: {err}"))?;
+    manifests.sort();
+    manifests.dedup();
+    Ok(manifests)
+}
+
+pub(super) fn relative_path_for_display(root: &Path, path: &Path) -> String {
+    match path.strip_prefix(root) {
+        Ok(relative) => relative.display().to_string(),
+        Err(_) => path.display().to_string(),
+    }
+}
+
+pub(super) fn manifest_validation_output(
+    path: &Path,
+    validation: &nettoolskit_manifest::handlers::ValidationResult,
+) -> CommandOutput {
+    let location = path.display().to_string();
+    let output = if validation.is_valid() {
+        CommandOutput::new().text(format!("✓ {location} is valid"), OutputTone::Success)
+    } else {
+        CommandOutput::new().text(
+            format!("✗ {location} has validation errors"),
+            OutputTone::Error,
+        )
+    };
+
+    let findings = validation
+        .errors
+        .iter()
+        .map(|error| ("error", error.line, &error.message))
+        .chain(
+            validation
+                .warnings
+                .iter()
+                .map(|warning| ("warning", warning.line, &warning.message)),
+        )
+        .map(|(level, line, message)| {
+            vec![
+                level.to_string(),
+                line.map(|line| line.to_string()).unwrap_or_default(),
+                message.clone(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let output = if findings.is_empty() {
+        output
+    } else {
+        output.table(["level", "line", "message"], findings)
+    };
+
+    output.text(
+        format!(
+            "errors: {}, warnings: {}",
+            validation.error_count(),
+            validation.warning_count()
+        ),
+        OutputTone::Plain,
+    )
+}
+
+/// Use `explicit` when given, otherwise discover a single manifest under the working directory.
+pub(super) fn resolve_manifest_target(
+    explicit: Option<PathBuf>,
+    action_label: &str,
+) -> Result<PathBuf, ExitStatus> {
+    if let Some(path) = explicit {
+        return Ok(path);
+    }
+
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let manifests = match discover_manifest_files(&cwd) {
+        Ok(found) => found,
+        Err(err) => {
+            emit(CommandOutput::new().error(err, Vec::new()));
+            return Err(ExitStatus::Error);
+        }
+    };
+
+    match manifests.len() {
+        0 => {
+            emit(CommandOutput::new().error(
+                format!("No manifest files found for {action_label}."),
+                vec![format!(
+                    "Provide an explicit path: /manifest {action_label} <manifest-file>"
+                )],
+            ));
+            Err(ExitStatus::Error)
+        }
+        1 => Ok(manifests[0].clone()),
+        _ => {
+            let candidates = manifests
+                .iter()
+                .map(|path| vec![relative_path_for_display(&cwd, path)])
+                .collect();
+            emit(
+                CommandOutput::new()
+                    .error(
+                        format!(
+                            "Multiple manifests detected ({}). Specify which one to use.",
+                            manifests.len()
+                        ),
+                        vec![format!(
+                            "Use: /manifest {action_label} <manifest-file> [--dry-run] [--output <dir>]"
+                        )],
+                    )
+                    .table(["manifest"], candidates),
+            );
+            Err(ExitStatus::Error)
+        }
+    }
+}
+
+/// Discover manifests under the working directory (`/manifest list`).
+pub(in crate::execution) fn manifest_list(metrics: &Metrics) -> ExitStatus {
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let header = CommandOutput::new()
+        .heading("📋 Manifest Discovery", OutputTone::Info)
+        .key_values([("root", cwd.display().to_string())]);
+
+    let cache_key = CacheKey::manifest_list(&cwd);
+    let discovery = match with_command_cache(|cache| cache.get(&cache_key)) {
+        Some(CacheValue::ManifestListEntries(entries)) => {
+            metrics.increment_counter("runtime_command_cache_hits_total");
+            metrics.increment_counter("runtime_command_cache_manifest_hits_total");
+            Ok(entries)
+        }
+        _ => {
+            metrics.increment_counter("runtime_command_cache_misses_total");
+            metrics.increment_counter("runtime_command_cache_manifest_misses_total");
+            let snapshot = DirectorySnapshot::capture(&cwd);
+            match discover_manifest_files(&cwd) {
+                Ok(found) => {
+                    let _ = with_command_cache(|cache| {
+                        cache.insert_manifest_listing(
+                            cache_key,
+                            CacheValue::ManifestListEntries(found.clone()),
+                            snapshot.as_ref(),
+                        )
+                    });
+                    Ok(found)
+                }
+                Err(err) => Err(err),
+            }
+        }
+    };
+
+    match discovery {
+        Ok(found) if found.is_empty() => {
+            emit(header.text("No manifest files found.", OutputTone::Warning));
+            ExitStatus::Error
+        }
+        Ok(found) => {
+            let rows = found
+                .iter()
+                .map(|path| vec![relative_path_for_display(&cwd, path)])
+                .collect();
+            emit(
+                header
+                    .text(
+                        format!("Found {} manifest file(s):", found.len()),
+                        OutputTone::Success,
+                    )
+                    .table(["manifest"], rows),
+            );
+            ExitStatus::Success
+        }
+        Err(err) => {
+            emit(header.error(format!("Discovery failed: {err}"), Vec::new()));
+            ExitStatus::Error
+        }
+    }
+}
+
+/// Validate a manifest or template file (`/manifest check`).
+pub(in crate::execution) async fn manifest_check(
+    path: Option<PathBuf>,
+    is_template: bool,
+) -> ExitStatus {
+    if is_template && path.is_none() {
+        emit(CommandOutput::new().error(
+            "Template validation requires a file path: /manifest check <file> --template",
+            Vec::new(),
+        ));
+        return ExitStatus::Error;
+    }
+
+    let target_path = match resolve_manifest_target(path, "check") {
+        Ok(target_path) => target_path,
+        Err(status) => return status,
+    };
+    let header = CommandOutput::new()
+        .heading("✅ Validating Manifest", OutputTone::Info)
+        .key_values([("target", target_path.display().to_string())]);
+
+    match nettoolskit_manifest::handlers::check::check_file(&target_path, is_template).await {
+        Ok(validation) => {
+            let mut output = header;
+            output.extend(manifest_validation_output(&target_path, &validation));
+            emit(output);
+            if validation.is_valid() {
+                ExitStatus::Success
+            } else {
+                ExitStatus::Error
+            }
+        }
+        Err(err) => {
+            emit(header.error(format!("Manifest validation failed: {err}"), Vec::new()));
+            ExitStatus::Error
+        }
+    }
+}
+
+/// Preview the files a manifest would generate (`/manifest render`); always a dry-run.
+pub(in crate::execution) async fn manifest_render(
+    path: Option<PathBuf>,
+    output_root: Option<PathBuf>,
+) -> ExitStatus {
+    let manifest_path = match resolve_manifest_target(path, "render") {
+        Ok(manifest_path) => manifest_path,
+        Err(status) => return status,
+    };
+    let output_root = output_root
+        .unwrap_or_else(|| std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")));
+
+    let header = CommandOutput::new()
+        .heading("🎨 Rendering Preview", OutputTone::Info)
+        .key_values([
+            ("manifest", manifest_path.display().to_string()),
+            ("output root", output_root.display().to_string()),
+        ])
+        .text("DRY-RUN mode enabled (preview only)", OutputTone::Warning);
+
+    let config = nettoolskit_manifest::ExecutionConfig {
+        manifest_path,
+        output_root,
+        dry_run: true,
+    };
+
+    let executor = nettoolskit_manifest::ManifestExecutor::new();
+    match executor.execute(config).await {
+        Ok(summary) => {
+            let mut output = header.text("✓ Render preview completed", OutputTone::Success);
+            output.extend(execution_summary_output(&summary));
+            emit(output);
+            ExitStatus::Success
+        }
+        Err(err) => {
+            emit(header.error(format!("Render preview failed: {err}"), Vec::new()));
+            ExitStatus::Error
+        }
+    }
+}
+
+/// Apply a manifest (`/manifest apply`); opens the interactive flow when no path is given.
+pub(in crate::execution) async fn manifest_apply(
+    path: Option<PathBuf>,
+    output_root: Option<PathBuf>,
+    dry_run: bool,
+) -> ExitStatus {
+    match path {
+        Some(path) => nettoolskit_manifest::execute_apply(path, output_root, dry_run).await,
+        None => {
+            info!("Manifest apply called without path; opening interactive apply flow");
+            nettoolskit_manifest::show_apply_menu().await
+        }
+    }
+}
+
+/// Draft a manifest from existing C# sources (`/manifest import`).
+pub(in crate::execution) fn manifest_import(
+    source_root: Option<PathBuf>,
+    output: Option<PathBuf>,
+) -> ExitStatus {
+    let source_root = source_root
+        .unwrap_or_else(|| std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")));
+    emit(CommandOutput::new().heading("📥 Importing Manifest", OutputTone::Info));
+    nettoolskit_manifest::execute_import(source_root, output)
+}
+
+pub(super) fn execution_summary_output(
+    summary: &nettoolskit_manifest::core::models::ExecutionSummary,
+) -> CommandOutput {
+    let rows = summary
+        .created
+        .iter()
+        .map(|path| {
+            vec![
+                "create".to_string(),
+                path.display().to_string(),
+                String::new(),
+            ]
+        })
+        .chain(summary.updated.iter().map(|path| {
+            vec![
+                "update".to_string(),
+                path.display().to_string(),
+                String::new(),
+            ]
+        }))
+        .chain(summary.skipped.iter().map(|(path, reason)| {
+            vec![
+                "skip".to_string(),
+                path.display().to_string(),
+                reason.clone(),
+            ]
+        }))
+        .collect::<Vec<_>>();
+
+    let mut output = CommandOutput::new();
+    if !rows.is_empty() {
+        output = output.table(["action", "path", "reason"], rows);
+    }
+    if !summary.notes.is_empty() {
+        output = output.text("Notes:", OutputTone::Info).text(
+            summary
+                .notes
+                .iter()
+                .map(|note| format!("  • {note}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            OutputTone::Plain,
+        );
+    }
+    output.text(
+        format!(
+            "Total artifacts: {} (create: {}, update: {}, skip: {})",
+            summary.created.len() + summary.updated.len(),
+            summary.created.len(),
+            summary.updated.len(),
+            summary.skipped.len()
+        ),
+        OutputTone::Plain,
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum AsyncManifestAlias {
+    New,
+    Render,
+    Apply,
+}
+
+pub(super) fn encode_exit_status(status: ExitStatus) -> &'static str {
+    match status {
+        ExitStatus::Success => "success",
+        ExitStatus::Error => "error",
+        ExitStatus::Interrupted => "interrupted",
+    }
+}
+
+pub(super) fn decode_exit_status(token: &str) -> ExitStatus {
+    match token {
+        "success" => ExitStatus::Success,
+        "interrupted" => ExitStatus::Interrupted,
+        _ => ExitStatus::Error,
+    }
+}
+
+pub(super) fn format_async_progress(operation: &str, progress: &CommandProgress) -> String {
+    let mut segments = vec![format!("{operation}: {}", progress.message)];
+
+    if let Some(percent) = progress.percent {
+        segments.push(format!("{percent}%"));
+    }
+
+    if let (Some(completed), Some(total)) = (progress.completed, progress.total) {
+        segments.push(format!("{completed}/{total}"));
+    }
+
+    segments.join(" | ")
+}
+
+pub(super) fn interrupt_requested(interrupted: Option<&AtomicBool>) -> bool {
+    interrupted.is_some_and(|flag| flag.load(Ordering::SeqCst))
+}
+
+pub(super) async fn cancel_async_alias_execution(
+    operation: &str,
+    executor: &mut AsyncCommandExecutor,
+) -> ExitStatus {
+    let _ = nettoolskit_ui::append_footer_log(&format!(
+        "{operation}: Ctrl+C detected, cancelling async operation"
+    ));
+    executor.cancel_all().await;
+    let _ = nettoolskit_ui::append_footer_log(&format!("{operation}: async operation cancelled"));
+    ExitStatus::Interrupted
+}
+
+pub(super) async fn run_async_alias_with_progress<F, Fut>(
+    operation: &str,
+    interrupted: Option<&AtomicBool>,
+    factory: F,
+) -> ExitStatus
+where
+    F: FnOnce(ProgressSender) -> Fut + Send + 'static,
+    Fut: Future<Output = ExitStatus> + Send + 'static,
+{
+    let mut executor = AsyncCommandExecutor::new();
+    let (handle, mut progress_rx) = executor.spawn_with_progress(move |progress_tx| async move {
+        let status = factory(progress_tx).await;
+        Ok(encode_exit_status(status).to_string())
+    });
+
+    if interrupt_requested(interrupted) {
+        return cancel_async_alias_execution(operation, &mut executor).await;
+    }
+
+    loop {
+        tokio::select! {
+            progress = progress_rx.recv() => {
+                match progress {
+                    Some(progress) => {
+                        let _ = nettoolskit_ui::append_footer_log(&format_async_progress(operation, &progress));
+                    }
+                    None => break,
+                }
+            }
+            _ = tokio::time::sleep(Duration::from_millis(25)), if interrupted.is_some() => {
+                if interrupt_requested(interrupted) {
+                    return cancel_async_alias_execution(operation, &mut executor).await;
+                }
+            }
+        }
+    }
+
+    if interrupt_requested(interrupted) {
+        return cancel_async_alias_execution(operation, &mut executor).await;
+    }
+
+    let status = match handle.wait().await {
+        Ok(Ok(token)) => decode_exit_status(&token),
+        Ok(Err(err)) => {
+            let _ = nettoolskit_ui::append_footer_log(&format!(
+                "{operation}: async worker error: {err}"
+            ));
+            ExitStatus::Error
+        }
+        Err(err) => {
+            let _ = nettoolskit_ui::append_footer_log(&format!(
+                "{operation}: async result channel failed: {err}"
+            ));
+            ExitStatus::Error
+        }
+    };
+
+    executor.wait_all().await;
+    status
+}
+
+pub(super) async fn process_async_manifest_alias(
+    alias: AsyncManifestAlias,
+    args: &ParsedArguments<'_>,
+    interrupted: Option<&AtomicBool>,
+) -> ExitStatus {
+    let output_override = output_root_arg(args);
+    let dry_run = args.has_flag("--dry-run");
+
+    match alias {
+        AsyncManifestAlias::Render => {
+            let manifest_path = match resolve_manifest_target(manifest_path_arg(args), "render") {
+                Ok(path) => path,
+                Err(status) => return status,
+            };
+            let output_root =
+                output_override.unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+
+            emit(
+                CommandOutput::new()
+                    .heading("🎨 Rendering Preview (Async)", OutputTone::Info)
+                    .key_values([
+                        ("Manifest", manifest_path.display().to_string()),
+                        ("Output root", output_root.display().to_string()),
+                    ])
+                    .text("DRY-RUN mode enabled (preview only)", OutputTone::Warning),
+            );
+
+            run_async_alias_with_progress(
+                "render-async",
+                interrupted,
+                move |progress_tx| async move {
+                    let _ = progress_tx
+                        .send(CommandProgress::percent("Preparing manifest context", 15));
+                    let _ = progress_tx.send(CommandProgress::steps("Render stages", 1, 3));
+
+                    let config = nettoolskit_manifest::ExecutionConfig {
+                        manifest_path,
+                        output_root,
+                        dry_run: true,
+                    };
+
+                    let _ = progress_tx.send(CommandProgress::percent("Rendering preview", 60));
+                    let _ = progress_tx.send(CommandProgress::steps("Render stages", 2, 3));
+
+                    let executor = nettoolskit_manifest::ManifestExecutor::new();
+                    match executor.execute(config).await {
+                        Ok(summary) => {
+                            let _ = progress_tx.send(CommandProgress::percent("Completed", 100));
+                            let _ = progress_tx.send(CommandProgress::steps("Render stages", 3, 3));
+                            let mut output = CommandOutput::new()
+                                .text("✓ Render preview completed", OutputTone::Success);
+                            output.extend(execution_summary_output(&summary));
+                            emit(output);
+                            ExitStatus::Success
+                        }
+                        Err(err) => {
+                            let _ = progress_tx.send(CommandProgress::percent("Failed", 100));
+                            emit(
+                                CommandOutput::new()
+                                    .error(format!("Render preview failed: {err}"), Vec::new()),
+                            );
+                            ExitStatus::Error
+                        }
+                    }
+                },
+            )
+            .await
+        }
+        AsyncManifestAlias::Apply | AsyncManifestAlias::New => {
+            let Some(manifest_path) = manifest_path_arg(args) else {
+                let command_name = if alias == AsyncManifestAlias::New {
+                    "/new-async"
+                } else {
+                    "/apply-async"
+                };
+                print_command_failure(
+                    &CommandFailure::new(format!("Missing manifest path for {command_name}"))
+                        .with_hint(format!(
+                            "Usage: {command_name} <manifest-file> [--dry-run] [--output <dir>]"
+                        )),
+                );
+                return ExitStatus::Error;
+            };
+
+            let operation = if alias == AsyncManifestAlias::New {
+                "new-async"
+            } else {
+                "apply-async"
+            };
+
+            run_async_alias_with_progress(operation, interrupted, move |progress_tx| async move {
+                let _ = progress_tx.send(CommandProgress::percent("Preparing apply plan", 20));
+                let _ = progress_tx.send(CommandProgress::steps("Apply stages", 1, 3));
+
+                let _ = progress_tx.send(CommandProgress::percent("Applying manifest", 65));
+                let _ = progress_tx.send(CommandProgress::steps("Apply stages", 2, 3));
+
+                let status =
+                    nettoolskit_manifest::execute_apply(manifest_path, output_override, dry_run)
+                        .await;
+
+                let completion = if matches!(status, ExitStatus::Success) {
+                    "Completed"
+                } else {
+                    "Finished with errors"
+                };
+                let _ = progress_tx.send(CommandProgress::percent(completion, 100));
+                let _ = progress_tx.send(CommandProgress::steps("Apply stages", 3, 3));
+                status
+            })
+            .await
+        }
+    }
+}
+
+/// `/manifest`: the interactive menu, or the subcommand listing.
+pub(crate) fn manifest_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        match ctx.subcommand {
+            None => {
+                // No subcommand provided - show interactive menu from manifest crate
+                info!("Opening manifest interactive menu (no subcommand)");
+                nettoolskit_manifest::show_menu().await
+            }
+            Some(_) => {
+                let output = CommandOutput::new()
+                    .heading("📋 Manifest Commands", OutputTone::Info)
+                    .text("Available subcommands:", OutputTone::Plain);
+                emit(with_subcommand_usage(output, "/manifest").text(
+                    "💡 Type a subcommand to continue or just type /manifest for interactive menu",
+                    OutputTone::Warning,
+                ));
+                ExitStatus::Success
+            }
+        }
+    })
+}
+
+/// `/manifest list`.
+pub(crate) fn manifest_list_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async { manifest_list(runtime_metrics()) })
+}
+
+/// `/manifest check <manifest> [--template]`.
+pub(crate) fn manifest_check_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    let args = ctx.args();
+    Box::pin(manifest_check(
+        manifest_path_arg(&args),
+        args.has_flag("--template"),
+    ))
+}
+
+/// `/manifest render <manifest> [--output <dir>]`.
+pub(crate) fn manifest_render_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    let args = ctx.args();
+    Box::pin(manifest_render(
+        manifest_path_arg(&args),
+        output_root_arg(&args),
+    ))
+}
+
+/// `/manifest apply <manifest> [--dry-run] [--output <dir>]`.
+pub(crate) fn manifest_apply_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    let args = ctx.args();
+    Box::pin(manifest_apply(
+        manifest_path_arg(&args),
+        output_root_arg(&args),
+        args.has_flag("--dry-run"),
+    ))
+}
+
+/// `/manifest import <dir> [--output <file>]`.
+pub(crate) fn manifest_import_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        let args = ctx.args();
+        manifest_import(manifest_path_arg(&args), output_root_arg(&args))
+    })
+}
+
+/// `/render-async` and `/manifest render-async`.
+pub(crate) fn render_async_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        process_async_manifest_alias(AsyncManifestAlias::Render, &ctx.args(), ctx.interrupted).await
+    })
+}
+
+/// `/apply-async` and `/manifest apply-async`.
+pub(crate) fn apply_async_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        process_async_manifest_alias(AsyncManifestAlias::Apply, &ctx.args(), ctx.interrupted).await
+    })
+}
+
+/// `/new-async` and `/manifest new-async`.
+pub(crate) fn new_async_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        process_async_manifest_alias(AsyncManifestAlias::New, &ctx.args(), ctx.interrupted).await
+    })
+}
@@ -0,0 +1,23 @@
+//! Handlers for `/clear` and `/quit`.
+
+use super::*;
+
+/// `/clear`: redraw the interactive terminal layout.
+pub(crate) fn clear_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async {
+        match nettoolskit_ui::reset_layout() {
+            Ok(()) => ExitStatus::Success,
+            Err(err) => {
+                print_command_failure(&CommandFailure::new(format!(
+                    "Failed to reset terminal layout: {err}"
+                )));
+                ExitStatus::Error
+            }
+        }
+    })
+}
+
+/// `/quit`; the CLI loop exits on its own.
+pub(crate) fn quit_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async { ExitStatus::Success })
+}
@@ -0,0 +1,891 @@
+//! Handlers for `/task` subcommands and the task submission path they drive.
+
+use super::*;
+
+pub(super) fn print_task_usage() {
+    let output = CommandOutput::new().heading("🗂️ Task Manager Commands", OutputTone::Info);
+    let intents = TASK_INTENTS
+        .iter()
+        .map(|intent| intent.label)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let policy_env = [
+        format!(
+            "{NTK_SERVICE_AUTOMATION_PROFILE_ENV}, {NTK_SERVICE_ALLOWED_INTENTS_ENV}, {NTK_SERVICE_SUBMIT_BUDGET_ENV}"
+        ),
+        format!("{NTK_TASK_APPROVAL_RULES_ENV}, {NTK_TASK_APPROVERS_ENV}"),
+        format!(
+            "{NTK_AI_TOKEN_BUDGET_INPUT_PER_REQUEST_ENV}, {NTK_AI_TOKEN_BUDGET_TOTAL_PER_REQUEST_ENV}, {NTK_AI_COST_BUDGET_USD_PER_REQUEST_ENV}"
+        ),
+        format!(
+            "{NTK_AI_MODEL_SELECTION_ENABLED_ENV}, {NTK_AI_MODEL_SELECTION_CHEAP_MODEL_ENV}, {NTK_AI_MODEL_SELECTION_REASONING_MODEL_ENV}, {NTK_AI_MODEL_SELECTION_CHEAP_COST_CAP_USD_ENV}, {NTK_AI_MODEL_SELECTION_REASONING_COST_CAP_USD_ENV}"
+        ),
+        format!(
+            "{NTK_AI_SESSION_COMPRESSION_MODE_ENV}, {NTK_AI_SESSION_COMPRESSION_MAX_CHARS_ENV}, {NTK_AI_SESSION_DELTA_MIN_SHARED_PREFIX_CHARS_ENV}"
+        ),
+        format!(
+            "{NTK_AI_SLO_MAX_P95_LATENCY_MS_ENV}, {NTK_AI_SLO_MIN_SUCCESS_RATE_PCT_ENV}, {NTK_AI_SLO_MAX_TOKENS_PER_TASK_ENV}, {NTK_AI_SLO_MAX_COST_USD_PER_TASK_ENV}"
+        ),
+        format!(
+            "{NTK_TOOL_SCOPE_ENABLED_ENV}, {NTK_TOOL_SCOPE_ALLOWED_TOOLS_ENV}, {}",
+            tool_scope_intent_env(TaskIntentKind::AiPlan)
+        ),
+    ];
+    emit(
+        with_subcommand_usage(output, "/task")
+            .heading("Supported intents:", OutputTone::Plain)
+            .text(intents, OutputTone::Info)
+            .heading("Service automation policy env:", OutputTone::Plain)
+            .text(policy_env.join("\n"), OutputTone::Info),
+    );
+}
+
+pub(super) fn task_list_output(title: &str, tasks: &[TaskSnapshot]) -> CommandOutput {
+    if tasks.is_empty() {
+        return CommandOutput::new().text("No task records found.", OutputTone::Warning);
+    }
+
+    let rows = tasks
+        .iter()
+        .map(|task| {
+            vec![
+                task.id.clone(),
+                task_status_label(task.status).to_string(),
+                task.intent.clone(),
+                task.runtime_mode.to_string(),
+                task.execution_target.clone(),
+                format!("{}/{}", task.attempts, task.max_attempts),
+            ]
+        })
+        .collect();
+    CommandOutput::new().heading(title, OutputTone::Info).table(
+        ["id", "status", "intent", "mode", "target", "attempts"],
+        rows,
+    )
+}
+
+/// Detail view of one task; `control` adds operator metadata known only locally.
+pub(super) fn task_detail_output(
+    title: &str,
+    task: &TaskSnapshot,
+    control: Option<&ControlEnvelope>,
+) -> CommandOutput {
+    let mut fields = vec![
+        ("id", task.id.clone()),
+        ("intent", task.intent.clone()),
+        ("payload", task.payload.clone()),
+        ("status", task_status_label(task.status).to_string()),
+        ("mode", task.runtime_mode.to_string()),
+        ("target", task.execution_target.clone()),
+        (
+            "attempts",
+            format!("{}/{}", task.attempts, task.max_attempts),
+        ),
+        ("detail", task.detail.clone()),
+        ("created_at_ms", task.created_at_unix_ms.to_string()),
+        ("updated_at_ms", task.updated_at_unix_ms.to_string()),
+    ];
+    if let Some(request_id) = &task.request_id {
+        fields.push(("request_id", request_id.clone()));
+    }
+    if let Some(correlation_id) = &task.correlation_id {
+        fields.push(("correlation_id", correlation_id.clone()));
+    }
+    if let Some(gate) = &task.approval_gate {
+        fields.push(("approval_gate", gate.clone()));
+    }
+    if let Some(control) = control {
+        fields.push(("operator_id", control.operator.id.clone()));
+        fields.push(("operator_kind", control.operator.kind.to_string()));
+        fields.push(("session_id", control.session.id.clone()));
+        fields.push(("transport", control.operator.transport.to_string()));
+    }
+
+    let mut output = CommandOutput::new()
+        .heading(title, OutputTone::Info)
+        .key_values(fields);
+    if !task.audit_events.is_empty() {
+        let recent = task
+            .audit_events
+            .iter()
+            .rev()
+            .take(5)
+            .rev()
+            .map(|event| {
+                vec![
+                    task_status_label(event.status).to_string(),
+                    event.message.clone(),
+                ]
+            })
+            .collect();
+        output = output
+            .text(
+                format!("audit_events: {}", task.audit_events.len()),
+                OutputTone::Plain,
+            )
+            .table(["status", "message"], recent);
+    }
+    if let Some(result) = &task.output {
+        output = output.heading("Result", OutputTone::Info);
+        output.extend(result.clone());
+    }
+    output
+}
+
+pub(super) async fn handle_task_submit(args: &ParsedArguments<'_>) -> ExitStatus {
+    let (Some(intent), Some(_)) = (args.positional(0), args.positional(1)) else {
+        emit(CommandOutput::new().text(
+            "Usage: /task submit <intent> <payload>",
+            OutputTone::Warning,
+        ));
+        return ExitStatus::Error;
+    };
+
+    let Some(intent_kind) = parse_task_intent_kind(intent) else {
+        print_command_failure(
+            &CommandFailure::new(format!("Unsupported task intent: {intent}"))
+                .with_hint(format!("Supported intents: {}", task_intent_labels())),
+        );
+        return ExitStatus::Error;
+    };
+
+    let payload = args.text_from(1);
+    if payload.trim().is_empty() {
+        print_command_failure(&CommandFailure::new("Task payload cannot be empty."));
+        return ExitStatus::Error;
+    }
+
+    let runtime_mode = AppConfig::load().general.runtime_mode;
+    let title = format!("{} task", task_intent_kind_label(intent_kind));
+    let control_envelope =
+        build_cli_task_control_envelope(intent_kind, title, payload.as_str(), runtime_mode);
+    process_control_envelope(control_envelope).await.exit_status
+}
+
+pub(super) async fn handle_task_list() -> ExitStatus {
+    match call_remote_task_service(ConsoleMode::Text, |client| async move {
+        client.list_tasks().await
+    })
+    .await
+    {
+        Some(Ok(tasks)) => {
+            emit(task_list_output("🗂️ Remote Task Records", &tasks));
+            ExitStatus::Success
+        }
+        Some(Err(error)) => {
+            print_remote_task_error(&error);
+            ExitStatus::Error
+        }
+        None => {
+            emit(task_list_output(
+                "🗂️ Local Task Records",
+                &list_task_snapshots(),
+            ));
+            ExitStatus::Success
+        }
+    }
+}
+
+pub(super) async fn handle_task_watch(args: &ParsedArguments<'_>) -> ExitStatus {
+    let Some(task_id) = args.positional(0) else {
+        emit(CommandOutput::new().text("Usage: /task watch <task-id>", OutputTone::Warning));
+        return ExitStatus::Error;
+    };
+    match call_remote_task_service(ConsoleMode::Text, |client| async move {
+        client.get_task(task_id).await
+    })
+    .await
+    {
+        Some(Ok(task)) => {
+            emit(task_detail_output("🔎 Remote Task Details", &task, None));
+            return ExitStatus::Success;
+        }
+        Some(Err(error)) => {
+            print_remote_task_error(&error);
+            return ExitStatus::Error;
+        }
+        None => {}
+    }
+
+    let record = with_task_registry(|registry| registry.get(task_id).cloned());
+    let Some(record) = record else {
+        emit(CommandOutput::new().error(format!("Task not found: {task_id}"), Vec::new()));
+        return ExitStatus::Error;
+    };
+
+    let snapshot = record.snapshot(list_task_audit_events(task_id));
+    emit(task_detail_output(
+        "🔎 Task Details",
+        &snapshot,
+        record.control_envelope.as_ref(),
+    ));
+    ExitStatus::Success
+}
+
+pub(super) async fn handle_task_cancel(args: &ParsedArguments<'_>) -> ExitStatus {
+    let Some(task_id) = args.positional(0) else {
+        emit(CommandOutput::new().text("Usage: /task cancel <task-id>", OutputTone::Warning));
+        return ExitStatus::Error;
+    };
+    let cancelled = match call_remote_task_service(ConsoleMode::Text, |client| async move {
+        client.cancel_task(task_id).await
+    })
+    .await
+    {
+        Some(Ok(task)) => Ok(task.id),
+        Some(Err(error)) => {
+            print_remote_task_error(&error);
+            return ExitStatus::Error;
+        }
+        None => cancel_task_record(task_id).map(|record| record.id),
+    };
+
+    match cancelled {
+        Ok(task_id) => {
+            emit(
+                CommandOutput::new()
+                    .text(format!("✅ Task cancelled: {task_id}"), OutputTone::Success),
+            );
+            ExitStatus::Success
+        }
+        Err(error) => {
+            emit(CommandOutput::new().error(error, Vec::new()));
+            ExitStatus::Error
+        }
+    }
+}
+
+pub(super) async fn handle_task_approvals() -> ExitStatus {
+    match call_remote_task_service(ConsoleMode::Text, |client| async move {
+        client.list_tasks().await
+    })
+    .await
+    {
+        Some(Ok(tasks)) => {
+            let pending: Vec<TaskSnapshot> = tasks
+                .into_iter()
+                .filter(|task| task.status == TaskExecutionStatus::AwaitingApproval)
+                .collect();
+            emit(task_list_output("⏸ Remote Pending Approvals", &pending));
+            ExitStatus::Success
+        }
+        Some(Err(error)) => {
+            print_remote_task_error(&error);
+            ExitStatus::Error
+        }
+        None => {
+            emit(task_list_output(
+                "⏸ Pending Approvals",
+                &list_pending_approval_snapshots(),
+            ));
+            ExitStatus::Success
+        }
+    }
+}
+
+pub(super) async fn handle_task_decision(
+    args: &ParsedArguments<'_>,
+    verdict: TaskApprovalVerdict,
+) -> ExitStatus {
+    let verb = match verdict {
+        TaskApprovalVerdict::Approve => "approve",
+        TaskApprovalVerdict::Reject => "reject",
+    };
+    let Some(task_id) = args.positional(0) else {
+        emit(CommandOutput::new().text(
+            format!("Usage: /task {verb} <task-id> [note]"),
+            OutputTone::Warning,
+        ));
+        return ExitStatus::Error;
+    };
+    let note = args.text_from(1);
+    let note = Some(note.as_str()).filter(|note| !note.trim().is_empty());
+    match call_remote_task_service(ConsoleMode::Text, |client| async move {
+        match verdict {
+            TaskApprovalVerdict::Approve => client.approve_task(task_id, note).await,
+            TaskApprovalVerdict::Reject => client.reject_task(task_id, note).await,
+        }
+    })
+    .await
+    {
+        Some(Ok(task)) => {
+            emit(task_decision_output(verdict, &task));
+            ExitStatus::Success
+        }
+        Some(Err(error)) => {
+            print_remote_task_error(&error);
+            ExitStatus::Error
+        }
+        None => process_task_approval_decision(task_id, verdict, note, &local_cli_operator()).await,
+    }
+}
+
+pub(super) fn task_decision_output(
+    verdict: TaskApprovalVerdict,
+    task: &TaskSnapshot,
+) -> CommandOutput {
+    let heading = match verdict {
+        TaskApprovalVerdict::Approve => format!("✅ Task approved: {}", task.id),
+        TaskApprovalVerdict::Reject => format!("🚫 Task rejected: {}", task.id),
+    };
+    CommandOutput::new()
+        .heading(heading, OutputTone::Success)
+        .key_values([
+            ("intent", task.intent.clone()),
+            ("status", task_status_label(task.status).to_string()),
+            ("detail", task.detail.clone()),
+        ])
+}
+
+/// `/task`: usage, `help`, or a usage error for unknown subcommands.
+pub(crate) fn task_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move {
+        match ctx.subcommand {
+            None => {
+                print_task_usage();
+                ExitStatus::Success
+            }
+            Some(subcommand) if subcommand.eq_ignore_ascii_case("help") => {
+                print_task_usage();
+                ExitStatus::Success
+            }
+            Some(subcommand) => {
+                emit(CommandOutput::new().error(
+                    format!("Unknown /task subcommand: {subcommand}"),
+                    Vec::new(),
+                ));
+                print_task_usage();
+                ExitStatus::Error
+            }
+        }
+    })
+}
+
+/// `/task submit <intent> <payload>`.
+pub(crate) fn task_submit_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move { handle_task_submit(&ctx.args()).await })
+}
+
+/// `/task list`.
+pub(crate) fn task_list_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(handle_task_list())
+}
+
+/// `/task watch <task-id>`.
+pub(crate) fn task_watch_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move { handle_task_watch(&ctx.args()).await })
+}
+
+/// `/task top`.
+pub(crate) fn task_top_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(run_task_dashboard())
+}
+
+/// `/task cancel <task-id>`.
+pub(crate) fn task_cancel_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move { handle_task_cancel(&ctx.args()).await })
+}
+
+/// `/task approvals`.
+pub(crate) fn task_approvals_command(_ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(handle_task_approvals())
+}
+
+/// `/task approve <task-id>`.
+pub(crate) fn task_approve_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move { handle_task_decision(&ctx.args(), TaskApprovalVerdict::Approve).await })
+}
+
+/// `/task reject <task-id>`.
+pub(crate) fn task_reject_command(ctx: CommandContext<'_>) -> CommandFuture<'_> {
+    Box::pin(async move { handle_task_decision(&ctx.args(), TaskApprovalVerdict::Reject).await })
+}
+
+pub(super) async fn submit_task_intent(
+    intent: TaskIntent,
+    runtime_mode: RuntimeMode,
+    control_envelope: Option<ControlEnvelope>,
+    console: ConsoleMode,
+) -> TaskSubmissionOutcome {
+    let metrics = runtime_metrics().clone();
+    let intent_kind = intent.kind;
+    let payload = intent.payload.trim().to_string();
+
+    if let Some(control) = &control_envelope {
+        metrics.increment_counter("runtime_control_envelope_submissions_total");
+        info!(
+            request_id = %control.request_id,
+            correlation_id = control.correlation_id.as_deref().unwrap_or(""),
+            operator_id = %control.operator.id,
+            session_id = %control.session.id,
+            task_intent = %task_intent_kind_label(control.task.kind),
+            "Processing typed control envelope submission"
+        );
+    }
+
+    let mut remote_failure = None;
+    if runtime_mode == RuntimeMode::Cli {
+        if let Some(client) = remote_task_client() {
+            match submit_task_remotely(&client, &intent, control_envelope.as_ref(), console).await {
+                Ok(outcome) => return outcome,
+                Err(error) if error.is_unreachable() => {
+                    runtime_metrics().increment_counter("runtime_remote_task_fallback_total");
+                    warn!(error = %error, "Remote task service unreachable; executing locally");
+                    remote_failure = Some(error);
+                }
+                Err(error) => {
+                    if console.is_text() {
+                        print_remote_task_error(&error);
+                    }
+                    return TaskSubmissionOutcome {
+                        remote_endpoint: Some(client.endpoint().to_string()),
+                        ..TaskSubmissionOutcome::rejected(
+                            runtime_mode,
+                            ExitStatus::Error,
+                            format!("Remote task request failed: {error}"),
+                        )
+                    };
+                }
+            }
+        }
+    }
+
+    if let Err(reason) = enforce_task_tool_scope(
+        runtime_mode,
+        intent_kind,
+        payload.as_str(),
+        "task_submit",
+        &metrics,
+    ) {
+        metrics.increment_counter("runtime_tool_scope_rejected_submit_total");
+        if console.is_text() {
+            print_command_failure(
+                &CommandFailure::new(format!(
+                    "Task rejected by secure tool-scope policy: {reason}"
+                ))
+                .with_hint(format!(
+                    "env: {NTK_TOOL_SCOPE_ENABLED_ENV} | {NTK_TOOL_SCOPE_ALLOWED_TOOLS_ENV} | {}",
+                    tool_scope_intent_env(intent_kind)
+                )),
+            );
+        }
+        return TaskSubmissionOutcome::rejected(
+            runtime_mode,
+            ExitStatus::Error,
+            format!("Task rejected by secure tool-scope policy: {reason}"),
+        );
+    }
+
+    if let Some(ai_intent) = ai_intent_from_task_intent(intent_kind) {
+        let token_policy = ai_token_economy_policy_from_env();
+        let model_selection_policy = ai_model_selection_policy_from_env();
+        if let Err(reason) = evaluate_ai_task_submit_budget(
+            ai_intent,
+            payload.as_str(),
+            token_policy,
+            &model_selection_policy,
+        ) {
+            if console.is_text() {
+                print_command_failure(
+                    &CommandFailure::new(format!(
+                        "Task rejected by AI budget/model policy: {reason}"
+                    ))
+                    .with_hint(format!(
+                        "env: {NTK_AI_TOKEN_BUDGET_INPUT_PER_REQUEST_ENV} | {NTK_AI_TOKEN_BUDGET_OUTPUT_PER_REQUEST_ENV} | {NTK_AI_TOKEN_BUDGET_TOTAL_PER_REQUEST_ENV} | {NTK_AI_COST_BUDGET_USD_PER_REQUEST_ENV} | {NTK_AI_MODEL_SELECTION_CHEAP_COST_CAP_USD_ENV} | {NTK_AI_MODEL_SELECTION_REASONING_COST_CAP_USD_ENV}"
+                    )),
+                );
+            }
+            return TaskSubmissionOutcome::rejected(
+                runtime_mode,
+                ExitStatus::Error,
+                format!("Task rejected by AI budget/model policy: {reason}"),
+            );
+        }
+    }
+
+    if runtime_mode == RuntimeMode::Service {
+        let policy = service_automation_policy_from_env();
+
+        if !policy.allows_intent(intent_kind) {
+            metrics.increment_counter("runtime_service_task_submit_policy_rejected_total");
+            if console.is_text() {
+                print_command_failure(
+                    &CommandFailure::new(format!(
+                        "Service automation policy rejected intent: {}",
+                        task_intent_kind_label(intent_kind)
+                    ))
+                    .with_hint(format!("profile: {}", policy.profile.as_str()))
+                    .with_hint(format!("allowed: {}", policy.allowed_intents_display()))
+                    .with_hint(format!(
+                        "env: {NTK_SERVICE_AUTOMATION_PROFILE_ENV} / {NTK_SERVICE_ALLOWED_INTENTS_ENV}"
+                    )),
+                );
+            }
+            return TaskSubmissionOutcome::rejected(
+                runtime_mode,
+                ExitStatus::Error,
+                format!(
+                    "Service automation policy rejected intent: {}",
+                    task_intent_kind_label(intent_kind)
+                ),
+            );
+        }
+
+        let payload_bytes = payload.len();
+        if payload_bytes > policy.max_payload_bytes {
+            metrics.increment_counter("runtime_service_task_submit_payload_rejected_total");
+            let budget = format!(
+                "{} bytes > {} bytes",
+                payload_bytes, policy.max_payload_bytes
+            );
+            if console.is_text() {
+                print_command_failure(
+                    &CommandFailure::new(format!(
+                        "Service payload exceeded automation policy budget: {budget}"
+                    ))
+                    .with_hint(format!("env: {NTK_SERVICE_MAX_PAYLOAD_BYTES_ENV}")),
+                );
+            }
+            return TaskSubmissionOutcome::rejected(
+                runtime_mode,
+                ExitStatus::Error,
+                format!("Service payload exceeded automation policy budget: {budget}"),
+            );
+        }
+
+        let inflight = service_inflight_task_count();
+        if inflight >= policy.max_inflight_tasks {
+            metrics.increment_counter("runtime_service_task_submit_inflight_rejected_total");
+            let budget = format!("{inflight}/{}", policy.max_inflight_tasks);
+            if console.is_text() {
+                print_command_failure(
+                    &CommandFailure::new(format!(
+                        "Service in-flight task budget reached: {budget}"
+                    ))
+                    .with_hint(format!("env: {NTK_SERVICE_MAX_INFLIGHT_TASKS_ENV}")),
+                );
+            }
+            return TaskSubmissionOutcome::rejected(
+                runtime_mode,
+                ExitStatus::Error,
+                format!("Service in-flight task budget reached: {budget}"),
+            );
+        }
+
+        match enforce_service_submission_budget(policy) {
+            Ok(used_submissions) => {
+                metrics.increment_counter("runtime_service_task_submit_accepted_total");
+                metrics.set_gauge(
+                    "runtime_service_task_submit_budget_used",
+                    used_submissions as f64,
+                );
+            }
+            Err(retry_after_seconds) => {
+                metrics.increment_counter("runtime_service_task_submit_budget_rejected_total");
+                metrics.set_gauge(
+                    "runtime_service_task_submit_budget_retry_after_seconds",
+                    retry_after_seconds as f64,
+                );
+                if console.is_text() {
+                    print_command_failure(
+                        &CommandFailure::new(format!(
+                            "Service submit budget exceeded. Retry in ~{retry_after_seconds}s."
+                        ))
+                        .with_hint(format!(
+                            "env: {NTK_SERVICE_SUBMIT_BUDGET_ENV} / {NTK_SERVICE_SUBMIT_WINDOW_SECONDS_ENV}"
+                        )),
+                    );
+                }
+                return TaskSubmissionOutcome::rejected(
+                    runtime_mode,
+                    ExitStatus::Error,
+                    format!("Service submit budget exceeded. Retry in ~{retry_after_seconds}s."),
+                );
+            }
+        }
+    }
+
+    let worker_policy = task_worker_policy_from_env();
+    let max_attempts = match runtime_mode {
+        RuntimeMode::Service => worker_policy.max_retries.saturating_add(1),
+        RuntimeMode::Cli => 1,
+    };
+    let task_id = next_task_id();
+    let now = current_unix_timestamp_ms();
+    let mut record = TaskRecord::new(
+        task_id.clone(),
+        intent.clone(),
+        runtime_mode,
+        max_attempts,
+        now,
+    );
+    if let Some(control) = control_envelope {
+        record = record.with_control_envelope(control);
+    }
+
+    with_task_registry(|registry| {
+        registry.insert(task_id.clone(), record);
+    });
+    append_task_audit_event(
+        &task_id,
+        runtime_mode,
+        TaskExecutionStatus::Queued,
+        format!(
+            "Task submitted (intent: {}, mode: {})",
+            task_intent_kind_label(intent_kind),
+            runtime_mode
+        ),
+    );
+
+    let (execution_target, fallback_reason) =
+        resolve_task_execution_target(runtime_mode, remote_failure.as_ref());
+    let _ = set_task_execution_target(&task_id, execution_target.clone());
+    if let Some(reason) = fallback_reason {
+        if console.is_text() {
+            emit(CommandOutput::new().text(format!("⚠ {reason}"), OutputTone::Warning));
+        }
+        let _ = nettoolskit_ui::append_footer_log(&format!("task: {reason}"));
+    }
+
+    let admitted = with_task_registry(|registry| registry.get(&task_id).cloned());
+    let Some(admitted) = admitted else {
+        return TaskSubmissionOutcome::rejected(
+            runtime_mode,
+            ExitStatus::Error,
+            format!("Task not found after admission: {task_id}"),
+        );
+    };
+    let approval_gate = TaskApprovalPolicy::from_env()
+        .gate_for(&admitted.intent, admitted.control_envelope.as_ref());
+    let final_record = match approval_gate {
+        Some(gate) => park_task_for_approval(admitted, &gate),
+        None => match dispatch_admitted_task(admitted, console).await {
+            Ok(record) => record,
+            Err(failed) => {
+                return TaskSubmissionOutcome::accepted(
+                    failed.id,
+                    task_status_to_exit_status(failed.status),
+                    failed.status,
+                    runtime_mode,
+                );
+            }
+        },
+    };
+
+    if console.is_text() {
+        // Streamed AI output and failures were already printed while the task ran.
+        emit(task_submitted_output(&final_record.summary_snapshot()));
+    } else if is_capturing_output() {
+        emit(task_submitted_output(&final_record.snapshot(Vec::new())));
+    }
+
+    TaskSubmissionOutcome::accepted(
+        final_record.id,
+        task_status_to_exit_status(final_record.status),
+        final_record.status,
+        runtime_mode,
+    )
+}
+
+fn task_submitted_output(task: &TaskSnapshot) -> CommandOutput {
+    if task.status == TaskExecutionStatus::AwaitingApproval {
+        return CommandOutput::new()
+            .heading("⏸ Task awaiting approval", OutputTone::Warning)
+            .key_values([
+                ("id", task.id.clone()),
+                ("intent", task.intent.clone()),
+                ("gate", task.approval_gate.clone().unwrap_or_default()),
+                ("detail", task.detail.clone()),
+            ])
+            .text(
+                format!(
+                    "Approve with `/task approve {0}` or reject with `/task reject {0}`.",
+                    task.id
+                ),
+                OutputTone::Info,
+            );
+    }
+
+    let output = CommandOutput::new()
+        .heading("✅ Task submitted", OutputTone::Success)
+        .key_values([
+            ("id", task.id.clone()),
+            ("intent", task.intent.clone()),
+            ("status", task_status_label(task.status).to_string()),
+            ("target", task.execution_target.clone()),
+            (
+                "attempts",
+                format!("{}/{}", task.attempts, task.max_attempts),
+            ),
+            ("detail", task.detail.clone()),
+        ]);
+    match &task.output {
+        Some(result) => {
+            let mut output = output;
+            output.extend(result.clone());
+            output
+        }
+        None => output,
+    }
+}
+
+/// Run an admitted task: queue it on the background worker (service mode) or execute it
+/// inline (CLI mode).
+///
+/// Returns `Err` with the failed record when the worker queue rejects the task.
+pub(super) async fn dispatch_admitted_task(
+    admitted: TaskRecord,
+    console: ConsoleMode,
+) -> Result<TaskRecord, TaskRecord> {
+    let task_id = admitted.id.clone();
+    let intent = admitted.intent.clone();
+    let runtime_mode = admitted.runtime_mode;
+    match runtime_mode {
+        RuntimeMode::Service => {
+            // Prefer the live admission span so the worker nests under it; fall back to the
+            // ingress context when OpenTelemetry is not active in this process.
+            let trace_context = span_trace_context(&tracing::Span::current()).or_else(|| {
+                admitted
+                    .control_envelope
+                    .as_ref()
+                    .and_then(|control| control.trace_context.clone())
+            });
+            match submit_task_to_worker(task_id.clone(), intent, runtime_mode, trace_context) {
+                Ok(()) => Ok(
+                    with_task_registry(|registry| registry.get(&task_id).cloned()).unwrap_or_else(
+                        || TaskRecord {
+                            status_message: "Task queued for background worker execution"
+                                .to_string(),
+                            ..admitted
+                        },
+                    ),
+                ),
+                Err(error) => {
+                    let failed =
+                        update_task_record_status(&task_id, TaskExecutionStatus::Failed, &error)
+                            .unwrap_or_else(|| TaskRecord {
+                                status: TaskExecutionStatus::Failed,
+                                status_message: error.clone(),
+                                ..admitted
+                            });
+                    if console.is_text() {
+                        print_command_failure(&CommandFailure::new(error));
+                    }
+                    Err(failed)
+                }
+            }
+        }
+        RuntimeMode::Cli => {
+            let _ = update_task_record_status(
+                &task_id,
+                TaskExecutionStatus::Running,
+                format!(
+                    "Executing intent {} locally",
+                    task_intent_kind_label(intent.kind)
+                ),
+            );
+            let _ = update_task_attempt(&task_id, 1);
+            let execution =
+                execute_task_locally_for_operator(&task_id, &intent, runtime_mode, console).await;
+            let final_status = execution.status;
+            set_task_output(&task_id, execution.output);
+            let fallback = TaskRecord {
+                status: final_status,
+                attempts: 1,
+                ..admitted
+            };
+            Ok(
+                update_task_record_status(&task_id, final_status, execution.detail)
+                    .unwrap_or(fallback),
+            )
+        }
+    }
+}
+
+/// Park an admitted task until an operator approves or rejects it.
+fn park_task_for_approval(admitted: TaskRecord, gate: &str) -> TaskRecord {
+    runtime_metrics().increment_counter("runtime_task_approval_parked_total");
+    with_task_registry(|registry| {
+        if let Some(record) = registry.get_mut(&admitted.id) {
+            record.approval_gate = Some(gate.to_string());
+            if let Some(control) = record.control_envelope.as_mut() {
+                control.policy.approval_state = ApprovalState::Required;
+            }
+        }
+    });
+    update_task_record_status(
+        &admitted.id,
+        TaskExecutionStatus::AwaitingApproval,
+        format!("Awaiting operator approval (gate: {gate})"),
+    )
+    .unwrap_or_else(|| TaskRecord {
+        status: TaskExecutionStatus::AwaitingApproval,
+        approval_gate: Some(gate.to_string()),
+        ..admitted
+    })
+}
+
+/// Send a task to the configured service and map the answer back into an outcome.
+async fn submit_task_remotely(
+    client: &RemoteServiceClient,
+    intent: &TaskIntent,
+    control_envelope: Option<&ControlEnvelope>,
+    console: ConsoleMode,
+) -> Result<TaskSubmissionOutcome, RemoteServiceError> {
+    let metrics = runtime_metrics();
+    metrics.increment_counter("runtime_remote_task_submit_total");
+    let context = control_envelope.map_or_else(RemoteRequestContext::default, |control| {
+        RemoteRequestContext {
+            request_id: Some(control.request_id.clone()),
+            correlation_id: control.correlation_id.clone(),
+            session_id: Some(control.session.id.clone()),
+        }
+    });
+    let submission = client
+        .submit_task(
+            task_intent_kind_label(intent.kind),
+            intent.payload.trim(),
+            &context,
+        )
+        .await?;
+    let outcome = TaskSubmissionOutcome::from_remote(submission, client.endpoint());
+
+    match (&outcome.task_id, &outcome.rejection_reason) {
+        (Some(task_id), _) => {
+            info!(task_id = %task_id, endpoint = %client.endpoint(), "Task submitted to remote service");
+            if console.is_text() {
+                emit(
+                    CommandOutput::new()
+                        .heading("✅ Task submitted to remote service", OutputTone::Success)
+                        .key_values([
+                            ("id", task_id.clone()),
+                            ("intent", task_intent_kind_label(intent.kind).to_string()),
+                            (
+                                "status",
+                                outcome
+                                    .task_status
+                                    .map_or("queued", task_status_label)
+                                    .to_string(),
+                            ),
+                            ("endpoint", client.endpoint().to_string()),
+                        ])
+                        .text(
+                            format!("Track it with /task watch {task_id}"),
+                            OutputTone::Warning,
+                        ),
+                );
+            }
+        }
+        (None, reason) => {
+            metrics.increment_counter("runtime_remote_task_rejected_total");
+            if console.is_text() {
+                print_command_failure(&CommandFailure::new(format!(
+                    "Task rejected by remote service: {}",
+                    reason.as_deref().unwrap_or_default()
+                )));
+            }
+        }
+    }
+
+    Ok(outcome)
+}
@@ -0,0 +1,477 @@
+//! Remote task client for a running `ntk service`
+//!
+//! When a service endpoint is configured (`ntk --remote <url>`,
+//! `NTK_TASK_SERVICE_ENDPOINT` or `general.service_endpoint`), CLI `/task`
+//! commands are sent to that service over HTTP instead of the in-process task
+//! registry. Requests carry the bearer token from `NTK_SERVICE_AUTH_TOKEN` and
+//! `x-ntk-operator-*` headers, so the service audits the caller as a remote
+//! operator. Callers fall back to local execution when the endpoint is
+//! unreachable ([`RemoteServiceError::is_unreachable`]), unless the process
+//! called [`require_remote_task_records`]: one-shot `ntk task` lookups have no
+//! local records to fall back to.
+
+use super::headless::TaskSnapshot;
+use nettoolskit_core::{AppConfig, PolicySource, RuntimeMode, TaskExecutionStatus};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+/// Bearer token sent to the service (same variable the service validates).
+pub const NTK_SERVICE_AUTH_TOKEN_ENV: &str = "NTK_SERVICE_AUTH_TOKEN";
+/// Operator id reported to the service in `x-ntk-operator-id`.
+pub const NTK_OPERATOR_ID_ENV: &str = "NTK_OPERATOR_ID";
+/// Per-request timeout for remote task calls, in milliseconds.
+pub const NTK_REMOTE_TIMEOUT_MS_ENV: &str = "NTK_REMOTE_TIMEOUT_MS";
+
+const DEFAULT_REMOTE_OPERATOR_ID: &str = "remote-cli-operator";
+const DEFAULT_REMOTE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum EndpointOverride {
+    FromConfig,
+    Endpoint(String),
+    Disabled,
+}
+
+static REMOTE_TASK_RECORDS_REQUIRED: AtomicBool = AtomicBool::new(false);
+
+fn endpoint_override() -> &'static RwLock<EndpointOverride> {
+    static OVERRIDE: OnceLock<RwLock<EndpointOverride>> = OnceLock::new();
+    OVERRIDE.get_or_init(|| RwLock::new(EndpointOverride::FromConfig))
+}
+
+fn store_endpoint_override(value: EndpointOverride) {
+    match endpoint_override().write() {
+        Ok(mut guard) => *guard = value,
+        Err(poisoned) => *poisoned.into_inner() = value,
+    }
+}
+
+/// Validate a service base URL and strip trailing slashes.
+///
+/// # Errors
+///
+/// Returns an error when the value is empty or not an `http(s)://` URL.
+pub fn normalize_service_endpoint(value: &str) -> Result<String, String> {
+    let trimmed = value.trim().trim_end_matches('/');
+    let has_host = ["http://", "https://"]
+        .iter()
+        .any(|scheme| trimmed.len() > scheme.len() && trimmed.starts_with(scheme));
+    if has_host {
+        Ok(trimmed.to_string())
+    } else {
+        Err("service_endpoint must be an http:// or https:// URL".to_string())
+    }
+}
+
+/// Route `/task` commands in this process to `endpoint` (the `--remote` flag).
+///
+/// Takes precedence over `NTK_TASK_SERVICE_ENDPOINT` and `general.service_endpoint`.
+///
+/// # Errors
+///
+/// Returns an error when `endpoint` is not a valid service URL.
+pub fn set_remote_service_endpoint(endpoint: &str) -> Result<(), String> {
+    let endpoint = normalize_service_endpoint(endpoint)?;
+    store_endpoint_override(EndpointOverride::Endpoint(endpoint));
+    Ok(())
+}
+
+/// Keep every `/task` command in this process local, ignoring configured endpoints.
+///
+/// `ntk service` calls this so its own handlers never dispatch back to a service.
+pub fn disable_remote_service_dispatch() {
+    store_endpoint_override(EndpointOverride::Disabled);
+}
+
+/// Make task lookups and decisions in this process fail instead of reading the
+/// in-process registry when no service is configured or reachable.
+///
+/// `ntk task list|watch|cancel|approvals|approve|reject` call this: a fresh
+/// process holds no task records, so a local answer would always be empty.
+pub fn require_remote_task_records() {
+    REMOTE_TASK_RECORDS_REQUIRED.store(true, Ordering::Relaxed);
+}
+
+/// Whether [`require_remote_task_records`] was called in this process.
+#[must_use]
+pub fn remote_task_records_required() -> bool {
+    REMOTE_TASK_RECORDS_REQUIRED.load(Ordering::Relaxed)
+}
+
+/// Service endpoint `/task` commands should use, if any.
+///
+/// Configured endpoints only apply in CLI runtime mode; an explicit
+/// [`set_remote_service_endpoint`] always applies.
+#[must_use]
+pub fn resolve_remote_service_endpoint() -> Option<String> {
+    let current = match endpoint_override().read() {
+        Ok(guard) => guard.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    };
+    match current {
+        EndpointOverride::Disabled => None,
+        EndpointOverride::Endpoint(endpoint) => Some(endpoint),
+        EndpointOverride::FromConfig => {
+            let config = AppConfig::load();
+            if config.general.runtime_mode != RuntimeMode::Cli {
+                return None;
+            }
+            config
+                .general
+                .service_endpoint
+                .and_then(|endpoint| normalize_service_endpoint(&endpoint).ok())
+        }
+    }
+}
+
+/// Remote task client error contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteServiceError {
+    /// Endpoint could not be reached (connection refused, DNS, timeout).
+    Unreachable {
+        /// Service base URL.
+        endpoint: String,
+        /// Transport error detail.
+        message: String,
+    },
+    /// Service answered with a non-success HTTP status.
+    Http {
+        /// HTTP status code.
+        status: u16,
+        /// Response body or reason.
+        message: String,
+    },
+    /// Service answered with a payload this client cannot decode.
+    InvalidResponse(String),
+    /// No service endpoint is configured but the caller requires one.
+    NotConfigured,
+}
+
+impl RemoteServiceError {
+    /// Whether callers should fall back to local execution.
+    #[must_use]
+    pub const fn is_unreachable(&self) -> bool {
+        matches!(self, Self::Unreachable { .. })
+    }
+}
+
+impl Display for RemoteServiceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unreachable { endpoint, message } => {
+                write!(f, "service endpoint {endpoint} unreachable: {message}")
+            }
+            Self::Http { status: 401, .. } => write!(
+                f,
+                "service rejected credentials (HTTP 401); check {NTK_SERVICE_AUTH_TOKEN_ENV}"
+            ),
+            Self::Http { status, message } if message.is_empty() => {
+                write!(f, "service returned HTTP {status}")
+            }
+            Self::Http { status, message } => {
+                write!(f, "service returned HTTP {status}: {message}")
+            }
+            Self::InvalidResponse(msg) => write!(f, "invalid service response: {msg}"),
+            Self::NotConfigured => write!(
+                f,
+                "no task service endpoint configured; task records live in a running `ntk service` \
+                 (use --remote <url>, NTK_TASK_SERVICE_ENDPOINT or general.service_endpoint)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RemoteServiceError {}
+
+/// Body of `POST /task/submit`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteTaskSubmitRequest {
+    /// Task intent label (for example `ai-plan`).
+    pub intent: String,
+    /// Task payload.
+    pub payload: String,
+}
+
+/// Body of `POST /task/{id}/approve` and `POST /task/{id}/reject`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteTaskDecisionRequest {
+    /// Optional note recorded with the decision.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Response of `POST /task/submit`, as returned by `ntk service`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteTaskSubmission {
+    /// Whether the service admitted the task.
+    pub accepted: bool,
+    /// Exit status label (`success`, `error`, `interrupted`).
+    pub exit_status: String,
+    /// Task id assigned by the service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    /// Task status at admission time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_status: Option<TaskExecutionStatus>,
+    /// Reason the service rejected the task.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rejection_reason: Option<String>,
+    /// Service request id.
+    pub request_id: String,
+    /// Correlation id echoed by the service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    /// Operator id the service recorded.
+    pub operator_id: String,
+}
+
+/// Operator identity attached to remote task requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RemoteRequestContext {
+    /// Value for `x-request-id`.
+    pub request_id: Option<String>,
+    /// Value for `x-correlation-id`.
+    pub correlation_id: Option<String>,
+    /// Value for `x-ntk-session-id`.
+    pub session_id: Option<String>,
+}
+
+/// HTTP client for the `ntk service` task API.
+#[derive(Debug, Clone)]
+pub struct RemoteServiceClient {
+    endpoint: String,
+    auth_token: Option<String>,
+    operator_id: String,
+    http: reqwest::Client,
+}
+
+impl RemoteServiceClient {
+    /// Build a client for `endpoint`, reading token, operator id and timeout from env.
+    #[must_use]
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        let timeout = std::env::var(NTK_REMOTE_TIMEOUT_MS_ENV)
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .filter(|millis| *millis > 0)
+            .map_or(DEFAULT_REMOTE_TIMEOUT, Duration::from_millis);
+        let http = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            endpoint: endpoint.into().trim_end_matches('/').to_string(),
+            auth_token: PolicySource::load()
+                .secret(NTK_SERVICE_AUTH_TOKEN_ENV)
+                .unwrap_or_else(|error| {
+                    tracing::warn!(error = %error, "Sending remote requests without a bearer token");
+                    None
+                }),
+            operator_id: non_empty_env(NTK_OPERATOR_ID_ENV)
+                .unwrap_or_else(|| DEFAULT_REMOTE_OPERATOR_ID.to_string()),
+            http,
+        }
+    }
+
+    /// Client for the resolved endpoint ([`resolve_remote_service_endpoint`]).
+    #[must_use]
+    pub fn from_environment() -> Option<Self> {
+        resolve_remote_service_endpoint().map(Self::new)
+    }
+
+    /// Override the bearer token.
+    #[must_use]
+    pub fn with_auth_token(mut self, token: Option<String>) -> Self {
+        self.auth_token = token;
+        self
+    }
+
+    /// Override the operator id.
+    #[must_use]
+    pub fn with_operator_id(mut self, operator_id: impl Into<String>) -> Self {
+        self.operator_id = operator_id.into();
+        self
+    }
+
+    /// Service base URL.
+    #[must_use]
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Submit a task (`POST /task/submit`).
+    ///
+    /// Admission rejections come back as `Ok` with `accepted = false`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the service is unreachable, answers with an
+    /// unexpected status, or returns an undecodable payload.
+    pub async fn submit_task(
+        &self,
+        intent: &str,
+        payload: &str,
+        context: &RemoteRequestContext,
+    ) -> Result<RemoteTaskSubmission, RemoteServiceError> {
+        let mut request =
+            self.request(reqwest::Method::POST, "/task/submit")
+                .json(&RemoteTaskSubmitRequest {
+                    intent: intent.to_string(),
+                    payload: payload.to_string(),
+                });
+        for (name, value) in [
+            ("x-request-id", &context.request_id),
+            ("x-correlation-id", &context.correlation_id),
+            ("x-ntk-session-id", &context.session_id),
+        ] {
+            if let Some(value) = value {
+                request = request.header(name, value);
+            }
+        }
+
+        let (status, body) = self.send(request).await?;
+        // Rejections are reported as JSON with a 4xx status; anything else non-2xx is an error.
+        match serde_json::from_str::<RemoteTaskSubmission>(&body) {
+            Ok(submission) => Ok(submission),
+            Err(_) if !status.is_success() => Err(http_error(status, body)),
+            Err(error) => Err(RemoteServiceError::InvalidResponse(error.to_string())),
+        }
+    }
+
+    /// List tasks known to the service (`GET /task/list`).
+    ///
+    /// # Errors
+    ///
+    /// See [`RemoteServiceClient::submit_task`].
+    pub async fn list_tasks(&self) -> Result<Vec<TaskSnapshot>, RemoteServiceError> {
+        self.send_json(self.request(reqwest::Method::GET, "/task/list"))
+            .await
+    }
+
+    /// Look up one task with its audit trail (`GET /task/{id}`).
+    ///
+    /// # Errors
+    ///
+    /// See [`RemoteServiceClient::submit_task`].
+    pub async fn get_task(&self, task_id: &str) -> Result<TaskSnapshot, RemoteServiceError> {
+        let path = format!("/task/{}", task_id.trim());
+        self.send_json(self.request(reqwest::Method::GET, &path))
+            .await
+    }
+
+    /// Cancel a queued or running task (`POST /task/{id}/cancel`).
+    ///
+    /// # Errors
+    ///
+    /// See [`RemoteServiceClient::submit_task`].
+    pub async fn cancel_task(&self, task_id: &str) -> Result<TaskSnapshot, RemoteServiceError> {
+        let path = format!("/task/{}/cancel", task_id.trim());
+        self.send_json(self.request(reqwest::Method::POST, &path))
+            .await
+    }
+
+    /// Approve a task waiting in the approval queue (`POST /task/{id}/approve`).
+    ///
+    /// # Errors
+    ///
+    /// See [`RemoteServiceClient::submit_task`].
+    pub async fn approve_task(
+        &self,
+        task_id: &str,
+        note: Option<&str>,
+    ) -> Result<TaskSnapshot, RemoteServiceError> {
+        self.decide_task(task_id, "approve", note).await
+    }
+
+    /// Reject a task waiting in the approval queue (`POST /task/{id}/reject`).
+    ///
+    /// # Errors
+    ///
+    /// See [`RemoteServiceClient::submit_task`].
+    pub async fn reject_task(
+        &self,
+        task_id: &str,
+        reason: Option<&str>,
+    ) -> Result<TaskSnapshot, RemoteServiceError> {
+        self.decide_task(task_id, "reject", reason).await
+    }
+
+    async fn decide_task(
+        &self,
+        task_id: &str,
+        action: &str,
+        note: Option<&str>,
+    ) -> Result<TaskSnapshot, RemoteServiceError> {
+        let path = format!("/task/{}/{action}", task_id.trim());
+        let body = RemoteTaskDecisionRequest {
+            note: note.map(str::to_string),
+        };
+        self.send_json(self.request(reqwest::Method::POST, &path).json(&body))
+            .await
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let mut request = self
+            .http
+            .request(method, format!("{}{path}", self.endpoint))
+            .header("x-ntk-operator-id", &self.operator_id);
+        if let Some(token) = &self.auth_token {
+            request = request.bearer_auth(token);
+        }
+        for (name, value) in nettoolskit_otel::current_trace_headers() {
+            request = request.header(name, value);
+        }
+        request
+    }
+
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<(reqwest::StatusCode, String), RemoteServiceError> {
+        let response = request.send().await.map_err(|error| {
+            if error.is_connect() || error.is_timeout() {
+                RemoteServiceError::Unreachable {
+                    endpoint: self.endpoint.clone(),
+                    message: error.to_string(),
+                }
+            } else {
+                RemoteServiceError::InvalidResponse(error.to_string())
+            }
+        })?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|error| RemoteServiceError::InvalidResponse(error.to_string()))?;
+        Ok((status, body))
+    }
+
+    async fn send_json<T: serde::de::DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T, RemoteServiceError> {
+        let (status, body) = self.send(request).await?;
+        if !status.is_success() {
+            return Err(http_error(status, body));
+        }
+        serde_json::from_str(&body)
+            .map_err(|error| RemoteServiceError::InvalidResponse(error.to_string()))
+    }
+}
+
+fn http_error(status: reqwest::StatusCode, body: String) -> RemoteServiceError {
+    RemoteServiceError::Http {
+        status: status.as_u16(),
+        message: body.trim().to_string(),
+    }
+}
+
+fn non_empty_env(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
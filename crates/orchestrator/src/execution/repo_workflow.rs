@@ -4,7 +4,7 @@
 //! `clone -> branch -> execute -> commit -> push -> PR`.
 //! All mutating actions are blocked unless policy is explicitly enabled.
 
-use nettoolskit_core::AppConfig;
+use nettoolskit_core::{AppConfig, PolicySource};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
@@ -56,39 +56,41 @@ impl Default for RepoWorkflowPolicy {
 }
 
 impl RepoWorkflowPolicy {
-    /// Resolve policy from environment.
+    /// Resolve policy from environment, falling back to the `[repo_workflow]`
+    /// section of the config file.
     #[must_use]
     pub fn from_env() -> Self {
+        let source = PolicySource::load();
         let mut policy = Self::default();
 
-        if let Ok(value) = std::env::var(NTK_REPO_WORKFLOW_ENABLED_ENV) {
+        if let Some(value) = source.var(NTK_REPO_WORKFLOW_ENABLED_ENV) {
             if let Some(parsed) = parse_bool(&value) {
                 policy.enabled = parsed;
             }
         }
-        if let Ok(value) = std::env::var(NTK_REPO_WORKFLOW_ALLOWED_HOSTS_ENV) {
+        if let Some(value) = source.var(NTK_REPO_WORKFLOW_ALLOWED_HOSTS_ENV) {
             policy.allowed_hosts = parse_list(&value)
                 .into_iter()
                 .map(|host| host.to_ascii_lowercase())
                 .collect();
         }
-        if let Ok(value) = std::env::var(NTK_REPO_WORKFLOW_ALLOWED_COMMANDS_ENV) {
+        if let Some(value) = source.var(NTK_REPO_WORKFLOW_ALLOWED_COMMANDS_ENV) {
             policy.allowed_command_prefixes = parse_list(&value)
                 .into_iter()
                 .map(|command| command.to_ascii_lowercase())
                 .collect();
         }
-        if let Ok(value) = std::env::var(NTK_REPO_WORKFLOW_ALLOW_PUSH_ENV) {
+        if let Some(value) = source.var(NTK_REPO_WORKFLOW_ALLOW_PUSH_ENV) {
             if let Some(parsed) = parse_bool(&value) {
                 policy.allow_push = parsed;
             }
         }
-        if let Ok(value) = std::env::var(NTK_REPO_WORKFLOW_ALLOW_PR_ENV) {
+        if let Some(value) = source.var(NTK_REPO_WORKFLOW_ALLOW_PR_ENV) {
             if let Some(parsed) = parse_bool(&value) {
                 policy.allow_pull_request = parsed;
             }
         }
-        if let Ok(value) = std::env::var(NTK_REPO_WORKFLOW_BASE_DIR_ENV) {
+        if let Some(value) = source.var(NTK_REPO_WORKFLOW_BASE_DIR_ENV) {
             let trimmed = value.trim();
             if !trimmed.is_empty() {
                 policy.workspace_root = PathBuf::from(trimmed);
@@ -0,0 +1,851 @@
+//! Live task dashboard behind `/task top`.
+//!
+//! Every task lifecycle transition appends an audit event, which bumps the
+//! revision published by [`subscribe_task_changes`]. The dashboard reloads on
+//! that signal and requests a terminal frame; the shared frame scheduler
+//! coalesces bursts of worker callbacks into one redraw, so long AI tasks can be
+//! monitored without polling the registry. A 1 Hz tick only advances elapsed
+//! clocks (and re-fetches the list when tasks live in a remote `ntk service`,
+//! which has no local callbacks).
+//!
+//! When output is captured (ChatOps, HTTP, the full-screen TUI) or stdout is not
+//! a terminal, `/task top` emits a single snapshot instead.
+
+use super::headless::TaskSnapshot;
+use super::output::{emit, is_capturing_output, CommandOutput, OutputTone, TerminalSink};
+use super::processor::{
+    call_remote_task_service, cancel_task, current_unix_timestamp_ms, list_task_snapshots,
+    remote_task_client, subscribe_task_changes, task_snapshot, task_status_label, ConsoleMode,
+};
+use super::remote::RemoteServiceClient;
+use crate::models::ExitStatus;
+use crossterm::cursor::{
+    Hide, MoveToColumn, MoveToNextLine, MoveUp, RestorePosition, SavePosition, Show,
+};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::queue;
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType};
+use nettoolskit_core::TaskExecutionStatus;
+use nettoolskit_ui::{
+    consume_scheduled_terminal_frame, ensure_layout_integrity, request_terminal_frame,
+    scheduled_frame_poll_timeout,
+};
+use owo_colors::OwoColorize;
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+/// Upper bound for the key-poll sleep between frames.
+const INPUT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Interval of the clock tick (elapsed times, remote refresh).
+const CLOCK_TICK_INTERVAL: Duration = Duration::from_secs(1);
+/// Rows kept free for the prompt and surrounding output.
+const DASHBOARD_RESERVED_ROWS: u16 = 6;
+const DASHBOARD_MIN_ROWS: u16 = 8;
+const DASHBOARD_MAX_ROWS: u16 = 30;
+
+const LIST_HINT: &str = "↑/↓ select · Enter audit trail · c cancel · q quit";
+const AUDIT_HINT: &str = "Esc back · c cancel · q quit";
+
+/// Dashboard section a task is listed under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TaskSection {
+    Running,
+    Queued,
+    Finished,
+}
+
+impl TaskSection {
+    fn of(status: TaskExecutionStatus) -> Self {
+        match status {
+            TaskExecutionStatus::Running => Self::Running,
+            TaskExecutionStatus::Queued | TaskExecutionStatus::AwaitingApproval => Self::Queued,
+            TaskExecutionStatus::Succeeded
+            | TaskExecutionStatus::Failed
+            | TaskExecutionStatus::Cancelled => Self::Finished,
+        }
+    }
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Running => "Running",
+            Self::Queued => "Queued",
+            Self::Finished => "Finished",
+        }
+    }
+}
+
+fn status_tone(status: TaskExecutionStatus) -> OutputTone {
+    match status {
+        TaskExecutionStatus::Running => OutputTone::Info,
+        TaskExecutionStatus::Queued => OutputTone::Plain,
+        TaskExecutionStatus::AwaitingApproval | TaskExecutionStatus::Cancelled => {
+            OutputTone::Warning
+        }
+        TaskExecutionStatus::Succeeded => OutputTone::Success,
+        TaskExecutionStatus::Failed => OutputTone::Error,
+    }
+}
+
+/// Time since admission; finished tasks stop at their last update.
+fn task_elapsed_ms(task: &TaskSnapshot, now_unix_ms: u64) -> u64 {
+    let end = match TaskSection::of(task.status) {
+        TaskSection::Finished => task.updated_at_unix_ms,
+        TaskSection::Running | TaskSection::Queued => now_unix_ms,
+    };
+    end.saturating_sub(task.created_at_unix_ms)
+}
+
+fn format_elapsed(elapsed_ms: u64) -> String {
+    let seconds = elapsed_ms / 1000;
+    match seconds {
+        0 => format!("{elapsed_ms}ms"),
+        1..=59 => format!("{seconds}s"),
+        60..=3599 => format!("{}m{:02}s", seconds / 60, seconds % 60),
+        _ => format!("{}h{:02}m", seconds / 3600, (seconds % 3600) / 60),
+    }
+}
+
+fn task_row(task: &TaskSnapshot, now_unix_ms: u64) -> Vec<String> {
+    vec![
+        task.id.clone(),
+        task_status_label(task.status).to_string(),
+        task.intent.clone(),
+        task.operator.clone().unwrap_or_else(|| "-".to_string()),
+        task.execution_target.clone(),
+        format!("{}/{}", task.attempts, task.max_attempts),
+        format_elapsed(task_elapsed_ms(task, now_unix_ms)),
+    ]
+}
+
+const TASK_COLUMNS: [&str; 7] = [
+    "id", "status", "intent", "operator", "target", "attempts", "elapsed",
+];
+
+fn section_counts(tasks: &[TaskSnapshot]) -> (usize, usize, usize) {
+    tasks.iter().fold(
+        (0, 0, 0),
+        |(running, queued, finished), task| match TaskSection::of(task.status) {
+            TaskSection::Running => (running + 1, queued, finished),
+            TaskSection::Queued => (running, queued + 1, finished),
+            TaskSection::Finished => (running, queued, finished + 1),
+        },
+    )
+}
+
+/// One-shot dashboard for captured output and non-terminal stdout.
+pub(super) fn task_top_output(tasks: &[TaskSnapshot], now_unix_ms: u64) -> CommandOutput {
+    if tasks.is_empty() {
+        return CommandOutput::new().text("No task records found.", OutputTone::Warning);
+    }
+
+    let mut ordered = tasks.to_vec();
+    sort_for_dashboard(&mut ordered);
+    let (running, queued, finished) = section_counts(&ordered);
+    CommandOutput::new()
+        .heading("📊 Task Dashboard", OutputTone::Info)
+        .key_values([
+            ("running", running.to_string()),
+            ("queued", queued.to_string()),
+            ("finished", finished.to_string()),
+        ])
+        .table(
+            TASK_COLUMNS,
+            ordered
+                .iter()
+                .map(|task| task_row(task, now_unix_ms))
+                .collect(),
+        )
+}
+
+/// Running first, then queued (oldest first), then finished (newest first).
+fn sort_for_dashboard(tasks: &mut [TaskSnapshot]) {
+    tasks.sort_by(|a, b| {
+        let rank = |task: &TaskSnapshot| match TaskSection::of(task.status) {
+            TaskSection::Running => 0,
+            TaskSection::Queued => 1,
+            TaskSection::Finished => 2,
+        };
+        rank(a).cmp(&rank(b)).then_with(|| {
+            if TaskSection::of(a.status) == TaskSection::Finished {
+                b.updated_at_unix_ms.cmp(&a.updated_at_unix_ms)
+            } else {
+                a.created_at_unix_ms.cmp(&b.created_at_unix_ms)
+            }
+        })
+    });
+}
+
+fn pad_cells(cells: &[String], widths: &[usize]) -> String {
+    cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{cell:width$}"))
+        .collect::<Vec<_>>()
+        .join("  ")
+        .trim_end()
+        .to_string()
+}
+
+fn truncate_to_width(line: &str, width: usize) -> String {
+    if line.chars().count() <= width {
+        return line.to_string();
+    }
+    let mut truncated: String = line.chars().take(width.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// What the run loop should do after a key press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DashboardCommand {
+    None,
+    OpenAudit(String),
+    Cancel(String),
+    Quit,
+}
+
+/// Dashboard state: ordered tasks, selection and the audit view.
+#[derive(Debug, Default)]
+struct TaskDashboard {
+    tasks: Vec<TaskSnapshot>,
+    selected: usize,
+    audit: Option<TaskSnapshot>,
+    notice: Option<(OutputTone, String)>,
+}
+
+impl TaskDashboard {
+    /// Replace the task list, keeping the selection on the same task id.
+    fn set_tasks(&mut self, mut tasks: Vec<TaskSnapshot>) {
+        let selected_id = self.selected_task().map(|task| task.id.clone());
+        sort_for_dashboard(&mut tasks);
+        self.selected = selected_id
+            .and_then(|id| tasks.iter().position(|task| task.id == id))
+            .unwrap_or(self.selected)
+            .min(tasks.len().saturating_sub(1));
+        self.tasks = tasks;
+    }
+
+    fn selected_task(&self) -> Option<&TaskSnapshot> {
+        self.tasks.get(self.selected)
+    }
+
+    fn has_active_tasks(&self) -> bool {
+        self.tasks
+            .iter()
+            .any(|task| TaskSection::of(task.status) != TaskSection::Finished)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> DashboardCommand {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return DashboardCommand::Quit;
+        }
+        if let Some(audit) = &self.audit {
+            return match key.code {
+                KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                    self.audit = None;
+                    DashboardCommand::None
+                }
+                KeyCode::Char('c') => DashboardCommand::Cancel(audit.id.clone()),
+                KeyCode::Char('q') => DashboardCommand::Quit,
+                _ => DashboardCommand::None,
+            };
+        }
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+                DashboardCommand::None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.tasks.len().saturating_sub(1));
+                DashboardCommand::None
+            }
+            KeyCode::Enter | KeyCode::Right => {
+                self.selected_task().map_or(DashboardCommand::None, |task| {
+                    DashboardCommand::OpenAudit(task.id.clone())
+                })
+            }
+            KeyCode::Char('c') => self.selected_task().map_or(DashboardCommand::None, |task| {
+                DashboardCommand::Cancel(task.id.clone())
+            }),
+            KeyCode::Char('q') | KeyCode::Esc => DashboardCommand::Quit,
+            _ => DashboardCommand::None,
+        }
+    }
+
+    /// Lines of the current frame, each with the tone it is drawn in.
+    fn frame_lines(
+        &self,
+        now_unix_ms: u64,
+        width: usize,
+        height: usize,
+    ) -> Vec<(OutputTone, String)> {
+        let (body, hint) = match &self.audit {
+            Some(task) => (self.audit_lines(task, now_unix_ms, height), AUDIT_HINT),
+            None => (self.list_lines(now_unix_ms, height), LIST_HINT),
+        };
+        let mut lines = body;
+        if let Some(notice) = &self.notice {
+            lines.push(notice.clone());
+        }
+        lines.push((OutputTone::Plain, hint.to_string()));
+        lines
+            .into_iter()
+            .map(|(tone, line)| (tone, truncate_to_width(&line, width)))
+            .collect()
+    }
+
+    fn list_lines(&self, now_unix_ms: u64, height: usize) -> Vec<(OutputTone, String)> {
+        let (running, queued, finished) = section_counts(&self.tasks);
+        let mut lines = vec![(
+            OutputTone::Info,
+            format!(
+                "📊 Task Dashboard · {running} running · {queued} queued · {finished} finished"
+            ),
+        )];
+        if self.tasks.is_empty() {
+            lines.push((
+                OutputTone::Warning,
+                "No task records yet. Submit one with /task submit <intent> <payload>.".to_string(),
+            ));
+            return lines;
+        }
+
+        let header: Vec<String> = TASK_COLUMNS
+            .iter()
+            .map(|column| column.to_uppercase())
+            .collect();
+        let rows: Vec<Vec<String>> = self
+            .tasks
+            .iter()
+            .map(|task| task_row(task, now_unix_ms))
+            .collect();
+        let mut widths: Vec<usize> = header.iter().map(|cell| cell.chars().count()).collect();
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        lines.push((
+            OutputTone::Plain,
+            format!("  {}", pad_cells(&header, &widths)),
+        ));
+
+        let mut body = Vec::new();
+        let mut selected_line = 0;
+        let mut section = None;
+        for (index, (task, row)) in self.tasks.iter().zip(&rows).enumerate() {
+            let task_section = TaskSection::of(task.status);
+            if section != Some(task_section) {
+                section = Some(task_section);
+                body.push((OutputTone::Info, format!("{}:", task_section.label())));
+            }
+            let marker = if index == self.selected { "❯ " } else { "  " };
+            if index == self.selected {
+                selected_line = body.len();
+            }
+            body.push((
+                status_tone(task.status),
+                format!("{marker}{}", pad_cells(row, &widths)),
+            ));
+        }
+
+        // Keep the selected row visible; reserve the title, header, notice and hint.
+        let available = height.saturating_sub(4).max(1);
+        let start = (selected_line + 1).saturating_sub(available);
+        lines.extend(body.into_iter().skip(start).take(available));
+        lines
+    }
+
+    fn audit_lines(
+        &self,
+        task: &TaskSnapshot,
+        now_unix_ms: u64,
+        height: usize,
+    ) -> Vec<(OutputTone, String)> {
+        let mut lines = vec![(
+            status_tone(task.status),
+            format!("🔎 {} · {}", task.id, task_status_label(task.status)),
+        )];
+        let fields = [
+            ("intent", task.intent.clone()),
+            ("payload", task.payload.clone()),
+            (
+                "operator",
+                task.operator.clone().unwrap_or_else(|| "-".to_string()),
+            ),
+            ("target", task.execution_target.clone()),
+            (
+                "attempts",
+                format!("{}/{}", task.attempts, task.max_attempts),
+            ),
+            (
+                "elapsed",
+                format_elapsed(task_elapsed_ms(task, now_unix_ms)),
+            ),
+            ("detail", task.detail.clone()),
+        ];
+        lines.extend(
+            fields
+                .into_iter()
+                .map(|(key, value)| (OutputTone::Plain, format!("  {key:<9} {value}"))),
+        );
+        lines.push((OutputTone::Info, "Audit trail:".to_string()));
+
+        let events: Vec<(OutputTone, String)> = task
+            .audit_events
+            .iter()
+            .map(|event| {
+                (
+                    status_tone(event.status),
+                    format!(
+                        "  +{:<7} {:<17} {}",
+                        format_elapsed(
+                            event
+                                .timestamp_unix_ms
+                                .saturating_sub(task.created_at_unix_ms)
+                        ),
+                        task_status_label(event.status),
+                        event.message
+                    ),
+                )
+            })
+            .collect();
+        if events.is_empty() {
+            lines.push((OutputTone::Plain, "  (no audit events)".to_string()));
+        }
+        // Newest events matter most while a task is running.
+        let available = height.saturating_sub(lines.len() + 2).max(1);
+        let skip = events.len().saturating_sub(available);
+        lines.extend(events.into_iter().skip(skip));
+        lines
+    }
+}
+
+/// Where the dashboard reads tasks from.
+enum DashboardSource {
+    Local,
+    Remote(RemoteServiceClient),
+}
+
+impl DashboardSource {
+    async fn list(&self) -> Result<Vec<TaskSnapshot>, String> {
+        match self {
+            Self::Local => Ok(list_task_snapshots()),
+            Self::Remote(client) => client.list_tasks().await.map_err(|error| error.to_string()),
+        }
+    }
+
+    async fn detail(&self, task_id: &str) -> Result<TaskSnapshot, String> {
+        match self {
+            Self::Local => {
+                task_snapshot(task_id).ok_or_else(|| format!("Task not found: {task_id}"))
+            }
+            Self::Remote(client) => client
+                .get_task(task_id)
+                .await
+                .map_err(|error| error.to_string()),
+        }
+    }
+
+    async fn cancel(&self, task_id: &str) -> Result<TaskSnapshot, String> {
+        match self {
+            Self::Local => cancel_task(task_id),
+            Self::Remote(client) => client
+                .cancel_task(task_id)
+                .await
+                .map_err(|error| error.to_string()),
+        }
+    }
+}
+
+/// Restores the raw-mode state found on entry.
+struct RawModeScope {
+    was_enabled: bool,
+}
+
+impl RawModeScope {
+    fn enter() -> io::Result<Self> {
+        let was_enabled = terminal::is_raw_mode_enabled()?;
+        if !was_enabled {
+            terminal::enable_raw_mode()?;
+        }
+        Ok(Self { was_enabled })
+    }
+}
+
+impl Drop for RawModeScope {
+    fn drop(&mut self) {
+        if !self.was_enabled {
+            let _ = terminal::disable_raw_mode();
+        }
+    }
+}
+
+/// Fixed-height block below the prompt that the dashboard redraws in place.
+///
+/// Lines are cleared one by one instead of `Clear(FromCursorDown)` so the footer
+/// log region of the interactive layout is never touched.
+struct DashboardScreen {
+    rows: u16,
+}
+
+impl DashboardScreen {
+    fn open() -> io::Result<Self> {
+        let (_, height) = terminal::size()?;
+        let rows = height
+            .saturating_sub(DASHBOARD_RESERVED_ROWS)
+            .clamp(DASHBOARD_MIN_ROWS, DASHBOARD_MAX_ROWS);
+        let mut stdout = io::stdout();
+        // Reserve the block first so the saved origin survives scrolling.
+        queue!(
+            stdout,
+            Print("\n".repeat(usize::from(rows))),
+            MoveUp(rows),
+            MoveToColumn(0),
+            SavePosition,
+            Hide
+        )?;
+        stdout.flush()?;
+        Ok(Self { rows })
+    }
+
+    fn draw(&self, lines: &[(OutputTone, String)]) -> io::Result<()> {
+        let mut stdout = io::stdout();
+        queue!(stdout, RestorePosition)?;
+        for row in 0..usize::from(self.rows) {
+            queue!(stdout, Clear(ClearType::CurrentLine))?;
+            if let Some((tone, line)) = lines.get(row) {
+                let colored = match tone {
+                    OutputTone::Plain => line.clone(),
+                    tone => line.color(TerminalSink::tone_color(*tone)).to_string(),
+                };
+                queue!(stdout, Print(colored))?;
+            }
+            if row + 1 < usize::from(self.rows) {
+                queue!(stdout, MoveToNextLine(1))?;
+            }
+        }
+        stdout.flush()
+    }
+
+    fn close(self) -> io::Result<()> {
+        self.draw(&[])?;
+        let mut stdout = io::stdout();
+        queue!(stdout, RestorePosition, Show)?;
+        stdout.flush()
+    }
+}
+
+/// Entry point of `/task top`.
+pub(super) async fn run_task_dashboard() -> ExitStatus {
+    if is_capturing_output() || !io::stdout().is_terminal() {
+        let tasks = match call_remote_task_service(ConsoleMode::Text, |client| async move {
+            client.list_tasks().await
+        })
+        .await
+        {
+            Some(Ok(tasks)) => tasks,
+            Some(Err(error)) => {
+                emit(CommandOutput::new().error(error.to_string(), Vec::new()));
+                return ExitStatus::Error;
+            }
+            None => list_task_snapshots(),
+        };
+        emit(task_top_output(&tasks, current_unix_timestamp_ms()));
+        return ExitStatus::Success;
+    }
+
+    let source = remote_task_client().map_or(DashboardSource::Local, DashboardSource::Remote);
+    let result = run_interactive_dashboard(&source).await;
+    let _ = ensure_layout_integrity();
+    match result {
+        Ok(tasks) => {
+            emit(task_top_output(&tasks, current_unix_timestamp_ms()));
+            ExitStatus::Success
+        }
+        Err(error) => {
+            emit(CommandOutput::new().error(format!("Task dashboard failed: {error}"), Vec::new()));
+            ExitStatus::Error
+        }
+    }
+}
+
+/// Run the live view until the operator quits; returns the last task list.
+async fn run_interactive_dashboard(source: &DashboardSource) -> io::Result<Vec<TaskSnapshot>> {
+    let mut dashboard = TaskDashboard::default();
+    let mut changes = subscribe_task_changes();
+    match source.list().await {
+        Ok(tasks) => dashboard.set_tasks(tasks),
+        Err(error) => dashboard.notice = Some((OutputTone::Error, error)),
+    }
+
+    let _raw_mode = RawModeScope::enter()?;
+    let screen = DashboardScreen::open()?;
+    let mut last_tick = Instant::now();
+    request_terminal_frame();
+
+    let outcome = loop {
+        let mut quit = false;
+        while event::poll(Duration::ZERO)? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    match dashboard.handle_key(key) {
+                        DashboardCommand::None => {}
+                        DashboardCommand::Quit => quit = true,
+                        DashboardCommand::OpenAudit(task_id) => match source.detail(&task_id).await
+                        {
+                            Ok(task) => dashboard.audit = Some(task),
+                            Err(error) => dashboard.notice = Some((OutputTone::Error, error)),
+                        },
+                        DashboardCommand::Cancel(task_id) => {
+                            dashboard.notice = Some(match source.cancel(&task_id).await {
+                                Ok(task) => {
+                                    (OutputTone::Success, format!("Task cancelled: {}", task.id))
+                                }
+                                Err(error) => (OutputTone::Error, error),
+                            });
+                        }
+                    }
+                    request_terminal_frame();
+                }
+                Event::Resize(_, _) => request_terminal_frame(),
+                _ => {}
+            }
+        }
+        if quit {
+            break Ok(());
+        }
+
+        if consume_scheduled_terminal_frame() {
+            let (width, _) = terminal::size()?;
+            let lines = dashboard.frame_lines(
+                current_unix_timestamp_ms(),
+                usize::from(width),
+                usize::from(screen.rows),
+            );
+            if let Err(error) = screen.draw(&lines) {
+                break Err(error);
+            }
+        }
+
+        let timeout = scheduled_frame_poll_timeout(INPUT_POLL_INTERVAL);
+        let changed = match source {
+            DashboardSource::Local => tokio::select! {
+                result = changes.changed() => result.is_ok(),
+                () = tokio::time::sleep(timeout) => false,
+            },
+            DashboardSource::Remote(_) => {
+                tokio::time::sleep(timeout).await;
+                false
+            }
+        };
+
+        let tick = last_tick.elapsed() >= CLOCK_TICK_INTERVAL;
+        if tick {
+            last_tick = Instant::now();
+        }
+        let remote_refresh = tick && matches!(source, DashboardSource::Remote(_));
+        if changed || remote_refresh {
+            if let Ok(tasks) = source.list().await {
+                dashboard.set_tasks(tasks);
+            }
+            if let Some(task_id) = dashboard.audit.as_ref().map(|task| task.id.clone()) {
+                if let Ok(task) = source.detail(&task_id).await {
+                    dashboard.audit = Some(task);
+                }
+            }
+            request_terminal_frame();
+        } else if tick && dashboard.has_active_tasks() {
+            request_terminal_frame();
+        }
+    };
+
+    screen.close()?;
+    outcome.map(|()| dashboard.tasks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::headless::TaskAuditSnapshot;
+    use crate::execution::output::OutputSink;
+    use nettoolskit_core::RuntimeMode;
+
+    fn task(id: &str, status: TaskExecutionStatus, created: u64, updated: u64) -> TaskSnapshot {
+        TaskSnapshot {
+            id: id.to_string(),
+            intent: "ai-plan".to_string(),
+            title: "plan".to_string(),
+            payload: "split processor".to_string(),
+            status,
+            runtime_mode: RuntimeMode::Cli,
+            execution_target: "background-worker".to_string(),
+            detail: String::new(),
+            attempts: 1,
+            max_attempts: 3,
+            created_at_unix_ms: created,
+            updated_at_unix_ms: updated,
+            request_id: None,
+            correlation_id: None,
+            operator: Some("local-cli".to_string()),
+            audit_events: Vec::new(),
+            output: None,
+            approval_gate: None,
+        }
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn sample_dashboard() -> TaskDashboard {
+        let mut dashboard = TaskDashboard::default();
+        dashboard.set_tasks(vec![
+            task("task-done", TaskExecutionStatus::Succeeded, 1_000, 4_000),
+            task("task-wait", TaskExecutionStatus::Queued, 2_000, 2_000),
+            task("task-run", TaskExecutionStatus::Running, 3_000, 3_500),
+        ]);
+        dashboard
+    }
+
+    #[test]
+    fn format_elapsed_scales_units() {
+        assert_eq!(format_elapsed(850), "850ms");
+        assert_eq!(format_elapsed(12_400), "12s");
+        assert_eq!(format_elapsed(184_000), "3m04s");
+        assert_eq!(format_elapsed(3_720_000), "1h02m");
+    }
+
+    #[test]
+    fn set_tasks_orders_sections_and_keeps_selection_by_id() {
+        let mut dashboard = sample_dashboard();
+        let ids: Vec<&str> = dashboard
+            .tasks
+            .iter()
+            .map(|task| task.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["task-run", "task-wait", "task-done"]);
+
+        dashboard.handle_key(key(KeyCode::Down));
+        assert_eq!(
+            dashboard.selected_task().map(|task| task.id.as_str()),
+            Some("task-wait")
+        );
+
+        dashboard.set_tasks(vec![
+            task("task-wait", TaskExecutionStatus::Running, 2_000, 5_000),
+            task("task-run", TaskExecutionStatus::Running, 3_000, 3_500),
+            task("task-done", TaskExecutionStatus::Succeeded, 1_000, 4_000),
+        ]);
+        assert_eq!(
+            dashboard.selected_task().map(|task| task.id.as_str()),
+            Some("task-wait")
+        );
+    }
+
+    #[test]
+    fn frame_lines_group_tasks_with_elapsed_and_operator() {
+        let dashboard = sample_dashboard();
+        let lines: Vec<String> = dashboard
+            .frame_lines(10_000, 200, 20)
+            .into_iter()
+            .map(|(_, line)| line)
+            .collect();
+
+        assert!(lines[0].contains("1 running · 1 queued · 1 finished"));
+        assert!(lines[1].contains("OPERATOR"));
+        assert_eq!(lines[2], "Running:");
+        assert!(lines[3].starts_with("❯ task-run"));
+        assert!(lines[3].contains("local-cli"));
+        assert!(lines[3].ends_with("7s"));
+        assert_eq!(lines[4], "Queued:");
+        assert_eq!(lines[6], "Finished:");
+        assert!(
+            lines[7].ends_with("3s"),
+            "finished tasks stop the clock: {}",
+            lines[7]
+        );
+        assert_eq!(lines.last().map(String::as_str), Some(LIST_HINT));
+    }
+
+    #[test]
+    fn frame_lines_scroll_to_keep_selection_visible() {
+        let mut dashboard = TaskDashboard::default();
+        dashboard.set_tasks(
+            (0..20)
+                .map(|index| {
+                    task(
+                        &format!("task-{index:02}"),
+                        TaskExecutionStatus::Queued,
+                        index,
+                        index,
+                    )
+                })
+                .collect(),
+        );
+        for _ in 0..19 {
+            dashboard.handle_key(key(KeyCode::Down));
+        }
+
+        let lines = dashboard.frame_lines(100, 120, 10);
+        assert!(lines.len() <= 10);
+        assert!(lines.iter().any(|(_, line)| line.starts_with("❯ task-19")));
+    }
+
+    #[test]
+    fn keys_open_audit_cancel_and_quit() {
+        let mut dashboard = sample_dashboard();
+        assert_eq!(
+            dashboard.handle_key(key(KeyCode::Enter)),
+            DashboardCommand::OpenAudit("task-run".to_string())
+        );
+        assert_eq!(
+            dashboard.handle_key(key(KeyCode::Char('c'))),
+            DashboardCommand::Cancel("task-run".to_string())
+        );
+
+        let mut audited = task("task-run", TaskExecutionStatus::Running, 3_000, 3_500);
+        audited.audit_events = vec![TaskAuditSnapshot {
+            status: TaskExecutionStatus::Running,
+            message: "attempt 1/3 in progress".to_string(),
+            timestamp_unix_ms: 3_500,
+        }];
+        dashboard.audit = Some(audited);
+        let lines: Vec<String> = dashboard
+            .frame_lines(10_000, 200, 20)
+            .into_iter()
+            .map(|(_, line)| line)
+            .collect();
+        assert!(lines[0].contains("task-run · running"));
+        assert!(lines
+            .iter()
+            .any(|line| line.contains("+500ms") && line.contains("attempt 1/3")));
+        assert_eq!(lines.last().map(String::as_str), Some(AUDIT_HINT));
+
+        assert_eq!(
+            dashboard.handle_key(key(KeyCode::Esc)),
+            DashboardCommand::None
+        );
+        assert!(dashboard.audit.is_none());
+        assert_eq!(
+            dashboard.handle_key(key(KeyCode::Char('q'))),
+            DashboardCommand::Quit
+        );
+    }
+
+    #[test]
+    fn task_top_output_summarizes_sections() {
+        let output = task_top_output(&sample_dashboard().tasks, 10_000);
+        let rendered = TerminalSink.render(&output);
+        assert!(rendered.contains("Task Dashboard"));
+        assert!(rendered.contains("task-run"));
+        assert!(rendered.contains("operator"));
+
+        let empty = task_top_output(&[], 0);
+        assert!(TerminalSink
+            .render(&empty)
+            .contains("No task records found."));
+    }
+}
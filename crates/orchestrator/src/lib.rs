@@ -18,35 +18,65 @@ pub use execution::{
     ai_session::{
         active_ai_session_id, list_local_ai_session_snapshots, load_local_ai_session_from_path,
         prune_local_ai_session_snapshots, resolve_active_ai_session_id, set_active_ai_session_id,
-        AiSessionCompressionMode, AiSessionExchange, LocalAiSessionSnapshot, LocalAiSessionState,
-        LOCAL_AI_SESSIONS_DIR_NAME, NTK_AI_SESSION_COMPRESSION_MAX_CHARS_ENV,
-        NTK_AI_SESSION_COMPRESSION_MODE_ENV, NTK_AI_SESSION_DELTA_MIN_SHARED_PREFIX_CHARS_ENV,
+        AiSessionCompressionMode, AiSessionExchange, AiSessionOrigin, AiSessionRollingSummary,
+        AiSessionSearchField, AiSessionSearchHit, LocalAiSessionSnapshot, LocalAiSessionState,
+        AI_SESSION_SUMMARY_TRUNCATION_SOURCE, LOCAL_AI_SESSIONS_DIR_NAME,
+        NTK_AI_SESSION_COMPRESSION_MAX_CHARS_ENV, NTK_AI_SESSION_COMPRESSION_MODE_ENV,
+        NTK_AI_SESSION_DELTA_MIN_SHARED_PREFIX_CHARS_ENV,
+    },
+    ai_spend::{
+        summarize_ai_spend, total_ai_spend, utc_day_label, utc_month_label, AiModelPrice,
+        AiPriceTable, AiSpendBucket, AiSpendCaps, AiSpendEntry, AiSpendGrouping, AiSpendLedger,
+        AiSpendTotals, AiUsageSource, NTK_AI_PRICE_TABLE_ENV, NTK_AI_SPEND_CAP_DAILY_USD_ENV,
+        NTK_AI_SPEND_CAP_MONTHLY_USD_ENV, NTK_AI_SPEND_LEDGER_PATH_ENV,
     },
     approval::{
-        evaluate_approval, request_approval, ApprovalActionKind, ApprovalDecision, ApprovalRequest,
+        evaluate_approval, record_task_approval_decision, request_approval, ApprovalActionKind,
+        ApprovalDecision, ApprovalRequest, TaskApprovalPolicy, TaskApprovalRule,
+        NTK_TASK_APPROVAL_RULES_ENV, NTK_TASK_APPROVERS_ENV,
     },
     chatops::{
-        execute_chatops_envelope, parse_chatops_intent, process_chatops_inbox, ChatOpsAdapterError,
-        ChatOpsAuditEntry, ChatOpsAuditKind, ChatOpsAuthorizationError, ChatOpsAuthorizationPolicy,
-        ChatOpsCommandEnvelope, ChatOpsExecutionError, ChatOpsIngress, ChatOpsIntent,
-        ChatOpsLocalAuditStore, ChatOpsNotification, ChatOpsNotificationSeverity, ChatOpsNotifier,
-        ChatOpsPlatform, MockChatOpsIngress, RecordingChatOpsNotifier,
+        execute_chatops_envelope, parse_chatops_intent, process_chatops_inbox, ChatOpsAction,
+        ChatOpsActionKind, ChatOpsAdapterError, ChatOpsAuditEntry, ChatOpsAuditKind,
+        ChatOpsAuthorizationError, ChatOpsAuthorizationPolicy, ChatOpsCommandEnvelope,
+        ChatOpsExecutionError, ChatOpsIngress, ChatOpsIntent, ChatOpsLocalAuditStore,
+        ChatOpsNotification, ChatOpsNotificationSeverity, ChatOpsNotifier, ChatOpsPlatform,
+        MockChatOpsIngress, RecordingChatOpsNotifier,
     },
     chatops_runtime::{
-        build_chatops_runtime, build_chatops_runtime_from_env, ChatOpsRuntime,
+        build_chatops_runtime, build_chatops_runtime_from_env,
+        discord_interaction_decides_approval, telegram_webhook_decides_approval, ChatOpsRuntime,
         ChatOpsRuntimeConfig, ChatOpsTickSummary, DiscordInteractionIngressOutcome,
     },
+    completion::{
+        complete_command_line, complete_command_line_with, CommandCompletion, CompletionCandidate,
+        CompletionSource, LiveCompletionSource,
+    },
     executor::{
         AsyncCommandExecutor, CommandHandle, CommandProgress, CommandResult, ProgressSender,
     },
+    headless::{AiRunOptions, ConfigValueReport, TaskAuditSnapshot, TaskSnapshot},
+    output::{
+        capture_output, emit, inherit_output_capture, is_capturing_output, ChatOpsTextSink,
+        CommandOutput, JsonSink, OutputBlock, OutputKeyValue, OutputSink, OutputTone, TerminalSink,
+    },
     plugins::{
-        command_plugin_count, list_command_plugins, register_command_plugin,
-        set_command_plugin_enabled, CommandHookContext, CommandPlugin, PluginDescriptor,
-        PluginMetadata, PluginRegistryError,
+        command_plugin_count, find_registered_command_spec, list_command_plugins,
+        register_command_plugin, registered_command_specs, set_command_plugin_enabled,
+        CommandHookContext, CommandInvocation, CommandPlugin, PluginDescriptor, PluginMetadata,
+        PluginRegistryError, NTK_PLUGIN_ALLOWED_SCOPES_ENV,
     },
     processor::{
-        process_command, process_command_with_interrupt, process_control_envelope, process_text,
-        TaskSubmissionOutcome,
+        process_command, process_command_with_interrupt, process_command_with_output,
+        process_control_envelope, process_text, runtime_metrics, AiCommandReport, AiIntent,
+        CommandFailure, ConsoleMode, TaskSubmissionOutcome,
+    },
+    remote::{
+        disable_remote_service_dispatch, normalize_service_endpoint, remote_task_records_required,
+        require_remote_task_records, resolve_remote_service_endpoint, set_remote_service_endpoint,
+        RemoteRequestContext, RemoteServiceClient, RemoteServiceError, RemoteTaskDecisionRequest,
+        RemoteTaskSubmission, RemoteTaskSubmitRequest, NTK_OPERATOR_ID_ENV,
+        NTK_REMOTE_TIMEOUT_MS_ENV,
     },
     repo_workflow::{
         execute_repo_workflow, parse_repo_workflow_payload, validate_repo_workflow_request,
@@ -57,4 +87,8 @@ pub use execution::{
         NTK_REPO_WORKFLOW_ENABLED_ENV,
     },
 };
-pub use models::{get_main_action, ExitStatus, MainAction};
+pub use models::{
+    command_specs, find_command_spec, get_main_action, parse_arguments, ArgKind, ArgSpec,
+    CommandContext, CommandFuture, CommandHandler, CommandSpec, ExitStatus, FlagSpec, MainAction,
+    SubcommandSpec,
+};
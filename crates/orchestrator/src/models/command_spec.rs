@@ -0,0 +1,1214 @@
+//! Declarative slash command registry.
+//!
+//! Each [`CommandSpec`] declares a command's aliases, subcommands, positional
+//! arguments, flags, required operator scope and handler. Dispatch, parsing,
+//! completion, help and usage output, the command palette, ChatOps scopes and
+//! task tool scopes are all derived from these tables, so adding a subcommand
+//! here is enough for every surface to pick it up. Plugins contribute further specs through
+//! [`CommandPlugin::commands`](crate::CommandPlugin::commands).
+
+use crate::execution::processor::{ai, cache, config, help, manifest, session, task};
+use crate::models::ExitStatus;
+use nettoolskit_core::POLICY_SETTINGS;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::AtomicBool;
+
+/// Command line handed to a [`CommandHandler`].
+#[derive(Debug, Clone, Copy)]
+pub struct CommandContext<'a> {
+    /// Raw command line.
+    pub command: &'a str,
+    /// Whitespace-separated tokens of `command`.
+    pub parts: &'a [&'a str],
+    /// Token after the command name, when present.
+    pub subcommand: Option<&'a str>,
+    /// Set when the operator pressed Ctrl+C.
+    pub interrupted: Option<&'a AtomicBool>,
+}
+
+impl<'a> CommandContext<'a> {
+    /// Positionals and flags of the line, split per the matched spec.
+    #[must_use]
+    pub fn args(&self) -> ParsedArguments<'a> {
+        parse_arguments(self.parts)
+    }
+}
+
+/// Future returned by a [`CommandHandler`].
+pub type CommandFuture<'a> = Pin<Box<dyn Future<Output = ExitStatus> + Send + 'a>>;
+
+/// Entry point of a built-in command or subcommand.
+pub type CommandHandler = for<'a> fn(CommandContext<'a>) -> CommandFuture<'a>;
+
+/// What a positional argument or flag value holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArgKind {
+    /// Manifest file discovered under the working directory.
+    ManifestPath,
+    /// Directory on disk.
+    Directory,
+    /// Any file or directory on disk.
+    Path,
+    /// Identifier of a task in the registry.
+    TaskId,
+    /// Task intent label (`command`, `ai-plan`, ...).
+    TaskIntent,
+    /// Identifier of a persisted local AI session.
+    AiSessionId,
+    /// Action of `/ai session` (`list`, `show`, ...).
+    AiSessionAction,
+    /// Configuration key accepted by `/config set`.
+    ConfigKey,
+    /// Value for the configuration key preceding it.
+    ConfigValue,
+    /// Free text; never completed.
+    Text,
+}
+
+/// Positional argument of a command or subcommand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArgSpec {
+    /// Placeholder shown in usage strings (`task-id`).
+    pub name: &'static str,
+    /// Value kind.
+    pub kind: ArgKind,
+}
+
+/// Flag of a command or subcommand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlagSpec {
+    /// Flag including its dashes (`--output`).
+    pub name: &'static str,
+    /// Kind of the value that follows the flag; `None` for switches.
+    pub value: Option<ArgKind>,
+    /// One-line description.
+    pub description: &'static str,
+}
+
+/// Subcommand of a [`CommandSpec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubcommandSpec {
+    /// Subcommand token (`apply`).
+    pub name: &'static str,
+    /// Free-text words that route straight to this subcommand (`planejar`
+    /// runs `/ai plan`).
+    pub aliases: &'static [&'static str],
+    /// One-line description.
+    pub description: &'static str,
+    /// Positional arguments in order.
+    pub args: &'static [ArgSpec],
+    /// Accepted flags.
+    pub flags: &'static [FlagSpec],
+    /// Operator scope required to run it; `None` inherits the command's.
+    pub scope: Option<&'static str>,
+    /// Handler; `None` falls back to the command's.
+    pub handler: Option<CommandHandler>,
+}
+
+/// Top-level slash command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandSpec {
+    /// Command including its slash (`/manifest`).
+    pub name: &'static str,
+    /// Bare words that also name the command in free text (`tasks`).
+    pub aliases: &'static [&'static str],
+    /// One-line description.
+    pub description: &'static str,
+    /// Positional arguments when the command has no subcommands.
+    pub args: &'static [ArgSpec],
+    /// Flags when the command has no subcommands.
+    pub flags: &'static [FlagSpec],
+    /// Subcommands, in the order they are listed.
+    pub subcommands: &'static [SubcommandSpec],
+    /// Operator scope required to run it.
+    pub scope: &'static str,
+    /// Handler for the bare command, usage and unknown subcommands; `None`
+    /// for plugin commands, which run through
+    /// [`CommandPlugin::execute_command`](crate::CommandPlugin::execute_command).
+    pub handler: Option<CommandHandler>,
+}
+
+impl SubcommandSpec {
+    /// Usage line under `command` (`/task watch <task-id>`).
+    #[must_use]
+    pub fn usage(&self, command: &str) -> String {
+        usage_line(&format!("{command} {}", self.name), self.args, self.flags)
+    }
+}
+
+impl CommandSpec {
+    /// Subcommand named `name` (case-insensitive).
+    #[must_use]
+    pub fn subcommand(&self, name: &str) -> Option<&'static SubcommandSpec> {
+        self.subcommands
+            .iter()
+            .find(|subcommand| subcommand.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Usage line of the command itself (`/render-async <manifest> [--output <dir>]`).
+    #[must_use]
+    pub fn usage(&self) -> String {
+        let head = if self.subcommands.is_empty() {
+            self.name.to_string()
+        } else {
+            format!("{} [subcommand]", self.name)
+        };
+        usage_line(&head, self.args, self.flags)
+    }
+
+    /// Handler for a command line whose subcommand token is `subcommand`.
+    ///
+    /// A matched subcommand's handler wins over the command's own.
+    #[must_use]
+    pub fn handler_for(&self, subcommand: Option<&str>) -> Option<CommandHandler> {
+        subcommand
+            .and_then(|name| self.subcommand(name))
+            .and_then(|subcommand| subcommand.handler)
+            .or(self.handler)
+    }
+
+    /// Scope required by `subcommand`, falling back to the command's own.
+    #[must_use]
+    pub fn scope_for(&self, subcommand: Option<&str>) -> &'static str {
+        subcommand
+            .and_then(|name| self.subcommand(name))
+            .and_then(|subcommand| subcommand.scope)
+            .unwrap_or(self.scope)
+    }
+}
+
+fn usage_line(head: &str, args: &[ArgSpec], flags: &[FlagSpec]) -> String {
+    let mut usage = head.to_string();
+    for arg in args {
+        usage.push_str(&format!(" <{}>", arg.name));
+    }
+    for flag in flags {
+        match flag.value {
+            Some(kind) => usage.push_str(&format!(" [{} <{}>]", flag.name, kind.placeholder())),
+            None => usage.push_str(&format!(" [{}]", flag.name)),
+        }
+    }
+    usage
+}
+
+impl ArgKind {
+    /// Whether a trailing argument of this kind spans the rest of the line.
+    #[must_use]
+    pub const fn takes_rest(self) -> bool {
+        matches!(self, Self::Text | Self::ConfigValue)
+    }
+
+    /// Placeholder used for flag values in usage lines.
+    #[must_use]
+    pub const fn placeholder(self) -> &'static str {
+        match self {
+            Self::ManifestPath => "manifest",
+            Self::Directory => "dir",
+            Self::Path => "path",
+            Self::TaskId => "task-id",
+            Self::TaskIntent => "intent",
+            Self::AiSessionId => "session-id",
+            Self::AiSessionAction => "action",
+            Self::ConfigKey => "key",
+            Self::ConfigValue => "value",
+            Self::Text => "text",
+        }
+    }
+}
+
+/// Configuration key accepted by `/config set` and `/config unset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigKeySpec {
+    /// Canonical key name.
+    pub name: &'static str,
+    /// Accepted values when the key is an enum or boolean; empty for free values.
+    pub values: &'static [&'static str],
+}
+
+const BOOL_VALUES: &[&str] = &["true", "false"];
+const DISPLAY_MODE_VALUES: &[&str] = &["auto", "always", "never"];
+
+/// Values accepted by `log_level`.
+pub const LOG_LEVEL_VALUES: &[&str] = &["off", "error", "warn", "info", "debug", "trace"];
+/// Values accepted by `runtime_mode`.
+pub const RUNTIME_MODE_VALUES: &[&str] = &["cli", "service"];
+/// Actions accepted by `/ai session`.
+pub const AI_SESSION_ACTIONS: &[&str] = &["list", "show", "search", "export", "rename", "delete"];
+
+/// Task intent accepted by `/task submit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskIntentSpec {
+    /// Canonical intent label (`ai-plan`).
+    pub label: &'static str,
+    /// Tool capabilities the intent needs by default (`ai.plan`).
+    pub tools: &'static [&'static str],
+}
+
+/// Intents accepted by `/task submit`, in display order.
+pub const TASK_INTENTS: &[TaskIntentSpec] = &[
+    TaskIntentSpec {
+        label: "command",
+        tools: &["task.command.execute"],
+    },
+    TaskIntentSpec {
+        label: "ai-ask",
+        tools: &["ai.ask"],
+    },
+    TaskIntentSpec {
+        label: "ai-plan",
+        tools: &["ai.plan"],
+    },
+    TaskIntentSpec {
+        label: "ai-explain",
+        tools: &["ai.explain"],
+    },
+    TaskIntentSpec {
+        label: "ai-apply-dry-run",
+        tools: &["ai.apply.dry-run"],
+    },
+    TaskIntentSpec {
+        label: "repo-workflow",
+        tools: &[
+            "repo.workflow.execute",
+            "repo.workflow.push",
+            "repo.workflow.pr",
+        ],
+    },
+];
+
+/// Intent spec labelled `label`.
+#[must_use]
+pub fn task_intent_spec(label: &str) -> Option<&'static TaskIntentSpec> {
+    TASK_INTENTS.iter().find(|intent| intent.label == label)
+}
+
+/// Comma-separated intent labels, for hints and usage output.
+#[must_use]
+pub fn task_intent_labels() -> String {
+    TASK_INTENTS
+        .iter()
+        .map(|intent| intent.label)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Keys accepted by `/config set`, in display order.
+pub const CONFIG_KEYS: &[ConfigKeySpec] = &[
+    ConfigKeySpec {
+        name: "verbose",
+        values: BOOL_VALUES,
+    },
+    ConfigKeySpec {
+        name: "log_level",
+        values: LOG_LEVEL_VALUES,
+    },
+    ConfigKeySpec {
+        name: "footer_output",
+        values: BOOL_VALUES,
+    },
+    ConfigKeySpec {
+        name: "runtime_mode",
+        values: RUNTIME_MODE_VALUES,
+    },
+    ConfigKeySpec {
+        name: "attention_bell",
+        values: BOOL_VALUES,
+    },
+    ConfigKeySpec {
+        name: "attention_desktop_notification",
+        values: BOOL_VALUES,
+    },
+    ConfigKeySpec {
+        name: "attention_unfocused_only",
+        values: BOOL_VALUES,
+    },
+    ConfigKeySpec {
+        name: "predictive_input",
+        values: BOOL_VALUES,
+    },
+    ConfigKeySpec {
+        name: "ai_session_retention",
+        values: &[],
+    },
+    ConfigKeySpec {
+        name: "service_endpoint",
+        values: &[],
+    },
+    ConfigKeySpec {
+        name: "color",
+        values: DISPLAY_MODE_VALUES,
+    },
+    ConfigKeySpec {
+        name: "unicode",
+        values: DISPLAY_MODE_VALUES,
+    },
+    ConfigKeySpec {
+        name: "template_dir",
+        values: &[],
+    },
+    ConfigKeySpec {
+        name: "shell",
+        values: &[],
+    },
+];
+
+/// General keys followed by the dotted policy keys (`chatops.enabled`).
+pub fn config_keys() -> impl Iterator<Item = ConfigKeySpec> {
+    CONFIG_KEYS
+        .iter()
+        .copied()
+        .chain(POLICY_SETTINGS.iter().map(|setting| ConfigKeySpec {
+            name: setting.key,
+            values: setting.kind.values(),
+        }))
+}
+
+/// Config key named `name`, accepting `-` for `_` (`log-level`).
+#[must_use]
+pub fn config_key_spec(name: &str) -> Option<ConfigKeySpec> {
+    let normalized = name.trim().to_ascii_lowercase().replace('-', "_");
+    config_keys().find(|spec| spec.name == normalized)
+}
+
+const MANIFEST_ARG: &[ArgSpec] = &[ArgSpec {
+    name: "manifest",
+    kind: ArgKind::ManifestPath,
+}];
+const TASK_ID_ARG: &[ArgSpec] = &[ArgSpec {
+    name: "task-id",
+    kind: ArgKind::TaskId,
+}];
+const TASK_DECISION_ARGS: &[ArgSpec] = &[
+    ArgSpec {
+        name: "task-id",
+        kind: ArgKind::TaskId,
+    },
+    ArgSpec {
+        name: "note",
+        kind: ArgKind::Text,
+    },
+];
+const PROMPT_ARG: &[ArgSpec] = &[ArgSpec {
+    name: "prompt",
+    kind: ArgKind::Text,
+}];
+const CONFIG_KEY_ARG: &[ArgSpec] = &[ArgSpec {
+    name: "key",
+    kind: ArgKind::ConfigKey,
+}];
+
+const OUTPUT_DIR_FLAG: FlagSpec = FlagSpec {
+    name: "--output",
+    value: Some(ArgKind::Directory),
+    description: "Output root directory",
+};
+const DRY_RUN_FLAG: FlagSpec = FlagSpec {
+    name: "--dry-run",
+    value: None,
+    description: "Preview without writing files",
+};
+const RENDER_FLAGS: &[FlagSpec] = &[OUTPUT_DIR_FLAG];
+const APPLY_FLAGS: &[FlagSpec] = &[DRY_RUN_FLAG, OUTPUT_DIR_FLAG];
+const AI_APPLY_FLAGS: &[FlagSpec] = &[
+    DRY_RUN_FLAG,
+    FlagSpec {
+        name: "--approve-write",
+        value: None,
+        description: "Explicitly approve mutating apply intent",
+    },
+];
+const AI_SESSION_FLAGS: &[FlagSpec] = &[
+    FlagSpec {
+        name: "--format",
+        value: Some(ArgKind::Text),
+        description: "Export format: md or json",
+    },
+    FlagSpec {
+        name: "--output",
+        value: Some(ArgKind::Path),
+        description: "Write the export to a file",
+    },
+];
+const AI_FORK_FLAGS: &[FlagSpec] = &[FlagSpec {
+    name: "--at",
+    value: Some(ArgKind::Text),
+    description: "Keep exchanges up to and including n",
+}];
+const AI_USAGE_FLAGS: &[FlagSpec] = &[
+    FlagSpec {
+        name: "--since",
+        value: Some(ArgKind::Text),
+        description: "Report window such as 24h, 7d or 30d",
+    },
+    FlagSpec {
+        name: "--by",
+        value: Some(ArgKind::Text),
+        description: "Group by day, model, intent, operator or session",
+    },
+];
+
+const MANIFEST_SUBCOMMANDS: &[SubcommandSpec] = &[
+    SubcommandSpec {
+        name: "list",
+        aliases: &[],
+        description: "Discover available manifests in the workspace",
+        args: &[],
+        flags: &[],
+        scope: None,
+        handler: Some(manifest::manifest_list_command),
+    },
+    SubcommandSpec {
+        name: "check",
+        aliases: &[],
+        description: "Validate manifest structure and dependencies",
+        args: MANIFEST_ARG,
+        flags: &[FlagSpec {
+            name: "--template",
+            value: None,
+            description: "Validate a template instead of a manifest",
+        }],
+        scope: None,
+        handler: Some(manifest::manifest_check_command),
+    },
+    SubcommandSpec {
+        name: "render",
+        aliases: &[],
+        description: "Preview generated files without creating them",
+        args: MANIFEST_ARG,
+        flags: RENDER_FLAGS,
+        scope: None,
+        handler: Some(manifest::manifest_render_command),
+    },
+    SubcommandSpec {
+        name: "render-async",
+        aliases: &[],
+        description: "Async preview with progress updates",
+        args: MANIFEST_ARG,
+        flags: RENDER_FLAGS,
+        scope: None,
+        handler: Some(manifest::render_async_command),
+    },
+    SubcommandSpec {
+        name: "apply",
+        aliases: &[],
+        description: "Apply manifest to generate/update project files",
+        args: MANIFEST_ARG,
+        flags: APPLY_FLAGS,
+        scope: None,
+        handler: Some(manifest::manifest_apply_command),
+    },
+    SubcommandSpec {
+        name: "apply-async",
+        aliases: &[],
+        description: "Async apply with progress updates",
+        args: MANIFEST_ARG,
+        flags: APPLY_FLAGS,
+        scope: None,
+        handler: Some(manifest::apply_async_command),
+    },
+    SubcommandSpec {
+        name: "new-async",
+        aliases: &[],
+        description: "Async scaffolding with progress updates",
+        args: MANIFEST_ARG,
+        flags: APPLY_FLAGS,
+        scope: None,
+        handler: Some(manifest::new_async_command),
+    },
+    SubcommandSpec {
+        name: "import",
+        aliases: &[],
+        description: "Draft a manifest from an existing C# codebase",
+        args: &[ArgSpec {
+            name: "dir",
+            kind: ArgKind::Directory,
+        }],
+        flags: &[FlagSpec {
+            name: "--output",
+            value: Some(ArgKind::Path),
+            description: "Manifest file to write",
+        }],
+        scope: None,
+        handler: Some(manifest::manifest_import_command),
+    },
+];
+
+const AI_SUBCOMMANDS: &[SubcommandSpec] = &[
+    SubcommandSpec {
+        name: "ask",
+        aliases: &["ask", "perguntar"],
+        description: "Ask the AI assistant",
+        args: PROMPT_ARG,
+        flags: &[],
+        scope: None,
+        handler: Some(ai::ai_prompt_command),
+    },
+    SubcommandSpec {
+        name: "plan",
+        aliases: &["plan", "planejar", "planeje"],
+        description: "Generate an implementation plan",
+        args: PROMPT_ARG,
+        flags: &[],
+        scope: None,
+        handler: Some(ai::ai_prompt_command),
+    },
+    SubcommandSpec {
+        name: "explain",
+        aliases: &["explain", "explicar", "explique"],
+        description: "Get a technical explanation",
+        args: PROMPT_ARG,
+        flags: &[],
+        scope: None,
+        handler: Some(ai::ai_prompt_command),
+    },
+    SubcommandSpec {
+        name: "context",
+        aliases: &[],
+        description: "Preview the workspace context sent with a prompt",
+        args: PROMPT_ARG,
+        flags: &[],
+        scope: None,
+        handler: Some(ai::ai_context_command),
+    },
+    SubcommandSpec {
+        name: "resume",
+        aliases: &[],
+        description: "Set the active local AI session",
+        args: &[ArgSpec {
+            name: "session-id",
+            kind: ArgKind::AiSessionId,
+        }],
+        flags: &[],
+        scope: None,
+        handler: Some(ai::ai_resume_command),
+    },
+    SubcommandSpec {
+        name: "session",
+        aliases: &[],
+        description: "List, show, search, export, rename or delete local AI sessions",
+        args: &[
+            ArgSpec {
+                name: "action",
+                kind: ArgKind::AiSessionAction,
+            },
+            ArgSpec {
+                name: "session-id",
+                kind: ArgKind::AiSessionId,
+            },
+        ],
+        flags: AI_SESSION_FLAGS,
+        scope: None,
+        handler: Some(ai::ai_session_command),
+    },
+    SubcommandSpec {
+        name: "fork",
+        aliases: &[],
+        description: "Branch a local AI session from an earlier exchange",
+        args: &[ArgSpec {
+            name: "session-id",
+            kind: ArgKind::AiSessionId,
+        }],
+        flags: AI_FORK_FLAGS,
+        scope: None,
+        handler: Some(ai::ai_fork_command),
+    },
+    SubcommandSpec {
+        name: "usage",
+        aliases: &[],
+        description: "Report AI spend from the local ledger",
+        args: &[],
+        flags: AI_USAGE_FLAGS,
+        scope: None,
+        handler: Some(ai::ai_usage_command),
+    },
+    SubcommandSpec {
+        name: "apply",
+        aliases: &[],
+        description: "Generate patch guidance (dry-run or approved write)",
+        args: &[ArgSpec {
+            name: "instruction",
+            kind: ArgKind::Text,
+        }],
+        flags: AI_APPLY_FLAGS,
+        scope: None,
+        handler: Some(ai::ai_prompt_command),
+    },
+    SubcommandSpec {
+        name: "manifest",
+        aliases: &[],
+        description: "Propose a validated patch to the nearest manifest",
+        args: &[ArgSpec {
+            name: "instruction",
+            kind: ArgKind::Text,
+        }],
+        flags: &[],
+        scope: None,
+        handler: Some(ai::ai_manifest_command),
+    },
+];
+
+const TASK_SUBCOMMANDS: &[SubcommandSpec] = &[
+    SubcommandSpec {
+        name: "submit",
+        aliases: &[],
+        description: "Submit a task for managed execution",
+        args: &[
+            ArgSpec {
+                name: "intent",
+                kind: ArgKind::TaskIntent,
+            },
+            ArgSpec {
+                name: "payload",
+                kind: ArgKind::Text,
+            },
+        ],
+        flags: &[],
+        scope: Some("task.submit"),
+        handler: Some(task::task_submit_command),
+    },
+    SubcommandSpec {
+        name: "list",
+        aliases: &[],
+        description: "List task records",
+        args: &[],
+        flags: &[],
+        scope: None,
+        handler: Some(task::task_list_command),
+    },
+    SubcommandSpec {
+        name: "watch",
+        aliases: &[],
+        description: "Inspect task status and details",
+        args: TASK_ID_ARG,
+        flags: &[],
+        scope: None,
+        handler: Some(task::task_watch_command),
+    },
+    SubcommandSpec {
+        name: "top",
+        aliases: &[],
+        description: "Live task dashboard",
+        args: &[],
+        flags: &[],
+        scope: None,
+        handler: Some(task::task_top_command),
+    },
+    SubcommandSpec {
+        name: "cancel",
+        aliases: &[],
+        description: "Cancel a queued or running task",
+        args: TASK_ID_ARG,
+        flags: &[],
+        scope: None,
+        handler: Some(task::task_cancel_command),
+    },
+    SubcommandSpec {
+        name: "approvals",
+        aliases: &[],
+        description: "List tasks awaiting approval",
+        args: &[],
+        flags: &[],
+        scope: None,
+        handler: Some(task::task_approvals_command),
+    },
+    SubcommandSpec {
+        name: "approve",
+        aliases: &[],
+        description: "Approve a task awaiting approval",
+        args: TASK_DECISION_ARGS,
+        flags: &[],
+        scope: Some("task.approve"),
+        handler: Some(task::task_approve_command),
+    },
+    SubcommandSpec {
+        name: "reject",
+        aliases: &[],
+        description: "Reject a task awaiting approval",
+        args: TASK_DECISION_ARGS,
+        flags: &[],
+        scope: Some("task.approve"),
+        handler: Some(task::task_reject_command),
+    },
+];
+
+const CACHE_SUBCOMMANDS: &[SubcommandSpec] = &[
+    SubcommandSpec {
+        name: "stats",
+        aliases: &[],
+        description: "Show memory and persistent cache counters",
+        args: &[],
+        flags: &[],
+        scope: None,
+        handler: Some(cache::cache_stats_command),
+    },
+    SubcommandSpec {
+        name: "clear",
+        aliases: &[],
+        description: "Drop every cached entry, on disk included",
+        args: &[],
+        flags: &[],
+        scope: None,
+        handler: Some(cache::cache_clear_command),
+    },
+];
+
+const CONFIG_SUBCOMMANDS: &[SubcommandSpec] = &[
+    SubcommandSpec {
+        name: "show",
+        aliases: &[],
+        description: "Show the effective configuration",
+        args: &[],
+        flags: &[FlagSpec {
+            name: "--origin",
+            value: None,
+            description: "Show which layer each value comes from",
+        }],
+        scope: None,
+        handler: Some(config::config_show_command),
+    },
+    SubcommandSpec {
+        name: "path",
+        aliases: &[],
+        description: "Show the configuration file path",
+        args: &[],
+        flags: &[],
+        scope: None,
+        handler: Some(config::config_path_command),
+    },
+    SubcommandSpec {
+        name: "init",
+        aliases: &[],
+        description: "Write a default configuration file",
+        args: &[],
+        flags: &[],
+        scope: None,
+        handler: Some(config::config_init_command),
+    },
+    SubcommandSpec {
+        name: "set",
+        aliases: &[],
+        description: "Set a configuration key",
+        args: &[
+            ArgSpec {
+                name: "key",
+                kind: ArgKind::ConfigKey,
+            },
+            ArgSpec {
+                name: "value",
+                kind: ArgKind::ConfigValue,
+            },
+        ],
+        flags: &[],
+        scope: None,
+        handler: Some(config::config_set_command),
+    },
+    SubcommandSpec {
+        name: "unset",
+        aliases: &[],
+        description: "Reset a configuration key to its default",
+        args: CONFIG_KEY_ARG,
+        flags: &[],
+        scope: None,
+        handler: Some(config::config_unset_command),
+    },
+    SubcommandSpec {
+        name: "reset",
+        aliases: &[],
+        description: "Reset the whole configuration",
+        args: &[],
+        flags: &[],
+        scope: None,
+        handler: Some(config::config_reset_command),
+    },
+];
+
+const COMMAND_SPECS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "/help",
+        aliases: &["ajuda"],
+        description: "Display help information and available commands",
+        args: &[],
+        flags: &[],
+        subcommands: &[],
+        scope: "help",
+        handler: Some(help::help_command),
+    },
+    CommandSpec {
+        name: "/manifest",
+        aliases: &["manifests"],
+        description: "Manage and apply manifests",
+        args: &[],
+        flags: &[],
+        subcommands: MANIFEST_SUBCOMMANDS,
+        scope: "manifest",
+        handler: Some(manifest::manifest_command),
+    },
+    CommandSpec {
+        name: "/render-async",
+        aliases: &[],
+        description: "Async render preview with progress",
+        args: MANIFEST_ARG,
+        flags: RENDER_FLAGS,
+        subcommands: &[],
+        scope: "manifest",
+        handler: Some(manifest::render_async_command),
+    },
+    CommandSpec {
+        name: "/apply-async",
+        aliases: &[],
+        description: "Async apply with progress",
+        args: MANIFEST_ARG,
+        flags: APPLY_FLAGS,
+        subcommands: &[],
+        scope: "manifest",
+        handler: Some(manifest::apply_async_command),
+    },
+    CommandSpec {
+        name: "/new-async",
+        aliases: &[],
+        description: "Async scaffolding alias",
+        args: MANIFEST_ARG,
+        flags: APPLY_FLAGS,
+        subcommands: &[],
+        scope: "manifest",
+        handler: Some(manifest::new_async_command),
+    },
+    CommandSpec {
+        name: "/ai",
+        aliases: &["assistant", "copilot"],
+        description: "AI assistant commands",
+        args: &[],
+        flags: &[],
+        subcommands: AI_SUBCOMMANDS,
+        scope: "ai",
+        handler: Some(ai::ai_command),
+    },
+    CommandSpec {
+        name: "/task",
+        aliases: &["tasks", "tarefa", "tarefas"],
+        description: "Task manager commands",
+        args: &[],
+        flags: &[],
+        subcommands: TASK_SUBCOMMANDS,
+        scope: "task.manage",
+        handler: Some(task::task_command),
+    },
+    CommandSpec {
+        name: "/config",
+        aliases: &["configuracao", "configuração", "settings"],
+        description: "View and edit user configuration",
+        args: &[],
+        flags: &[],
+        subcommands: CONFIG_SUBCOMMANDS,
+        scope: "config",
+        handler: Some(config::config_command),
+    },
+    CommandSpec {
+        name: "/cache",
+        aliases: &[],
+        description: "Inspect or clear the command-result cache",
+        args: &[],
+        flags: &[],
+        subcommands: CACHE_SUBCOMMANDS,
+        scope: "cache",
+        handler: Some(cache::cache_command),
+    },
+    CommandSpec {
+        name: "/clear",
+        aliases: &["cls", "limpar"],
+        description: "Clear and redraw the interactive terminal layout",
+        args: &[],
+        flags: &[],
+        subcommands: &[],
+        scope: "session",
+        handler: Some(session::clear_command),
+    },
+    CommandSpec {
+        name: "/quit",
+        aliases: &[],
+        description: "Exit NetToolsKit CLI",
+        args: &[],
+        flags: &[],
+        subcommands: &[],
+        scope: "session",
+        handler: Some(session::quit_command),
+    },
+];
+
+/// Every slash command known to the processor.
+#[must_use]
+pub fn command_specs() -> &'static [CommandSpec] {
+    COMMAND_SPECS
+}
+
+/// Command named `name`, with or without its leading slash (case-insensitive).
+#[must_use]
+pub fn find_command_spec(name: &str) -> Option<&'static CommandSpec> {
+    let bare = name.trim().trim_start_matches('/');
+    COMMAND_SPECS
+        .iter()
+        .find(|spec| spec.name[1..].eq_ignore_ascii_case(bare))
+}
+
+/// Command that free-text `word` names, by slash name or alias (`tarefas`).
+#[must_use]
+pub fn find_command_by_alias(word: &str) -> Option<&'static CommandSpec> {
+    let word = word.trim().trim_start_matches('/');
+    COMMAND_SPECS.iter().find(|spec| {
+        spec.name[1..].eq_ignore_ascii_case(word)
+            || spec
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(word))
+    })
+}
+
+/// Subcommand that free-text `word` routes to directly (`planejar` → `/ai plan`).
+#[must_use]
+pub fn find_subcommand_shortcut(
+    word: &str,
+) -> Option<(&'static CommandSpec, &'static SubcommandSpec)> {
+    COMMAND_SPECS.iter().find_map(|spec| {
+        spec.subcommands
+            .iter()
+            .find(|subcommand| {
+                subcommand
+                    .aliases
+                    .iter()
+                    .any(|alias| alias.eq_ignore_ascii_case(word))
+            })
+            .map(|subcommand| (spec, subcommand))
+    })
+}
+
+/// Positional arguments and flag values of one command line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedArguments<'a> {
+    /// Tokens that are neither flags nor flag values, in order.
+    pub positionals: Vec<&'a str>,
+    /// Flags in order, with the value that followed value-taking ones.
+    pub flags: Vec<(&'a str, Option<&'a str>)>,
+}
+
+impl<'a> ParsedArguments<'a> {
+    /// Whether `flag` was given.
+    #[must_use]
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|(name, _)| *name == flag)
+    }
+
+    /// Value given to `flag`, when present.
+    #[must_use]
+    pub fn flag_value(&self, flag: &str) -> Option<&'a str> {
+        self.flags
+            .iter()
+            .find(|(name, _)| *name == flag)
+            .and_then(|(_, value)| *value)
+    }
+
+    /// Positional `index`, when present.
+    #[must_use]
+    pub fn positional(&self, index: usize) -> Option<&'a str> {
+        self.positionals.get(index).copied()
+    }
+
+    /// Positionals from `index` on, joined by spaces (free-text arguments).
+    #[must_use]
+    pub fn text_from(&self, index: usize) -> String {
+        self.positionals.get(index..).unwrap_or_default().join(" ")
+    }
+}
+
+/// Split `parts` (the whitespace-separated command line) per the spec of the
+/// command and subcommand it names.
+///
+/// The command and subcommand tokens are not included in the result, and a
+/// leading lone `/` (`/ manifest apply`) is skipped. See [`split_arguments`]
+/// for how the remaining tokens are split.
+#[must_use]
+pub fn parse_arguments<'a>(parts: &[&'a str]) -> ParsedArguments<'a> {
+    let parts = match parts.first() {
+        Some(&"/") => &parts[1..],
+        _ => parts,
+    };
+    let Some(command) = parts.first().and_then(|name| find_command_spec(name)) else {
+        return split_arguments(parts.get(1..).unwrap_or_default(), &[], &[]);
+    };
+    match parts.get(1).and_then(|name| command.subcommand(name)) {
+        Some(subcommand) => split_arguments(&parts[2..], subcommand.args, subcommand.flags),
+        None => split_arguments(&parts[1..], command.args, command.flags),
+    }
+}
+
+/// Split `tokens` into positionals and flags per `args` and `flags`.
+///
+/// Flags declared with a value consume the following token unless it is
+/// itself a flag; unknown flags are kept as switches. When the last of `args`
+/// [takes the rest of the line](ArgKind::takes_rest) and the spec takes no
+/// flags, that argument keeps the remaining tokens verbatim, `--` tokens
+/// included (`/task submit command /manifest apply app.yml --dry-run`).
+#[must_use]
+pub fn split_arguments<'a>(
+    tokens: &[&'a str],
+    args: &[ArgSpec],
+    flags: &[FlagSpec],
+) -> ParsedArguments<'a> {
+    let text_from = match args.last() {
+        Some(last) if last.kind.takes_rest() && flags.is_empty() => Some(args.len() - 1),
+        _ => None,
+    };
+    let mut parsed = ParsedArguments::default();
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = tokens[index];
+        index += 1;
+        if text_from.is_some_and(|start| parsed.positionals.len() >= start) {
+            parsed.positionals.extend_from_slice(&tokens[index - 1..]);
+            break;
+        }
+        if !token.starts_with("--") {
+            parsed.positionals.push(token);
+            continue;
+        }
+        let takes_value = flags
+            .iter()
+            .any(|flag| flag.name == token && flag.value.is_some());
+        let value = tokens
+            .get(index)
+            .copied()
+            .filter(|next| takes_value && !next.starts_with("--"));
+        if value.is_some() {
+            index += 1;
+        }
+        parsed.flags.push((token, value));
+    }
+    parsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::MainAction;
+    use nettoolskit_core::MenuEntry;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn every_main_action_has_a_spec() {
+        for action in MainAction::iter() {
+            assert!(
+                find_command_spec(action.label()).is_some(),
+                "missing spec for {}",
+                action.label()
+            );
+        }
+    }
+
+    #[test]
+    fn find_command_spec_accepts_bare_and_slashed_names() {
+        assert_eq!(
+            find_command_spec("manifest").map(|spec| spec.name),
+            Some("/manifest")
+        );
+        assert_eq!(
+            find_command_spec("/TASK").map(|spec| spec.name),
+            Some("/task")
+        );
+        assert!(find_command_spec("/unknown").is_none());
+    }
+
+    #[test]
+    fn parse_arguments_skips_values_of_value_flags() {
+        let parts = [
+            "/manifest",
+            "apply",
+            "--output",
+            "./out",
+            "app.yml",
+            "--dry-run",
+        ];
+        let parsed = parse_arguments(&parts);
+
+        assert_eq!(parsed.positionals, vec!["app.yml"]);
+        assert_eq!(parsed.flag_value("--output"), Some("./out"));
+        assert!(parsed.has_flag("--dry-run"));
+    }
+
+    #[test]
+    fn parse_arguments_treats_switches_as_valueless() {
+        let parsed = parse_arguments(&["/", "ai", "apply", "--dry-run", "add", "tests"]);
+
+        assert_eq!(parsed.positionals, vec!["add", "tests"]);
+        assert_eq!(parsed.flags, vec![("--dry-run", None)]);
+    }
+
+    #[test]
+    fn parse_arguments_keeps_trailing_text_verbatim() {
+        let parsed = parse_arguments(&[
+            "/task",
+            "submit",
+            "command",
+            "/manifest",
+            "apply",
+            "app.yml",
+            "--dry-run",
+        ]);
+
+        assert_eq!(parsed.positional(0), Some("command"));
+        assert_eq!(parsed.text_from(1), "/manifest apply app.yml --dry-run");
+        assert!(parsed.flags.is_empty());
+    }
+
+    #[test]
+    fn parse_arguments_reads_top_level_alias_arguments() {
+        let parsed = parse_arguments(&["/render-async", "app.yml", "--output", "./out"]);
+
+        assert_eq!(parsed.positionals, vec!["app.yml"]);
+        assert_eq!(parsed.flag_value("--output"), Some("./out"));
+    }
+
+    #[test]
+    fn usage_lines_list_arguments_and_flags() {
+        let manifest = find_command_spec("/manifest").expect("manifest spec");
+        let apply = manifest.subcommand("apply").expect("apply spec");
+        assert_eq!(
+            apply.usage(manifest.name),
+            "/manifest apply <manifest> [--dry-run] [--output <dir>]"
+        );
+        let render = find_command_spec("/render-async").expect("alias spec");
+        assert_eq!(render.usage(), "/render-async <manifest> [--output <dir>]");
+    }
+
+    #[test]
+    fn scopes_fall_back_to_the_command_scope() {
+        let task = find_command_spec("/task").expect("task spec");
+        assert_eq!(task.scope_for(Some("submit")), "task.submit");
+        assert_eq!(task.scope_for(Some("watch")), "task.manage");
+        assert_eq!(task.scope_for(None), "task.manage");
+    }
+
+    #[test]
+    fn aliases_resolve_commands_and_shortcuts() {
+        assert_eq!(
+            find_command_by_alias("tarefas").map(|spec| spec.name),
+            Some("/task")
+        );
+        assert_eq!(
+            find_command_by_alias("config").map(|spec| spec.name),
+            Some("/config")
+        );
+        let (command, subcommand) = find_subcommand_shortcut("planejar").expect("shortcut");
+        assert_eq!((command.name, subcommand.name), ("/ai", "plan"));
+        assert!(find_subcommand_shortcut("list").is_none());
+    }
+
+    #[test]
+    fn config_key_spec_normalizes_dashes() {
+        let spec = config_key_spec("log-level").expect("known key");
+        assert_eq!(spec.values, LOG_LEVEL_VALUES);
+        assert!(config_key_spec("nope").is_none());
+
+        let policy = config_key_spec("chatops.rate-limit-strategy").expect("policy key");
+        assert_eq!(policy.values, ["fixed_window", "token_bucket"]);
+    }
+}
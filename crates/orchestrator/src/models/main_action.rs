@@ -31,6 +31,10 @@ pub enum MainAction {
     #[strum(serialize = "config")]
     Config,
 
+    /// Inspect or clear the command-result cache
+    #[strum(serialize = "cache")]
+    Cache,
+
     /// Clear and redraw the interactive terminal layout
     #[strum(serialize = "clear")]
     Clear,
@@ -56,6 +60,7 @@ impl MainAction {
                 "Task manager commands (submit, list, watch, cancel) with local fallback"
             }
             MainAction::Config => "View and edit user configuration",
+            MainAction::Cache => "Inspect or clear the command-result cache (stats, clear)",
             MainAction::Clear => "Clear and redraw the interactive terminal layout",
             MainAction::Quit => "Exit NetToolsKit CLI",
         }
@@ -70,6 +75,7 @@ impl MenuEntry for MainAction {
             MainAction::Ai => "/ai",
             MainAction::Task => "/task",
             MainAction::Config => "/config",
+            MainAction::Cache => "/cache",
             MainAction::Clear => "/clear",
             MainAction::Quit => "/quit",
         }
@@ -2,8 +2,16 @@
 //!
 //! This module organizes all models used for command management
 
+pub mod command_spec;
 pub mod main_action;
 
 // Re-export canonical names only
+pub use command_spec::{
+    command_specs, config_key_spec, config_keys, find_command_by_alias, find_command_spec,
+    find_subcommand_shortcut, parse_arguments, split_arguments, task_intent_labels,
+    task_intent_spec, ArgKind, ArgSpec, CommandContext, CommandFuture, CommandHandler, CommandSpec,
+    ConfigKeySpec, FlagSpec, ParsedArguments, SubcommandSpec, TaskIntentSpec, CONFIG_KEYS,
+    TASK_INTENTS,
+};
 pub use main_action::{get_main_action, MainAction};
 pub use nettoolskit_core::ExitStatus;
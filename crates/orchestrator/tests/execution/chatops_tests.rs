@@ -1,16 +1,18 @@
 //! ChatOps public API integration tests.
 
+use std::collections::VecDeque;
 use std::sync::{
     atomic::{AtomicUsize, Ordering},
-    Arc,
+    Arc, Mutex,
 };
 use std::time::Duration;
 
 use nettoolskit_core::IngressTransport;
+use nettoolskit_orchestrator::execution::headless;
 use nettoolskit_orchestrator::{
-    build_chatops_runtime, execute_chatops_envelope, parse_chatops_intent,
+    build_chatops_runtime, execute_chatops_envelope, parse_chatops_intent, ChatOpsAuditKind,
     ChatOpsAuthorizationPolicy, ChatOpsCommandEnvelope, ChatOpsIntent, ChatOpsLocalAuditStore,
-    ChatOpsPlatform, ChatOpsRuntimeConfig, RecordingChatOpsNotifier,
+    ChatOpsPlatform, ChatOpsRuntimeConfig, ConsoleMode, RecordingChatOpsNotifier,
 };
 use serial_test::serial;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
@@ -46,10 +48,15 @@ impl Drop for EnvVarGuard {
     }
 }
 
+const TELEGRAM_LIST_UPDATE: &str = r#"{"update_id":101,"message":{"date":1737200000,"text":"list","chat":{"id":555},"from":{"id":777}}}"#;
+
 #[derive(Clone, Default)]
 struct TelegramMockState {
     updates_calls: Arc<AtomicUsize>,
     send_message_calls: Arc<AtomicUsize>,
+    answer_callback_calls: Arc<AtomicUsize>,
+    pending_updates: Arc<Mutex<VecDeque<String>>>,
+    sent_messages: Arc<Mutex<Vec<serde_json::Value>>>,
 }
 
 impl TelegramMockState {
@@ -60,6 +67,54 @@ impl TelegramMockState {
     fn send_message_calls(&self) -> usize {
         self.send_message_calls.load(Ordering::SeqCst)
     }
+
+    fn answer_callback_calls(&self) -> usize {
+        self.answer_callback_calls.load(Ordering::SeqCst)
+    }
+
+    /// Queue one update to be returned by the next `getUpdates` call.
+    fn push_update(&self, update: impl Into<String>) {
+        self.pending_updates
+            .lock()
+            .expect("pending updates lock")
+            .push_back(update.into());
+    }
+
+    fn sent_messages(&self) -> Vec<serde_json::Value> {
+        self.sent_messages
+            .lock()
+            .expect("sent messages lock")
+            .clone()
+    }
+}
+
+/// Read one HTTP request, waiting for the full body announced by `Content-Length`.
+async fn read_http_request(stream: &mut tokio::net::TcpStream) -> Option<String> {
+    let mut raw = Vec::new();
+    let mut buffer = [0_u8; 8 * 1024];
+    loop {
+        let bytes_read = stream.read(&mut buffer).await.ok()?;
+        if bytes_read == 0 {
+            break;
+        }
+        raw.extend_from_slice(&buffer[..bytes_read]);
+        let text = String::from_utf8_lossy(&raw);
+        if let Some((head, body)) = text.split_once("\r\n\r\n") {
+            let content_length = head
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().ok())
+                        .flatten()
+                })
+                .unwrap_or(0);
+            if body.len() >= content_length {
+                break;
+            }
+        }
+    }
+    (!raw.is_empty()).then(|| String::from_utf8_lossy(&raw).into_owned())
 }
 
 async fn spawn_telegram_mock_server() -> (
@@ -75,6 +130,7 @@ async fn spawn_telegram_mock_server() -> (
         .local_addr()
         .expect("mock server should expose local address");
     let state = TelegramMockState::default();
+    state.push_update(TELEGRAM_LIST_UPDATE);
     let state_for_task = state.clone();
     let (shutdown_tx, mut shutdown_rx) = oneshot::channel::<()>();
 
@@ -87,16 +143,9 @@ async fn spawn_telegram_mock_server() -> (
                         break;
                     };
 
-                    let mut buffer = [0_u8; 8 * 1024];
-                    let bytes_read = match stream.read(&mut buffer).await {
-                        Ok(bytes) => bytes,
-                        Err(_) => continue,
-                    };
-                    if bytes_read == 0 {
+                    let Some(request) = read_http_request(&mut stream).await else {
                         continue;
-                    }
-
-                    let request = String::from_utf8_lossy(&buffer[..bytes_read]);
+                    };
                     let request_line = request.lines().next().unwrap_or_default();
                     let mut parts = request_line.split_whitespace();
                     let method = parts.next().unwrap_or_default();
@@ -104,13 +153,15 @@ async fn spawn_telegram_mock_server() -> (
 
                     let (status_line, response_body) = match (method, path) {
                         ("GET", path) if path.starts_with("/bottest-token/getUpdates") => {
-                            let call_index =
-                                state_for_task.updates_calls.fetch_add(1, Ordering::SeqCst);
-                            let body = if call_index == 0 {
-                                r#"{"ok":true,"result":[{"update_id":101,"message":{"date":1737200000,"text":"list","chat":{"id":555},"from":{"id":777}}}]}"#
-                                    .to_string()
-                            } else {
-                                r#"{"ok":true,"result":[]}"#.to_string()
+                            state_for_task.updates_calls.fetch_add(1, Ordering::SeqCst);
+                            let next_update = state_for_task
+                                .pending_updates
+                                .lock()
+                                .expect("pending updates lock")
+                                .pop_front();
+                            let body = match next_update {
+                                Some(update) => format!(r#"{{"ok":true,"result":[{update}]}}"#),
+                                None => r#"{"ok":true,"result":[]}"#.to_string(),
                             };
                             ("200 OK", body)
                         }
@@ -118,8 +169,22 @@ async fn spawn_telegram_mock_server() -> (
                             state_for_task
                                 .send_message_calls
                                 .fetch_add(1, Ordering::SeqCst);
+                            let body = request.split_once("\r\n\r\n").map_or("", |(_, body)| body);
+                            if let Ok(body) = serde_json::from_str(body) {
+                                state_for_task
+                                    .sent_messages
+                                    .lock()
+                                    .expect("sent messages lock")
+                                    .push(body);
+                            }
                             ("200 OK", r#"{"ok":true,"result":{"message_id":1}}"#.to_string())
                         }
+                        ("POST", path) if path.starts_with("/bottest-token/answerCallbackQuery") => {
+                            state_for_task
+                                .answer_callback_calls
+                                .fetch_add(1, Ordering::SeqCst);
+                            ("200 OK", r#"{"ok":true,"result":true}"#.to_string())
+                        }
                         _ => (
                             "404 Not Found",
                             r#"{"ok":false,"description":"not found"}"#.to_string(),
@@ -266,6 +331,85 @@ async fn chatops_vps_smoke_profile_executes_poll_and_notification_flow() {
         .any(|entry| entry.internal_command.as_deref() == Some("/task list")));
 }
 
+#[tokio::test]
+#[serial]
+async fn chatops_telegram_approval_buttons_round_trip_through_callback_query() {
+    let _env_guard = EnvVarGuard::set(&[
+        (
+            "NTK_TOOL_SCOPE_ALLOWED_TOOLS",
+            Some("repo.workflow.execute,repo.workflow.push"),
+        ),
+        (
+            "NTK_TOOL_SCOPE_INTENT_REPO_WORKFLOW_TOOLS",
+            Some("repo.workflow.execute,repo.workflow.push"),
+        ),
+    ]);
+    let (telegram_api_base, mock_state, shutdown_tx, server_task) =
+        spawn_telegram_mock_server().await;
+    // Replace the default `list` update with a gated submission.
+    mock_state
+        .pending_updates
+        .lock()
+        .expect("pending updates lock")
+        .clear();
+    mock_state.push_update(
+        r#"{"update_id":201,"message":{"date":1737200000,"text":"submit repo-workflow repo=https://example.invalid/acme/app.git;branch=main;command=cargo test;push=true;dry_run=false","chat":{"id":555},"from":{"id":777}}}"#,
+    );
+
+    let config = ChatOpsRuntimeConfig {
+        enabled: true,
+        max_batch_size: 4,
+        allowed_user_ids: vec!["777".to_string(), "888".to_string()],
+        allowed_channel_ids: vec!["555".to_string()],
+        allowed_command_scopes: vec!["submit:repo-workflow".to_string(), "approve".to_string()],
+        telegram_bot_token: Some("test-token".to_string()),
+        telegram_api_base,
+        ..ChatOpsRuntimeConfig::default()
+    };
+    let runtime = build_chatops_runtime(config)
+        .expect("runtime build should succeed")
+        .expect("enabled runtime should be present");
+
+    let submitted = runtime.tick().await;
+    assert_eq!(submitted.executed_success, 1);
+    let prompt = mock_state
+        .sent_messages()
+        .into_iter()
+        .find(|message| message.get("reply_markup").is_some())
+        .expect("approval prompt should carry an inline keyboard");
+    let buttons = prompt["reply_markup"]["inline_keyboard"][0]
+        .as_array()
+        .expect("keyboard row")
+        .clone();
+    let approve_data = buttons[0]["callback_data"]
+        .as_str()
+        .expect("approve callback data")
+        .to_string();
+    let task_id = approve_data
+        .strip_prefix("ntk:approve:")
+        .expect("approve button should target the parked task")
+        .to_string();
+
+    // The submitter may not approve its own task, so a second operator clicks.
+    mock_state.push_update(format!(
+        r#"{{"update_id":202,"callback_query":{{"id":"cb-1","from":{{"id":888}},"data":"{approve_data}","message":{{"date":1737200000,"chat":{{"id":555}}}}}}}}"#
+    ));
+    let clicked = runtime.tick().await;
+    let _ = shutdown_tx.send(());
+    let _ = server_task.await;
+
+    assert_eq!(clicked.envelopes_received, 1);
+    assert_eq!(clicked.executed_success, 1);
+    assert_eq!(mock_state.answer_callback_calls(), 1);
+    let task = headless::get_task(&task_id, ConsoleMode::Silent)
+        .await
+        .expect("approved task should exist");
+    assert!(task
+        .audit_events
+        .iter()
+        .any(|event| event.message.starts_with("Approved by telegram:888")));
+}
+
 #[tokio::test]
 #[serial]
 async fn execute_chatops_envelope_submit_emits_control_plane_metadata_in_audit() {
@@ -327,3 +471,96 @@ async fn execute_chatops_envelope_submit_emits_control_plane_metadata_in_audit()
     );
     assert!(executed.task_id.as_deref().is_some());
 }
+
+#[tokio::test]
+#[serial]
+async fn chatops_submit_completion_is_posted_back_to_originating_channel() {
+    let _env_guard = EnvVarGuard::set(&[
+        ("NTK_TOOL_SCOPE_ALLOWED_TOOLS", Some("ai.plan")),
+        ("NTK_TOOL_SCOPE_INTENT_AI_PLAN_TOOLS", Some("ai.plan")),
+    ]);
+    let policy = ChatOpsAuthorizationPolicy::new_with_scopes(
+        vec!["777".to_string()],
+        vec!["555".to_string()],
+        vec!["list".to_string(), "submit:ai-plan".to_string()],
+    );
+    let notifier = RecordingChatOpsNotifier::new();
+    let envelope = ChatOpsCommandEnvelope::new(
+        ChatOpsPlatform::Telegram,
+        "555",
+        "777",
+        "submit ai-plan announce completion in chat",
+        1_737_200_000_333,
+    );
+
+    let status = execute_chatops_envelope(&envelope, &policy, &notifier, None)
+        .await
+        .expect("authorized chatops submit should execute");
+    assert_eq!(status.to_string(), "success");
+    assert_eq!(
+        notifier.snapshot().len(),
+        1,
+        "submission is acknowledged once"
+    );
+
+    let task_id = headless::list_tasks(ConsoleMode::Silent)
+        .await
+        .expect("local listing cannot fail")
+        .into_iter()
+        .find(|task| task.payload == "announce completion in chat")
+        .map(|task| task.id)
+        .expect("submitted task should be listed");
+    let mut finished = false;
+    for _ in 0..100 {
+        let task = headless::get_task(&task_id, ConsoleMode::Silent)
+            .await
+            .expect("task should exist");
+        if task.status.is_terminal() {
+            finished = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(finished, "background task should finish");
+
+    let (telegram_api_base, mock_state, shutdown_tx, server_task) =
+        spawn_telegram_mock_server().await;
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let audit_path = temp_dir.path().join("chatops-completion-audit.jsonl");
+    let runtime = build_chatops_runtime(ChatOpsRuntimeConfig {
+        enabled: true,
+        allowed_user_ids: policy.allowed_user_ids.clone(),
+        allowed_channel_ids: policy.allowed_channel_ids.clone(),
+        allowed_command_scopes: policy.allowed_command_scopes.clone(),
+        telegram_bot_token: Some("test-token".to_string()),
+        telegram_api_base,
+        audit_path: Some(audit_path.clone()),
+        ..ChatOpsRuntimeConfig::default()
+    })
+    .expect("runtime build should succeed")
+    .expect("enabled runtime should be present");
+
+    let summary = runtime.tick().await;
+    let _ = shutdown_tx.send(());
+    let _ = server_task.await;
+
+    assert_eq!(summary.task_notifications_sent, 1);
+    assert_eq!(summary.notification_errors, 0);
+    // One reply to the polled `list` command plus the completion notification.
+    assert_eq!(mock_state.send_message_calls(), 2);
+
+    let entries = ChatOpsLocalAuditStore::from_path(audit_path)
+        .load_latest(32)
+        .expect("audit entries should be readable");
+    let completion = entries
+        .iter()
+        .find(|entry| entry.task_id.as_deref() == Some(task_id.as_str()))
+        .expect("completion audit entry should exist");
+    assert_eq!(completion.kind, ChatOpsAuditKind::NotificationSent);
+    assert_eq!(completion.channel_id, "555");
+    assert_eq!(completion.operator_id.as_deref(), Some("telegram:777"));
+    assert!(completion.message_text.contains(&task_id));
+    assert!(completion
+        .note
+        .contains("task completion notification sent"));
+}
@@ -0,0 +1,335 @@
+//! Headless entrypoint tests
+//!
+//! Exercise the typed `ai`, `task`, `config` and `manifest` entrypoints used by
+//! scripted callers and verify they return structured results without console
+//! output.
+
+use nettoolskit_core::{IngressTransport, OperatorContext, OperatorKind, TaskExecutionStatus};
+use nettoolskit_orchestrator::execution::headless;
+use nettoolskit_orchestrator::{runtime_metrics, AiIntent, AiRunOptions, ConsoleMode, ExitStatus};
+use serial_test::serial;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Point the user config directory at `dir` for the duration of `body`.
+async fn with_config_home<F, Fut>(dir: &Path, body: F)
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    let previous_xdg = std::env::var_os("XDG_CONFIG_HOME");
+    let previous_appdata = std::env::var_os("APPDATA");
+    std::env::set_var("XDG_CONFIG_HOME", dir);
+    std::env::set_var("APPDATA", dir);
+
+    body().await;
+
+    match previous_xdg {
+        Some(value) => std::env::set_var("XDG_CONFIG_HOME", value),
+        None => std::env::remove_var("XDG_CONFIG_HOME"),
+    }
+    match previous_appdata {
+        Some(value) => std::env::set_var("APPDATA", value),
+        None => std::env::remove_var("APPDATA"),
+    }
+}
+
+#[tokio::test]
+async fn run_ai_returns_report_for_mock_provider() {
+    let report = headless::run_ai(
+        AiIntent::Explain,
+        "headless retry policy",
+        AiRunOptions::default(),
+        ConsoleMode::Silent,
+    )
+    .await
+    .expect("mock provider should answer");
+
+    assert_eq!(report.intent, "explain");
+    assert!(report.output.contains("headless retry policy"));
+    assert!(!report.session_id.is_empty());
+}
+
+#[tokio::test]
+async fn run_ai_apply_without_approval_is_rejected_with_hint() {
+    let failure = headless::run_ai(
+        AiIntent::ApplyDryRun,
+        "rewrite module",
+        AiRunOptions::default(),
+        ConsoleMode::Silent,
+    )
+    .await
+    .expect_err("apply without --dry-run or --approve-write must be blocked");
+
+    assert!(failure.message.contains("approval gateway"));
+    assert!(failure.hints.iter().any(|hint| hint.contains("--dry-run")));
+}
+
+#[tokio::test]
+async fn run_ai_rejects_empty_prompt() {
+    let failure = headless::run_ai(
+        AiIntent::Ask,
+        "   ",
+        AiRunOptions::default(),
+        ConsoleMode::Silent,
+    )
+    .await
+    .expect_err("empty prompt must fail");
+
+    assert_eq!(failure.message, "Missing prompt for ai ask.");
+}
+
+#[tokio::test]
+async fn submit_task_then_lookup_and_cancel_reports_terminal_state() {
+    let submitted = headless::submit_task("ai-ask", "headless lookup", ConsoleMode::Silent)
+        .await
+        .expect("task should be admitted");
+    assert_eq!(submitted.intent, "ai-ask");
+    assert_eq!(submitted.status, TaskExecutionStatus::Succeeded);
+
+    let fetched = headless::get_task(&submitted.id, ConsoleMode::Silent)
+        .await
+        .expect("task should be listed");
+    assert_eq!(fetched.id, submitted.id);
+    assert!(!fetched.audit_events.is_empty());
+
+    let listed = headless::list_tasks(ConsoleMode::Silent)
+        .await
+        .expect("local listing cannot fail");
+    assert!(listed.iter().any(|task| task.id == submitted.id));
+
+    let cancel = headless::cancel_task_by_id(&submitted.id, ConsoleMode::Silent)
+        .await
+        .expect_err("terminal task cannot be cancelled");
+    assert!(cancel.message.contains("already terminal"));
+}
+
+#[tokio::test]
+async fn get_task_reports_unknown_id() {
+    let failure = headless::get_task(" no-such-task ", ConsoleMode::Silent)
+        .await
+        .expect_err("unknown task must fail");
+
+    assert_eq!(failure.message, "Task not found: no-such-task");
+}
+
+#[tokio::test]
+async fn cancel_task_by_id_reports_unknown_id() {
+    let failure = headless::cancel_task_by_id("no-such-task", ConsoleMode::Silent)
+        .await
+        .expect_err("unknown task must fail");
+
+    assert_eq!(failure.message, "Task not found: no-such-task");
+}
+
+#[tokio::test]
+async fn list_tasks_includes_submitted_task_most_recent_first() {
+    let first = headless::submit_task("ai-ask", "list order one", ConsoleMode::Silent)
+        .await
+        .expect("task should be admitted");
+    let second = headless::submit_task("ai-ask", "list order two", ConsoleMode::Silent)
+        .await
+        .expect("task should be admitted");
+
+    let listed = headless::list_tasks(ConsoleMode::Silent)
+        .await
+        .expect("local listing cannot fail");
+
+    assert!(listed.iter().any(|task| task.id == first.id));
+    assert!(listed.iter().any(|task| task.id == second.id));
+    assert!(listed
+        .windows(2)
+        .all(|pair| pair[0].updated_at_unix_ms >= pair[1].updated_at_unix_ms));
+}
+
+#[tokio::test]
+#[serial]
+async fn config_set_get_and_unset_round_trip_through_config_file() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    with_config_home(home.path(), || async {
+        let set = headless::config_set("log_level", "warn")
+            .await
+            .expect("log_level should be settable");
+        assert_eq!(set.key, "log_level");
+        assert_eq!(set.value, "warn");
+        assert!(set.path.starts_with(home.path()));
+        assert!(set.path.is_file());
+
+        let fetched = headless::config_get("log-level")
+            .await
+            .expect("alias should resolve");
+        assert_eq!(fetched.value, "warn");
+        assert_eq!(fetched.path, set.path);
+
+        let unset = headless::config_unset("log_level")
+            .await
+            .expect("log_level should be resettable");
+        assert_eq!(unset.value, "info");
+    })
+    .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn config_entrypoints_reject_unknown_keys_and_invalid_values() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    with_config_home(home.path(), || async {
+        let unknown = headless::config_get("no_such_key")
+            .await
+            .expect_err("unknown key must fail");
+        assert!(unknown.message.starts_with("Invalid config value:"));
+        assert_eq!(unknown.hints.len(), 1);
+
+        let invalid = headless::config_set("log_level", "loud")
+            .await
+            .expect_err("invalid value must fail");
+        assert!(invalid.message.starts_with("Invalid config value:"));
+        assert!(!home.path().join("ntk").join("config.toml").exists());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn manifest_entrypoints_report_missing_files_as_errors() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    let missing = dir.path().join("missing.yml");
+
+    assert_eq!(
+        headless::manifest_check(missing.clone(), false).await,
+        ExitStatus::Error
+    );
+    assert_eq!(
+        headless::manifest_render(missing.clone(), None).await,
+        ExitStatus::Error
+    );
+    assert_eq!(
+        headless::manifest_apply(missing, None, true).await,
+        ExitStatus::Error
+    );
+    assert_eq!(
+        headless::manifest_import(dir.path().join("no-sources"), None).await,
+        ExitStatus::Error
+    );
+}
+
+#[tokio::test]
+async fn manifest_import_writes_draft_to_output_file() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(
+        dir.path().join("Order.cs"),
+        "namespace Shop.Domain.Entities;\npublic class Order : AggregateRoot<Guid> { public string Number { get; set; } }\n",
+    )
+    .expect("source should be written");
+    let output = dir.path().join("draft.yml");
+
+    let status = headless::manifest_import(dir.path().to_path_buf(), Some(output.clone())).await;
+
+    assert_eq!(status, ExitStatus::Success);
+    let draft = std::fs::read_to_string(output).expect("draft should be written");
+    assert!(draft.contains("name: Order"));
+}
+
+#[tokio::test]
+async fn submit_task_rejects_unknown_intent() {
+    let failure = headless::submit_task("not-an-intent", "payload", ConsoleMode::Silent)
+        .await
+        .expect_err("unknown intent must fail");
+
+    assert_eq!(failure.message, "Unsupported task intent: not-an-intent");
+    assert_eq!(failure.hints.len(), 1);
+}
+
+#[tokio::test]
+async fn typed_commands_record_runtime_command_metrics() {
+    let metrics = runtime_metrics();
+    let before = metrics.get_counter("runtime_commands_total");
+
+    let _ = headless::list_tasks(ConsoleMode::Silent).await;
+
+    assert!(metrics.get_counter("runtime_commands_total") > before);
+}
+
+/// Non-dry-run push: parked by the default `repo-workflow-push` gate. The host is
+/// never allowlisted, so an approved run fails policy instead of touching git.
+const GATED_REPO_PUSH_PAYLOAD: &str =
+    "repo=https://example.invalid/acme/app.git;branch=main;command=cargo test;push=true;dry_run=false";
+
+#[tokio::test]
+async fn gated_task_waits_for_approval_and_runs_once_approved() {
+    let parked = headless::submit_task(
+        "repo-workflow",
+        GATED_REPO_PUSH_PAYLOAD,
+        ConsoleMode::Silent,
+    )
+    .await
+    .expect("gated task should be admitted");
+    assert_eq!(parked.status, TaskExecutionStatus::AwaitingApproval);
+    assert_eq!(parked.approval_gate.as_deref(), Some("repo-workflow-push"));
+
+    let pending = headless::list_pending_approvals(ConsoleMode::Silent)
+        .await
+        .expect("local listing cannot fail");
+    assert!(pending.iter().any(|task| task.id == parked.id));
+
+    let approved = headless::approve_task(&parked.id, Some("ship it"), None, ConsoleMode::Silent)
+        .await
+        .expect("approval should be accepted");
+    assert_ne!(approved.status, TaskExecutionStatus::AwaitingApproval);
+    assert!(approved.audit_events.iter().any(|event| {
+        event.status == TaskExecutionStatus::Queued
+            && event.message == "Approved by local-cli-operator: ship it"
+    }));
+
+    let again = headless::approve_task(&parked.id, None, None, ConsoleMode::Silent)
+        .await
+        .expect_err("a decided task cannot be approved twice");
+    assert!(again.message.contains("not awaiting approval"));
+}
+
+#[tokio::test]
+async fn rejected_task_is_cancelled_without_running() {
+    let parked = headless::submit_task(
+        "repo-workflow",
+        GATED_REPO_PUSH_PAYLOAD,
+        ConsoleMode::Silent,
+    )
+    .await
+    .expect("gated task should be admitted");
+
+    let rejected =
+        headless::reject_task(&parked.id, Some("wrong branch"), None, ConsoleMode::Silent)
+            .await
+            .expect("rejection should be accepted");
+    assert_eq!(rejected.status, TaskExecutionStatus::Cancelled);
+    assert_eq!(
+        rejected.detail,
+        "Rejected by local-cli-operator: wrong branch"
+    );
+    assert_eq!(rejected.attempts, 0);
+}
+
+#[tokio::test]
+async fn automation_operator_cannot_approve_parked_task() {
+    let parked = headless::submit_task(
+        "repo-workflow",
+        GATED_REPO_PUSH_PAYLOAD,
+        ConsoleMode::Silent,
+    )
+    .await
+    .expect("gated task should be admitted");
+    let bot = OperatorContext::new(
+        OperatorKind::Automation,
+        "ci-bot",
+        IngressTransport::ServiceHttp,
+    );
+
+    let denied = headless::approve_task(&parked.id, None, Some(bot), ConsoleMode::Silent)
+        .await
+        .expect_err("automation identities must not approve");
+    assert!(denied.message.contains("automation operator"));
+
+    let cancelled = headless::cancel_task_by_id(&parked.id, ConsoleMode::Silent)
+        .await
+        .expect("parked task can be cancelled");
+    assert_eq!(cancelled.status, TaskExecutionStatus::Cancelled);
+}
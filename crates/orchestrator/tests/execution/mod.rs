@@ -5,5 +5,9 @@
 mod ai_e2e_tests;
 mod chatops_tests;
 mod executor_tests;
+mod headless_tests;
+mod output_tests;
+mod plugin_command_tests;
 mod processor_tests;
+mod remote_tests;
 mod repo_workflow_tests;
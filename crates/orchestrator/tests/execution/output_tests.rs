@@ -0,0 +1,191 @@
+//! Structured output tests
+//!
+//! Validate the `CommandOutput` model, its rendering sinks and the capture
+//! scope used by non-terminal ingresses.
+
+use nettoolskit_core::TaskExecutionStatus;
+use nettoolskit_orchestrator::execution::headless;
+use nettoolskit_orchestrator::{
+    capture_output, emit, inherit_output_capture, is_capturing_output, process_command_with_output,
+    ChatOpsPlatform, ChatOpsTextSink, CommandOutput, ConsoleMode, ExitStatus, JsonSink,
+    OutputBlock, OutputSink, OutputTone, TerminalSink,
+};
+
+fn sample_output() -> CommandOutput {
+    CommandOutput::new()
+        .heading("Task Records", OutputTone::Info)
+        .key_values([("root", "/workspace")])
+        .table(
+            ["id", "status"],
+            vec![
+                vec!["task-1".to_string(), "running".to_string()],
+                vec!["task-22".to_string(), "queued".to_string()],
+            ],
+        )
+        .diff(Some("src/lib.rs".to_string()), "@@ -1 +1 @@\n-old\n+new")
+        .error("boom", vec!["retry later".to_string()])
+}
+
+#[test]
+fn json_sink_roundtrips_blocks_with_kind_tags() {
+    let output = sample_output();
+    let json = JsonSink.render(&output);
+
+    assert!(json.contains(r#""kind":"heading""#));
+    assert!(json.contains(r#""kind":"key_values""#));
+    assert!(json.contains(r#""kind":"table""#));
+    assert!(json.contains(r#""kind":"diff""#));
+    assert!(json.contains(r#""kind":"error""#));
+
+    let decoded: CommandOutput = serde_json::from_str(&json).expect("json should decode");
+    assert_eq!(decoded, output);
+    assert_eq!(decoded.first_error(), Some("boom"));
+}
+
+#[test]
+fn terminal_sink_renders_every_block() {
+    let rendered = TerminalSink.render(&sample_output());
+
+    assert!(rendered.contains("Task Records"));
+    assert!(rendered.contains("root"));
+    assert!(rendered.contains("task-22"));
+    assert!(rendered.contains("+new"));
+    assert!(rendered.contains("✗ boom"));
+    assert!(rendered.contains("retry later"));
+}
+
+#[test]
+fn chatops_sink_formats_per_platform() {
+    let output = sample_output();
+
+    let discord = ChatOpsTextSink::new(ChatOpsPlatform::Discord).render(&output);
+    assert!(discord.contains("**Task Records**"));
+    assert!(discord.contains("```diff"));
+    assert!(discord.contains("id       status\ntask-1   running\ntask-22  queued"));
+
+    let telegram = ChatOpsTextSink::new(ChatOpsPlatform::Telegram).render(&output);
+    assert!(!telegram.contains("```"));
+    assert!(!telegram.contains("**"));
+    assert!(telegram.contains("• root: /workspace"));
+    assert!(
+        !telegram.contains('\u{1b}'),
+        "chat text must not carry ANSI codes"
+    );
+}
+
+#[test]
+fn chatops_sink_truncates_to_platform_limit() {
+    let output = CommandOutput::new().markdown("x".repeat(5_000));
+    let sink = ChatOpsTextSink::new(ChatOpsPlatform::Discord);
+
+    let rendered = sink.render(&output);
+
+    assert_eq!(rendered.chars().count(), sink.max_chars());
+    assert!(rendered.ends_with("(output truncated)"));
+}
+
+#[tokio::test]
+async fn capture_output_collects_emitted_blocks() {
+    assert!(!is_capturing_output());
+
+    let ((), output) = capture_output(async {
+        assert!(is_capturing_output());
+        emit(CommandOutput::new().text("first", OutputTone::Plain));
+        emit(CommandOutput::new().text("second", OutputTone::Success));
+    })
+    .await;
+
+    assert_eq!(output.blocks.len(), 2);
+    assert!(matches!(
+        &output.blocks[1],
+        OutputBlock::Text { text, tone: OutputTone::Success } if text == "second"
+    ));
+}
+
+#[tokio::test]
+async fn capture_output_follows_spawned_work_that_inherits_the_scope() {
+    let ((), output) = capture_output(async {
+        emit(CommandOutput::new().text("caller", OutputTone::Plain));
+        tokio::spawn(inherit_output_capture(async {
+            assert!(is_capturing_output());
+            emit(CommandOutput::new().text("spawned", OutputTone::Plain));
+        }))
+        .await
+        .expect("spawned task should finish");
+        tokio::spawn(async { assert!(!is_capturing_output()) })
+            .await
+            .expect("spawned task should finish");
+    })
+    .await;
+
+    assert_eq!(output.blocks.len(), 2);
+    assert!(matches!(
+        &output.blocks[1],
+        OutputBlock::Text { text, .. } if text == "spawned"
+    ));
+}
+
+#[tokio::test]
+async fn process_command_with_output_returns_failures_as_blocks() {
+    let (status, output) = process_command_with_output("/task watch missing-task-id").await;
+
+    assert_eq!(status, ExitStatus::Error);
+    assert_eq!(
+        output.first_error(),
+        Some("Task not found: missing-task-id")
+    );
+}
+
+#[tokio::test]
+async fn process_command_with_output_returns_ai_response_instead_of_streaming() {
+    let (status, output) = process_command_with_output("/ai explain captured ai output").await;
+
+    assert_eq!(status, ExitStatus::Success);
+    assert!(output.blocks.iter().any(|block| matches!(
+        block,
+        OutputBlock::Markdown { markdown } if markdown.contains("captured ai output")
+    )));
+}
+
+#[tokio::test]
+async fn task_snapshot_carries_execution_output() {
+    let submitted = headless::submit_task("ai-plan", "stored task output", ConsoleMode::Silent)
+        .await
+        .expect("task should be admitted");
+    assert_eq!(submitted.status, TaskExecutionStatus::Succeeded);
+
+    let result = submitted
+        .output
+        .as_ref()
+        .expect("completed task should keep its output");
+    assert!(result.blocks.iter().any(|block| matches!(
+        block,
+        OutputBlock::Markdown { markdown } if markdown.contains("stored task output")
+    )));
+
+    let listed = headless::list_tasks(ConsoleMode::Silent)
+        .await
+        .expect("local listing cannot fail");
+    let summary = listed
+        .iter()
+        .find(|task| task.id == submitted.id)
+        .expect("task should be listed");
+    assert!(summary.output.is_none(), "list views omit task output");
+}
+
+#[tokio::test]
+async fn task_top_returns_dashboard_snapshot_when_captured() {
+    let submitted = headless::submit_task("ai-plan", "dashboard snapshot", ConsoleMode::Silent)
+        .await
+        .expect("task should be admitted");
+
+    let (status, output) = process_command_with_output("/task top").await;
+
+    assert_eq!(status, ExitStatus::Success);
+    assert!(output.blocks.iter().any(|block| matches!(
+        block,
+        OutputBlock::Table { headers, rows }
+            if headers.iter().any(|header| header == "elapsed")
+                && rows.iter().any(|row| row[0] == submitted.id)
+    )));
+}
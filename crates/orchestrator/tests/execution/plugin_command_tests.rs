@@ -0,0 +1,124 @@
+//! Plugin command tests
+//!
+//! Validate that commands contributed through `CommandPlugin::commands` are
+//! dispatched, registered and offered by completion like built-ins.
+
+use nettoolskit_orchestrator::{
+    complete_command_line, find_registered_command_spec, process_command_with_output,
+    register_command_plugin, ArgKind, ArgSpec, CommandInvocation, CommandOutput, CommandPlugin,
+    CommandSpec, ExitStatus, FlagSpec, OutputBlock, OutputTone, PluginMetadata,
+    NTK_PLUGIN_ALLOWED_SCOPES_ENV,
+};
+use serial_test::serial;
+use std::sync::{Arc, Once};
+
+const GREET_COMMANDS: &[CommandSpec] = &[CommandSpec {
+    name: "/greet-it",
+    aliases: &[],
+    description: "Greet someone from a test plugin",
+    args: &[ArgSpec {
+        name: "name",
+        kind: ArgKind::Text,
+    }],
+    flags: &[FlagSpec {
+        name: "--fail",
+        value: None,
+        description: "Report a failure instead",
+    }],
+    subcommands: &[],
+    scope: "greet",
+    handler: None,
+}];
+
+struct GreetPlugin;
+
+impl CommandPlugin for GreetPlugin {
+    fn metadata(&self) -> PluginMetadata {
+        PluginMetadata::new("tests.greet", "Greeter", "1.0.0", "integration test plugin")
+    }
+
+    fn commands(&self) -> &'static [CommandSpec] {
+        GREET_COMMANDS
+    }
+
+    fn execute_command(&self, invocation: &CommandInvocation) -> Result<CommandOutput, String> {
+        if invocation.has_flag("--fail") {
+            return Err("greeting refused".to_string());
+        }
+        let name = invocation
+            .positionals
+            .first()
+            .map_or("world", String::as_str);
+        Ok(CommandOutput::new().text(format!("hello {name}"), OutputTone::Success))
+    }
+}
+
+fn register_greet_plugin() {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        register_command_plugin(Arc::new(GreetPlugin)).expect("plugin registers");
+    });
+}
+
+fn rendered_text(output: &CommandOutput) -> String {
+    output
+        .blocks
+        .iter()
+        .filter_map(|block| match block {
+            OutputBlock::Text { text, .. } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[tokio::test]
+#[serial]
+async fn plugin_command_is_dispatched_with_parsed_arguments() {
+    register_greet_plugin();
+
+    let (status, output) = process_command_with_output("/greet-it operator").await;
+
+    assert_eq!(status, ExitStatus::Success);
+    assert!(rendered_text(&output).contains("hello operator"));
+}
+
+#[tokio::test]
+#[serial]
+async fn plugin_command_failure_maps_to_error_status() {
+    register_greet_plugin();
+
+    let (status, _) = process_command_with_output("/greet-it --fail").await;
+
+    assert_eq!(status, ExitStatus::Error);
+}
+
+#[tokio::test]
+#[serial]
+async fn plugin_command_outside_allowed_scopes_is_refused() {
+    register_greet_plugin();
+
+    std::env::set_var(NTK_PLUGIN_ALLOWED_SCOPES_ENV, "deploy");
+    let (refused, output) = process_command_with_output("/greet-it operator").await;
+    std::env::set_var(NTK_PLUGIN_ALLOWED_SCOPES_ENV, "deploy,greet");
+    let (granted, _) = process_command_with_output("/greet-it operator").await;
+    std::env::remove_var(NTK_PLUGIN_ALLOWED_SCOPES_ENV);
+
+    assert_eq!(refused, ExitStatus::Error);
+    assert!(!rendered_text(&output).contains("hello operator"));
+    assert_eq!(granted, ExitStatus::Success);
+}
+
+#[test]
+fn plugin_command_is_listed_in_registry_and_completion() {
+    register_greet_plugin();
+
+    let spec = find_registered_command_spec("greet-it").expect("plugin spec is registered");
+    assert_eq!(spec.usage(), "/greet-it <name> [--fail]");
+
+    let completion = complete_command_line("/gree");
+    assert!(completion
+        .candidates
+        .iter()
+        .any(|candidate| candidate.value == "/greet-it"));
+}
@@ -2,7 +2,9 @@
 //!
 //! Tests for command processor and text processor functionality.
 
-use nettoolskit_orchestrator::{process_command, process_text, ExitStatus};
+use nettoolskit_orchestrator::{
+    process_command, process_command_with_output, process_text, ExitStatus, OutputBlock,
+};
 use std::sync::OnceLock;
 use tokio::sync::Mutex;
 
@@ -102,6 +104,79 @@ async fn test_process_ai_ask_with_mock_provider_succeeds() {
     );
 }
 
+#[tokio::test]
+async fn test_process_ai_context_previews_retrieved_context() {
+    let (result, output) =
+        process_command_with_output("/ai context how does the command cache evict entries").await;
+    assert_eq!(result, ExitStatus::Success);
+    assert!(output.blocks.iter().any(|block| matches!(
+        block,
+        OutputBlock::Heading { text, .. } if text.contains("AI Context Preview")
+    )));
+    assert!(output.blocks.iter().any(|block| matches!(
+        block,
+        OutputBlock::KeyValues { .. } | OutputBlock::Text { .. }
+    )));
+
+    let missing_prompt = process_command("/ai context").await;
+    assert_eq!(missing_prompt, ExitStatus::Error);
+}
+
+#[tokio::test]
+async fn test_process_ai_manifest_requires_instruction() {
+    let result = process_command("/ai manifest").await;
+    assert_eq!(result, ExitStatus::Error);
+}
+
+#[tokio::test]
+async fn test_process_ai_session_commands_validate_arguments() {
+    assert_eq!(
+        process_command("/ai session list").await,
+        ExitStatus::Success
+    );
+    assert_eq!(process_command("/ai session show").await, ExitStatus::Error);
+    assert_eq!(
+        process_command("/ai session show no-such-session-for-tests").await,
+        ExitStatus::Error
+    );
+    assert_eq!(
+        process_command("/ai session export no-such-session-for-tests --format pdf").await,
+        ExitStatus::Error
+    );
+    assert_eq!(
+        process_command("/ai session frobnicate").await,
+        ExitStatus::Error
+    );
+    assert_eq!(process_command("/ai fork").await, ExitStatus::Error);
+    assert_eq!(
+        process_command("/ai fork no-such-session-for-tests --at 0").await,
+        ExitStatus::Error
+    );
+}
+
+#[tokio::test]
+async fn test_process_ai_usage_validates_window_and_grouping() {
+    assert_eq!(
+        process_command("/ai usage --since 0d").await,
+        ExitStatus::Error
+    );
+    assert_eq!(
+        process_command("/ai usage --since yesterday").await,
+        ExitStatus::Error
+    );
+    assert_eq!(
+        process_command("/ai usage --by provider").await,
+        ExitStatus::Error
+    );
+}
+
+#[tokio::test]
+async fn test_process_cache_command_reports_stats_and_rejects_unknown_actions() {
+    assert_eq!(process_command("/cache").await, ExitStatus::Success);
+    assert_eq!(process_command("/cache stats").await, ExitStatus::Success);
+    assert_eq!(process_command("/cache purge").await, ExitStatus::Error);
+}
+
 #[tokio::test]
 async fn test_process_ai_apply_requires_dry_run() {
     let result = process_command("/ai apply create service").await;
@@ -0,0 +1,182 @@
+//! Remote task client tests
+//!
+//! Exercise the HTTP client used by CLI `/task` commands against a minimal
+//! single-response server, plus endpoint validation and error mapping.
+
+use nettoolskit_core::TaskExecutionStatus;
+use nettoolskit_orchestrator::{
+    normalize_service_endpoint, RemoteRequestContext, RemoteServiceClient, RemoteServiceError,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// Serve one HTTP response and return the raw request (lowercased).
+async fn spawn_single_response_server(status: u16, body: &str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("listener should bind");
+    let endpoint = format!(
+        "http://{}",
+        listener.local_addr().expect("listener must have address")
+    );
+    let body = body.to_string();
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.expect("accept should pass");
+        let mut request_buffer = [0_u8; 8192];
+        let read = socket.read(&mut request_buffer).await.unwrap_or(0);
+        let response = format!(
+            "HTTP/1.1 {status} Status\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let _ = socket.write_all(response.as_bytes()).await;
+        let _ = socket.shutdown().await;
+        String::from_utf8_lossy(&request_buffer[..read]).to_ascii_lowercase()
+    });
+    (endpoint, server)
+}
+
+fn task_snapshot_json(id: &str, status: &str) -> String {
+    format!(
+        r#"{{"id":"{id}","intent":"ai-plan","title":"ai-plan task","payload":"split","status":"{status}","runtime_mode":"service","execution_target":"background-worker-local","detail":"queued","attempts":0,"max_attempts":3,"created_at_unix_ms":1,"updated_at_unix_ms":2}}"#
+    )
+}
+
+#[test]
+fn normalize_service_endpoint_accepts_http_urls_and_trims_slashes() {
+    assert_eq!(
+        normalize_service_endpoint(" https://agent-box:8443/ ").as_deref(),
+        Ok("https://agent-box:8443")
+    );
+    assert!(normalize_service_endpoint("agent-box:8080").is_err());
+    assert!(normalize_service_endpoint("http://").is_err());
+    assert!(normalize_service_endpoint("").is_err());
+}
+
+#[tokio::test]
+async fn submit_task_sends_auth_and_operator_headers() {
+    let (endpoint, server) = spawn_single_response_server(
+        202,
+        r#"{"accepted":true,"exit_status":"success","task_id":"task-42","task_status":"queued","request_id":"req-1","operator_id":"dev-laptop","operator_kind":"remote_human","session_id":"s","transport":"service_http"}"#,
+    )
+    .await;
+
+    let client = RemoteServiceClient::new(endpoint)
+        .with_auth_token(Some("token-123".to_string()))
+        .with_operator_id("dev-laptop");
+    let context = RemoteRequestContext {
+        request_id: Some("cli-task-1".to_string()),
+        correlation_id: Some("cli-task-1".to_string()),
+        session_id: Some("cli-session-1".to_string()),
+    };
+    let submission = client
+        .submit_task("ai-plan", "split module", &context)
+        .await
+        .expect("submission should decode");
+    let raw_request = server.await.expect("server should complete");
+
+    assert!(submission.accepted);
+    assert_eq!(submission.task_id.as_deref(), Some("task-42"));
+    assert_eq!(submission.task_status, Some(TaskExecutionStatus::Queued));
+    assert!(raw_request.starts_with("post /task/submit "));
+    assert!(raw_request.contains("authorization: bearer token-123"));
+    assert!(raw_request.contains("x-ntk-operator-id: dev-laptop"));
+    assert!(raw_request.contains("x-ntk-session-id: cli-session-1"));
+    assert!(raw_request.contains("x-correlation-id: cli-task-1"));
+    assert!(raw_request.contains(r#""intent":"ai-plan""#));
+}
+
+#[tokio::test]
+async fn submit_task_decodes_rejection_payload_from_client_error_status() {
+    let (endpoint, server) = spawn_single_response_server(
+        400,
+        r#"{"accepted":false,"exit_status":"error","rejection_reason":"Task rejected by secure tool-scope policy: blocked","request_id":"req-2","operator_id":"op"}"#,
+    )
+    .await;
+
+    let submission = RemoteServiceClient::new(endpoint)
+        .submit_task("command", "rm -rf", &RemoteRequestContext::default())
+        .await
+        .expect("rejections are decoded, not errors");
+    let _ = server.await;
+
+    assert!(!submission.accepted);
+    assert!(submission.task_id.is_none());
+    assert!(submission
+        .rejection_reason
+        .as_deref()
+        .is_some_and(|reason| reason.contains("tool-scope")));
+}
+
+#[tokio::test]
+async fn get_task_maps_http_errors() {
+    let (endpoint, server) = spawn_single_response_server(404, "Task not found: task-9").await;
+
+    let error = RemoteServiceClient::new(endpoint)
+        .get_task("task-9")
+        .await
+        .expect_err("404 should be an error");
+    let raw_request = server.await.expect("server should complete");
+
+    assert!(raw_request.starts_with("get /task/task-9 "));
+    assert_eq!(
+        error,
+        RemoteServiceError::Http {
+            status: 404,
+            message: "Task not found: task-9".to_string()
+        }
+    );
+    assert!(!error.is_unreachable());
+}
+
+#[tokio::test]
+async fn list_and_cancel_decode_task_snapshots() {
+    let body = format!("[{}]", task_snapshot_json("task-1", "running"));
+    let (endpoint, server) = spawn_single_response_server(200, &body).await;
+    let tasks = RemoteServiceClient::new(endpoint)
+        .list_tasks()
+        .await
+        .expect("list should decode");
+    let raw_request = server.await.expect("server should complete");
+    assert!(raw_request.starts_with("get /task/list "));
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].status, TaskExecutionStatus::Running);
+    assert!(tasks[0].audit_events.is_empty());
+
+    let (endpoint, server) =
+        spawn_single_response_server(200, &task_snapshot_json("task-1", "cancelled")).await;
+    let cancelled = RemoteServiceClient::new(endpoint)
+        .cancel_task("task-1")
+        .await
+        .expect("cancel should decode");
+    let raw_request = server.await.expect("server should complete");
+    assert!(raw_request.starts_with("post /task/task-1/cancel "));
+    assert_eq!(cancelled.status, TaskExecutionStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn closed_endpoint_is_reported_as_unreachable() {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("listener should bind");
+    let endpoint = format!("http://{}", listener.local_addr().expect("address"));
+    drop(listener);
+
+    let error = RemoteServiceClient::new(endpoint.clone())
+        .list_tasks()
+        .await
+        .expect_err("closed port must fail");
+
+    assert!(error.is_unreachable());
+    assert!(error.to_string().contains(&endpoint));
+}
+
+#[test]
+fn unauthorized_error_points_to_token_env() {
+    let error = RemoteServiceError::Http {
+        status: 401,
+        message: "missing bearer token".to_string(),
+    };
+    assert!(error.to_string().contains("NTK_SERVICE_AUTH_TOKEN"));
+}
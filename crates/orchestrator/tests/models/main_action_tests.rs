@@ -197,6 +197,7 @@ fn test_main_action_pattern_matching_exhaustive() {
         MainAction::Ai,
         MainAction::Task,
         MainAction::Config,
+        MainAction::Cache,
         MainAction::Clear,
         MainAction::Quit,
     ];
@@ -209,6 +210,7 @@ fn test_main_action_pattern_matching_exhaustive() {
             MainAction::Ai => true,
             MainAction::Task => true,
             MainAction::Config => true,
+            MainAction::Cache => true,
             MainAction::Clear => true,
             MainAction::Quit => true,
         };
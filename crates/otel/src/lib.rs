@@ -6,6 +6,8 @@
 //! - **OpenTelemetry traces** via `tracing-opentelemetry` + OTLP exporter (optional, env-driven)
 //! - **Metrics** — thread-safe counters, gauges, and timing histograms (custom, in-process),
 //!   with optional OTLP metric mirror when exporter env vars are configured
+//! - **Trace-context propagation** — W3C `traceparent`/`tracestate` parenting across queues and HTTP calls
+//! - **Prometheus exposition** — text-format rendering of the metrics registry for scraping
 //! - **Timer** — RAII-based operation timing with auto-record on drop
 //! - **Correlation IDs** — lightweight execution/command identifiers for log correlation
 //!
@@ -25,6 +27,10 @@
 
 /// Correlation id helpers for tracing context.
 pub mod correlation;
+/// Prometheus text exposition for in-process metrics.
+pub mod prometheus;
+/// W3C trace-context propagation helpers.
+pub mod propagation;
 /// In-process metrics and timers.
 pub mod telemetry;
 /// Tracing subscriber configuration and initialization.
@@ -32,6 +38,13 @@ pub mod tracing_setup;
 
 /// Re-exported correlation id generator.
 pub use correlation::next_correlation_id;
+/// Re-exported Prometheus exposition helpers.
+pub use prometheus::{render_prometheus, PROMETHEUS_CONTENT_TYPE};
+/// Re-exported trace-context propagation helpers.
+pub use propagation::{
+    current_trace_headers, extract_trace_context, install_trace_context_propagator,
+    set_span_parent, span_trace_context,
+};
 /// Re-exported metrics and timer types.
 pub use telemetry::{Metrics, Timer};
 /// Re-exported tracing initialization functions and configuration.
@@ -0,0 +1,235 @@
+//! Prometheus text exposition for in-process metrics
+//!
+//! Renders a [`Metrics`] registry in the Prometheus text format (version 0.0.4)
+//! so service mode can be scraped directly, without an OTLP collector.
+//!
+//! - Counters are exported as `counter` families with a `_total` suffix
+//! - Gauges are exported as `gauge` families
+//! - Timings are exported as `histogram` families in seconds
+//!   (`_bucket`, `_sum`, `_count`)
+//!
+//! A family name can only carry one type per scrape, so when a gauge or timing
+//! family collides with an earlier kind it is suffixed with its type
+//! (`_gauge`, `_histogram`) instead of emitting a second `# TYPE` line.
+//!
+//! Metric and label names are sanitized to `[a-zA-Z_:][a-zA-Z0-9_:]*`, and label
+//! values are escaped per the exposition format.
+
+use crate::telemetry::Metrics;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::time::Duration;
+
+/// Content type for the Prometheus text exposition format.
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Default prefix applied to every exported metric family.
+pub const PROMETHEUS_METRIC_PREFIX: &str = "ntk";
+
+/// Histogram bucket upper bounds (seconds) used for timing families.
+pub const PROMETHEUS_TIMING_BUCKETS_SECONDS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0,
+];
+
+/// Sanitize an arbitrary string into a valid Prometheus metric name.
+pub fn sanitize_metric_name(name: &str) -> String {
+    sanitize_identifier(name, true)
+}
+
+/// Sanitize an arbitrary string into a valid Prometheus label name.
+///
+/// Repeated underscores are collapsed, so the reserved `__` prefix never appears.
+pub fn sanitize_label_name(name: &str) -> String {
+    sanitize_identifier(name, false)
+}
+
+/// Escape a label value (`\`, `"` and newlines).
+pub fn escape_label_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+/// Render all counters, gauges and timings in Prometheus text format.
+///
+/// `const_labels` are attached to every sample (e.g. `service="ntk"`).
+pub fn render_prometheus(metrics: &Metrics, const_labels: &[(&str, &str)]) -> String {
+    let labels = format_labels(const_labels);
+    let mut output = String::new();
+    let mut claimed = BTreeSet::new();
+
+    let counters: BTreeMap<String, u64> = merge_families(
+        metrics
+            .counters_snapshot()
+            .into_iter()
+            .map(|(name, value)| (counter_family_name(&name), value)),
+        |left, right| left.saturating_add(right),
+    );
+    for (family, value) in counters {
+        let family = claim_family(&mut claimed, family, "counter");
+        let _ = writeln!(output, "# TYPE {family} counter");
+        let _ = writeln!(output, "{family}{labels} {value}");
+    }
+
+    let gauges: BTreeMap<String, f64> = merge_families(
+        metrics
+            .gauges_snapshot()
+            .into_iter()
+            .map(|(name, value)| (family_name(&name), value)),
+        |_, right| right,
+    );
+    for (family, value) in gauges {
+        let family = claim_family(&mut claimed, family, "gauge");
+        let _ = writeln!(output, "# TYPE {family} gauge");
+        let _ = writeln!(output, "{family}{labels} {}", format_float(value));
+    }
+
+    let timings: BTreeMap<String, Vec<Duration>> = merge_families(
+        metrics
+            .timings_snapshot()
+            .into_iter()
+            .map(|(name, samples)| (timing_family_name(&name), samples)),
+        |mut left, right| {
+            left.extend(right);
+            left
+        },
+    );
+    for (family, samples) in timings {
+        let family = claim_family(&mut claimed, family, "histogram");
+        render_histogram(&mut output, &family, const_labels, &samples);
+    }
+
+    output
+}
+
+fn render_histogram(
+    output: &mut String,
+    family: &str,
+    const_labels: &[(&str, &str)],
+    samples: &[Duration],
+) {
+    let _ = writeln!(output, "# TYPE {family} histogram");
+    let seconds: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+
+    for bound in PROMETHEUS_TIMING_BUCKETS_SECONDS {
+        let count = seconds.iter().filter(|value| **value <= *bound).count();
+        let le = format_float(*bound);
+        let labels = format_labels_with(const_labels, ("le", &le));
+        let _ = writeln!(output, "{family}_bucket{labels} {count}");
+    }
+    let labels = format_labels_with(const_labels, ("le", "+Inf"));
+    let _ = writeln!(output, "{family}_bucket{labels} {}", seconds.len());
+
+    let labels = format_labels(const_labels);
+    let sum: f64 = seconds.iter().sum();
+    let _ = writeln!(output, "{family}_sum{labels} {}", format_float(sum));
+    let _ = writeln!(output, "{family}_count{labels} {}", seconds.len());
+}
+
+fn merge_families<T>(
+    entries: impl Iterator<Item = (String, T)>,
+    merge: impl Fn(T, T) -> T,
+) -> BTreeMap<String, T> {
+    let mut families: BTreeMap<String, T> = BTreeMap::new();
+    for (name, value) in entries {
+        let merged = match families.remove(&name) {
+            Some(existing) => merge(existing, value),
+            None => value,
+        };
+        families.insert(name, merged);
+    }
+    families
+}
+
+/// Reserve `family` for one metric type, suffixing it with `kind` on collision.
+fn claim_family(claimed: &mut BTreeSet<String>, family: String, kind: &str) -> String {
+    let mut family = family;
+    while claimed.contains(&family) {
+        family = format!("{family}_{kind}");
+    }
+    claimed.insert(family.clone());
+    family
+}
+
+fn family_name(name: &str) -> String {
+    sanitize_metric_name(&format!("{PROMETHEUS_METRIC_PREFIX}_{name}"))
+}
+
+fn counter_family_name(name: &str) -> String {
+    let family = family_name(name);
+    if family.ends_with("_total") {
+        family
+    } else {
+        format!("{family}_total")
+    }
+}
+
+fn timing_family_name(name: &str) -> String {
+    let family = family_name(name);
+    if family.ends_with("_seconds") {
+        family
+    } else {
+        format!("{family}_seconds")
+    }
+}
+
+fn sanitize_identifier(name: &str, allow_colon: bool) -> String {
+    let mut sanitized = String::with_capacity(name.len());
+    let mut previous_underscore = false;
+    for ch in name.chars() {
+        let valid = ch.is_ascii_alphanumeric() || ch == '_' || (allow_colon && ch == ':');
+        let ch = if valid { ch } else { '_' };
+        if ch == '_' && previous_underscore {
+            continue;
+        }
+        previous_underscore = ch == '_';
+        sanitized.push(ch);
+    }
+
+    let sanitized = sanitized.trim_end_matches('_').to_string();
+    match sanitized.chars().next() {
+        None => "_".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("_{sanitized}"),
+        Some(_) => sanitized,
+    }
+}
+
+fn format_labels(const_labels: &[(&str, &str)]) -> String {
+    if const_labels.is_empty() {
+        return String::new();
+    }
+    let pairs: Vec<String> = const_labels
+        .iter()
+        .map(|(name, value)| {
+            format!(
+                "{}=\"{}\"",
+                sanitize_label_name(name),
+                escape_label_value(value)
+            )
+        })
+        .collect();
+    format!("{{{}}}", pairs.join(","))
+}
+
+fn format_labels_with(const_labels: &[(&str, &str)], extra: (&str, &str)) -> String {
+    let mut labels: Vec<(&str, &str)> = const_labels.to_vec();
+    labels.push(extra);
+    format_labels(&labels)
+}
+
+fn format_float(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        format!("{value}")
+    }
+}
@@ -0,0 +1,80 @@
+//! W3C trace-context propagation helpers
+//!
+//! Bridges [`TraceContext`] values captured at ingress (HTTP headers, ChatOps
+//! webhooks) with `tracing` spans, so work executed later on background
+//! workers joins the caller's trace instead of starting a new one.
+//!
+//! Parenting and header injection only take effect when the
+//! `tracing-opentelemetry` layer is installed (OTLP tracing enabled);
+//! otherwise every helper is a cheap no-op.
+
+use nettoolskit_core::{TraceContext, TRACEPARENT_HEADER, TRACESTATE_HEADER};
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use std::collections::HashMap;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Register the W3C trace-context propagator as the global text-map propagator.
+pub fn install_trace_context_propagator() {
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+}
+
+/// Parse trace-context headers using a case-insensitive header lookup.
+#[must_use]
+pub fn extract_trace_context<'a>(header: impl Fn(&str) -> Option<&'a str>) -> Option<TraceContext> {
+    TraceContext::parse(header(TRACEPARENT_HEADER), header(TRACESTATE_HEADER))
+}
+
+/// Make `span` a child of the remote caller described by `trace_context`.
+///
+/// Returns `true` when the parent was attached to an OpenTelemetry span.
+pub fn set_span_parent(span: &Span, trace_context: &TraceContext) -> bool {
+    let mut carrier = HashMap::new();
+    carrier.insert(
+        TRACEPARENT_HEADER.to_string(),
+        trace_context.traceparent.clone(),
+    );
+    if let Some(tracestate) = &trace_context.tracestate {
+        carrier.insert(TRACESTATE_HEADER.to_string(), tracestate.clone());
+    }
+
+    let parent = TraceContextPropagator::new().extract(&carrier);
+    if !parent.span().span_context().is_remote() {
+        return false;
+    }
+    span.set_parent(parent).is_ok()
+}
+
+/// Trace context of `span`, suitable for outgoing request headers.
+///
+/// Returns `None` when the span is not backed by a valid OpenTelemetry span.
+#[must_use]
+pub fn span_trace_context(span: &Span) -> Option<TraceContext> {
+    let context = span.context();
+    if !context.span().span_context().is_valid() {
+        return None;
+    }
+
+    let mut carrier: HashMap<String, String> = HashMap::new();
+    TraceContextPropagator::new().inject_context(&context, &mut carrier);
+    TraceContext::parse(
+        carrier.get(TRACEPARENT_HEADER).map(String::as_str),
+        carrier.get(TRACESTATE_HEADER).map(String::as_str),
+    )
+}
+
+/// Header pairs propagating the current span's trace context.
+#[must_use]
+pub fn current_trace_headers() -> Vec<(&'static str, String)> {
+    let Some(trace_context) = span_trace_context(&Span::current()) else {
+        return Vec::new();
+    };
+
+    let mut headers = vec![(TRACEPARENT_HEADER, trace_context.traceparent)];
+    if let Some(tracestate) = trace_context.tracestate {
+        headers.push((TRACESTATE_HEADER, tracestate));
+    }
+    headers
+}
@@ -134,6 +134,12 @@ impl Metrics {
         gauges.clone()
     }
 
+    /// Get all timing samples snapshot
+    pub fn timings_snapshot(&self) -> HashMap<String, Vec<Duration>> {
+        let timings = self.timings.lock().unwrap();
+        timings.clone()
+    }
+
     /// Log metrics summary
     pub fn log_summary(&self) {
         let counters = self.counters_snapshot();
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use nettoolskit_core::redact_registered_secrets;
 use opentelemetry::metrics::{Counter, Gauge, Histogram, Meter};
 use opentelemetry::trace::TracerProvider as _;
 use opentelemetry::{global, KeyValue};
@@ -8,9 +9,11 @@ use opentelemetry_sdk::trace::{SdkTracerProvider, Tracer};
 use opentelemetry_sdk::Resource;
 use std::collections::HashMap;
 use std::env;
+use std::io::{self, Write};
 use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
 use tracing::{debug, info, warn};
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
 const DEFAULT_OTLP_TIMEOUT_MS: u64 = 10_000;
@@ -144,6 +147,37 @@ impl Default for TracingConfig {
     }
 }
 
+/// Log sink wrapper that masks resolved secrets before they are written.
+struct RedactingMakeWriter<M>(M);
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for RedactingMakeWriter<M> {
+    type Writer = RedactingWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter(self.0.make_writer())
+    }
+}
+
+/// Writer produced by [`RedactingMakeWriter`]; each formatted event arrives
+/// in a single `write` call, so secrets are never split across buffers.
+struct RedactingWriter<W>(W);
+
+impl<W: Write> Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let (redacted, count) = redact_registered_secrets(&String::from_utf8_lossy(buf));
+        if count == 0 {
+            self.0.write_all(buf)?;
+        } else {
+            self.0.write_all(redacted.as_bytes())?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
 /// Initialize comprehensive tracing for NetToolsKit CLI with structured logging.
 pub fn init_tracing(verbose: bool) -> Result<()> {
     let config = TracingConfig {
@@ -186,7 +220,7 @@ pub fn init_tracing_with_config(config: TracingConfig) -> Result<()> {
                         .with_file(config.with_file)
                         .with_line_number(config.with_line_numbers)
                         .with_span_events(fmt::format::FmtSpan::ENTER | fmt::format::FmtSpan::CLOSE)
-                        .with_writer(nettoolskit_ui::UiWriter::new)
+                        .with_writer(RedactingMakeWriter(nettoolskit_ui::UiWriter::new))
                         .compact(),
                 )
                 .try_init()?;
@@ -198,7 +232,7 @@ pub fn init_tracing_with_config(config: TracingConfig) -> Result<()> {
                         .with_file(config.with_file)
                         .with_line_number(config.with_line_numbers)
                         .with_span_events(fmt::format::FmtSpan::ENTER | fmt::format::FmtSpan::CLOSE)
-                        .with_writer(nettoolskit_ui::UiWriter::new)
+                        .with_writer(RedactingMakeWriter(nettoolskit_ui::UiWriter::new))
                         .compact(),
                 )
                 .try_init()?;
@@ -214,7 +248,7 @@ pub fn init_tracing_with_config(config: TracingConfig) -> Result<()> {
                         .with_file(config.with_file)
                         .with_line_number(config.with_line_numbers)
                         .with_span_events(fmt::format::FmtSpan::ENTER | fmt::format::FmtSpan::CLOSE)
-                        .with_writer(std::io::stderr)
+                        .with_writer(RedactingMakeWriter(std::io::stderr))
                         .pretty(),
                 )
                 .try_init()?;
@@ -226,7 +260,7 @@ pub fn init_tracing_with_config(config: TracingConfig) -> Result<()> {
                         .with_file(config.with_file)
                         .with_line_number(config.with_line_numbers)
                         .with_span_events(fmt::format::FmtSpan::ENTER | fmt::format::FmtSpan::CLOSE)
-                        .with_writer(std::io::stderr)
+                        .with_writer(RedactingMakeWriter(std::io::stderr))
                         .pretty(),
                 )
                 .try_init()?;
@@ -240,7 +274,7 @@ pub fn init_tracing_with_config(config: TracingConfig) -> Result<()> {
                     .with_file(config.with_file)
                     .with_line_number(config.with_line_numbers)
                     .with_span_events(fmt::format::FmtSpan::ENTER | fmt::format::FmtSpan::CLOSE)
-                    .with_writer(std::io::stderr)
+                    .with_writer(RedactingMakeWriter(std::io::stderr))
                     .compact(),
             )
             .try_init()?;
@@ -252,7 +286,7 @@ pub fn init_tracing_with_config(config: TracingConfig) -> Result<()> {
                     .with_file(config.with_file)
                     .with_line_number(config.with_line_numbers)
                     .with_span_events(fmt::format::FmtSpan::ENTER | fmt::format::FmtSpan::CLOSE)
-                    .with_writer(std::io::stderr)
+                    .with_writer(RedactingMakeWriter(std::io::stderr))
                     .compact(),
             )
             .try_init()?;
@@ -282,7 +316,7 @@ pub fn init_tracing_with_filter(filter: &str) -> Result<()> {
                     .with_file(true)
                     .with_line_number(true)
                     .with_span_events(fmt::format::FmtSpan::ENTER | fmt::format::FmtSpan::CLOSE)
-                    .with_writer(std::io::stderr),
+                    .with_writer(RedactingMakeWriter(std::io::stderr)),
             )
             .try_init()?;
     } else {
@@ -292,7 +326,7 @@ pub fn init_tracing_with_filter(filter: &str) -> Result<()> {
                 .with_file(true)
                 .with_line_number(true)
                 .with_span_events(fmt::format::FmtSpan::ENTER | fmt::format::FmtSpan::CLOSE)
-                .with_writer(std::io::stderr),
+                .with_writer(RedactingMakeWriter(std::io::stderr)),
         )
         .try_init()?;
     }
@@ -435,6 +469,7 @@ fn build_otlp_tracer(config: &TracingConfig) -> Result<Option<Tracer>> {
     let tracer = provider.tracer(config.service_name.clone());
     register_tracer_provider(provider.clone());
     global::set_tracer_provider(provider);
+    crate::propagation::install_trace_context_propagator();
 
     Ok(Some(tracer))
 }
@@ -989,4 +1024,17 @@ mod tests {
             .iter()
             .any(|(key, value)| key == "service.version" && value.contains("9.9.9")));
     }
+
+    #[test]
+    fn redacting_writer_masks_registered_secrets() {
+        nettoolskit_core::register_secret("otel-test-secret-0042");
+        let mut writer = RedactingWriter(Vec::new());
+        let line = b"token=otel-test-secret-0042 accepted\n";
+
+        assert_eq!(writer.write(line).unwrap(), line.len());
+        assert_eq!(
+            String::from_utf8(writer.0).unwrap(),
+            "token=[REDACTED] accepted\n"
+        );
+    }
 }
@@ -0,0 +1,138 @@
+//! Tests for Prometheus text exposition
+//!
+//! Validates family naming/sanitization, counter `_total` suffixing, gauge
+//! rendering, timing histograms (buckets, sum, count), constant labels, and
+//! label value escaping.
+
+use nettoolskit_otel::prometheus::{escape_label_value, sanitize_label_name, sanitize_metric_name};
+use nettoolskit_otel::{render_prometheus, Metrics};
+use std::time::Duration;
+
+#[test]
+fn test_sanitize_metric_name_replaces_invalid_characters() {
+    assert_eq!(
+        sanitize_metric_name("command_/help_usage"),
+        "command_help_usage"
+    );
+    assert_eq!(sanitize_metric_name("9lives"), "_9lives");
+    assert_eq!(sanitize_metric_name("a:b-c.d"), "a:b_c_d");
+    assert_eq!(sanitize_metric_name("---"), "_");
+}
+
+#[test]
+fn test_sanitize_label_name_rejects_colons_and_reserved_prefix() {
+    assert_eq!(sanitize_label_name("team:name"), "team_name");
+    assert_eq!(sanitize_label_name("__internal"), "_internal");
+}
+
+#[test]
+fn test_escape_label_value_escapes_quotes_backslashes_and_newlines() {
+    assert_eq!(escape_label_value("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+}
+
+#[test]
+fn test_render_prometheus_exports_counters_with_total_suffix() {
+    // Arrange
+    let metrics = Metrics::new();
+    metrics.increment_counter("runtime_commands_total");
+    metrics.increment_counter("runtime_commands_total");
+    metrics.increment_counter("command_/help_usage");
+
+    // Act
+    let output = render_prometheus(&metrics, &[]);
+
+    // Assert
+    assert!(output.contains("# TYPE ntk_runtime_commands_total counter\n"));
+    assert!(output.contains("ntk_runtime_commands_total 2\n"));
+    assert!(output.contains("ntk_command_help_usage_total 1\n"));
+}
+
+#[test]
+fn test_render_prometheus_exports_gauges() {
+    let metrics = Metrics::new();
+    metrics.set_gauge("runtime_ai_approval_ratio_pct", 87.5);
+
+    let output = render_prometheus(&metrics, &[]);
+
+    assert!(output.contains("# TYPE ntk_runtime_ai_approval_ratio_pct gauge\n"));
+    assert!(output.contains("ntk_runtime_ai_approval_ratio_pct 87.5\n"));
+}
+
+#[test]
+fn test_render_prometheus_keeps_one_type_per_family_across_kinds() {
+    // Arrange
+    let metrics = Metrics::new();
+    metrics.increment_counter("runtime_command_cache_hits_total");
+    metrics.set_gauge("runtime_command_cache_hits_total", 4.0);
+
+    // Act
+    let output = render_prometheus(&metrics, &[]);
+
+    // Assert
+    assert_eq!(
+        output
+            .matches("# TYPE ntk_runtime_command_cache_hits_total ")
+            .count(),
+        1
+    );
+    assert!(output.contains("# TYPE ntk_runtime_command_cache_hits_total counter\n"));
+    assert!(output.contains("ntk_runtime_command_cache_hits_total 1\n"));
+    assert!(output.contains("# TYPE ntk_runtime_command_cache_hits_total_gauge gauge\n"));
+    assert!(output.contains("ntk_runtime_command_cache_hits_total_gauge 4\n"));
+}
+
+#[test]
+fn test_render_prometheus_exports_timing_histograms_in_seconds() {
+    // Arrange
+    let metrics = Metrics::new();
+    metrics.record_timing("runtime_command_latency_help", Duration::from_millis(20));
+    metrics.record_timing("runtime_command_latency_help", Duration::from_millis(700));
+
+    // Act
+    let output = render_prometheus(&metrics, &[]);
+
+    // Assert
+    let family = "ntk_runtime_command_latency_help_seconds";
+    assert!(output.contains(&format!("# TYPE {family} histogram\n")));
+    assert!(output.contains(&format!("{family}_bucket{{le=\"0.01\"}} 0\n")));
+    assert!(output.contains(&format!("{family}_bucket{{le=\"0.025\"}} 1\n")));
+    assert!(output.contains(&format!("{family}_bucket{{le=\"1\"}} 2\n")));
+    assert!(output.contains(&format!("{family}_bucket{{le=\"+Inf\"}} 2\n")));
+    assert!(output.contains(&format!("{family}_sum 0.72\n")));
+    assert!(output.contains(&format!("{family}_count 2\n")));
+}
+
+#[test]
+fn test_render_prometheus_applies_escaped_constant_labels() {
+    let metrics = Metrics::new();
+    metrics.increment_counter("runtime_commands_total");
+    metrics.record_timing("latency", Duration::from_millis(1));
+
+    let output = render_prometheus(&metrics, &[("service", "ntk"), ("host", "a\"b")]);
+
+    assert!(output.contains("ntk_runtime_commands_total{service=\"ntk\",host=\"a\\\"b\"} 1\n"));
+    assert!(output
+        .contains("ntk_latency_seconds_bucket{service=\"ntk\",host=\"a\\\"b\",le=\"0.005\"} 1\n"));
+}
+
+#[test]
+fn test_render_prometheus_merges_names_that_sanitize_to_same_family() {
+    let metrics = Metrics::new();
+    metrics.increment_counter("cache.hits");
+    metrics.increment_counter("cache-hits");
+
+    let output = render_prometheus(&metrics, &[]);
+
+    assert_eq!(
+        output
+            .matches("# TYPE ntk_cache_hits_total counter")
+            .count(),
+        1
+    );
+    assert!(output.contains("ntk_cache_hits_total 2\n"));
+}
+
+#[test]
+fn test_render_prometheus_is_empty_for_empty_registry() {
+    assert!(render_prometheus(&Metrics::new(), &[]).is_empty());
+}
@@ -0,0 +1,83 @@
+//! Propagation tests
+//!
+//! Tests for W3C trace-context parenting and outgoing header injection.
+
+use nettoolskit_core::TraceContext;
+use nettoolskit_otel::propagation::{
+    current_trace_headers, extract_trace_context, set_span_parent, span_trace_context,
+};
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use std::collections::HashMap;
+use tracing_subscriber::layer::SubscriberExt;
+
+const CALLER_TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+fn with_otel_subscriber<T>(body: impl FnOnce() -> T) -> T {
+    let provider = SdkTracerProvider::builder().build();
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("propagation-tests")));
+    tracing::subscriber::with_default(subscriber, body)
+}
+
+fn caller_context() -> TraceContext {
+    TraceContext::parse(Some(CALLER_TRACEPARENT), Some("vendor=abc")).expect("valid caller")
+}
+
+#[test]
+fn test_extract_trace_context_reads_header_lookup() {
+    let headers: HashMap<&str, &str> = HashMap::from([
+        ("traceparent", CALLER_TRACEPARENT),
+        ("tracestate", "vendor=abc"),
+    ]);
+
+    let trace = extract_trace_context(|name| headers.get(name).copied()).expect("trace");
+
+    assert_eq!(trace, caller_context());
+}
+
+#[test]
+fn test_extract_trace_context_ignores_invalid_header() {
+    let trace = extract_trace_context(|name| (name == "traceparent").then_some("not-a-trace"));
+    assert_eq!(trace, None);
+}
+
+#[test]
+fn test_child_span_keeps_caller_trace_id() {
+    let child = with_otel_subscriber(|| {
+        let span = tracing::info_span!("worker.execute");
+        assert!(set_span_parent(&span, &caller_context()));
+        span_trace_context(&span).expect("span should carry trace context")
+    });
+
+    assert_eq!(child.trace_id(), "4bf92f3577b34da6a3ce929d0e0e4736");
+    assert_ne!(child.parent_id(), "00f067aa0ba902b7");
+    assert!(child.is_sampled());
+    assert_eq!(child.tracestate.as_deref(), Some("vendor=abc"));
+}
+
+#[test]
+fn test_current_trace_headers_inject_active_span() {
+    let headers = with_otel_subscriber(|| {
+        let span = tracing::info_span!("ai.request");
+        set_span_parent(&span, &caller_context());
+        let _entered = span.enter();
+        current_trace_headers()
+    });
+
+    assert_eq!(headers.len(), 2);
+    assert_eq!(headers[0].0, "traceparent");
+    assert!(headers[0]
+        .1
+        .starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+    assert_eq!(headers[1], ("tracestate", "vendor=abc".to_string()));
+}
+
+#[test]
+fn test_propagation_is_noop_without_otel_layer() {
+    let span = tracing::info_span!("no.layer");
+
+    assert!(!set_span_parent(&span, &caller_context()));
+    assert_eq!(span_trace_context(&span), None);
+    assert!(current_trace_headers().is_empty());
+}
@@ -3,6 +3,8 @@
 //! Main test suite aggregator for nettoolskit-otel crate.
 //! Test structure mirrors the src/ directory structure:
 //! - error_tests: Error handling tests
+//! - prometheus_tests: Prometheus exposition tests
+//! - propagation_tests: W3C trace-context propagation tests
 //! - telemetry_tests: Telemetry and tracing tests
 //! - tracing_setup_tests: Tracing configuration tests
 
@@ -10,6 +12,12 @@
 #[path = "error_tests.rs"]
 mod error_tests;
 
+#[path = "prometheus_tests.rs"]
+mod prometheus_tests;
+
+#[path = "propagation_tests.rs"]
+mod propagation_tests;
+
 #[path = "telemetry_tests.rs"]
 mod telemetry_tests;
 
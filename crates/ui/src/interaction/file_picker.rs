@@ -159,6 +159,36 @@ impl FilePicker {
         Ok(files)
     }
 
+    /// Ranks `paths` against `query` with the same filter modes as [`Self::show`].
+    ///
+    /// Paths that do not match are dropped; ties keep their original order.
+    #[must_use]
+    pub fn rank_paths(&self, paths: &[PathBuf], query: &str) -> Vec<PathBuf> {
+        let mut scored: Vec<(i64, usize, &PathBuf)> = paths
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, path)| {
+                let entry = FilePickerEntry {
+                    display: relative_display_path(&self.root, path),
+                    path: path.clone(),
+                };
+                file_picker_scorer(query, &entry, &entry.display, idx)
+                    .map(|score| (score, idx, path))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored
+            .into_iter()
+            .map(|(_, _, path)| path.clone())
+            .collect()
+    }
+
+    /// Path shown for `path`, relative to the picker root when possible.
+    #[must_use]
+    pub fn display_path(&self, path: &Path) -> String {
+        relative_display_path(&self.root, path)
+    }
+
     /// Shows the interactive file picker and returns the selected file path.
     ///
     /// Returns `None` when the picker is cancelled or when no files are found.
@@ -497,6 +527,19 @@ mod tests {
         assert!(score_a > score_b);
     }
 
+    #[test]
+    fn rank_paths_orders_by_score_and_drops_misses() {
+        let picker = FilePicker::new("repo");
+        let paths = vec![
+            PathBuf::from("repo").join("docs").join("readme.md"),
+            PathBuf::from("repo").join("src").join("main.rs"),
+            PathBuf::from("repo").join("src").join("manifest.rs"),
+        ];
+        let ranked = picker.rank_paths(&paths, "lit:main");
+        assert_eq!(ranked, vec![paths[1].clone()]);
+        assert_eq!(picker.rank_paths(&paths, "").len(), 3);
+    }
+
     #[test]
     fn file_picker_scorer_supports_filter_modes() {
         let option = FilePickerEntry {
@@ -0,0 +1,252 @@
+//! Fuzzy matching and frecency ranking.
+//!
+//! Used by [`crate::CommandPalette`] to score labels against a typed query and
+//! to boost entries the operator used often and recently. Both scores are pure
+//! functions of their inputs, so ranking is deterministic for a given history.
+
+use std::collections::HashMap;
+
+const SCORE_MATCH: i64 = 16;
+const BONUS_CONSECUTIVE: i64 = 8;
+const BONUS_BOUNDARY: i64 = 12;
+const BONUS_PREFIX: i64 = 24;
+const PENALTY_LEADING_GAP_MAX: i64 = 15;
+const PENALTY_LENGTH_MAX: i64 = 10;
+
+const HOUR_MS: u64 = 60 * 60 * 1000;
+const DAY_MS: u64 = 24 * HOUR_MS;
+const WEEK_MS: u64 = 7 * DAY_MS;
+const MONTH_MS: u64 = 30 * DAY_MS;
+
+/// Result of matching a query against one candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher is better.
+    pub score: i64,
+    /// Char indices in the candidate matched by the query, ascending.
+    pub positions: Vec<usize>,
+}
+
+/// Match `query` as a case-insensitive subsequence of `candidate`.
+///
+/// Contiguous runs, matches at word boundaries (after `/`, space, `-`, `_`,
+/// `.`) and matches at the very start score higher; leading gaps and longer
+/// candidates cost a little, so `/task` beats `/task list` for `tas`. An empty
+/// query matches everything with score `0`.
+#[must_use]
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+    let haystack: Vec<char> = candidate
+        .chars()
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect();
+
+    let mut options: Vec<Vec<usize>> = Vec::new();
+    // Contiguous occurrences, earliest first; one at a word boundary usually wins.
+    for start in 0..haystack.len().saturating_sub(query.len() - 1) {
+        if haystack[start..start + query.len()] == query[..] {
+            options.push((start..start + query.len()).collect());
+        }
+    }
+    if let Some(greedy) = greedy_positions(&haystack, &query) {
+        options.push(greedy);
+    }
+
+    options
+        .into_iter()
+        .map(|positions| FuzzyMatch {
+            score: score_positions(&haystack, &positions),
+            positions,
+        })
+        // `max_by_key` keeps the last maximum; reverse so the earliest option wins ties.
+        .rev()
+        .max_by_key(|candidate| candidate.score)
+}
+
+fn greedy_positions(haystack: &[char], query: &[char]) -> Option<Vec<usize>> {
+    let mut positions = Vec::with_capacity(query.len());
+    let mut next = 0usize;
+    for ch in query {
+        let offset = haystack[next..]
+            .iter()
+            .position(|candidate| candidate == ch)?;
+        positions.push(next + offset);
+        next += offset + 1;
+    }
+    Some(positions)
+}
+
+fn score_positions(haystack: &[char], positions: &[usize]) -> i64 {
+    let mut score = 0i64;
+    for (index, &position) in positions.iter().enumerate() {
+        score += SCORE_MATCH;
+        if index > 0 && positions[index - 1] + 1 == position {
+            score += BONUS_CONSECUTIVE;
+        }
+        if is_word_boundary(haystack, position) {
+            score += BONUS_BOUNDARY;
+        }
+    }
+    let first = positions.first().copied().unwrap_or(0);
+    if first == 0 {
+        score += BONUS_PREFIX;
+    }
+    score -= i64::try_from(first)
+        .unwrap_or(i64::MAX)
+        .min(PENALTY_LEADING_GAP_MAX);
+    let unmatched = haystack.len().saturating_sub(positions.len());
+    score
+        - i64::try_from(unmatched)
+            .unwrap_or(i64::MAX)
+            .min(PENALTY_LENGTH_MAX)
+}
+
+fn is_word_boundary(haystack: &[char], position: usize) -> bool {
+    position == 0
+        || matches!(
+            haystack.get(position - 1),
+            Some('/' | ' ' | '-' | '_' | '.' | ':')
+        )
+}
+
+/// Usage-based ranking boost built from command history.
+///
+/// Each slash command in the history credits itself and every shorter
+/// whitespace-delimited prefix (`/task list --all` credits `/task list` and
+/// `/task`), weighted by age: the last hour counts 4, the last day 2, the last
+/// week 1, the last month 0.5 and anything older 0.25.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Frecency {
+    weights: HashMap<String, f64>,
+}
+
+impl Frecency {
+    /// Build from `(entry, timestamp_ms)` pairs relative to `now_ms`.
+    ///
+    /// Entries that are not slash commands are ignored.
+    #[must_use]
+    pub fn from_history<'a, I>(entries: I, now_ms: u64) -> Self
+    where
+        I: IntoIterator<Item = (&'a str, u64)>,
+    {
+        let mut weights: HashMap<String, f64> = HashMap::new();
+        for (value, timestamp_ms) in entries {
+            let tokens: Vec<&str> = value.split_whitespace().collect();
+            if !tokens.first().is_some_and(|token| token.starts_with('/')) {
+                continue;
+            }
+            let weight = recency_weight(now_ms.saturating_sub(timestamp_ms));
+            for len in 1..=tokens.len() {
+                *weights
+                    .entry(tokens[..len].join(" ").to_ascii_lowercase())
+                    .or_default() += weight;
+            }
+        }
+        Self { weights }
+    }
+
+    /// Accumulated weight for `label` (`0.0` when never used).
+    #[must_use]
+    pub fn score(&self, label: &str) -> f64 {
+        let key = label
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_ascii_lowercase();
+        self.weights.get(&key).copied().unwrap_or(0.0)
+    }
+
+    /// Whether no history contributed any weight.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+}
+
+fn recency_weight(age_ms: u64) -> f64 {
+    if age_ms < HOUR_MS {
+        4.0
+    } else if age_ms < DAY_MS {
+        2.0
+    } else if age_ms < WEEK_MS {
+        1.0
+    } else if age_ms < MONTH_MS {
+        0.5
+    } else {
+        0.25
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_prefers_prefix_and_boundaries() {
+        let prefix = fuzzy_match("tas", "task").expect("prefix matches");
+        let inner = fuzzy_match("tas", "a tasks").expect("inner matches");
+        let scattered = fuzzy_match("tas", "xtxaxs").expect("subsequence matches");
+
+        assert_eq!(prefix.positions, vec![0, 1, 2]);
+        assert_eq!(scattered.positions, vec![1, 3, 5]);
+        assert!(prefix.score > inner.score);
+        assert!(inner.score > scattered.score);
+    }
+
+    #[test]
+    fn fuzzy_match_picks_boundary_occurrence_over_earlier_substring() {
+        let matched = fuzzy_match("list", "blister list").expect("matches");
+        assert_eq!(matched.positions, vec![8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn fuzzy_match_reports_subsequence_positions() {
+        let matched = fuzzy_match("mfa", "manifest apply").expect("matches");
+        assert_eq!(matched.positions, vec![0, 4, 9]);
+        assert!(fuzzy_match("xyz", "manifest apply").is_none());
+    }
+
+    #[test]
+    fn fuzzy_match_prefers_shorter_candidates_on_ties() {
+        let short = fuzzy_match("task", "task").expect("matches");
+        let long = fuzzy_match("task", "task list").expect("matches");
+        assert!(short.score > long.score);
+    }
+
+    #[test]
+    fn fuzzy_match_empty_query_matches_everything() {
+        let matched = fuzzy_match("  ", "anything").expect("matches");
+        assert_eq!(matched.score, 0);
+        assert!(matched.positions.is_empty());
+    }
+
+    #[test]
+    fn frecency_credits_command_prefixes_with_recency_weights() {
+        let now = 100 * DAY_MS;
+        let frecency = Frecency::from_history(
+            [
+                ("/task list --all", now - 10),
+                ("/task submit", now - 2 * DAY_MS),
+                ("/ai ask hi", now - 60 * DAY_MS),
+                ("free text", now),
+            ],
+            now,
+        );
+
+        assert_eq!(frecency.score("/task"), 5.0);
+        assert_eq!(frecency.score("/task list"), 4.0);
+        assert_eq!(frecency.score("/TASK  submit"), 1.0);
+        assert_eq!(frecency.score("/ai"), 0.25);
+        assert_eq!(frecency.score("free"), 0.0);
+    }
+}
@@ -6,6 +6,8 @@
 pub mod clipboard;
 /// Interactive file picker for path discovery and selection.
 pub mod file_picker;
+/// Fuzzy matching and frecency ranking for palettes.
+pub mod fuzzy;
 /// Interactive history viewer with filtering and pagination.
 pub mod history_viewer;
 /// Cross-platform desktop notification helpers.
@@ -1,10 +1,12 @@
 use crate::core::colors::Color;
 use crate::core::formatting::format_menu_item;
+use crate::interaction::fuzzy::{fuzzy_match, Frecency};
+use crate::rendering::components::menu::menu_render_config;
 use crate::rendering::components::{
-    render_box, render_command, render_interactive_menu, render_menu_instructions, BoxConfig,
-    MenuConfig,
+    render_box, render_command, render_menu_instructions, BoxConfig,
 };
 use crossterm::terminal;
+use inquire::Select;
 use nettoolskit_core::MenuEntry;
 use owo_colors::OwoColorize;
 
@@ -13,6 +15,15 @@ const COMPACT_TERMINAL_WIDTH: usize = 60;
 const DEFAULT_ALIGN_COLUMN: usize = 20;
 const NARROW_ALIGN_COLUMN: usize = 14;
 const COMPACT_ALIGN_COLUMN: usize = 10;
+const MENU_PAGE_SIZE: usize = 6;
+
+/// Any label match outranks every description-only match.
+const LABEL_MATCH_TIER: i64 = 10_000;
+/// Ranking points per unit of frecency weight.
+const FRECENCY_WEIGHT: f64 = 6.0;
+/// Upper bound on the frecency boost so a heavily used entry cannot bury a
+/// much closer label match.
+const FRECENCY_BOOST_MAX: i64 = 60;
 
 /// Entry type used internally by CommandPalette
 struct PaletteEntry {
@@ -30,10 +41,22 @@ impl MenuEntry for PaletteEntry {
     }
 }
 
+/// One ranked palette row returned by [`CommandPalette::matching_entries`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteMatch<'a> {
+    /// Entry label, e.g. `/task list`.
+    pub label: &'a str,
+    /// Entry description.
+    pub description: &'a str,
+    /// Char indices in `label` matched by the query, for highlighting.
+    pub positions: Vec<usize>,
+}
+
 /// Command palette for interactive menu display.
 ///
 /// Uses boxed layout (manifest style) with full-screen menu, border,
-/// title, and subtitle for visual clarity and consistency.
+/// title, and subtitle for visual clarity and consistency. Entries are
+/// filtered with fuzzy matching and ranked with an optional [`Frecency`].
 pub struct CommandPalette {
     /// All available entries
     all_entries: Vec<PaletteEntry>,
@@ -45,6 +68,8 @@ pub struct CommandPalette {
     directory: Option<String>,
     /// Prompt text for the menu selection (defaults to "Select →")
     prompt: Option<String>,
+    /// Usage history boost applied on top of fuzzy scores
+    frecency: Frecency,
 }
 
 impl CommandPalette {
@@ -72,6 +97,7 @@ impl CommandPalette {
             subtitle: None,
             directory: None,
             prompt: None,
+            frecency: Frecency::default(),
         }
     }
 
@@ -131,6 +157,20 @@ impl CommandPalette {
         self
     }
 
+    /// Boost entries by how often and how recently they were used.
+    ///
+    /// # Arguments
+    ///
+    /// * `frecency` - Usage weights, usually built from the persisted history
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining.
+    pub fn with_frecency(mut self, frecency: Frecency) -> Self {
+        self.frecency = frecency;
+        self
+    }
+
     /// Reloads the palette with new menu entries.
     ///
     /// # Arguments
@@ -146,6 +186,64 @@ impl CommandPalette {
             .collect();
     }
 
+    /// Returns entries matching `query`, best match first.
+    ///
+    /// Labels are fuzzy-matched case-insensitively (a leading `/` is ignored
+    /// on both sides); entries whose description merely contains the query
+    /// rank after every label match. Frecency boosts entries within each tier
+    /// and orders the list for an empty query. Ties keep menu order. Used by
+    /// the full-screen TUI, which renders the palette itself instead of
+    /// calling [`Self::show`].
+    #[must_use]
+    pub fn matching_entries(&self, query: &str) -> Vec<PaletteMatch<'_>> {
+        let mut ranked: Vec<(i64, usize, PaletteMatch<'_>)> = self
+            .all_entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| {
+                let (rank, positions) = self.rank_entry(entry, query)?;
+                Some((
+                    rank,
+                    index,
+                    PaletteMatch {
+                        label: &entry.label,
+                        description: &entry.description,
+                        positions,
+                    },
+                ))
+            })
+            .collect();
+        ranked.sort_by(|left, right| right.0.cmp(&left.0).then(left.1.cmp(&right.1)));
+        ranked.into_iter().map(|(_, _, matched)| matched).collect()
+    }
+
+    /// Ranking key for `entry` and the char positions matched in its label.
+    fn rank_entry(&self, entry: &PaletteEntry, query: &str) -> Option<(i64, Vec<usize>)> {
+        let needle = query.trim().trim_start_matches('/');
+        let boost = ((self.frecency.score(&entry.label) * FRECENCY_WEIGHT).round() as i64)
+            .min(FRECENCY_BOOST_MAX);
+        if needle.is_empty() {
+            return Some((boost, Vec::new()));
+        }
+
+        let stripped = entry.label.trim_start_matches('/');
+        let offset = entry.label.chars().count() - stripped.chars().count();
+        if let Some(matched) = fuzzy_match(needle, stripped) {
+            let positions = matched
+                .positions
+                .into_iter()
+                .map(|position| position + offset)
+                .collect();
+            return Some((LABEL_MATCH_TIER + matched.score + boost, positions));
+        }
+
+        entry
+            .description
+            .to_ascii_lowercase()
+            .contains(&needle.to_ascii_lowercase())
+            .then_some((boost, Vec::new()))
+    }
+
     /// Shows the menu and returns the selected option.
     ///
     /// This function displays a full-screen boxed menu (manifest style) with the
@@ -213,14 +311,15 @@ impl CommandPalette {
         render_menu_instructions();
         println!();
 
-        // Build displayable items for inquire menu with aligned descriptions
-        let display_items: Vec<String> = self
-            .all_entries
+        // Build displayable items for inquire menu with aligned descriptions,
+        // most frequently and recently used first
+        let ordered = self.matching_entries("");
+        let display_items: Vec<String> = ordered
             .iter()
             .map(|entry| {
                 format_menu_item(
-                    entry.label(),
-                    entry.description(),
+                    entry.label,
+                    entry.description,
                     align_column_for_width(terminal_width),
                 )
             })
@@ -236,9 +335,22 @@ impl CommandPalette {
             terminal_width,
             crate::capabilities().unicode,
         ));
-        let menu_config = MenuConfig::new(prompt, display_items).with_cursor_color(Color::PURPLE);
-
-        match render_interactive_menu(menu_config) {
+        let scorer = |input: &str, _item: &String, _value: &str, index: usize| {
+            let label = ordered.get(index)?.label;
+            let entry = self.all_entries.iter().find(|entry| entry.label == label)?;
+            let (rank, _) = self.rank_entry(entry, input)?;
+            // Keep the frecency order between equally ranked rows.
+            let index = i64::try_from(index).unwrap_or(i64::MAX);
+            Some(rank.saturating_mul(1024).saturating_sub(index))
+        };
+
+        match Select::new(prompt, display_items)
+            .with_page_size(MENU_PAGE_SIZE)
+            .with_render_config(menu_render_config(Color::PURPLE))
+            .without_help_message()
+            .with_scorer(&scorer)
+            .prompt()
+        {
             Ok(selected) => {
                 // Extract label from formatted string "   / help           - description"
                 // The format_menu_item adds padding between label and description
@@ -283,7 +395,86 @@ fn default_prompt_for_width(width: Option<usize>, unicode: bool) -> &'static str
 
 #[cfg(test)]
 mod tests {
-    use super::{align_column_for_width, default_prompt_for_width, is_narrow_terminal};
+    use super::{
+        align_column_for_width, default_prompt_for_width, is_narrow_terminal, CommandPalette,
+        PaletteEntry,
+    };
+    use crate::interaction::fuzzy::Frecency;
+
+    fn labels<'a>(palette: &'a CommandPalette, query: &str) -> Vec<&'a str> {
+        palette
+            .matching_entries(query)
+            .into_iter()
+            .map(|matched| matched.label)
+            .collect()
+    }
+
+    fn palette() -> CommandPalette {
+        CommandPalette::new(vec![
+            PaletteEntry {
+                label: "/help".to_string(),
+                description: "Show available commands".to_string(),
+            },
+            PaletteEntry {
+                label: "/task".to_string(),
+                description: "Manage background tasks".to_string(),
+            },
+            PaletteEntry {
+                label: "/ai".to_string(),
+                description: "Query the model".to_string(),
+            },
+        ])
+    }
+
+    #[test]
+    fn matching_entries_ranks_prefix_before_description_matches() {
+        let palette = palette();
+        assert_eq!(labels(&palette, "/ta"), vec!["/task"]);
+        assert_eq!(labels(&palette, "a"), vec!["/ai", "/task", "/help"]);
+    }
+
+    #[test]
+    fn matching_entries_fuzzy_matches_and_reports_label_positions() {
+        let palette = palette();
+        let matches = palette.matching_entries("tk");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].label, "/task");
+        assert_eq!(matches[0].positions, vec![1, 4]);
+    }
+
+    #[test]
+    fn frecency_orders_empty_query_and_breaks_close_matches() {
+        let now = 1_000_000_000;
+        let frecency = Frecency::from_history(
+            [
+                ("/help", now - 1),
+                ("/help", now - 2),
+                ("/task list", now - 3),
+            ],
+            now,
+        );
+        let palette = palette().with_frecency(frecency);
+
+        assert_eq!(labels(&palette, ""), vec!["/help", "/task", "/ai"]);
+        // Label matches still beat description-only matches.
+        assert_eq!(labels(&palette, "a"), vec!["/ai", "/task", "/help"]);
+        // Deterministic: repeated ranking yields the same order.
+        assert_eq!(labels(&palette, "a"), labels(&palette, "a"));
+    }
+
+    #[test]
+    fn frecency_lifts_frequently_used_label_matches() {
+        let now = 1_000_000_000;
+        let heavy: Vec<(&str, u64)> = (0..100).map(|offset| ("/task", now - offset)).collect();
+        let palette = palette().with_frecency(Frecency::from_history(heavy, now));
+        assert_eq!(labels(&palette, "a"), vec!["/task", "/ai", "/help"]);
+        assert_eq!(labels(&palette, "ai"), vec!["/ai", "/help"]);
+    }
+
+    #[test]
+    fn matching_entries_returns_all_for_empty_query() {
+        assert_eq!(palette().matching_entries("/").len(), 3);
+    }
 
     #[test]
     fn narrow_terminal_detection_works() {
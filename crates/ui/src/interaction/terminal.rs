@@ -222,6 +222,15 @@ pub fn begin_interactive_logging() -> InteractiveLogGuard {
     InteractiveLogGuard { active: true }
 }
 
+/// Take the footer log lines queued while no [`TerminalLayout`] is active.
+///
+/// Full-screen renderers own the footer themselves and drain this queue on
+/// every frame instead of letting the lines be flushed to stdout.
+pub fn drain_pending_footer_logs() -> Vec<String> {
+    let mut pending = PENDING_LOGS.lock().unwrap_or_else(|e| e.into_inner());
+    pending.drain(..).collect()
+}
+
 /// Disable interactive logging mode and clear pending buffers.
 pub fn disable_interactive_logging() {
     INTERACTIVE_MODE.store(false, Ordering::SeqCst);
@@ -927,13 +936,13 @@ pub fn append_footer_log(line: &str) -> io::Result<()> {
 #[cfg(test)]
 mod tests {
     use super::{
-        append_footer_log, calculate_layout_metrics, clamp_cursor_to_metrics, emit_attention_bell,
-        focus_detection_enabled, footer_output_enabled, handle_resize, normalize_log_entry,
-        pad_to_width, prepare_prompt_line, process_pending_resize, reset_layout,
-        set_focus_detection_enabled, set_footer_output_enabled, set_terminal_focused,
-        should_emit_attention_signal, terminal_focused, truncate_to_width, FrameScheduler,
-        LayoutMetrics, FOOTER_TARGET_HEIGHT, INTERACTIVE_MODE, MIN_DYNAMIC_HEIGHT, PENDING_RESIZE,
-        RECONFIGURING, RESIZE_DEBOUNCE_MS,
+        append_footer_log, calculate_layout_metrics, clamp_cursor_to_metrics,
+        drain_pending_footer_logs, emit_attention_bell, focus_detection_enabled,
+        footer_output_enabled, handle_resize, normalize_log_entry, pad_to_width,
+        prepare_prompt_line, process_pending_resize, reset_layout, set_focus_detection_enabled,
+        set_footer_output_enabled, set_terminal_focused, should_emit_attention_signal,
+        terminal_focused, truncate_to_width, FrameScheduler, LayoutMetrics, FOOTER_TARGET_HEIGHT,
+        INTERACTIVE_MODE, MIN_DYNAMIC_HEIGHT, PENDING_RESIZE, RECONFIGURING, RESIZE_DEBOUNCE_MS,
     };
     use serial_test::serial;
     use std::collections::VecDeque;
@@ -1400,6 +1409,25 @@ mod tests {
         pending.clear();
     }
 
+    #[test]
+    #[serial]
+    fn drain_pending_footer_logs_returns_queued_lines_once() {
+        let previous_state = footer_output_enabled();
+        set_footer_output_enabled(true);
+        INTERACTIVE_MODE.store(true, Ordering::SeqCst);
+        let _ = drain_pending_footer_logs();
+
+        append_footer_log("first").expect("queue first line");
+        append_footer_log("second").expect("queue second line");
+        let drained = drain_pending_footer_logs();
+
+        INTERACTIVE_MODE.store(false, Ordering::SeqCst);
+        set_footer_output_enabled(previous_state);
+
+        assert_eq!(drained, vec!["first".to_string(), "second".to_string()]);
+        assert!(drain_pending_footer_logs().is_empty());
+    }
+
     #[test]
     #[serial]
     fn append_footer_log_writes_stdout_when_not_interactive() {
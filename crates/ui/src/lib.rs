@@ -31,9 +31,10 @@ pub use core::style;
 // Re-export components
 pub use interaction::clipboard::{copy_to_clipboard, paste_from_clipboard};
 pub use interaction::file_picker::FilePicker;
+pub use interaction::fuzzy::{fuzzy_match, Frecency, FuzzyMatch};
 pub use interaction::history_viewer::HistoryViewer;
 pub use interaction::notification::emit_desktop_attention_notification;
-pub use interaction::palette::CommandPalette;
+pub use interaction::palette::{CommandPalette, PaletteMatch};
 pub use interaction::prompt::*;
 pub use interaction::status_bar::{StatusBar, StatusBarMode, StatusNotificationLevel};
 pub use interaction::terminal::*;
@@ -41,5 +42,8 @@ pub use rendering::components::{
     format_menu_item, render_box, render_command, render_enum_menu, render_interactive_menu,
     render_menu_instructions, render_section_title, BoxConfig, EnumMenuConfig, MenuConfig,
 };
-pub use rendering::markdown::render_markdown;
+pub use rendering::markdown::{render_markdown, render_markdown_with_width};
+pub use rendering::syntax::{
+    detect_syntax_language, highlight_line_for_language, SyntaxLanguage, SyntaxTheme,
+};
 pub use rendering::writer::UiWriter;
@@ -74,35 +74,41 @@ where
         println!();
     }
 
-    // Configure render config with cursor color
+    let render_config = menu_render_config(config.cursor_color);
+
+    // Build and execute the select prompt
+    // Always disable inquire's built-in help message
+    Select::new(&config.prompt, config.items)
+        .with_page_size(config.page_size)
+        .with_render_config(render_config)
+        .without_help_message()
+        .prompt()
+}
+
+/// Inquire render config shared by menus: `?` prefix, `❯` cursor and the
+/// selected option in `cursor_color`.
+pub(crate) fn menu_render_config(cursor_color: Rgb) -> RenderConfig<'static> {
     let mut render_config = RenderConfig::default();
     render_config.prompt_prefix = Styled::new("?").with_fg(InquireColor::Rgb {
-        r: config.cursor_color.0,
-        g: config.cursor_color.1,
-        b: config.cursor_color.2,
+        r: cursor_color.0,
+        g: cursor_color.1,
+        b: cursor_color.2,
     });
     render_config.highlighted_option_prefix = Styled::new("❯").with_fg(InquireColor::Rgb {
-        r: config.cursor_color.0,
-        g: config.cursor_color.1,
-        b: config.cursor_color.2,
+        r: cursor_color.0,
+        g: cursor_color.1,
+        b: cursor_color.2,
     });
     render_config.selected_option = Some(
         render_config
             .selected_option
             .unwrap_or_default()
             .with_fg(InquireColor::Rgb {
-                r: config.cursor_color.0,
-                g: config.cursor_color.1,
-                b: config.cursor_color.2,
+                r: cursor_color.0,
+                g: cursor_color.1,
+                b: cursor_color.2,
             }),
     );
     render_config.help_message = render_config.help_message.with_fg(InquireColor::DarkYellow);
-
-    // Build and execute the select prompt
-    // Always disable inquire's built-in help message
-    Select::new(&config.prompt, config.items)
-        .with_page_size(config.page_size)
-        .with_render_config(render_config)
-        .without_help_message()
-        .prompt()
+    render_config
 }
@@ -1,16 +1,28 @@
 //! Markdown rendering helpers for terminal output.
 //!
 //! Converts Markdown into readable terminal text, preserving headings, lists,
-//! code spans/blocks, links, and basic emphasis.
+//! task lists, code spans/blocks, links, emphasis, block quotes, footnotes and
+//! GFM tables. Fenced Rust, C#, JavaScript and TypeScript blocks are
+//! highlighted with tree-sitter through [`super::syntax`].
 
+use super::syntax::{highlight_line_for_language, SyntaxLanguage, SyntaxTheme};
 use crate::core::capabilities::capabilities;
+use crossterm::terminal;
 use owo_colors::OwoColorize;
-use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+#[derive(Debug, Clone, Copy)]
+struct RenderOptions {
+    color: bool,
+    unicode: bool,
+    width: usize,
+}
 
 #[derive(Debug, Clone, Copy, Default)]
 struct InlineState {
     strong: usize,
     emphasis: usize,
+    strikethrough: usize,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -19,6 +31,51 @@ struct ListState {
     next_index: u64,
 }
 
+/// GFM table buffered until its end tag so column widths can be fitted.
+#[derive(Debug, Default)]
+struct TableState {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<String>>,
+    has_header: bool,
+}
+
+impl TableState {
+    fn new(alignments: Vec<Alignment>) -> Self {
+        Self {
+            alignments,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TableBorder {
+    top: [&'static str; 3],
+    middle: [&'static str; 3],
+    bottom: [&'static str; 3],
+    vertical: &'static str,
+}
+
+impl TableBorder {
+    const fn pick(unicode: bool) -> Self {
+        if unicode {
+            Self {
+                top: ["┌", "┬", "┐"],
+                middle: ["├", "┼", "┤"],
+                bottom: ["└", "┴", "┘"],
+                vertical: "│",
+            }
+        } else {
+            Self {
+                top: ["+", "+", "+"],
+                middle: ["+", "+", "+"],
+                bottom: ["+", "+", "+"],
+                vertical: "|",
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum CodeBlockLanguage {
     Plain,
@@ -32,6 +89,19 @@ enum CodeBlockLanguage {
     PowerShell,
 }
 
+impl CodeBlockLanguage {
+    /// Languages with a tree-sitter grammar; the rest use the lexical pass.
+    const fn tree_sitter_language(self) -> Option<SyntaxLanguage> {
+        match self {
+            Self::Rust => Some(SyntaxLanguage::Rust),
+            Self::CSharp => Some(SyntaxLanguage::CSharp),
+            Self::JavaScript => Some(SyntaxLanguage::JavaScript),
+            Self::TypeScript => Some(SyntaxLanguage::TypeScript),
+            _ => None,
+        }
+    }
+}
+
 const ANSI_CODE_DEFAULT: &str = "\x1b[90m";
 const ANSI_CODE_KEYWORD: &str = "\x1b[1;94m";
 const ANSI_CODE_STRING: &str = "\x1b[33m";
@@ -39,6 +109,22 @@ const ANSI_CODE_COMMENT: &str = "\x1b[2;90m";
 const ANSI_CODE_NUMBER: &str = "\x1b[36m";
 const ANSI_RESET: &str = "\x1b[0m";
 
+/// Theme handed to the tree-sitter highlighter so fenced blocks keep the
+/// palette of the lexical fallback.
+const CODE_BLOCK_THEME: SyntaxTheme = SyntaxTheme {
+    command: ANSI_CODE_DEFAULT,
+    flag: ANSI_CODE_DEFAULT,
+    keyword: ANSI_CODE_KEYWORD,
+    string: ANSI_CODE_STRING,
+    comment: ANSI_CODE_COMMENT,
+    reset: ANSI_RESET,
+};
+
+/// Table width used when the terminal size is unknown (pipes, tests).
+const DEFAULT_RENDER_WIDTH: usize = 100;
+/// Columns never shrink below this width while fitting a table.
+const MIN_TABLE_COLUMN_WIDTH: usize = 3;
+
 const RUST_CODE_KEYWORDS: &[&str] = &[
     "as", "break", "const", "continue", "crate", "else", "enum", "extern", "fn", "for", "if",
     "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self",
@@ -137,10 +223,31 @@ const POWERSHELL_CODE_KEYWORDS: &[&str] = &[
 /// Render Markdown into a terminal-friendly string.
 ///
 /// The renderer is intentionally lightweight and optimized for CLI help output.
-/// It supports headings, paragraphs, ordered/unordered lists, links, inline
-/// code, fenced code blocks, and emphasis markers.
+/// It supports headings, paragraphs, ordered/unordered lists, task lists, links,
+/// inline code, fenced code blocks, emphasis, strikethrough, block quotes,
+/// footnotes and GFM tables. Tables are fitted to the current terminal width.
 #[must_use]
 pub fn render_markdown(markdown: &str) -> String {
+    render_markdown_with_width(markdown, terminal_width())
+}
+
+/// Render Markdown, fitting tables into `width` columns.
+///
+/// Color and box-drawing characters follow [`capabilities`].
+#[must_use]
+pub fn render_markdown_with_width(markdown: &str, width: usize) -> String {
+    let caps = capabilities();
+    render_with_options(
+        markdown,
+        RenderOptions {
+            color: caps.color.has_color(),
+            unicode: caps.unicode,
+            width,
+        },
+    )
+}
+
+fn render_with_options(markdown: &str, options: RenderOptions) -> String {
     let parser = Parser::new_ext(
         markdown,
         Options::ENABLE_STRIKETHROUGH
@@ -149,12 +256,14 @@ pub fn render_markdown(markdown: &str) -> String {
             | Options::ENABLE_FOOTNOTES,
     );
 
-    let color_enabled = capabilities().color.has_color();
+    let color_enabled = options.color;
 
     let mut out = String::new();
     let mut inline = InlineState::default();
     let mut heading_level: Option<HeadingLevel> = None;
     let mut list_stack: Vec<ListState> = Vec::new();
+    let mut quote_stack: Vec<String> = Vec::new();
+    let mut table: Option<TableState> = None;
     let mut code_block = false;
     let mut code_block_language: Option<CodeBlockLanguage> = None;
     let mut current_link: Option<String> = None;
@@ -171,6 +280,12 @@ pub fn render_markdown(markdown: &str) -> String {
                         out.push(' ');
                     }
                 }
+                Tag::BlockQuote(_) => {
+                    ensure_section_break(&mut out);
+                    // Quotes render into a fresh buffer so the gutter can be
+                    // applied to every line, including nested quotes.
+                    quote_stack.push(std::mem::take(&mut out));
+                }
                 Tag::List(start) => {
                     ensure_line_break(&mut out);
                     let next_index = start.unwrap_or(1);
@@ -199,8 +314,34 @@ pub fn render_markdown(markdown: &str) -> String {
                     code_block = true;
                     code_block_language = extract_code_block_language(&kind);
                 }
+                Tag::FootnoteDefinition(name) => {
+                    ensure_line_break(&mut out);
+                    let label = format!("[{name}]:");
+                    if color_enabled {
+                        out.push_str(&format!("{} ", label.bright_black()));
+                    } else {
+                        out.push_str(&format!("{label} "));
+                    }
+                }
+                Tag::Table(alignments) => table = Some(TableState::new(alignments)),
+                Tag::TableHead | Tag::TableRow => {
+                    if let Some(table) = table.as_mut() {
+                        table.rows.push(Vec::new());
+                    }
+                }
+                Tag::TableCell => {
+                    if let Some(row) = table.as_mut().and_then(|table| table.rows.last_mut()) {
+                        row.push(String::new());
+                    }
+                }
                 Tag::Emphasis => inline.emphasis = inline.emphasis.saturating_add(1),
                 Tag::Strong => inline.strong = inline.strong.saturating_add(1),
+                Tag::Strikethrough => {
+                    inline.strikethrough = inline.strikethrough.saturating_add(1);
+                    if table.is_some() || !color_enabled {
+                        push_inline(&mut out, &mut table, "~~");
+                    }
+                }
                 Tag::Link { dest_url, .. } => current_link = Some(dest_url.to_string()),
                 _ => {}
             },
@@ -210,6 +351,11 @@ pub fn render_markdown(markdown: &str) -> String {
                     heading_level = None;
                     out.push_str("\n\n");
                 }
+                TagEnd::BlockQuote(_) => {
+                    let quoted = std::mem::replace(&mut out, quote_stack.pop().unwrap_or_default());
+                    out.push_str(&apply_quote_gutter(&quoted, options));
+                    out.push_str("\n\n");
+                }
                 TagEnd::List(_) => {
                     list_stack.pop();
                     out.push('\n');
@@ -220,20 +366,45 @@ pub fn render_markdown(markdown: &str) -> String {
                     code_block_language = None;
                     out.push_str("\n\n");
                 }
+                TagEnd::TableHead => {
+                    if let Some(table) = table.as_mut() {
+                        table.has_header = true;
+                    }
+                }
+                TagEnd::Table => {
+                    if let Some(finished) = table.take() {
+                        ensure_section_break(&mut out);
+                        // Leave room for the gutter of any enclosing quotes.
+                        let width = options.width.saturating_sub(quote_stack.len() * 2);
+                        out.push_str(&render_table(&finished, width, options));
+                        out.push_str("\n\n");
+                    }
+                }
                 TagEnd::Emphasis => inline.emphasis = inline.emphasis.saturating_sub(1),
                 TagEnd::Strong => inline.strong = inline.strong.saturating_sub(1),
+                TagEnd::Strikethrough => {
+                    inline.strikethrough = inline.strikethrough.saturating_sub(1);
+                    if table.is_some() || !color_enabled {
+                        push_inline(&mut out, &mut table, "~~");
+                    }
+                }
                 TagEnd::Link => {
                     if let Some(link) = current_link.take() {
-                        if color_enabled {
+                        if color_enabled && table.is_none() {
                             out.push_str(&format!(" {}", format!("({link})").blue()));
                         } else {
-                            out.push_str(&format!(" ({link})"));
+                            push_inline(&mut out, &mut table, &format!(" ({link})"));
                         }
                     }
                 }
                 _ => {}
             },
             Event::Text(text) => {
+                if table.is_some() {
+                    // Cells stay unstyled so column widths can be measured.
+                    push_inline(&mut out, &mut table, &text);
+                    continue;
+                }
                 let rendered = if code_block {
                     render_code_block_segment(
                         &text,
@@ -246,23 +417,35 @@ pub fn render_markdown(markdown: &str) -> String {
                 out.push_str(&rendered);
             }
             Event::Code(code) => {
-                if color_enabled {
+                if color_enabled && table.is_none() {
                     out.push_str(&format!("{}", format!("`{code}`").bright_yellow()));
                 } else {
-                    out.push_str(&format!("`{code}`"));
+                    push_inline(&mut out, &mut table, &format!("`{code}`"));
                 }
             }
-            Event::SoftBreak | Event::HardBreak => out.push('\n'),
-            Event::Rule => out.push_str("\n---\n"),
-            Event::Html(text) | Event::InlineHtml(text) => out.push_str(&text),
-            Event::FootnoteReference(name) => out.push_str(&format!("[{name}]")),
-            Event::TaskListMarker(checked) => {
-                if checked {
-                    out.push_str("[x] ");
+            Event::SoftBreak | Event::HardBreak => {
+                if table.is_some() {
+                    push_inline(&mut out, &mut table, " ");
                 } else {
-                    out.push_str("[ ] ");
+                    out.push('\n');
                 }
             }
+            Event::Rule => out.push_str("\n---\n"),
+            Event::Html(text) | Event::InlineHtml(text) => {
+                push_inline(&mut out, &mut table, &text);
+            }
+            Event::FootnoteReference(name) => {
+                push_inline(&mut out, &mut table, &format!("[{name}]"));
+            }
+            Event::TaskListMarker(checked) => {
+                let marker = match (checked, options.unicode) {
+                    (true, true) => "☑ ",
+                    (false, true) => "☐ ",
+                    (true, false) => "[x] ",
+                    (false, false) => "[ ] ",
+                };
+                out.push_str(marker);
+            }
             _ => {}
         }
     }
@@ -298,6 +481,9 @@ fn render_text_segment(
         return styled;
     }
 
+    if inline.strikethrough > 0 {
+        return format!("{}", text.strikethrough());
+    }
     if inline.strong > 0 && inline.emphasis > 0 {
         return format!("{}", text.bold().italic());
     }
@@ -311,6 +497,201 @@ fn render_text_segment(
     text.to_string()
 }
 
+/// Append inline text to the open table cell, or to `out` outside tables.
+fn push_inline(out: &mut String, table: &mut Option<TableState>, text: &str) {
+    match table.as_mut() {
+        Some(table) => {
+            if let Some(cell) = table.rows.last_mut().and_then(|row| row.last_mut()) {
+                cell.push_str(text);
+            }
+        }
+        None => out.push_str(text),
+    }
+}
+
+fn apply_quote_gutter(quoted: &str, options: RenderOptions) -> String {
+    let gutter = if options.unicode { "│" } else { "|" };
+    let gutter = if options.color {
+        format!("{}", gutter.bright_black())
+    } else {
+        gutter.to_string()
+    };
+
+    quoted
+        .trim_end_matches('\n')
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                gutter.clone()
+            } else {
+                format!("{gutter} {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn terminal_width() -> usize {
+    terminal::size()
+        .ok()
+        .map(|(width, _)| usize::from(width))
+        .filter(|width| *width > 0)
+        .unwrap_or(DEFAULT_RENDER_WIDTH)
+}
+
+fn render_table(table: &TableState, width: usize, options: RenderOptions) -> String {
+    let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+    if columns == 0 {
+        return String::new();
+    }
+
+    let natural: Vec<usize> = (0..columns)
+        .map(|column| {
+            table
+                .rows
+                .iter()
+                .filter_map(|row| row.get(column))
+                .map(|cell| cell.trim().chars().count())
+                .max()
+                .unwrap_or(0)
+                .max(1)
+        })
+        .collect();
+    // Every column costs "│ " + " " plus one closing border.
+    let available = width.saturating_sub(columns * 3 + 1);
+    let widths = fit_column_widths(&natural, available);
+
+    let border = TableBorder::pick(options.unicode);
+    let mut lines = Vec::new();
+    lines.push(table_rule(&widths, border.top, options));
+    for (index, row) in table.rows.iter().enumerate() {
+        let header = table.has_header && index == 0;
+        let wrapped: Vec<Vec<String>> = widths
+            .iter()
+            .enumerate()
+            .map(|(column, width)| {
+                wrap_cell(row.get(column).map_or("", |cell| cell.trim()), *width)
+            })
+            .collect();
+        let height = wrapped.iter().map(Vec::len).max().unwrap_or(1);
+
+        for line_index in 0..height {
+            let cells: Vec<String> = wrapped
+                .iter()
+                .enumerate()
+                .map(|(column, cell_lines)| {
+                    let text = cell_lines.get(line_index).map_or("", String::as_str);
+                    let alignment = table
+                        .alignments
+                        .get(column)
+                        .copied()
+                        .unwrap_or(Alignment::None);
+                    let padded = align_cell(text, widths[column], alignment);
+                    if header && options.color {
+                        format!("{}", padded.bold())
+                    } else {
+                        padded
+                    }
+                })
+                .collect();
+            let separator = table_border_text(border.vertical, options);
+            lines.push(format!(
+                "{separator} {} {separator}",
+                cells.join(&format!(" {separator} "))
+            ));
+        }
+
+        if header && table.rows.len() > 1 {
+            lines.push(table_rule(&widths, border.middle, options));
+        }
+    }
+    lines.push(table_rule(&widths, border.bottom, options));
+    lines.join("\n")
+}
+
+/// Shrink the widest columns one cell at a time until the table fits.
+fn fit_column_widths(natural: &[usize], available: usize) -> Vec<usize> {
+    let mut widths = natural.to_vec();
+    let mut total: usize = widths.iter().sum();
+    while total > available {
+        let Some((index, widest)) = widths
+            .iter()
+            .copied()
+            .enumerate()
+            .max_by_key(|(_, width)| *width)
+        else {
+            break;
+        };
+        if widest <= MIN_TABLE_COLUMN_WIDTH {
+            break;
+        }
+        widths[index] -= 1;
+        total -= 1;
+    }
+    widths
+}
+
+fn wrap_cell(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0usize;
+
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        if current_len > 0 && current_len + 1 + word.len() <= width {
+            current.push(' ');
+            current.extend(word.iter());
+            current_len += 1 + word.len();
+            continue;
+        }
+        if current_len > 0 {
+            lines.push(std::mem::take(&mut current));
+        }
+        while word.len() > width {
+            let rest = word.split_off(width);
+            lines.push(word.into_iter().collect());
+            word = rest;
+        }
+        current_len = word.len();
+        current = word.into_iter().collect();
+    }
+
+    if current_len > 0 || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+fn align_cell(text: &str, width: usize, alignment: Alignment) -> String {
+    let padding = width.saturating_sub(text.chars().count());
+    let (left, right) = match alignment {
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+        Alignment::Left | Alignment::None => (0, padding),
+    };
+    format!("{}{text}{}", " ".repeat(left), " ".repeat(right))
+}
+
+fn table_rule(widths: &[usize], parts: [&str; 3], options: RenderOptions) -> String {
+    let [left, junction, right] = parts;
+    let horizontal = if options.unicode { "─" } else { "-" };
+    let segments: Vec<String> = widths
+        .iter()
+        .map(|width| horizontal.repeat(width + 2))
+        .collect();
+    let rule = format!("{left}{}{right}", segments.join(junction));
+    table_border_text(&rule, options)
+}
+
+fn table_border_text(text: &str, options: RenderOptions) -> String {
+    if options.color {
+        format!("{}", text.bright_black())
+    } else {
+        text.to_string()
+    }
+}
+
 fn extract_code_block_language(kind: &CodeBlockKind<'_>) -> Option<CodeBlockLanguage> {
     match kind {
         CodeBlockKind::Indented => Some(CodeBlockLanguage::Plain),
@@ -362,6 +743,13 @@ fn render_code_block_line(line: &str, language: CodeBlockLanguage) -> String {
         (line, "")
     };
 
+    if let Some(highlighted) = language
+        .tree_sitter_language()
+        .and_then(|syntax| highlight_line_for_language(content, syntax, CODE_BLOCK_THEME))
+    {
+        return format!("{highlighted}{newline}");
+    }
+
     let mut out = String::new();
     if let Some(comment_start) = find_comment_start(content, language) {
         let (code_part, comment_part) = content.split_at(comment_start);
@@ -595,10 +983,24 @@ fn heading_rank(level: HeadingLevel) -> usize {
 #[cfg(test)]
 mod tests {
     use super::{
-        find_comment_start, parse_code_block_language, render_code_block_segment,
-        CodeBlockLanguage, ANSI_CODE_KEYWORD, ANSI_CODE_STRING,
+        find_comment_start, fit_column_widths, parse_code_block_language,
+        render_code_block_segment, render_with_options, wrap_cell, CodeBlockLanguage,
+        RenderOptions, ANSI_CODE_KEYWORD, ANSI_CODE_STRING,
     };
 
+    const PLAIN_UNICODE: RenderOptions = RenderOptions {
+        color: false,
+        unicode: true,
+        width: 80,
+    };
+    const PLAIN_ASCII: RenderOptions = RenderOptions {
+        color: false,
+        unicode: false,
+        width: 80,
+    };
+
+    const TABLE: &str = "| Command | Description |\n|:--|--:|\n| `/help` | Show help |\n| `/ai ask` | Ask the assistant a question |";
+
     #[test]
     fn parse_code_block_language_maps_common_aliases() {
         assert_eq!(
@@ -624,7 +1026,7 @@ mod tests {
         let rendered =
             render_code_block_segment("let name = \"ntk\";", CodeBlockLanguage::Rust, true);
         assert!(rendered.contains(&format!("{ANSI_CODE_KEYWORD}let")));
-        assert!(rendered.contains(&format!("{ANSI_CODE_STRING}\"ntk\"")));
+        assert!(rendered.contains(&format!("{ANSI_CODE_STRING}ntk")));
     }
 
     #[test]
@@ -646,4 +1048,94 @@ mod tests {
             "// trailing comment"
         );
     }
+
+    #[test]
+    fn tables_render_with_box_drawing_borders() {
+        let rendered = render_with_options(TABLE, PLAIN_UNICODE);
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].starts_with("┌─"));
+        assert_eq!(lines[1], "│ Command   │                  Description │");
+        assert!(lines[2].starts_with("├─") && lines[2].contains('┼'));
+        assert_eq!(lines[3], "│ `/help`   │                    Show help │");
+        assert!(lines[5].starts_with("└─") && lines[5].ends_with('┘'));
+    }
+
+    #[test]
+    fn tables_fall_back_to_ascii_and_wrap_to_width() {
+        let options = RenderOptions {
+            width: 30,
+            ..PLAIN_ASCII
+        };
+        let rendered = render_with_options(TABLE, options);
+
+        assert!(rendered.starts_with("+-"));
+        assert!(!rendered.contains('│'));
+        for line in rendered.lines() {
+            assert!(line.chars().count() <= 30, "line too wide: {line:?}");
+        }
+        assert!(rendered.contains("assistant"));
+    }
+
+    #[test]
+    fn fit_column_widths_shrinks_widest_column_first() {
+        assert_eq!(fit_column_widths(&[4, 20], 30), vec![4, 20]);
+        assert_eq!(fit_column_widths(&[4, 20], 14), vec![4, 10]);
+        assert_eq!(fit_column_widths(&[4, 20], 2), vec![3, 3]);
+    }
+
+    #[test]
+    fn wrap_cell_breaks_on_words_and_splits_long_tokens() {
+        assert_eq!(
+            wrap_cell("ask the assistant", 9),
+            vec!["ask the", "assistant"]
+        );
+        assert_eq!(wrap_cell("abcdefgh", 3), vec!["abc", "def", "gh"]);
+        assert_eq!(wrap_cell("", 5), vec![""]);
+    }
+
+    #[test]
+    fn block_quotes_render_with_gutter() {
+        let rendered = render_with_options("> first\n>\n> > nested", PLAIN_UNICODE);
+        assert_eq!(rendered, "│ first\n│\n│ │ nested");
+
+        let ascii = render_with_options("> quoted", PLAIN_ASCII);
+        assert_eq!(ascii, "| quoted");
+    }
+
+    #[test]
+    fn task_lists_render_as_checkboxes() {
+        let input = "- [x] done\n- [ ] pending";
+        let unicode = render_with_options(input, PLAIN_UNICODE);
+        assert!(unicode.contains("☑ done"));
+        assert!(unicode.contains("☐ pending"));
+
+        let ascii = render_with_options(input, PLAIN_ASCII);
+        assert!(ascii.contains("[x] done"));
+        assert!(ascii.contains("[ ] pending"));
+    }
+
+    #[test]
+    fn strikethrough_keeps_markers_without_color() {
+        let rendered = render_with_options("Use ~~old~~ new.", PLAIN_ASCII);
+        assert_eq!(rendered, "Use ~~old~~ new.");
+
+        let colored = render_with_options(
+            "~~old~~",
+            RenderOptions {
+                color: true,
+                ..PLAIN_ASCII
+            },
+        );
+        assert!(colored.contains("\x1b[9m"));
+        assert!(!colored.contains("~~"));
+    }
+
+    #[test]
+    fn footnotes_render_reference_and_definition() {
+        let rendered = render_with_options("See note[^1].\n\n[^1]: Details.", PLAIN_ASCII);
+        assert!(rendered.contains("See note[1]."));
+        assert!(rendered.contains("[1]: Details."));
+    }
 }
@@ -5,5 +5,7 @@
 pub mod components;
 /// Lightweight Markdown-to-terminal renderer.
 pub mod markdown;
+/// Tree-sitter backed single-line syntax highlighting.
+pub mod syntax;
 /// Output writer for terminal rendering.
 pub mod writer;
@@ -0,0 +1,712 @@
+//! Single-line syntax highlighting.
+//!
+//! Shared by the interactive input line editor and fenced code blocks in
+//! [`super::markdown`]. Tree-sitter grammars (Rust, C#, JavaScript,
+//! TypeScript) classify comments, strings and keywords; a lexical pass covers
+//! slash commands, overlong lines and lines the grammar cannot classify.
+
+use std::cell::RefCell;
+use tree_sitter::{Node, Parser};
+
+const MAX_HIGHLIGHT_LINE_BYTES: usize = 2048;
+
+/// Language used to pick a highlighting grammar and keyword set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxLanguage {
+    /// No highlighting.
+    Plain,
+    /// `ntk` slash command with flags.
+    Command,
+    /// Rust source.
+    Rust,
+    /// C# source.
+    CSharp,
+    /// JavaScript source.
+    JavaScript,
+    /// TypeScript source.
+    TypeScript,
+}
+
+/// ANSI escape sequences applied per token class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxTheme {
+    /// Leading slash command.
+    pub command: &'static str,
+    /// Command flags (`--path`, `-v`).
+    pub flag: &'static str,
+    /// Language keywords.
+    pub keyword: &'static str,
+    /// String and character literals.
+    pub string: &'static str,
+    /// Comments.
+    pub comment: &'static str,
+    /// Reset sequence closing every highlighted span.
+    pub reset: &'static str,
+}
+
+impl Default for SyntaxTheme {
+    fn default() -> Self {
+        Self {
+            command: "\u{1b}[1;32m",
+            flag: "\u{1b}[36m",
+            keyword: "\u{1b}[94m",
+            string: "\u{1b}[33m",
+            comment: "\u{1b}[90m",
+            reset: "\u{1b}[0m",
+        }
+    }
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "fn", "for", "if",
+    "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self",
+    "Self", "static", "struct", "trait", "type", "unsafe", "use", "where", "while",
+];
+
+const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract",
+    "async",
+    "await",
+    "bool",
+    "class",
+    "enum",
+    "false",
+    "interface",
+    "namespace",
+    "new",
+    "null",
+    "private",
+    "protected",
+    "public",
+    "record",
+    "return",
+    "sealed",
+    "static",
+    "string",
+    "struct",
+    "this",
+    "true",
+    "using",
+    "var",
+    "void",
+];
+
+const JAVASCRIPT_KEYWORDS: &[&str] = &[
+    "async",
+    "await",
+    "class",
+    "const",
+    "constructor",
+    "default",
+    "export",
+    "extends",
+    "false",
+    "for",
+    "function",
+    "if",
+    "import",
+    "let",
+    "new",
+    "null",
+    "return",
+    "static",
+    "this",
+    "true",
+    "var",
+    "while",
+];
+
+const TYPESCRIPT_KEYWORDS: &[&str] = &[
+    "as",
+    "async",
+    "await",
+    "class",
+    "const",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "function",
+    "implements",
+    "import",
+    "interface",
+    "let",
+    "module",
+    "namespace",
+    "new",
+    "null",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "return",
+    "static",
+    "this",
+    "true",
+    "type",
+    "var",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenClass {
+    Keyword,
+    String,
+    Comment,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct HighlightSpan {
+    start: usize,
+    end: usize,
+    class: TokenClass,
+}
+
+struct TreeSitterRuntime {
+    rust: Option<Parser>,
+    csharp: Option<Parser>,
+    javascript: Option<Parser>,
+    typescript: Option<Parser>,
+}
+
+impl TreeSitterRuntime {
+    fn new() -> Self {
+        Self {
+            rust: build_parser(tree_sitter_rust::language()),
+            csharp: build_parser(tree_sitter_c_sharp::language()),
+            javascript: build_parser(tree_sitter_javascript::language()),
+            typescript: build_parser(tree_sitter_typescript::language_typescript()),
+        }
+    }
+
+    fn parser_for(&mut self, language: SyntaxLanguage) -> Option<&mut Parser> {
+        match language {
+            SyntaxLanguage::Rust => self.rust.as_mut(),
+            SyntaxLanguage::CSharp => self.csharp.as_mut(),
+            SyntaxLanguage::JavaScript => self.javascript.as_mut(),
+            SyntaxLanguage::TypeScript => self.typescript.as_mut(),
+            SyntaxLanguage::Plain | SyntaxLanguage::Command => None,
+        }
+    }
+}
+
+impl Default for TreeSitterRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Default)]
+struct SyntaxHighlightCache {
+    line: String,
+    language: Option<SyntaxLanguage>,
+    theme: Option<SyntaxTheme>,
+    highlighted: Option<String>,
+}
+
+thread_local! {
+    static TREE_SITTER_RUNTIME: RefCell<TreeSitterRuntime> = RefCell::new(TreeSitterRuntime::new());
+    static SYNTAX_HIGHLIGHT_CACHE: RefCell<SyntaxHighlightCache> = RefCell::new(SyntaxHighlightCache::default());
+}
+
+fn build_parser(language: tree_sitter::Language) -> Option<Parser> {
+    let mut parser = Parser::new();
+    parser.set_language(&language).ok()?;
+    Some(parser)
+}
+
+/// Guess the language of one interactive input line.
+#[must_use]
+pub fn detect_syntax_language(line: &str) -> SyntaxLanguage {
+    let trimmed = line.trim_start();
+    if trimmed.is_empty() {
+        return SyntaxLanguage::Plain;
+    }
+
+    if trimmed.starts_with('/') {
+        return SyntaxLanguage::Command;
+    }
+
+    if trimmed.contains("namespace ")
+        || trimmed.contains("using ")
+        || trimmed.contains("public class")
+    {
+        return SyntaxLanguage::CSharp;
+    }
+
+    if trimmed.contains("interface ")
+        || trimmed.contains("type ")
+        || trimmed.contains(": string")
+        || trimmed.contains(": number")
+    {
+        return SyntaxLanguage::TypeScript;
+    }
+
+    if trimmed.contains("function ")
+        || trimmed.contains("const ")
+        || trimmed.contains("let ")
+        || trimmed.contains("=>")
+    {
+        return SyntaxLanguage::JavaScript;
+    }
+
+    if trimmed.contains("fn ")
+        || trimmed.contains("let ")
+        || trimmed.contains("impl ")
+        || trimmed.contains("match ")
+        || trimmed.contains("pub ")
+    {
+        return SyntaxLanguage::Rust;
+    }
+
+    SyntaxLanguage::Plain
+}
+
+fn comment_prefix(language: SyntaxLanguage) -> Option<&'static str> {
+    match language {
+        SyntaxLanguage::Rust
+        | SyntaxLanguage::CSharp
+        | SyntaxLanguage::JavaScript
+        | SyntaxLanguage::TypeScript => Some("//"),
+        SyntaxLanguage::Command => Some("#"),
+        SyntaxLanguage::Plain => None,
+    }
+}
+
+fn sanitize_keyword_token(token: &str) -> &str {
+    token.trim_matches(|ch: char| !ch.is_alphanumeric() && ch != '_')
+}
+
+fn is_language_keyword(token: &str, language: SyntaxLanguage) -> bool {
+    let token = sanitize_keyword_token(token);
+    match language {
+        SyntaxLanguage::Rust => RUST_KEYWORDS.contains(&token),
+        SyntaxLanguage::CSharp => CSHARP_KEYWORDS.contains(&token),
+        SyntaxLanguage::JavaScript => JAVASCRIPT_KEYWORDS.contains(&token),
+        SyntaxLanguage::TypeScript => TYPESCRIPT_KEYWORDS.contains(&token),
+        SyntaxLanguage::Plain | SyntaxLanguage::Command => false,
+    }
+}
+
+fn keyword_color_for_token(
+    token: &str,
+    token_index: usize,
+    language: SyntaxLanguage,
+    theme: SyntaxTheme,
+) -> Option<&'static str> {
+    match language {
+        SyntaxLanguage::Command => {
+            if token_index == 0 && token.starts_with('/') {
+                Some(theme.command)
+            } else if token.starts_with("--") || token.starts_with('-') {
+                Some(theme.flag)
+            } else {
+                None
+            }
+        }
+        SyntaxLanguage::Rust
+        | SyntaxLanguage::CSharp
+        | SyntaxLanguage::JavaScript
+        | SyntaxLanguage::TypeScript => {
+            is_language_keyword(token, language).then_some(theme.keyword)
+        }
+        SyntaxLanguage::Plain => None,
+    }
+}
+
+/// Highlight one line, preferring tree-sitter and falling back to a lexical pass.
+///
+/// Returns `None` when nothing was highlighted. The last result is cached per
+/// thread, since line editors re-highlight the same line on every keystroke.
+#[must_use]
+pub fn highlight_line_for_language(
+    line: &str,
+    language: SyntaxLanguage,
+    theme: SyntaxTheme,
+) -> Option<String> {
+    if line.is_empty() {
+        return None;
+    }
+
+    if line.len() > MAX_HIGHLIGHT_LINE_BYTES {
+        return highlight_line_lexical(line, language, theme);
+    }
+
+    SYNTAX_HIGHLIGHT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.language == Some(language) && cache.theme == Some(theme) && cache.line == line {
+            return cache.highlighted.clone();
+        }
+
+        let highlighted = highlight_line_with_backends(line, language, theme);
+        cache.line.clear();
+        cache.line.push_str(line);
+        cache.language = Some(language);
+        cache.theme = Some(theme);
+        cache.highlighted = highlighted.clone();
+        highlighted
+    })
+}
+
+fn highlight_line_with_backends(
+    line: &str,
+    language: SyntaxLanguage,
+    theme: SyntaxTheme,
+) -> Option<String> {
+    if matches!(language, SyntaxLanguage::Plain | SyntaxLanguage::Command) {
+        return highlight_line_lexical(line, language, theme);
+    }
+
+    tree_sitter_highlight_line(line, language, theme)
+        .or_else(|| highlight_line_lexical(line, language, theme))
+}
+
+fn highlight_line_lexical(
+    line: &str,
+    language: SyntaxLanguage,
+    theme: SyntaxTheme,
+) -> Option<String> {
+    if line.is_empty() {
+        return None;
+    }
+
+    let comment = comment_prefix(language);
+    let mut output = String::with_capacity(line.len() + 32);
+    let mut cursor = 0usize;
+    let mut token_index = 0usize;
+
+    while cursor < line.len() {
+        let Some(ch) = line[cursor..].chars().next() else {
+            break;
+        };
+
+        if ch.is_whitespace() {
+            output.push(ch);
+            cursor += ch.len_utf8();
+            continue;
+        }
+
+        if let Some(prefix) = comment {
+            if line[cursor..].starts_with(prefix) {
+                output.push_str(theme.comment);
+                output.push_str(&line[cursor..]);
+                output.push_str(theme.reset);
+                return Some(output);
+            }
+        }
+
+        if ch == '"' || ch == '\'' {
+            let quote = ch;
+            let start = cursor;
+            cursor += ch.len_utf8();
+
+            while cursor < line.len() {
+                let Some(current) = line[cursor..].chars().next() else {
+                    break;
+                };
+
+                cursor += current.len_utf8();
+
+                if current == quote {
+                    break;
+                }
+
+                if current == '\\' && cursor < line.len() {
+                    if let Some(escaped) = line[cursor..].chars().next() {
+                        cursor += escaped.len_utf8();
+                    }
+                }
+            }
+
+            output.push_str(theme.string);
+            output.push_str(&line[start..cursor]);
+            output.push_str(theme.reset);
+            token_index += 1;
+            continue;
+        }
+
+        let start = cursor;
+        while cursor < line.len() {
+            let Some(current) = line[cursor..].chars().next() else {
+                break;
+            };
+            if current.is_whitespace() || current == '"' || current == '\'' {
+                break;
+            }
+
+            if let Some(prefix) = comment {
+                if line[cursor..].starts_with(prefix) {
+                    break;
+                }
+            }
+
+            cursor += current.len_utf8();
+        }
+
+        let token = &line[start..cursor];
+        if let Some(color) = keyword_color_for_token(token, token_index, language, theme) {
+            output.push_str(color);
+            output.push_str(token);
+            output.push_str(theme.reset);
+        } else {
+            output.push_str(token);
+        }
+        token_index += 1;
+    }
+
+    (output != line).then_some(output)
+}
+
+fn tree_sitter_highlight_line(
+    line: &str,
+    language: SyntaxLanguage,
+    theme: SyntaxTheme,
+) -> Option<String> {
+    if matches!(language, SyntaxLanguage::Plain | SyntaxLanguage::Command) {
+        return None;
+    }
+
+    let spans = TREE_SITTER_RUNTIME.with(|runtime| {
+        let mut runtime = runtime.borrow_mut();
+        let Some(parser) = runtime.parser_for(language) else {
+            return Vec::new();
+        };
+
+        let Some(tree) = parser.parse(line, None) else {
+            return Vec::new();
+        };
+
+        collect_tree_sitter_spans(tree.root_node(), line, language)
+    });
+
+    if spans.is_empty() {
+        return None;
+    }
+
+    let mut output = String::with_capacity(line.len() + 32);
+    let mut cursor = 0usize;
+
+    for span in spans {
+        if span.start < cursor || span.end > line.len() || span.start >= span.end {
+            continue;
+        }
+        output.push_str(&line[cursor..span.start]);
+        output.push_str(color_for_class(span.class, theme));
+        output.push_str(&line[span.start..span.end]);
+        output.push_str(theme.reset);
+        cursor = span.end;
+    }
+
+    output.push_str(&line[cursor..]);
+
+    if line_contains_comment_prefix(line, language) && !output.contains(theme.comment) {
+        return highlight_line_lexical(line, language, theme);
+    }
+
+    (output != line).then_some(output)
+}
+
+fn line_contains_comment_prefix(line: &str, language: SyntaxLanguage) -> bool {
+    comment_prefix(language).is_some_and(|prefix| line.contains(prefix))
+}
+
+fn collect_tree_sitter_spans(
+    root: Node<'_>,
+    source: &str,
+    language: SyntaxLanguage,
+) -> Vec<HighlightSpan> {
+    let mut spans = Vec::new();
+    collect_spans_from_node(root, source.as_bytes(), language, &mut spans);
+
+    spans.sort_by(|left, right| {
+        left.start
+            .cmp(&right.start)
+            .then_with(|| token_priority(right.class).cmp(&token_priority(left.class)))
+            .then_with(|| left.end.cmp(&right.end))
+    });
+
+    let mut deduplicated = Vec::with_capacity(spans.len());
+    for span in spans {
+        if deduplicated
+            .last()
+            .is_some_and(|last: &HighlightSpan| span.start < last.end)
+        {
+            continue;
+        }
+        deduplicated.push(span);
+    }
+
+    deduplicated
+}
+
+fn collect_spans_from_node(
+    node: Node<'_>,
+    source: &[u8],
+    language: SyntaxLanguage,
+    spans: &mut Vec<HighlightSpan>,
+) {
+    if node.child_count() == 0 {
+        let start = node.start_byte();
+        let end = node.end_byte();
+        if start >= end || end > source.len() {
+            return;
+        }
+
+        let Ok(token) = std::str::from_utf8(&source[start..end]) else {
+            return;
+        };
+
+        if let Some(class) = token_class_from_node(node.kind(), token, language) {
+            spans.push(HighlightSpan { start, end, class });
+        }
+        return;
+    }
+
+    for index in 0..node.child_count() {
+        if let Some(child) = node.child(index) {
+            collect_spans_from_node(child, source, language, spans);
+        }
+    }
+}
+
+fn token_class_from_node(kind: &str, token: &str, language: SyntaxLanguage) -> Option<TokenClass> {
+    let lowered = kind.to_ascii_lowercase();
+    if lowered.contains("comment") {
+        return Some(TokenClass::Comment);
+    }
+
+    if lowered.contains("string")
+        || lowered.contains("char")
+        || lowered.contains("template")
+        || lowered.contains("raw")
+    {
+        return Some(TokenClass::String);
+    }
+
+    is_language_keyword(token, language).then_some(TokenClass::Keyword)
+}
+
+const fn token_priority(class: TokenClass) -> u8 {
+    match class {
+        TokenClass::Comment => 3,
+        TokenClass::String => 2,
+        TokenClass::Keyword => 1,
+    }
+}
+
+const fn color_for_class(class: TokenClass, theme: SyntaxTheme) -> &'static str {
+    match class {
+        TokenClass::Keyword => theme.keyword,
+        TokenClass::String => theme.string,
+        TokenClass::Comment => theme.comment,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_syntax_language_identifies_supported_variants() {
+        assert_eq!(
+            detect_syntax_language("/manifest apply"),
+            SyntaxLanguage::Command
+        );
+        assert_eq!(
+            detect_syntax_language("pub fn run() -> Result<()>"),
+            SyntaxLanguage::Rust
+        );
+        assert_eq!(
+            detect_syntax_language("public class AppService"),
+            SyntaxLanguage::CSharp
+        );
+        assert_eq!(
+            detect_syntax_language("const run = async () => {}"),
+            SyntaxLanguage::JavaScript
+        );
+        assert_eq!(
+            detect_syntax_language("interface AppConfig { retries: number }"),
+            SyntaxLanguage::TypeScript
+        );
+        assert_eq!(detect_syntax_language("free text"), SyntaxLanguage::Plain);
+    }
+
+    #[test]
+    fn highlight_command_line_includes_command_flags_and_strings() {
+        let theme = SyntaxTheme::default();
+        let highlighted = highlight_line_for_language(
+            "/manifest apply --path \"templates/app\" # note",
+            SyntaxLanguage::Command,
+            theme,
+        )
+        .expect("expected highlighted output");
+
+        assert!(highlighted.contains(theme.command));
+        assert!(highlighted.contains(theme.flag));
+        assert!(highlighted.contains(theme.string));
+        assert!(highlighted.contains(theme.comment));
+        assert!(highlighted.contains(theme.reset));
+    }
+
+    #[test]
+    fn highlight_rust_line_includes_keyword_and_comment_colors() {
+        let theme = SyntaxTheme::default();
+        let highlighted = highlight_line_for_language(
+            "pub fn run() { let value = \"ok\"; // status }",
+            SyntaxLanguage::Rust,
+            theme,
+        )
+        .expect("expected highlighted output");
+
+        assert!(highlighted.contains(theme.keyword));
+        assert!(highlighted.contains(theme.string));
+        assert!(highlighted.contains(theme.comment));
+        assert!(highlighted.contains(theme.reset));
+    }
+
+    #[test]
+    fn tree_sitter_highlight_marks_rust_comment_tokens() {
+        let theme = SyntaxTheme::default();
+        let highlighted =
+            tree_sitter_highlight_line("fn run() { // note }", SyntaxLanguage::Rust, theme)
+                .expect("expected tree-sitter highlight output");
+
+        assert!(highlighted.contains(theme.comment));
+        assert!(highlighted.contains(theme.reset));
+    }
+
+    #[test]
+    fn highlight_cache_stores_last_line_and_result() {
+        let theme = SyntaxTheme::default();
+        let line = "pub fn run() { let value = \"ok\"; }";
+
+        let first = highlight_line_for_language(line, SyntaxLanguage::Rust, theme);
+        let second = highlight_line_for_language(line, SyntaxLanguage::Rust, theme);
+        assert_eq!(first, second);
+
+        SYNTAX_HIGHLIGHT_CACHE.with(|cache| {
+            let cache = cache.borrow();
+            assert_eq!(cache.line, line);
+            assert_eq!(cache.language, Some(SyntaxLanguage::Rust));
+            assert_eq!(cache.theme, Some(theme));
+            assert_eq!(cache.highlighted, second);
+        });
+    }
+
+    #[test]
+    fn highlight_long_line_uses_fallback_without_panicking() {
+        let theme = SyntaxTheme::default();
+        let long_payload = "x".repeat(MAX_HIGHLIGHT_LINE_BYTES + 64);
+        let line = format!("pub fn run() {{ {long_payload} }}");
+
+        let highlighted = highlight_line_for_language(&line, SyntaxLanguage::Rust, theme)
+            .expect("expected lexical fallback output");
+
+        assert!(highlighted.contains(theme.keyword));
+    }
+
+    #[test]
+    fn highlight_plain_text_returns_none() {
+        let theme = SyntaxTheme::default();
+        assert!(highlight_line_for_language("plain text", SyntaxLanguage::Plain, theme).is_none());
+    }
+}
@@ -1,29 +1,20 @@
-use nettoolskit_ui::render_markdown;
+use nettoolskit_ui::{pick_str, render_markdown, render_markdown_with_width};
 
 fn strip_ansi(input: &str) -> String {
     let mut stripped = String::with_capacity(input.len());
-    let bytes = input.as_bytes();
-    let mut index = 0usize;
+    let mut chars = input.chars().peekable();
 
-    while index < bytes.len() {
-        if bytes[index] == b'\x1b' {
-            index += 1;
-            if index < bytes.len() && bytes[index] == b'[' {
-                index += 1;
-                while index < bytes.len() {
-                    let byte = bytes[index];
-                    if byte.is_ascii_alphabetic() {
-                        index += 1;
-                        break;
-                    }
-                    index += 1;
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' && chars.peek() == Some(&'[') {
+            chars.next();
+            for next in chars.by_ref() {
+                if next.is_ascii_alphabetic() {
+                    break;
                 }
-                continue;
             }
+            continue;
         }
-
-        stripped.push(bytes[index] as char);
-        index += 1;
+        stripped.push(ch);
     }
 
     stripped
@@ -64,6 +55,27 @@ fn render_markdown_handles_task_list_markers() {
     let input = "- [x] done\n- [ ] pending";
     let output = strip_ansi(&render_markdown(input));
 
-    assert!(output.contains("[x] done"));
-    assert!(output.contains("[ ] pending"));
+    assert!(output.contains(&format!("{} done", pick_str("☑", "[x]"))));
+    assert!(output.contains(&format!("{} pending", pick_str("☐", "[ ]"))));
+}
+
+#[test]
+fn render_markdown_fits_tables_to_width() {
+    let input =
+        "| Key | Value |\n|---|---|\n| timeout | Seconds to wait before the command is cancelled |";
+    let output = strip_ansi(&render_markdown_with_width(input, 32));
+
+    assert!(output.contains("timeout"));
+    assert!(output.contains("cancelled"));
+    assert!(!output.contains("|---|"));
+    for line in output.lines() {
+        assert!(line.chars().count() <= 32, "line too wide: {line:?}");
+    }
+}
+
+#[test]
+fn render_markdown_renders_block_quotes_with_gutter() {
+    let output = strip_ansi(&render_markdown("> careful"));
+
+    assert_eq!(output, format!("{} careful", pick_str("│", "|")));
 }